@@ -0,0 +1,452 @@
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::system::Resource;
+use bevy_ecs::{
+    component::Component,
+    intern::{Interned, Interner},
+    query::{QueryBuilder, QueryData, QueryFilter},
+    world::FilteredEntityRef,
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeInfo, TypeRegistry};
+#[cfg(feature = "bevy_reflect")]
+use bevy_utils::HashMap;
+use std::fmt;
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+static LABEL_INTERNER: Interner<str> = Interner::new();
+
+/// An interned string used to tag entities through their [`Labels`].
+///
+/// Labels are compared and hashed by pointer, so checking for a label is cheap.
+/// Interned strings are never freed, so labels should come from a bounded vocabulary
+/// rather than from arbitrary user input.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label(Interned<str>);
+
+impl Label {
+    /// Interns the given string as a label.
+    pub fn new(label: &str) -> Self {
+        Self(LABEL_INTERNER.intern(label))
+    }
+
+    /// Gets the label as a `&str`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &'static str {
+        self.0 .0
+    }
+}
+
+impl From<&str> for Label {
+    #[inline(always)]
+    fn from(label: &str) -> Self {
+        Label::new(label)
+    }
+}
+
+impl AsRef<str> for Label {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Label {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl fmt::Display for Label {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for Label {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Component used to tag an entity with a set of [`Label`]s.
+///
+/// Labels are kept sorted, so that equal sets of labels compare, print and serialize the same.
+/// They are serialized as a plain list of strings.
+///
+/// Unlike marker components, labels can be created at runtime, such as from level data,
+/// at the cost of not being visible to the archetype-level query filters.
+/// Use a [`LabelFilter`] to select entities by label.
+///
+/// ```
+/// # use bevy_core::Labels;
+/// let labels = Labels::from_iter(["enemy", "flying"]);
+/// assert!(labels.contains("flying"));
+/// assert!(!labels.contains("boss"));
+/// ```
+#[derive(Component, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect_value(Component, Default, Debug, PartialEq, Hash)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect_value(Deserialize, Serialize)
+)]
+pub struct Labels {
+    labels: Vec<Label>,
+}
+
+impl Labels {
+    /// Creates an empty set of labels.
+    pub const fn new() -> Self {
+        Self { labels: Vec::new() }
+    }
+
+    /// Adds a label, returning `true` if it wasn't present yet.
+    pub fn insert(&mut self, label: impl Into<Label>) -> bool {
+        let label = label.into();
+        match self.labels.binary_search(&label) {
+            Ok(_) => false,
+            Err(index) => {
+                self.labels.insert(index, label);
+                true
+            }
+        }
+    }
+
+    /// Removes a label, returning `true` if it was present.
+    pub fn remove(&mut self, label: impl AsRef<str>) -> bool {
+        match self.position(label.as_ref()) {
+            Ok(index) => {
+                self.labels.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the given label is present.
+    pub fn contains(&self, label: impl AsRef<str>) -> bool {
+        self.position(label.as_ref()).is_ok()
+    }
+
+    /// Looks up a label by its string, so that querying doesn't intern it.
+    fn position(&self, label: &str) -> Result<usize, usize> {
+        self.labels
+            .binary_search_by(|probe| probe.as_str().cmp(label))
+    }
+
+    /// Returns an iterator over the labels, sorted by their string.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Label> + '_ {
+        self.labels.iter().copied()
+    }
+
+    /// The number of labels.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if there are no labels.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl<L: Into<Label>> FromIterator<L> for Labels {
+    fn from_iter<I: IntoIterator<Item = L>>(iter: I) -> Self {
+        let mut labels = Labels::new();
+        labels.extend(iter);
+        labels
+    }
+}
+
+impl<L: Into<Label>> Extend<L> for Labels {
+    fn extend<I: IntoIterator<Item = L>>(&mut self, iter: I) {
+        for label in iter {
+            self.insert(label);
+        }
+    }
+}
+
+impl fmt::Debug for Labels {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(&self.labels).finish()
+    }
+}
+
+/// Selects entities by their [`Labels`], on top of a dynamic query built with a [`QueryBuilder`].
+///
+/// Labels are stored as component data, so they can't filter archetypes directly.
+/// Instead, [`configure`](Self::configure) adds the read access to [`Labels`] the filter needs,
+/// and [`matches_entity`](Self::matches_entity) is checked on each query item.
+///
+/// ```
+/// # use bevy_core::{LabelFilter, Labels};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::FilteredEntityRef;
+/// let mut world = World::new();
+/// let boss = world.spawn(Labels::from_iter(["enemy", "boss"])).id();
+/// world.spawn(Labels::from_iter(["enemy"]));
+/// world.spawn(Labels::from_iter(["boss", "defeated"]));
+///
+/// let filter = LabelFilter::new().with("boss").without("defeated");
+/// let mut builder = QueryBuilder::<FilteredEntityRef>::new(&mut world);
+/// filter.configure(&mut builder);
+/// let mut query = builder.build();
+///
+/// let bosses: Vec<Entity> = query
+///     .iter(&world)
+///     .filter(|entity| filter.matches_entity(entity))
+///     .map(|entity| entity.id())
+///     .collect();
+/// assert_eq!(vec![boss], bosses);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LabelFilter {
+    with: Vec<Box<str>>,
+    without: Vec<Box<str>>,
+}
+
+impl LabelFilter {
+    /// Creates a filter that matches every entity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match entities with the given label.
+    #[must_use]
+    pub fn with(mut self, label: impl AsRef<str>) -> Self {
+        self.with.push(label.as_ref().into());
+        self
+    }
+
+    /// Only match entities without the given label.
+    #[must_use]
+    pub fn without(mut self, label: impl AsRef<str>) -> Self {
+        self.without.push(label.as_ref().into());
+        self
+    }
+
+    /// Adds the access needed by this filter to a dynamic query.
+    ///
+    /// If the filter requires any label, entities without [`Labels`] are filtered out by the query itself.
+    pub fn configure<D: QueryData, F: QueryFilter>(&self, builder: &mut QueryBuilder<D, F>) {
+        let id = builder.world_mut().init_component::<Labels>();
+        if self.with.is_empty() {
+            builder.optional(|builder| {
+                builder.ref_id(id);
+            });
+        } else {
+            builder.ref_id(id);
+        }
+    }
+
+    /// Returns `true` if an entity with the given labels matches the filter.
+    pub fn matches(&self, labels: Option<&Labels>) -> bool {
+        match labels {
+            Some(labels) => {
+                self.with.iter().all(|label| labels.contains(label))
+                    && !self.without.iter().any(|label| labels.contains(label))
+            }
+            None => self.with.is_empty(),
+        }
+    }
+
+    /// Returns `true` if an entity of a query configured with [`configure`](Self::configure) matches the filter.
+    pub fn matches_entity(&self, entity: &FilteredEntityRef) -> bool {
+        self.matches(entity.get::<Labels>())
+    }
+}
+
+/// A custom attribute declaring the labels that relate to a reflected type,
+/// collected into a [`LabelVocabulary`].
+///
+/// ```
+/// # use bevy_core::DeclaredLabels;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect)]
+/// #[reflect(@DeclaredLabels::new(["enemy", "boss"]))]
+/// struct Boss;
+/// ```
+#[cfg(feature = "bevy_reflect")]
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+pub struct DeclaredLabels(pub Vec<&'static str>);
+
+#[cfg(feature = "bevy_reflect")]
+impl DeclaredLabels {
+    /// Creates the attribute from a list of labels.
+    pub fn new(labels: impl IntoIterator<Item = &'static str>) -> Self {
+        Self(labels.into_iter().collect())
+    }
+}
+
+/// The set of known labels, such as for validating level data or suggesting labels in an editor.
+///
+/// Labels are collected from the [`DeclaredLabels`] attribute of the registered types,
+/// and can also be added manually.
+#[cfg(feature = "bevy_reflect")]
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LabelVocabulary {
+    // Keyed by the interned string of each label, so that lookups don't need to intern.
+    labels: HashMap<&'static str, Vec<&'static str>>,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl LabelVocabulary {
+    /// Collects the labels declared by the types of the registry.
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut vocabulary = Self::default();
+        for registration in registry.iter() {
+            let info = registration.type_info();
+            let declared = match info {
+                TypeInfo::Struct(info) => info.get_attribute::<DeclaredLabels>(),
+                TypeInfo::TupleStruct(info) => info.get_attribute::<DeclaredLabels>(),
+                TypeInfo::Enum(info) => info.get_attribute::<DeclaredLabels>(),
+                _ => None,
+            };
+            for label in declared.into_iter().flat_map(|declared| &declared.0) {
+                vocabulary
+                    .labels
+                    .entry(Label::new(label).as_str())
+                    .or_default()
+                    .push(info.type_path());
+            }
+        }
+        vocabulary
+    }
+
+    /// Adds a label that isn't declared by any type.
+    pub fn insert(&mut self, label: impl Into<Label>) {
+        self.labels.entry(label.into().as_str()).or_default();
+    }
+
+    /// Returns `true` if the label is part of the vocabulary.
+    pub fn contains(&self, label: impl AsRef<str>) -> bool {
+        self.labels.contains_key(label.as_ref())
+    }
+
+    /// Returns the type paths of the types declaring the given label.
+    pub fn declared_by(&self, label: impl AsRef<str>) -> &[&'static str] {
+        self.labels
+            .get(label.as_ref())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the labels of the vocabulary, in no particular order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Label> + '_ {
+        // The keys were interned on insertion
+        self.labels.keys().map(|&label| Label(Interned(label)))
+    }
+
+    /// Returns the labels of `labels` that aren't part of the vocabulary.
+    pub fn unknown<'a>(&'a self, labels: &'a Labels) -> impl Iterator<Item = Label> + 'a {
+        labels
+            .iter()
+            .filter(|label| !self.labels.contains_key(label.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{entity::Entity, world::World};
+
+    #[test]
+    fn labels_are_interned_and_sorted() {
+        assert_eq!(
+            Label::new("enemy"),
+            Label::from(String::from("enemy").as_str())
+        );
+        assert_ne!(Label::new("enemy"), Label::new("ally"));
+
+        let mut labels = Labels::from_iter(["water", "fire", "earth"]);
+        assert!(!labels.insert("fire"));
+        assert_eq!(
+            vec!["earth", "fire", "water"],
+            labels
+                .iter()
+                .map(|label| label.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(labels.contains(Label::new("earth")));
+        assert!(!labels.remove("air"));
+        assert!(labels.remove("fire"));
+        assert!(!labels.contains("fire"));
+        assert_eq!(Labels::from_iter(["water", "earth"]), labels);
+    }
+
+    #[test]
+    fn label_filter_selects_entities() {
+        let mut world = World::new();
+        let a = world.spawn(Labels::from_iter(["a"])).id();
+        let ab = world.spawn(Labels::from_iter(["a", "b"])).id();
+        let unlabeled = world.spawn_empty().id();
+
+        let mut query = |filter: LabelFilter| {
+            let mut builder = QueryBuilder::<FilteredEntityRef>::new(&mut world);
+            filter.configure(&mut builder);
+            let mut query = builder.build();
+            let mut entities: Vec<Entity> = query
+                .iter(&world)
+                .filter(|entity| filter.matches_entity(entity))
+                .map(|entity| entity.id())
+                .collect();
+            entities.sort();
+            entities
+        };
+
+        assert_eq!(vec![a, ab], query(LabelFilter::new().with("a")));
+        assert_eq!(vec![a], query(LabelFilter::new().with("a").without("b")));
+        assert_eq!(vec![a, unlabeled], query(LabelFilter::new().without("b")));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn vocabulary_collects_declared_labels() {
+        #[derive(Reflect)]
+        #[reflect(@DeclaredLabels::new(["enemy", "boss"]))]
+        struct Boss;
+
+        #[derive(Reflect)]
+        #[reflect(@DeclaredLabels::new(["enemy"]))]
+        enum Minion {
+            Melee,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Boss>();
+        registry.register::<Minion>();
+        let mut vocabulary = LabelVocabulary::from_registry(&registry);
+        vocabulary.insert("decoration");
+
+        assert_eq!(3, vocabulary.iter().len());
+        assert!(vocabulary.iter().any(|label| label == Label::new("boss")));
+        assert!(vocabulary.contains("decoration"));
+        assert!(!vocabulary.contains("typo"));
+        assert_eq!(2, vocabulary.declared_by("enemy").len());
+        assert_eq!(
+            [<Boss as bevy_reflect::TypePath>::type_path()].as_slice(),
+            vocabulary.declared_by("boss")
+        );
+
+        let labels = Labels::from_iter(["boss", "decoration", "typo"]);
+        assert_eq!(
+            vec![Label::new("typo")],
+            vocabulary.unknown(&labels).collect::<Vec<_>>()
+        );
+    }
+}
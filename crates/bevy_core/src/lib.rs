@@ -7,12 +7,14 @@
 
 //! This crate provides core functionality for Bevy Engine.
 
+mod labels;
 mod name;
 #[cfg(feature = "serialize")]
 mod serde;
 mod task_pool_options;
 
 use bevy_ecs::system::Resource;
+pub use labels::*;
 pub use name::*;
 pub use task_pool_options::*;
 
@@ -39,7 +41,7 @@ impl Plugin for TypeRegistrationPlugin {
     #[cfg_attr(not(feature = "bevy_reflect"), allow(unused_variables))]
     fn build(&self, app: &mut App) {
         #[cfg(feature = "bevy_reflect")]
-        app.register_type::<Name>();
+        app.register_type::<Name>().register_type::<Labels>();
     }
 }
 
@@ -4,10 +4,12 @@ use std::{
 };
 
 use serde::{
-    de::{Error, Visitor},
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+use super::labels::Labels;
 use super::name::Name;
 use super::FrameCount;
 
@@ -41,6 +43,41 @@ impl<'de> Visitor<'de> for EntityVisitor {
     }
 }
 
+// Labels are serialized as a plain list of strings
+impl Serialize for Labels {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for label in self.iter() {
+            seq.serialize_element(label.as_str())?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Labels {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LabelsVisitor)
+    }
+}
+
+struct LabelsVisitor;
+
+impl<'de> Visitor<'de> for LabelsVisitor {
+    type Value = Labels;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a list of labels")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut labels = Labels::new();
+        while let Some(label) = seq.next_element::<std::borrow::Cow<str>>()? {
+            labels.insert(&*label);
+        }
+        Ok(labels)
+    }
+}
+
 // Manually implementing serialize/deserialize allows us to use a more compact representation as simple integers
 impl Serialize for FrameCount {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -83,6 +120,20 @@ mod tests {
         assert_tokens(&name, &[Token::String("MyComponent")]);
     }
 
+    #[test]
+    fn test_serde_labels() {
+        let labels = Labels::from_iter(["enemy", "boss"]);
+        assert_tokens(
+            &labels,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("boss"),
+                Token::Str("enemy"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_serde_frame_count() {
         let frame_count = FrameCount(100);
@@ -15,6 +15,8 @@ mod diagnostic;
 mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+#[cfg(feature = "reflect_stats")]
+mod reflect_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
@@ -23,6 +25,11 @@ pub use diagnostic::*;
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+#[cfg(feature = "reflect_stats")]
+pub use reflect_diagnostics_plugin::{
+    measure_reflect, MeasureReflect, ReflectDiagnosticsPlugin, ReflectFrameStats,
+    ReflectSystemStats,
+};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
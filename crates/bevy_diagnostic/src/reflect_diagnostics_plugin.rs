@@ -0,0 +1,225 @@
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::system::{Adapt, AdapterSystem};
+use bevy_reflect::{
+    stats::{self, ReflectStats},
+    std_traits::ReflectDefault,
+    Reflect,
+};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+use std::sync::{Mutex, PoisonError};
+
+/// The counts of the systems wrapped with [`measure_reflect`] that ran since the last frame.
+static SYSTEM_STATS: Mutex<Vec<(Cow<'static, str>, ReflectStats)>> = Mutex::new(Vec::new());
+
+/// Adds "reflection" diagnostics to an App, counting how often reflection hot paths
+/// were hit during the last frame.
+///
+/// The counts are read from [`bevy_reflect::stats`] once per frame, in [`Last`],
+/// and reset afterwards.
+/// They are stored in the [`ReflectFrameStats`] resource and recorded as diagnostics.
+///
+/// The counters are process-wide, so work done outside of the schedule,
+/// such as by asset loaders on other threads, is attributed to the frame it happened in.
+/// To find out which systems are responsible, wrap them with [`measure_reflect`]:
+/// their own counts are then stored in the [`ReflectSystemStats`] resource.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct ReflectDiagnosticsPlugin;
+
+impl Plugin for ReflectDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ReflectFrameStats>()
+            .register_type::<ReflectSystemStats>()
+            .init_resource::<ReflectFrameStats>()
+            .init_resource::<ReflectSystemStats>()
+            .register_diagnostic(Diagnostic::new(Self::DYNAMIC_ALLOCATIONS))
+            .register_diagnostic(Diagnostic::new(Self::REGISTRY_LOOKUPS))
+            .register_diagnostic(Diagnostic::new(Self::APPLIES))
+            .register_diagnostic(Diagnostic::new(Self::CLONES))
+            .add_systems(Last, Self::diagnostic_system);
+
+        // Don't attribute the work done while building the app to the first frame
+        stats::take();
+        SYSTEM_STATS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl ReflectDiagnosticsPlugin {
+    pub const DYNAMIC_ALLOCATIONS: DiagnosticPath =
+        DiagnosticPath::const_new("reflect/dynamic_allocations");
+    pub const REGISTRY_LOOKUPS: DiagnosticPath =
+        DiagnosticPath::const_new("reflect/registry_lookups");
+    pub const APPLIES: DiagnosticPath = DiagnosticPath::const_new("reflect/applies");
+    pub const CLONES: DiagnosticPath = DiagnosticPath::const_new("reflect/clones");
+
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        mut frame_stats: ResMut<ReflectFrameStats>,
+        mut system_stats: ResMut<ReflectSystemStats>,
+    ) {
+        let counts = stats::take();
+        *frame_stats = counts.into();
+
+        system_stats.systems.clear();
+        let measured =
+            std::mem::take(&mut *SYSTEM_STATS.lock().unwrap_or_else(PoisonError::into_inner));
+        for (name, counts) in measured {
+            let total = system_stats.systems.entry(name).or_default();
+            total.dynamic_allocations += counts.dynamic_allocations;
+            total.registry_lookups += counts.registry_lookups;
+            total.applies += counts.applies;
+            total.clones += counts.clones;
+        }
+
+        diagnostics.add_measurement(&Self::DYNAMIC_ALLOCATIONS, || {
+            counts.dynamic_allocations as f64
+        });
+        diagnostics.add_measurement(&Self::REGISTRY_LOOKUPS, || counts.registry_lookups as f64);
+        diagnostics.add_measurement(&Self::APPLIES, || counts.applies as f64);
+        diagnostics.add_measurement(&Self::CLONES, || counts.clones as f64);
+    }
+}
+
+/// The number of reflection operations performed during the last frame.
+///
+/// This resource is updated by the [`ReflectDiagnosticsPlugin`].
+/// See [`bevy_reflect::stats::ReflectStats`] for what each field counts.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct ReflectFrameStats {
+    pub dynamic_allocations: u64,
+    pub registry_lookups: u64,
+    pub applies: u64,
+    pub clones: u64,
+}
+
+impl ReflectFrameStats {
+    /// Returns the total number of reflection operations performed during the last frame.
+    pub fn total(&self) -> u64 {
+        self.dynamic_allocations + self.registry_lookups + self.applies + self.clones
+    }
+}
+
+impl From<ReflectStats> for ReflectFrameStats {
+    fn from(counts: ReflectStats) -> Self {
+        Self {
+            dynamic_allocations: counts.dynamic_allocations,
+            registry_lookups: counts.registry_lookups,
+            applies: counts.applies,
+            clones: counts.clones,
+        }
+    }
+}
+
+/// The number of reflection operations performed by each system wrapped with [`measure_reflect`]
+/// during the last frame, keyed by system name.
+///
+/// Only the systems that ran during the last frame are present.
+/// Operations the systems hand off to other threads, such as through a `par_iter`, aren't counted.
+///
+/// This resource is updated by the [`ReflectDiagnosticsPlugin`].
+#[derive(Resource, Reflect, Debug, Default, Clone, PartialEq, Eq)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct ReflectSystemStats {
+    pub systems: HashMap<Cow<'static, str>, ReflectFrameStats>,
+}
+
+impl ReflectSystemStats {
+    /// Returns the counts of the system with the given name,
+    /// or `None` if it didn't run during the last frame.
+    pub fn get(&self, system: &str) -> Option<&ReflectFrameStats> {
+        self.systems.get(system)
+    }
+}
+
+/// Counts the reflection operations performed by `system` each time it runs,
+/// for the [`ReflectDiagnosticsPlugin`] to report in [`ReflectSystemStats`].
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_diagnostic::{measure_reflect, ReflectDiagnosticsPlugin};
+/// fn my_system() {}
+///
+/// App::new()
+///     .add_plugins(ReflectDiagnosticsPlugin)
+///     .add_systems(Update, measure_reflect(my_system));
+/// ```
+pub fn measure_reflect<S, In, Out, Marker>(system: S) -> AdapterSystem<MeasureReflect, S::System>
+where
+    S: IntoSystem<In, Out, Marker>,
+{
+    let system = IntoSystem::into_system(system);
+    let name = system.name();
+    AdapterSystem::new(MeasureReflect { name: name.clone() }, system, name)
+}
+
+/// The [`Adapt`] implementation used by [`measure_reflect`].
+pub struct MeasureReflect {
+    name: Cow<'static, str>,
+}
+
+impl<S: System> Adapt<S> for MeasureReflect {
+    type In = S::In;
+    type Out = S::Out;
+
+    fn adapt(&mut self, input: Self::In, run_system: impl FnOnce(S::In) -> S::Out) -> Self::Out {
+        // Systems run to completion on a single thread, so the thread's counts belong to this system
+        let (output, counts) = stats::measure(|| run_system(input));
+        SYSTEM_STATS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((self.name.clone(), counts));
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsStore;
+
+    #[test]
+    fn should_record_and_reset_counts() {
+        fn clone_once(mut done: Local<bool>) {
+            if !*done {
+                *done = true;
+                vec![1_u32, 2, 3].clone_value();
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(ReflectDiagnosticsPlugin)
+            .add_systems(Update, measure_reflect(clone_once));
+        let name = IntoSystem::into_system(clone_once).name();
+
+        app.update();
+        let system_stats = app.world().resource::<ReflectSystemStats>();
+        assert_eq!(4, system_stats.get(&name).unwrap().clones);
+        assert_eq!(0, system_stats.get(&name).unwrap().applies);
+        let frame_stats = *app.world().resource::<ReflectFrameStats>();
+        assert!(frame_stats.clones >= 4);
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert_eq!(
+            Some(frame_stats.clones as f64),
+            store
+                .get(&ReflectDiagnosticsPlugin::CLONES)
+                .unwrap()
+                .value()
+        );
+
+        app.update();
+        let system_stats = app.world().resource::<ReflectSystemStats>();
+        assert_eq!(1, system_stats.systems.len());
+        assert_eq!(0, system_stats.get(&name).unwrap().total());
+    }
+}
@@ -57,7 +57,7 @@
 //!
 //! [`get_type_registration`]: bevy_reflect::GetTypeRegistration::get_type_registration
 
-use super::from_reflect_with_fallback;
+use super::{from_reflect_with_fallback, ReflectSnapshot};
 use crate::{
     change_detection::Mut,
     component::Component,
@@ -177,6 +177,22 @@ impl ReflectComponent {
         (self.0.reflect)(entity.into())
     }
 
+    /// Takes an owned [snapshot](ReflectSnapshot) of this [`Component`] type from the entity,
+    /// including its change ticks.
+    ///
+    /// Returns `None` if the entity does not exist or does not have the component.
+    pub fn snapshot(&self, world: &World, entity: Entity) -> Option<ReflectSnapshot> {
+        let entity = world.get_entity(entity)?;
+        let component = self.reflect(entity)?;
+        let component_id = world.components().get_id(component.as_any().type_id())?;
+        let ticks = entity.get_change_ticks_by_id(component_id)?;
+        Some(ReflectSnapshot::new(
+            component,
+            ticks,
+            world.read_change_tick(),
+        ))
+    }
+
     /// Gets the value of this [`Component`] type from the entity as a mutable reflected reference.
     pub fn reflect_mut<'a>(
         &self,
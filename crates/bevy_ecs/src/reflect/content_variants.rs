@@ -0,0 +1,429 @@
+use std::any::TypeId;
+
+use bevy_reflect::{std_traits::ReflectDefault, ApplyError, GetPath, Reflect, TypeRegistry};
+use bevy_utils::{tracing::error, HashMap};
+use thiserror::Error;
+
+use crate as bevy_ecs;
+use crate::{
+    change_detection::Mut,
+    system::Resource,
+    world::{Command, World},
+};
+
+use super::{AppTypeRegistry, ReflectResource};
+
+/// A value applied to a field of a reflected [`Resource`] when a content variant is selected.
+#[derive(Debug)]
+pub struct ContentPatch {
+    /// The [`TypeId`] of the patched resource.
+    pub resource: TypeId,
+    /// The [path](GetPath) of the patched field, relative to the resource.
+    pub path: String,
+    /// The value that is [applied](Reflect::try_apply) to the field.
+    pub value: Box<dyn Reflect>,
+}
+
+/// A set of named variants of some content, each patching fields of reflected resources.
+///
+/// This is used to run A/B experiments and live-tuning sessions driven by data:
+/// every variant sets the same fields to different values,
+/// and one of them is selected with [`World::select_content_variant`]
+/// or the [`SelectContentVariant`] command.
+///
+/// Since selecting a variant only applies its own patches,
+/// all variants of an experiment should patch the same set of fields.
+///
+/// Experiments are stored in the [`ContentExperiments`] resource,
+/// and the selected variants are recorded in the [`ActiveContentVariants`] resource.
+#[derive(Debug, Default)]
+pub struct ContentExperiment {
+    variants: Vec<(String, Vec<ContentPatch>)>,
+}
+
+impl ContentExperiment {
+    /// Creates an experiment without any variants.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a patch setting the field at `path` of the resource `R` to `value` in the given variant.
+    pub fn with_patch<R: Resource>(
+        mut self,
+        variant: impl Into<String>,
+        path: impl Into<String>,
+        value: impl Reflect,
+    ) -> Self {
+        self.insert(
+            variant,
+            ContentPatch {
+                resource: TypeId::of::<R>(),
+                path: path.into(),
+                value: Box::new(value),
+            },
+        );
+        self
+    }
+
+    /// Adds a patch to the given variant, creating the variant if it doesn't exist.
+    ///
+    /// Patches are applied in the order they were added.
+    pub fn insert(&mut self, variant: impl Into<String>, patch: ContentPatch) {
+        let variant = variant.into();
+        match self.variants.iter_mut().find(|(name, _)| *name == variant) {
+            Some((_, patches)) => patches.push(patch),
+            None => self.variants.push((variant, vec![patch])),
+        }
+    }
+
+    /// Returns the patches of the given variant, if it exists.
+    pub fn variant(&self, name: &str) -> Option<&[ContentPatch]> {
+        self.variants
+            .iter()
+            .find(|(variant, _)| variant == name)
+            .map(|(_, patches)| patches.as_slice())
+    }
+
+    /// Returns an iterator over the names of all variants, in the order they were added.
+    pub fn variant_names(&self) -> impl Iterator<Item = &str> {
+        self.variants.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+/// The [`ContentExperiment`]s of the world, by name.
+#[derive(Resource, Debug, Default)]
+pub struct ContentExperiments {
+    experiments: HashMap<String, ContentExperiment>,
+}
+
+impl ContentExperiments {
+    /// Adds an experiment, replacing any existing experiment with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, experiment: ContentExperiment) {
+        self.experiments.insert(name.into(), experiment);
+    }
+
+    /// Returns the experiment with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&ContentExperiment> {
+        self.experiments.get(name)
+    }
+
+    /// Removes the experiment with the given name, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<ContentExperiment> {
+        self.experiments.remove(name)
+    }
+
+    /// Returns an iterator over all experiments and their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ContentExperiment)> {
+        self.experiments
+            .iter()
+            .map(|(name, experiment)| (name.as_str(), experiment))
+    }
+}
+
+/// The variant selected for each [`ContentExperiment`], by experiment name.
+///
+/// This resource is reflected, so it can be saved alongside other data,
+/// such as analytics events or save files.
+/// After loading it, call [`World::apply_active_content_variants`]
+/// to apply the recorded variants again.
+#[derive(Resource, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct ActiveContentVariants {
+    variants: HashMap<String, String>,
+}
+
+impl ActiveContentVariants {
+    /// Returns the name of the variant selected for the given experiment, if any.
+    pub fn get(&self, experiment: &str) -> Option<&str> {
+        self.variants.get(experiment).map(String::as_str)
+    }
+
+    /// Records the variant selected for the given experiment, without applying it.
+    pub fn set(&mut self, experiment: impl Into<String>, variant: impl Into<String>) {
+        self.variants.insert(experiment.into(), variant.into());
+    }
+
+    /// Returns an iterator over all experiment names and their selected variant.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.variants
+            .iter()
+            .map(|(experiment, variant)| (experiment.as_str(), variant.as_str()))
+    }
+}
+
+/// An error returned when selecting a content variant.
+#[derive(Error, Debug)]
+pub enum ContentVariantError {
+    /// No experiment with the given name exists in [`ContentExperiments`].
+    #[error("unknown content experiment `{0}`")]
+    UnknownExperiment(String),
+    /// The experiment has no variant with the given name.
+    #[error("content experiment `{experiment}` has no variant `{variant}`")]
+    UnknownVariant {
+        /// The name of the experiment.
+        experiment: String,
+        /// The name of the variant.
+        variant: String,
+    },
+    /// The patched resource type has no [`ReflectResource`] registered in the [`AppTypeRegistry`].
+    #[error("no `ReflectResource` is registered for the resource type {0:?}")]
+    UnregisteredResource(TypeId),
+    /// The patched resource does not exist in the world.
+    #[error("the resource `{0}` does not exist")]
+    MissingResource(&'static str),
+    /// The path of a patch does not point to a field of the resource.
+    #[error("invalid path `{path}` in `{resource}`: {message}")]
+    InvalidPath {
+        /// The type path of the patched resource.
+        resource: &'static str,
+        /// The path of the patch.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The value of a patch could not be applied to its field.
+    #[error("failed to apply `{path}` in `{resource}`: {error}")]
+    Apply {
+        /// The type path of the patched resource.
+        resource: &'static str,
+        /// The path of the patch.
+        path: String,
+        /// The error returned when applying the value.
+        error: ApplyError,
+    },
+}
+
+impl World {
+    /// Selects a variant of a [`ContentExperiment`] and applies its patches.
+    ///
+    /// The selected variant is recorded in [`ActiveContentVariants`],
+    /// which is initialized if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the experiment or variant doesn't exist, or if a patch can't be applied.
+    /// If a patch fails to apply, the patches before it will already have been applied,
+    /// but the variant is not recorded as active.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AppTypeRegistry`] or [`ContentExperiments`] resources do not exist.
+    pub fn select_content_variant(
+        &mut self,
+        experiment: &str,
+        variant: &str,
+    ) -> Result<(), ContentVariantError> {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        self.resource_scope(|world, experiments: Mut<ContentExperiments>| {
+            let patches = experiments
+                .get(experiment)
+                .ok_or_else(|| ContentVariantError::UnknownExperiment(experiment.to_string()))?
+                .variant(variant)
+                .ok_or_else(|| ContentVariantError::UnknownVariant {
+                    experiment: experiment.to_string(),
+                    variant: variant.to_string(),
+                })?;
+            for patch in patches {
+                apply_patch(world, &registry, patch)?;
+            }
+            Ok(())
+        })?;
+
+        self.get_resource_or_insert_with(ActiveContentVariants::default)
+            .set(experiment, variant);
+        Ok(())
+    }
+
+    /// Applies the variants recorded in [`ActiveContentVariants`], if it exists.
+    ///
+    /// This is typically called at startup, after loading the active variants from saved data.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of all variants that could not be applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AppTypeRegistry`] or [`ContentExperiments`] resources do not exist.
+    pub fn apply_active_content_variants(&mut self) -> Result<(), Vec<ContentVariantError>> {
+        let Some(active) = self.get_resource::<ActiveContentVariants>() else {
+            return Ok(());
+        };
+        let mut active: Vec<_> = active
+            .iter()
+            .map(|(experiment, variant)| (experiment.to_string(), variant.to_string()))
+            .collect();
+        // Apply in a stable order, so that overlapping experiments behave deterministically
+        active.sort_unstable();
+
+        let errors: Vec<_> = active
+            .iter()
+            .filter_map(|(experiment, variant)| {
+                self.select_content_variant(experiment, variant).err()
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn apply_patch(
+    world: &mut World,
+    registry: &TypeRegistry,
+    patch: &ContentPatch,
+) -> Result<(), ContentVariantError> {
+    let registration = registry
+        .get(patch.resource)
+        .ok_or(ContentVariantError::UnregisteredResource(patch.resource))?;
+    let reflect_resource = registration
+        .data::<ReflectResource>()
+        .ok_or(ContentVariantError::UnregisteredResource(patch.resource))?;
+    let resource_path = registration.type_info().type_path();
+
+    let mut resource = reflect_resource
+        .reflect_mut(world)
+        .ok_or(ContentVariantError::MissingResource(resource_path))?;
+    let field = resource
+        .reflect_path_mut(patch.path.as_str())
+        .map_err(|err| ContentVariantError::InvalidPath {
+            resource: resource_path,
+            path: patch.path.clone(),
+            message: err.to_string(),
+        })?;
+    field
+        .try_apply(&*patch.value)
+        .map_err(|error| ContentVariantError::Apply {
+            resource: resource_path,
+            path: patch.path.clone(),
+            error,
+        })
+}
+
+/// A [`Command`] that selects a variant of a [`ContentExperiment`].
+///
+/// See [`World::select_content_variant`] for details.
+/// Errors are logged rather than returned.
+#[derive(Debug, Clone)]
+pub struct SelectContentVariant {
+    /// The name of the experiment.
+    pub experiment: String,
+    /// The name of the variant to select.
+    pub variant: String,
+}
+
+impl SelectContentVariant {
+    /// Creates a command selecting the given variant of an experiment.
+    pub fn new(experiment: impl Into<String>, variant: impl Into<String>) -> Self {
+        Self {
+            experiment: experiment.into(),
+            variant: variant.into(),
+        }
+    }
+}
+
+impl Command for SelectContentVariant {
+    fn apply(self, world: &mut World) {
+        if let Err(err) = world.select_content_variant(&self.experiment, &self.variant) {
+            error!("Failed to select content variant: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::Reflect;
+
+    use super::*;
+    use crate::{system::Commands, world::CommandQueue};
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Tuning {
+        jump_height: f32,
+        enemy: Enemy,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Enemy {
+        health: u32,
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Tuning>();
+        world.insert_resource(Tuning {
+            jump_height: 1.0,
+            enemy: Enemy { health: 100 },
+        });
+
+        let mut experiments = ContentExperiments::default();
+        experiments.insert(
+            "difficulty",
+            ContentExperiment::new()
+                .with_patch::<Tuning>("easy", "jump_height", 1.5_f32)
+                .with_patch::<Tuning>("easy", "enemy.health", 50_u32)
+                .with_patch::<Tuning>("hard", "jump_height", 0.8_f32)
+                .with_patch::<Tuning>("hard", "enemy.health", 200_u32),
+        );
+        world.insert_resource(experiments);
+        world
+    }
+
+    #[test]
+    fn should_select_and_record_variant() {
+        let mut world = world();
+
+        world.select_content_variant("difficulty", "hard").unwrap();
+        assert_eq!(0.8, world.resource::<Tuning>().jump_height);
+        assert_eq!(200, world.resource::<Tuning>().enemy.health);
+        assert_eq!(
+            Some("hard"),
+            world.resource::<ActiveContentVariants>().get("difficulty")
+        );
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world).add(SelectContentVariant::new("difficulty", "easy"));
+        queue.apply(&mut world);
+        assert_eq!(1.5, world.resource::<Tuning>().jump_height);
+        assert_eq!(
+            Some("easy"),
+            world.resource::<ActiveContentVariants>().get("difficulty")
+        );
+    }
+
+    #[test]
+    fn should_apply_active_variants() {
+        let mut world = world();
+        let mut active = ActiveContentVariants::default();
+        active.set("difficulty", "easy");
+        world.insert_resource(active);
+
+        world.apply_active_content_variants().unwrap();
+        assert_eq!(50, world.resource::<Tuning>().enemy.health);
+    }
+
+    #[test]
+    fn should_report_unknown_variants() {
+        let mut world = world();
+
+        assert!(matches!(
+            world.select_content_variant("difficulty", "nightmare"),
+            Err(ContentVariantError::UnknownVariant { .. })
+        ));
+        assert!(matches!(
+            world.select_content_variant("speed", "fast"),
+            Err(ContentVariantError::UnknownExperiment(_))
+        ));
+        assert!(world.get_resource::<ActiveContentVariants>().is_none());
+        assert_eq!(1.0, world.resource::<Tuning>().jump_height);
+    }
+}
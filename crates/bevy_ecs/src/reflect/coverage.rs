@@ -0,0 +1,384 @@
+use std::any::TypeId;
+use std::fmt;
+
+use bevy_reflect::serde::SerializationData;
+use bevy_reflect::std_traits::ReflectDefault;
+use bevy_reflect::{
+    ReflectDeserialize, ReflectFromReflect, ReflectSerialize, TypeInfo, TypeRegistration,
+    TypeRegistry, VariantInfo,
+};
+use bevy_utils::HashSet;
+
+use super::{ReflectComponent, ReflectFromWorld, ReflectResource};
+use crate::world::World;
+
+/// Checks that every component and resource type present in the world
+/// can be saved and loaded through reflection.
+///
+/// This walks the components of every non-empty archetype and every resource of the world,
+/// and reports all the problems that would otherwise surface one at a time,
+/// as load-time failures of scenes and other reflected content.
+///
+/// Run it once at startup, after all plugins are built, and log or assert on the result.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::verify_reflection_coverage;
+/// # use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeRegistry};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, Default)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Unregistered;
+///
+/// let mut world = World::new();
+/// world.spawn((Health(10), Unregistered));
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+///
+/// let report = verify_reflection_coverage(&world, &registry);
+/// assert_eq!(1, report.entries.len());
+/// assert!(report.entries[0].name.ends_with("Unregistered"));
+/// ```
+pub fn verify_reflection_coverage(world: &World, registry: &TypeRegistry) -> CoverageReport {
+    let mut targets = Vec::new();
+    for archetype in world.archetypes().iter() {
+        if archetype.is_empty() {
+            continue;
+        }
+        targets.extend(
+            archetype
+                .components()
+                .map(|id| (id, CoverageTarget::Component)),
+        );
+    }
+    targets.extend(
+        world
+            .iter_resources()
+            .map(|(info, _)| (info.id(), CoverageTarget::Resource)),
+    );
+    targets.sort_unstable();
+    targets.dedup();
+
+    let mut entries: Vec<CoverageEntry> = targets
+        .into_iter()
+        .filter_map(|(id, target)| {
+            let info = world.components().get_info(id)?;
+            // Components registered at runtime without a Rust type can't be reflected anyway
+            let type_id = info.type_id()?;
+            let issues = check_type(registry, type_id, target);
+            (!issues.is_empty()).then(|| CoverageEntry {
+                name: info.name().to_string(),
+                target,
+                issues,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    CoverageReport { entries }
+}
+
+fn check_type(
+    registry: &TypeRegistry,
+    type_id: TypeId,
+    target: CoverageTarget,
+) -> Vec<CoverageIssue> {
+    let Some(registration) = registry.get(type_id) else {
+        return vec![CoverageIssue::Unregistered];
+    };
+
+    let mut issues = Vec::new();
+    let has_reflect_data = match target {
+        CoverageTarget::Component => registration.data::<ReflectComponent>().is_some(),
+        CoverageTarget::Resource => registration.data::<ReflectResource>().is_some(),
+    };
+    if !has_reflect_data {
+        issues.push(CoverageIssue::MissingReflectData);
+    }
+    if registration.data::<ReflectFromReflect>().is_none()
+        && registration.data::<ReflectDefault>().is_none()
+        && registration.data::<ReflectFromWorld>().is_none()
+    {
+        issues.push(CoverageIssue::NotConstructible);
+    }
+
+    let mut visited = HashSet::default();
+    check_fields(registry, registration, &mut visited, &mut issues);
+    issues
+}
+
+/// Walks the fields of a type, reporting the field types that can't be (de)serialized.
+fn check_fields(
+    registry: &TypeRegistry,
+    registration: &TypeRegistration,
+    visited: &mut HashSet<TypeId>,
+    issues: &mut Vec<CoverageIssue>,
+) {
+    if !visited.insert(registration.type_id()) {
+        return;
+    }
+
+    let skipped = registration.data::<SerializationData>();
+    let is_skipped = |index: usize| skipped.is_some_and(|data| data.is_field_skipped(index));
+    let mut field_types = Vec::new();
+    match registration.type_info() {
+        TypeInfo::Struct(info) => field_types.extend(
+            info.iter()
+                .enumerate()
+                .filter(|(index, _)| !is_skipped(*index))
+                .map(|(_, field)| (field.type_id(), field.type_path())),
+        ),
+        TypeInfo::TupleStruct(info) => field_types.extend(
+            info.iter()
+                .enumerate()
+                .filter(|(index, _)| !is_skipped(*index))
+                .map(|(_, field)| (field.type_id(), field.type_path())),
+        ),
+        TypeInfo::Tuple(info) => field_types.extend(
+            info.iter()
+                .map(|field| (field.type_id(), field.type_path())),
+        ),
+        TypeInfo::List(info) => {
+            field_types.push((info.item_type_id(), info.item_type_path_table().path()));
+        }
+        TypeInfo::Array(info) => {
+            field_types.push((info.item_type_id(), info.item_type_path_table().path()));
+        }
+        TypeInfo::Map(info) => {
+            field_types.push((info.key_type_id(), info.key_type_path_table().path()));
+            field_types.push((info.value_type_id(), info.value_type_path_table().path()));
+        }
+        TypeInfo::Enum(info) => {
+            for variant in info.iter() {
+                match variant {
+                    VariantInfo::Struct(variant) => field_types.extend(
+                        variant
+                            .iter()
+                            .map(|field| (field.type_id(), field.type_path())),
+                    ),
+                    VariantInfo::Tuple(variant) => field_types.extend(
+                        variant
+                            .iter()
+                            .map(|field| (field.type_id(), field.type_path())),
+                    ),
+                    VariantInfo::Unit(_) => {}
+                }
+            }
+        }
+        TypeInfo::Value(info) => {
+            if registration.data::<ReflectSerialize>().is_none()
+                || registration.data::<ReflectDeserialize>().is_none()
+            {
+                issues.push(CoverageIssue::MissingSerde {
+                    type_path: info.type_path().to_string(),
+                });
+            }
+        }
+    }
+
+    for (type_id, type_path) in field_types {
+        match registry.get(type_id) {
+            Some(registration) => check_fields(registry, registration, visited, issues),
+            None => {
+                let issue = CoverageIssue::UnregisteredField {
+                    type_path: type_path.to_string(),
+                };
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+    }
+}
+
+/// The result of [`verify_reflection_coverage`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// The component and resource types with at least one issue, sorted by name.
+    pub entries: Vec<CoverageEntry>,
+}
+
+impl CoverageReport {
+    /// Returns `true` if no issue was found.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{} `{}`:", entry.target, entry.name)?;
+            for issue in &entry.issues {
+                writeln!(f, "  - {issue}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The issues of a single component or resource type in a [`CoverageReport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageEntry {
+    /// The name of the type.
+    pub name: String,
+    /// Whether the type is used as a component or as a resource.
+    pub target: CoverageTarget,
+    /// The issues found, in the order they were checked.
+    pub issues: Vec<CoverageIssue>,
+}
+
+/// How a type of a [`CoverageEntry`] is used in the world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CoverageTarget {
+    /// The type is used as a component.
+    Component,
+    /// The type is used as a resource.
+    Resource,
+}
+
+impl fmt::Display for CoverageTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoverageTarget::Component => f.write_str("component"),
+            CoverageTarget::Resource => f.write_str("resource"),
+        }
+    }
+}
+
+/// A reason why a type of a [`CoverageEntry`] can't be saved or loaded through reflection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoverageIssue {
+    /// The type is not registered in the [`TypeRegistry`].
+    Unregistered,
+    /// The type is registered without [`ReflectComponent`] or [`ReflectResource`] type data,
+    /// depending on how it is used.
+    MissingReflectData,
+    /// The type can't be created from reflected data, as it registers none of
+    /// [`ReflectFromReflect`], [`ReflectDefault`] and [`ReflectFromWorld`].
+    ///
+    /// This is usually the case of types opting out of `FromReflect`
+    /// because some of their ignored fields have no default value.
+    NotConstructible,
+    /// A field type, or the type itself, is a value type registered without
+    /// [`ReflectSerialize`] and [`ReflectDeserialize`] type data.
+    ///
+    /// Fields skipped with `#[reflect(skip_serializing)]` are not checked.
+    MissingSerde {
+        /// The type path of the value type.
+        type_path: String,
+    },
+    /// A field type is not registered in the [`TypeRegistry`].
+    UnregisteredField {
+        /// The type path of the field type.
+        type_path: String,
+    },
+}
+
+impl fmt::Display for CoverageIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoverageIssue::Unregistered => f.write_str("type is not registered"),
+            CoverageIssue::MissingReflectData => {
+                f.write_str("type is registered without `ReflectComponent` or `ReflectResource`")
+            }
+            CoverageIssue::NotConstructible => f.write_str(
+                "type registers none of `ReflectFromReflect`, `ReflectDefault` and `ReflectFromWorld`",
+            ),
+            CoverageIssue::MissingSerde { type_path } => {
+                write!(f, "value type `{type_path}` has no serde type data")
+            }
+            CoverageIssue::UnregisteredField { type_path } => {
+                write!(f, "field type `{type_path}` is not registered")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify_reflection_coverage, CoverageIssue, CoverageTarget};
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::reflect::{ReflectComponent, ReflectResource};
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    #[derive(Reflect, Clone, Default)]
+    #[reflect_value]
+    struct Opaque;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Covered {
+        name: String,
+        #[reflect(skip_serializing)]
+        cache: Opaque,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct WithOpaque(Vec<Opaque>);
+
+    #[derive(Component, Reflect)]
+    #[reflect(from_reflect = false)]
+    struct Bare {
+        #[reflect(ignore)]
+        _started: std::time::Instant,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Settings(u8);
+
+    #[derive(Resource)]
+    struct Runtime;
+
+    #[test]
+    fn reports_coverage_gaps() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Covered>();
+        registry.register::<WithOpaque>();
+        registry.register::<Bare>();
+        registry.register::<Settings>();
+
+        let mut world = World::new();
+        world.spawn((Covered::default(), WithOpaque(vec![Opaque])));
+        world.spawn(Bare {
+            _started: std::time::Instant::now(),
+        });
+        world.insert_resource(Settings(1));
+        world.insert_resource(Runtime);
+
+        let report = verify_reflection_coverage(&world, &registry);
+        let issues = |name: &str| {
+            &report
+                .entries
+                .iter()
+                .find(|entry| entry.name.ends_with(name))
+                .unwrap_or_else(|| panic!("no entry for {name}"))
+                .issues
+        };
+
+        assert_eq!(3, report.entries.len(), "{report}");
+        assert_eq!(
+            &vec![
+                CoverageIssue::MissingReflectData,
+                CoverageIssue::NotConstructible,
+            ],
+            issues("::Bare")
+        );
+        assert_eq!(
+            &vec![CoverageIssue::MissingSerde {
+                type_path: std::any::type_name::<Opaque>().to_string(),
+            }],
+            issues("::WithOpaque")
+        );
+        assert_eq!(&vec![CoverageIssue::Unregistered], issues("::Runtime"));
+        assert!(report
+            .entries
+            .iter()
+            .any(|entry| entry.target == CoverageTarget::Resource));
+    }
+}
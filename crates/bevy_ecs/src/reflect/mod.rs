@@ -10,17 +10,30 @@ use bevy_reflect::{Reflect, ReflectFromReflect, TypeRegistry, TypeRegistryArc};
 
 mod bundle;
 mod component;
+mod content_variants;
+mod coverage;
 mod entity_commands;
 mod from_world;
 mod map_entities;
+mod patch;
 mod resource;
+mod snapshot;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use content_variants::{
+    ActiveContentVariants, ContentExperiment, ContentExperiments, ContentPatch,
+    ContentVariantError, SelectContentVariant,
+};
+pub use coverage::{
+    verify_reflection_coverage, CoverageEntry, CoverageIssue, CoverageReport, CoverageTarget,
+};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::{ReflectMapEntities, ReflectMapEntitiesResource};
+pub use patch::ReflectPatchError;
 pub use resource::{ReflectResource, ReflectResourceFns};
+pub use snapshot::ReflectSnapshot;
 
 /// A [`Resource`] storing [`TypeRegistry`] for
 /// type registrations relevant to a whole app.
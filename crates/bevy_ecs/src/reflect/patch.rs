@@ -0,0 +1,216 @@
+use std::any::TypeId;
+
+use bevy_reflect::{ApplyError, Reflect};
+use thiserror::Error;
+
+use crate::{entity::Entity, world::World};
+
+use super::{AppTypeRegistry, ReflectComponent};
+
+/// An error returned by [`World::apply_reflect_patches`] for a patch that could not be applied.
+#[derive(Error, Debug)]
+pub enum ReflectPatchError {
+    /// The entity does not exist.
+    #[error("entity {0:?} does not exist")]
+    NoSuchEntity(Entity),
+    /// The component type has no [`ReflectComponent`] registered in the [`AppTypeRegistry`].
+    #[error("no `ReflectComponent` is registered for the component type {0:?}")]
+    UnregisteredComponent(TypeId),
+    /// The entity does not have the component being patched.
+    #[error("entity {entity:?} does not have the component `{type_path}`")]
+    MissingComponent {
+        /// The patched entity.
+        entity: Entity,
+        /// The type path of the patched component.
+        type_path: &'static str,
+    },
+    /// The patch could not be applied to the component.
+    #[error("failed to patch `{type_path}` on entity {entity:?}: {error}")]
+    Apply {
+        /// The patched entity.
+        entity: Entity,
+        /// The type path of the patched component.
+        type_path: &'static str,
+        /// The error returned when applying the patch.
+        error: ApplyError,
+    },
+}
+
+impl World {
+    /// Applies reflected patches to the components of many entities at once.
+    ///
+    /// Each patch is an entity, the [`TypeId`] of one of its components,
+    /// and a value that is [applied] to that component.
+    /// This is typically used to apply the component changes received by replication.
+    ///
+    /// Compared to applying each patch with [`ReflectComponent::apply`],
+    /// this looks up the [`ReflectComponent`] of each component type only once,
+    /// and applies the patches grouped by component type and in storage order,
+    /// which keeps the accessed memory close together.
+    /// Patches for the same entity and component are applied in the order they are given.
+    ///
+    /// Every patched component is marked as changed, as it would be by a mutable access.
+    ///
+    /// Patches that can't be applied are skipped, and do not prevent the others from being applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index and error of every patch that was skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AppTypeRegistry`] resource does not exist.
+    ///
+    /// [applied]: Reflect::try_apply
+    pub fn apply_reflect_patches(
+        &mut self,
+        patches: &[(Entity, TypeId, &dyn Reflect)],
+    ) -> Result<(), Vec<(usize, ReflectPatchError)>> {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let mut errors = Vec::new();
+
+        // Sort by component type, then by storage location
+        let mut order = Vec::with_capacity(patches.len());
+        for (index, &(entity, type_id, _)) in patches.iter().enumerate() {
+            match self.entities().get(entity) {
+                Some(location) => order.push((
+                    type_id,
+                    location.archetype_id,
+                    location.archetype_row.index(),
+                    index,
+                )),
+                None => errors.push((index, ReflectPatchError::NoSuchEntity(entity))),
+            }
+        }
+        order.sort_unstable();
+
+        for group in order.chunk_by(|a, b| a.0 == b.0) {
+            let type_id = group[0].0;
+            let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(type_id)
+            else {
+                errors.extend(group.iter().map(|&(.., index)| {
+                    (index, ReflectPatchError::UnregisteredComponent(type_id))
+                }));
+                continue;
+            };
+            let type_path = registry.get(type_id).map_or("<unknown>", |registration| {
+                registration.type_info().type_path()
+            });
+
+            for &(.., index) in group {
+                let (entity, _, patch) = patches[index];
+                let mut entity_mut = self
+                    .get_entity_mut(entity)
+                    .expect("entity locations were checked above");
+                let Some(mut component) = reflect_component.reflect_mut(&mut entity_mut) else {
+                    errors.push((
+                        index,
+                        ReflectPatchError::MissingComponent { entity, type_path },
+                    ));
+                    continue;
+                };
+                if let Err(error) = component.try_apply(patch) {
+                    errors.push((
+                        index,
+                        ReflectPatchError::Apply {
+                            entity,
+                            type_path,
+                            error,
+                        },
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort_unstable_by_key(|(index, _)| *index);
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_reflect::Reflect;
+
+    use super::ReflectPatchError;
+    use crate::{
+        self as bevy_ecs,
+        change_detection::DetectChanges,
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name(String);
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[test]
+    fn should_apply_patches_to_many_entities() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Name>();
+        }
+
+        let a = world.spawn((Health(10), Name("a".into()))).id();
+        let b = world.spawn(Health(20)).id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let untouched = world.spawn(Health(30)).id();
+        world.clear_trackers();
+
+        let result = world.apply_reflect_patches(&[
+            (b, TypeId::of::<Health>(), &Health(21)),
+            (a, TypeId::of::<Name>(), &Name("A".into())),
+            (a, TypeId::of::<Health>(), &Health(11)),
+            (b, TypeId::of::<Name>(), &Name("B".into())),
+            (missing, TypeId::of::<Health>(), &Health(0)),
+            (a, TypeId::of::<Unregistered>(), &Health(0)),
+            (a, TypeId::of::<Health>(), &Name("oops".into())),
+        ]);
+
+        assert_eq!(Some(&Health(11)), world.get::<Health>(a));
+        assert_eq!(Some(&Name("A".into())), world.get::<Name>(a));
+        assert_eq!(Some(&Health(21)), world.get::<Health>(b));
+        assert!(world.entity(a).get_ref::<Health>().unwrap().is_changed());
+        assert!(!world
+            .entity(untouched)
+            .get_ref::<Health>()
+            .unwrap()
+            .is_changed());
+
+        let errors = result.unwrap_err();
+        let indices: Vec<_> = errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(vec![3, 4, 5, 6], indices);
+        assert!(matches!(
+            errors[0].1,
+            ReflectPatchError::MissingComponent { entity, .. } if entity == b
+        ));
+        assert!(
+            matches!(errors[1].1, ReflectPatchError::NoSuchEntity(entity) if entity == missing)
+        );
+        assert!(matches!(
+            errors[2].1,
+            ReflectPatchError::UnregisteredComponent(type_id) if type_id == TypeId::of::<Unregistered>()
+        ));
+        assert!(matches!(errors[3].1, ReflectPatchError::Apply { .. }));
+    }
+}
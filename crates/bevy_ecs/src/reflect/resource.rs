@@ -11,7 +11,7 @@ use crate::{
 };
 use bevy_reflect::{FromReflect, FromType, Reflect, TypeRegistry};
 
-use super::from_reflect_with_fallback;
+use super::{from_reflect_with_fallback, ReflectSnapshot};
 
 /// A struct used to operate on reflected [`Resource`] of a type.
 ///
@@ -107,6 +107,23 @@ impl ReflectResource {
         (self.0.reflect)(world)
     }
 
+    /// Takes an owned [snapshot](ReflectSnapshot) of this [`Resource`] type from the world,
+    /// including its change ticks.
+    ///
+    /// Returns `None` if the resource does not exist.
+    pub fn snapshot(&self, world: &World) -> Option<ReflectSnapshot> {
+        let resource = self.reflect(world)?;
+        let component_id = world
+            .components()
+            .get_resource_id(resource.as_any().type_id())?;
+        let ticks = world.get_resource_change_ticks_by_id(component_id)?;
+        Some(ReflectSnapshot::new(
+            resource,
+            ticks,
+            world.read_change_tick(),
+        ))
+    }
+
     /// Gets the value of this [`Resource`] type from the world as a mutable reflected reference.
     pub fn reflect_mut<'a>(&self, world: &'a mut World) -> Option<Mut<'a, dyn Reflect>> {
         // SAFETY: unique world access
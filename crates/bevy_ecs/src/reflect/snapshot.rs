@@ -0,0 +1,129 @@
+use bevy_reflect::Reflect;
+
+use crate::component::{ComponentTicks, Tick};
+
+/// An owned copy of a reflected component or resource, along with its change ticks.
+///
+/// The value is copied with [`Reflect::clone_value`],
+/// so it is usually a dynamic type representing the original value.
+///
+/// Snapshots are taken with [`ReflectComponent::snapshot`] and [`ReflectResource::snapshot`],
+/// which only need shared access to the [`World`].
+/// This makes them suitable for external tooling, such as inspectors and profilers,
+/// which can take snapshots while holding a shared borrow of the world
+/// and then process them on another thread without ever observing a partially updated value.
+///
+/// All snapshots taken during the same shared borrow of the world have the same [`read_tick`],
+/// and are consistent with each other.
+///
+/// [`ReflectComponent::snapshot`]: super::ReflectComponent::snapshot
+/// [`ReflectResource::snapshot`]: super::ReflectResource::snapshot
+/// [`World`]: crate::world::World
+/// [`read_tick`]: Self::read_tick
+#[derive(Debug)]
+pub struct ReflectSnapshot {
+    value: Box<dyn Reflect>,
+    ticks: ComponentTicks,
+    read_tick: Tick,
+}
+
+impl ReflectSnapshot {
+    pub(super) fn new(value: &dyn Reflect, ticks: ComponentTicks, read_tick: Tick) -> Self {
+        Self {
+            value: value.clone_value(),
+            ticks,
+            read_tick,
+        }
+    }
+
+    /// Returns the copied value.
+    pub fn value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// Consumes the snapshot, returning the copied value.
+    pub fn into_value(self) -> Box<dyn Reflect> {
+        self.value
+    }
+
+    /// Returns the change ticks of the value at the time the snapshot was taken.
+    pub fn ticks(&self) -> ComponentTicks {
+        self.ticks
+    }
+
+    /// Returns the change tick of the world at the time the snapshot was taken.
+    pub fn read_tick(&self) -> Tick {
+        self.read_tick
+    }
+
+    /// Returns `true` if the value was added or changed after `last_read`,
+    /// the [`read_tick`](Self::read_tick) of a previous snapshot.
+    pub fn is_changed_since(&self, last_read: Tick) -> bool {
+        self.ticks.is_changed(last_read, self.read_tick)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::Reflect;
+
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        system::Resource,
+        world::World,
+    };
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn should_snapshot_components_and_resources() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Score>();
+        }
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let reflect_component = registry
+            .get_type_data::<ReflectComponent>(std::any::TypeId::of::<Health>())
+            .unwrap();
+        let reflect_resource = registry
+            .get_type_data::<ReflectResource>(std::any::TypeId::of::<Score>())
+            .unwrap();
+
+        let entity = world.spawn(Health(10)).id();
+        assert!(reflect_resource.snapshot(&world).is_none());
+        world.insert_resource(Score(0));
+
+        let health = reflect_component.snapshot(&world, entity).unwrap();
+        let score = reflect_resource.snapshot(&world).unwrap();
+        assert_eq!(health.read_tick(), score.read_tick());
+        assert_eq!(Some(true), health.value().reflect_partial_eq(&Health(10)));
+
+        world.increment_change_tick();
+        world.get_mut::<Health>(entity).unwrap().0 = 5;
+
+        let new_health = reflect_component.snapshot(&world, entity).unwrap();
+        let new_score = reflect_resource.snapshot(&world).unwrap();
+        assert!(new_health.is_changed_since(health.read_tick()));
+        assert!(!new_score.is_changed_since(score.read_tick()));
+        assert_eq!(Some(true), health.value().reflect_partial_eq(&Health(10)));
+        assert_eq!(
+            Some(true),
+            new_health.value().reflect_partial_eq(&Health(5))
+        );
+
+        world.despawn(entity);
+        assert!(reflect_component.snapshot(&world, entity).is_none());
+    }
+}
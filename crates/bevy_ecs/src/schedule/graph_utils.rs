@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
 use bevy_utils::{HashMap, HashSet};
 use fixedbitset::FixedBitSet;
 use petgraph::{algo::TarjanScc, graphmap::NodeTrait, prelude::*};
@@ -10,6 +12,7 @@ use crate::schedule::set::*;
 ///
 /// [`ScheduleGraph`]: super::ScheduleGraph
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub enum NodeId {
     /// Identifier for a system.
     System(usize),
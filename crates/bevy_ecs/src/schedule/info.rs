@@ -0,0 +1,210 @@
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+use petgraph::Direction;
+
+use crate::schedule::{BoxedCondition, NodeId, Schedule, ScheduleGraph};
+
+/// A snapshot of the systems, system sets, and ordering constraints of a [`Schedule`].
+///
+/// Unlike the [`ScheduleGraph`] it is created from, this only contains owned, plain data,
+/// so that it can be inspected through reflection and serialized by external tools
+/// (such as profilers and editors) without compile-time access to the systems themselves.
+///
+/// Systems and sets are identified by their [`NodeId`] in the schedule.
+///
+/// This is returned by [`Schedule::info`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct ScheduleInfo {
+    /// The name of the schedule's label.
+    pub label: String,
+    /// The systems in the schedule, ordered by their [`NodeId`].
+    pub systems: Vec<SystemInfo>,
+    /// The system sets in the schedule, ordered by their [`NodeId`].
+    pub sets: Vec<SystemSetInfo>,
+    /// The ordering constraints between systems and sets in the schedule.
+    pub dependencies: Vec<DependencyInfo>,
+}
+
+/// Information about a system in a [`ScheduleInfo`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct SystemInfo {
+    /// The id of the system in the schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+    /// The sets the system is a direct member of.
+    pub sets: Vec<NodeId>,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+}
+
+/// Information about a system set in a [`ScheduleInfo`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct SystemSetInfo {
+    /// The id of the set in the schedule.
+    pub id: NodeId,
+    /// The debug name of the set.
+    pub name: String,
+    /// Whether this is the set automatically created for a system's type.
+    pub is_system_type: bool,
+    /// Whether this is an anonymous set created when configuring a group of systems.
+    pub is_anonymous: bool,
+    /// The sets this set is a direct member of.
+    pub sets: Vec<NodeId>,
+    /// The names of the run conditions of the set.
+    pub conditions: Vec<String>,
+}
+
+/// An ordering constraint between two systems or sets in a [`ScheduleInfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct DependencyInfo {
+    /// The system or set that has to run first.
+    pub before: NodeId,
+    /// The system or set that has to run after [`before`](Self::before).
+    pub after: NodeId,
+}
+
+impl Schedule {
+    /// Returns a [`ScheduleInfo`] describing the systems, sets, and ordering constraints
+    /// that have been added to this schedule.
+    ///
+    /// Constraints that are only computed when the schedule is built,
+    /// such as automatically inserted `apply_deferred` systems, are not included.
+    pub fn info(&self) -> ScheduleInfo {
+        self.graph().info(format!("{:?}", self.label()))
+    }
+}
+
+impl ScheduleGraph {
+    fn info(&self, label: String) -> ScheduleInfo {
+        let parents = |id: NodeId| {
+            let mut parents = self
+                .hierarchy()
+                .graph()
+                .neighbors_directed(id, Direction::Incoming)
+                .collect::<Vec<_>>();
+            parents.sort();
+            parents
+        };
+        let condition_names = |conditions: &[BoxedCondition]| {
+            conditions
+                .iter()
+                .map(|condition| condition.name().into_owned())
+                .collect()
+        };
+
+        let systems = self
+            .systems()
+            .map(|(id, system, conditions)| SystemInfo {
+                id,
+                name: system.name().into_owned(),
+                sets: parents(id),
+                conditions: condition_names(conditions),
+            })
+            .collect();
+
+        let mut sets = self
+            .system_sets()
+            .map(|(id, set, conditions)| SystemSetInfo {
+                id,
+                name: format!("{set:?}"),
+                is_system_type: set.system_type().is_some(),
+                is_anonymous: set.is_anonymous(),
+                sets: parents(id),
+                conditions: condition_names(conditions),
+            })
+            .collect::<Vec<_>>();
+        sets.sort_by_key(|set| set.id);
+
+        let mut dependencies = self
+            .dependency()
+            .graph()
+            .all_edges()
+            .map(|(before, after, ())| DependencyInfo { before, after })
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|dependency| (dependency.before, dependency.after));
+
+        ScheduleInfo {
+            label,
+            systems,
+            sets,
+            dependencies,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_ecs, prelude::*, schedule::ScheduleLabel};
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Update;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    fn step() {}
+    fn sync() {}
+    fn is_running() -> bool {
+        true
+    }
+
+    #[test]
+    fn schedule_info() {
+        let mut schedule = Schedule::new(Update);
+        schedule.configure_sets(Physics.run_if(is_running));
+        schedule.add_systems((step.in_set(Physics), sync.after(step)));
+
+        let info = schedule.info();
+        assert_eq!("Update", info.label);
+
+        let [step_info, sync_info] = &info.systems[..] else {
+            panic!("expected two systems, got {:?}", info.systems);
+        };
+        assert!(step_info.name.ends_with("step"));
+        assert!(sync_info.name.ends_with("sync"));
+
+        let physics = info.sets.iter().find(|set| set.name == "Physics").unwrap();
+        assert!(!physics.is_system_type && !physics.is_anonymous);
+        assert_eq!(1, physics.conditions.len());
+        assert!(physics.conditions[0].ends_with("is_running"));
+        assert!(step_info.sets.contains(&physics.id));
+        assert!(!sync_info.sets.contains(&physics.id));
+
+        // `sync.after(step)` orders `sync` after the set created for the type of `step`
+        let step_type = info
+            .sets
+            .iter()
+            .find(|set| set.is_system_type && step_info.sets.contains(&set.id))
+            .unwrap();
+        assert_eq!(
+            vec![DependencyInfo {
+                before: step_type.id,
+                after: sync_info.id,
+            }],
+            info.dependencies
+        );
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn schedule_info_is_reflected() {
+        use bevy_reflect::GetPath;
+
+        let mut schedule = Schedule::new(Update);
+        schedule.add_systems(step);
+
+        let info = schedule.info();
+        let name = info.path::<String>("systems[0].name").unwrap();
+        assert!(name.ends_with("step"));
+        assert_eq!(
+            &NodeId::System(0),
+            info.path::<NodeId>("systems[0].id").unwrap()
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! The input binding functionality.
+//!
+//! Games with rebindable controls usually refer to their inputs through a set of
+//! actions (such as `Jump` or `Attack`) instead of hardcoding keys and buttons.
+//! The [`InputBindings`] resource maps these actions to the inputs that trigger them.
+//!
+//! Both the actions and the bindings are plain reflected data,
+//! so the bindings can be serialized through the type registry,
+//! and edited at runtime using reflection paths such as `bindings[0].inputs[1]`.
+
+use crate::{
+    gamepad::{GamepadButton, GamepadButtonType},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    ButtonInput,
+};
+use bevy_ecs::system::Resource;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use thiserror::Error;
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A physical input that can be bound to an action in [`InputBindings`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputBinding {
+    /// A key on the keyboard.
+    Keyboard(KeyCode),
+    /// A button on the mouse.
+    Mouse(MouseButton),
+    /// A button on any connected gamepad.
+    Gamepad(GamepadButtonType),
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        Self::Keyboard(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl From<GamepadButtonType> for InputBinding {
+    fn from(button: GamepadButtonType) -> Self {
+        Self::Gamepad(button)
+    }
+}
+
+/// The inputs bound to a single action in [`InputBindings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct ActionBinding<A> {
+    /// The action triggered by the inputs.
+    pub action: A,
+    /// The inputs that trigger the action.
+    pub inputs: Vec<InputBinding>,
+}
+
+/// A resource mapping the actions of type `A` to the inputs that trigger them.
+///
+/// Actions are usually declared as a reflected enum:
+///
+/// ```
+/// # use bevy_input::{bindings::{InputBinding, InputBindings}, keyboard::KeyCode, mouse::MouseButton};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+/// enum Action {
+///     Jump,
+///     Attack,
+/// }
+///
+/// let mut bindings = InputBindings::default();
+/// bindings
+///     .bind(Action::Jump, KeyCode::Space)
+///     .bind(Action::Attack, MouseButton::Left);
+///
+/// assert_eq!(
+///     &[InputBinding::Keyboard(KeyCode::Space)],
+///     bindings.inputs(&Action::Jump)
+/// );
+/// assert!(bindings.validate().is_ok());
+/// ```
+///
+/// Registering `InputBindings<A>` in the type registry allows the bindings
+/// to be saved and loaded with the reflection serializers.
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Default))]
+pub struct InputBindings<A: Clone + Eq + Send + Sync + 'static> {
+    /// The bindings of each action, in the order they were first bound.
+    pub bindings: Vec<ActionBinding<A>>,
+}
+
+impl<A: Clone + Eq + Send + Sync + 'static> Default for InputBindings<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<A: Clone + Eq + Send + Sync + 'static> InputBindings<A> {
+    /// Binds `input` to `action`, in addition to any inputs already bound to it.
+    ///
+    /// Binding the same input to an action twice has no effect.
+    pub fn bind(&mut self, action: A, input: impl Into<InputBinding>) -> &mut Self {
+        let input = input.into();
+        match self.bindings.iter_mut().find(|b| b.action == action) {
+            Some(binding) => {
+                if !binding.inputs.contains(&input) {
+                    binding.inputs.push(input);
+                }
+            }
+            None => self.bindings.push(ActionBinding {
+                action,
+                inputs: vec![input],
+            }),
+        }
+        self
+    }
+
+    /// Removes `input` from the inputs bound to `action`.
+    ///
+    /// Returns `true` if the input was bound to the action.
+    pub fn unbind(&mut self, action: &A, input: impl Into<InputBinding>) -> bool {
+        let input = input.into();
+        let Some(binding) = self.bindings.iter_mut().find(|b| b.action == *action) else {
+            return false;
+        };
+        let len = binding.inputs.len();
+        binding.inputs.retain(|bound| *bound != input);
+        binding.inputs.len() != len
+    }
+
+    /// Removes all inputs bound to `action`.
+    pub fn clear_action(&mut self, action: &A) {
+        self.bindings.retain(|binding| binding.action != *action);
+    }
+
+    /// Returns the inputs bound to `action`.
+    pub fn inputs(&self, action: &A) -> &[InputBinding] {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == *action)
+            .map_or(&[], |binding| &binding.inputs)
+    }
+
+    /// Returns an iterator over the actions `input` is bound to.
+    pub fn actions(&self, input: impl Into<InputBinding>) -> impl Iterator<Item = &A> {
+        let input = input.into();
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.inputs.contains(&input))
+            .map(|binding| &binding.action)
+    }
+
+    /// Returns `true` if any input bound to `action` is pressed.
+    pub fn pressed(
+        &self,
+        action: &A,
+        keys: &ButtonInput<KeyCode>,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        gamepad_buttons: &ButtonInput<GamepadButton>,
+    ) -> bool {
+        self.inputs(action).iter().any(|input| match input {
+            InputBinding::Keyboard(key) => keys.pressed(*key),
+            InputBinding::Mouse(button) => mouse_buttons.pressed(*button),
+            InputBinding::Gamepad(button_type) => gamepad_buttons
+                .get_pressed()
+                .any(|button| button.button_type == *button_type),
+        })
+    }
+
+    /// Checks that no input is bound more than once.
+    ///
+    /// Bindings edited through reflection are not deduplicated the way [`bind`](Self::bind) does,
+    /// so this should be called after loading or editing them.
+    pub fn validate(&self) -> Result<(), DuplicateBindingError<A>> {
+        let bound = self
+            .bindings
+            .iter()
+            .flat_map(|binding| binding.inputs.iter().map(|input| (*input, &binding.action)))
+            .collect::<Vec<_>>();
+        for (index, (input, _)) in bound.iter().enumerate() {
+            if bound[index + 1..].iter().any(|(other, _)| other == input) {
+                return Err(DuplicateBindingError {
+                    input: *input,
+                    actions: bound
+                        .iter()
+                        .filter(|(other, _)| other == input)
+                        .map(|(_, action)| (*action).clone())
+                        .collect(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error returned by [`InputBindings::validate`] when an input is bound more than once.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("`{input:?}` is bound more than once, to the actions {actions:?}")]
+pub struct DuplicateBindingError<A> {
+    /// The input that is bound more than once.
+    pub input: InputBinding,
+    /// The actions the input is bound to, once for each binding.
+    pub actions: Vec<A>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+    enum Action {
+        Jump,
+        Attack,
+    }
+
+    #[test]
+    fn bind_and_unbind() {
+        let mut bindings = InputBindings::default();
+        bindings
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Jump, GamepadButtonType::South)
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Attack, MouseButton::Left);
+
+        assert_eq!(
+            &[
+                InputBinding::Keyboard(KeyCode::Space),
+                InputBinding::Gamepad(GamepadButtonType::South)
+            ],
+            bindings.inputs(&Action::Jump)
+        );
+        assert_eq!(
+            vec![&Action::Attack],
+            bindings.actions(MouseButton::Left).collect::<Vec<_>>()
+        );
+
+        assert!(bindings.unbind(&Action::Jump, KeyCode::Space));
+        assert!(!bindings.unbind(&Action::Jump, KeyCode::Space));
+        bindings.clear_action(&Action::Attack);
+        assert!(bindings.inputs(&Action::Attack).is_empty());
+    }
+
+    #[test]
+    fn pressed() {
+        let mut bindings = InputBindings::default();
+        bindings.bind(Action::Jump, KeyCode::Space);
+
+        let mut keys = ButtonInput::default();
+        let mouse_buttons = ButtonInput::default();
+        let gamepad_buttons = ButtonInput::default();
+        assert!(!bindings.pressed(&Action::Jump, &keys, &mouse_buttons, &gamepad_buttons));
+
+        keys.press(KeyCode::Space);
+        assert!(bindings.pressed(&Action::Jump, &keys, &mouse_buttons, &gamepad_buttons));
+        assert!(!bindings.pressed(&Action::Attack, &keys, &mouse_buttons, &gamepad_buttons));
+    }
+
+    #[test]
+    fn validate_detects_duplicates() {
+        let mut bindings = InputBindings::default();
+        bindings
+            .bind(Action::Jump, KeyCode::Space)
+            .bind(Action::Attack, KeyCode::KeyF);
+        assert!(bindings.validate().is_ok());
+
+        bindings.bindings[1].inputs.push(KeyCode::Space.into());
+        assert_eq!(
+            Err(DuplicateBindingError {
+                input: KeyCode::Space.into(),
+                actions: vec![Action::Jump, Action::Attack],
+            }),
+            bindings.validate()
+        );
+
+        bindings.bindings[1].inputs[1] = KeyCode::KeyF.into();
+        assert_eq!(
+            Err(DuplicateBindingError {
+                input: KeyCode::KeyF.into(),
+                actions: vec![Action::Attack, Action::Attack],
+            }),
+            bindings.validate()
+        );
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn edit_through_reflection() {
+        use bevy_reflect::GetPath;
+
+        let mut bindings = InputBindings::default();
+        bindings.bind(Action::Jump, KeyCode::Space);
+
+        *bindings
+            .path_mut::<InputBinding>("bindings[0].inputs[0]")
+            .unwrap() = MouseButton::Right.into();
+        assert_eq!(
+            &[InputBinding::Mouse(MouseButton::Right)],
+            bindings.inputs(&Action::Jump)
+        );
+    }
+}
@@ -12,6 +12,7 @@
 //! `bevy` currently supports keyboard, mouse, gamepad, and touch inputs.
 
 mod axis;
+pub mod bindings;
 mod button_input;
 /// Common run conditions
 pub mod common_conditions;
@@ -28,6 +29,7 @@ pub use button_input::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        bindings::{InputBinding, InputBindings},
         gamepad::{
             Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
         },
@@ -132,6 +134,7 @@ impl Plugin for InputPlugin {
         {
             // Register common types
             app.register_type::<ButtonState>()
+                .register_type::<bindings::InputBinding>()
                 .register_type::<KeyboardInput>()
                 .register_type::<MouseButtonInput>()
                 .register_type::<PinchGesture>()
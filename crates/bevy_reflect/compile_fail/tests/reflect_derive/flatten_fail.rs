@@ -0,0 +1,16 @@
+//@error-in-other-file: a field is defined more than once in this struct and the structs flattened into it
+use bevy_reflect::Reflect;
+
+#[derive(Reflect)]
+struct Settings {
+    enabled: bool,
+}
+
+#[derive(Reflect)]
+struct Config {
+    enabled: bool,
+    #[reflect(flatten)]
+    settings: Settings,
+}
+
+fn main() {}
@@ -2,7 +2,10 @@ use core::fmt;
 use proc_macro2::Span;
 
 use crate::container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs};
-use crate::field_attributes::FieldAttributes;
+use crate::field_attributes::{
+    FieldAttributes, ReflectIgnoreBehavior, ALIAS_ATTR, CLONE_WITH_ATTR, FLATTEN_ATTR,
+    IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR, READ_ONLY_ATTR, REBUILD_WITH_ATTR, RENAME_ATTR,
+};
 use crate::type_path::parse_path_no_leading_colon;
 use crate::utility::{StringExpr, WhereClauseOptions};
 use quote::{quote, ToTokens};
@@ -87,6 +90,7 @@ pub(crate) struct ReflectStruct<'a> {
 /// ```
 pub(crate) struct ReflectEnum<'a> {
     meta: ReflectMeta<'a>,
+    serialization_data: Option<SerializationDataDef>,
     variants: Vec<EnumVariant<'a>>,
 }
 
@@ -118,10 +122,8 @@ pub(crate) struct EnumVariant<'a> {
     /// The fields within this variant.
     pub fields: EnumVariantFields<'a>,
     /// The reflection-based attributes on the variant.
-    #[allow(dead_code)]
     pub attrs: FieldAttributes,
     /// The index of this variant within the enum.
-    #[allow(dead_code)]
     pub index: usize,
     /// The documentation for this variant, if any
     #[cfg(feature = "documentation")]
@@ -318,6 +320,7 @@ impl<'a> ReflectDerive<'a> {
         return match &input.data {
             Data::Struct(data) => {
                 let fields = Self::collect_struct_fields(&data.fields)?;
+                Self::validate_flattened_fields(&data.fields, &fields)?;
                 let reflect_struct = ReflectStruct {
                     meta,
                     serialization_data: SerializationDataDef::new(&fields)?,
@@ -333,7 +336,11 @@ impl<'a> ReflectDerive<'a> {
             Data::Enum(data) => {
                 let variants = Self::collect_enum_variants(&data.variants)?;
 
-                let reflect_enum = ReflectEnum { meta, variants };
+                let reflect_enum = ReflectEnum {
+                    meta,
+                    serialization_data: SerializationDataDef::from_variants(&variants)?,
+                    variants,
+                };
                 Ok(Self::Enum(reflect_enum))
             }
             Data::Union(..) => Err(syn::Error::new(
@@ -362,6 +369,48 @@ impl<'a> ReflectDerive<'a> {
                 |(declaration_index, field)| -> Result<StructField, syn::Error> {
                     let attrs = FieldAttributes::parse_attributes(&field.attrs)?;
 
+                    if attrs.read_only && attrs.ignore.is_ignored() {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format_args!(
+                                "`{READ_ONLY_ATTR}` cannot be used on fields marked with `{IGNORE_ALL_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    if attrs.flatten
+                        && (attrs.read_only || attrs.ignore != ReflectIgnoreBehavior::None)
+                    {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format_args!(
+                                "`{FLATTEN_ATTR}` cannot be used on fields marked with `{READ_ONLY_ATTR}`, `{IGNORE_ALL_ATTR}` or `{IGNORE_SERIALIZATION_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    if (attrs.clone_with.is_some() || attrs.rebuild_with.is_some())
+                        && !attrs.ignore.is_ignored()
+                    {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format_args!(
+                                "`{CLONE_WITH_ATTR}` and `{REBUILD_WITH_ATTR}` can only be used on fields marked with `{IGNORE_ALL_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    if (attrs.rename.is_some() || !attrs.aliases.is_empty())
+                        && (field.ident.is_none() || attrs.flatten)
+                    {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format_args!(
+                                "`{RENAME_ATTR}` and `{ALIAS_ATTR}` are only supported on named fields that are not marked with `{FLATTEN_ATTR}`"
+                            ),
+                        ));
+                    }
+
                     let reflection_index = if attrs.ignore.is_ignored() {
                         None
                     } else {
@@ -384,7 +433,69 @@ impl<'a> ReflectDerive<'a> {
                 utility::ResultSifter::fold,
             );
 
-        sifter.finish()
+        let fields = sifter.finish()?;
+        Self::validate_reflected_names(
+            fields
+                .iter()
+                .filter(|field| field.attrs.ignore.is_active() && field.data.ident.is_some())
+                .map(|field| (field.reflected_name(), &field.attrs, field.data.span())),
+        )?;
+        Ok(fields)
+    }
+
+    /// Checks that no two fields or variants share a name or alias once renames are applied.
+    fn validate_reflected_names<'b>(
+        items: impl Iterator<Item = (String, &'b FieldAttributes, Span)>,
+    ) -> Result<(), syn::Error> {
+        let mut names = Vec::new();
+        for (name, attrs, span) in items {
+            let aliases = attrs.aliases.iter().map(LitStr::value);
+            for name in std::iter::once(name).chain(aliases) {
+                if names.contains(&name) {
+                    return Err(syn::Error::new(
+                        span,
+                        format_args!("the reflected name `{name}` is already in use"),
+                    ));
+                }
+                names.push(name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the uses of `#[reflect(flatten)]` that can be detected at derive time.
+    ///
+    /// Collisions between the names of flattened fields and other fields are checked
+    /// by a const assertion in the generated `Typed` impl, since the derive can't see the flattened fields.
+    fn validate_flattened_fields(
+        data: &Fields,
+        fields: &[StructField<'a>],
+    ) -> Result<(), syn::Error> {
+        let mut flattened = fields.iter().filter(|field| field.attrs.flatten);
+        let Some(first) = flattened.next() else {
+            return Ok(());
+        };
+
+        if !matches!(data, Fields::Named(..)) {
+            return Err(syn::Error::new_spanned(
+                first.data,
+                format_args!("`{FLATTEN_ATTR}` is only supported on named fields"),
+            ));
+        }
+
+        let mut types = vec![first.data.ty.to_token_stream().to_string()];
+        for field in flattened {
+            let ty = field.data.ty.to_token_stream().to_string();
+            if types.contains(&ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.data.ty,
+                    "this type is already flattened into this struct, so its fields would collide",
+                ));
+            }
+            types.push(ty);
+        }
+
+        Ok(())
     }
 
     fn collect_enum_variants(
@@ -396,6 +507,31 @@ impl<'a> ReflectDerive<'a> {
             .map(|(index, variant)| -> Result<EnumVariant, syn::Error> {
                 let fields = Self::collect_struct_fields(&variant.fields)?;
 
+                if let Some(field) = fields.iter().find(|field| field.attrs.read_only) {
+                    return Err(syn::Error::new_spanned(
+                        field.data,
+                        format_args!("`{READ_ONLY_ATTR}` is not supported on enum variant fields"),
+                    ));
+                }
+
+                if let Some(field) = fields.iter().find(|field| {
+                    field.attrs.clone_with.is_some() || field.attrs.rebuild_with.is_some()
+                }) {
+                    return Err(syn::Error::new_spanned(
+                        field.data,
+                        format_args!(
+                            "`{CLONE_WITH_ATTR}` and `{REBUILD_WITH_ATTR}` are not supported on enum variant fields"
+                        ),
+                    ));
+                }
+
+                if let Some(field) = fields.iter().find(|field| field.attrs.flatten) {
+                    return Err(syn::Error::new_spanned(
+                        field.data,
+                        format_args!("`{FLATTEN_ATTR}` is not supported on enum variant fields"),
+                    ));
+                }
+
                 let fields = match variant.fields {
                     Fields::Named(..) => EnumVariantFields::Named(fields),
                     Fields::Unnamed(..) => EnumVariantFields::Unnamed(fields),
@@ -415,7 +551,15 @@ impl<'a> ReflectDerive<'a> {
                 utility::ResultSifter::fold,
             );
 
-        sifter.finish()
+        let variants = sifter.finish()?;
+        Self::validate_reflected_names(variants.iter().map(|variant| {
+            (
+                variant.reflected_name(),
+                &variant.attrs,
+                variant.data.span(),
+            )
+        }))?;
+        Ok(variants)
     }
 }
 
@@ -480,13 +624,69 @@ impl<'a> ReflectMeta<'a> {
     pub fn doc(&self) -> &crate::documentation::Documentation {
         &self.docs
     }
+
+    /// Generates a `.with_generics(...)` call describing the type and const parameters of this type.
+    ///
+    /// Returns `None` if the type has no such parameters, or if its type parameters
+    /// are not required to implement `TypePath`.
+    pub fn generics_to_info_tokens(&self) -> Option<proc_macro2::TokenStream> {
+        let generics = self.type_path.generics();
+        let bevy_reflect_path = &self.bevy_reflect_path;
+
+        if generics.type_params().next().is_some() && !self.type_path_attrs().should_auto_derive() {
+            return None;
+        }
+
+        let params = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    let name = ident.to_string();
+                    Some(quote! {
+                        #bevy_reflect_path::TypeParamInfo::new::<#ident>(#name)
+                    })
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    let ty = &param.ty;
+                    let name = ident.to_string();
+                    Some(quote! {
+                        #bevy_reflect_path::ConstParamInfo::new::<#ty>(#name, #ident)
+                    })
+                }
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if params.is_empty() {
+            return None;
+        }
+
+        Some(quote! {
+            .with_generics(#bevy_reflect_path::Generics::new()#(.with(#params))*)
+        })
+    }
 }
 
 impl<'a> StructField<'a> {
+    /// The name of this field as seen by the reflection API.
+    ///
+    /// This is the field's identifier unless it is renamed with `#[reflect(rename = "...")]`.
+    /// Unnamed fields use their declaration index.
+    pub fn reflected_name(&self) -> String {
+        match (&self.attrs.rename, &self.data.ident) {
+            (Some(rename), _) => rename.value(),
+            (None, Some(ident)) => ident.to_string(),
+            (None, None) => self.declaration_index.to_string(),
+        }
+    }
+
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
     pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
         let name = match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(),
+            Some(_) => self.reflected_name().to_token_stream(),
             None => self.reflection_index.to_token_stream(),
         };
 
@@ -503,11 +703,23 @@ impl<'a> StructField<'a> {
         let ty = &self.data.ty;
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        if self.attrs.read_only {
+            info.extend(quote! {
+                .with_read_only(true)
+            });
+        }
+
+        if !self.attrs.aliases.is_empty() {
+            let aliases = &self.attrs.aliases;
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -572,6 +784,11 @@ impl<'a> ReflectStruct<'a> {
         &self.fields
     }
 
+    /// Returns `true` if any field of this struct is marked with `#[reflect(flatten)]`.
+    pub fn has_flattened_fields(&self) -> bool {
+        self.fields.iter().any(|field| field.attrs.flatten)
+    }
+
     pub fn where_clause_options(&self) -> WhereClauseOptions {
         WhereClauseOptions::new_with_fields(self.meta(), self.active_types().into_boxed_slice())
     }
@@ -592,22 +809,50 @@ impl<'a> ReflectStruct<'a> {
             )
         };
 
-        let field_infos = self
-            .active_fields()
-            .map(|field| field.to_info_tokens(bevy_reflect_path));
-
         let custom_attributes = self
             .meta
             .attrs
             .custom_attributes()
             .to_tokens(bevy_reflect_path);
 
+        let field_infos = if self.has_flattened_fields() {
+            // The fields of flattened structs are only known once their type info is available
+            let extend_fields = self.active_fields().map(|field| {
+                if field.attrs.flatten {
+                    let ty = &field.data.ty;
+                    quote! {
+                        #bevy_reflect_path::__macro_exports::extend_flattened_fields::<#ty>(
+                            <Self as #bevy_reflect_path::TypePath>::type_path(),
+                            &mut fields,
+                        );
+                    }
+                } else {
+                    let info = field.to_info_tokens(bevy_reflect_path);
+                    quote!(fields.push(#info);)
+                }
+            });
+            quote! {
+                &{
+                    let () = #bevy_reflect_path::__macro_exports::UniqueFieldNames::<Self>::ASSERT;
+                    let mut fields = ::std::vec::Vec::new();
+                    #(#extend_fields)*
+                    fields
+                }
+            }
+        } else {
+            let field_infos = self
+                .active_fields()
+                .map(|field| field.to_info_tokens(bevy_reflect_path));
+            quote!(&[#(#field_infos),*])
+        };
+
+        let generics = self.meta().generics_to_info_tokens();
+
         #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
-            #bevy_reflect_path::#info_struct::new::<Self>(&[
-                #(#field_infos),*
-            ])
+            #bevy_reflect_path::#info_struct::new::<Self>(#field_infos)
             .with_custom_attributes(#custom_attributes)
+            #generics
         };
 
         #[cfg(feature = "documentation")]
@@ -669,7 +914,7 @@ impl<'a> ReflectEnum<'a> {
         crate::registration::impl_get_type_registration(
             self.meta(),
             where_clause_options,
-            None,
+            self.serialization_data.as_ref(),
             Some(self.active_fields().map(|field| &field.data.ty)),
         )
     }
@@ -689,12 +934,15 @@ impl<'a> ReflectEnum<'a> {
             .custom_attributes()
             .to_tokens(bevy_reflect_path);
 
+        let generics = self.meta().generics_to_info_tokens();
+
         #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
             #bevy_reflect_path::EnumInfo::new::<Self>(&[
                 #(#variants),*
             ])
             .with_custom_attributes(#custom_attributes)
+            #generics
         };
 
         #[cfg(feature = "documentation")]
@@ -712,6 +960,16 @@ impl<'a> ReflectEnum<'a> {
 }
 
 impl<'a> EnumVariant<'a> {
+    /// The name of this variant as seen by the reflection API.
+    ///
+    /// This is the variant's identifier unless it is renamed with `#[reflect(rename = "...")]`.
+    pub fn reflected_name(&self) -> String {
+        match &self.attrs.rename {
+            Some(rename) => rename.value(),
+            None => self.data.ident.to_string(),
+        }
+    }
+
     /// Get an iterator of fields which are exposed to the reflection API
     pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.fields()
@@ -730,7 +988,7 @@ impl<'a> EnumVariant<'a> {
 
     /// Generates a `TokenStream` for `VariantInfo` construction.
     pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
-        let variant_name = &self.data.ident.to_string();
+        let variant_name = &self.reflected_name();
 
         let (info_variant, info_struct) = match &self.fields {
             EnumVariantFields::Unit => (
@@ -760,12 +1018,18 @@ impl<'a> EnumVariant<'a> {
 
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
             #bevy_reflect_path::#info_struct::new(#args)
                 .with_custom_attributes(#custom_attributes)
         };
 
+        if !self.attrs.aliases.is_empty() {
+            let aliases = &self.attrs.aliases;
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
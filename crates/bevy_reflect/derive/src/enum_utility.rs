@@ -43,8 +43,8 @@ pub(crate) trait VariantBuilder: Sized {
     /// * `field`: The field to access
     fn access_field(&self, this: &Ident, field: VariantField) -> TokenStream {
         match &field.field.data.ident {
-            Some(field_ident) => {
-                let name = field_ident.to_string();
+            Some(_) => {
+                let name = field.field.reflected_name();
                 quote!(#this.field(#name))
             }
             None => {
@@ -132,7 +132,7 @@ pub(crate) trait VariantBuilder: Sized {
 
         for variant in variants {
             let variant_ident = &variant.data.ident;
-            let variant_name = variant_ident.to_string();
+            let variant_name = variant.reflected_name();
             let variant_path = self.reflect_enum().get_unit(variant_ident);
 
             let fields = variant.fields();
@@ -252,15 +252,26 @@ impl<'a> VariantBuilder for TryApplyVariantBuilder<'a> {
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
         let alias = field.alias;
         let field_ty = &field.field.data.ty;
+        let field_path = match &field.field.data.ident {
+            Some(_) => field.field.reflected_name(),
+            None => field
+                .field
+                .reflection_index
+                .unwrap_or(field.field.declaration_index)
+                .to_string(),
+        };
 
         quote! {
             <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)
-                .ok_or(#bevy_reflect_path::ApplyError::MismatchedTypes {
-                    from_type: ::core::convert::Into::into(
-                        #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
-                    ),
-                    to_type: ::core::convert::Into::into(<#field_ty as #bevy_reflect_path::TypePath>::type_path())
-                })?
+                .ok_or_else(|| #bevy_reflect_path::ApplyError::with_field(
+                    #bevy_reflect_path::ApplyError::MismatchedTypes {
+                        from_type: ::core::convert::Into::into(
+                            #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
+                        ),
+                        to_type: ::core::convert::Into::into(<#field_ty as #bevy_reflect_path::TypePath>::type_path())
+                    },
+                    #field_path,
+                ))?
         }
     }
 }
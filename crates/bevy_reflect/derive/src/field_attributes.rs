@@ -14,6 +14,12 @@ mod kw {
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
+    syn::custom_keyword!(read_only);
+    syn::custom_keyword!(flatten);
+    syn::custom_keyword!(rename);
+    syn::custom_keyword!(alias);
+    syn::custom_keyword!(clone_with);
+    syn::custom_keyword!(rebuild_with);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -21,6 +27,16 @@ pub(crate) const IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) const DEFAULT_ATTR: &str = "default";
 
+pub(crate) const READ_ONLY_ATTR: &str = "read_only";
+
+pub(crate) const FLATTEN_ATTR: &str = "flatten";
+
+pub(crate) const RENAME_ATTR: &str = "rename";
+pub(crate) const ALIAS_ATTR: &str = "alias";
+
+pub(crate) const CLONE_WITH_ATTR: &str = "clone_with";
+pub(crate) const REBUILD_WITH_ATTR: &str = "rebuild_with";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -74,6 +90,18 @@ pub(crate) struct FieldAttributes {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Whether this field can only be read, and not mutated, via reflection.
+    pub read_only: bool,
+    /// Whether the fields of this field's struct are merged into the fields of the parent struct.
+    pub flatten: bool,
+    /// The name this field or variant is reflected under, if different from its identifier.
+    pub rename: Option<LitStr>,
+    /// Alternative names accepted for this field or variant, such as names it previously had.
+    pub aliases: Vec<LitStr>,
+    /// A function used to clone this ignored field from a concrete source value in `FromReflect`.
+    pub clone_with: Option<syn::ExprPath>,
+    /// A function used to rebuild this ignored field from the rest of its container in `FromReflect`.
+    pub rebuild_with: Option<syn::ExprPath>,
     /// Custom attributes created via `#[reflect(@...)]`.
     pub custom_attributes: CustomAttributes,
 }
@@ -119,6 +147,18 @@ impl FieldAttributes {
             self.parse_skip_serializing(input)
         } else if lookahead.peek(kw::default) {
             self.parse_default(input)
+        } else if lookahead.peek(kw::read_only) {
+            self.parse_read_only(input)
+        } else if lookahead.peek(kw::flatten) {
+            self.parse_flatten(input)
+        } else if lookahead.peek(kw::rename) {
+            self.parse_rename(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
+        } else if lookahead.peek(kw::clone_with) {
+            self.parse_clone_with(input)
+        } else if lookahead.peek(kw::rebuild_with) {
+            self.parse_rebuild_with(input)
         } else {
             Err(lookahead.error())
         }
@@ -182,6 +222,90 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `read_only` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(read_only)]`
+    fn parse_read_only(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.read_only {
+            return Err(input.error(format!("only one of {:?} is allowed", [READ_ONLY_ATTR])));
+        }
+
+        input.parse::<kw::read_only>()?;
+        self.read_only = true;
+        Ok(())
+    }
+
+    /// Parse `flatten` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(flatten)]`
+    fn parse_flatten(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.flatten {
+            return Err(input.error(format!("only one of {:?} is allowed", [FLATTEN_ATTR])));
+        }
+
+        input.parse::<kw::flatten>()?;
+        self.flatten = true;
+        Ok(())
+    }
+
+    /// Parse `rename` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(rename = "new_name")]`
+    fn parse_rename(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.rename.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [RENAME_ATTR])));
+        }
+
+        input.parse::<kw::rename>()?;
+        input.parse::<Token![=]>()?;
+        self.rename = Some(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(alias = "old_name")]`
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+        self.aliases.push(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `clone_with` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(ignore, clone_with = "path::to::func")]`
+    fn parse_clone_with(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.clone_with.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [CLONE_WITH_ATTR])));
+        }
+
+        input.parse::<kw::clone_with>()?;
+        input.parse::<Token![=]>()?;
+        self.clone_with = Some(input.parse::<LitStr>()?.parse()?);
+        Ok(())
+    }
+
+    /// Parse `rebuild_with` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(ignore, rebuild_with = "path::to::func")]`
+    fn parse_rebuild_with(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.rebuild_with.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [REBUILD_WITH_ATTR])));
+        }
+
+        input.parse::<kw::rebuild_with>()?;
+        input.parse::<Token![=]>()?;
+        self.rebuild_with = Some(input.parse::<LitStr>()?.parse()?);
+        Ok(())
+    }
+
     /// Parse `@` (custom attribute) attribute.
     ///
     /// Examples:
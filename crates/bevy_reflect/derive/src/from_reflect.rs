@@ -1,5 +1,5 @@
 use crate::container_attributes::REFLECT_DEFAULT;
-use crate::derive_data::ReflectEnum;
+use crate::derive_data::{ReflectEnum, StructField};
 use crate::enum_utility::{EnumVariantOutputData, FromReflectVariantBuilder, VariantBuilder};
 use crate::field_attributes::DefaultBehavior;
 use crate::utility::{ident_or_index, WhereClauseOptions};
@@ -7,7 +7,7 @@ use crate::{ReflectMeta, ReflectStruct};
 use bevy_macro_utils::fq_std::{FQAny, FQClone, FQDefault, FQOption};
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{Field, Ident, Lit, LitInt, LitStr, Member};
+use syn::{Ident, Lit, LitInt, LitStr, Member};
 
 /// Implements `FromReflect` for the given struct
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
@@ -100,6 +100,8 @@ fn impl_struct_internal(
     let MemberValuePair(active_members, active_values) =
         get_active_fields(reflect_struct, &ref_struct, &ref_struct_type, is_tuple);
 
+    let ignored_hooks = get_ignored_field_hooks(reflect_struct);
+
     let is_defaultable = reflect_struct.meta().attrs().contains(REFLECT_DEFAULT);
     let constructor = if is_defaultable {
         quote!(
@@ -110,9 +112,10 @@ fn impl_struct_internal(
                     __this.#active_members = __field;
                 }
             )*
+            #(#ignored_hooks)*
             #FQOption::Some(__this)
         )
-    } else {
+    } else if ignored_hooks.is_empty() {
         let MemberValuePair(ignored_members, ignored_values) = get_ignored_fields(reflect_struct);
 
         quote!(
@@ -123,6 +126,17 @@ fn impl_struct_internal(
                 }
             )
         )
+    } else {
+        let MemberValuePair(ignored_members, ignored_values) = get_ignored_fields(reflect_struct);
+
+        quote!(
+            let mut __this = Self {
+                #(#active_members: #active_values()?,)*
+                #(#ignored_members: #ignored_values,)*
+            };
+            #(#ignored_hooks)*
+            #FQOption::Some(__this)
+        )
     };
 
     let (impl_generics, ty_generics, where_clause) = reflect_struct
@@ -171,6 +185,44 @@ fn get_ignored_fields(reflect_struct: &ReflectStruct) -> MemberValuePair {
     )
 }
 
+/// Get the statements that reconstruct ignored fields marked with `clone_with` or `rebuild_with`.
+///
+/// These run once `__this` has been fully constructed, overwriting the ignored field's default.
+/// A `clone_with` function is only used when the reflected value is a concrete instance of the
+/// struct, in which case its field is cloned directly.
+/// Otherwise, the `rebuild_with` function (if any) rebuilds the field from the rest of `__this`.
+fn get_ignored_field_hooks(reflect_struct: &ReflectStruct) -> Vec<proc_macro2::TokenStream> {
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+
+    reflect_struct
+        .ignored_fields()
+        .filter_map(|field| {
+            let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+            let downcast = quote! {
+                <dyn #bevy_reflect_path::Reflect>::downcast_ref::<Self>(reflect)
+            };
+
+            match (&field.attrs.clone_with, &field.attrs.rebuild_with) {
+                (Some(clone_with), Some(rebuild_with)) => Some(quote! {
+                    __this.#member = match #downcast {
+                        #FQOption::Some(__source) => #clone_with(&__source.#member),
+                        #FQOption::None => #rebuild_with(&__this),
+                    };
+                }),
+                (Some(clone_with), None) => Some(quote! {
+                    if let #FQOption::Some(__source) = #downcast {
+                        __this.#member = #clone_with(&__source.#member);
+                    }
+                }),
+                (None, Some(rebuild_with)) => Some(quote! {
+                    __this.#member = #rebuild_with(&__this);
+                }),
+                (None, None) => None,
+            }
+        })
+        .collect()
+}
+
 /// Get the collection of active field definitions.
 ///
 /// Each value of the `MemberValuePair` is a token stream that generates a
@@ -189,12 +241,34 @@ fn get_active_fields(
             .map(|field| {
                 let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
                 let accessor = get_field_accessor(
-                    field.data,
+                    field,
                     field.reflection_index.expect("field should be active"),
                     is_tuple,
                 );
                 let ty = field.data.ty.clone();
 
+                // Flattened structs take their fields from the outer struct itself
+                if field.attrs.flatten {
+                    let value = match &field.attrs.default {
+                        DefaultBehavior::Func(path) => quote! {
+                            (|| #FQOption::Some(
+                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(reflect)
+                                    .unwrap_or_else(#path)
+                            ))
+                        },
+                        DefaultBehavior::Default => quote! {
+                            (|| #FQOption::Some(
+                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(reflect)
+                                    .unwrap_or_default()
+                            ))
+                        },
+                        DefaultBehavior::Required => quote! {
+                            (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(reflect))
+                        },
+                    };
+                    return (member, value);
+                }
+
                 let get_field = quote! {
                     #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor)
                 };
@@ -233,14 +307,12 @@ fn get_active_fields(
 ///
 /// This differs from a member in that it needs to be a number for tuple structs
 /// and a string for standard structs.
-fn get_field_accessor(field: &Field, index: usize, is_tuple: bool) -> Lit {
+fn get_field_accessor(field: &StructField, index: usize, is_tuple: bool) -> Lit {
     if is_tuple {
         Lit::Int(LitInt::new(&index.to_string(), Span::call_site()))
+    } else if field.data.ident.is_some() {
+        Lit::Str(LitStr::new(&field.reflected_name(), Span::call_site()))
     } else {
-        field
-            .ident
-            .as_ref()
-            .map(|ident| Lit::Str(LitStr::new(&ident.to_string(), Span::call_site())))
-            .unwrap_or_else(|| Lit::Str(LitStr::new(&index.to_string(), Span::call_site())))
+        Lit::Str(LitStr::new(&index.to_string(), Span::call_site()))
     }
 }
@@ -0,0 +1,188 @@
+use crate::container_attributes::REFLECT_DEFAULT;
+use crate::derive_data::{ReflectEnum, StructField};
+use crate::field_attributes::DefaultBehavior;
+use crate::utility::ident_or_index;
+use crate::ReflectStruct;
+use bevy_macro_utils::fq_std::{FQBox, FQDefault, FQResult};
+use quote::{format_ident, quote};
+use syn::Path;
+
+/// Returns the implementation of `Reflect::reflect_clone` for a struct or tuple struct.
+///
+/// If `Clone` was registered, the type's own implementation is used.
+/// Otherwise, a new instance is built by cloning each field individually.
+///
+/// Like `FromReflect`, types that register `Default` are cloned by assigning each field
+/// onto a default instance, since their fields may not be accessible through a struct literal
+/// (as is the case for types reflected with `impl_reflect!`).
+pub(crate) fn get_struct_clone_impl(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+
+    if let Some(clone_fn) = reflect_struct
+        .meta()
+        .attrs()
+        .get_clone_impl(bevy_reflect_path)
+    {
+        return clone_fn;
+    }
+
+    let mut members = Vec::new();
+    let mut values = Vec::new();
+
+    for field in reflect_struct.fields() {
+        let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+        let value = quote!(&self.#member);
+
+        let value = if field.attrs.ignore.is_active() {
+            clone_active_field(field, &value, bevy_reflect_path)
+        } else if let Some(value) = clone_ignored_field(field, &value) {
+            value
+        } else {
+            let field_name = field.reflected_name();
+            let error = field_not_cloneable(&field_name, bevy_reflect_path);
+            return quote! {
+                fn reflect_clone(&self) -> #FQResult<#FQBox<dyn #bevy_reflect_path::Reflect>, #bevy_reflect_path::ReflectCloneError> {
+                    #FQResult::Err(#error)
+                }
+            };
+        };
+
+        members.push(member);
+        values.push(value);
+    }
+
+    let is_defaultable = reflect_struct.meta().attrs().contains(REFLECT_DEFAULT);
+    let constructor = if is_defaultable {
+        quote! {
+            let mut __this: Self = #FQDefault::default();
+            #(__this.#members = #values;)*
+            __this
+        }
+    } else {
+        quote! {
+            Self {
+                #(#members: #values,)*
+            }
+        }
+    };
+
+    quote! {
+        fn reflect_clone(&self) -> #FQResult<#FQBox<dyn #bevy_reflect_path::Reflect>, #bevy_reflect_path::ReflectCloneError> {
+            #FQResult::Ok(#FQBox::new({ #constructor }))
+        }
+    }
+}
+
+/// Returns the implementation of `Reflect::reflect_clone` for an enum.
+///
+/// If `Clone` was registered, the type's own implementation is used.
+/// Otherwise, the current variant is rebuilt by cloning each of its fields individually.
+pub(crate) fn get_enum_clone_impl(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+
+    if let Some(clone_fn) = reflect_enum
+        .meta()
+        .attrs()
+        .get_clone_impl(bevy_reflect_path)
+    {
+        return clone_fn;
+    }
+
+    if reflect_enum.variants().is_empty() {
+        return quote! {
+            fn reflect_clone(&self) -> #FQResult<#FQBox<dyn #bevy_reflect_path::Reflect>, #bevy_reflect_path::ReflectCloneError> {
+                match *self {}
+            }
+        };
+    }
+
+    let arms = reflect_enum.variants().iter().map(|variant| {
+        let ident = &variant.data.ident;
+
+        let mut bound_members = Vec::new();
+        let mut bindings = Vec::new();
+        let mut members = Vec::new();
+        let mut values = Vec::new();
+
+        for field in variant.fields() {
+            let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+
+            let value = if field.attrs.ignore.is_active() {
+                let binding = format_ident!("__field_{}", field.declaration_index);
+                let value = clone_active_field(field, &quote!(#binding), bevy_reflect_path);
+                bound_members.push(member.clone());
+                bindings.push(binding);
+                value
+            } else if let Some(value) = clone_ignored_field(field, &quote!()) {
+                value
+            } else {
+                let field_name = format!("{}::{}", variant.reflected_name(), field.reflected_name());
+                let error = field_not_cloneable(&field_name, bevy_reflect_path);
+                return quote! {
+                    Self::#ident { .. } => #FQResult::Err(#error)
+                };
+            };
+
+            members.push(member);
+            values.push(value);
+        }
+
+        quote! {
+            Self::#ident { #(#bound_members: #bindings,)* .. } => #FQResult::Ok(#FQBox::new(Self::#ident {
+                #(#members: #values,)*
+            }))
+        }
+    });
+
+    quote! {
+        fn reflect_clone(&self) -> #FQResult<#FQBox<dyn #bevy_reflect_path::Reflect>, #bevy_reflect_path::ReflectCloneError> {
+            match self {
+                #(#arms,)*
+            }
+        }
+    }
+}
+
+/// Clones an active field, given a reference to its value, by calling `reflect_clone` on it.
+fn clone_active_field(
+    field: &StructField,
+    value: &proc_macro2::TokenStream,
+    bevy_reflect_path: &Path,
+) -> proc_macro2::TokenStream {
+    let ty = &field.data.ty;
+    quote! {
+        <dyn #bevy_reflect_path::Reflect>::reflect_clone_and_take::<#ty>(#value)?
+    }
+}
+
+/// Clones an ignored field, given a reference to its value.
+///
+/// Ignored fields are cloned with their `clone_with` function if they have one,
+/// or else fall back to their default value if they have a `default` attribute.
+/// Returns `None` if neither is available.
+fn clone_ignored_field(
+    field: &StructField,
+    value: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    if let Some(clone_with) = &field.attrs.clone_with {
+        return Some(quote!(#clone_with(#value)));
+    }
+
+    match &field.attrs.default {
+        DefaultBehavior::Func(path) => Some(quote!(#path())),
+        DefaultBehavior::Default => Some(quote!(#FQDefault::default())),
+        DefaultBehavior::Required => None,
+    }
+}
+
+/// The error returned when a field can't be cloned.
+fn field_not_cloneable(field_name: &str, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
+    quote! {
+        #bevy_reflect_path::ReflectCloneError::FieldNotCloneable {
+            container_type_path: ::core::convert::Into::into(
+                #bevy_reflect_path::DynamicTypePath::reflect_type_path(self)
+            ),
+            field_name: ::core::convert::Into::into(#field_name),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::derive_data::{EnumVariantFields, ReflectEnum, StructField};
 use crate::enum_utility::{EnumVariantOutputData, TryApplyVariantBuilder, VariantBuilder};
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{get_enum_clone_impl, impl_type_path, impl_typed};
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQOption, FQResult};
 use proc_macro2::{Ident, Span};
 use quote::quote;
@@ -45,6 +45,11 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
         });
     let debug_fn = reflect_enum.meta().attrs().get_debug_impl();
+    let clone_fn = get_enum_clone_impl(reflect_enum);
+    let partial_ord_fn = reflect_enum
+        .meta()
+        .attrs()
+        .get_partial_ord_impl(bevy_reflect_path);
     let partial_eq_fn = reflect_enum
         .meta()
         .attrs()
@@ -209,6 +214,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                #bevy_reflect_path::__macro_exports::stats::record_clone();
                 #FQBox::new(#bevy_reflect_path::Enum::clone_dynamic(self))
             }
 
@@ -220,6 +226,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn try_apply(&mut self, #ref_value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError>  {
+                #bevy_reflect_path::__macro_exports::stats::record_apply();
                 if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
                     if #bevy_reflect_path::Enum::variant_name(self) == #bevy_reflect_path::Enum::variant_name(#ref_value) {
                         // Same variant -> just update fields
@@ -228,14 +235,16 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
                                 for field in #bevy_reflect_path::Enum::iter_fields(#ref_value) {
                                     let name = field.name().unwrap();
                                     if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_mut(self, name) {
-                                       #bevy_reflect_path::Reflect::try_apply(v, field.value())?;
+                                        #bevy_reflect_path::Reflect::try_apply(v, field.value())
+                                            .map_err(|err| #bevy_reflect_path::ApplyError::with_field(err, name))?;
                                     }
                                 }
                             }
                             #bevy_reflect_path::VariantType::Tuple => {
                                 for (index, field) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Enum::iter_fields(#ref_value)) {
                                     if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_at_mut(self, index) {
-                                        #bevy_reflect_path::Reflect::try_apply(v, field.value())?;
+                                        #bevy_reflect_path::Reflect::try_apply(v, field.value())
+                                            .map_err(|err| #bevy_reflect_path::ApplyError::with_field(err, index))?;
                                     }
                                 }
                             }
@@ -284,10 +293,14 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
                 #bevy_reflect_path::ReflectOwned::Enum(self)
             }
 
+            #clone_fn
+
             #hash_fn
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
@@ -318,7 +331,7 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
 
     for (variant_index, variant) in reflect_enum.variants().iter().enumerate() {
         let ident = &variant.data.ident;
-        let name = ident.to_string();
+        let name = variant.reflected_name();
         let unit = reflect_enum.get_unit(ident);
 
         let variant_type_ident = match variant.data.fields {
@@ -383,7 +396,7 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             EnumVariantFields::Named(fields) => {
                 let field_len = process_fields(fields, |field: &StructField| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = field.reflected_name();
                     let reflection_index = field
                         .reflection_index
                         .expect("reflection index should exist for active field");
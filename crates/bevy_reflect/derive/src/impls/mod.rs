@@ -1,3 +1,4 @@
+mod clone;
 mod enums;
 #[cfg(feature = "functions")]
 mod func;
@@ -6,6 +7,7 @@ mod tuple_structs;
 mod typed;
 mod values;
 
+pub(crate) use clone::{get_enum_clone_impl, get_struct_clone_impl};
 pub(crate) use enums::impl_enum;
 #[cfg(feature = "functions")]
 pub(crate) use func::impl_function_traits;
@@ -1,8 +1,10 @@
-use crate::impls::{impl_type_path, impl_typed};
+use crate::derive_data::StructField;
+use crate::impls::{get_struct_clone_impl, impl_type_path, impl_typed};
 use crate::utility::ident_or_index;
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
 use quote::{quote, ToTokens};
+use syn::{parse_quote, Type, TypePath};
 
 /// Implements `Struct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
@@ -13,14 +15,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
     let field_names = reflect_struct
         .active_fields()
-        .map(|field| {
-            field
-                .data
-                .ident
-                .as_ref()
-                .map(ToString::to_string)
-                .unwrap_or_else(|| field.declaration_index.to_string())
-        })
+        .map(StructField::reflected_name)
         .collect::<Vec<String>>();
     let field_idents = reflect_struct
         .active_fields()
@@ -29,11 +24,89 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
+    // Read-only fields are not accessible mutably
+    let mutable_fields = reflect_struct
+        .active_fields()
+        .enumerate()
+        .filter(|(_, field)| !field.attrs.read_only)
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let mutable_field_names = mutable_fields
+        .iter()
+        .map(|&index| &field_names[index])
+        .collect::<Vec<_>>();
+    let mutable_field_idents = mutable_fields
+        .iter()
+        .map(|&index| &field_idents[index])
+        .collect::<Vec<_>>();
+
+    let struct_methods = if reflect_struct.has_flattened_fields() {
+        impl_flattened_struct_methods(reflect_struct)
+    } else {
+        quote! {
+            fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
+                match name {
+                    #(#field_names => #fqoption::Some(&self.#field_idents),)*
+                    _ => #FQOption::None,
+                }
+            }
+
+            fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
+                match name {
+                    #(#mutable_field_names => #fqoption::Some(&mut self.#mutable_field_idents),)*
+                    _ => #FQOption::None,
+                }
+            }
+
+            fn field_at(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
+                match index {
+                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
+                    _ => #FQOption::None,
+                }
+            }
+
+            fn field_at_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
+                match index {
+                    #(#mutable_fields => #fqoption::Some(&mut self.#mutable_field_idents),)*
+                    _ => #FQOption::None,
+                }
+            }
+
+            fn name_at(&self, index: usize) -> #FQOption<&str> {
+                match index {
+                    #(#field_indices => #fqoption::Some(#field_names),)*
+                    _ => #FQOption::None,
+                }
+            }
+
+            fn field_len(&self) -> usize {
+                #field_count
+            }
+
+            fn iter_fields(&self) -> #bevy_reflect_path::FieldIter {
+                #bevy_reflect_path::FieldIter::new(self)
+            }
+
+            fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
+                #bevy_reflect_path::__macro_exports::stats::record_dynamic_allocation();
+                let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
+                dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
+                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
+                dynamic
+            }
+        }
+    };
+
     let hash_fn = reflect_struct
         .meta()
         .attrs()
         .get_hash_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().attrs().get_debug_impl();
+    let clone_fn = get_struct_clone_impl(reflect_struct);
+    let partial_ord_fn = reflect_struct
+        .meta()
+        .attrs()
+        .get_partial_ord_impl(bevy_reflect_path);
     let partial_eq_fn = reflect_struct.meta()
         .attrs()
         .get_partial_eq_impl(bevy_reflect_path)
@@ -70,9 +143,13 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
 
+    let flattened_fields_impl = impl_flattened_fields(reflect_struct);
+
     quote! {
         #get_type_registration_impl
 
+        #flattened_fields_impl
+
         #typed_impl
 
         #type_path_impl
@@ -80,55 +157,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         #function_impls
 
         impl #impl_generics #bevy_reflect_path::Struct for #struct_path #ty_generics #where_reflect_clause {
-            fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                match name {
-                    #(#field_names => #fqoption::Some(&self.#field_idents),)*
-                    _ => #FQOption::None,
-                }
-            }
-
-            fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                match name {
-                    #(#field_names => #fqoption::Some(&mut self.#field_idents),)*
-                    _ => #FQOption::None,
-                }
-            }
-
-            fn field_at(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                match index {
-                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
-                    _ => #FQOption::None,
-                }
-            }
-
-            fn field_at_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
-                    _ => #FQOption::None,
-                }
-            }
-
-            fn name_at(&self, index: usize) -> #FQOption<&str> {
-                match index {
-                    #(#field_indices => #fqoption::Some(#field_names),)*
-                    _ => #FQOption::None,
-                }
-            }
-
-            fn field_len(&self) -> usize {
-                #field_count
-            }
-
-            fn iter_fields(&self) -> #bevy_reflect_path::FieldIter {
-                #bevy_reflect_path::FieldIter::new(self)
-            }
-
-            fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
-                let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
-                dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
-                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
-                dynamic
-            }
+            #struct_methods
         }
 
         impl #impl_generics #bevy_reflect_path::Reflect for #struct_path #ty_generics #where_reflect_clause {
@@ -169,6 +198,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
             #[inline]
             fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                #bevy_reflect_path::__macro_exports::stats::record_clone();
                 #FQBox::new(#bevy_reflect_path::Struct::clone_dynamic(self))
             }
 
@@ -180,11 +210,21 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
             #[inline]
             fn try_apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                #bevy_reflect_path::__macro_exports::stats::record_apply();
                 if let #bevy_reflect_path::ReflectRef::Struct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
                     for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Struct::iter_fields(struct_value)) {
                         let name = #bevy_reflect_path::Struct::name_at(struct_value, i).unwrap();
                         if let #FQOption::Some(v) = #bevy_reflect_path::Struct::field_mut(self, name) {
-                           #bevy_reflect_path::Reflect::try_apply(v, value)?;
+                           #bevy_reflect_path::Reflect::try_apply(v, value)
+                               .map_err(|err| #bevy_reflect_path::ApplyError::with_field(err, name))?;
+                        } else if let #FQOption::Some(v) = #bevy_reflect_path::Struct::field(self, name) {
+                            // Read-only fields may only be applied with their current value.
+                            // Fields which can't be compared are left untouched.
+                            if #bevy_reflect_path::Reflect::reflect_partial_eq(v, value) == #FQOption::Some(false) {
+                                return #FQResult::Err(#bevy_reflect_path::ApplyError::ReadOnlyField {
+                                    field_name: ::core::convert::Into::into(name),
+                                });
+                            }
                         }
                     }
                 } else {
@@ -214,11 +254,236 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
                 #bevy_reflect_path::ReflectOwned::Struct(self)
             }
 
+            #clone_fn
+
             #hash_fn
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
 }
+
+/// Implements `FlattenedFields`, which lists the names of the fields of the struct for the
+/// compile-time collision check of `#[reflect(flatten)]`.
+///
+/// Flattened fields whose type is a type parameter can't be listed, since the parameter isn't
+/// bound by `FlattenedFields`, so they are left out of the check.
+fn impl_flattened_fields(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let struct_path = reflect_struct.meta().type_path();
+    let generics = struct_path.generics();
+
+    let is_type_param = |ty: &Type| match ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .get_ident()
+            .is_some_and(|ident| generics.type_params().any(|param| param.ident == *ident)),
+        _ => false,
+    };
+
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote!(where));
+    let mut fields = Vec::new();
+    for field in reflect_struct.active_fields() {
+        if !field.attrs.flatten {
+            let name = field.reflected_name();
+            fields.push(quote!(#bevy_reflect_path::__macro_exports::FlattenedField::Named(#name)));
+        } else if !is_type_param(&field.data.ty) {
+            let ty = &field.data.ty;
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: #bevy_reflect_path::__macro_exports::FlattenedFields));
+            fields.push(quote! {
+                #bevy_reflect_path::__macro_exports::FlattenedField::Flattened(
+                    <#ty as #bevy_reflect_path::__macro_exports::FlattenedFields>::FIELDS
+                )
+            });
+        }
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    // Generic structs are checked when their `Typed` impl is instantiated
+    let assert_unique = (reflect_struct.has_flattened_fields() && generics.params.is_empty())
+        .then(|| {
+            quote! {
+                const _: () = #bevy_reflect_path::__macro_exports::UniqueFieldNames::<#struct_path>::ASSERT;
+            }
+        });
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::__macro_exports::FlattenedFields for #struct_path #ty_generics #where_clause {
+            const FIELDS: &'static [#bevy_reflect_path::__macro_exports::FlattenedField] = &[#(#fields),*];
+        }
+
+        #assert_unique
+    }
+}
+
+/// Implements the methods of `Struct` for a struct with fields marked with `#[reflect(flatten)]`.
+///
+/// The fields of flattened structs are merged into the fields of the outer struct, in declaration order.
+/// Since the number of flattened fields is only known at runtime,
+/// indexed access walks over the fields until the index falls within one of them.
+fn impl_flattened_struct_methods(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let fqoption = FQOption.into_token_stream();
+
+    let mut direct_names = Vec::new();
+    let mut direct_idents = Vec::new();
+    let mut mutable_names = Vec::new();
+    let mut mutable_idents = Vec::new();
+    let mut flattened_idents = Vec::new();
+
+    let mut field_at = Vec::new();
+    let mut field_at_mut = Vec::new();
+    let mut name_at = Vec::new();
+    let mut clone_fields = Vec::new();
+
+    for field in reflect_struct.active_fields() {
+        let ident = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+
+        if field.attrs.flatten {
+            field_at.push(quote! {
+                let len = #bevy_reflect_path::Struct::field_len(&self.#ident);
+                if index < len {
+                    return #bevy_reflect_path::Struct::field_at(&self.#ident, index);
+                }
+                index -= len;
+            });
+            field_at_mut.push(quote! {
+                let len = #bevy_reflect_path::Struct::field_len(&self.#ident);
+                if index < len {
+                    return #bevy_reflect_path::Struct::field_at_mut(&mut self.#ident, index);
+                }
+                index -= len;
+            });
+            name_at.push(quote! {
+                let len = #bevy_reflect_path::Struct::field_len(&self.#ident);
+                if index < len {
+                    return #bevy_reflect_path::Struct::name_at(&self.#ident, index);
+                }
+                index -= len;
+            });
+            clone_fields.push(quote! {
+                for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Struct::iter_fields(&self.#ident)) {
+                    let name = #bevy_reflect_path::Struct::name_at(&self.#ident, i).unwrap();
+                    dynamic.insert_boxed(name, #bevy_reflect_path::Reflect::clone_value(value));
+                }
+            });
+            flattened_idents.push(ident);
+            continue;
+        }
+
+        let name = field.reflected_name();
+
+        field_at.push(quote! {
+            if index == 0 {
+                return #fqoption::Some(&self.#ident);
+            }
+            index -= 1;
+        });
+        field_at_mut.push(if field.attrs.read_only {
+            quote! {
+                if index == 0 {
+                    return #FQOption::None;
+                }
+                index -= 1;
+            }
+        } else {
+            quote! {
+                if index == 0 {
+                    return #fqoption::Some(&mut self.#ident);
+                }
+                index -= 1;
+            }
+        });
+        name_at.push(quote! {
+            if index == 0 {
+                return #fqoption::Some(#name);
+            }
+            index -= 1;
+        });
+        clone_fields.push(quote! {
+            dynamic.insert_boxed(#name, #bevy_reflect_path::Reflect::clone_value(&self.#ident));
+        });
+
+        if !field.attrs.read_only {
+            mutable_names.push(name.clone());
+            mutable_idents.push(ident.clone());
+        }
+        direct_names.push(name);
+        direct_idents.push(ident);
+    }
+
+    let direct_count = direct_idents.len();
+
+    quote! {
+        fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
+            match name {
+                #(#direct_names => #fqoption::Some(&self.#direct_idents),)*
+                _ => {
+                    #(
+                        if let #fqoption::Some(field) = #bevy_reflect_path::Struct::field(&self.#flattened_idents, name) {
+                            return #fqoption::Some(field);
+                        }
+                    )*
+                    #FQOption::None
+                }
+            }
+        }
+
+        fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
+            match name {
+                #(#mutable_names => #fqoption::Some(&mut self.#mutable_idents),)*
+                _ => {
+                    #(
+                        if let #fqoption::Some(field) = #bevy_reflect_path::Struct::field_mut(&mut self.#flattened_idents, name) {
+                            return #fqoption::Some(field);
+                        }
+                    )*
+                    #FQOption::None
+                }
+            }
+        }
+
+        #[allow(unused_assignments)]
+        fn field_at(&self, mut index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
+            #(#field_at)*
+            #FQOption::None
+        }
+
+        #[allow(unused_assignments)]
+        fn field_at_mut(&mut self, mut index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
+            #(#field_at_mut)*
+            #FQOption::None
+        }
+
+        #[allow(unused_assignments)]
+        fn name_at(&self, mut index: usize) -> #FQOption<&str> {
+            #(#name_at)*
+            #FQOption::None
+        }
+
+        fn field_len(&self) -> usize {
+            #direct_count #(+ #bevy_reflect_path::Struct::field_len(&self.#flattened_idents))*
+        }
+
+        fn iter_fields(&self) -> #bevy_reflect_path::FieldIter {
+            #bevy_reflect_path::FieldIter::new(self)
+        }
+
+        fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
+            #bevy_reflect_path::__macro_exports::stats::record_dynamic_allocation();
+            let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
+            dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
+            #(#clone_fields)*
+            dynamic
+        }
+    }
+}
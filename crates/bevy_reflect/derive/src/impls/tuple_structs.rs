@@ -1,4 +1,4 @@
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{get_struct_clone_impl, impl_type_path, impl_typed};
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
 use quote::{quote, ToTokens};
@@ -18,6 +18,18 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
+    // Read-only fields are not accessible mutably
+    let mutable_fields = reflect_struct
+        .active_fields()
+        .enumerate()
+        .filter(|(_, field)| !field.attrs.read_only)
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let mutable_field_idents = mutable_fields
+        .iter()
+        .map(|&index| &field_idents[index])
+        .collect::<Vec<_>>();
+
     let where_clause_options = reflect_struct.where_clause_options();
     let get_type_registration_impl = reflect_struct.get_type_registration(&where_clause_options);
 
@@ -26,6 +38,11 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
         .attrs()
         .get_hash_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().attrs().get_debug_impl();
+    let clone_fn = get_struct_clone_impl(reflect_struct);
+    let partial_ord_fn = reflect_struct
+        .meta()
+        .attrs()
+        .get_partial_ord_impl(bevy_reflect_path);
     let partial_eq_fn = reflect_struct
         .meta()
         .attrs()
@@ -79,7 +96,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
             fn field_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#mutable_fields => #fqoption::Some(&mut self.#mutable_field_idents),)*
                     _ => #FQOption::None,
                 }
             }
@@ -93,6 +110,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
             }
 
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicTupleStruct {
+                #bevy_reflect_path::__macro_exports::stats::record_dynamic_allocation();
                 let mut dynamic: #bevy_reflect_path::DynamicTupleStruct = #FQDefault::default();
                 dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
                 #(dynamic.insert_boxed(#bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
@@ -138,6 +156,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
             #[inline]
             fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                #bevy_reflect_path::__macro_exports::stats::record_clone();
                 #FQBox::new(#bevy_reflect_path::TupleStruct::clone_dynamic(self))
             }
 
@@ -149,10 +168,20 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
             #[inline]
             fn try_apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                #bevy_reflect_path::__macro_exports::stats::record_apply();
                 if let #bevy_reflect_path::ReflectRef::TupleStruct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
                     for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::TupleStruct::iter_fields(struct_value)) {
                         if let #FQOption::Some(v) = #bevy_reflect_path::TupleStruct::field_mut(self, i) {
-                            #bevy_reflect_path::Reflect::try_apply(v, value)?;
+                            #bevy_reflect_path::Reflect::try_apply(v, value)
+                                .map_err(|err| #bevy_reflect_path::ApplyError::with_field(err, i))?;
+                        } else if let #FQOption::Some(v) = #bevy_reflect_path::TupleStruct::field(self, i) {
+                            // Read-only fields may only be applied with their current value.
+                            // Fields which can't be compared are left untouched.
+                            if #bevy_reflect_path::Reflect::reflect_partial_eq(v, value) == #FQOption::Some(false) {
+                                return #FQResult::Err(#bevy_reflect_path::ApplyError::ReadOnlyField {
+                                    field_name: ::core::convert::Into::into(::std::string::ToString::to_string(&i)),
+                                });
+                            }
                         }
                     }
                 } else {
@@ -182,10 +211,14 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
                 #bevy_reflect_path::ReflectOwned::TupleStruct(self)
             }
 
+            #clone_fn
+
             #hash_fn
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
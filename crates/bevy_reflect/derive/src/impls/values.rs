@@ -11,7 +11,18 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
     let hash_fn = meta.attrs().get_hash_impl(bevy_reflect_path);
     let partial_eq_fn = meta.attrs().get_partial_eq_impl(bevy_reflect_path);
+    let partial_ord_fn = meta.attrs().get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = meta.attrs().get_debug_impl();
+    let clone_fn = meta
+        .attrs()
+        .get_clone_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_clone(&self) -> #FQResult<#FQBox<dyn #bevy_reflect_path::Reflect>, #bevy_reflect_path::ReflectCloneError> {
+                    #FQResult::Ok(#FQBox::new(#FQClone::clone(self)))
+                }
+            }
+        });
 
     #[cfg(feature = "documentation")]
     let with_docs = {
@@ -21,12 +32,14 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+    let with_generics = meta.generics_to_info_tokens();
+
     let where_clause_options = WhereClauseOptions::new(meta);
     let typed_impl = impl_typed(
         meta,
         &where_clause_options,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_generics #with_docs;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
     );
@@ -89,11 +102,13 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
             #[inline]
             fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                #bevy_reflect_path::__macro_exports::stats::record_clone();
                 #FQBox::new(#FQClone::clone(self))
             }
 
              #[inline]
             fn try_apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                #bevy_reflect_path::__macro_exports::stats::record_apply();
                 let any = #bevy_reflect_path::Reflect::as_any(value);
                 if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(any) {
                     *self = #FQClone::clone(value);
@@ -134,10 +149,14 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
                 #bevy_reflect_path::ReflectOwned::Value(self)
             }
 
+            #clone_fn
+
             #hash_fn
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
@@ -27,6 +27,7 @@ mod from_reflect;
 mod impls;
 mod reflect_value;
 mod registration;
+mod remote;
 mod serialization;
 mod trait_reflection;
 mod type_path;
@@ -144,6 +145,11 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///
 /// There are a few "special" identifiers that work a bit differently:
 ///
+/// * `#[reflect(Clone)]` will force the implementation of `Reflect::reflect_clone` to rely on
+///   the type's [`Clone`] implementation.
+///   A custom implementation may be provided using `#[reflect(Clone(my_clone_func))]` where
+///   `my_clone_func` is the path to a function matching the signature: `(&self) -> Self`.
+///   Without it, `Reflect::reflect_clone` clones each field individually.
 /// * `#[reflect(Debug)]` will force the implementation of `Reflect::reflect_debug` to rely on
 ///   the type's [`Debug`] implementation.
 ///   A custom implementation may be provided using `#[reflect(Debug(my_debug_func))]` where
@@ -154,6 +160,11 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///   A custom implementation may be provided using `#[reflect(PartialEq(my_partial_eq_func))]` where
 ///   `my_partial_eq_func` is the path to a function matching the signature:
 ///   `(&self, value: &dyn #bevy_reflect_path::Reflect) -> bool`.
+/// * `#[reflect(PartialOrd)]` will force the implementation of `Reflect::reflect_cmp` to rely on
+///   the type's [`PartialOrd`] implementation.
+///   A custom implementation may be provided using `#[reflect(PartialOrd(my_partial_cmp_func))]` where
+///   `my_partial_cmp_func` is the path to a function matching the signature:
+///   `(&self, value: &dyn #bevy_reflect_path::Reflect) -> Option<::core::cmp::Ordering>`.
 /// * `#[reflect(Hash)]` will force the implementation of `Reflect::reflect_hash` to rely on
 ///   the type's [`Hash`] implementation.
 ///   A custom implementation may be provided using `#[reflect(Hash(my_hash_func))]` where
@@ -323,10 +334,63 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// it simply opts the field out of both serialization and deserialization.
 /// This can be useful when a field should be accessible via reflection, but may not make
 /// sense in a serialized form, such as computed data.
+/// It can be used on the fields of enum variants as well as on struct fields.
 ///
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(rename = "...")]` and `#[reflect(alias = "...")]`
+///
+/// These attributes can be placed on named fields and on enum variants.
+///
+/// `rename` changes the name the field or variant is reflected under.
+/// The new name is used everywhere reflection refers to it by name, including its `TypeInfo`,
+/// name-based accessors such as `Struct::field`, reflection paths, and serialized data.
+///
+/// `alias` can be given any number of times to declare other names that are also accepted
+/// when deserializing and when accessing fields through reflection paths.
+/// This makes it possible to rename a field without breaking data that still uses the old name:
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(alias = "hp")]
+///     health: u32,
+/// }
+/// ```
+///
+/// ## `#[reflect(read_only)]`
+///
+/// This marks a field as visible to reflection without allowing it to be mutated through it.
+///
+/// The field is still part of the type's `TypeInfo` (see `NamedField::is_read_only`),
+/// can be accessed immutably, and is serialized as usual.
+/// However, mutable accessors such as `Struct::field_mut` return `None` for it,
+/// and applying a value that would change it results in `ApplyError::ReadOnlyField`.
+/// If the field's type can't be compared with `Reflect::reflect_partial_eq`,
+/// applied values are ignored instead.
+///
+/// This is useful for exposing internal state, such as a generation counter,
+/// to inspectors without letting them modify it.
+/// It is only supported on the fields of structs and tuple structs.
+///
+/// ## `#[reflect(flatten)]`
+///
+/// This merges the fields of a field's struct into the fields of the containing struct,
+/// so that they appear inline in its `StructInfo`, its `Struct` accessors, reflection paths,
+/// and serialized form.
+///
+/// The flattened fields are placed where the flattened field is declared.
+/// The flattened type must be a struct, and is constructed by `FromReflect` from the containing struct's fields.
+/// Generic flattened types need a `#[reflect(where T: Struct + Typed)]` bound.
+///
+/// Collisions between the names of the fields, including flattening the same type twice,
+/// are errors at compile time.
+/// If the flattened type is a type parameter, its collisions are instead detected when the type's
+/// `TypeInfo` is first created, which panics.
+/// Fields of the flattened struct keep their `#[reflect(skip_serializing)]` and `#[reflect(default)]`
+/// behavior when serialized as part of the containing struct.
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
@@ -389,6 +453,48 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 /// or to remove the `Default` requirement on fields marked with `#[reflect(ignore)]`.
 /// Additionally, either form of this attribute can be used to fill in fields that are simply missing,
 /// such as when converting a partially-constructed dynamic type to a concrete one.
+///
+/// When deriving `Reflect`, the default is also recorded in the type's `SerializationData`,
+/// so the reflection deserializer fills in the field when it is missing from the serialized input.
+/// This allows data saved before a field was added to still be loaded.
+///
+/// ## `#[reflect(clone_with = "...")]` and `#[reflect(rebuild_with = "...")]`
+///
+/// These attributes can only be used alongside `#[reflect(ignore)]` on struct fields,
+/// and allow an ignored field to be reconstructed rather than always falling back to its default.
+///
+/// `clone_with` takes a path to a function of the form `fn(&T) -> T`, where `T` is the field's type.
+/// It is used whenever the value passed to `FromReflect::from_reflect` is a concrete instance of the struct,
+/// in which case the field is cloned out of it.
+///
+/// `rebuild_with` takes a path to a function of the form `fn(&Self) -> T`.
+/// It is called after all other fields have been set, making it useful for rebuilding caches or other
+/// data derived from the reflected fields.
+/// If both attributes are given, `rebuild_with` is only used when `clone_with` is not applicable,
+/// such as when converting from a dynamic type.
+///
+/// Since `ReflectFromReflect` defers to `FromReflect::from_reflect`, these hooks also apply when
+/// constructing values through the type registry.
+///
+/// `clone_with` is also used by `Reflect::reflect_clone` to clone the ignored field.
+/// Ignored fields without it can only be cloned if they have a `#[reflect(default)]` attribute,
+/// otherwise `reflect_clone` returns an error.
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect)]
+/// struct Polygon {
+///     points: Vec<f32>,
+///     #[reflect(ignore, rebuild_with = "Polygon::compute_len")]
+///     len: usize,
+/// }
+///
+/// impl Polygon {
+///     fn compute_len(&self) -> usize {
+///         self.points.len()
+///     }
+/// }
+/// ```
 #[proc_macro_derive(FromReflect, attributes(reflect))]
 pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -511,6 +617,43 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
     trait_reflection::reflect_trait(&args, input)
 }
 
+/// An attribute macro for reflecting a type defined in another crate through a local "mirror" type.
+///
+/// The orphan rule prevents deriving `Reflect` on a foreign type, so instead the macro is placed
+/// on a local type with exactly the same fields (or variants) as the foreign type, naming the foreign
+/// type as its argument.
+/// This works much like serde's `#[serde(remote = "...")]`.
+///
+/// The macro derives `Reflect` on the mirror type, so every `#[reflect(...)]` attribute remains
+/// available, and generates `From` conversions in both directions along with an implementation of
+/// the `ReflectRemote` trait.
+/// The foreign type's fields must be visible from the mirror's module.
+///
+/// The foreign type itself can't implement `Reflect`, for the same reason.
+/// Its reflected data is described by the mirror instead: `ReflectRemote::remote_type_info`
+/// returns the mirror's `TypeInfo`, and `ReflectRemote::remote_from_reflect` plays the role of
+/// `FromReflect`, building the foreign type out of any data shaped like the mirror.
+///
+/// Since `Reflect` is derived on the mirror, its generic type parameters need the same bounds
+/// as with `#[derive(Reflect)]`, namely `FromReflect`, `TypePath` and `GetTypeRegistration`.
+///
+/// # Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[reflect_remote(other_crate::Rect)]
+/// #[reflect(RemoteType)]
+/// struct RectDef {
+///     width: f32,
+///     height: f32,
+/// }
+///
+/// let rect: other_crate::Rect = RectDef::remote_from_reflect(&dynamic_rect).unwrap();
+/// ```
+#[proc_macro_attribute]
+pub fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    remote::reflect_remote(args, input)
+}
+
 /// A macro used to generate reflection trait implementations for the given type.
 ///
 /// This is functionally the same as [deriving `Reflect`] using the `#[reflect_value]` container attribute.
@@ -542,6 +685,12 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
 /// impl_reflect_value!((in not_my_crate as NotFoo) Foo(Debug, Default));
 /// ```
 ///
+/// The `FromReflect` implementation can be skipped in order to write it by hand:
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// impl_reflect_value!(my_crate::Foo(Debug, from_reflect = false));
+/// ```
+///
 /// [deriving `Reflect`]: Reflect
 #[proc_macro]
 pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
@@ -564,7 +713,10 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
     let meta = meta.with_docs(documentation::Documentation::from_attributes(&def.attrs));
 
     let reflect_impls = impls::impl_value(&meta);
-    let from_reflect_impl = from_reflect::impl_value(&meta);
+    let from_reflect_impl = meta
+        .from_reflect()
+        .should_auto_derive()
+        .then(|| from_reflect::impl_value(&meta));
 
     TokenStream::from(quote! {
         const _: () = {
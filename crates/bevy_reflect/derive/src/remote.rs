@@ -0,0 +1,131 @@
+use crate::utility::get_bevy_reflect_path;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Path, PathArguments, Token,
+};
+
+/// Derives `Reflect` on a local mirror type and generates the conversions to and from the
+/// remote type it mirrors, along with an implementation of `ReflectRemote`.
+pub(crate) fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    let remote_ty = parse_macro_input!(args as Path);
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match expand(&remote_ty, &ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn expand(remote_ty: &Path, ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let bevy_reflect_path = get_bevy_reflect_path();
+    let remote_expr = as_expr_path(remote_ty);
+
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // `Self` is the remote type when converting into it, so the mirror has to be named
+    let mirror_expr = {
+        let turbofish = ty_generics.as_turbofish();
+        quote!(#ident #turbofish)
+    };
+
+    let (from_remote, into_remote) = match &ast.data {
+        Data::Struct(data) => (
+            convert_struct(&quote!(Self), &data.fields),
+            convert_struct(&quote!(#remote_expr), &data.fields),
+        ),
+        Data::Enum(data) => {
+            let (from_arms, into_arms): (Vec<_>, Vec<_>) = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let (pattern, bindings) = destructure(&variant.fields);
+                    (
+                        quote!(#remote_expr::#variant_ident #pattern => Self::#variant_ident #bindings),
+                        quote!(#mirror_expr::#variant_ident #pattern => #remote_expr::#variant_ident #bindings),
+                    )
+                })
+                .unzip();
+            (
+                quote!(match value { #(#from_arms,)* }),
+                quote!(match value { #(#into_arms,)* }),
+            )
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "#[reflect_remote] does not currently support unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        #[derive(#bevy_reflect_path::Reflect)]
+        #ast
+
+        impl #impl_generics ::core::convert::From<#remote_ty> for #ident #ty_generics #where_clause {
+            fn from(value: #remote_ty) -> Self {
+                #from_remote
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #remote_ty #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                #into_remote
+            }
+        }
+
+        impl #impl_generics #bevy_reflect_path::ReflectRemote for #ident #ty_generics #where_clause {
+            type Remote = #remote_ty;
+        }
+    })
+}
+
+/// Builds `target` out of the fields of `value`, which has the same shape.
+fn convert_struct(target: &proc_macro2::TokenStream, fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let idents = fields.named.iter().map(|field| &field.ident);
+            quote!(#target { #(#idents: value.#idents,)* })
+        }
+        Fields::Unnamed(fields) => {
+            let indices = (0..fields.unnamed.len()).map(syn::Index::from);
+            quote!(#target(#(value.#indices,)*))
+        }
+        Fields::Unit => quote!(#target),
+    }
+}
+
+/// Returns a pattern binding every field of a variant, and the matching constructor arguments.
+fn destructure(fields: &Fields) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match fields {
+        Fields::Named(fields) => {
+            let idents: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+            (quote!({ #(#idents,)* }), quote!({ #(#idents,)* }))
+        }
+        Fields::Unnamed(fields) => {
+            let bindings: Vec<_> = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(index, field)| format_ident!("__{}", index, span = field.span()))
+                .collect();
+            (quote!((#(#bindings,)*)), quote!((#(#bindings,)*)))
+        }
+        Fields::Unit => (quote!(), quote!()),
+    }
+}
+
+/// Converts `external::Foo<T>` into `external::Foo::<T>` so it can be used in expression
+/// and pattern position.
+fn as_expr_path(path: &Path) -> Path {
+    let mut path = path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token.get_or_insert_with(<Token![::]>::default);
+        }
+    }
+    path
+}
@@ -1,10 +1,10 @@
-use crate::derive_data::StructField;
+use crate::derive_data::{EnumVariant, StructField};
 use crate::field_attributes::{DefaultBehavior, ReflectIgnoreBehavior};
 use bevy_macro_utils::fq_std::{FQBox, FQDefault};
 use quote::quote;
 use std::collections::HashMap;
 use syn::spanned::Spanned;
-use syn::Path;
+use syn::{Path, Type};
 
 type ReflectionIndex = usize;
 
@@ -12,37 +12,93 @@ type ReflectionIndex = usize;
 pub(crate) struct SerializationDataDef {
     /// Maps a field's _reflection_ index to its [`SkippedFieldDef`] if marked as `#[reflect(skip_serializing)]`.
     skipped: HashMap<ReflectionIndex, SkippedFieldDef>,
+    /// Maps a field's _reflection_ index to its [`SkippedFieldDef`] if it is serialized
+    /// but marked with `#[reflect(default)]`, so it can be filled in when missing from the input.
+    defaults: HashMap<ReflectionIndex, SkippedFieldDef>,
+    /// Maps an enum variant's index to the serialization data for its fields.
+    variants: HashMap<usize, SerializationDataDef>,
+    /// The _reflection_ indices and types of fields marked with `#[reflect(flatten)]`, in order.
+    ///
+    /// Each of these expands into the fields of its struct, shifting the indices of the fields after it,
+    /// so the final indices can only be computed at runtime.
+    flattened: Vec<(ReflectionIndex, Type)>,
 }
 
 impl SerializationDataDef {
     /// Attempts to create a new `SerializationDataDef` from the given collection of fields.
     ///
-    /// Returns `Ok(Some(data))` if there are any fields needing to be skipped during serialization.
+    /// Returns `Ok(Some(data))` if there are any fields needing to be skipped during serialization
+    /// or that can be defaulted when missing from the serialized data.
     /// Otherwise, returns `Ok(None)`.
     pub fn new(fields: &[StructField<'_>]) -> Result<Option<Self>, syn::Error> {
         let mut skipped = HashMap::default();
+        let mut defaults = HashMap::default();
+        let mut flattened = Vec::new();
 
         for field in fields {
-            match field.attrs.ignore {
-                ReflectIgnoreBehavior::IgnoreSerialization => {
-                    skipped.insert(
-                        field.reflection_index.ok_or_else(|| {
-                            syn::Error::new(
-                                field.data.span(),
-                                "internal error: field is missing a reflection index",
-                            )
-                        })?,
-                        SkippedFieldDef::new(field)?,
-                    );
+            let Some(reflection_index) = field.reflection_index else {
+                if field.attrs.ignore == ReflectIgnoreBehavior::IgnoreSerialization {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        "internal error: field is missing a reflection index",
+                    ));
+                }
+                continue;
+            };
+
+            if field.attrs.flatten {
+                flattened.push((reflection_index, field.data.ty.clone()));
+                continue;
+            }
+
+            match (field.attrs.ignore, &field.attrs.default) {
+                (ReflectIgnoreBehavior::IgnoreSerialization, _) => {
+                    skipped.insert(reflection_index, SkippedFieldDef::new(field)?);
+                }
+                (
+                    ReflectIgnoreBehavior::None,
+                    DefaultBehavior::Default | DefaultBehavior::Func(_),
+                ) => {
+                    defaults.insert(reflection_index, SkippedFieldDef::new(field)?);
                 }
                 _ => continue,
             }
         }
 
-        if skipped.is_empty() {
+        if skipped.is_empty() && defaults.is_empty() && flattened.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(Self {
+                skipped,
+                defaults,
+                variants: HashMap::default(),
+                flattened,
+            }))
+        }
+    }
+
+    /// Attempts to create a new `SerializationDataDef` from the fields of the given enum variants.
+    ///
+    /// Returns `Ok(Some(data))` if any variant has fields needing serialization data.
+    /// Otherwise, returns `Ok(None)`.
+    pub fn from_variants(variants: &[EnumVariant<'_>]) -> Result<Option<Self>, syn::Error> {
+        let mut data = HashMap::default();
+
+        for variant in variants {
+            if let Some(variant_data) = Self::new(variant.fields())? {
+                data.insert(variant.index, variant_data);
+            }
+        }
+
+        if data.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(Self { skipped }))
+            Ok(Some(Self {
+                skipped: HashMap::default(),
+                defaults: HashMap::default(),
+                variants: data,
+                flattened: Vec::new(),
+            }))
         }
     }
 
@@ -57,10 +113,33 @@ impl SerializationDataDef {
                         #bevy_reflect_path::serde::SkippedField::new(#default_fn)
                     )}
                 });
+        let defaults = self
+            .defaults
+            .iter()
+            .map(|(reflection_index, SkippedFieldDef { default_fn })| {
+                quote!(.with_default(#reflection_index, #bevy_reflect_path::serde::SkippedField::new(#default_fn)))
+            });
+        let variants = self.variants.iter().map(|(variant_index, data)| {
+            let data = data.as_serialization_data(bevy_reflect_path);
+            quote!(.with_variant(#variant_index, #data))
+        });
+        // Expanded from the last flattened field backwards so that the indices of earlier ones stay valid
+        let flattened = self.flattened.iter().rev().map(|(reflection_index, ty)| {
+            quote! {
+                .with_flattened(
+                    #reflection_index,
+                    #bevy_reflect_path::__macro_exports::flattened_field_len::<#ty>(),
+                    #bevy_reflect_path::__macro_exports::flattened_serialization_data::<#ty>().as_ref(),
+                )
+            }
+        });
         quote! {
             #bevy_reflect_path::serde::SerializationData::new(
                 ::core::iter::IntoIterator::into_iter([#(#fields),*])
             )
+            #(#defaults)*
+            #(#variants)*
+            #(#flattened)*
         }
     }
 }
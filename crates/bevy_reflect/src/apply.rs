@@ -0,0 +1,274 @@
+use crate::{
+    list_try_apply_elements, ApplyError, ApplyStrategy, Reflect, ReflectMut, ReflectRef,
+    TypeRegistry, VariantField,
+};
+
+/// Type data used to configure the [`ApplyStrategy`] of a list type.
+///
+/// This is consulted by [`try_apply_with`] whenever it encounters a list of the registered type.
+/// Lists without this type data use [`ApplyStrategy::Merge`].
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{apply_with, ApplyStrategy, ReflectApplyStrategy, TypeRegistry};
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Vec<u32>>();
+/// registry
+///     .get_mut(TypeId::of::<Vec<u32>>())
+///     .unwrap()
+///     .insert(ReflectApplyStrategy(ApplyStrategy::Truncate));
+///
+/// let mut inventory = vec![1_u32, 2, 3];
+/// apply_with(&mut inventory, &vec![4_u32], &registry);
+/// assert_eq!(vec![4], inventory);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReflectApplyStrategy(pub ApplyStrategy);
+
+/// Applies `value` to `target`, using the [`ApplyStrategy`] registered for each list
+/// encountered along the way.
+///
+/// Unlike [`Reflect::apply`], this walks into structs, tuples, arrays, maps,
+/// and enums sharing the same variant, so that nested lists are also applied
+/// using their registered strategy.
+///
+/// # Panics
+///
+/// Panics if the value cannot be applied. See [`try_apply_with`] for a non-panicking version.
+pub fn apply_with(target: &mut dyn Reflect, value: &dyn Reflect, registry: &TypeRegistry) {
+    if let Err(err) = try_apply_with(target, value, registry) {
+        panic!("{err}");
+    }
+}
+
+/// Tries to apply `value` to `target`, using the [`ApplyStrategy`] registered for each list
+/// encountered along the way.
+///
+/// See [`apply_with`] for details.
+///
+/// # Errors
+///
+/// Returns an [`ApplyError`] if the value could not be applied,
+/// such as when the kinds of `target` and `value` differ.
+pub fn try_apply_with(
+    target: &mut dyn Reflect,
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), ApplyError> {
+    let strategy = target
+        .get_represented_type_info()
+        .and_then(|info| registry.get_type_data::<ReflectApplyStrategy>(info.type_id()))
+        .map(|data| data.0)
+        .unwrap_or_default();
+
+    // Values, as well as enums changing variant, are applied as a whole.
+    let recurse = match (target.reflect_ref(), value.reflect_ref()) {
+        (ReflectRef::Enum(target), ReflectRef::Enum(value)) => {
+            target.variant_name() == value.variant_name()
+        }
+        (ReflectRef::Value(_), _) => false,
+        (target, value) => target.kind() == value.kind(),
+    };
+    if !recurse {
+        return target.try_apply(value);
+    }
+
+    match (target.reflect_mut(), value.reflect_ref()) {
+        (ReflectMut::Struct(target), ReflectRef::Struct(value)) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(i).unwrap();
+                if let Some(target) = target.field_mut(name) {
+                    try_apply_with(target, field, registry).map_err(|err| err.with_field(name))?;
+                } else if let Some(current) = target.field(name) {
+                    check_read_only(current, field, name)?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectMut::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                if let Some(target) = target.field_mut(i) {
+                    try_apply_with(target, field, registry).map_err(|err| err.with_field(i))?;
+                } else if let Some(current) = target.field(i) {
+                    check_read_only(current, field, i)?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectMut::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                if let Some(target) = target.field_mut(i) {
+                    try_apply_with(target, field, registry).map_err(|err| err.with_field(i))?;
+                }
+            }
+            Ok(())
+        }
+        (ReflectMut::Array(target), ReflectRef::Array(value)) => {
+            if target.len() != value.len() {
+                return Err(ApplyError::DifferentSize {
+                    from_size: value.len(),
+                    to_size: target.len(),
+                });
+            }
+            for (i, item) in value.iter().enumerate() {
+                try_apply_with(target.get_mut(i).unwrap(), item, registry)
+                    .map_err(|err| err.with_index(i))?;
+            }
+            Ok(())
+        }
+        (ReflectMut::List(target), ReflectRef::List(value)) => {
+            list_try_apply_elements(target, value, strategy, |a, b| {
+                try_apply_with(a, b, registry)
+            })
+        }
+        (ReflectMut::Map(target), ReflectRef::Map(value)) => {
+            for (key, item) in value.iter() {
+                match target.get_mut(key) {
+                    Some(target) => {
+                        try_apply_with(target, item, registry).map_err(|err| err.with_key(key))?;
+                    }
+                    None => {
+                        target.insert_boxed(key.clone_value(), item.clone_value());
+                    }
+                }
+            }
+            Ok(())
+        }
+        (ReflectMut::Enum(target), ReflectRef::Enum(value)) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => {
+                        if let Some(target) = target.field_mut(name) {
+                            try_apply_with(target, field, registry)
+                                .map_err(|err| err.with_field(name))?;
+                        }
+                    }
+                    VariantField::Tuple(field) => {
+                        if let Some(target) = target.field_at_mut(i) {
+                            try_apply_with(target, field, registry)
+                                .map_err(|err| err.with_field(i))?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        _ => unreachable!("kinds were checked above"),
+    }
+}
+
+/// Returns an error if `value` would change the current value of a read-only field.
+///
+/// Values which can't be compared are assumed to be unchanged, since the field is not applied anyway.
+fn check_read_only(
+    current: &dyn Reflect,
+    value: &dyn Reflect,
+    name: impl ToString,
+) -> Result<(), ApplyError> {
+    if current.reflect_partial_eq(value) == Some(false) {
+        Err(ApplyError::ReadOnlyField {
+            field_name: name.to_string().into(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{list_apply_with, Reflect};
+    use std::any::TypeId;
+
+    #[test]
+    fn should_apply_list_strategies() {
+        let mut list = vec![1, 2, 3];
+        list_apply_with(&mut list, &vec![4], ApplyStrategy::Merge);
+        assert_eq!(vec![4, 2, 3], list);
+
+        let mut list = vec![1, 2, 3];
+        list_apply_with(&mut list, &vec![4], ApplyStrategy::Truncate);
+        assert_eq!(vec![4], list);
+
+        let mut list = vec![1, 2];
+        list_apply_with(&mut list, &vec![4, 5, 6], ApplyStrategy::Replace);
+        assert_eq!(vec![4, 5, 6], list);
+    }
+
+    #[test]
+    fn should_use_registered_strategy_for_nested_lists() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            inventory: Vec<u32>,
+            scores: Vec<u64>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry
+            .get_mut(TypeId::of::<Vec<u32>>())
+            .unwrap()
+            .insert(ReflectApplyStrategy(ApplyStrategy::Truncate));
+        registry
+            .get_mut(TypeId::of::<Vec<u64>>())
+            .unwrap()
+            .insert(ReflectApplyStrategy(ApplyStrategy::Merge));
+
+        let mut player = Player {
+            inventory: vec![1, 2, 3],
+            scores: vec![1, 2, 3],
+        };
+        apply_with(
+            &mut player,
+            &Player {
+                inventory: vec![7],
+                scores: vec![8],
+            },
+            &registry,
+        );
+
+        assert_eq!(
+            Player {
+                inventory: vec![7],
+                scores: vec![8, 2, 3],
+            },
+            player
+        );
+    }
+
+    #[test]
+    fn should_report_enum_field_paths() {
+        use crate::{DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant};
+
+        #[derive(Reflect, Debug)]
+        enum Shape {
+            Circle { radius: f32 },
+            Polygon(Vec<f32>),
+        }
+
+        let registry = TypeRegistry::default();
+
+        let mut circle = DynamicStruct::default();
+        circle.insert("radius", String::from("big"));
+        let error = try_apply_with(
+            &mut Shape::Circle { radius: 1.0 },
+            &DynamicEnum::new("Circle", DynamicVariant::Struct(circle)),
+            &registry,
+        )
+        .unwrap_err();
+        assert_eq!(Some(".radius"), error.path());
+
+        let mut polygon = DynamicTuple::default();
+        polygon.insert(vec![String::from("side")]);
+        let error = try_apply_with(
+            &mut Shape::Polygon(vec![1.0]),
+            &DynamicEnum::new("Polygon", DynamicVariant::Tuple(polygon)),
+            &registry,
+        )
+        .unwrap_err();
+        assert_eq!(Some(".0[0]"), error.path());
+    }
+}
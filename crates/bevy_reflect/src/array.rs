@@ -1,10 +1,12 @@
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
-    self as bevy_reflect, utility::reflect_hasher, ApplyError, Reflect, ReflectKind, ReflectMut,
-    ReflectOwned, ReflectRef, TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, utility::reflect_hasher, ApplyError, Reflect, ReflectCloneError,
+    ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
 use bevy_reflect_derive::impl_type_path;
 use std::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
 };
@@ -62,11 +64,25 @@ pub trait Array: Reflect {
     /// Returns an iterator over the array.
     fn iter(&self) -> ArrayIter;
 
+    /// Returns an iterator over mutable references to the elements of the array.
+    ///
+    /// # Migration
+    ///
+    /// This method was added as a required method, since a default implementation
+    /// can't hand out mutable references to several elements at once through
+    /// [`get_mut`](Array::get_mut).
+    /// Manual implementations of [`Array`] wrapping a collection can usually forward to
+    /// the collection's own `iter_mut`, e.g.
+    /// `ArrayIterMut::new(self.0.iter_mut().map(|item| item as &mut dyn Reflect))`.
+    fn iter_mut(&mut self) -> ArrayIterMut;
+
     /// Drain the elements of this array to get a vector of owned values.
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>>;
 
     /// Clones the list, producing a [`DynamicArray`].
     fn clone_dynamic(&self) -> DynamicArray {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicArray {
             represented_type: self.get_represented_type_info(),
             values: self.iter().map(Reflect::clone_value).collect(),
@@ -82,11 +98,21 @@ pub struct ArrayInfo {
     item_type_path: TypePathTable,
     item_type_id: TypeId,
     capacity: usize,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl ArrayInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`ArrayInfo`].
     ///
     /// # Arguments
@@ -100,6 +126,7 @@ impl ArrayInfo {
             item_type_path: TypePathTable::of::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
             capacity,
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -165,6 +192,8 @@ impl ArrayInfo {
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
+
+    impl_generic_info_methods!(self.generics);
 }
 
 /// A fixed-size list of reflected values.
@@ -202,6 +231,11 @@ impl DynamicArray {
         }
     }
 
+    /// Creates a new [`DynamicArray`] containing the given bytes as [`u8`] elements.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_vec(bytes.to_vec())
+    }
+
     /// Sets the [type] to be represented by this `DynamicArray`.
     ///
     /// # Panics
@@ -294,9 +328,15 @@ impl Reflect for DynamicArray {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         array_hash(self)
@@ -306,6 +346,10 @@ impl Reflect for DynamicArray {
         array_partial_eq(self, value)
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        array_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicArray(")?;
         array_debug(self, f)?;
@@ -339,6 +383,11 @@ impl Array for DynamicArray {
         ArrayIter::new(self)
     }
 
+    #[inline]
+    fn iter_mut(&mut self) -> ArrayIterMut {
+        ArrayIterMut::new(self.values.iter_mut().map(|value| &mut **value))
+    }
+
     #[inline]
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
         self.values.into_vec()
@@ -346,6 +395,8 @@ impl Array for DynamicArray {
 
     #[inline]
     fn clone_dynamic(&self) -> DynamicArray {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicArray {
             represented_type: self.represented_type,
             values: self
@@ -394,6 +445,80 @@ impl<'a> Iterator for ArrayIter<'a> {
 
 impl<'a> ExactSizeIterator for ArrayIter<'a> {}
 
+/// An iterator over mutable references to the elements of an [`Array`].
+///
+/// This is created by calling [`Array::iter_mut`].
+pub struct ArrayIterMut<'a> {
+    elements: Box<dyn ExactSizeIterator<Item = &'a mut dyn Reflect> + 'a>,
+}
+
+impl<'a> ArrayIterMut<'a> {
+    /// Creates a new [`ArrayIterMut`] from an iterator over the elements of an array, in order.
+    #[inline]
+    pub fn new(elements: impl ExactSizeIterator<Item = &'a mut dyn Reflect> + 'a) -> Self {
+        Self {
+            elements: Box::new(elements),
+        }
+    }
+}
+
+impl<'a> Iterator for ArrayIterMut<'a> {
+    type Item = &'a mut dyn Reflect;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for ArrayIterMut<'a> {}
+
+impl dyn Array {
+    /// Returns the elements of the array as bytes, or `None` if any element is not a [`u8`].
+    ///
+    /// This allows byte arrays, such as hashes or keys stored as `[u8; N]`,
+    /// to be handled as a single value rather than element by element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::Array;
+    /// let key: &dyn Array = &[0xde_u8, 0xad, 0xbe, 0xef];
+    /// assert_eq!(Some(vec![0xde, 0xad, 0xbe, 0xef]), key.as_bytes());
+    /// assert_eq!(Some(String::from("deadbeef")), key.to_hex());
+    ///
+    /// let not_bytes: &dyn Array = &[1_u32, 2, 3];
+    /// assert_eq!(None, not_bytes.as_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        self.iter()
+            .map(|item| item.downcast_ref::<u8>().copied())
+            .collect()
+    }
+
+    /// Returns the elements of the array as a lowercase hexadecimal string,
+    /// or `None` if any element is not a [`u8`].
+    pub fn to_hex(&self) -> Option<String> {
+        self.as_bytes().map(|bytes| encode_hex(&bytes))
+    }
+}
+
+/// Encodes the given bytes as a lowercase hexadecimal string.
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+    hex
+}
+
 /// Returns the `u64` hash of the given [array](Array).
 #[inline]
 pub fn array_hash<A: Array>(array: &A) -> Option<u64> {
@@ -440,6 +565,8 @@ pub fn array_apply<A: Array>(array: &mut A, reflect: &dyn Reflect) {
 ///
 #[inline]
 pub fn array_try_apply<A: Array>(array: &mut A, reflect: &dyn Reflect) -> Result<(), ApplyError> {
+    #[cfg(feature = "stats")]
+    crate::stats::record_apply();
     if let ReflectRef::Array(reflect_array) = reflect.reflect_ref() {
         if array.len() != reflect_array.len() {
             return Err(ApplyError::DifferentSize {
@@ -449,7 +576,7 @@ pub fn array_try_apply<A: Array>(array: &mut A, reflect: &dyn Reflect) -> Result
         }
         for (i, value) in reflect_array.iter().enumerate() {
             let v = array.get_mut(i).unwrap();
-            v.try_apply(value)?;
+            v.try_apply(value).map_err(|err| err.with_index(i))?;
         }
     } else {
         return Err(ApplyError::MismatchedKinds {
@@ -481,6 +608,28 @@ pub fn array_partial_eq<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<bo
     Some(true)
 }
 
+/// Compares two [arrays](Array) (one concrete and one reflected) lexicographically.
+///
+/// Elements are compared pairwise using [`Reflect::reflect_cmp`],
+/// stopping at the first pair that isn't equal.
+///
+/// Returns [`None`] if the comparison couldn't even be performed.
+#[inline]
+pub fn array_cmp<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Array(reflect_array) = reflect.reflect_ref() else {
+        return None;
+    };
+
+    for (a, b) in array.iter().zip(reflect_array.iter()) {
+        match a.reflect_cmp(b)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+
+    Some(array.len().cmp(&reflect_array.len()))
+}
+
 /// The default debug formatter for [`Array`] types.
 ///
 /// # Example
@@ -506,9 +655,17 @@ pub fn array_debug(dyn_array: &dyn Array, f: &mut std::fmt::Formatter<'_>) -> st
     }
     debug.finish()
 }
+
 #[cfg(test)]
 mod tests {
+    use super::encode_hex;
     use crate::{Reflect, ReflectRef};
+
+    #[test]
+    fn should_encode_hex() {
+        assert_eq!("000fa5ff", encode_hex(&[0x00, 0x0f, 0xa5, 0xff]));
+    }
+
     #[test]
     fn next_index_increment() {
         const SIZE: usize = if cfg!(debug_assertions) {
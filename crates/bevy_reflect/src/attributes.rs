@@ -135,7 +135,7 @@ impl Debug for CustomAttribute {
 ///
 macro_rules! impl_custom_attribute_methods {
     ($self:ident . $attributes:ident, $term:literal) => {
-        $crate::attributes::impl_custom_attribute_methods!($self, &$self.$attributes, "item");
+        $crate::attributes::impl_custom_attribute_methods!($self, &$self.$attributes, $term);
     };
     ($self:ident, $attributes:expr, $term:literal) => {
         #[doc = concat!("Returns the custom attributes for this ", $term, ".")]
@@ -408,6 +408,37 @@ mod tests {
         assert_eq!(&(0..=255), range);
     }
 
+    #[test]
+    fn should_get_custom_attributes_from_type_info() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A slider"))]
+        struct Slider {
+            #[reflect(@0.0..=1.0_f32)]
+            value: f32,
+        }
+
+        let info = Slider::type_info();
+        let attributes = info.custom_attributes().unwrap();
+        assert_eq!(Some(&Tooltip::new("A slider")), attributes.get::<Tooltip>());
+
+        let TypeInfo::Struct(info) = info else {
+            panic!("expected struct info");
+        };
+        let (id, range) = info
+            .field("value")
+            .unwrap()
+            .custom_attributes()
+            .iter()
+            .next()
+            .unwrap();
+        assert_eq!(TypeId::of::<RangeInclusive<f32>>(), *id);
+        assert!(range.reflect_partial_eq(&(0.0..=1.0_f32)).unwrap());
+
+        assert!(<Vec<f32> as Typed>::type_info()
+            .custom_attributes()
+            .is_none());
+    }
+
     #[test]
     fn should_allow_unit_struct_attribute_values() {
         #[derive(Reflect)]
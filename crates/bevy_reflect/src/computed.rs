@@ -0,0 +1,448 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    access::Access, ApplyError, FromReflect, GetPath, ParsedPath, Reflect, ReflectPath, TypeInfo,
+    TypePath, TypeRegistry, Typed,
+};
+
+type Getter = dyn Fn(&dyn Reflect) -> Option<Box<dyn Reflect>> + Send + Sync;
+type Setter =
+    dyn Fn(&mut dyn Reflect, &dyn Reflect) -> Result<(), ComputedFieldError> + Send + Sync;
+
+/// A field of a type whose value is computed from the type's other fields,
+/// rather than stored in it.
+///
+/// Computed fields let tools display and edit derived values, such as a speed in km/h
+/// when the type stores it in m/s, without adding them to the type itself.
+/// They are registered with [`ReflectComputedFields`] type data,
+/// and can be accessed by path using [`computed_path`] and [`set_computed_path`].
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, ComputedField};
+/// #[derive(Reflect)]
+/// struct Vehicle {
+///     speed_ms: f32,
+/// }
+///
+/// let speed_kmh = ComputedField::new("speed_kmh", |vehicle: &Vehicle| vehicle.speed_ms * 3.6)
+///     .with_setter(|vehicle: &mut Vehicle, value: f32| vehicle.speed_ms = value / 3.6);
+///
+/// let mut vehicle = Vehicle { speed_ms: 10.0 };
+/// let value = speed_kmh.get(&vehicle).unwrap();
+/// assert_eq!(Some(&36.0), value.downcast_ref::<f32>());
+///
+/// speed_kmh.set(&mut vehicle, &72.0_f32).unwrap();
+/// assert_eq!(20.0, vehicle.speed_ms);
+/// ```
+#[derive(Clone)]
+pub struct ComputedField {
+    name: &'static str,
+    value_info: &'static TypeInfo,
+    getter: Arc<Getter>,
+    setter: Option<Arc<Setter>>,
+}
+
+impl ComputedField {
+    /// Creates a read-only computed field of `T` named `name`, whose value is returned by `getter`.
+    pub fn new<T: Reflect, V: Typed>(
+        name: &'static str,
+        getter: impl Fn(&T) -> V + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            value_info: V::type_info(),
+            getter: Arc::new(move |target: &dyn Reflect| {
+                let target = target.downcast_ref::<T>()?;
+                Some(Box::new(getter(target)) as Box<dyn Reflect>)
+            }),
+            setter: None,
+        }
+    }
+
+    /// Makes this field writable, using `setter` to update `T` from a new value.
+    ///
+    /// Values passed to [`set`](Self::set) are converted to `V` using [`FromReflect`].
+    pub fn with_setter<T: Reflect + TypePath, V: FromReflect + Typed>(
+        mut self,
+        setter: impl Fn(&mut T, V) + Send + Sync + 'static,
+    ) -> Self {
+        let name = self.name;
+        self.setter = Some(Arc::new(
+            move |target: &mut dyn Reflect, value: &dyn Reflect| {
+                let target_type_path = target.reflect_type_path().to_string();
+                let target =
+                    target
+                        .downcast_mut::<T>()
+                        .ok_or_else(|| ComputedFieldError::Apply {
+                            name: name.to_string(),
+                            error: ApplyError::MismatchedTypes {
+                                from_type: target_type_path.into(),
+                                to_type: T::type_path().into(),
+                            },
+                        })?;
+                let value = V::from_reflect(value).ok_or_else(|| ComputedFieldError::Apply {
+                    name: name.to_string(),
+                    error: ApplyError::MismatchedTypes {
+                        from_type: value.reflect_type_path().into(),
+                        to_type: V::type_path().into(),
+                    },
+                })?;
+                setter(target, value);
+                Ok(())
+            },
+        ));
+        self
+    }
+
+    /// Returns the name of this field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the [`TypeInfo`] of the values of this field.
+    pub fn value_info(&self) -> &'static TypeInfo {
+        self.value_info
+    }
+
+    /// Returns `true` if this field has no setter.
+    pub fn is_read_only(&self) -> bool {
+        self.setter.is_none()
+    }
+
+    /// Computes the value of this field for `target`.
+    ///
+    /// Returns `None` if `target` is not of the type this field was created for.
+    pub fn get(&self, target: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.getter)(target)
+    }
+
+    /// Updates `target` so that this field has the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ComputedFieldError::ReadOnly`] if this field has no setter,
+    /// or [`ComputedFieldError::Apply`] if `target` or `value` have the wrong type.
+    pub fn set(
+        &self,
+        target: &mut dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<(), ComputedFieldError> {
+        let setter = self
+            .setter
+            .as_ref()
+            .ok_or_else(|| ComputedFieldError::ReadOnly {
+                name: self.name.to_string(),
+            })?;
+        setter(target, value)
+    }
+}
+
+impl Debug for ComputedField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComputedField")
+            .field("name", &self.name)
+            .field("value_type_path", &self.value_info.type_path())
+            .field("read_only", &self.is_read_only())
+            .finish()
+    }
+}
+
+/// Type data holding the [computed fields] of a type.
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{prelude::*, computed_path, ComputedField, ReflectComputedFields, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Vehicle {
+///     speed_ms: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Vehicle>();
+/// registry
+///     .get_mut(TypeId::of::<Vehicle>())
+///     .unwrap()
+///     .insert(ReflectComputedFields::new().with(ComputedField::new(
+///         "speed_kmh",
+///         |vehicle: &Vehicle| vehicle.speed_ms * 3.6,
+///     )));
+///
+/// let vehicle = Vehicle { speed_ms: 10.0 };
+/// let value = computed_path(&vehicle, "speed_kmh", &registry).unwrap();
+/// assert_eq!(Some(&36.0), value.downcast_ref::<f32>());
+/// ```
+///
+/// [computed fields]: ComputedField
+#[derive(Clone, Debug, Default)]
+pub struct ReflectComputedFields {
+    fields: Vec<ComputedField>,
+}
+
+impl ReflectComputedFields {
+    /// Creates an empty set of computed fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a computed field, returning `self` for chaining.
+    ///
+    /// See [`insert`](Self::insert) for details.
+    pub fn with(mut self, field: ComputedField) -> Self {
+        self.insert(field);
+        self
+    }
+
+    /// Adds a computed field, replacing any existing field with the same name.
+    pub fn insert(&mut self, field: ComputedField) {
+        match self.fields.iter_mut().find(|f| f.name == field.name) {
+            Some(existing) => *existing = field,
+            None => self.fields.push(field),
+        }
+    }
+
+    /// Returns the computed field with the given name.
+    pub fn get(&self, name: &str) -> Option<&ComputedField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns an iterator over the computed fields, in the order they were added.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ComputedField> {
+        self.fields.iter()
+    }
+
+    /// Returns the number of computed fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if there are no computed fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// An error returned when accessing a [`ComputedField`].
+#[derive(Error, Debug)]
+pub enum ComputedFieldError {
+    /// The path does not point to a field or computed field.
+    #[error("invalid path `{path}`: {message}")]
+    InvalidPath {
+        /// The path that was accessed.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The computed field has no setter.
+    #[error("the computed field `{name}` is read-only")]
+    ReadOnly {
+        /// The name of the computed field.
+        name: String,
+    },
+    /// The value could not be applied to the field.
+    #[error("failed to set `{name}`: {error}")]
+    Apply {
+        /// The name of the field.
+        name: String,
+        /// The error returned when applying the value.
+        error: ApplyError,
+    },
+}
+
+/// Returns a copy of the value at `path` in `root`,
+/// where the last segment of the path may name a [`ComputedField`].
+///
+/// Stored fields take precedence over computed fields with the same name.
+/// Computed fields are looked up in the [`ReflectComputedFields`] registered
+/// for the type containing them.
+///
+/// # Errors
+///
+/// Returns [`ComputedFieldError::InvalidPath`] if the path does not point to
+/// a stored or computed field.
+pub fn computed_path(
+    root: &dyn Reflect,
+    path: &str,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, ComputedFieldError> {
+    let path_error = match root.reflect_path(path) {
+        Ok(value) => return Ok(value.clone_value()),
+        Err(error) => error.to_string(),
+    };
+
+    let (parent_path, name) = split_computed(path, path_error)?;
+    let parent = parent_path
+        .reflect_element(root)
+        .map_err(|error| invalid_path(path, error))?;
+    let field = find_computed(parent, &name, path, registry)?;
+    field
+        .get(parent)
+        .ok_or_else(|| ComputedFieldError::InvalidPath {
+            path: path.to_string(),
+            message: format!(
+                "computed field `{name}` cannot be read from a dynamic `{}`",
+                parent.reflect_type_path()
+            ),
+        })
+}
+
+/// Sets the value at `path` in `root`,
+/// where the last segment of the path may name a [`ComputedField`].
+///
+/// Stored fields are updated using [`Reflect::try_apply`],
+/// and computed fields using their setter.
+///
+/// # Errors
+///
+/// Returns [`ComputedFieldError::InvalidPath`] if the path does not point to
+/// a stored or computed field, or any error returned while setting the field.
+pub fn set_computed_path(
+    root: &mut dyn Reflect,
+    path: &str,
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), ComputedFieldError> {
+    let path_error = match root.reflect_path_mut(path) {
+        Ok(field) => {
+            return field
+                .try_apply(value)
+                .map_err(|error| ComputedFieldError::Apply {
+                    name: path.to_string(),
+                    error,
+                })
+        }
+        Err(error) => error.to_string(),
+    };
+
+    let (parent_path, name) = split_computed(path, path_error)?;
+    let parent = parent_path
+        .reflect_element_mut(root)
+        .map_err(|error| invalid_path(path, error))?;
+    let field = find_computed(&*parent, &name, path, registry)?;
+    field.set(parent, value)
+}
+
+/// Splits `path` into the path of the containing value and the name of the computed field.
+fn split_computed(
+    path: &str,
+    path_error: String,
+) -> Result<(ParsedPath, String), ComputedFieldError> {
+    let mut parsed = ParsedPath::parse(path).map_err(|error| invalid_path(path, error))?;
+    let name = match parsed.0.pop().map(|last| last.access) {
+        Some(Access::Field(name)) => name.into_owned(),
+        _ => {
+            return Err(ComputedFieldError::InvalidPath {
+                path: path.to_string(),
+                message: path_error,
+            })
+        }
+    };
+    Ok((parsed, name))
+}
+
+fn find_computed<'r>(
+    parent: &dyn Reflect,
+    name: &str,
+    path: &str,
+    registry: &'r TypeRegistry,
+) -> Result<&'r ComputedField, ComputedFieldError> {
+    parent
+        .get_represented_type_info()
+        .and_then(|info| registry.get_type_data::<ReflectComputedFields>(info.type_id()))
+        .and_then(|fields| fields.get(name))
+        .ok_or_else(|| ComputedFieldError::InvalidPath {
+            path: path.to_string(),
+            message: format!(
+                "`{}` has no field or computed field named `{name}`",
+                parent.reflect_type_path()
+            ),
+        })
+}
+
+fn invalid_path(path: &str, error: impl ToString) -> ComputedFieldError {
+    ComputedFieldError::InvalidPath {
+        path: path.to_string(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use std::any::TypeId;
+
+    #[derive(Reflect)]
+    struct Vehicle {
+        speed_ms: f32,
+    }
+
+    #[derive(Reflect)]
+    struct Garage {
+        vehicles: Vec<Vehicle>,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Garage>();
+        registry.get_mut(TypeId::of::<Vehicle>()).unwrap().insert(
+            ReflectComputedFields::new()
+                .with(
+                    ComputedField::new("speed_kmh", |vehicle: &Vehicle| vehicle.speed_ms * 3.6)
+                        .with_setter(|vehicle: &mut Vehicle, value: f32| {
+                            vehicle.speed_ms = value / 3.6;
+                        }),
+                )
+                .with(ComputedField::new("is_moving", |vehicle: &Vehicle| {
+                    vehicle.speed_ms != 0.0
+                })),
+        );
+        registry
+    }
+
+    #[test]
+    fn should_access_nested_computed_fields() {
+        let registry = get_registry();
+        let mut garage = Garage {
+            vehicles: vec![Vehicle { speed_ms: 0.0 }, Vehicle { speed_ms: 10.0 }],
+        };
+
+        let value = computed_path(&garage, "vehicles[1].speed_kmh", &registry).unwrap();
+        assert_eq!(Some(&36.0), value.downcast_ref::<f32>());
+
+        set_computed_path(&mut garage, "vehicles[0].speed_kmh", &18.0_f32, &registry).unwrap();
+        assert_eq!(5.0, garage.vehicles[0].speed_ms);
+
+        // Stored fields are still accessible
+        set_computed_path(&mut garage, "vehicles[1].speed_ms", &1.0_f32, &registry).unwrap();
+        let value = computed_path(&garage, "vehicles[1].speed_ms", &registry).unwrap();
+        assert_eq!(Some(&1.0), value.downcast_ref::<f32>());
+    }
+
+    #[test]
+    fn should_reject_invalid_computed_access() {
+        let registry = get_registry();
+        let mut garage = Garage {
+            vehicles: vec![Vehicle { speed_ms: 0.0 }],
+        };
+
+        assert!(matches!(
+            set_computed_path(&mut garage, "vehicles[0].is_moving", &true, &registry),
+            Err(ComputedFieldError::ReadOnly { .. })
+        ));
+        assert!(matches!(
+            set_computed_path(&mut garage, "vehicles[0].speed_kmh", &1_u32, &registry),
+            Err(ComputedFieldError::Apply { .. })
+        ));
+        assert!(matches!(
+            computed_path(&garage, "vehicles[0].speed_mph", &registry),
+            Err(ComputedFieldError::InvalidPath { .. })
+        ));
+        assert!(matches!(
+            computed_path(&garage, "vehicles[3].speed_kmh", &registry),
+            Err(ComputedFieldError::InvalidPath { .. })
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+use std::any::TypeId;
+use std::fmt;
+
+use crate::{
+    attributes::CustomAttributes, std_traits::ReflectDefault, Reflect, ReflectNumeric, TypeInfo,
+    TypeRegistry,
+};
+
+/// The constraints of a reflected field, gathered from its attributes and type data.
+///
+/// This brings together the information that tools such as validators, random generators,
+/// inspectors and clamping helpers need about a field:
+/// - its minimum and maximum, from a [`RangeInclusive`] [custom attribute] of the field's own type,
+///   which requires the field's type to register [`ReflectNumeric`],
+/// - its default value, from the [`ReflectDefault`] registered for the field's type,
+/// - whether it is [read-only](crate::NamedField::is_read_only).
+///
+/// Constraints are retrieved with [`TypeRegistry::field_constraints`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use std::any::TypeId;
+/// # use std::ops::RangeInclusive;
+/// #[derive(Reflect)]
+/// struct Light {
+///     #[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]
+///     intensity: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Light>();
+///
+/// let constraints = registry
+///     .field_constraints(TypeId::of::<Light>(), "intensity")
+///     .unwrap();
+/// assert_eq!(Some(&1.0), constraints.max().unwrap().downcast_ref::<f32>());
+/// assert_eq!(Some(&0.0), constraints.default_value().unwrap().downcast_ref::<f32>());
+///
+/// let mut intensity = 1.5_f32;
+/// assert_eq!(Some(false), constraints.contains(&intensity));
+/// assert!(constraints.clamp(&mut intensity));
+/// assert_eq!(1.0, intensity);
+/// ```
+///
+/// [`RangeInclusive`]: std::ops::RangeInclusive
+/// [custom attribute]: crate::attributes::CustomAttributes
+#[derive(Clone)]
+pub struct FieldConstraints {
+    type_path: &'static str,
+    type_id: TypeId,
+    read_only: bool,
+    attributes: &'static CustomAttributes,
+    numeric: Option<ReflectNumeric>,
+    default: Option<ReflectDefault>,
+}
+
+impl FieldConstraints {
+    /// The type path of the field.
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of the field.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Whether the field is read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Whether the field's type registers [`ReflectNumeric`].
+    pub fn is_numeric(&self) -> bool {
+        self.numeric.is_some()
+    }
+
+    /// The custom attributes of the field.
+    pub fn attributes(&self) -> &'static CustomAttributes {
+        self.attributes
+    }
+
+    /// The minimum value of the field, if it has a range.
+    pub fn min(&self) -> Option<Box<dyn Reflect>> {
+        self.range().map(|(min, _)| min)
+    }
+
+    /// The maximum value of the field, if it has a range.
+    pub fn max(&self) -> Option<Box<dyn Reflect>> {
+        self.range().map(|(_, max)| max)
+    }
+
+    /// The minimum and maximum values of the field, if it has a range.
+    pub fn range(&self) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        self.numeric.as_ref()?.range(self.attributes)
+    }
+
+    /// The default value of the field's type, if it registers [`ReflectDefault`].
+    pub fn default_value(&self) -> Option<Box<dyn Reflect>> {
+        self.default.as_ref().map(ReflectDefault::default)
+    }
+
+    /// Returns whether `value` lies within the range of the field.
+    ///
+    /// Returns `None` if the field has no range, or if `value` is not of the field's type.
+    pub fn contains(&self, value: &dyn Reflect) -> Option<bool> {
+        self.numeric.as_ref()?.contains(value, self.attributes)
+    }
+
+    /// Clamps `value` to the range of the field, if it has one.
+    ///
+    /// Returns `true` if `value` was changed.
+    pub fn clamp(&self, value: &mut dyn Reflect) -> bool {
+        self.numeric
+            .as_ref()
+            .is_some_and(|numeric| numeric.clamp(value, self.attributes))
+    }
+}
+
+impl fmt::Debug for FieldConstraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldConstraints")
+            .field("type_path", &self.type_path)
+            .field("read_only", &self.read_only)
+            .field("range", &self.range())
+            .field("default", &self.default_value())
+            .finish()
+    }
+}
+
+impl TypeRegistry {
+    /// Returns the [`FieldConstraints`] of a field of the struct or tuple struct with the given [`TypeId`].
+    ///
+    /// Tuple struct fields are given by their index, such as `"0"`.
+    ///
+    /// Returns `None` if the type is not registered, is not a struct or tuple struct,
+    /// or has no such field.
+    pub fn field_constraints(&self, type_id: TypeId, field: &str) -> Option<FieldConstraints> {
+        let (type_path, field_type_id, read_only, attributes) = match self.get(type_id)?.type_info()
+        {
+            TypeInfo::Struct(info) => {
+                let field = info.field(field)?;
+                (
+                    field.type_path(),
+                    field.type_id(),
+                    field.is_read_only(),
+                    field.custom_attributes(),
+                )
+            }
+            TypeInfo::TupleStruct(info) => {
+                let field = info.field_at(field.parse().ok()?)?;
+                (
+                    field.type_path(),
+                    field.type_id(),
+                    field.is_read_only(),
+                    field.custom_attributes(),
+                )
+            }
+            _ => return None,
+        };
+
+        Some(FieldConstraints {
+            type_path,
+            type_id: field_type_id,
+            read_only,
+            attributes,
+            numeric: self.get_type_data::<ReflectNumeric>(field_type_id).cloned(),
+            default: self.get_type_data::<ReflectDefault>(field_type_id).cloned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+    use std::ops::RangeInclusive;
+
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeRegistry};
+
+    #[derive(Reflect)]
+    struct Enemy {
+        #[reflect(@RangeInclusive::<u32>::new(1, 100))]
+        health: u32,
+        #[reflect(read_only)]
+        name: String,
+    }
+
+    #[derive(Reflect)]
+    struct Speed(#[reflect(@RangeInclusive::<f64>::new(0.0, 10.0))] f64);
+
+    #[test]
+    fn should_get_field_constraints() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Enemy>();
+        registry.register::<Speed>();
+
+        let health = registry
+            .field_constraints(TypeId::of::<Enemy>(), "health")
+            .unwrap();
+        assert!(health.is_numeric());
+        assert!(!health.is_read_only());
+        assert_eq!(Some(&1), health.min().unwrap().downcast_ref::<u32>());
+        assert_eq!(Some(&100), health.max().unwrap().downcast_ref::<u32>());
+        assert_eq!(
+            Some(&0),
+            health.default_value().unwrap().downcast_ref::<u32>()
+        );
+        assert_eq!(Some(false), health.contains(&0_u32));
+        assert_eq!(Some(true), health.contains(&50_u32));
+        assert_eq!(None, health.contains(&50_i32));
+
+        let name = registry
+            .field_constraints(TypeId::of::<Enemy>(), "name")
+            .unwrap();
+        assert!(name.is_read_only());
+        assert!(!name.is_numeric());
+        assert!(name.range().is_none());
+        assert!(!name.clamp(&mut String::new()));
+
+        let speed = registry
+            .field_constraints(TypeId::of::<Speed>(), "0")
+            .unwrap();
+        let mut value = -3.0_f64;
+        assert!(speed.clamp(&mut value));
+        assert_eq!(0.0, value);
+
+        assert!(registry
+            .field_constraints(TypeId::of::<Enemy>(), "mana")
+            .is_none());
+        assert!(registry
+            .field_constraints(TypeId::of::<Speed>(), "speed")
+            .is_none());
+    }
+}
@@ -0,0 +1,542 @@
+use crate::{ApplyError, Reflect, ReflectKind, ReflectMut, ReflectRef, VariantField};
+
+/// A structured description of how a reflected value changed.
+///
+/// A diff is created with [`diff`] and only describes the parts of a value that changed.
+/// Applying it to the old value with [`Diff::apply`] turns that value into the new one,
+/// which makes diffs useful as patches for things like network replication, undo history,
+/// and prefab overrides.
+///
+/// A diff doesn't borrow either value, so it can be stored and applied any number of times.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff, Diff, Reflect};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<u32>,
+/// }
+///
+/// let old = Player {
+///     name: "Ferris".to_string(),
+///     inventory: vec![1, 2],
+/// };
+/// let new = Player {
+///     name: "Ferris".to_string(),
+///     inventory: vec![1, 3, 4],
+/// };
+///
+/// // Only `inventory` changed
+/// let patch = diff(&old, &new);
+/// assert!(matches!(&patch, Diff::Struct(fields) if fields.len() == 1));
+///
+/// let mut value = old.clone();
+/// patch.apply(&mut value).unwrap();
+/// assert_eq!(new, value);
+/// ```
+#[derive(Debug)]
+pub enum Diff {
+    /// The value didn't change.
+    NoChange,
+    /// The value is replaced as a whole.
+    ///
+    /// This is used for [value types], values whose types differ, enums that changed variant,
+    /// and types whose shape changed (such as arrays of different lengths).
+    ///
+    /// [value types]: ReflectRef::Value
+    Replace(Box<dyn Reflect>),
+    /// Some fields of a struct, or of a struct variant, changed.
+    Struct(Vec<(String, Diff)>),
+    /// Some fields of a tuple, tuple struct, or tuple variant, or some elements of an array, changed.
+    Indexed(Vec<(usize, Diff)>),
+    /// Some elements of a list changed.
+    ///
+    /// Changes are listed in the order they need to be applied.
+    List(Vec<ListChange>),
+    /// Some entries of a map changed.
+    Map(Vec<MapChange>),
+}
+
+/// A change made to a single element of a list, as part of a [`Diff::List`].
+#[derive(Debug)]
+pub enum ListChange {
+    /// The element at the given index changed.
+    Modify(usize, Diff),
+    /// An element was inserted at the given index.
+    Insert(usize, Box<dyn Reflect>),
+    /// The element at the given index was removed.
+    Remove(usize),
+}
+
+/// A change made to a single entry of a map, as part of a [`Diff::Map`].
+#[derive(Debug)]
+pub enum MapChange {
+    /// The value of the given key changed.
+    Modify(Box<dyn Reflect>, Diff),
+    /// An entry was added.
+    Insert(Box<dyn Reflect>, Box<dyn Reflect>),
+    /// The entry with the given key was removed.
+    Remove(Box<dyn Reflect>),
+}
+
+/// Computes the [`Diff`] that turns `old` into `new`.
+///
+/// Structs, tuples, arrays, and enums that keep the same variant are compared field by field.
+/// Lists are compared element by element, with elements added or removed at the end.
+/// Maps are compared by key.
+///
+/// Values that can't be broken down any further are compared with [`Reflect::reflect_partial_eq`].
+/// If that comparison isn't supported, the value is always considered changed.
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> Diff {
+    let same_type = match (
+        old.get_represented_type_info(),
+        new.get_represented_type_info(),
+    ) {
+        (Some(old), Some(new)) => old.type_id() == new.type_id(),
+        _ => true,
+    };
+    if !same_type {
+        return Diff::Replace(new.clone_value());
+    }
+
+    let diff = match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => {
+            if old.field_len() != new.field_len() {
+                return Diff::Replace(new.clone_value());
+            }
+            let mut changes = Vec::new();
+            for (i, field) in new.iter_fields().enumerate() {
+                let name = new.name_at(i).unwrap();
+                let Some(old_field) = old.field(name) else {
+                    return Diff::Replace(new.clone_value());
+                };
+                push_change(&mut changes, name.to_string(), diff(old_field, field));
+            }
+            Diff::Struct(changes)
+        }
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+            if old.field_len() != new.field_len() {
+                return Diff::Replace(new.clone_value());
+            }
+            diff_indexed(old.iter_fields().zip(new.iter_fields()))
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => {
+            if old.field_len() != new.field_len() {
+                return Diff::Replace(new.clone_value());
+            }
+            diff_indexed(old.iter_fields().zip(new.iter_fields()))
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) => {
+            if old.len() != new.len() {
+                return Diff::Replace(new.clone_value());
+            }
+            diff_indexed(old.iter().zip(new.iter()))
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) => {
+            let mut changes = Vec::new();
+            for (i, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                let diff = diff(old, new);
+                if !diff.is_no_change() {
+                    changes.push(ListChange::Modify(i, diff));
+                }
+            }
+            for i in old.len()..new.len() {
+                changes.push(ListChange::Insert(i, new.get(i).unwrap().clone_value()));
+            }
+            for i in (new.len()..old.len()).rev() {
+                changes.push(ListChange::Remove(i));
+            }
+            Diff::List(changes)
+        }
+        (ReflectRef::Map(old), ReflectRef::Map(new)) => {
+            let mut changes = Vec::new();
+            for (key, value) in new.iter() {
+                match old.get(key) {
+                    Some(old_value) => {
+                        let diff = diff(old_value, value);
+                        if !diff.is_no_change() {
+                            changes.push(MapChange::Modify(key.clone_value(), diff));
+                        }
+                    }
+                    None => changes.push(MapChange::Insert(key.clone_value(), value.clone_value())),
+                }
+            }
+            for (key, _) in old.iter() {
+                if new.get(key).is_none() {
+                    changes.push(MapChange::Remove(key.clone_value()));
+                }
+            }
+            Diff::Map(changes)
+        }
+        (ReflectRef::Enum(old), ReflectRef::Enum(new)) => {
+            if old.variant_name() != new.variant_name() || old.field_len() != new.field_len() {
+                return Diff::Replace(new.clone_value());
+            }
+            let mut named = Vec::new();
+            let mut indexed = Vec::new();
+            for (i, field) in new.iter_fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, value) => {
+                        let Some(old_field) = old.field(name) else {
+                            return Diff::Replace(new.clone_value());
+                        };
+                        push_change(&mut named, name.to_string(), diff(old_field, value));
+                    }
+                    VariantField::Tuple(value) => {
+                        push_change(&mut indexed, i, diff(old.field_at(i).unwrap(), value));
+                    }
+                }
+            }
+            if indexed.is_empty() {
+                Diff::Struct(named)
+            } else {
+                Diff::Indexed(indexed)
+            }
+        }
+        _ => {
+            return match old.reflect_partial_eq(new) {
+                Some(true) => Diff::NoChange,
+                _ => Diff::Replace(new.clone_value()),
+            };
+        }
+    };
+
+    if diff.is_empty() {
+        Diff::NoChange
+    } else {
+        diff
+    }
+}
+
+impl Diff {
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_no_change(&self) -> bool {
+        matches!(self, Diff::NoChange)
+    }
+
+    /// Returns `true` if this diff contains no changes, either because it is
+    /// [`Diff::NoChange`] or because it is a collection of zero changes.
+    fn is_empty(&self) -> bool {
+        match self {
+            Diff::NoChange => true,
+            Diff::Replace(_) => false,
+            Diff::Struct(changes) => changes.is_empty(),
+            Diff::Indexed(changes) => changes.is_empty(),
+            Diff::List(changes) => changes.is_empty(),
+            Diff::Map(changes) => changes.is_empty(),
+        }
+    }
+
+    /// Applies this diff to `target`, which should be equal to the old value the diff was created from.
+    ///
+    /// Values marked for replacement are applied with [`Reflect::try_apply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ApplyError`] if `target` doesn't have the shape described by this diff,
+    /// such as when a field is missing or a list is too short.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ApplyError> {
+        match self {
+            Diff::NoChange => Ok(()),
+            Diff::Replace(value) => target.try_apply(value.as_ref()),
+            Diff::Struct(fields) => {
+                for (name, diff) in fields {
+                    diff.apply(named_field_mut(target, name)?)
+                        .map_err(|err| err.with_field(name))?;
+                }
+                Ok(())
+            }
+            Diff::Indexed(fields) => {
+                for (index, diff) in fields {
+                    diff.apply(indexed_field_mut(target, *index)?)
+                        .map_err(|err| err.with_field(index))?;
+                }
+                Ok(())
+            }
+            Diff::List(changes) => {
+                let list = match target.reflect_mut() {
+                    ReflectMut::List(list) => list,
+                    target => {
+                        return Err(ApplyError::MismatchedKinds {
+                            from_kind: ReflectKind::List,
+                            to_kind: target.kind(),
+                        })
+                    }
+                };
+                for change in changes {
+                    match change {
+                        ListChange::Modify(index, diff) => {
+                            let len = list.len();
+                            let item = list
+                                .get_mut(*index)
+                                .ok_or(ApplyError::IndexOutOfBounds { index: *index, len })?;
+                            diff.apply(item).map_err(|err| err.with_index(*index))?;
+                        }
+                        ListChange::Insert(index, value) => {
+                            if *index > list.len() {
+                                return Err(ApplyError::IndexOutOfBounds {
+                                    index: *index,
+                                    len: list.len(),
+                                });
+                            }
+                            list.insert(*index, value.clone_value());
+                        }
+                        ListChange::Remove(index) => {
+                            if *index >= list.len() {
+                                return Err(ApplyError::IndexOutOfBounds {
+                                    index: *index,
+                                    len: list.len(),
+                                });
+                            }
+                            list.remove(*index);
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Diff::Map(changes) => {
+                let map = match target.reflect_mut() {
+                    ReflectMut::Map(map) => map,
+                    target => {
+                        return Err(ApplyError::MismatchedKinds {
+                            from_kind: ReflectKind::Map,
+                            to_kind: target.kind(),
+                        })
+                    }
+                };
+                for change in changes {
+                    match change {
+                        MapChange::Modify(key, diff) => {
+                            let value = map.get_mut(key.as_ref()).ok_or_else(|| {
+                                ApplyError::MissingKey {
+                                    key: format!("{key:?}").into(),
+                                }
+                            })?;
+                            diff.apply(value)
+                                .map_err(|err| err.with_key(key.as_ref()))?;
+                        }
+                        MapChange::Insert(key, value) => {
+                            map.insert_boxed(key.clone_value(), value.clone_value());
+                        }
+                        MapChange::Remove(key) => {
+                            map.remove(key.as_ref());
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Applies `patch` to `target`, turning it into the value the patch was created from.
+///
+/// This is the same as calling [`Diff::apply`], and is mostly useful for patches
+/// read back with a [`DiffDeserializer`].
+///
+/// # Errors
+///
+/// Returns an [`ApplyError`] if `target` doesn't have the shape described by the patch.
+///
+/// [`DiffDeserializer`]: crate::serde::DiffDeserializer
+pub fn apply_patch(target: &mut dyn Reflect, patch: &Diff) -> Result<(), ApplyError> {
+    patch.apply(target)
+}
+
+/// Pushes `diff` onto `changes` if it changes anything.
+fn push_change<K>(changes: &mut Vec<(K, Diff)>, key: K, diff: Diff) {
+    if !diff.is_no_change() {
+        changes.push((key, diff));
+    }
+}
+
+/// Diffs pairs of fields or elements by their position.
+fn diff_indexed<'a>(pairs: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>) -> Diff {
+    let mut changes = Vec::new();
+    for (i, (old, new)) in pairs.enumerate() {
+        push_change(&mut changes, i, diff(old, new));
+    }
+    Diff::Indexed(changes)
+}
+
+/// Returns the field with the given name of a struct or struct variant.
+fn named_field_mut<'a>(
+    target: &'a mut dyn Reflect,
+    name: &str,
+) -> Result<&'a mut dyn Reflect, ApplyError> {
+    match target.reflect_mut() {
+        ReflectMut::Struct(target) => {
+            target
+                .field_mut(name)
+                .ok_or_else(|| ApplyError::MissingField {
+                    field_name: name.into(),
+                })
+        }
+        ReflectMut::Enum(target) => {
+            let variant_name: Box<str> = target.variant_name().into();
+            target
+                .field_mut(name)
+                .ok_or_else(|| ApplyError::MissingEnumField {
+                    variant_name,
+                    field_name: name.into(),
+                })
+        }
+        target => Err(ApplyError::MismatchedKinds {
+            from_kind: ReflectKind::Struct,
+            to_kind: target.kind(),
+        }),
+    }
+}
+
+/// Returns the field or element at the given index of a tuple, tuple struct, array, or tuple variant.
+fn indexed_field_mut(
+    target: &mut dyn Reflect,
+    index: usize,
+) -> Result<&mut dyn Reflect, ApplyError> {
+    let (field, len) = match target.reflect_mut() {
+        ReflectMut::TupleStruct(target) => {
+            let len = target.field_len();
+            (target.field_mut(index), len)
+        }
+        ReflectMut::Tuple(target) => {
+            let len = target.field_len();
+            (target.field_mut(index), len)
+        }
+        ReflectMut::Array(target) => {
+            let len = target.len();
+            (target.get_mut(index), len)
+        }
+        ReflectMut::Enum(target) => {
+            let len = target.field_len();
+            (target.field_at_mut(index), len)
+        }
+        target => {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: ReflectKind::Tuple,
+                to_kind: target.kind(),
+            })
+        }
+    };
+    field.ok_or(ApplyError::IndexOutOfBounds { index, len })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Status {
+        Idle,
+        Moving { speed: f32 },
+        Attacking(u32, bool),
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Unit {
+        name: String,
+        position: (f32, f32),
+        inventory: Vec<u32>,
+        stats: HashMap<String, i32>,
+        status: Status,
+    }
+
+    fn unit() -> Unit {
+        Unit {
+            name: "knight".to_string(),
+            position: (1.0, 2.0),
+            inventory: vec![1, 2, 3],
+            stats: [("hp".to_string(), 10), ("mp".to_string(), 5)]
+                .into_iter()
+                .collect(),
+            status: Status::Moving { speed: 1.0 },
+        }
+    }
+
+    #[test]
+    fn should_not_diff_equal_values() {
+        assert!(diff(&unit(), &unit()).is_no_change());
+        assert!(diff(&5_u32, &5_u32).is_no_change());
+    }
+
+    #[test]
+    fn should_only_diff_changed_fields() {
+        let old = unit();
+        let mut new = unit();
+        new.position.1 = 3.0;
+
+        let Diff::Struct(fields) = diff(&old, &new) else {
+            panic!("expected struct diff");
+        };
+        assert_eq!(1, fields.len());
+        assert_eq!("position", fields[0].0);
+        let Diff::Indexed(elements) = &fields[0].1 else {
+            panic!("expected indexed diff");
+        };
+        assert_eq!(1, elements.len());
+        assert_eq!(1, elements[0].0);
+    }
+
+    #[test]
+    fn should_diff_list_insertions_and_removals() {
+        let Diff::List(changes) = diff(&vec![1_u32, 2, 3], &vec![1_u32, 5]) else {
+            panic!("expected list diff");
+        };
+        assert!(matches!(changes[0], ListChange::Modify(1, _)));
+        assert!(matches!(changes[1], ListChange::Remove(2)));
+
+        let Diff::List(changes) = diff(&vec![1_u32], &vec![1_u32, 2]) else {
+            panic!("expected list diff");
+        };
+        assert!(matches!(changes[..], [ListChange::Insert(1, _)]));
+    }
+
+    #[test]
+    fn should_apply_diff_as_patch() {
+        let old = unit();
+        let mut new = unit();
+        new.name = "archer".to_string();
+        new.inventory = vec![1, 4];
+        new.stats.remove("mp");
+        new.stats.insert("hp".to_string(), 7);
+        new.stats.insert("xp".to_string(), 1);
+        new.status = Status::Attacking(3, true);
+
+        let patch = diff(&old, &new);
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(new, value);
+
+        // Diffs can be applied more than once
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(new, value);
+
+        let mut attacking = new.clone();
+        attacking.status = Status::Attacking(4, true);
+        let patch = diff(&new, &attacking);
+        assert!(matches!(&patch, Diff::Struct(fields) if matches!(fields[0].1, Diff::Indexed(_))));
+        patch.apply(&mut new).unwrap();
+        assert_eq!(attacking, new);
+    }
+
+    #[test]
+    fn should_report_path_when_patch_does_not_fit() {
+        let patch = diff(&vec![1_u32, 2, 3], &vec![1_u32, 2, 4]);
+
+        let mut short = unit();
+        short.inventory = vec![1];
+        let patch = Diff::Struct(vec![("inventory".to_string(), patch)]);
+
+        let error = patch.apply(&mut short).unwrap_err();
+        assert_eq!(Some(".inventory"), error.path());
+        assert!(matches!(
+            error.root_cause(),
+            ApplyError::IndexOutOfBounds { index: 2, len: 1 }
+        ));
+    }
+}
@@ -0,0 +1,199 @@
+use bevy_utils::HashMap;
+
+use crate::{self as bevy_reflect, Reflect, TypeInfo, VariantInfo};
+
+/// A [custom attribute] giving a human-readable name to a field or variant.
+///
+/// Inspectors and generated UI can show this name instead of the identifier used in code,
+/// while [reflection paths] and serialization keep using the identifier.
+/// Names can be translated at runtime using a [`DisplayNameProvider`], see [`display_name`].
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, DisplayName, TypeInfo, Typed};
+/// #[derive(Reflect)]
+/// struct Vehicle {
+///     #[reflect(@DisplayName("Top speed"))]
+///     max_speed: f32,
+/// }
+///
+/// assert_eq!(Some("Top speed"), DisplayName::of(Vehicle::type_info(), "max_speed"));
+/// ```
+///
+/// [custom attribute]: crate::attributes::CustomAttributes
+/// [reflection paths]: crate::GetPath
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DisplayName(pub &'static str);
+
+impl DisplayName {
+    /// Returns the [`DisplayName`] attribute of a member of the type described by `info`.
+    ///
+    /// Members are named as follows:
+    /// - struct fields by their name, such as `max_speed`
+    /// - tuple struct fields by their index, such as `0`
+    /// - enum variants by their name, such as `Idle`
+    /// - enum variant fields by their variant and their name or index, such as `Moving::speed`
+    pub fn of(info: &'static TypeInfo, member: &str) -> Option<&'static str> {
+        let attributes = match info {
+            TypeInfo::Struct(info) => info.field(member)?.custom_attributes(),
+            TypeInfo::TupleStruct(info) => info.field_at(member.parse().ok()?)?.custom_attributes(),
+            TypeInfo::Enum(info) => match member.split_once("::") {
+                None => info.variant(member)?.custom_attributes(),
+                Some((variant, field)) => match info.variant(variant)? {
+                    VariantInfo::Struct(variant) => variant.field(field)?.custom_attributes(),
+                    VariantInfo::Tuple(variant) => {
+                        variant.field_at(field.parse().ok()?)?.custom_attributes()
+                    }
+                    VariantInfo::Unit(_) => return None,
+                },
+            },
+            _ => return None,
+        };
+        attributes.get::<DisplayName>().map(|name| name.0)
+    }
+}
+
+/// A runtime source of display names, used to translate the labels of fields and variants.
+///
+/// Names are looked up by the [type path] of the containing type and the name of the member,
+/// using the same member names as [`DisplayName::of`].
+///
+/// [type path]: crate::TypePath::type_path
+pub trait DisplayNameProvider: Send + Sync {
+    /// Returns the display name of `member` of the type with the given type path,
+    /// or [`None`] if this provider has no name for it.
+    fn display_name(&self, type_path: &str, member: &str) -> Option<&str>;
+}
+
+/// A table of display names, such as the translations for a single language.
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, display_name, DisplayName, DisplayNames, Typed};
+/// #[derive(Reflect)]
+/// struct Vehicle {
+///     #[reflect(@DisplayName("Top speed"))]
+///     max_speed: f32,
+///     wheels: u32,
+/// }
+///
+/// let french = DisplayNames::new().with(Vehicle::type_path(), "max_speed", "Vitesse maximale");
+///
+/// let info = Vehicle::type_info();
+/// assert_eq!("Vitesse maximale", display_name(info, "max_speed", &french));
+/// assert_eq!("Top speed", display_name(info, "max_speed", &DisplayNames::new()));
+/// assert_eq!("wheels", display_name(info, "wheels", &french));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DisplayNames {
+    names: HashMap<(String, String), String>,
+}
+
+impl DisplayNames {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the display name of `member` of the type with the given type path.
+    pub fn with(
+        mut self,
+        type_path: impl Into<String>,
+        member: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.insert(type_path, member, name);
+        self
+    }
+
+    /// Sets the display name of `member` of the type with the given type path,
+    /// returning the previous name if there was one.
+    pub fn insert(
+        &mut self,
+        type_path: impl Into<String>,
+        member: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Option<String> {
+        self.names
+            .insert((type_path.into(), member.into()), name.into())
+    }
+
+    /// Returns the number of names in the table.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if the table contains no names.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl DisplayNameProvider for DisplayNames {
+    fn display_name(&self, type_path: &str, member: &str) -> Option<&str> {
+        self.names
+            .get(&(type_path.to_owned(), member.to_owned()))
+            .map(String::as_str)
+    }
+}
+
+/// Returns the name to display for `member` of the type described by `info`.
+///
+/// The name is taken from the first of the following that has one:
+/// 1. the `provider`
+/// 2. the [`DisplayName`] attribute of the member
+/// 3. the member itself
+pub fn display_name<'a>(
+    info: &'static TypeInfo,
+    member: &'a str,
+    provider: &'a dyn DisplayNameProvider,
+) -> &'a str {
+    provider
+        .display_name(info.type_path(), member)
+        .or_else(|| DisplayName::of(info, member))
+        .unwrap_or(member)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TypePath, Typed};
+
+    #[derive(Reflect)]
+    enum State {
+        #[reflect(@DisplayName("Waiting"))]
+        Idle,
+        Moving {
+            #[reflect(@DisplayName("Speed"))]
+            speed: f32,
+        },
+        Turning(#[reflect(@DisplayName("Angle"))] f32),
+    }
+
+    #[derive(Reflect)]
+    struct Wheel(#[reflect(@DisplayName("Radius"))] f32);
+
+    #[test]
+    fn should_get_display_name_attributes() {
+        let info = State::type_info();
+        assert_eq!(Some("Waiting"), DisplayName::of(info, "Idle"));
+        assert_eq!(Some("Speed"), DisplayName::of(info, "Moving::speed"));
+        assert_eq!(Some("Angle"), DisplayName::of(info, "Turning::0"));
+        assert_eq!(None, DisplayName::of(info, "Moving"));
+        assert_eq!(None, DisplayName::of(info, "Idle::speed"));
+
+        assert_eq!(Some("Radius"), DisplayName::of(Wheel::type_info(), "0"));
+        assert_eq!(None, DisplayName::of(Wheel::type_info(), "radius"));
+    }
+
+    #[test]
+    fn should_prefer_provider_names() {
+        let french = DisplayNames::new()
+            .with(State::type_path(), "Idle", "En attente")
+            .with(State::type_path(), "Moving", "En mouvement");
+
+        let info = State::type_info();
+        assert_eq!("En attente", display_name(info, "Idle", &french));
+        assert_eq!("En mouvement", display_name(info, "Moving", &french));
+        assert_eq!("Speed", display_name(info, "Moving::speed", &french));
+        assert_eq!("Turning", display_name(info, "Turning", &french));
+    }
+}
@@ -2,11 +2,12 @@ use bevy_reflect_derive::impl_type_path;
 
 use crate::{
     self as bevy_reflect, enum_debug, enum_hash, enum_partial_eq, ApplyError, DynamicStruct,
-    DynamicTuple, Enum, Reflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, Struct, Tuple,
-    TypeInfo, VariantFieldIter, VariantType,
+    DynamicTuple, Enum, Reflect, ReflectCloneError, ReflectKind, ReflectMut, ReflectOwned,
+    ReflectRef, Struct, Tuple, TypeInfo, VariantFieldIter, VariantInfo, VariantType,
 };
 use std::any::Any;
 use std::fmt::Formatter;
+use thiserror::Error;
 
 /// A dynamic representation of an enum variant.
 #[derive(Debug, Default)]
@@ -117,6 +118,9 @@ impl DynamicEnum {
 
     /// Sets the [type] to be represented by this `DynamicEnum`.
     ///
+    /// The fields of a struct variant are reordered to follow their declaration order in the given type,
+    /// so that they are indexed like the fields of the concrete enum.
+    ///
     /// # Panics
     ///
     /// Panics if the given [type] is not a [`TypeInfo::Enum`].
@@ -132,12 +136,14 @@ impl DynamicEnum {
         }
 
         self.represented_type = represented_type;
+        self.sort_struct_fields();
     }
 
     /// Set the current enum variant represented by this struct.
     pub fn set_variant<I: Into<String>, V: Into<DynamicVariant>>(&mut self, name: I, variant: V) {
         self.variant_name = name.into();
         self.variant = variant.into();
+        self.sort_struct_fields();
     }
 
     /// Set the current enum variant represented by this struct along with its variant index.
@@ -150,6 +156,7 @@ impl DynamicEnum {
         self.variant_index = variant_index;
         self.variant_name = variant_name.into();
         self.variant = variant.into();
+        self.sort_struct_fields();
     }
 
     /// Create a [`DynamicEnum`] from an existing one.
@@ -163,6 +170,8 @@ impl DynamicEnum {
     ///
     /// This is functionally the same as [`DynamicEnum::from`] except it takes a reference.
     pub fn from_ref<TEnum: Enum>(value: &TEnum) -> Self {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         let type_info = value.get_represented_type_info();
         let mut dyn_enum = match value.variant_type() {
             VariantType::Unit => DynamicEnum::new_with_index(
@@ -198,6 +207,166 @@ impl DynamicEnum {
         dyn_enum.set_represented_type(type_info);
         dyn_enum
     }
+
+    /// Create a [`DynamicEnum`] for the named struct variant of the given enum type,
+    /// using the fields of a [`DynamicStruct`].
+    ///
+    /// The variant index and represented type are resolved from `type_info`.
+    /// The struct may contain a subset of the variant's fields,
+    /// which are reordered to follow the declaration order of the variant.
+    /// An empty struct may also be used to create a unit variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StructVariantError`] if the enum has no variant with the given name,
+    /// if that variant isn't a struct variant, or if the struct contains a field the variant does not have.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given [type] is not a [`TypeInfo::Enum`].
+    ///
+    /// [type]: TypeInfo
+    pub fn from_struct_variant(
+        type_info: &'static TypeInfo,
+        variant_name: &str,
+        data: DynamicStruct,
+    ) -> Result<Self, StructVariantError> {
+        let TypeInfo::Enum(enum_info) = type_info else {
+            panic!("expected TypeInfo::Enum but received: {type_info:?}");
+        };
+
+        let (variant_index, variant_info) = enum_info
+            .index_of(variant_name)
+            .zip(enum_info.variant(variant_name))
+            .ok_or_else(|| StructVariantError::UnknownVariant {
+                enum_name: enum_info.type_path().into(),
+                variant_name: variant_name.into(),
+            })?;
+
+        let variant = match variant_info {
+            VariantInfo::Struct(info) => {
+                for index in 0..data.field_len() {
+                    let name = data.name_at(index).unwrap();
+                    if info.field(name).is_none() {
+                        return Err(StructVariantError::UnknownField {
+                            variant_name: variant_name.into(),
+                            field_name: name.into(),
+                        });
+                    }
+                }
+                DynamicVariant::Struct(data)
+            }
+            VariantInfo::Unit(_) if data.field_len() == 0 => DynamicVariant::Unit,
+            _ => {
+                return Err(StructVariantError::NotStructVariant {
+                    variant_name: variant_name.into(),
+                    variant_type: match variant_info {
+                        VariantInfo::Struct(_) => VariantType::Struct,
+                        VariantInfo::Tuple(_) => VariantType::Tuple,
+                        VariantInfo::Unit(_) => VariantType::Unit,
+                    },
+                });
+            }
+        };
+
+        let mut dyn_enum = Self::new_with_index(variant_index, variant_name, variant);
+        dyn_enum.set_represented_type(Some(type_info));
+        Ok(dyn_enum)
+    }
+
+    /// Reorders the fields of a struct variant to follow their declaration order in the represented type.
+    ///
+    /// Fields which aren't part of the represented variant are kept after the others, in insertion order.
+    fn sort_struct_fields(&mut self) {
+        let DynamicVariant::Struct(data) = &mut self.variant else {
+            return;
+        };
+        let Some(TypeInfo::Enum(enum_info)) = self.represented_type else {
+            return;
+        };
+        let Some(VariantInfo::Struct(info)) = enum_info.variant(&self.variant_name) else {
+            return;
+        };
+
+        let declaration_index = |index: usize| {
+            info.index_of(data.name_at(index).unwrap())
+                .unwrap_or(usize::MAX)
+        };
+        if (1..data.field_len())
+            .all(|index| declaration_index(index - 1) <= declaration_index(index))
+        {
+            return;
+        }
+
+        let mut sorted = DynamicStruct::default();
+        sorted.set_represented_type(data.get_represented_type_info());
+        for field in info.iter() {
+            if let Some(value) = data.remove(field.name()) {
+                sorted.insert_boxed(field.name(), value);
+            }
+        }
+        while let Some(name) = data.name_at(0).map(ToString::to_string) {
+            let value = data.remove(&name).unwrap();
+            sorted.insert_boxed(name, value);
+        }
+        *data = sorted;
+    }
+
+    /// Inserts a named field into the current variant, replacing any existing field with that name.
+    ///
+    /// If the current variant is a unit variant, it becomes a struct variant containing just this field.
+    /// With a represented type, the field is placed according to its declaration order in the variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current variant is a tuple variant, since its fields cannot be named.
+    pub fn insert_field_boxed(&mut self, name: &str, value: Box<dyn Reflect>) {
+        match &mut self.variant {
+            DynamicVariant::Struct(data) => data.insert_boxed(name, value),
+            DynamicVariant::Unit => {
+                let mut data = DynamicStruct::default();
+                data.insert_boxed(name, value);
+                self.variant = DynamicVariant::Struct(data);
+            }
+            DynamicVariant::Tuple(_) => panic!(
+                "cannot insert field `{name}` into tuple variant `{}`",
+                self.variant_name
+            ),
+        }
+        self.sort_struct_fields();
+    }
+
+    /// Inserts a typed named field into the current variant, replacing any existing field with that name.
+    ///
+    /// See [`DynamicEnum::insert_field_boxed`] for details.
+    pub fn insert_field<T: Reflect>(&mut self, name: &str, value: T) {
+        self.insert_field_boxed(name, Box::new(value));
+    }
+}
+
+/// An error returned by [`DynamicEnum::from_struct_variant`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StructVariantError {
+    #[error("variant with name `{variant_name}` does not exist on enum `{enum_name}`")]
+    /// The enum doesn't have a variant with the given name.
+    UnknownVariant {
+        enum_name: Box<str>,
+        variant_name: Box<str>,
+    },
+
+    #[error("struct variant `{variant_name}` doesn't have a field named `{field_name}`")]
+    /// The struct contained a field that the variant doesn't have.
+    UnknownField {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+    },
+
+    #[error("variant `{variant_name}` is a {variant_type:?} variant, not a struct variant")]
+    /// The variant isn't a struct variant, nor a unit variant built from an empty struct.
+    NotStructVariant {
+        variant_name: Box<str>,
+        variant_type: VariantType,
+    },
 }
 
 impl Enum for DynamicEnum {
@@ -210,10 +379,10 @@ impl Enum for DynamicEnum {
     }
 
     fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
-        if let DynamicVariant::Tuple(data) = &self.variant {
-            data.field(index)
-        } else {
-            None
+        match &self.variant {
+            DynamicVariant::Unit => None,
+            DynamicVariant::Tuple(data) => data.field(index),
+            DynamicVariant::Struct(data) => data.field_at(index),
         }
     }
 
@@ -226,10 +395,10 @@ impl Enum for DynamicEnum {
     }
 
     fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        if let DynamicVariant::Tuple(data) = &mut self.variant {
-            data.field_mut(index)
-        } else {
-            None
+        match &mut self.variant {
+            DynamicVariant::Unit => None,
+            DynamicVariant::Tuple(data) => data.field_mut(index),
+            DynamicVariant::Struct(data) => data.field_at_mut(index),
         }
     }
 
@@ -278,6 +447,8 @@ impl Enum for DynamicEnum {
     }
 
     fn clone_dynamic(&self) -> DynamicEnum {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         Self {
             represented_type: self.represented_type,
             variant_index: self.variant_index,
@@ -325,6 +496,8 @@ impl Reflect for DynamicEnum {
 
     #[inline]
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         if let ReflectRef::Enum(value) = value.reflect_ref() {
             if Enum::variant_name(self) == value.variant_name() {
                 // Same variant -> just update fields
@@ -333,14 +506,16 @@ impl Reflect for DynamicEnum {
                         for field in value.iter_fields() {
                             let name = field.name().unwrap();
                             if let Some(v) = Enum::field_mut(self, name) {
-                                v.try_apply(field.value())?;
+                                v.try_apply(field.value())
+                                    .map_err(|err| err.with_field(name))?;
                             }
                         }
                     }
                     VariantType::Tuple => {
                         for (index, field) in value.iter_fields().enumerate() {
                             if let Some(v) = Enum::field_at_mut(self, index) {
-                                v.try_apply(field.value())?;
+                                v.try_apply(field.value())
+                                    .map_err(|err| err.with_field(index))?;
                             }
                         }
                     }
@@ -405,9 +580,15 @@ impl Reflect for DynamicEnum {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         enum_hash(self)
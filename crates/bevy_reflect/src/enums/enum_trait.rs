@@ -1,5 +1,6 @@
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
-use crate::{DynamicEnum, Reflect, TypePath, TypePathTable, VariantInfo, VariantType};
+use crate::generics::{impl_generic_info_methods, Generics};
+use crate::{DynamicEnum, Reflect, TypeInfo, TypePath, TypePathTable, VariantInfo, VariantType};
 use bevy_utils::HashMap;
 use std::any::{Any, TypeId};
 use std::slice::Iter;
@@ -130,6 +131,15 @@ pub trait Enum: Reflect {
     fn variant_path(&self) -> String {
         format!("{}::{}", self.reflect_type_path(), self.variant_name())
     }
+    /// Returns the [`VariantInfo`] of the current variant, if the represented type is known.
+    ///
+    /// This gives access to the docs and custom attributes of the variant a value is currently set to.
+    fn get_represented_variant_info(&self) -> Option<&'static VariantInfo> {
+        match self.get_represented_type_info()? {
+            TypeInfo::Enum(info) => info.variant(self.variant_name()),
+            _ => None,
+        }
+    }
 }
 
 /// A container for compile-time enum info, used by [`TypeInfo`](crate::TypeInfo).
@@ -141,11 +151,21 @@ pub struct EnumInfo {
     variant_names: Box<[&'static str]>,
     variant_indices: HashMap<&'static str, usize>,
     custom_attributes: Arc<CustomAttributes>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl EnumInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`EnumInfo`].
     ///
     /// # Arguments
@@ -168,6 +188,7 @@ impl EnumInfo {
             variant_names,
             variant_indices,
             custom_attributes: Arc::new(CustomAttributes::default()),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -199,6 +220,17 @@ impl EnumInfo {
             .map(|index| &self.variants[*index])
     }
 
+    /// Get the variant with the given name, falling back to a variant with the given [alias].
+    ///
+    /// [alias]: VariantInfo::aliases
+    pub fn variant_or_alias(&self, name: &str) -> Option<&VariantInfo> {
+        self.variant(name).or_else(|| {
+            self.variants
+                .iter()
+                .find(|variant| variant.aliases().contains(&name))
+        })
+    }
+
     /// Get a variant at the given index.
     pub fn variant_at(&self, index: usize) -> Option<&VariantInfo> {
         self.variants.get(index)
@@ -264,6 +296,8 @@ impl EnumInfo {
         self.docs
     }
 
+    impl_generic_info_methods!(self.generics);
+
     impl_custom_attribute_methods!(self.custom_attributes, "enum");
 }
 
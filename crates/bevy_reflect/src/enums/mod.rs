@@ -305,21 +305,25 @@ mod tests {
         let mut target = MyEnumAnalogue::A(0);
 
         // === Tuple === //
-        let result = target.try_apply(&MyEnum::B(0, 1));
+        let error = target.try_apply(&MyEnum::B(0, 1)).unwrap_err();
+        assert_eq!(Some(".1"), error.path());
         assert!(
-            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
-            "`result` was {result:?}"
+            matches!(error.root_cause(), ApplyError::MismatchedTypes { .. }),
+            "`error` was {error:?}"
         );
 
         // === Struct === //
         target = MyEnumAnalogue::C { foo: 0.0, bar: 1 };
-        let result = target.try_apply(&MyEnum::C {
-            foo: 1.0,
-            bar: true,
-        });
+        let error = target
+            .try_apply(&MyEnum::C {
+                foo: 1.0,
+                bar: true,
+            })
+            .unwrap_err();
+        assert_eq!(Some(".bar"), error.path());
         assert!(
-            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
-            "`result` was {result:?}"
+            matches!(error.root_cause(), ApplyError::MismatchedTypes { .. }),
+            "`error` was {error:?}"
         );
         // Type mismatch should occur after partial application.
         assert_eq!(target, MyEnumAnalogue::C { foo: 1.0, bar: 1 });
@@ -666,4 +670,88 @@ mod tests {
             "expected TestEnum::C{{value: 123}} != TestEnum::C2{{value: 1.23}}"
         );
     }
+
+    #[test]
+    fn dynamic_enum_should_access_struct_variant_fields_by_name_and_index() {
+        let mut data = DynamicStruct::default();
+        data.insert("bar", true);
+        let mut value = DynamicEnum::from_struct_variant(MyEnum::type_info(), "C", data).unwrap();
+        assert_eq!(2, value.variant_index());
+
+        assert_eq!(
+            Some(&true),
+            value.field_at(0).unwrap().downcast_ref::<bool>()
+        );
+
+        // Fields are indexed in the declaration order of the variant, not in insertion order
+        value.insert_field("foo", 1.23_f32);
+        assert_eq!(
+            Some(&true),
+            value.field("bar").unwrap().downcast_ref::<bool>()
+        );
+        assert_eq!(
+            Some(&true),
+            value.field_at(1).unwrap().downcast_ref::<bool>()
+        );
+        assert_eq!(Some("foo"), value.name_at(0));
+        assert_eq!(Some(1), value.index_of("bar"));
+        *value
+            .field_at_mut(0)
+            .unwrap()
+            .downcast_mut::<f32>()
+            .unwrap() = 3.21;
+        assert_eq!(
+            Some(&3.21),
+            value.field("foo").unwrap().downcast_ref::<f32>()
+        );
+
+        let field_names = value
+            .iter_fields()
+            .map(|field| field.name().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["foo", "bar"], field_names);
+
+        let mut my_enum = MyEnum::A;
+        my_enum.apply(&value);
+        assert_eq!(
+            MyEnum::C {
+                foo: 3.21,
+                bar: true
+            },
+            my_enum
+        );
+    }
+
+    #[test]
+    fn dynamic_enum_from_struct_variant_should_validate_fields() {
+        let mut data = DynamicStruct::default();
+        data.insert("baz", 1_u8);
+        assert_eq!(
+            Some(StructVariantError::UnknownField {
+                variant_name: "C".into(),
+                field_name: "baz".into(),
+            }),
+            DynamicEnum::from_struct_variant(MyEnum::type_info(), "C", data).err()
+        );
+
+        assert!(matches!(
+            DynamicEnum::from_struct_variant(MyEnum::type_info(), "D", DynamicStruct::default()),
+            Err(StructVariantError::UnknownVariant { .. })
+        ));
+
+        let mut data = DynamicStruct::default();
+        data.insert("0", 1_usize);
+        assert_eq!(
+            Some(StructVariantError::NotStructVariant {
+                variant_name: "B".into(),
+                variant_type: VariantType::Tuple,
+            }),
+            DynamicEnum::from_struct_variant(MyEnum::type_info(), "B", data).err()
+        );
+
+        let unit =
+            DynamicEnum::from_struct_variant(MyEnum::type_info(), "A", DynamicStruct::default())
+                .unwrap();
+        assert_eq!(VariantType::Unit, unit.variant_type());
+    }
 }
@@ -75,9 +75,21 @@ impl VariantInfo {
         }
     }
 
+    /// Alternative names the underlying variant is known by.
+    ///
+    /// Aliases are accepted in place of the variant's name when deserializing.
+    /// They are set using `#[reflect(alias = "...")]`.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::Struct(info) => info.aliases(),
+            Self::Tuple(info) => info.aliases(),
+            Self::Unit(info) => info.aliases(),
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
-    pub fn docs(&self) -> Option<&str> {
+    pub fn docs(&self) -> Option<&'static str> {
         match self {
             Self::Struct(info) => info.docs(),
             Self::Tuple(info) => info.docs(),
@@ -100,6 +112,7 @@ impl VariantInfo {
 #[derive(Clone, Debug)]
 pub struct StructVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
@@ -115,6 +128,7 @@ impl StructVariantInfo {
         let field_names = fields.iter().map(NamedField::name).collect();
         Self {
             name,
+            aliases: &[],
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
@@ -138,11 +152,21 @@ impl StructVariantInfo {
         }
     }
 
+    /// Sets the alternative names this variant is known by.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// Alternative names this variant is known by, such as names it had before being renamed.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -155,6 +179,14 @@ impl StructVariantInfo {
             .map(|index| &self.fields[*index])
     }
 
+    /// Get the field with the given name, falling back to a field with the given [alias].
+    ///
+    /// [alias]: NamedField::aliases
+    pub fn field_or_alias(&self, name: &str) -> Option<&NamedField> {
+        self.field(name)
+            .or_else(|| self.fields.iter().find(|field| field.is_named(name)))
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&NamedField> {
         self.fields.get(index)
@@ -196,6 +228,7 @@ impl StructVariantInfo {
 #[derive(Clone, Debug)]
 pub struct TupleVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     fields: Box<[UnnamedField]>,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
@@ -207,6 +240,7 @@ impl TupleVariantInfo {
     pub fn new(name: &'static str, fields: &[UnnamedField]) -> Self {
         Self {
             name,
+            aliases: &[],
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
@@ -228,11 +262,21 @@ impl TupleVariantInfo {
         }
     }
 
+    /// Sets the alternative names this variant is known by.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// Alternative names this variant is known by, such as names it had before being renamed.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -261,6 +305,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -271,6 +316,7 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            aliases: &[],
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -291,11 +337,21 @@ impl UnitVariantInfo {
         }
     }
 
+    /// Sets the alternative names this variant is known by.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// Alternative names this variant is known by, such as names it had before being renamed.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
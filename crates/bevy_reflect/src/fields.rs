@@ -7,9 +7,11 @@ use std::sync::Arc;
 #[derive(Clone, Debug)]
 pub struct NamedField {
     name: &'static str,
+    aliases: &'static [&'static str],
     type_path: TypePathTable,
     type_id: TypeId,
     custom_attributes: Arc<CustomAttributes>,
+    read_only: bool,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -19,9 +21,11 @@ impl NamedField {
     pub fn new<T: Reflect + TypePath>(name: &'static str) -> Self {
         Self {
             name,
+            aliases: &[],
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            read_only: false,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -41,11 +45,41 @@ impl NamedField {
         }
     }
 
+    /// Sets whether this field is read-only.
+    ///
+    /// See [`is_read_only`](Self::is_read_only) for details.
+    pub fn with_read_only(self, read_only: bool) -> Self {
+        Self { read_only, ..self }
+    }
+
+    /// Sets the alternative names this field is known by.
+    ///
+    /// See [`aliases`](Self::aliases) for details.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// Alternative names this field is known by, such as names it had before being renamed.
+    ///
+    /// Aliases are accepted in place of the field's [name](Self::name) when deserializing
+    /// and when accessing the field through a [reflection path].
+    /// They are set using `#[reflect(alias = "...")]`.
+    ///
+    /// [reflection path]: crate::ReflectPath
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns true if the given name is this field's name or one of its [aliases](Self::aliases).
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+
     /// A representation of the type path of the field.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -73,6 +107,15 @@ impl NamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// Whether this field is read-only.
+    ///
+    /// Read-only fields are visible to reflection and serialization,
+    /// but cannot be mutably accessed or changed by applying a value to their container.
+    /// This is set for fields marked with `#[reflect(read_only)]`.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -89,6 +132,7 @@ pub struct UnnamedField {
     type_path: TypePathTable,
     type_id: TypeId,
     custom_attributes: Arc<CustomAttributes>,
+    read_only: bool,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -100,6 +144,7 @@ impl UnnamedField {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            read_only: false,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -119,6 +164,13 @@ impl UnnamedField {
         }
     }
 
+    /// Sets whether this field is read-only.
+    ///
+    /// See [`is_read_only`](Self::is_read_only) for details.
+    pub fn with_read_only(self, read_only: bool) -> Self {
+        Self { read_only, ..self }
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
@@ -151,6 +203,15 @@ impl UnnamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// Whether this field is read-only.
+    ///
+    /// Read-only fields are visible to reflection and serialization,
+    /// but cannot be mutably accessed or changed by applying a value to their container.
+    /// This is set for fields marked with `#[reflect(read_only)]`.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -0,0 +1,349 @@
+use crate::{Reflect, TypePath, TypePathTable};
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// The generic parameters of a type, along with the concrete arguments they were instantiated with.
+///
+/// This is stored on the [`TypeInfo`] of a type so that tools like code generators and schema
+/// exporters can recover the arguments of a generic type (such as `T` in `Vec<T>`)
+/// without parsing its type path.
+///
+/// Lifetime parameters are not included.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{GenericInfo, Reflect, Typed};
+/// #[derive(Reflect)]
+/// struct Buffer<T: Reflect, const N: usize> {
+///     items: [T; N],
+/// }
+///
+/// let generics = <Buffer<f32, 4> as Typed>::type_info().generics();
+///
+/// let GenericInfo::Type(item) = generics.get_named("T").unwrap() else {
+///     panic!("expected type parameter");
+/// };
+/// assert!(item.is::<f32>());
+///
+/// let GenericInfo::Const(len) = generics.get_named("N").unwrap() else {
+///     panic!("expected const parameter");
+/// };
+/// assert_eq!(Some(&4), len.value::<usize>());
+/// ```
+///
+/// [`TypeInfo`]: crate::TypeInfo
+#[derive(Clone, Default, Debug)]
+pub struct Generics(Box<[GenericInfo]>);
+
+impl Generics {
+    /// Creates an empty set of generics.
+    pub fn new() -> Self {
+        Self(Box::new([]))
+    }
+
+    /// Appends a generic parameter to this set.
+    pub fn with(mut self, info: impl Into<GenericInfo>) -> Self {
+        let mut infos = self.0.into_vec();
+        infos.push(info.into());
+        self.0 = infos.into_boxed_slice();
+        self
+    }
+
+    /// Gets the generic parameter with the given name, if any.
+    pub fn get_named(&self, name: &str) -> Option<&GenericInfo> {
+        self.0.iter().find(|info| info.name() == name)
+    }
+
+    /// Gets the generic parameter at the given position, if any.
+    pub fn get(&self, index: usize) -> Option<&GenericInfo> {
+        self.0.get(index)
+    }
+}
+
+impl Deref for Generics {
+    type Target = [GenericInfo];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<GenericInfo> for Generics {
+    fn from_iter<T: IntoIterator<Item = GenericInfo>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// A single generic parameter of a type.
+#[derive(Clone, Debug)]
+pub enum GenericInfo {
+    Type(TypeParamInfo),
+    Const(ConstParamInfo),
+}
+
+impl GenericInfo {
+    /// The name of the parameter, such as `T` or `N`.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Type(info) => info.name(),
+            Self::Const(info) => info.name(),
+        }
+    }
+
+    /// Returns `true` if this is a const parameter.
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    /// The [`TypeId`] of the argument for a type parameter, or of the value's type for a const parameter.
+    pub fn type_id(&self) -> TypeId {
+        match self {
+            Self::Type(info) => info.type_id(),
+            Self::Const(info) => info.type_id(),
+        }
+    }
+
+    /// The [`TypePathTable`] of the argument for a type parameter,
+    /// or of the value's type for a const parameter.
+    pub fn type_path_table(&self) -> &TypePathTable {
+        match self {
+            Self::Type(info) => info.type_path_table(),
+            Self::Const(info) => info.type_path_table(),
+        }
+    }
+}
+
+impl From<TypeParamInfo> for GenericInfo {
+    fn from(info: TypeParamInfo) -> Self {
+        Self::Type(info)
+    }
+}
+
+impl From<ConstParamInfo> for GenericInfo {
+    fn from(info: ConstParamInfo) -> Self {
+        Self::Const(info)
+    }
+}
+
+/// A type parameter, such as `T`, along with the type it was instantiated with.
+#[derive(Clone, Debug)]
+pub struct TypeParamInfo {
+    name: Cow<'static, str>,
+    type_path: TypePathTable,
+    type_id: TypeId,
+}
+
+impl TypeParamInfo {
+    /// Creates a new type parameter with the given name, instantiated with `T`.
+    pub fn new<T: TypePath + ?Sized>(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// The name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A representation of the type path of the argument.
+    ///
+    /// Provides dynamic access to all methods on [`TypePath`].
+    pub fn type_path_table(&self) -> &TypePathTable {
+        &self.type_path
+    }
+
+    /// The [stable, full type path] of the argument.
+    ///
+    /// [stable, full type path]: TypePath
+    pub fn type_path(&self) -> &'static str {
+        self.type_path.path()
+    }
+
+    /// The [`TypeId`] of the argument.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the argument.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
+
+/// A const parameter, such as `N`, along with the value it was instantiated with.
+#[derive(Clone)]
+pub struct ConstParamInfo {
+    name: Cow<'static, str>,
+    type_path: TypePathTable,
+    type_id: TypeId,
+    value: Arc<dyn Reflect>,
+}
+
+impl ConstParamInfo {
+    /// Creates a new const parameter with the given name, instantiated with `value`.
+    pub fn new<T: Reflect + TypePath>(name: impl Into<Cow<'static, str>>, value: T) -> Self {
+        Self {
+            name: name.into(),
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            value: Arc::new(value),
+        }
+    }
+
+    /// The name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A representation of the type path of the parameter's type.
+    ///
+    /// Provides dynamic access to all methods on [`TypePath`].
+    pub fn type_path_table(&self) -> &TypePathTable {
+        &self.type_path
+    }
+
+    /// The [stable, full type path] of the parameter's type.
+    ///
+    /// [stable, full type path]: TypePath
+    pub fn type_path(&self) -> &'static str {
+        self.type_path.path()
+    }
+
+    /// The [`TypeId`] of the parameter's type.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the parameter's type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The value this parameter was instantiated with.
+    pub fn reflect_value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// The value this parameter was instantiated with, if it is of type `T`.
+    pub fn value<T: Reflect>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+}
+
+impl Debug for ConstParamInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConstParamInfo")
+            .field("name", &self.name)
+            .field("type_path", &self.type_path)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// Implements methods for accessing the generic parameters of a type.
+///
+/// Implements the following methods:
+///
+/// * `fn with_generics(self, generics: Generics) -> Self`
+/// * `fn generics(&self) -> &Generics`
+macro_rules! impl_generic_info_methods {
+    ($self:ident . $generics:ident) => {
+        /// Sets the generic parameters of this type.
+        pub fn with_generics(mut $self, generics: $crate::generics::Generics) -> Self {
+            $self.$generics = generics;
+            $self
+        }
+
+        /// The generic parameters of this type, along with the arguments they were instantiated with.
+        pub fn generics(&$self) -> &$crate::generics::Generics {
+            &$self.$generics
+        }
+    };
+}
+
+pub(crate) use impl_generic_info_methods;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{TypeInfo, Typed};
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_get_generics_of_derived_types() {
+        #[derive(Reflect)]
+        struct Pair<A: Reflect + TypePath, B: Reflect + TypePath>(A, B);
+
+        let generics = <Pair<u8, String> as Typed>::type_info().generics();
+        assert_eq!(2, generics.len());
+        assert_eq!("A", generics[0].name());
+        assert_eq!(TypeId::of::<u8>(), generics[0].type_id());
+        assert_eq!("B", generics[1].name());
+        assert_eq!(
+            "alloc::string::String",
+            generics[1].type_path_table().path()
+        );
+        assert!(!generics[1].is_const());
+
+        #[derive(Reflect)]
+        enum Slots<T: Reflect + TypePath, const N: usize> {
+            Empty,
+            Full([T; N]),
+        }
+
+        let generics = <Slots<bool, 3> as Typed>::type_info().generics();
+        let GenericInfo::Const(len) = generics.get_named("N").unwrap() else {
+            panic!("expected const parameter");
+        };
+        assert!(len.is::<usize>());
+        assert_eq!(Some(&3), len.value::<usize>());
+        assert!(len.reflect_value().reflect_partial_eq(&3_usize).unwrap());
+    }
+
+    #[test]
+    fn should_get_generics_of_std_types() {
+        let TypeInfo::List(info) = <Vec<f32> as Typed>::type_info() else {
+            panic!("expected list info");
+        };
+        let GenericInfo::Type(item) = info.generics().get(0).unwrap() else {
+            panic!("expected type parameter");
+        };
+        assert_eq!("T", item.name());
+        assert!(item.is::<f32>());
+
+        let generics = <HashMap<String, i32> as Typed>::type_info().generics();
+        assert_eq!(
+            TypeId::of::<String>(),
+            generics.get_named("K").unwrap().type_id()
+        );
+        assert_eq!(
+            TypeId::of::<i32>(),
+            generics.get_named("V").unwrap().type_id()
+        );
+
+        let generics = <[u8; 5] as Typed>::type_info().generics();
+        let GenericInfo::Const(len) = generics.get_named("N").unwrap() else {
+            panic!("expected const parameter");
+        };
+        assert_eq!(Some(&5), len.value::<usize>());
+    }
+
+    #[test]
+    fn non_generic_types_should_have_no_generics() {
+        #[derive(Reflect)]
+        struct Plain {
+            value: u32,
+        }
+
+        assert!(<Plain as Typed>::type_info().generics().is_empty());
+        assert!(<u32 as Typed>::type_info().generics().is_empty());
+    }
+}
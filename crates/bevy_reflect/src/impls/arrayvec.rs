@@ -0,0 +1,257 @@
+use arrayvec::ArrayVec;
+use bevy_reflect_derive::{impl_reflect_value, impl_type_path};
+
+use std::any::Any;
+
+use crate::std_traits::ReflectDefault;
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
+    ListIter, ListIterMut, Reflect, ReflectCloneError, ReflectDeserialize, ReflectFromPtr,
+    ReflectKind, ReflectMut, ReflectOwned, ReflectRef, ReflectSerialize, TypeInfo, TypePath,
+    TypeRegistration, TypeRegistry, Typed,
+};
+
+impl<T: FromReflect + TypePath, const N: usize> List for ArrayVec<T, N> {
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        if index < ArrayVec::len(self) {
+            Some(&self[index] as &dyn Reflect)
+        } else {
+            None
+        }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        if index < ArrayVec::len(self) {
+            Some(&mut self[index] as &mut dyn Reflect)
+        } else {
+            None
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is already full or if `index` is out of bounds.
+    fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
+        let value = value.take::<T>().unwrap_or_else(|value| {
+            T::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.reflect_type_path()
+                )
+            })
+        });
+        ArrayVec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(self.remove(index))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is already full.
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        let value = value.take::<T>().unwrap_or_else(|value| {
+            T::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to push invalid value of type {}.",
+                    value.reflect_type_path()
+                )
+            })
+        });
+        ArrayVec::push(self, value);
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.pop().map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn len(&self) -> usize {
+        <ArrayVec<T, N>>::len(self)
+    }
+
+    fn iter(&self) -> ListIter {
+        ListIter::new(self)
+    }
+
+    fn iter_mut(&mut self) -> ListIterMut {
+        ListIterMut::new(<[T]>::iter_mut(self).map(|value| value as &mut dyn Reflect))
+    }
+
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + TypePath, const N: usize> Reflect for ArrayVec<T, N> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        crate::list_apply(self, value);
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        crate::list_try_apply(self, value)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::List(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        self.iter()
+            .map(|value| <dyn Reflect>::reflect_clone_and_take::<T>(value))
+            .collect::<Result<Self, _>>()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        crate::list_partial_eq(self, value)
+    }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::list_cmp(self, value)
+    }
+}
+
+impl<T: FromReflect + TypePath, const N: usize> Typed for ArrayVec<T, N> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
+    }
+}
+
+impl_type_path!(::arrayvec::ArrayVec<T, const CAP: usize>);
+
+impl<T: FromReflect + TypePath, const N: usize> FromReflect for ArrayVec<T, N> {
+    /// Returns [`None`] if the list has more than `N` elements.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
+            let mut new_list = Self::new();
+            for field in ref_list.iter() {
+                new_list.try_push(T::from_reflect(field)?).ok()?;
+            }
+            Some(new_list)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: FromReflect + TypePath + GetTypeRegistration, const N: usize> GetTypeRegistration
+    for ArrayVec<T, N>
+{
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<ArrayVec<T, N>>();
+        registration.insert::<ReflectFromPtr>(FromType::<ArrayVec<T, N>>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(ArrayVec<T, N>; <T: FromReflect + TypePath> [const N: usize]);
+
+impl_reflect_value!(::arrayvec::ArrayString<const CAP: usize>(
+    Debug,
+    Hash,
+    PartialEq,
+    Default,
+    Serialize,
+    Deserialize
+));
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicList, FromReflect, List, Reflect};
+    use arrayvec::{ArrayString, ArrayVec};
+
+    #[test]
+    fn should_reflect_arrayvec() {
+        let mut value = ArrayVec::<u32, 3>::from_iter([1, 2]);
+        List::push(&mut value, Box::new(3_u32));
+        assert_eq!(&[1, 2, 3], value.as_slice());
+
+        let mut dynamic = DynamicList::default();
+        dynamic.push(4_u32);
+        value.apply(&dynamic);
+        assert_eq!(&[4, 2, 3], value.as_slice());
+
+        let dynamic = value.clone_dynamic();
+        assert_eq!(Some(value), <ArrayVec<u32, 3>>::from_reflect(&dynamic));
+        assert!(<ArrayVec<u32, 2>>::from_reflect(&dynamic).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity")]
+    fn should_panic_when_pushing_to_full_arrayvec() {
+        let mut value = ArrayVec::<u32, 1>::from_iter([1]);
+        List::push(&mut value, Box::new(2_u32));
+    }
+
+    #[test]
+    fn should_reflect_arraystring() {
+        let a: &dyn Reflect = &ArrayString::<8>::from("hello").unwrap();
+        let b = a.clone_value();
+        assert_eq!(Some(true), a.reflect_partial_eq(&*b));
+        assert_eq!(
+            Some(ArrayString::<8>::from("hello").unwrap()),
+            <ArrayString<8>>::from_reflect(&*b)
+        );
+    }
+}
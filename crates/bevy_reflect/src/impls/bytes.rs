@@ -0,0 +1,59 @@
+use crate::std_traits::ReflectDefault;
+use crate::{self as bevy_reflect, ReflectDeserialize, ReflectSerialize};
+use bevy_reflect_derive::impl_reflect_value;
+
+impl_reflect_value!(::bytes::Bytes(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+impl_reflect_value!(::bytes::BytesMut(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{from_bincode, ReflectDeserializer, ReflectSerializer},
+        FromReflect, Reflect, TypeRegistry,
+    };
+    use bytes::{Bytes, BytesMut};
+
+    #[test]
+    fn should_partial_eq_bytes() {
+        let a: &dyn Reflect = &Bytes::from_static(b"abc");
+        let a2: &dyn Reflect = &Bytes::from(vec![b'a', b'b', b'c']);
+        let b: &dyn Reflect = &Bytes::from_static(b"xyz");
+        assert_eq!(Some(true), a.reflect_partial_eq(a2));
+        assert_eq!(Some(false), a.reflect_partial_eq(b));
+    }
+
+    #[test]
+    fn bytes_mut_should_from_reflect() {
+        let bytes = BytesMut::from(&b"payload"[..]);
+        let output = <BytesMut as FromReflect>::from_reflect(&bytes);
+        assert_eq!(Some(bytes), output);
+    }
+
+    #[test]
+    fn should_roundtrip_bytes_as_byte_buffer() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Bytes>();
+
+        let bytes = Bytes::from_static(&[0, 1, 2, 255]);
+        let serializer = ReflectSerializer::new(&bytes, &registry);
+        let output = bincode::serialize(&serializer).unwrap();
+
+        let value = from_bincode(ReflectDeserializer::new(&registry), &output).unwrap();
+
+        assert_eq!(Some(&bytes), value.downcast_ref::<Bytes>());
+    }
+}
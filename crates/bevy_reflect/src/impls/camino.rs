@@ -0,0 +1,193 @@
+use crate::std_traits::ReflectDefault;
+use crate::utility::{reflect_hasher, NonGenericTypeInfoCell};
+use crate::{
+    self as bevy_reflect, impl_type_path, ApplyError, DynamicTypePath, FromReflect, FromType,
+    GetTypeRegistration, Reflect, ReflectCloneError, ReflectDeserialize, ReflectFromPtr,
+    ReflectFromReflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, ReflectSerialize,
+    TypeInfo, TypeRegistration, Typed, ValueInfo,
+};
+use bevy_reflect_derive::impl_reflect_value;
+use camino::Utf8Path;
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+impl_reflect_value!(::camino::Utf8PathBuf(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+
+impl_type_path!(::camino::Utf8Path);
+
+impl Reflect for &'static Utf8Path {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
+        let any = value.as_any();
+        if let Some(&value) = any.downcast_ref::<Self>() {
+            *self = value;
+            Ok(())
+        } else {
+            Err(ApplyError::MismatchedTypes {
+                from_type: value.reflect_type_path().into(),
+                to_type: <Self as DynamicTypePath>::reflect_type_path(self).into(),
+            })
+        }
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Value
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Value(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Value(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Value(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(*self)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut hasher = reflect_hasher();
+        Hash::hash(&Any::type_id(self), &mut hasher);
+        Hash::hash(self, &mut hasher);
+        Some(hasher.finish())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            Some(PartialEq::eq(self, value))
+        } else {
+            Some(false)
+        }
+    }
+
+    fn debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl Typed for &'static Utf8Path {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_set(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+    }
+}
+
+// `&'static Utf8Path` can be serialized, but not deserialized since it borrows its data.
+impl GetTypeRegistration for &'static Utf8Path {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration.insert::<ReflectSerialize>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl FromReflect for &'static Utf8Path {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.as_any().downcast_ref::<Self>().copied()
+    }
+}
+
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(&'static Utf8Path);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        FromReflect, Reflect, TypeRegistry,
+    };
+    use camino::{Utf8Path, Utf8PathBuf};
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn should_serialize_utf8_path_buf_as_string() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Utf8PathBuf>();
+
+        let path = Utf8PathBuf::from("textures/grass.png");
+        let serializer = ReflectSerializer::new(&path, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(r#"{"camino::Utf8PathBuf":"textures/grass.png"}"#, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(path), Utf8PathBuf::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_reflect_static_utf8_path() {
+        let path: &'static Utf8Path = Utf8Path::new("textures/grass.png");
+        let reflected: &dyn Reflect = &path;
+
+        assert_eq!(Some(path), <&Utf8Path>::from_reflect(reflected));
+        assert_eq!(
+            Some(true),
+            reflected.reflect_partial_eq(&Utf8Path::new("textures/grass.png"))
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<&'static Utf8Path>();
+        let serializer = ReflectSerializer::new(reflected, &registry);
+        assert_eq!(
+            r#"{"&camino::Utf8Path":"textures/grass.png"}"#,
+            ron::to_string(&serializer).unwrap()
+        );
+    }
+}
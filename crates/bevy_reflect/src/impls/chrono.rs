@@ -0,0 +1,226 @@
+use crate::std_traits::ReflectDefault;
+use crate::utility::{reflect_hasher, NonGenericTypeInfoCell};
+use crate::{
+    self as bevy_reflect, impl_type_path, ApplyError, FromReflect, FromType, GetTypeRegistration,
+    Reflect, ReflectCloneError, ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectMut,
+    ReflectOwned, ReflectRef, ReflectSerialize, TypeInfo, TypePath, TypeRegistration, Typed,
+    ValueInfo,
+};
+use bevy_reflect_derive::impl_reflect_value;
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+impl_type_path!(::chrono::Utc);
+impl_type_path!(::chrono::FixedOffset);
+impl_type_path!(::chrono::DateTime<Tz: ::chrono::TimeZone>);
+
+// `DateTime` is only serializable for some time zones,
+// so it is reflected for each of them rather than for any `Tz`.
+macro_rules! impl_reflect_for_date_time {
+    ($($tz:ident),* $(,)?) => {
+        $(
+            impl Reflect for ::chrono::DateTime<::chrono::$tz> {
+                fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                    Some(<Self as Typed>::type_info())
+                }
+
+                fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                    self
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_any_mut(&mut self) -> &mut dyn Any {
+                    self
+                }
+
+                fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                    self
+                }
+
+                fn as_reflect(&self) -> &dyn Reflect {
+                    self
+                }
+
+                fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                    self
+                }
+
+                fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                    #[cfg(feature = "stats")]
+                    crate::stats::record_apply();
+                    if let Some(value) = value.as_any().downcast_ref::<Self>() {
+                        *self = *value;
+                        Ok(())
+                    } else {
+                        Err(ApplyError::MismatchedTypes {
+                            from_type: value.reflect_type_path().into(),
+                            to_type: Self::type_path().into(),
+                        })
+                    }
+                }
+
+                fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                    *self = value.take()?;
+                    Ok(())
+                }
+
+                fn reflect_ref(&self) -> ReflectRef {
+                    ReflectRef::Value(self)
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut {
+                    ReflectMut::Value(self)
+                }
+
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Value(self)
+                }
+
+                fn clone_value(&self) -> Box<dyn Reflect> {
+                    #[cfg(feature = "stats")]
+                    crate::stats::record_clone();
+                    Box::new(*self)
+                }
+
+                fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                    Ok(Box::new(*self))
+                }
+
+                fn reflect_hash(&self) -> Option<u64> {
+                    let mut hasher = reflect_hasher();
+                    Hash::hash(&Any::type_id(self), &mut hasher);
+                    Hash::hash(self, &mut hasher);
+                    Some(hasher.finish())
+                }
+
+                fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                    let value = value.as_any();
+                    if let Some(value) = value.downcast_ref::<Self>() {
+                        Some(self == value)
+                    } else {
+                        Some(false)
+                    }
+                }
+
+                fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl Typed for ::chrono::DateTime<::chrono::$tz> {
+                fn type_info() -> &'static TypeInfo {
+                    static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+                    CELL.get_or_set(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+                }
+            }
+
+            impl GetTypeRegistration for ::chrono::DateTime<::chrono::$tz> {
+                fn get_type_registration() -> TypeRegistration {
+                    let mut registration = TypeRegistration::of::<Self>();
+                    registration.insert::<ReflectDeserialize>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectSerialize>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+                    registration
+                }
+            }
+
+            impl FromReflect for ::chrono::DateTime<::chrono::$tz> {
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    reflect.as_any().downcast_ref::<Self>().copied()
+                }
+            }
+
+            #[cfg(feature = "functions")]
+            crate::func::macros::impl_function_traits!(::chrono::DateTime<::chrono::$tz>);
+        )*
+    };
+}
+
+impl_reflect_for_date_time!(Utc, FixedOffset);
+
+impl_reflect_value!(::chrono::NaiveDate(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+impl_reflect_value!(::chrono::NaiveTime(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+impl_reflect_value!(::chrono::NaiveDateTime(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        FromReflect, Reflect, TypePath, TypeRegistry,
+    };
+    use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn should_serialize_date_time_as_rfc3339() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<DateTime<Utc>>();
+
+        let timestamp = Utc.with_ymd_and_hms(2024, 5, 17, 12, 30, 0).unwrap();
+        let serializer = ReflectSerializer::new(&timestamp, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"chrono::DateTime<chrono::Utc>":"2024-05-17T12:30:00Z"}"#,
+            output
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(timestamp), DateTime::<Utc>::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_reflect_fixed_offset_date_time() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let timestamp = offset.with_ymd_and_hms(2024, 5, 17, 14, 30, 0).unwrap();
+        assert_eq!(
+            "chrono::DateTime<chrono::FixedOffset>",
+            DateTime::<FixedOffset>::type_path()
+        );
+
+        let clone = timestamp.clone_value();
+        assert_eq!(Some(true), timestamp.reflect_partial_eq(&*clone));
+        assert_eq!(timestamp.reflect_hash(), clone.reflect_hash());
+        assert_eq!(
+            Some(timestamp),
+            DateTime::<FixedOffset>::from_reflect(&*clone)
+        );
+    }
+
+    #[test]
+    fn should_partial_eq_naive_date() {
+        let a: &dyn Reflect = &NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let b: &dyn Reflect = &NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(Some(true), a.reflect_partial_eq(a));
+        assert_eq!(Some(false), a.reflect_partial_eq(b));
+        assert_eq!("chrono::NaiveDate", NaiveDate::type_path());
+    }
+}
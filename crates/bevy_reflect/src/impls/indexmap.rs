@@ -0,0 +1,404 @@
+use bevy_reflect_derive::{impl_reflect_value, impl_type_path};
+use indexmap::IndexMap;
+
+use std::any::Any;
+use std::hash::{BuildHasher, Hash};
+
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, map_apply, map_partial_eq, map_try_apply, ApplyError, DynamicMap,
+    FromReflect, FromType, GetTypeRegistration, IndexedMap, Map, MapInfo, MapIter, MapKeys,
+    MapValues, MapValuesMut, Reflect, ReflectFromPtr, ReflectIndexedMap, ReflectKind, ReflectMut,
+    ReflectOwned, ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
+};
+
+impl<K, V, S> Map for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(|key| Self::get(self, key))
+            .map(|value| value as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(move |key| Self::get_mut(self, key))
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.get_index(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+        self.get_index_mut(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
+    }
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn iter(&self) -> MapIter {
+        MapIter::new(self)
+    }
+
+    fn keys(&self) -> MapKeys {
+        MapKeys::new(Self::keys(self).map(|key| key as &dyn Reflect))
+    }
+
+    fn values(&self) -> MapValues {
+        MapValues::new(Self::values(self).map(|value| value as &dyn Reflect))
+    }
+
+    fn values_mut(&mut self) -> MapValuesMut {
+        MapValuesMut::new(Self::values_mut(self).map(|value| value as &mut dyn Reflect))
+    }
+
+    fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        self.into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value) as Box<dyn Reflect>,
+                )
+            })
+            .collect()
+    }
+
+    fn clone_dynamic(&self) -> DynamicMap {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
+        let mut dynamic_map = DynamicMap::default();
+        dynamic_map.set_represented_type(self.get_represented_type_info());
+        for (k, v) in self {
+            let key = K::from_reflect(k).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to clone invalid key of type {}.",
+                    k.reflect_type_path()
+                )
+            });
+            dynamic_map.insert_boxed(Box::new(key), v.clone_value());
+        }
+        dynamic_map
+    }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+            panic!(
+                "Attempted to insert invalid value of type {}.",
+                value.reflect_type_path()
+            )
+        });
+        self.insert(key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        self.entry(key).or_insert_with(|| {
+            V::take_from_reflect(default()).unwrap_or_else(|value| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.reflect_type_path()
+                )
+            })
+        })
+    }
+
+    /// Removes an entry from the map, preserving the order of the remaining entries.
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        IndexedMap::shift_remove(self, key)
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        Self::retain(self, |key, value| f(key, value));
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
+impl<K, V, S> IndexedMap for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get_index_of(&self, key: &dyn Reflect) -> Option<usize> {
+        let mut from_reflect = None;
+        key.downcast_ref::<K>()
+            .or_else(|| {
+                from_reflect = K::from_reflect(key);
+                from_reflect.as_ref()
+            })
+            .and_then(|key| Self::get_index_of(self, key))
+    }
+
+    fn shift_remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let mut from_reflect = None;
+        key.downcast_ref::<K>()
+            .or_else(|| {
+                from_reflect = K::from_reflect(key);
+                from_reflect.as_ref()
+            })
+            .and_then(|key| Self::shift_remove(self, key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn shift_remove_index(&mut self, index: usize) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        Self::shift_remove_index(self, index).map(|(key, value)| {
+            (
+                Box::new(key) as Box<dyn Reflect>,
+                Box::new(value) as Box<dyn Reflect>,
+            )
+        })
+    }
+
+    fn move_index(&mut self, from: usize, to: usize) {
+        Self::move_index(self, from, to);
+    }
+}
+
+impl<K, V, S> Reflect for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        map_apply(self, value);
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        map_try_apply(self, value)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Map
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Map(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        map_partial_eq(self, value)
+    }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::map_cmp(self, value)
+    }
+}
+
+impl<K, V, S> Typed for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+    }
+}
+
+impl<K, V, S> GetTypeRegistration for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectIndexedMap>(FromType::<Self>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
+}
+
+impl<K, V, S> FromReflect for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Default + Send + Sync,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::Map(ref_map) = reflect.reflect_ref() {
+            let mut new_map = Self::with_capacity_and_hasher(ref_map.len(), S::default());
+            for (key, value) in ref_map.iter() {
+                let new_key = K::from_reflect(key)?;
+                let new_value = V::from_reflect(value)?;
+                new_map.insert(new_key, new_value);
+            }
+            Some(new_map)
+        } else {
+            None
+        }
+    }
+}
+
+impl_type_path!(::indexmap::IndexMap<K, V, S>);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(::indexmap::IndexMap<K, V, S>;
+    <
+        K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+        V: FromReflect + TypePath + GetTypeRegistration,
+        S: TypePath + BuildHasher + Default + Send + Sync
+    >
+);
+
+impl_reflect_value!(::indexmap::IndexSet<T: Hash + Eq + Clone + Send + Sync, S: TypePath + Clone + Send + Sync>());
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        FromReflect, GetTypeRegistration, Map, Reflect, ReflectIndexedMap, TypeRegistry,
+    };
+    use indexmap::IndexMap;
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn should_preserve_insertion_order() {
+        let map: IndexMap<String, u32> = IndexMap::from([
+            (String::from("c"), 3),
+            (String::from("a"), 1),
+            (String::from("b"), 2),
+        ]);
+
+        let keys: Vec<_> = Map::keys(&map)
+            .map(|key| key.downcast_ref::<String>().unwrap().as_str())
+            .collect();
+        assert_eq!(vec!["c", "a", "b"], keys);
+
+        let (key, value) = map.get_at(1).unwrap();
+        assert_eq!(Some(&String::from("a")), key.downcast_ref::<String>());
+        assert_eq!(Some(&1), value.downcast_ref::<u32>());
+
+        let clone = IndexMap::<String, u32>::from_reflect(&*map.clone_value()).unwrap();
+        assert!(map.iter().eq(clone.iter()));
+    }
+
+    #[test]
+    fn should_roundtrip_in_order() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<IndexMap<String, u32>>();
+
+        let map: IndexMap<String, u32> = IndexMap::from([
+            (String::from("z"), 26),
+            (String::from("y"), 25),
+            (String::from("x"), 24),
+        ]);
+
+        let serializer = ReflectSerializer::new(&map, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let result = IndexMap::<String, u32>::from_reflect(&*value).unwrap();
+
+        assert!(map.iter().eq(result.iter()));
+    }
+
+    #[test]
+    fn should_shift_remove_and_move_entries() {
+        let mut map: IndexMap<u32, u32> = IndexMap::from([(1, 10), (2, 20), (3, 30), (4, 40)]);
+
+        let registration = IndexMap::<u32, u32>::get_type_registration();
+        let reflect_indexed_map = registration.data::<ReflectIndexedMap>().unwrap();
+        let indexed_map = reflect_indexed_map.get_mut(&mut map).unwrap();
+
+        assert_eq!(Some(2), indexed_map.get_index_of(&3_u32));
+
+        let removed = indexed_map.shift_remove(&2_u32).unwrap();
+        assert_eq!(Some(&20), removed.downcast_ref::<u32>());
+
+        indexed_map.move_index(2, 0);
+
+        let (key, _) = indexed_map.shift_remove_index(1).unwrap();
+        assert_eq!(Some(&1), key.downcast_ref::<u32>());
+
+        assert_eq!(vec![(4, 40), (3, 30)], map.into_iter().collect::<Vec<_>>());
+    }
+}
@@ -6,8 +6,8 @@ use std::any::Any;
 use crate::utility::GenericTypeInfoCell;
 use crate::{
     self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
-    ListIter, Reflect, ReflectFromPtr, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
-    TypePath, TypeRegistration, Typed,
+    ListIter, ListIterMut, Reflect, ReflectCloneError, ReflectFromPtr, ReflectKind, ReflectMut,
+    ReflectOwned, ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
 };
 
 impl<T: SmallArray + TypePath + Send + Sync> List for SmallVec<T>
@@ -70,6 +70,10 @@ where
         ListIter::new(self)
     }
 
+    fn iter_mut(&mut self) -> ListIterMut {
+        ListIterMut::new(<[T::Item]>::iter_mut(self).map(|value| value as &mut dyn Reflect))
+    }
+
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
         self.into_iter()
             .map(|value| Box::new(value) as Box<dyn Reflect>)
@@ -139,12 +143,25 @@ where
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        self.iter()
+            .map(<dyn Reflect>::reflect_clone_and_take::<T::Item>)
+            .collect::<Result<Self, _>>()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::list_partial_eq(self, value)
     }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::list_cmp(self, value)
+    }
 }
 
 impl<T: SmallArray + TypePath + Send + Sync + 'static> Typed for SmallVec<T>
@@ -178,13 +195,17 @@ where
 
 impl<T: SmallArray + TypePath + Send + Sync> GetTypeRegistration for SmallVec<T>
 where
-    T::Item: FromReflect + TypePath,
+    T::Item: FromReflect + TypePath + GetTypeRegistration,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<SmallVec<T>>();
         registration.insert::<ReflectFromPtr>(FromType::<SmallVec<T>>::from_type());
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T::Item>();
+    }
 }
 
 #[cfg(feature = "functions")]
@@ -4,10 +4,13 @@ use crate::utility::{
 };
 use crate::{
     self as bevy_reflect, impl_type_path, map_apply, map_partial_eq, map_try_apply, ApplyError,
-    Array, ArrayInfo, ArrayIter, DynamicMap, DynamicTypePath, FromReflect, FromType,
-    GetTypeRegistration, List, ListInfo, ListIter, Map, MapInfo, MapIter, Reflect,
-    ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, ReflectSerialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
+    ApplyStrategy, Array, ArrayInfo, ArrayIter, ArrayIterMut, ConstParamInfo, DynamicMap,
+    DynamicStruct, DynamicTypePath, FieldIter, FromReflect, FromType, Generics,
+    GetTypeRegistration, List, ListInfo, ListIter, ListIterMut, Map, MapInfo, MapIter, MapKeys,
+    MapValues, MapValuesMut, NamedField, OrderedMap, OrderedMapRange, Reflect, ReflectCloneError,
+    ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut,
+    ReflectNumeric, ReflectOrderedMap, ReflectOwned, ReflectRef, ReflectSerialize, Struct,
+    StructInfo, TypeInfo, TypeParamInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
     ValueInfo,
 };
 use bevy_reflect_derive::{impl_reflect, impl_reflect_value};
@@ -15,15 +18,18 @@ use std::fmt;
 use std::{
     any::Any,
     borrow::Cow,
-    collections::VecDeque,
+    collections::{BinaryHeap, LinkedList, VecDeque},
     hash::{BuildHasher, Hash, Hasher},
+    ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
     path::Path,
+    sync::atomic::Ordering,
 };
 
 impl_reflect_value!(bool(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
@@ -32,172 +38,225 @@ impl_reflect_value!(char(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
 ));
-impl_reflect_value!(u8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u128(
+impl_reflect_value!(u8(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(usize(
+impl_reflect_value!(u16(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(i8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i128(
+impl_reflect_value!(u32(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(isize(
+impl_reflect_value!(u64(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(f32(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(f64(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_type_path!(str);
-impl_reflect_value!(::alloc::string::String(
+impl_reflect_value!(u128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::std::path::PathBuf(
+impl_reflect_value!(usize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::std::any::TypeId(Debug, Hash, PartialEq,));
-impl_reflect_value!(::std::collections::BTreeSet<T: Ord + Eq + Clone + Send + Sync>());
-impl_reflect_value!(::std::collections::HashSet<T: Hash + Eq + Clone + Send + Sync, S: TypePath + Clone + Send + Sync>());
-impl_reflect_value!(::bevy_utils::hashbrown::HashSet<T: Hash + Eq + Clone + Send + Sync, S: TypePath + Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::Range<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeInclusive<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeFrom<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeTo<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeToInclusive<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeFull());
-impl_reflect_value!(::bevy_utils::Duration(
+impl_reflect_value!(i8(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
-));
-impl_reflect_value!(::bevy_utils::Instant(Debug, Hash, PartialEq));
-impl_reflect_value!(::core::num::NonZeroI128(
-    Debug,
-    Hash,
-    PartialEq,
-    Serialize,
-    Deserialize
-));
-impl_reflect_value!(::core::num::NonZeroU128(
-    Debug,
-    Hash,
-    PartialEq,
-    Serialize,
-    Deserialize
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroIsize(
+impl_reflect_value!(i16(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroUsize(
+impl_reflect_value!(i32(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroI64(
+impl_reflect_value!(i64(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroU64(
+impl_reflect_value!(i128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroU32(
+impl_reflect_value!(isize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroI32(
+impl_reflect_value!(f32(
     Debug,
-    Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroI16(
+impl_reflect_value!(f64(
     Debug,
-    Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default,
+    Numeric
 ));
-impl_reflect_value!(::core::num::NonZeroU16(
+impl_type_path!(str);
+impl_reflect_value!(::alloc::string::String(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default
 ));
-impl_reflect_value!(::core::num::NonZeroU8(
+impl_reflect_value!(::std::path::PathBuf(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default
 ));
-impl_reflect_value!(::core::num::NonZeroI8(
+impl_reflect_value!(::std::any::TypeId(Debug, Hash, PartialEq,));
+impl_reflect_value!(::std::collections::BTreeSet<T: Ord + Eq + Clone + Send + Sync>());
+impl_reflect_value!(::core::ops::RangeFull());
+impl_reflect_value!(::bevy_utils::Duration(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
-    Deserialize
+    Deserialize,
+    Default
 ));
+impl_reflect_value!(::bevy_utils::Instant(Debug, Hash, PartialEq, PartialOrd));
+macro_rules! impl_reflect_for_nonzero {
+    ($($nonzero:ident($int:ty)),* $(,)?) => {
+        $(
+            impl_reflect_value!(::core::num::$nonzero(
+                Debug,
+                Hash,
+                PartialEq,
+                PartialOrd,
+    PartialOrd,
+                Serialize,
+                Deserialize,
+                FromReflect,
+                from_reflect = false
+            ));
+
+            impl FromReflect for ::core::num::$nonzero {
+                // Also accept the plain integer, failing on zero
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    let any = reflect.as_any();
+                    if let Some(value) = any.downcast_ref::<Self>() {
+                        Some(*value)
+                    } else {
+                        Self::new(*any.downcast_ref::<$int>()?)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_reflect_for_nonzero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+    NonZeroIsize(isize),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroUsize(usize),
+);
 impl_reflect_value!(::core::num::Wrapping<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::num::Saturating<T: Clone + Send + Sync>());
 impl_reflect_value!(::std::sync::Arc<T: Send + Sync>);
@@ -209,28 +268,46 @@ impl_reflect_value!(::std::ffi::OsString(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize
 ));
 #[cfg(not(any(unix, windows)))]
-impl_reflect_value!(::std::ffi::OsString(Debug, Hash, PartialEq));
-impl_reflect_value!(::alloc::collections::BinaryHeap<T: Clone>);
+impl_reflect_value!(::std::ffi::OsString(Debug, Hash, PartialEq, PartialOrd));
 
 impl_type_path!(::bevy_utils::NoOpHash);
 impl_type_path!(::bevy_utils::EntityHash);
 impl_type_path!(::bevy_utils::FixedState);
 
+// Most hashing crates, such as `rustc-hash` or `fxhash`, expose their hasher through
+// `BuildHasherDefault`, so it is reflected for any hasher with a `TypePath`.
+impl_type_path!(::core::hash::BuildHasherDefault<H>);
+impl_type_path!(::bevy_utils::hashbrown::hash_map::DefaultHashBuilder);
+
 macro_rules! impl_reflect_for_veclike {
-    ($ty:path, $insert:expr, $remove:expr, $push:expr, $pop:expr, $sub:ty) => {
+    (
+        $ty:path,
+        $insert:expr,
+        $remove:expr,
+        $push:expr,
+        $pop:expr,
+        $push_front:expr,
+        $pop_front:expr,
+        $get:expr,
+        $get_mut:expr,
+        $iter_mut:expr,
+        $with_capacity:expr,
+        |$this:ident| $iter:expr
+    ) => {
         impl<T: FromReflect + TypePath + GetTypeRegistration> List for $ty {
             #[inline]
             fn get(&self, index: usize) -> Option<&dyn Reflect> {
-                <$sub>::get(self, index).map(|value| value as &dyn Reflect)
+                $get(self, index).map(|value| value as &dyn Reflect)
             }
 
             #[inline]
             fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-                <$sub>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+                $get_mut(self, index).map(|value| value as &mut dyn Reflect)
             }
 
             fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
@@ -263,14 +340,34 @@ macro_rules! impl_reflect_for_veclike {
                 $pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
             }
 
+            fn push_front(&mut self, value: Box<dyn Reflect>) {
+                let value = T::take_from_reflect(value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to push invalid value of type {}.",
+                        value.reflect_type_path()
+                    )
+                });
+                $push_front(self, value);
+            }
+
+            fn pop_front(&mut self) -> Option<Box<dyn Reflect>> {
+                $pop_front(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
             #[inline]
             fn len(&self) -> usize {
-                <$sub>::len(self)
+                Self::len(self)
             }
 
             #[inline]
             fn iter(&self) -> ListIter {
-                ListIter::new(self)
+                let $this = self;
+                $iter
+            }
+
+            #[inline]
+            fn iter_mut(&mut self) -> ListIterMut {
+                ListIterMut::new($iter_mut(self).map(|value| value as &mut dyn Reflect))
             }
 
             #[inline]
@@ -279,6 +376,11 @@ macro_rules! impl_reflect_for_veclike {
                     .map(|value| Box::new(value) as Box<dyn Reflect>)
                     .collect()
             }
+
+            #[inline]
+            fn as_primitive_slice(&self) -> Option<crate::PrimitiveSlice> {
+                crate::PrimitiveSlice::from_vec(self.as_any())
+            }
         }
 
         impl<T: FromReflect + TypePath + GetTypeRegistration> Reflect for $ty {
@@ -340,9 +442,21 @@ macro_rules! impl_reflect_for_veclike {
             }
 
             fn clone_value(&self) -> Box<dyn Reflect> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_clone();
                 Box::new(self.clone_dynamic())
             }
 
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                if let Some(slice) = self.as_primitive_slice() {
+                    return Ok(slice.to_vec());
+                }
+                self.iter()
+                    .map(|value| <dyn Reflect>::reflect_clone_and_take::<T>(value))
+                    .collect::<Result<Self, _>>()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
             fn reflect_hash(&self) -> Option<u64> {
                 crate::list_hash(self)
             }
@@ -350,12 +464,21 @@ macro_rules! impl_reflect_for_veclike {
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 crate::list_partial_eq(self, value)
             }
+
+            fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+                crate::list_cmp(self, value)
+            }
         }
 
         impl<T: FromReflect + TypePath + GetTypeRegistration> Typed for $ty {
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-                CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
+                CELL.get_or_insert::<Self, _>(|| {
+                    TypeInfo::List(
+                        ListInfo::new::<Self, T>()
+                            .with_generics(Generics::new().with(TypeParamInfo::new::<T>("T"))),
+                    )
+                })
             }
         }
 
@@ -365,6 +488,7 @@ macro_rules! impl_reflect_for_veclike {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<$ty>();
                 registration.insert::<ReflectFromPtr>(FromType::<$ty>::from_type());
+                registration.insert::<ReflectFromReflect>(FromType::<$ty>::from_type());
                 registration
             }
 
@@ -376,7 +500,7 @@ macro_rules! impl_reflect_for_veclike {
         impl<T: FromReflect + TypePath + GetTypeRegistration> FromReflect for $ty {
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
                 if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
-                    let mut new_list = Self::with_capacity(ref_list.len());
+                    let mut new_list: Self = $with_capacity(ref_list.len());
                     for field in ref_list.iter() {
                         $push(&mut new_list, T::from_reflect(field)?);
                     }
@@ -395,7 +519,13 @@ impl_reflect_for_veclike!(
     Vec::remove,
     Vec::push,
     Vec::pop,
-    [T]
+    |vec: &mut Vec<T>, value| vec.insert(0, value),
+    |vec: &mut Vec<T>| (!vec.is_empty()).then(|| vec.remove(0)),
+    <[T]>::get,
+    <[T]>::get_mut,
+    <[T]>::iter_mut,
+    Vec::with_capacity,
+    |vec| ListIter::new(vec)
 );
 #[cfg(feature = "functions")]
 crate::func::macros::impl_function_traits!(Vec<T>; <T: FromReflect + TypePath + GetTypeRegistration>);
@@ -406,140 +536,604 @@ impl_reflect_for_veclike!(
     VecDeque::remove,
     VecDeque::push_back,
     VecDeque::pop_back,
-    VecDeque::<T>
+    VecDeque::push_front,
+    VecDeque::pop_front,
+    VecDeque::get,
+    VecDeque::get_mut,
+    VecDeque::iter_mut,
+    VecDeque::with_capacity,
+    |deque| {
+        let (front, back) = deque.as_slices();
+        ListIter::from_slices(deque, front, back)
+    }
 );
 #[cfg(feature = "functions")]
 crate::func::macros::impl_function_traits!(VecDeque<T>; <T: FromReflect + TypePath + GetTypeRegistration>);
 
-macro_rules! impl_reflect_for_hashmap {
-    ($ty:path) => {
-        impl<K, V, S> Map for $ty
-        where
-            K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
-            V: FromReflect + TypePath + GetTypeRegistration,
-            S: TypePath + BuildHasher + Send + Sync,
-        {
-            fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
-                key.downcast_ref::<K>()
-                    .and_then(|key| Self::get(self, key))
-                    .map(|value| value as &dyn Reflect)
-            }
+// `LinkedList` has no indexed access, so elements are reached by walking the list.
+fn linked_list_get<T>(list: &LinkedList<T>, index: usize) -> Option<&T> {
+    list.iter().nth(index)
+}
 
-            fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
-                key.downcast_ref::<K>()
-                    .and_then(move |key| Self::get_mut(self, key))
-                    .map(|value| value as &mut dyn Reflect)
-            }
+fn linked_list_get_mut<T>(list: &mut LinkedList<T>, index: usize) -> Option<&mut T> {
+    list.iter_mut().nth(index)
+}
 
-            fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
-                self.iter()
-                    .nth(index)
-                    .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
-            }
+fn linked_list_insert<T>(list: &mut LinkedList<T>, index: usize, value: T) {
+    let mut back = list.split_off(index);
+    list.push_back(value);
+    list.append(&mut back);
+}
 
-            fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
-                self.iter_mut()
-                    .nth(index)
-                    .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
-            }
+fn linked_list_remove<T>(list: &mut LinkedList<T>, index: usize) -> T {
+    let len = list.len();
+    let mut back = list.split_off(index);
+    let value = back
+        .pop_front()
+        .unwrap_or_else(|| panic!("removal index (is {index}) should be < len (is {len})"));
+    list.append(&mut back);
+    value
+}
 
-            fn len(&self) -> usize {
-                Self::len(self)
-            }
+impl_reflect_for_veclike!(
+    ::alloc::collections::LinkedList<T>,
+    linked_list_insert,
+    linked_list_remove,
+    LinkedList::push_back,
+    LinkedList::pop_back,
+    LinkedList::push_front,
+    LinkedList::pop_front,
+    linked_list_get,
+    linked_list_get_mut,
+    LinkedList::iter_mut,
+    |_| LinkedList::new(),
+    |list| ListIter::from_elements(list, list.iter())
+);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(LinkedList<T>; <T: FromReflect + TypePath + GetTypeRegistration>);
 
-            fn iter(&self) -> MapIter {
-                MapIter::new(self)
-            }
+// `BinaryHeap` is reflected as a list of its elements in arbitrary order.
+// Elements cannot be mutated in place, as that could break the heap invariant,
+// so `get_mut` always returns `None` and applying a list replaces the contents of the heap.
+impl<T: FromReflect + TypePath + GetTypeRegistration + Ord> List for BinaryHeap<T> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.iter().nth(index).map(|value| value as &dyn Reflect)
+    }
 
-            fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
-                self.into_iter()
-                    .map(|(key, value)| {
-                        (
-                            Box::new(key) as Box<dyn Reflect>,
-                            Box::new(value) as Box<dyn Reflect>,
-                        )
-                    })
-                    .collect()
-            }
+    #[inline]
+    fn get_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+        None
+    }
 
-            fn clone_dynamic(&self) -> DynamicMap {
-                let mut dynamic_map = DynamicMap::default();
-                dynamic_map.set_represented_type(self.get_represented_type_info());
-                for (k, v) in self {
-                    let key = K::from_reflect(k).unwrap_or_else(|| {
-                        panic!(
-                            "Attempted to clone invalid key of type {}.",
-                            k.reflect_type_path()
-                        )
-                    });
-                    dynamic_map.insert_boxed(Box::new(key), v.clone_value());
-                }
-                dynamic_map
-            }
+    /// Pushes the element onto the heap, ignoring `index`.
+    fn insert(&mut self, _index: usize, value: Box<dyn Reflect>) {
+        List::push(self, value);
+    }
 
-            fn insert_boxed(
-                &mut self,
-                key: Box<dyn Reflect>,
-                value: Box<dyn Reflect>,
-            ) -> Option<Box<dyn Reflect>> {
-                let key = K::take_from_reflect(key).unwrap_or_else(|key| {
-                    panic!(
-                        "Attempted to insert invalid key of type {}.",
-                        key.reflect_type_path()
-                    )
-                });
-                let value = V::take_from_reflect(value).unwrap_or_else(|value| {
-                    panic!(
-                        "Attempted to insert invalid value of type {}.",
-                        value.reflect_type_path()
-                    )
-                });
-                self.insert(key, value)
-                    .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
-            }
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let mut elements = std::mem::take(self).into_vec();
+        let value = elements.remove(index);
+        *self = BinaryHeap::from(elements);
+        Box::new(value)
+    }
 
-            fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
-                let mut from_reflect = None;
-                key.downcast_ref::<K>()
-                    .or_else(|| {
-                        from_reflect = K::from_reflect(key);
-                        from_reflect.as_ref()
-                    })
-                    .and_then(|key| self.remove(key))
-                    .map(|value| Box::new(value) as Box<dyn Reflect>)
-            }
-        }
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        let value = T::take_from_reflect(value).unwrap_or_else(|value| {
+            panic!(
+                "Attempted to push invalid value of type {}.",
+                value.reflect_type_path()
+            )
+        });
+        BinaryHeap::push(self, value);
+    }
 
-        impl<K, V, S> Reflect for $ty
-        where
-            K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
-            V: FromReflect + TypePath + GetTypeRegistration,
-            S: TypePath + BuildHasher + Send + Sync,
-        {
-            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
-                Some(<Self as Typed>::type_info())
-            }
+    /// Removes the greatest element from the heap and returns it, or [`None`] if it is empty.
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        BinaryHeap::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 
-            fn into_any(self: Box<Self>) -> Box<dyn Any> {
-                self
-            }
+    #[inline]
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
 
-            fn as_any(&self) -> &dyn Any {
-                self
-            }
+    #[inline]
+    fn iter(&self) -> ListIter {
+        ListIter::from_elements(self, BinaryHeap::iter(self))
+    }
 
-            fn as_any_mut(&mut self) -> &mut dyn Any {
-                self
-            }
+    /// Yields no elements, as mutating them in place could break the heap's ordering.
+    #[inline]
+    fn iter_mut(&mut self) -> ListIterMut {
+        ListIterMut::new(std::iter::empty())
+    }
 
-            #[inline]
-            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
-                self
-            }
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
 
-            fn as_reflect(&self) -> &dyn Reflect {
-                self
-            }
+impl<T: FromReflect + TypePath + GetTypeRegistration + Ord> Reflect for BinaryHeap<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        crate::list_apply_with(self, value, ApplyStrategy::Replace);
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        crate::list_try_apply_with(self, value, ApplyStrategy::Replace)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::List(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        self.iter()
+            .map(|value| <dyn Reflect>::reflect_clone_and_take::<T>(value))
+            .collect::<Result<Self, _>>()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// Hashes the elements in sorted order, so that equal heaps have the same hash
+    /// regardless of their internal layout.
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut elements = self.iter().collect::<Vec<_>>();
+        elements.sort_unstable();
+
+        let mut hasher = reflect_hasher();
+        Any::type_id(self).hash(&mut hasher);
+        elements.len().hash(&mut hasher);
+        for value in elements {
+            hasher.write_u64(value.reflect_hash()?);
+        }
+        Some(hasher.finish())
+    }
+
+    /// Compares the elements of both heaps regardless of their order.
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        let ReflectRef::List(list) = value.reflect_ref() else {
+            return Some(false);
+        };
+        if list.len() != BinaryHeap::len(self) {
+            return Some(false);
+        }
+
+        let other = Self::from_reflect(value)?;
+        let mut a = self.iter().collect::<Vec<_>>();
+        let mut b = other.iter().collect::<Vec<_>>();
+        a.sort_unstable();
+        b.sort_unstable();
+        Some(a == b)
+    }
+}
+
+impl<T: FromReflect + TypePath + GetTypeRegistration + Ord> Typed for BinaryHeap<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::List(
+                ListInfo::new::<Self, T>()
+                    .with_generics(Generics::new().with(TypeParamInfo::new::<T>("T"))),
+            )
+        })
+    }
+}
+
+impl_type_path!(::alloc::collections::BinaryHeap<T>);
+
+impl<T: FromReflect + TypePath + GetTypeRegistration + Ord> GetTypeRegistration for BinaryHeap<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + TypePath + GetTypeRegistration + Ord> FromReflect for BinaryHeap<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::List(ref_list) = reflect.reflect_ref() else {
+            return None;
+        };
+        let mut new_heap = Self::with_capacity(ref_list.len());
+        for field in ref_list.iter() {
+            new_heap.push(T::from_reflect(field)?);
+        }
+        Some(new_heap)
+    }
+}
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(BinaryHeap<T>; <T: FromReflect + TypePath + GetTypeRegistration + Ord>);
+
+// `Box<T>` is reflected transparently as the `T` it contains,
+// which allows recursive types such as `Option<Box<Node>>` to be reflected.
+// Its type info is the one of `T`, but describing `Box<T>`.
+/// Returns the boxed value if `value` is a `Box<T>`, or `value` itself otherwise.
+fn unbox_reflect<T: FromReflect + Typed + TypePath + GetTypeRegistration>(
+    value: &dyn Reflect,
+) -> &dyn Reflect {
+    match value.downcast_ref::<Box<T>>() {
+        Some(value) => &**value,
+        None => value,
+    }
+}
+
+impl<T: FromReflect + Typed + TypePath + GetTypeRegistration> Reflect for Box<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        T::apply(self, unbox_reflect::<T>(value));
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        T::try_apply(self, unbox_reflect::<T>(value))
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        match value.take::<Self>() {
+            Ok(value) => {
+                *self = value;
+                Ok(())
+            }
+            Err(value) => T::set(self, value),
+        }
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        T::reflect_kind(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        T::reflect_ref(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        T::reflect_mut(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        T::reflect_owned(*self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        T::clone_value(self)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let value = <dyn Reflect>::reflect_clone_and_take::<T>(&**self)?;
+        Ok(Box::new(Box::new(value)))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        T::reflect_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        T::reflect_partial_eq(self, unbox_reflect::<T>(value))
+    }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        T::reflect_cmp(self, unbox_reflect::<T>(value))
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + TypePath + GetTypeRegistration> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| T::type_info().with_type::<Self>())
+    }
+}
+
+impl_type_path!(::alloc::boxed::Box<T>);
+
+impl<T: FromReflect + Typed + TypePath + GetTypeRegistration> GetTypeRegistration for Box<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+/// The number of nested boxes [`FromReflect`] goes through before giving up.
+///
+/// Reflected values can't contain themselves, but a recursive type may still be nested deep enough
+/// to overflow the stack, so such values fail to convert instead.
+const MAX_BOX_FROM_REFLECT_DEPTH: usize = 128;
+
+std::thread_local! {
+    static BOX_FROM_REFLECT_DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Decrements [`BOX_FROM_REFLECT_DEPTH`] when dropped, even if the conversion panics.
+struct BoxFromReflectDepthGuard;
+
+impl Drop for BoxFromReflectDepthGuard {
+    fn drop(&mut self) {
+        BOX_FROM_REFLECT_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+impl<T: FromReflect + Typed + TypePath + GetTypeRegistration> FromReflect for Box<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let depth = BOX_FROM_REFLECT_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        let _guard = BoxFromReflectDepthGuard;
+        if depth > MAX_BOX_FROM_REFLECT_DEPTH {
+            return None;
+        }
+        T::from_reflect(unbox_reflect::<T>(reflect)).map(Box::new)
+    }
+}
+
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(Box<T>; <T: FromReflect + Typed + TypePath + GetTypeRegistration>);
+
+macro_rules! impl_reflect_for_hashmap {
+    ($ty:path) => {
+        impl<K, V, S> Map for $ty
+        where
+            K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+            V: FromReflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+                key.downcast_ref::<K>()
+                    .and_then(|key| Self::get(self, key))
+                    .map(|value| value as &dyn Reflect)
+            }
+
+            fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+                key.downcast_ref::<K>()
+                    .and_then(move |key| Self::get_mut(self, key))
+                    .map(|value| value as &mut dyn Reflect)
+            }
+
+            fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+                self.iter()
+                    .nth(index)
+                    .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+            }
+
+            fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+                self.iter_mut()
+                    .nth(index)
+                    .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
+            }
+
+            fn len(&self) -> usize {
+                Self::len(self)
+            }
+
+            fn iter(&self) -> MapIter {
+                MapIter::new(self)
+            }
+
+            fn keys(&self) -> MapKeys {
+                MapKeys::new(Self::keys(self).map(|key| key as &dyn Reflect))
+            }
+
+            fn values(&self) -> MapValues {
+                MapValues::new(Self::values(self).map(|value| value as &dyn Reflect))
+            }
+
+            fn values_mut(&mut self) -> MapValuesMut {
+                MapValuesMut::new(Self::values_mut(self).map(|value| value as &mut dyn Reflect))
+            }
+
+            fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+                self.into_iter()
+                    .map(|(key, value)| {
+                        (
+                            Box::new(key) as Box<dyn Reflect>,
+                            Box::new(value) as Box<dyn Reflect>,
+                        )
+                    })
+                    .collect()
+            }
+
+            fn clone_dynamic(&self) -> DynamicMap {
+                #[cfg(feature = "stats")]
+                crate::stats::record_dynamic_allocation();
+                let mut dynamic_map = DynamicMap::default();
+                dynamic_map.set_represented_type(self.get_represented_type_info());
+                for (k, v) in self {
+                    let key = K::from_reflect(k).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to clone invalid key of type {}.",
+                            k.reflect_type_path()
+                        )
+                    });
+                    dynamic_map.insert_boxed(Box::new(key), v.clone_value());
+                }
+                dynamic_map
+            }
+
+            fn insert_boxed(
+                &mut self,
+                key: Box<dyn Reflect>,
+                value: Box<dyn Reflect>,
+            ) -> Option<Box<dyn Reflect>> {
+                let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+                    panic!(
+                        "Attempted to insert invalid key of type {}.",
+                        key.reflect_type_path()
+                    )
+                });
+                let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to insert invalid value of type {}.",
+                        value.reflect_type_path()
+                    )
+                });
+                self.insert(key, value)
+                    .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+            }
+
+            fn get_or_insert_with(
+                &mut self,
+                key: Box<dyn Reflect>,
+                default: &mut dyn FnMut() -> Box<dyn Reflect>,
+            ) -> &mut dyn Reflect {
+                let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+                    panic!(
+                        "Attempted to insert invalid key of type {}.",
+                        key.reflect_type_path()
+                    )
+                });
+                self.entry(key).or_insert_with(|| {
+                    V::take_from_reflect(default()).unwrap_or_else(|value| {
+                        panic!(
+                            "Attempted to insert invalid value of type {}.",
+                            value.reflect_type_path()
+                        )
+                    })
+                })
+            }
+
+            fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+                let mut from_reflect = None;
+                key.downcast_ref::<K>()
+                    .or_else(|| {
+                        from_reflect = K::from_reflect(key);
+                        from_reflect.as_ref()
+                    })
+                    .and_then(|key| self.remove(key))
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
+            fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+                Self::retain(self, |key, value| f(key, value));
+            }
+
+            fn clear(&mut self) {
+                Self::clear(self);
+            }
+        }
+
+        impl<K, V, S> Reflect for $ty
+        where
+            K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+            V: FromReflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
 
             fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
                 self
@@ -575,23 +1169,48 @@ macro_rules! impl_reflect_for_hashmap {
             }
 
             fn clone_value(&self) -> Box<dyn Reflect> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_clone();
                 Box::new(self.clone_dynamic())
             }
 
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                let mut map = Self::with_capacity_and_hasher(Self::len(self), S::default());
+                for (key, value) in self.iter() {
+                    map.insert(
+                        <dyn Reflect>::reflect_clone_and_take::<K>(key)?,
+                        <dyn Reflect>::reflect_clone_and_take::<V>(value)?,
+                    );
+                }
+                Ok(Box::new(map))
+            }
+
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 map_partial_eq(self, value)
             }
+
+            fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+                crate::map_cmp(self, value)
+            }
         }
 
         impl<K, V, S> Typed for $ty
         where
             K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
             V: FromReflect + TypePath + GetTypeRegistration,
-            S: TypePath + BuildHasher + Send + Sync,
+            S: TypePath + BuildHasher + Default + Send + Sync,
         {
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-                CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+                CELL.get_or_insert::<Self, _>(|| {
+                    TypeInfo::Map(
+                        MapInfo::new::<Self, K, V>().with_generics(
+                            Generics::new()
+                                .with(TypeParamInfo::new::<K>("K"))
+                                .with(TypeParamInfo::new::<V>("V")),
+                        ),
+                    )
+                })
             }
         }
 
@@ -599,11 +1218,12 @@ macro_rules! impl_reflect_for_hashmap {
         where
             K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
             V: FromReflect + TypePath + GetTypeRegistration,
-            S: TypePath + BuildHasher + Send + Sync,
+            S: TypePath + BuildHasher + Default + Send + Sync,
         {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<Self>();
                 registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
                 registration
             }
 
@@ -636,6 +1256,151 @@ macro_rules! impl_reflect_for_hashmap {
     };
 }
 
+// Sets are reflected as values, rebuilt with `S::default()` when cloned,
+// so that any hasher implementing `Default` can be used, even without `Clone`.
+macro_rules! impl_reflect_for_hashset {
+    ($ty:path) => {
+        impl<T, S> Reflect for $ty
+        where
+            T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_apply();
+                let Some(value) = value.as_any().downcast_ref::<Self>() else {
+                    return Err(ApplyError::MismatchedTypes {
+                        from_type: value.reflect_type_path().into(),
+                        to_type: Self::type_path().into(),
+                    });
+                };
+                self.clear();
+                self.extend(value.iter().cloned());
+                Ok(())
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_ref(&self) -> ReflectRef {
+                ReflectRef::Value(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut {
+                ReflectMut::Value(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Value(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_clone();
+                Box::new(self.iter().cloned().collect::<Self>())
+            }
+
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                Ok(Box::new(self.iter().cloned().collect::<Self>()))
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                let value = value.as_any();
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    Some(std::cmp::PartialEq::eq(self, value))
+                } else {
+                    Some(false)
+                }
+            }
+        }
+
+        impl<T, S> Typed for $ty
+        where
+            T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+            }
+        }
+
+        impl<T, S> GetTypeRegistration for $ty
+        where
+            T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<Self>();
+                registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+                registration
+            }
+        }
+
+        impl<T, S> FromReflect for $ty
+        where
+            T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+            S: TypePath + BuildHasher + Default + Send + Sync,
+        {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                let value = reflect.as_any().downcast_ref::<Self>()?;
+                Some(value.iter().cloned().collect())
+            }
+        }
+    };
+}
+
+impl_reflect_for_hashset!(::std::collections::HashSet<T, S>);
+impl_type_path!(::std::collections::HashSet<T, S>);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(::std::collections::HashSet<T, S>;
+    <
+        T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+        S: TypePath + BuildHasher + Default + Send + Sync
+    >
+);
+
+impl_reflect_for_hashset!(::bevy_utils::hashbrown::HashSet<T, S>);
+impl_type_path!(::bevy_utils::hashbrown::HashSet<T, S>);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(::bevy_utils::hashbrown::HashSet<T, S>;
+    <
+        T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+        S: TypePath + BuildHasher + Default + Send + Sync
+    >
+);
+
 impl_reflect_for_hashmap!(::std::collections::HashMap<K, V, S>);
 impl_type_path!(::std::collections::hash_map::RandomState);
 impl_type_path!(::std::collections::HashMap<K, V, S>);
@@ -648,11 +1413,37 @@ crate::func::macros::impl_function_traits!(::std::collections::HashMap<K, V, S>;
     >
 );
 
-impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
-impl_type_path!(::bevy_utils::hashbrown::hash_map::DefaultHashBuilder);
-impl_type_path!(::bevy_utils::hashbrown::HashMap<K, V, S>);
-#[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(::bevy_utils::hashbrown::HashMap<K, V, S>;
+impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
+impl_type_path!(::bevy_utils::hashbrown::HashMap<K, V, S>);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(::bevy_utils::hashbrown::HashMap<K, V, S>;
+    <
+        K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+        V: FromReflect + TypePath + GetTypeRegistration,
+        S: TypePath + BuildHasher + Default + Send + Sync
+    >
+);
+
+#[cfg(feature = "hashbrown")]
+impl_reflect_for_hashset!(::hashbrown::HashSet<T, S>);
+#[cfg(feature = "hashbrown")]
+impl_type_path!(::hashbrown::HashSet<T, S>);
+#[cfg(all(feature = "hashbrown", feature = "functions"))]
+crate::func::macros::impl_function_traits!(::hashbrown::HashSet<T, S>;
+    <
+        T: Hash + Eq + Clone + Reflect + TypePath + GetTypeRegistration,
+        S: TypePath + BuildHasher + Default + Send + Sync
+    >
+);
+
+#[cfg(feature = "hashbrown")]
+impl_reflect_for_hashmap!(::hashbrown::HashMap<K, V, S>);
+#[cfg(feature = "hashbrown")]
+impl_type_path!(::hashbrown::HashMap<K, V, S>);
+#[cfg(feature = "hashbrown")]
+impl_type_path!(::hashbrown::DefaultHashBuilder);
+#[cfg(all(feature = "hashbrown", feature = "functions"))]
+crate::func::macros::impl_function_traits!(::hashbrown::HashMap<K, V, S>;
     <
         K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
         V: FromReflect + TypePath + GetTypeRegistration,
@@ -697,6 +1488,18 @@ where
         MapIter::new(self)
     }
 
+    fn keys(&self) -> MapKeys {
+        MapKeys::new(Self::keys(self).map(|key| key as &dyn Reflect))
+    }
+
+    fn values(&self) -> MapValues {
+        MapValues::new(Self::values(self).map(|value| value as &dyn Reflect))
+    }
+
+    fn values_mut(&mut self) -> MapValuesMut {
+        MapValuesMut::new(Self::values_mut(self).map(|value| value as &mut dyn Reflect))
+    }
+
     fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
         self.into_iter()
             .map(|(key, value)| {
@@ -709,6 +1512,8 @@ where
     }
 
     fn clone_dynamic(&self) -> DynamicMap {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         let mut dynamic_map = DynamicMap::default();
         dynamic_map.set_represented_type(self.get_represented_type_info());
         for (k, v) in self {
@@ -744,6 +1549,27 @@ where
             .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
     }
 
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        self.entry(key).or_insert_with(|| {
+            V::take_from_reflect(default()).unwrap_or_else(|value| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.reflect_type_path()
+                )
+            })
+        })
+    }
+
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
         let mut from_reflect = None;
         key.downcast_ref::<K>()
@@ -754,6 +1580,14 @@ where
             .and_then(|key| self.remove(key))
             .map(|value| Box::new(value) as Box<dyn Reflect>)
     }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        Self::retain(self, |key, value| f(key, value));
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
 }
 
 impl<K, V> Reflect for ::std::collections::BTreeMap<K, V>
@@ -820,12 +1654,30 @@ where
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        self.iter()
+            .map(|(key, value)| -> Result<_, ReflectCloneError> {
+                Ok((
+                    <dyn Reflect>::reflect_clone_and_take::<K>(key)?,
+                    <dyn Reflect>::reflect_clone_and_take::<V>(value)?,
+                ))
+            })
+            .collect::<Result<Self, _>>()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         map_partial_eq(self, value)
     }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::map_cmp(self, value)
+    }
 }
 
 impl<K, V> Typed for ::std::collections::BTreeMap<K, V>
@@ -835,7 +1687,15 @@ where
 {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::Map(
+                MapInfo::new::<Self, K, V>().with_generics(
+                    Generics::new()
+                        .with(TypeParamInfo::new::<K>("K"))
+                        .with(TypeParamInfo::new::<V>("V")),
+                ),
+            )
+        })
     }
 }
 
@@ -847,8 +1707,54 @@ where
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
         registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectOrderedMap>(FromType::<Self>::from_type());
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
+}
+
+impl<K, V> OrderedMap for ::std::collections::BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Ord,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn first(&self) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.first_key_value()
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn last(&self) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.last_key_value()
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn range(&self, start: Bound<&dyn Reflect>, end: Bound<&dyn Reflect>) -> OrderedMapRange {
+        fn to_key<K: FromReflect>(bound: Bound<&dyn Reflect>) -> Bound<K> {
+            let from_reflect = |key: &dyn Reflect| {
+                K::from_reflect(key).unwrap_or_else(|| {
+                    panic!(
+                        "Attempted to use invalid key of type {} as a range bound.",
+                        key.reflect_type_path()
+                    )
+                })
+            };
+            match bound {
+                Bound::Included(key) => Bound::Included(from_reflect(key)),
+                Bound::Excluded(key) => Bound::Excluded(from_reflect(key)),
+                Bound::Unbounded => Bound::Unbounded,
+            }
+        }
+
+        let range = (to_key::<K>(start), to_key::<K>(end));
+        OrderedMapRange::new(
+            Self::range(self, range)
+                .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect)),
+        )
+    }
 }
 
 impl<K, V> FromReflect for ::std::collections::BTreeMap<K, V>
@@ -871,167 +1777,406 @@ where
     }
 }
 
-impl_type_path!(::std::collections::BTreeMap<K, V>);
-#[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!(::std::collections::BTreeMap<K, V>;
-    <
-        K: FromReflect + TypePath + GetTypeRegistration + Eq + Ord,
-        V: FromReflect + TypePath + GetTypeRegistration
-    >
-);
+impl_type_path!(::std::collections::BTreeMap<K, V>);
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(::std::collections::BTreeMap<K, V>;
+    <
+        K: FromReflect + TypePath + GetTypeRegistration + Eq + Ord,
+        V: FromReflect + TypePath + GetTypeRegistration
+    >
+);
+
+impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Array for [T; N] {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn iter(&self) -> ArrayIter {
+        ArrayIter::new(self)
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> ArrayIterMut {
+        ArrayIterMut::new(<[T]>::iter_mut(self).map(|value| value as &mut dyn Reflect))
+    }
+
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Reflect for [T; N] {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn apply(&mut self, value: &dyn Reflect) {
+        crate::array_apply(self, value);
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        crate::array_try_apply(self, value)
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Array
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Array(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Array(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Array(self)
+    }
+
+    #[inline]
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let values = self
+            .iter()
+            .map(|value| <dyn Reflect>::reflect_clone_and_take::<T>(value))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(Box::new(
+            <[T; N]>::try_from(values).unwrap_or_else(|_| unreachable!()),
+        ))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        crate::array_hash(self)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        crate::array_partial_eq(self, value)
+    }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::array_cmp(self, value)
+    }
+}
+
+impl<T: FromReflect + TypePath + GetTypeRegistration, const N: usize> FromReflect for [T; N] {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::Array(ref_array) = reflect.reflect_ref() {
+            let mut temp_vec = Vec::with_capacity(ref_array.len());
+            for field in ref_array.iter() {
+                temp_vec.push(T::from_reflect(field)?);
+            }
+            temp_vec.try_into().ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Typed for [T; N] {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::Array(
+                ArrayInfo::new::<Self, T>(N).with_generics(
+                    Generics::new()
+                        .with(TypeParamInfo::new::<T>("T"))
+                        .with(ConstParamInfo::new::<usize>("N", N)),
+                ),
+            )
+        })
+    }
+}
+
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{t}; {N}]", t = T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{t}; {N}]", t = T::short_type_path()))
+    }
+}
+
+impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> GetTypeRegistration for [T; N] {
+    fn get_type_registration() -> TypeRegistration {
+        TypeRegistration::of::<[T; N]>()
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!([T; N]; <T: Reflect + TypePath + GetTypeRegistration> [const N: usize]);
+
+impl_reflect! {
+    #[type_path = "core::ops"]
+    struct Range<T> {
+        start: T,
+        end: T,
+    }
+}
+
+impl_reflect! {
+    #[type_path = "core::ops"]
+    struct RangeFrom<T> {
+        start: T,
+    }
+}
+
+impl_reflect! {
+    #[type_path = "core::ops"]
+    struct RangeTo<T> {
+        end: T,
+    }
+}
+
+impl_reflect! {
+    #[type_path = "core::ops"]
+    struct RangeToInclusive<T> {
+        end: T,
+    }
+}
+
+// `RangeInclusive` is reflected as a struct with `start` and `end` fields,
+// but its fields are private, so they can't be borrowed mutably.
+// Applying a value to it replaces the whole range instead.
+impl<T: FromReflect + TypePath + GetTypeRegistration> Struct for RangeInclusive<T> {
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        match name {
+            "start" => Some(self.start()),
+            "end" => Some(self.end()),
+            _ => None,
+        }
+    }
 
-impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Array for [T; N] {
-    #[inline]
-    fn get(&self, index: usize) -> Option<&dyn Reflect> {
-        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
     }
 
-    #[inline]
-    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match index {
+            0 => Some(self.start()),
+            1 => Some(self.end()),
+            _ => None,
+        }
     }
 
-    #[inline]
-    fn len(&self) -> usize {
-        N
+    fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+        None
     }
 
-    #[inline]
-    fn iter(&self) -> ArrayIter {
-        ArrayIter::new(self)
+    fn name_at(&self, index: usize) -> Option<&str> {
+        match index {
+            0 => Some("start"),
+            1 => Some("end"),
+            _ => None,
+        }
     }
 
-    #[inline]
-    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
-        self.into_iter()
-            .map(|value| Box::new(value) as Box<dyn Reflect>)
-            .collect()
+    fn field_len(&self) -> usize {
+        2
+    }
+
+    fn iter_fields(&self) -> FieldIter {
+        FieldIter::new(self)
+    }
+
+    fn clone_dynamic(&self) -> DynamicStruct {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_represented_type(self.get_represented_type_info());
+        dynamic.insert_boxed("start", self.start().clone_value());
+        dynamic.insert_boxed("end", self.end().clone_value());
+        dynamic
     }
 }
 
-impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Reflect for [T; N] {
+impl<T: FromReflect + TypePath + GetTypeRegistration> Reflect for RangeInclusive<T> {
     fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
         Some(<Self as Typed>::type_info())
     }
 
-    #[inline]
     fn into_any(self: Box<Self>) -> Box<dyn Any> {
         self
     }
 
-    #[inline]
     fn as_any(&self) -> &dyn Any {
         self
     }
 
-    #[inline]
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
 
-    #[inline]
     fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
         self
     }
 
-    #[inline]
     fn as_reflect(&self) -> &dyn Reflect {
         self
     }
 
-    #[inline]
     fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
         self
     }
 
-    #[inline]
-    fn apply(&mut self, value: &dyn Reflect) {
-        crate::array_apply(self, value);
-    }
-
-    #[inline]
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
-        crate::array_try_apply(self, value)
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: value.reflect_kind(),
+                to_kind: ReflectKind::Struct,
+            });
+        };
+
+        // Fields missing from `value` keep their current value
+        let field = |name: &str, current: &T| {
+            let field = value.field(name).unwrap_or(current);
+            T::from_reflect(field).ok_or_else(|| ApplyError::MismatchedTypes {
+                from_type: field.reflect_type_path().into(),
+                to_type: T::type_path().into(),
+            })
+        };
+        let start = field("start", self.start()).map_err(|err| err.with_field("start"))?;
+        let end = field("end", self.end()).map_err(|err| err.with_field("end"))?;
+        *self = RangeInclusive::new(start, end);
+        Ok(())
     }
 
-    #[inline]
     fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
         *self = value.take()?;
         Ok(())
     }
 
-    #[inline]
     fn reflect_kind(&self) -> ReflectKind {
-        ReflectKind::Array
+        ReflectKind::Struct
     }
 
-    #[inline]
     fn reflect_ref(&self) -> ReflectRef {
-        ReflectRef::Array(self)
+        ReflectRef::Struct(self)
     }
 
-    #[inline]
     fn reflect_mut(&mut self) -> ReflectMut {
-        ReflectMut::Array(self)
+        ReflectMut::Struct(self)
     }
 
-    #[inline]
     fn reflect_owned(self: Box<Self>) -> ReflectOwned {
-        ReflectOwned::Array(self)
+        ReflectOwned::Struct(self)
     }
 
-    #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
-    #[inline]
-    fn reflect_hash(&self) -> Option<u64> {
-        crate::array_hash(self)
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(RangeInclusive::new(
+            <dyn Reflect>::reflect_clone_and_take::<T>(self.start())?,
+            <dyn Reflect>::reflect_clone_and_take::<T>(self.end())?,
+        )))
     }
 
-    #[inline]
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
-        crate::array_partial_eq(self, value)
+        crate::struct_partial_eq(self, value)
     }
-}
 
-impl<T: FromReflect + TypePath + GetTypeRegistration, const N: usize> FromReflect for [T; N] {
-    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
-        if let ReflectRef::Array(ref_array) = reflect.reflect_ref() {
-            let mut temp_vec = Vec::with_capacity(ref_array.len());
-            for field in ref_array.iter() {
-                temp_vec.push(T::from_reflect(field)?);
-            }
-            temp_vec.try_into().ok()
-        } else {
-            None
-        }
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::struct_debug(self, f)
     }
 }
 
-impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Typed for [T; N] {
+impl<T: FromReflect + TypePath + GetTypeRegistration> Typed for RangeInclusive<T> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::Array(ArrayInfo::new::<Self, T>(N)))
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::Struct(StructInfo::new::<Self>(&[
+                NamedField::new::<T>("start"),
+                NamedField::new::<T>("end"),
+            ]))
+        })
     }
 }
 
-impl<T: TypePath, const N: usize> TypePath for [T; N] {
-    fn type_path() -> &'static str {
-        static CELL: GenericTypePathCell = GenericTypePathCell::new();
-        CELL.get_or_insert::<Self, _>(|| format!("[{t}; {N}]", t = T::type_path()))
-    }
-
-    fn short_type_path() -> &'static str {
-        static CELL: GenericTypePathCell = GenericTypePathCell::new();
-        CELL.get_or_insert::<Self, _>(|| format!("[{t}; {N}]", t = T::short_type_path()))
-    }
-}
+impl_type_path!(::core::ops::RangeInclusive<T>);
 
-impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> GetTypeRegistration for [T; N] {
+impl<T: FromReflect + TypePath + GetTypeRegistration> GetTypeRegistration for RangeInclusive<T> {
     fn get_type_registration() -> TypeRegistration {
-        TypeRegistration::of::<[T; N]>()
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration
     }
 
     fn register_type_dependencies(registry: &mut TypeRegistry) {
@@ -1039,8 +2184,20 @@ impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> GetTypeRegistr
     }
 }
 
+impl<T: FromReflect + TypePath + GetTypeRegistration> FromReflect for RangeInclusive<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Struct(value) = reflect.reflect_ref() else {
+            return None;
+        };
+        Some(RangeInclusive::new(
+            T::from_reflect(value.field("start")?)?,
+            T::from_reflect(value.field("end")?)?,
+        ))
+    }
+}
+
 #[cfg(feature = "functions")]
-crate::func::macros::impl_function_traits!([T; N]; <T: Reflect + TypePath + GetTypeRegistration> [const N: usize]);
+crate::func::macros::impl_function_traits!(RangeInclusive<T>; <T: FromReflect + TypePath + GetTypeRegistration>);
 
 impl_reflect! {
     #[type_path = "core::option"]
@@ -1112,9 +2269,15 @@ impl Reflect for Cow<'static, str> {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         let any = value.as_any();
         if let Some(value) = any.downcast_ref::<Self>() {
             self.clone_from(value);
+        } else if let Some(value) = any.downcast_ref::<String>() {
+            *self = Cow::Owned(value.clone());
+        } else if let Some(value) = any.downcast_ref::<&'static str>() {
+            *self = Cow::Borrowed(value);
         } else {
             return Err(ApplyError::MismatchedTypes {
                 from_type: value.reflect_type_path().into(),
@@ -1147,9 +2310,15 @@ impl Reflect for Cow<'static, str> {
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = reflect_hasher();
         Hash::hash(&std::any::Any::type_id(self), &mut hasher);
@@ -1166,6 +2335,15 @@ impl Reflect for Cow<'static, str> {
         }
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            std::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
+
     fn debug(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
         fmt::Debug::fmt(self, f)
     }
@@ -1184,18 +2362,23 @@ impl GetTypeRegistration for Cow<'static, str> {
         registration.insert::<ReflectDeserialize>(FromType::<Cow<'static, str>>::from_type());
         registration.insert::<ReflectFromPtr>(FromType::<Cow<'static, str>>::from_type());
         registration.insert::<ReflectSerialize>(FromType::<Cow<'static, str>>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Cow<'static, str>>::from_type());
         registration
     }
 }
 
 impl FromReflect for Cow<'static, str> {
     fn from_reflect(reflect: &dyn crate::Reflect) -> Option<Self> {
-        Some(
-            reflect
-                .as_any()
-                .downcast_ref::<Cow<'static, str>>()?
-                .clone(),
-        )
+        // Also accept the other string types, so that values created from `String`s can be applied
+        let any = reflect.as_any();
+        if let Some(value) = any.downcast_ref::<Cow<'static, str>>() {
+            Some(value.clone())
+        } else if let Some(value) = any.downcast_ref::<String>() {
+            Some(Cow::Owned(value.clone()))
+        } else {
+            any.downcast_ref::<&'static str>()
+                .map(|value| Cow::Borrowed(*value))
+        }
     }
 }
 
@@ -1266,6 +2449,10 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> List for Cow<'stat
         ListIter::new(self)
     }
 
+    fn iter_mut(&mut self) -> ListIterMut {
+        ListIterMut::new(self.to_mut().iter_mut().map(|x| x as &mut dyn Reflect))
+    }
+
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
         // into_owned() is not unnecessary here because it avoids cloning whenever you have a Cow::Owned already
         #[allow(clippy::unnecessary_to_owned)]
@@ -1335,9 +2522,15 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> Reflect for Cow<'s
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(List::clone_dynamic(self))
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
     fn reflect_hash(&self) -> Option<u64> {
         crate::list_hash(self)
     }
@@ -1345,12 +2538,21 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> Reflect for Cow<'s
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::list_partial_eq(self, value)
     }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::list_cmp(self, value)
+    }
 }
 
 impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> Typed for Cow<'static, [T]> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
+        CELL.get_or_insert::<Self, _>(|| {
+            TypeInfo::List(
+                ListInfo::new::<Self, T>()
+                    .with_generics(Generics::new().with(TypeParamInfo::new::<T>("T"))),
+            )
+        })
     }
 }
 
@@ -1358,7 +2560,10 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> GetTypeRegistratio
     for Cow<'static, [T]>
 {
     fn get_type_registration() -> TypeRegistration {
-        TypeRegistration::of::<Cow<'static, [T]>>()
+        let mut registration = TypeRegistration::of::<Cow<'static, [T]>>();
+        registration.insert::<ReflectFromPtr>(FromType::<Cow<'static, [T]>>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Cow<'static, [T]>>::from_type());
+        registration
     }
 
     fn register_type_dependencies(registry: &mut TypeRegistry) {
@@ -1413,6 +2618,8 @@ impl Reflect for &'static str {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         let any = value.as_any();
         if let Some(&value) = any.downcast_ref::<Self>() {
             *self = value;
@@ -1443,9 +2650,15 @@ impl Reflect for &'static str {
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(*self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = reflect_hasher();
         Hash::hash(&std::any::Any::type_id(self), &mut hasher);
@@ -1462,6 +2675,15 @@ impl Reflect for &'static str {
         }
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            std::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
+
     fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self, f)
     }
@@ -1522,6 +2744,8 @@ impl Reflect for &'static Path {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         let any = value.as_any();
         if let Some(&value) = any.downcast_ref::<Self>() {
             *self = value;
@@ -1556,9 +2780,15 @@ impl Reflect for &'static Path {
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(*self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = reflect_hasher();
         Hash::hash(&std::any::Any::type_id(self), &mut hasher);
@@ -1574,6 +2804,15 @@ impl Reflect for &'static Path {
             Some(false)
         }
     }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            std::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
 }
 
 impl Typed for &'static Path {
@@ -1630,6 +2869,8 @@ impl Reflect for Cow<'static, Path> {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         let any = value.as_any();
         if let Some(value) = any.downcast_ref::<Self>() {
             self.clone_from(value);
@@ -1664,9 +2905,15 @@ impl Reflect for Cow<'static, Path> {
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
     fn reflect_hash(&self) -> Option<u64> {
         let mut hasher = reflect_hasher();
         Hash::hash(&std::any::Any::type_id(self), &mut hasher);
@@ -1683,6 +2930,15 @@ impl Reflect for Cow<'static, Path> {
         }
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        let value = value.as_any();
+        if let Some(value) = value.downcast_ref::<Self>() {
+            std::cmp::PartialOrd::partial_cmp(self, value)
+        } else {
+            None
+        }
+    }
+
     fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self, f)
     }
@@ -1718,19 +2974,193 @@ impl GetTypeRegistration for Cow<'static, Path> {
 #[cfg(feature = "functions")]
 crate::func::macros::impl_function_traits!(Cow<'static, Path>);
 
+macro_rules! impl_reflect_for_atomic {
+    ($($atomic:ident($value:ty)),* $(,)?) => {
+        $(
+            // Atomics are read and written with relaxed ordering,
+            // since reflection is only used to inspect and tweak them.
+            impl Reflect for ::core::sync::atomic::$atomic {
+                fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                    Some(<Self as Typed>::type_info())
+                }
+
+                fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                    self
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_any_mut(&mut self) -> &mut dyn Any {
+                    self
+                }
+
+                fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                    self
+                }
+
+                fn as_reflect(&self) -> &dyn Reflect {
+                    self
+                }
+
+                fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                    self
+                }
+
+                fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                    #[cfg(feature = "stats")]
+                    crate::stats::record_apply();
+                    let any = value.as_any();
+                    if let Some(value) = any.downcast_ref::<Self>() {
+                        self.store(value.load(Ordering::Relaxed), Ordering::Relaxed);
+                    } else if let Some(&value) = any.downcast_ref::<$value>() {
+                        self.store(value, Ordering::Relaxed);
+                    } else {
+                        return Err(ApplyError::MismatchedTypes {
+                            from_type: value.reflect_type_path().into(),
+                            to_type: Self::type_path().into(),
+                        });
+                    }
+                    Ok(())
+                }
+
+                fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                    *self = value.take()?;
+                    Ok(())
+                }
+
+                fn reflect_ref(&self) -> ReflectRef {
+                    ReflectRef::Value(self)
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut {
+                    ReflectMut::Value(self)
+                }
+
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Value(self)
+                }
+
+                fn clone_value(&self) -> Box<dyn Reflect> {
+                    #[cfg(feature = "stats")]
+                    crate::stats::record_clone();
+                    Box::new(Self::new(self.load(Ordering::Relaxed)))
+                }
+
+                fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                    Ok(Box::new(Self::new(self.load(Ordering::Relaxed))))
+                }
+
+                fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                    let value = value.as_any();
+                    if let Some(value) = value.downcast_ref::<Self>() {
+                        Some(self.load(Ordering::Relaxed) == value.load(Ordering::Relaxed))
+                    } else {
+                        Some(false)
+                    }
+                }
+
+                fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl Typed for ::core::sync::atomic::$atomic {
+                fn type_info() -> &'static TypeInfo {
+                    static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+                    CELL.get_or_set(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+                }
+            }
+
+            impl_type_path!(::core::sync::atomic::$atomic);
+
+            impl GetTypeRegistration for ::core::sync::atomic::$atomic {
+                fn get_type_registration() -> TypeRegistration {
+                    let mut registration = TypeRegistration::of::<Self>();
+                    registration.insert::<ReflectDeserialize>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectSerialize>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+                    registration.insert::<ReflectDefault>(FromType::<Self>::from_type());
+                    registration
+                }
+            }
+
+            impl FromReflect for ::core::sync::atomic::$atomic {
+                // Also accept the plain value
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    let any = reflect.as_any();
+                    if let Some(value) = any.downcast_ref::<Self>() {
+                        Some(Self::new(value.load(Ordering::Relaxed)))
+                    } else {
+                        any.downcast_ref::<$value>().copied().map(Self::new)
+                    }
+                }
+            }
+
+            #[cfg(feature = "functions")]
+            crate::func::macros::impl_function_traits!(::core::sync::atomic::$atomic);
+        )*
+    };
+}
+
+impl_reflect_for_atomic!(
+    AtomicBool(bool),
+    AtomicI8(i8),
+    AtomicI16(i16),
+    AtomicI32(i32),
+    AtomicI64(i64),
+    AtomicIsize(isize),
+    AtomicU8(u8),
+    AtomicU16(u16),
+    AtomicU32(u32),
+    AtomicU64(u64),
+    AtomicUsize(usize),
+);
+
 #[cfg(test)]
 mod tests {
     use crate as bevy_reflect;
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
     use crate::{
-        Enum, FromReflect, Reflect, ReflectSerialize, TypeInfo, TypeRegistry, Typed, VariantInfo,
-        VariantType,
+        DynamicStruct, Enum, FromReflect, GetPath, Reflect, ReflectRef, ReflectSerialize, Struct,
+        TypeInfo, TypePath, TypeRegistry, Typed, VariantInfo, VariantType,
     };
     use bevy_utils::HashMap;
     use bevy_utils::{Duration, Instant};
+    use serde::de::DeserializeSeed;
     use static_assertions::assert_impl_all;
     use std::collections::BTreeMap;
     use std::f32::consts::{PI, TAU};
     use std::path::Path;
+    use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
+
+    #[test]
+    fn should_limit_boxed_from_reflect_depth() {
+        #[derive(Reflect, Debug)]
+        #[reflect(no_field_bounds)]
+        struct Link {
+            next: Option<Box<Link>>,
+        }
+
+        fn chain(len: usize) -> Link {
+            (0..len).fold(Link { next: None }, |link, _| Link {
+                next: Some(Box::new(link)),
+            })
+        }
+
+        let max_depth = super::MAX_BOX_FROM_REFLECT_DEPTH;
+        let value = chain(max_depth).clone_value();
+        assert!(Link::from_reflect(value.as_reflect()).is_some());
+
+        let value = chain(max_depth + 1).clone_value();
+        assert!(Link::from_reflect(value.as_reflect()).is_none());
+
+        // The depth is reset after a failed conversion
+        let value = chain(2).clone_value();
+        assert!(Link::from_reflect(value.as_reflect()).is_some());
+    }
 
     #[test]
     fn can_serialize_duration() {
@@ -1743,6 +3173,62 @@ mod tests {
         let _serializable = reflect_serialize.get_serializable(&Duration::ZERO);
     }
 
+    #[test]
+    fn ranges_should_reflect_as_structs() {
+        let info = <std::ops::Range<u32> as Typed>::type_info();
+        let TypeInfo::Struct(info) = info else {
+            panic!("expected struct info");
+        };
+        assert_eq!("core::ops::Range<u32>", info.type_path());
+        assert!(info.field("start").unwrap().is::<u32>());
+        assert!(info.field("end").unwrap().is::<u32>());
+
+        let mut range = 0_u32..10;
+        *range.path_mut::<u32>("end").unwrap() = 5;
+        assert_eq!(0..5, range);
+
+        let mut range = std::ops::RangeInclusive::new(0.0_f32, 1.0);
+        assert_eq!(Some(&1.0), range.path::<f32>("end").ok());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("start", 0.5_f32);
+        range.apply(&patch);
+        assert_eq!(0.5..=1.0, range);
+        assert!(std::ops::RangeInclusive::<i32>::from_reflect(&patch).is_none());
+        assert!(range
+            .reflect_partial_eq(&std::ops::RangeInclusive::new(0.5_f32, 1.0))
+            .unwrap());
+    }
+
+    #[test]
+    fn ranges_should_serialize_as_structs() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<std::ops::RangeInclusive<f32>>();
+        registry.register::<std::ops::RangeFrom<i32>>();
+
+        let range = 0.0_f32..=1.0;
+        let serializer = ReflectSerializer::new(&range, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"core::ops::RangeInclusive<f32>":(start:0.0,end:1.0)}"#,
+            output
+        );
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(range),
+            std::ops::RangeInclusive::<f32>::from_reflect(&*value)
+        );
+
+        let range = -3..;
+        let serializer = ReflectSerializer::new(&range, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(r#"{"core::ops::RangeFrom<i32>":(start:-3)}"#, output);
+    }
+
     #[test]
     fn should_partial_eq_char() {
         let a: &dyn Reflect = &'x';
@@ -1944,6 +3430,69 @@ mod tests {
         assert_eq!(forty_two, std::num::NonZeroUsize::new(42).unwrap());
     }
 
+    #[test]
+    fn nonzero_should_from_reflect_plain_integer() {
+        use std::num::{NonZeroI8, NonZeroU32};
+
+        assert_eq!(NonZeroU32::new(7), NonZeroU32::from_reflect(&7_u32));
+        assert_eq!(NonZeroI8::new(-3), NonZeroI8::from_reflect(&-3_i8));
+        assert_eq!(None, NonZeroU32::from_reflect(&0_u32));
+        assert_eq!(None, NonZeroU32::from_reflect(&7_u64));
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<NonZeroU32>();
+        let from_reflect = registry
+            .get_type_data::<crate::ReflectFromReflect>(std::any::TypeId::of::<NonZeroU32>())
+            .unwrap();
+        let value = from_reflect.from_reflect(&12_u32).unwrap();
+        assert_eq!(NonZeroU32::new(12), value.take::<NonZeroU32>().ok());
+    }
+
+    #[test]
+    fn should_reflect_static_cows() {
+        use std::borrow::Cow;
+
+        static FRAMES: [u32; 3] = [1, 2, 3];
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Animation {
+            name: Cow<'static, str>,
+            frames: Cow<'static, [u32]>,
+        }
+
+        let mut animation = Animation {
+            name: Cow::Borrowed("idle"),
+            frames: Cow::Borrowed(&FRAMES),
+        };
+
+        // Strings are treated as values, and can be set from other string types
+        animation
+            .path_mut::<Cow<'static, str>>("name")
+            .unwrap()
+            .apply(&String::from("walk"));
+        assert_eq!("walk", animation.name);
+        assert!(matches!(animation.name, Cow::Owned(_)));
+        assert_eq!(
+            Some(Cow::Borrowed("run")),
+            <Cow<'static, str>>::from_reflect(&"run")
+        );
+
+        // Slices are lists, and are only copied once mutated
+        let ReflectRef::List(frames) = animation.frames.reflect_ref() else {
+            panic!("expected a list");
+        };
+        assert_eq!(Some(&2), frames.get(1).unwrap().downcast_ref::<u32>());
+        assert!(matches!(animation.frames, Cow::Borrowed(_)));
+
+        *animation.path_mut::<u32>("frames[1]").unwrap() = 5;
+        assert!(matches!(animation.frames, Cow::Owned(_)));
+        assert_eq!([1, 5, 3], *animation.frames);
+        assert_eq!([1, 2, 3], FRAMES);
+
+        let dynamic = animation.clone_value();
+        assert_eq!(Some(animation), Animation::from_reflect(&*dynamic));
+    }
+
     #[test]
     fn instant_should_from_reflect() {
         let expected = Instant::now();
@@ -1965,6 +3514,146 @@ mod tests {
         assert_eq!(type_id, output);
     }
 
+    #[test]
+    fn should_reflect_atomics() {
+        #[derive(Reflect, Default)]
+        struct Counters {
+            hits: AtomicU32,
+            enabled: AtomicBool,
+        }
+
+        let mut counters = Counters::default();
+        counters.hits.fetch_add(3, Ordering::Relaxed);
+        let hits = counters.path::<AtomicU32>("hits").unwrap();
+        assert_eq!(3, hits.load(Ordering::Relaxed));
+
+        counters.field_mut("hits").unwrap().apply(&7_u32);
+        counters
+            .field_mut("enabled")
+            .unwrap()
+            .apply(&AtomicBool::new(true));
+        assert_eq!(7, counters.hits.load(Ordering::Relaxed));
+        assert!(counters.enabled.load(Ordering::Relaxed));
+
+        let cloned = Counters::from_reflect(&*counters.clone_value()).unwrap();
+        assert_eq!(7, cloned.hits.load(Ordering::Relaxed));
+        assert_eq!(Some(true), counters.reflect_partial_eq(&cloned));
+        assert_eq!(
+            Some(5),
+            AtomicI64::from_reflect(&5_i64).map(AtomicI64::into_inner)
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<AtomicU32>();
+        let serializer = ReflectSerializer::new(&counters.hits, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"{"core::sync::atomic::AtomicU32":7}"#, output);
+    }
+
+    #[test]
+    fn should_reflect_hash_sets_with_custom_hasher() {
+        #[derive(TypePath, Default)]
+        struct NoCloneState;
+
+        impl std::hash::BuildHasher for NoCloneState {
+            type Hasher = std::collections::hash_map::DefaultHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                Default::default()
+            }
+        }
+
+        let mut set = std::collections::HashSet::with_hasher(NoCloneState);
+        set.insert(1_u32);
+        set.insert(2_u32);
+
+        let clone = set.clone_value();
+        assert_eq!(Some(true), set.reflect_partial_eq(&*clone));
+
+        let mut other = std::collections::HashSet::with_hasher(NoCloneState);
+        other.insert(3_u32);
+        other.apply(&*clone);
+        assert_eq!(set, other);
+        assert!(<std::collections::HashSet<u32, NoCloneState>>::from_reflect(&other).is_some());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<std::collections::HashSet<u32, NoCloneState>>();
+        registry.register::<std::collections::BTreeSet<u32>>();
+    }
+
+    #[test]
+    fn should_reflect_hash_maps_with_custom_hasher() {
+        #[derive(Default, TypePath)]
+        struct FxLikeHasher(u64);
+
+        impl std::hash::Hasher for FxLikeHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = self.0.rotate_left(5) ^ u64::from(*byte);
+                }
+            }
+        }
+
+        type FxLikeHashMap<K, V> =
+            std::collections::HashMap<K, V, std::hash::BuildHasherDefault<FxLikeHasher>>;
+
+        let mut map = FxLikeHashMap::default();
+        map.insert(String::from("a"), 1_u32);
+        map.insert(String::from("b"), 2_u32);
+
+        let clone = map.clone_value();
+        assert_eq!(Some(true), map.reflect_partial_eq(&*clone));
+        assert_eq!(Some(map.clone()), FxLikeHashMap::from_reflect(&*clone));
+        assert_eq!(
+            "BuildHasherDefault<FxLikeHasher>",
+            <std::hash::BuildHasherDefault<FxLikeHasher>>::short_type_path()
+        );
+        assert_eq!(
+            "bevy_utils::hashbrown::HashMap<alloc::string::String, u32, bevy_utils::hashbrown::hash_map::DefaultHashBuilder>",
+            <HashMap<String, u32>>::type_path()
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<FxLikeHashMap<String, u32>>();
+        registry.register::<bevy_utils::hashbrown::HashMap<
+            String,
+            u32,
+            std::hash::BuildHasherDefault<FxLikeHasher>,
+        >>();
+        registry.register::<HashMap<String, u32>>();
+    }
+
+    #[cfg(feature = "hashbrown")]
+    #[test]
+    fn should_reflect_hashbrown_collections() {
+        let mut map = hashbrown::HashMap::<String, u32>::default();
+        map.insert(String::from("a"), 1);
+        let set = hashbrown::HashSet::<u32>::from_iter([1, 2]);
+
+        let clone = map.clone_value();
+        assert_eq!(Some(true), map.reflect_partial_eq(&*clone));
+        assert_eq!(
+            Some(map),
+            <hashbrown::HashMap<String, u32>>::from_reflect(&*clone)
+        );
+        assert_eq!(
+            Some(set.clone()),
+            <hashbrown::HashSet<u32>>::from_reflect(&*set.clone_value())
+        );
+        assert_eq!(
+            "hashbrown::HashMap<alloc::string::String, u32, hashbrown::DefaultHashBuilder>",
+            <hashbrown::HashMap<String, u32>>::type_path()
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<hashbrown::HashMap<String, u32>>();
+        registry.register::<hashbrown::HashSet<u32>>();
+    }
+
     #[test]
     fn static_str_should_from_reflect() {
         let expected = "Hello, World!";
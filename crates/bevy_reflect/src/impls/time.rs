@@ -0,0 +1,60 @@
+use crate::std_traits::ReflectDefault;
+use crate::{self as bevy_reflect, ReflectDeserialize, ReflectSerialize};
+use bevy_reflect_derive::impl_reflect_value;
+
+impl_reflect_value!(::time::OffsetDateTime(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::time::PrimitiveDateTime(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize
+));
+impl_reflect_value!(::time::Date(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(::time::Time(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(::time::Duration(
+    Debug,
+    Hash,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Default
+));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{from_bincode, ReflectDeserializer, ReflectSerializer},
+        FromReflect, Reflect, TypeRegistry,
+    };
+    use time::{Duration, OffsetDateTime};
+
+    #[test]
+    fn should_roundtrip_offset_date_time() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<OffsetDateTime>();
+
+        let timestamp = OffsetDateTime::from_unix_timestamp(1_715_949_000).unwrap();
+        let serializer = ReflectSerializer::new(&timestamp, &registry);
+        let output = bincode::serialize(&serializer).unwrap();
+
+        let value = from_bincode(ReflectDeserializer::new(&registry), &output).unwrap();
+
+        assert_eq!(Some(timestamp), OffsetDateTime::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_partial_eq_duration() {
+        let a: &dyn Reflect = &Duration::seconds(90);
+        let a2: &dyn Reflect = &(Duration::minutes(1) + Duration::seconds(30));
+        let b: &dyn Reflect = &Duration::minutes(1);
+        assert_eq!(Some(true), a.reflect_partial_eq(a2));
+        assert_eq!(Some(false), a.reflect_partial_eq(b));
+    }
+}
@@ -0,0 +1,267 @@
+use bevy_reflect_derive::impl_type_path;
+use tinyvec::{Array as TinyArray, ArrayVec, TinyVec};
+
+use std::any::Any;
+
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
+    ListIter, ListIterMut, Reflect, ReflectFromPtr, ReflectKind, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
+};
+
+macro_rules! impl_reflect_for_tinyvec {
+    ($ty:ident) => {
+        impl<A: TinyArray + TypePath + Send + Sync> List for $ty<A>
+        where
+            A::Item: FromReflect + TypePath,
+        {
+            fn get(&self, index: usize) -> Option<&dyn Reflect> {
+                <[A::Item]>::get(self, index).map(|value| value as &dyn Reflect)
+            }
+
+            fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                <[A::Item]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+            }
+
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds, or for an [`ArrayVec`], if it is already full.
+            fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
+                let value = value.take::<A::Item>().unwrap_or_else(|value| {
+                    A::Item::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to insert invalid value of type {}.",
+                            value.reflect_type_path()
+                        )
+                    })
+                });
+                $ty::insert(self, index, value);
+            }
+
+            fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+                Box::new($ty::remove(self, index))
+            }
+
+            /// # Panics
+            ///
+            /// Panics if this is an [`ArrayVec`] that is already full.
+            fn push(&mut self, value: Box<dyn Reflect>) {
+                let value = value.take::<A::Item>().unwrap_or_else(|value| {
+                    A::Item::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to push invalid value of type {}.",
+                            value.reflect_type_path()
+                        )
+                    })
+                });
+                $ty::push(self, value);
+            }
+
+            fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+                $ty::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
+            fn len(&self) -> usize {
+                <$ty<A>>::len(self)
+            }
+
+            fn iter(&self) -> ListIter {
+                ListIter::new(self)
+            }
+
+            fn iter_mut(&mut self) -> ListIterMut {
+                ListIterMut::new(<[A::Item]>::iter_mut(self).map(|value| value as &mut dyn Reflect))
+            }
+
+            fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+                self.into_iter()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+        }
+
+        impl<A: TinyArray + TypePath + Send + Sync> Reflect for $ty<A>
+        where
+            A::Item: FromReflect + TypePath,
+        {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn apply(&mut self, value: &dyn Reflect) {
+                crate::list_apply(self, value);
+            }
+
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                crate::list_try_apply(self, value)
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::List
+            }
+
+            fn reflect_ref(&self) -> ReflectRef {
+                ReflectRef::List(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut {
+                ReflectMut::List(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::List(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_clone();
+                Box::new(self.clone_dynamic())
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                crate::list_partial_eq(self, value)
+            }
+
+            fn reflect_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+                crate::list_cmp(self, value)
+            }
+        }
+
+        impl<A: TinyArray + TypePath + Send + Sync> Typed for $ty<A>
+        where
+            A::Item: FromReflect + TypePath,
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, A::Item>()))
+            }
+        }
+
+        impl_type_path!(::tinyvec::$ty<A: TinyArray>);
+
+        impl<A: TinyArray + TypePath + Send + Sync> GetTypeRegistration for $ty<A>
+        where
+            A::Item: FromReflect + TypePath + GetTypeRegistration,
+        {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<$ty<A>>();
+                registration.insert::<ReflectFromPtr>(FromType::<$ty<A>>::from_type());
+                registration
+            }
+
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<A::Item>();
+            }
+        }
+
+        #[cfg(feature = "functions")]
+        crate::func::macros::impl_function_traits!($ty<A>; <A: TinyArray + TypePath + Send + Sync> where A::Item: FromReflect + TypePath);
+    };
+}
+
+impl_reflect_for_tinyvec!(TinyVec);
+impl_reflect_for_tinyvec!(ArrayVec);
+
+impl<A: TinyArray + TypePath + Send + Sync> FromReflect for TinyVec<A>
+where
+    A::Item: FromReflect + TypePath,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
+            let mut new_list = Self::with_capacity(ref_list.len());
+            for field in ref_list.iter() {
+                new_list.push(A::Item::from_reflect(field)?);
+            }
+            Some(new_list)
+        } else {
+            None
+        }
+    }
+}
+
+impl<A: TinyArray + TypePath + Send + Sync> FromReflect for ArrayVec<A>
+where
+    A::Item: FromReflect + TypePath,
+{
+    /// Returns [`None`] if the list has more elements than the capacity of the array.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
+            let mut new_list = Self::new();
+            for field in ref_list.iter() {
+                if new_list.try_push(A::Item::from_reflect(field)?).is_some() {
+                    return None;
+                }
+            }
+            Some(new_list)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicList, FromReflect, List, Reflect};
+    use tinyvec::{array_vec, tiny_vec, ArrayVec, TinyVec};
+
+    #[test]
+    fn should_reflect_tinyvec() {
+        let mut value: TinyVec<[u32; 2]> = tiny_vec![1, 2];
+        List::push(&mut value, Box::new(3_u32));
+        assert!(value.is_heap());
+        assert_eq!(&[1, 2, 3], value.as_slice());
+
+        let mut dynamic = DynamicList::default();
+        dynamic.push(4_u32);
+        value.apply(&dynamic);
+        assert_eq!(&[4, 2, 3], value.as_slice());
+
+        let dynamic = value.clone_dynamic();
+        assert_eq!(Some(value), <TinyVec<[u32; 2]>>::from_reflect(&dynamic));
+    }
+
+    #[test]
+    fn should_reflect_tinyvec_arrayvec() {
+        let mut value: ArrayVec<[u32; 3]> = array_vec![1, 2];
+        List::insert(&mut value, 0, Box::new(0_u32));
+        assert_eq!(&[0, 1, 2], value.as_slice());
+        assert_eq!(
+            Some(&2_u32),
+            List::get(&value, 2).and_then(<dyn Reflect>::downcast_ref)
+        );
+
+        let dynamic = value.clone_dynamic();
+        assert_eq!(Some(value), <ArrayVec<[u32; 3]>>::from_reflect(&dynamic));
+        assert!(<ArrayVec<[u32; 2]>>::from_reflect(&dynamic).is_none());
+    }
+}
@@ -11,3 +11,50 @@ impl_reflect_value!(::uuid::Uuid(
     PartialEq,
     Hash
 ));
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        std_traits::ReflectDefault,
+        FromReflect, Reflect, TypeRegistry,
+    };
+    use serde::de::DeserializeSeed;
+    use uuid::Uuid;
+
+    #[test]
+    fn should_serialize_uuid_as_hyphenated_string() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Uuid>();
+
+        let uuid = Uuid::from_u128(0x9cb4cf49_5c3d_4647_b782_0bb5a1b2c3d4);
+        let serializer = ReflectSerializer::new(&uuid, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"uuid::Uuid":"9cb4cf49-5c3d-4647-b782-0bb5a1b2c3d4"}"#,
+            output
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(uuid), Uuid::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_default_to_nil_uuid() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Uuid>();
+
+        let default = registry
+            .get_type_data::<ReflectDefault>(std::any::TypeId::of::<Uuid>())
+            .unwrap()
+            .default();
+        assert_eq!(Some(&Uuid::nil()), default.downcast_ref::<Uuid>());
+        assert_eq!(
+            Some(true),
+            default.reflect_partial_eq(&Uuid::nil() as &dyn Reflect)
+        );
+    }
+}
@@ -473,41 +473,75 @@
 //! [`bevy_reflect_derive/documentation`]: bevy_reflect_derive
 //! [derive `Reflect`]: derive@crate::Reflect
 
+mod apply;
 mod array;
+mod computed;
+mod constraints;
+mod diff;
+mod display_name;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
 pub mod func;
+mod generics;
 mod list;
+mod list_map;
 mod map;
+mod numeric;
+mod overlay;
 mod path;
+mod primitive_slice;
+mod propagation;
 mod reflect;
+mod reflect_ptr;
+mod registry_merge;
+mod remote;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
+mod type_hash;
 mod type_info;
 mod type_path;
 mod type_registry;
 
 mod impls {
+    #[cfg(feature = "arrayvec")]
+    mod arrayvec;
+    #[cfg(feature = "bytes")]
+    mod bytes;
+    #[cfg(feature = "camino")]
+    mod camino;
+    #[cfg(feature = "chrono")]
+    mod chrono;
     #[cfg(feature = "glam")]
     mod glam;
+    #[cfg(feature = "indexmap")]
+    mod indexmap;
     #[cfg(feature = "petgraph")]
     mod petgraph;
     #[cfg(feature = "smallvec")]
     mod smallvec;
     #[cfg(feature = "smol_str")]
     mod smol_str;
+    #[cfg(feature = "tinyvec")]
+    mod tinyvec;
 
     mod std;
+    #[cfg(feature = "time")]
+    mod time;
     #[cfg(feature = "uuid")]
     mod uuid;
 }
 
 pub mod attributes;
 mod enums;
+pub mod schema;
 pub mod serde;
+#[cfg(feature = "stats")]
+pub mod stats;
 pub mod std_traits;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utility;
 
 pub mod prelude {
@@ -523,14 +557,27 @@ pub mod prelude {
     pub use crate::func::IntoFunction;
 }
 
+pub use apply::*;
 pub use array::*;
+pub use computed::*;
+pub use constraints::*;
+pub use diff::*;
+pub use display_name::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
+pub use generics::*;
 pub use list::*;
+pub use list_map::*;
 pub use map::*;
+pub use numeric::*;
+pub use overlay::*;
 pub use path::*;
+pub use primitive_slice::*;
 pub use reflect::*;
+pub use reflect_ptr::*;
+pub use registry_merge::*;
+pub use remote::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
@@ -550,7 +597,7 @@ extern crate alloc;
 pub mod __macro_exports {
     use crate::{
         DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-        DynamicTupleStruct, GetTypeRegistration, TypeRegistry,
+        DynamicTupleStruct, GetTypeRegistration, NamedField, TypeInfo, TypeRegistry, Typed,
     };
 
     /// A wrapper trait around [`GetTypeRegistration`].
@@ -585,6 +632,175 @@ pub mod __macro_exports {
     impl RegisterForReflection for DynamicArray {}
 
     impl RegisterForReflection for DynamicTuple {}
+
+    /// The names of the fields of a derived struct, as seen by a struct it is flattened into.
+    ///
+    /// This is used by the derive macro to reject collisions between the names of flattened fields at compile time.
+    pub trait FlattenedFields {
+        const FIELDS: &'static [FlattenedField];
+    }
+
+    /// A field of a struct implementing [`FlattenedFields`].
+    pub enum FlattenedField {
+        /// A field with the given name.
+        Named(&'static str),
+        /// A field marked with `#[reflect(flatten)]`, with the fields of its type.
+        Flattened(&'static [FlattenedField]),
+    }
+
+    /// Holds the compile-time check that the fields of `T` have unique names.
+    pub struct UniqueFieldNames<T>(core::marker::PhantomData<T>);
+
+    impl<T: FlattenedFields> UniqueFieldNames<T> {
+        /// Fails to evaluate if two fields of `T`, including flattened ones, share a name.
+        pub const ASSERT: () = assert!(
+            has_unique_field_names(T::FIELDS),
+            "a field is defined more than once in this struct and the structs flattened into it"
+        );
+    }
+
+    const fn flattened_field_count(fields: &[FlattenedField]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < fields.len() {
+            count += match &fields[i] {
+                FlattenedField::Named(_) => 1,
+                FlattenedField::Flattened(inner) => flattened_field_count(inner),
+            };
+            i += 1;
+        }
+        count
+    }
+
+    const fn flattened_field_name(
+        fields: &'static [FlattenedField],
+        mut index: usize,
+    ) -> &'static str {
+        let mut i = 0;
+        loop {
+            match &fields[i] {
+                FlattenedField::Named(name) => {
+                    if index == 0 {
+                        return name;
+                    }
+                    index -= 1;
+                }
+                FlattenedField::Flattened(inner) => {
+                    let len = flattened_field_count(inner);
+                    if index < len {
+                        return flattened_field_name(inner, index);
+                    }
+                    index -= len;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    const fn str_eq(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn has_unique_field_names(fields: &'static [FlattenedField]) -> bool {
+        let count = flattened_field_count(fields);
+        let mut i = 0;
+        while i < count {
+            let mut j = i + 1;
+            while j < count {
+                if str_eq(
+                    flattened_field_name(fields, i),
+                    flattened_field_name(fields, j),
+                ) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Appends the fields of the struct `T` to `fields`, for a field of `outer` marked with `#[reflect(flatten)]`.
+    ///
+    /// Collisions are normally rejected at compile time through [`UniqueFieldNames`],
+    /// except when the flattened type is a type parameter of `outer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not a struct, or if one of its fields has the same name as a field in `fields`.
+    pub fn extend_flattened_fields<T: Typed>(outer: &str, fields: &mut Vec<NamedField>) {
+        let TypeInfo::Struct(info) = T::type_info() else {
+            panic!(
+                "cannot flatten `{}` into `{outer}`: only structs can be flattened",
+                T::type_info().type_path()
+            );
+        };
+        for field in info.iter() {
+            assert!(
+                fields
+                    .iter()
+                    .all(|existing| existing.name() != field.name()),
+                "cannot flatten `{}` into `{outer}`: the field `{}` is defined more than once",
+                info.type_path(),
+                field.name()
+            );
+            fields.push(field.clone());
+        }
+    }
+
+    /// Returns the number of fields the struct `T` contributes to a struct it is flattened into.
+    pub fn flattened_field_len<T: Typed>() -> usize {
+        match T::type_info() {
+            TypeInfo::Struct(info) => info.field_len(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the [`SerializationData`] of the struct `T`, for a field marked with `#[reflect(flatten)]`.
+    ///
+    /// [`SerializationData`]: crate::serde::SerializationData
+    pub fn flattened_serialization_data<T: RegisterForReflection + 'static>(
+    ) -> Option<crate::serde::SerializationData> {
+        let mut registry = TypeRegistry::empty();
+        T::__register(&mut registry);
+        registry
+            .get_type_data::<crate::serde::SerializationData>(std::any::TypeId::of::<T>())
+            .cloned()
+    }
+
+    /// Hooks used by the derive macro to update the counters in the `stats` module.
+    ///
+    /// These do nothing unless the `stats` feature is enabled.
+    pub mod stats {
+        #[inline(always)]
+        pub fn record_dynamic_allocation() {
+            #[cfg(feature = "stats")]
+            crate::stats::record_dynamic_allocation();
+        }
+
+        #[inline(always)]
+        pub fn record_apply() {
+            #[cfg(feature = "stats")]
+            crate::stats::record_apply();
+        }
+
+        #[inline(always)]
+        pub fn record_clone() {
+            #[cfg(feature = "stats")]
+            crate::stats::record_clone();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -659,6 +875,388 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_apply_should_report_error_path() {
+        #[derive(Reflect, Debug)]
+        struct Stats {
+            hp: u32,
+        }
+
+        #[derive(Reflect, Debug)]
+        struct Item {
+            stats: Stats,
+        }
+
+        #[derive(Reflect, Debug)]
+        struct Inventory {
+            items: Vec<Item>,
+        }
+
+        let mut target = Inventory {
+            items: vec![Item {
+                stats: Stats { hp: 1 },
+            }],
+        };
+
+        let mut stats = DynamicStruct::default();
+        stats.insert("hp", String::from("full"));
+        let mut item = DynamicStruct::default();
+        item.insert("stats", stats);
+        let mut items = DynamicList::default();
+        items.push(item);
+        let mut patch = DynamicStruct::default();
+        patch.insert("items", items);
+
+        let result = target.try_apply(&patch);
+        let Err(error) = result else {
+            panic!("expected error but got {result:?}");
+        };
+        assert_eq!(Some(".items[0].stats.hp"), error.path());
+        assert!(
+            matches!(error.root_cause(), ApplyError::MismatchedTypes { .. }),
+            "error was {error:?}"
+        );
+        assert_eq!(
+            "failed at `.items[0].stats.hp`: `alloc::string::String` is not `u32`",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_not_mutate_read_only_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Entity {
+            #[reflect(read_only)]
+            generation: u32,
+            index: u32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Handle(#[reflect(read_only)] u64, bool);
+
+        let TypeInfo::Struct(info) = <Entity as Typed>::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("generation").unwrap().is_read_only());
+        assert!(!info.field("index").unwrap().is_read_only());
+
+        let mut entity = Entity {
+            generation: 1,
+            index: 2,
+        };
+        assert!(entity.field("generation").is_some());
+        assert!(entity.field_mut("generation").is_none());
+        assert!(entity.field_at_mut(0).is_none());
+        assert!(entity.field_mut("index").is_some());
+
+        // Applying the current value of a read-only field is allowed
+        entity.apply(&Entity {
+            generation: 1,
+            index: 3,
+        });
+        assert_eq!(
+            Entity {
+                generation: 1,
+                index: 3
+            },
+            entity
+        );
+
+        let result = entity.try_apply(&Entity {
+            generation: 5,
+            index: 4,
+        });
+        assert!(
+            matches!(result, Err(ApplyError::ReadOnlyField { ref field_name }) if &**field_name == "generation"),
+            "result was {result:?}"
+        );
+
+        let mut handle = Handle(7, false);
+        assert!(handle.field_mut(0).is_none());
+        assert!(matches!(
+            handle.try_apply(&Handle(8, true)),
+            Err(ApplyError::ReadOnlyField { .. })
+        ));
+
+        // Read-only fields are still serialized
+        let mut registry = TypeRegistry::default();
+        registry.register::<Entity>();
+        let output = ron::to_string(&ReflectSerializer::new(&entity, &registry)).unwrap();
+        assert!(output.contains("generation:1"), "output was {output}");
+    }
+
+    #[test]
+    fn should_ignore_uncomparable_read_only_fields() {
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        #[reflect_value(Debug)]
+        struct Opaque(u32);
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct Owner {
+            #[reflect(read_only)]
+            key: Opaque,
+            value: u32,
+        }
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        struct TupleOwner(#[reflect(read_only)] Opaque, u32);
+
+        let mut owner = Owner {
+            key: Opaque(1),
+            value: 2,
+        };
+        owner.apply(&owner.clone());
+        owner
+            .try_apply(&Owner {
+                key: Opaque(5),
+                value: 3,
+            })
+            .unwrap();
+        assert_eq!(
+            Owner {
+                key: Opaque(1),
+                value: 3
+            },
+            owner
+        );
+
+        let registry = TypeRegistry::default();
+        let value = Owner {
+            key: Opaque(5),
+            value: 4,
+        };
+        try_apply_with(&mut owner, &value, &registry).unwrap();
+        assert_eq!(Opaque(1), owner.key);
+        assert_eq!(4, owner.value);
+
+        let mut owner = TupleOwner(Opaque(1), 2);
+        owner.apply(&TupleOwner(Opaque(5), 3));
+        assert_eq!(TupleOwner(Opaque(1), 3), owner);
+    }
+
+    #[test]
+    fn should_flatten_nested_structs() {
+        #[derive(Reflect, Debug, PartialEq, Clone)]
+        struct CommonSettings {
+            enabled: bool,
+            volume: f32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq, Clone)]
+        struct AudioConfig {
+            name: String,
+            #[reflect(flatten)]
+            common: CommonSettings,
+            channels: u8,
+        }
+
+        let TypeInfo::Struct(info) = <AudioConfig as Typed>::type_info() else {
+            panic!("expected struct info");
+        };
+        let names: Vec<_> = info.field_names().to_vec();
+        assert_eq!(vec!["name", "enabled", "volume", "channels"], names);
+
+        let mut config = AudioConfig {
+            name: "music".to_string(),
+            common: CommonSettings {
+                enabled: true,
+                volume: 0.5,
+            },
+            channels: 2,
+        };
+        assert_eq!(4, config.field_len());
+        assert_eq!(Some("volume"), config.name_at(2));
+        assert_eq!(Some("channels"), config.name_at(3));
+        assert_eq!(Some(&0.5), config.get_field::<f32>("volume"));
+        assert_eq!(Some(&2), config.field_at(3).unwrap().downcast_ref::<u8>());
+        assert!(config.field("common").is_none());
+
+        *config.get_field_mut::<f32>("volume").unwrap() = 0.75;
+        *config.path_mut::<bool>("enabled").unwrap() = false;
+        assert_eq!(0.75, config.common.volume);
+        assert!(!config.common.enabled);
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("volume", 1.0_f32);
+        config.apply(&patch);
+        assert_eq!(1.0, config.common.volume);
+
+        let dynamic = config.clone_dynamic();
+        assert_eq!(Some(&false), dynamic.get_field::<bool>("enabled"));
+        assert_eq!(Some(config.clone()), AudioConfig::from_reflect(&dynamic));
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<AudioConfig>();
+        let output = ron::to_string(&ReflectSerializer::new(&config, &registry)).unwrap();
+        assert!(output.contains("volume:1.0"), "output was {output}");
+        assert!(!output.contains("common"), "output was {output}");
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(config), AudioConfig::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_keep_serialization_data_of_flattened_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Stats {
+            health: u32,
+            #[reflect(skip_serializing)]
+            cached_power: u32,
+            #[reflect(default = "default_armor")]
+            armor: u32,
+        }
+
+        fn default_armor() -> u32 {
+            5
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Unit {
+            name: String,
+            #[reflect(flatten)]
+            stats: Stats,
+            #[reflect(skip_serializing)]
+            selected: bool,
+            #[reflect(default)]
+            level: u8,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Unit>();
+
+        let data = registry
+            .get_type_data::<crate::serde::SerializationData>(TypeId::of::<Unit>())
+            .unwrap();
+        assert!(data.is_field_skipped(2));
+        assert!(data.is_field_skipped(4));
+        assert_eq!(2, data.len());
+        assert!(data.generate_missing(3).is_some());
+        assert!(data.generate_missing(5).is_some());
+
+        let unit = Unit {
+            name: "knight".to_string(),
+            stats: Stats {
+                health: 10,
+                cached_power: 99,
+                armor: 3,
+            },
+            selected: true,
+            level: 2,
+        };
+        let output = ron::to_string(&ReflectSerializer::new(&unit, &registry)).unwrap();
+        assert!(!output.contains("cached_power"), "output was {output}");
+        assert!(!output.contains("selected"), "output was {output}");
+        assert!(output.contains("armor:3"), "output was {output}");
+
+        let input = r#"{
+            "bevy_reflect::tests::Unit": (name: "archer", health: 7),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(Unit {
+                name: "archer".to_string(),
+                stats: Stats {
+                    health: 7,
+                    cached_power: 0,
+                    armor: 5,
+                },
+                selected: false,
+                level: 0,
+            }),
+            Unit::from_reflect(&*value)
+        );
+    }
+
+    #[test]
+    fn should_reconstruct_ignored_fields_from_reflect() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Polygon {
+            points: Vec<u32>,
+            #[reflect(ignore, rebuild_with = "Polygon::compute_perimeter")]
+            perimeter: u32,
+            #[reflect(ignore, clone_with = "Clone::clone")]
+            label: String,
+            #[reflect(
+                ignore,
+                clone_with = "Clone::clone",
+                rebuild_with = "Polygon::compute_count"
+            )]
+            count: usize,
+        }
+
+        impl Polygon {
+            fn compute_perimeter(&self) -> u32 {
+                self.points.as_slice().iter().sum()
+            }
+
+            fn compute_count(&self) -> usize {
+                self.points.len()
+            }
+        }
+
+        let polygon = Polygon {
+            points: vec![1, 2, 3],
+            perimeter: 100,
+            label: "triangle".to_string(),
+            count: 42,
+        };
+
+        // Concrete values clone the fields that support it
+        assert_eq!(
+            Some(Polygon {
+                points: vec![1, 2, 3],
+                perimeter: 6,
+                label: "triangle".to_string(),
+                count: 42,
+            }),
+            Polygon::from_reflect(&polygon)
+        );
+
+        // Dynamic values can only rebuild them
+        let expected = Polygon {
+            points: vec![1, 2, 3],
+            perimeter: 6,
+            label: String::new(),
+            count: 3,
+        };
+        let dynamic = polygon.clone_value();
+        assert_eq!(Some(&expected), Polygon::from_reflect(&*dynamic).as_ref());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Polygon>();
+        let rfr = registry
+            .get_type_data::<ReflectFromReflect>(TypeId::of::<Polygon>())
+            .unwrap();
+        let value = rfr.from_reflect(&*dynamic).unwrap();
+        assert_eq!(Some(&expected), value.downcast_ref::<Polygon>());
+    }
+
+    #[test]
+    #[should_panic(expected = "the field `enabled` is defined more than once")]
+    fn should_panic_on_flattened_type_parameter_collision() {
+        #[derive(Reflect)]
+        struct Settings {
+            enabled: bool,
+        }
+
+        // Collisions with a flattened type parameter can't be rejected at compile time
+        #[derive(Reflect)]
+        #[reflect(where T: Struct + Typed)]
+        struct Config<T> {
+            enabled: bool,
+            #[reflect(flatten)]
+            settings: T,
+        }
+
+        <Config<Settings> as Typed>::type_info();
+    }
+
     #[test]
     fn reflect_struct() {
         #[derive(Reflect)]
@@ -1936,6 +2534,34 @@ mod tests {
                 panic!("expected enum info");
             }
         }
+
+        #[test]
+        fn current_variant_should_contain_docs() {
+            #[derive(Reflect)]
+            enum Mode {
+                /// Runs forever
+                #[reflect(@0..=10_u8)]
+                Loop,
+                /// Runs once
+                Once,
+            }
+
+            let value = Mode::Loop;
+            let variant = value.get_represented_variant_info().unwrap();
+            assert_eq!(Some(" Runs forever"), variant.docs());
+            assert_eq!(
+                Some(&(0..=10)),
+                variant.get_attribute::<std::ops::RangeInclusive<u8>>()
+            );
+
+            let dynamic = DynamicEnum::from_ref(&Mode::Once);
+            let variant = dynamic.get_represented_variant_info().unwrap();
+            assert_eq!(Some(" Runs once"), variant.docs());
+            assert!(variant.custom_attributes().is_empty());
+
+            let unrepresented = DynamicEnum::new("Once", ());
+            assert!(unrepresented.get_represented_variant_info().is_none());
+        }
     }
 
     #[test]
@@ -2126,6 +2752,136 @@ bevy_reflect::tests::Test {
         assert_eq!("123", format!("{:?}", foo));
     }
 
+    #[test]
+    fn should_reflect_clone_concrete_types() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            inventory: Vec<Option<u32>>,
+            #[reflect(ignore, clone_with = "Clone::clone")]
+            cache: Vec<u32>,
+            #[reflect(ignore, default)]
+            dirty: bool,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Shape {
+            Circle { radius: f32 },
+            Polygon(Vec<(f32, f32)>, #[reflect(ignore, default)] Option<usize>),
+            Empty,
+        }
+
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        #[reflect(Clone)]
+        struct Counter(#[reflect(ignore)] usize);
+
+        #[derive(Reflect)]
+        struct Handle(
+            #[reflect(ignore)]
+            #[allow(dead_code)]
+            usize,
+        );
+
+        let player = Player {
+            name: "Alice".to_string(),
+            inventory: vec![Some(1), None],
+            cache: vec![3, 2, 1],
+            dirty: true,
+        };
+        let clone = player.reflect_clone().unwrap().take::<Player>().unwrap();
+        assert_eq!(player.name, clone.name);
+        assert_eq!(player.inventory, clone.inventory);
+        assert_eq!(player.cache, clone.cache);
+        assert!(!clone.dirty);
+
+        let shape = Shape::Polygon(vec![(0.0, 1.0)], Some(2));
+        let clone = shape.reflect_clone().unwrap().take::<Shape>().unwrap();
+        assert_eq!(Shape::Polygon(vec![(0.0, 1.0)], None), clone);
+        let shape = Shape::Circle { radius: 3.0 };
+        assert_eq!(
+            shape,
+            shape.reflect_clone().unwrap().take::<Shape>().unwrap()
+        );
+
+        let counter = Counter(5);
+        assert_eq!(
+            counter,
+            counter.reflect_clone().unwrap().take::<Counter>().unwrap()
+        );
+
+        let error = Handle(1).reflect_clone().unwrap_err();
+        assert!(matches!(
+            error,
+            ReflectCloneError::FieldNotCloneable { ref field_name, .. } if &**field_name == "0"
+        ));
+
+        let value: Box<dyn Reflect> = Box::new(HashMap::from_iter([(1_u8, "a".to_string())]));
+        let clone = value.reflect_clone().unwrap();
+        assert!(clone.is::<HashMap<u8, String>>());
+        assert!(value.reflect_partial_eq(&*clone).unwrap());
+
+        let dynamic = player.clone_dynamic();
+        let clone = dynamic.reflect_clone().unwrap();
+        assert!(clone.is::<DynamicStruct>());
+        assert!(dynamic.reflect_partial_eq(&*clone).unwrap());
+    }
+
+    #[test]
+    fn should_compare_reflected_values() {
+        use std::cmp::Ordering;
+
+        #[derive(Reflect, PartialEq, PartialOrd)]
+        #[reflect(PartialOrd)]
+        struct Version(u32, u32);
+
+        #[derive(Reflect)]
+        #[reflect(PartialOrd(cmp_len))]
+        struct Name(String);
+
+        fn cmp_len(a: &Name, b: &dyn Reflect) -> Option<Ordering> {
+            let b = b.downcast_ref::<Name>()?;
+            Some(a.0.len().cmp(&b.0.len()))
+        }
+
+        #[derive(Reflect)]
+        struct Unordered(u32);
+
+        assert_eq!(Some(Ordering::Less), 1_u8.reflect_cmp(&2_u8));
+        assert_eq!(
+            Some(Ordering::Greater),
+            "b".to_string().reflect_cmp(&"a".to_string())
+        );
+        assert_eq!(None, f32::NAN.reflect_cmp(&1.0_f32));
+        assert_eq!(None, 1_u8.reflect_cmp(&1_u16));
+
+        assert_eq!(
+            Some(Ordering::Less),
+            Version(1, 2).reflect_cmp(&Version(1, 3))
+        );
+        assert_eq!(
+            Some(Ordering::Greater),
+            Name("long".into()).reflect_cmp(&Name("a".into()))
+        );
+        assert_eq!(None, Unordered(1).reflect_cmp(&Unordered(2)));
+
+        assert_eq!(Some(Ordering::Less), vec![1, 2].reflect_cmp(&vec![1, 2, 0]));
+        assert_eq!(
+            Some(Ordering::Greater),
+            vec![1, 3].reflect_cmp(&vec![1, 2, 5].clone_dynamic())
+        );
+        assert_eq!(Some(Ordering::Equal), [1, 2].reflect_cmp(&[1, 2]));
+        assert_eq!(
+            Some(Ordering::Less),
+            (1, "a".to_string()).reflect_cmp(&(1, "b".to_string()))
+        );
+        assert_eq!(None, vec![Unordered(1)].reflect_cmp(&vec![Unordered(1)]));
+
+        let a: HashMap<u32, u32> = [(2, 0), (1, 5)].into_iter().collect();
+        let b: HashMap<u32, u32> = [(1, 5), (2, 1)].into_iter().collect();
+        assert_eq!(Some(Ordering::Less), a.reflect_cmp(&b));
+        assert_eq!(Some(Ordering::Equal), a.reflect_cmp(&a.clone_dynamic()));
+    }
+
     #[test]
     fn should_allow_custom_where() {
         #[derive(Reflect)]
@@ -2268,6 +3024,68 @@ bevy_reflect::tests::Test {
         assert!(registry.contains(TypeId::of::<RecurseB>()));
     }
 
+    #[test]
+    fn should_reflect_boxed_recursive_types() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(no_field_bounds)]
+        struct Node {
+            value: i32,
+            children: Vec<Node>,
+            parent_hint: Option<Box<Node>>,
+        }
+
+        let info = <Box<Node> as Typed>::type_info();
+        assert!(info.is::<Box<Node>>());
+        let TypeInfo::Struct(info) = info else {
+            panic!("expected struct info");
+        };
+        assert_eq!(3, info.field_len());
+        assert!(info.field("parent_hint").unwrap().is::<Option<Box<Node>>>());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Node>();
+        assert!(registry.contains(TypeId::of::<Box<Node>>()));
+        assert!(registry.contains(TypeId::of::<Option<Box<Node>>>()));
+
+        let leaf = |value| Node {
+            value,
+            children: Vec::new(),
+            parent_hint: None,
+        };
+        let mut node = Node {
+            value: 1,
+            children: vec![leaf(2)],
+            parent_hint: Some(Box::new(leaf(0))),
+        };
+
+        // Clone
+        let cloned = Node::from_reflect(node.clone_value().as_reflect()).unwrap();
+        assert_eq!(node, cloned);
+
+        // Apply
+        let patch = Node {
+            value: 1,
+            children: vec![leaf(2)],
+            parent_hint: Some(Box::new(leaf(-1))),
+        };
+        node.apply(&patch);
+        assert_eq!(patch, node);
+        assert!(node.reflect_partial_eq(&patch).unwrap());
+
+        // Path
+        *node.path_mut::<i32>("parent_hint.0.value").unwrap() = -2;
+        assert_eq!(-2, node.parent_hint.as_ref().unwrap().value);
+
+        // Serialization
+        let serializer = ReflectSerializer::new(&node, &registry);
+        let output = to_string_pretty(&serializer, PrettyConfig::default()).unwrap();
+
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut deserializer = Deserializer::from_str(&output).unwrap();
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(node, Node::from_reflect(value.as_ref()).unwrap());
+    }
+
     #[test]
     fn can_opt_out_type_path() {
         #[derive(Reflect)]
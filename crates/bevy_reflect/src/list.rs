@@ -1,13 +1,16 @@
 use std::any::{Any, TypeId};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::ops::{Bound, Range, RangeBounds};
 
 use bevy_reflect_derive::impl_type_path;
 
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::utility::reflect_hasher;
 use crate::{
-    self as bevy_reflect, ApplyError, FromReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, ApplyError, FromReflect, PrimitiveSlice, Reflect, ReflectCloneError,
+    ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
 
 /// A trait used to power [list-like] operations via [reflection].
@@ -82,6 +85,21 @@ pub trait List: Reflect {
         }
     }
 
+    /// Inserts an element at the _front_ of the list,
+    /// shifting all other elements towards the back of the list.
+    fn push_front(&mut self, value: Box<dyn Reflect>) {
+        self.insert(0, value);
+    }
+
+    /// Removes the _front_ element from the list and returns it, or [`None`] if it is empty.
+    fn pop_front(&mut self) -> Option<Box<dyn Reflect>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove(0))
+        }
+    }
+
     /// Returns the number of elements in the list.
     fn len(&self) -> usize;
 
@@ -93,16 +111,41 @@ pub trait List: Reflect {
     /// Returns an iterator over the list.
     fn iter(&self) -> ListIter;
 
+    /// Returns an iterator over mutable references to the elements of the list.
+    ///
+    /// # Migration
+    ///
+    /// This method was added as a required method, since a default implementation
+    /// can't hand out mutable references to several elements at once through
+    /// [`get_mut`](List::get_mut).
+    /// Manual implementations of [`List`] wrapping a collection can usually forward to
+    /// the collection's own `iter_mut`, e.g.
+    /// `ListIterMut::new(self.0.iter_mut().map(|item| item as &mut dyn Reflect))`.
+    fn iter_mut(&mut self) -> ListIterMut;
+
     /// Drain the elements of this list to get a vector of owned values.
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>>;
 
     /// Clones the list, producing a [`DynamicList`].
     fn clone_dynamic(&self) -> DynamicList {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicList {
             represented_type: self.get_represented_type_info(),
             values: self.iter().map(Reflect::clone_value).collect(),
         }
     }
+
+    /// Returns the elements of the list as a contiguous slice of primitives,
+    /// or `None` if the list doesn't store its elements that way.
+    ///
+    /// When this returns a slice, serializing, cloning, hashing and comparing the list
+    /// operate on the slice directly instead of on each element through [`Reflect`].
+    ///
+    /// See [`ListInfo::has_primitive_slice`] for checking this ahead of time.
+    fn as_primitive_slice(&self) -> Option<PrimitiveSlice> {
+        None
+    }
 }
 
 /// A container for compile-time list info.
@@ -112,11 +155,22 @@ pub struct ListInfo {
     type_id: TypeId,
     item_type_path: TypePathTable,
     item_type_id: TypeId,
+    primitive_slice: bool,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl ListInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`ListInfo`].
     pub fn new<TList: List + TypePath, TItem: FromReflect + TypePath>() -> Self {
         Self {
@@ -124,6 +178,9 @@ impl ListInfo {
             type_id: TypeId::of::<TList>(),
             item_type_path: TypePathTable::of::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
+            primitive_slice: PrimitiveSlice::supports::<TItem>()
+                && TypeId::of::<TList>() == TypeId::of::<Vec<TItem>>(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -179,11 +236,18 @@ impl ListInfo {
         TypeId::of::<T>() == self.item_type_id
     }
 
+    /// Whether values of this list expose their elements through [`List::as_primitive_slice`].
+    pub fn has_primitive_slice(&self) -> bool {
+        self.primitive_slice
+    }
+
     /// The docstring of this list, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
+
+    impl_generic_info_methods!(self.generics);
 }
 
 /// A list of reflected values.
@@ -218,9 +282,61 @@ impl DynamicList {
     }
 
     /// Appends a [`Reflect`] trait object to the list.
+    ///
+    /// This does not check that the value matches the item type of the [represented type].
+    /// Use [`try_push_box`] to validate the value before it is added.
+    ///
+    /// [represented type]: Reflect::get_represented_type_info
+    /// [`try_push_box`]: Self::try_push_box
     pub fn push_box(&mut self, value: Box<dyn Reflect>) {
         self.values.push(value);
     }
+
+    /// Appends a [`Reflect`] trait object to the list,
+    /// checking it against the item type of the [represented type].
+    ///
+    /// If this list has no represented type, this behaves exactly like [`push_box`].
+    /// Dynamic values are compared using their own represented type,
+    /// and are rejected if they don't have one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplyError::MismatchedTypes`] if the value's type does not match the list's item type.
+    ///
+    /// [represented type]: Reflect::get_represented_type_info
+    /// [`push_box`]: Self::push_box
+    pub fn try_push_box(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        if let Some(TypeInfo::List(list_info)) = self.represented_type {
+            let value_type_id = value
+                .get_represented_type_info()
+                .map(TypeInfo::type_id)
+                .or_else(|| (!value.is_dynamic()).then(|| value.as_any().type_id()));
+
+            if value_type_id != Some(list_info.item_type_id()) {
+                return Err(ApplyError::MismatchedTypes {
+                    from_type: value.reflect_type_path().into(),
+                    to_type: list_info.item_type_path_table().path().into(),
+                });
+            }
+        }
+
+        self.values.push(value);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl DynamicList {
+    /// Returns a [parallel iterator] over mutable references to the elements of the list.
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_iter_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut dyn Reflect> {
+        use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+        self.values.par_iter_mut().map(|value| &mut **value)
+    }
 }
 
 impl List for DynamicList {
@@ -256,11 +372,17 @@ impl List for DynamicList {
         ListIter::new(self)
     }
 
+    fn iter_mut(&mut self) -> ListIterMut {
+        ListIterMut::new(self.values.iter_mut().map(|value| &mut **value))
+    }
+
     fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
         self.values
     }
 
     fn clone_dynamic(&self) -> DynamicList {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicList {
             represented_type: self.represented_type,
             values: self
@@ -344,9 +466,15 @@ impl Reflect for DynamicList {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         list_hash(self)
@@ -356,6 +484,10 @@ impl Reflect for DynamicList {
         list_partial_eq(self, value)
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        list_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicList(")?;
         list_debug(self, f)?;
@@ -391,13 +523,46 @@ impl IntoIterator for DynamicList {
 pub struct ListIter<'a> {
     list: &'a dyn List,
     index: usize,
+    elements: Option<Box<dyn Iterator<Item = &'a dyn Reflect> + 'a>>,
 }
 
 impl<'a> ListIter<'a> {
     /// Creates a new [`ListIter`].
     #[inline]
     pub const fn new(list: &'a dyn List) -> ListIter {
-        ListIter { list, index: 0 }
+        ListIter {
+            list,
+            index: 0,
+            elements: None,
+        }
+    }
+
+    /// Creates a new [`ListIter`] over a list stored as two contiguous slices,
+    /// such as a [`VecDeque`].
+    ///
+    /// Elements are read directly from `front` and then `back`,
+    /// instead of being fetched by index through [`List::get`].
+    ///
+    /// [`VecDeque`]: std::collections::VecDeque
+    pub fn from_slices<T: Reflect>(list: &'a dyn List, front: &'a [T], back: &'a [T]) -> Self {
+        Self::from_elements(list, front.iter().chain(back))
+    }
+
+    /// Creates a new [`ListIter`] that reads the elements of `list` from `elements`,
+    /// which must yield them in order.
+    ///
+    /// This is useful for lists without efficient indexed access, such as a [`LinkedList`].
+    ///
+    /// [`LinkedList`]: std::collections::LinkedList
+    pub fn from_elements<T: Reflect>(
+        list: &'a dyn List,
+        elements: impl Iterator<Item = &'a T> + 'a,
+    ) -> Self {
+        ListIter {
+            list,
+            index: 0,
+            elements: Some(Box::new(elements.map(|element| element as &dyn Reflect))),
+        }
     }
 }
 
@@ -406,7 +571,10 @@ impl<'a> Iterator for ListIter<'a> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let value = self.list.get(self.index);
+        let value = match &mut self.elements {
+            Some(elements) => elements.next(),
+            None => self.list.get(self.index),
+        };
         self.index += value.is_some() as usize;
         value
     }
@@ -420,12 +588,424 @@ impl<'a> Iterator for ListIter<'a> {
 
 impl<'a> ExactSizeIterator for ListIter<'a> {}
 
+/// An iterator over mutable references to the elements of a [`List`].
+///
+/// This is created by calling [`List::iter_mut`].
+pub struct ListIterMut<'a> {
+    elements: Box<dyn ExactSizeIterator<Item = &'a mut dyn Reflect> + 'a>,
+}
+
+impl<'a> ListIterMut<'a> {
+    /// Creates a new [`ListIterMut`] from an iterator over the elements of a list, in order.
+    #[inline]
+    pub fn new(elements: impl ExactSizeIterator<Item = &'a mut dyn Reflect> + 'a) -> Self {
+        Self {
+            elements: Box::new(elements),
+        }
+    }
+}
+
+impl<'a> Iterator for ListIterMut<'a> {
+    type Item = &'a mut dyn Reflect;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for ListIterMut<'a> {}
+
+impl dyn List {
+    /// Returns the elements of the list as a slice of `T`,
+    /// or `None` if the list is not a `Vec<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::List;
+    /// let list: &dyn List = &vec![1.0_f32, 2.0];
+    /// assert_eq!(Some(&[1.0_f32, 2.0][..]), list.as_slice_of::<f32>());
+    /// assert_eq!(None, list.as_slice_of::<f64>());
+    /// ```
+    pub fn as_slice_of<T: Any>(&self) -> Option<&[T]> {
+        self.as_any().downcast_ref::<Vec<T>>().map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over `size` elements of the list at a time,
+    /// starting at the front of the list.
+    ///
+    /// The chunks do not overlap. If `size` does not divide the length of the list,
+    /// the last chunk will contain the remaining elements.
+    ///
+    /// This is the reflected counterpart to [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{List, Reflect};
+    /// let list: &dyn List = &vec![1_u32, 2, 3, 4, 5];
+    /// let chunks = list.chunks(2).map(|chunk| chunk.len()).collect::<Vec<_>>();
+    /// assert_eq!(vec![2, 2, 1], chunks);
+    /// ```
+    pub fn chunks(&self, size: usize) -> ListChunks {
+        assert!(size != 0, "chunk size must be non-zero");
+        ListChunks {
+            list: self,
+            size,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over all contiguous windows of `size` elements in the list.
+    ///
+    /// The windows overlap. If the list is shorter than `size`, the iterator returns no values.
+    ///
+    /// This is the reflected counterpart to [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{List, Reflect};
+    /// let list: &dyn List = &vec![1_u32, 2, 3];
+    /// let mut windows = list.windows(2);
+    /// let first = windows.next().unwrap();
+    /// assert_eq!(Some(&1), first[0].downcast_ref::<u32>());
+    /// assert_eq!(Some(&2), first[1].downcast_ref::<u32>());
+    /// assert_eq!(1, windows.count());
+    /// ```
+    pub fn windows(&self, size: usize) -> ListWindows {
+        assert!(size != 0, "window size must be non-zero");
+        ListWindows {
+            list: self,
+            size,
+            index: 0,
+        }
+    }
+
+    /// Returns a view into the elements of the list within the given range,
+    /// without copying them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{List, Reflect};
+    /// let list: &dyn List = &vec![1_u32, 2, 3, 4, 5];
+    /// let slice = list.slice(1..3);
+    /// assert_eq!(2, slice.len());
+    /// assert_eq!(Some(&2), slice.get(0).unwrap().downcast_ref::<u32>());
+    /// assert!(slice.get(2).is_none());
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> ListSlice {
+        ListSlice {
+            range: slice_range(range, self.len()),
+            list: self,
+        }
+    }
+
+    /// Returns a mutable view into the elements of the list within the given range,
+    /// without copying them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or its start is greater than its end.
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> ListSliceMut {
+        ListSliceMut {
+            range: slice_range(range, self.len()),
+            list: self,
+        }
+    }
+}
+
+/// Resolves the given range against a list of length `len`, panicking if it is out of bounds.
+fn slice_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for list of length {len}"
+    );
+    start..end
+}
+
+#[cfg(feature = "parallel")]
+impl dyn List {
+    /// Returns a [parallel iterator] over the elements of the list.
+    ///
+    /// The elements are first collected with [`List::iter`],
+    /// since [`List::get`] may need to walk the list to reach a given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{List, Reflect};
+    /// use rayon::prelude::*;
+    ///
+    /// let list: &dyn List = &vec![1_u32, 2, 3, 4];
+    /// let sum: u32 = list
+    ///     .par_iter()
+    ///     .map(|item| *item.downcast_ref::<u32>().unwrap())
+    ///     .sum();
+    /// assert_eq!(10, sum);
+    /// ```
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_iter(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &dyn Reflect> {
+        use rayon::iter::IntoParallelIterator;
+
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+
+    /// Returns a [parallel iterator] over mutable references to the elements of the list.
+    ///
+    /// The elements are first collected with [`List::iter_mut`].
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_iter_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut dyn Reflect> {
+        use rayon::iter::IntoParallelIterator;
+
+        self.iter_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+/// An iterator over non-overlapping chunks of a [`List`].
+///
+/// This is created by calling `chunks` on a `dyn List`.
+pub struct ListChunks<'a> {
+    list: &'a dyn List,
+    size: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for ListChunks<'a> {
+    type Item = Vec<&'a dyn Reflect>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.list.len().min(self.index.saturating_add(self.size));
+        if self.index >= end {
+            return None;
+        }
+
+        let chunk = (self.index..end)
+            .filter_map(|index| self.list.get(index))
+            .collect();
+        self.index = end;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.len().saturating_sub(self.index);
+        let size = remaining.div_ceil(self.size);
+        (size, Some(size))
+    }
+}
+
+impl<'a> ExactSizeIterator for ListChunks<'a> {}
+
+/// An iterator over overlapping windows of a [`List`].
+///
+/// This is created by calling `windows` on a `dyn List`.
+pub struct ListWindows<'a> {
+    list: &'a dyn List,
+    size: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for ListWindows<'a> {
+    type Item = Vec<&'a dyn Reflect>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self
+            .index
+            .checked_add(self.size)
+            .filter(|&end| end <= self.list.len())?;
+
+        let window = (self.index..end)
+            .filter_map(|index| self.list.get(index))
+            .collect();
+        self.index += 1;
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self
+            .list
+            .len()
+            .saturating_sub(self.index)
+            .checked_sub(self.size)
+            .map_or(0, |size| size + 1);
+        (size, Some(size))
+    }
+}
+
+impl<'a> ExactSizeIterator for ListWindows<'a> {}
+
+/// A view into a contiguous range of elements of a [`List`].
+///
+/// Indices are relative to the start of the range.
+///
+/// Since it borrows the list, this view is not `'static` and so cannot implement [`Reflect`]
+/// or [`List`] itself, nor be returned by a [path](crate::GetPath).
+/// Use [`clone_dynamic`](ListSlice::clone_dynamic) to get an owned, reflected copy of the range.
+///
+/// This is created by calling `slice` on a `dyn List`.
+#[derive(Clone)]
+pub struct ListSlice<'a> {
+    list: &'a dyn List,
+    range: Range<usize>,
+}
+
+impl<'a> ListSlice<'a> {
+    /// Returns a reference to the element at `index` within the slice, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a dyn Reflect> {
+        if index < self.len() {
+            self.list.get(self.range.start + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns `true` if the slice contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns the range of the underlying list covered by this slice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns an iterator over the elements of the slice.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a dyn Reflect> + 'a {
+        let list = self.list;
+        self.range
+            .clone()
+            .map(move |index| list.get(index).unwrap())
+    }
+
+    /// Clones the elements of the slice into a new [`DynamicList`].
+    pub fn clone_dynamic(&self) -> DynamicList {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
+        DynamicList {
+            represented_type: self.list.get_represented_type_info(),
+            values: self.iter().map(Reflect::clone_value).collect(),
+        }
+    }
+}
+
+impl<'a> Debug for ListSlice<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A mutable view into a contiguous range of elements of a [`List`].
+///
+/// Elements within the range can be mutated, but the slice itself cannot grow or shrink.
+/// Indices are relative to the start of the range.
+///
+/// This is created by calling `slice_mut` on a `dyn List`.
+pub struct ListSliceMut<'a> {
+    list: &'a mut dyn List,
+    range: Range<usize>,
+}
+
+impl<'a> ListSliceMut<'a> {
+    /// Returns a reference to the element at `index` within the slice, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index` within the slice,
+    /// or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        if index < self.len() {
+            self.list.get_mut(self.range.start + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns `true` if the slice contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns the range of the underlying list covered by this slice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns an immutable view of this slice.
+    pub fn as_slice(&self) -> ListSlice {
+        ListSlice {
+            list: &*self.list,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a> Debug for ListSliceMut<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
 /// Returns the `u64` hash of the given [list](List).
 #[inline]
 pub fn list_hash<L: List>(list: &L) -> Option<u64> {
     let mut hasher = reflect_hasher();
     Any::type_id(list).hash(&mut hasher);
     list.len().hash(&mut hasher);
+    if let Some(slice) = list.as_primitive_slice() {
+        slice.hash_elements(&mut hasher)?;
+        return Some(hasher.finish());
+    }
     for value in list.iter() {
         hasher.write_u64(value.reflect_hash()?);
     }
@@ -459,22 +1039,93 @@ pub fn list_apply<L: List>(a: &mut L, b: &dyn Reflect) {
 /// applying elements to each other fails.
 #[inline]
 pub fn list_try_apply<L: List>(a: &mut L, b: &dyn Reflect) -> Result<(), ApplyError> {
-    if let ReflectRef::List(list_value) = b.reflect_ref() {
-        for (i, value) in list_value.iter().enumerate() {
-            if i < a.len() {
-                if let Some(v) = a.get_mut(i) {
-                    v.try_apply(value)?;
-                }
-            } else {
-                List::push(a, value.clone_value());
-            }
-        }
-    } else {
+    list_try_apply_with(a, b, ApplyStrategy::Merge)
+}
+
+/// Determines how the elements of one list are applied to another.
+///
+/// The default strategy for a list type can be configured in the [`TypeRegistry`]
+/// using the [`ReflectApplyStrategy`] type data.
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [`ReflectApplyStrategy`]: crate::ReflectApplyStrategy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ApplyStrategy {
+    /// Elements are applied pairwise, with any excess elements of the source
+    /// cloned and appended to the target.
+    ///
+    /// Excess elements of the target are kept.
+    /// This is the behavior of [`list_apply`].
+    #[default]
+    Merge,
+    /// The target is cleared and every element of the source is cloned into it.
+    Replace,
+    /// Like [`Merge`](ApplyStrategy::Merge), but excess elements of the target are removed
+    /// so that it ends up with the same length as the source.
+    Truncate,
+}
+
+/// Applies the elements of `b` to `a` using the given [`ApplyStrategy`].
+///
+/// # Panics
+///
+/// This function panics if `b` is not a list.
+#[inline]
+pub fn list_apply_with<L: List + ?Sized>(a: &mut L, b: &dyn Reflect, strategy: ApplyStrategy) {
+    if let Err(err) = list_try_apply_with(a, b, strategy) {
+        panic!("{err}");
+    }
+}
+
+/// Tries to apply the elements of `b` to `a` using the given [`ApplyStrategy`].
+///
+/// # Errors
+///
+/// This function returns an [`ApplyError::MismatchedKinds`] if `b` is not a list or if
+/// applying elements to each other fails.
+#[inline]
+pub fn list_try_apply_with<L: List + ?Sized>(
+    a: &mut L,
+    b: &dyn Reflect,
+    strategy: ApplyStrategy,
+) -> Result<(), ApplyError> {
+    #[cfg(feature = "stats")]
+    crate::stats::record_apply();
+    let ReflectRef::List(list_value) = b.reflect_ref() else {
         return Err(ApplyError::MismatchedKinds {
             from_kind: b.reflect_kind(),
             to_kind: ReflectKind::List,
         });
+    };
+
+    list_try_apply_elements(a, list_value, strategy, Reflect::try_apply)
+}
+
+/// Applies the elements of `b` to `a` according to `strategy`,
+/// using `apply_element` to apply a pair of elements.
+pub(crate) fn list_try_apply_elements<L: List + ?Sized>(
+    a: &mut L,
+    b: &dyn List,
+    strategy: ApplyStrategy,
+    mut apply_element: impl FnMut(&mut dyn Reflect, &dyn Reflect) -> Result<(), ApplyError>,
+) -> Result<(), ApplyError> {
+    if strategy == ApplyStrategy::Replace {
+        while a.pop().is_some() {}
+    }
+
+    for (i, value) in b.iter().enumerate() {
+        match a.get_mut(i) {
+            Some(v) => apply_element(v, value).map_err(|err| err.with_index(i))?,
+            None => a.push(value.clone_value()),
+        }
+    }
+
+    if strategy == ApplyStrategy::Truncate {
+        while a.len() > b.len() {
+            a.pop();
+        }
     }
+
     Ok(())
 }
 
@@ -496,6 +1147,10 @@ pub fn list_partial_eq<L: List>(a: &L, b: &dyn Reflect) -> Option<bool> {
         return Some(false);
     }
 
+    if let (Some(a_slice), Some(b_slice)) = (a.as_primitive_slice(), list.as_primitive_slice()) {
+        return Some(a_slice.is_empty() || a_slice == b_slice);
+    }
+
     for (a_value, b_value) in a.iter().zip(list.iter()) {
         let eq_result = a_value.reflect_partial_eq(b_value);
         if let failed @ (Some(false) | None) = eq_result {
@@ -506,6 +1161,29 @@ pub fn list_partial_eq<L: List>(a: &L, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`List`] with a [`Reflect`] value lexicographically.
+///
+/// Elements are compared pairwise using [`Reflect::reflect_cmp`],
+/// stopping at the first pair that isn't equal.
+/// If one list is a prefix of the other, the shorter list is ordered first.
+///
+/// Returns [`None`] if `b` is not a list or if any pair of elements couldn't be compared.
+#[inline]
+pub fn list_cmp<L: List>(a: &L, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::List(list) = b.reflect_ref() else {
+        return None;
+    };
+
+    for (a_value, b_value) in a.iter().zip(list.iter()) {
+        match a_value.reflect_cmp(b_value)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+
+    Some(a.len().cmp(&list.len()))
+}
+
 /// The default debug formatter for [`List`] types.
 ///
 /// # Example
@@ -535,7 +1213,7 @@ pub fn list_debug(dyn_list: &dyn List, f: &mut Formatter<'_>) -> std::fmt::Resul
 #[cfg(test)]
 mod tests {
     use super::DynamicList;
-    use crate::{Reflect, ReflectRef};
+    use crate::{ApplyError, FromReflect, List, Reflect, ReflectRef, Typed};
     use std::assert_eq;
 
     #[test]
@@ -551,6 +1229,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_push_and_pop_front() {
+        use std::collections::VecDeque;
+
+        let mut deque = VecDeque::from([1_u32, 2]);
+        let mut vec = vec![1_u32, 2];
+        let mut dynamic = DynamicList::default();
+        dynamic.push(1_u32);
+        dynamic.push(2_u32);
+
+        for list in [&mut deque as &mut dyn List, &mut vec, &mut dynamic] {
+            list.push_front(Box::new(0_u32));
+            list.push(Box::new(3_u32));
+            assert_eq!(
+                vec![0, 1, 2, 3],
+                list.iter()
+                    .map(|value| *value.downcast_ref::<u32>().unwrap())
+                    .collect::<Vec<_>>()
+            );
+
+            let front = list.pop_front().unwrap();
+            assert_eq!(Some(&0), front.downcast_ref::<u32>());
+            assert_eq!(3, list.len());
+        }
+
+        let mut empty = VecDeque::<u32>::new();
+        assert!(List::pop_front(&mut empty).is_none());
+    }
+
+    #[test]
+    fn should_iterate_wrapped_deque() {
+        use std::collections::VecDeque;
+
+        // Pushing to the front of the deque wraps its elements around its buffer
+        let mut deque = VecDeque::with_capacity(4);
+        deque.extend([2_u32, 3]);
+        deque.push_front(1);
+        deque.push_front(0);
+        assert!(!deque.as_slices().1.is_empty());
+
+        let iter = List::iter(&deque);
+        assert_eq!(4, iter.len());
+        assert_eq!(
+            vec![0, 1, 2, 3],
+            iter.map(|value| *value.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_reflect_linked_list() {
+        use std::collections::LinkedList;
+
+        let mut list = LinkedList::from([1_u32, 3]);
+        List::insert(&mut list, 1, Box::new(2_u32));
+        List::push_front(&mut list, Box::new(0_u32));
+        assert_eq!(LinkedList::from([0, 1, 2, 3]), list);
+        assert_eq!(Some(&2), List::get(&list, 2).unwrap().downcast_ref::<u32>());
+
+        *List::get_mut(&mut list, 3)
+            .unwrap()
+            .downcast_mut::<u32>()
+            .unwrap() = 4;
+        let removed = List::remove(&mut list, 1);
+        assert_eq!(Some(&1), removed.downcast_ref::<u32>());
+        assert_eq!(LinkedList::from([0, 2, 4]), list);
+
+        let dynamic = list.clone_dynamic();
+        assert_eq!(
+            Some(list.clone()),
+            LinkedList::<u32>::from_reflect(&dynamic)
+        );
+        assert!(list.reflect_partial_eq(&dynamic).unwrap_or_default());
+        assert_eq!(
+            vec![0, 2, 4],
+            List::iter(&list)
+                .map(|value| *value.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_reflect_binary_heap() {
+        use std::collections::BinaryHeap;
+
+        let mut heap = BinaryHeap::from([1_u32, 5, 3]);
+        List::push(&mut heap, Box::new(4_u32));
+        List::insert(&mut heap, 0, Box::new(2_u32));
+        assert_eq!(5, List::len(&heap));
+        assert!(List::get_mut(&mut heap, 0).is_none());
+
+        let greatest = List::pop(&mut heap).unwrap();
+        assert_eq!(Some(&5), greatest.downcast_ref::<u32>());
+
+        // Heaps are compared regardless of the order of their elements
+        let mut dynamic = DynamicList::default();
+        for value in [4_u32, 3, 2, 1] {
+            dynamic.push(value);
+        }
+        assert!(heap.reflect_partial_eq(&dynamic).unwrap());
+        assert_eq!(
+            heap.reflect_hash(),
+            BinaryHeap::from([2_u32, 1, 3, 4]).reflect_hash()
+        );
+
+        let mut other = BinaryHeap::from([7_u32, 8]);
+        other.apply(&dynamic);
+        assert_eq!(vec![1, 2, 3, 4], other.into_sorted_vec());
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            BinaryHeap::<u32>::from_reflect(&dynamic)
+                .unwrap()
+                .into_sorted_vec()
+        );
+    }
+
     #[test]
     fn next_index_increment() {
         const SIZE: usize = if cfg!(debug_assertions) {
@@ -575,4 +1369,120 @@ mod tests {
         assert!(iter.next().is_none());
         assert!(iter.index == SIZE);
     }
+
+    #[test]
+    fn try_push_box_should_validate_item_type() {
+        let mut list = DynamicList::default();
+        list.set_represented_type(Some(<Vec<u32> as Typed>::type_info()));
+
+        assert!(list.try_push_box(Box::new(123_u32)).is_ok());
+        assert!(matches!(
+            list.try_push_box(Box::new(String::from("hello"))),
+            Err(ApplyError::MismatchedTypes { .. })
+        ));
+        assert_eq!(1, list.len());
+
+        // Lists without a represented type accept anything
+        let mut list = DynamicList::default();
+        assert!(list.try_push_box(Box::new(String::from("hello"))).is_ok());
+    }
+
+    #[test]
+    fn should_iterate_chunks_and_windows() {
+        let list: &dyn List = &vec![1_u32, 2, 3, 4, 5];
+        let to_values = |items: Vec<&dyn Reflect>| {
+            items
+                .into_iter()
+                .map(|item| *item.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let chunks = list.chunks(2);
+        assert_eq!(3, chunks.len());
+        assert_eq!(
+            vec![vec![1, 2], vec![3, 4], vec![5]],
+            chunks.map(to_values).collect::<Vec<_>>()
+        );
+
+        let windows = list.windows(3);
+        assert_eq!(3, windows.len());
+        assert_eq!(
+            vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]],
+            windows.map(to_values).collect::<Vec<_>>()
+        );
+
+        assert_eq!(0, list.windows(6).count());
+        assert_eq!(0, (&Vec::<u32>::new() as &dyn List).chunks(2).count());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn should_iterate_in_parallel() {
+        use rayon::iter::ParallelIterator;
+        use std::collections::LinkedList;
+
+        let mut list = LinkedList::from([1_u32, 2, 3, 4]);
+        (&mut list as &mut dyn List)
+            .par_iter_mut()
+            .for_each(|item| *item.downcast_mut::<u32>().unwrap() *= 10);
+        assert_eq!(LinkedList::from([10, 20, 30, 40]), list);
+
+        let sum: u32 = (&list as &dyn List)
+            .par_iter()
+            .map(|item| *item.downcast_ref::<u32>().unwrap())
+            .sum();
+        assert_eq!(100, sum);
+    }
+
+    #[test]
+    fn should_not_overflow_chunks_and_windows() {
+        let list: &dyn List = &vec![1_u32, 2, 3];
+
+        let mut chunks = list.chunks(usize::MAX);
+        assert_eq!(1, chunks.len());
+        assert_eq!(3, chunks.next().unwrap().len());
+        assert!(chunks.next().is_none());
+
+        let mut windows = list.windows(usize::MAX);
+        assert_eq!(0, windows.len());
+        assert!(windows.next().is_none());
+
+        let mut windows = list.windows(2);
+        windows.next();
+        assert_eq!(1, windows.len());
+        assert_eq!(Some(0), list.windows(usize::MAX - 1).size_hint().1);
+    }
+
+    #[test]
+    fn should_slice_list() {
+        let mut list = vec![1_u32, 2, 3, 4, 5];
+        let dyn_list: &mut dyn List = &mut list;
+
+        let slice = dyn_list.slice(1..=3);
+        assert_eq!(1..4, slice.range());
+        assert_eq!(
+            vec![2, 3, 4],
+            slice
+                .iter()
+                .map(|item| *item.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert!(slice
+            .clone_dynamic()
+            .reflect_partial_eq(&vec![2_u32, 3, 4])
+            .unwrap());
+        assert!(dyn_list.slice(5..).is_empty());
+
+        let mut slice = dyn_list.slice_mut(3..);
+        assert!(slice.get_mut(2).is_none());
+        *slice.get_mut(0).unwrap().downcast_mut::<u32>().unwrap() = 40;
+        assert_eq!(vec![1, 2, 3, 40, 5], list);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn should_panic_on_out_of_bounds_slice() {
+        let list: &dyn List = &vec![1_u32, 2, 3];
+        list.slice(1..4);
+    }
 }
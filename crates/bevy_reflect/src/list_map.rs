@@ -0,0 +1,579 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use bevy_reflect_derive::impl_type_path;
+
+use crate::{
+    self as bevy_reflect, map_apply, map_cmp, map_partial_eq, map_try_apply,
+    utility::GenericTypeInfoCell, ApplyError, DynamicMap, FromReflect, FromType,
+    GetTypeRegistration, Map, MapInfo, MapIter, MapKeys, MapValues, MapValuesMut, Reflect,
+    ReflectCloneError, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
+};
+
+/// How a [`ListMap`] handles the insertion of a key it already contains.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DuplicateKeys {
+    /// Replace the value of the first entry with that key, keeping its position.
+    #[default]
+    Replace,
+    /// Keep the existing entry and discard the new value.
+    KeepFirst,
+    /// Append a new entry after the existing ones.
+    ///
+    /// Lookups by key return the first entry with that key.
+    Append,
+}
+
+/// A list of key-value pairs that is reflected as a [`Map`].
+///
+/// Unlike [`HashMap`] and [`BTreeMap`], a `ListMap` keeps its entries in insertion order,
+/// and only requires its keys to implement [`PartialEq`].
+/// Lookups are linear, which is usually fine for the small lists found in configuration data.
+///
+/// Since it is reflected as a map, map-oriented tooling and serializers treat it as one:
+/// it is serialized as a map, in the order of its entries.
+///
+/// Keys inserted through [`Map::insert_boxed`], [`ListMap::insert`] or [`Reflect::apply`]
+/// follow the [`DuplicateKeys`] policy of the list,
+/// while the underlying [`Vec`] can still be edited freely through [`DerefMut`].
+/// Note that [`DynamicMap`], and therefore [`Reflect::clone_value`], collapse duplicate keys.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{DuplicateKeys, FromReflect, ListMap, Map, Reflect};
+/// let mut bindings = ListMap::with_duplicates(DuplicateKeys::Append);
+/// bindings.insert("jump".to_string(), 'w');
+/// bindings.insert("crouch".to_string(), 's');
+/// bindings.insert("jump".to_string(), ' ');
+///
+/// let map: &dyn Map = &bindings;
+/// assert_eq!(3, map.len());
+/// assert_eq!(Some(&'w'), map.get(&"jump".to_string()).unwrap().downcast_ref());
+///
+/// let (key, value) = map.get_at(2).unwrap();
+/// assert_eq!(Some(&"jump".to_string()), key.downcast_ref());
+/// assert_eq!(Some(&' '), value.downcast_ref());
+/// ```
+///
+/// [`HashMap`]: std::collections::HashMap
+/// [`BTreeMap`]: std::collections::BTreeMap
+#[derive(Clone, Debug, Default)]
+pub struct ListMap<K, V> {
+    entries: Vec<(K, V)>,
+    duplicates: DuplicateKeys,
+}
+
+impl<K, V> ListMap<K, V> {
+    /// Creates an empty list, replacing the values of duplicate keys.
+    pub const fn new() -> Self {
+        Self::with_duplicates(DuplicateKeys::Replace)
+    }
+
+    /// Creates an empty list with the given [`DuplicateKeys`] policy.
+    pub const fn with_duplicates(duplicates: DuplicateKeys) -> Self {
+        Self {
+            entries: Vec::new(),
+            duplicates,
+        }
+    }
+
+    /// Returns the [`DuplicateKeys`] policy of the list.
+    pub fn duplicates(&self) -> DuplicateKeys {
+        self.duplicates
+    }
+
+    /// Sets the [`DuplicateKeys`] policy of the list.
+    ///
+    /// This does not affect the entries already in the list.
+    pub fn set_duplicates(&mut self, duplicates: DuplicateKeys) {
+        self.duplicates = duplicates;
+    }
+
+    /// Consumes the list, returning its entries.
+    pub fn into_entries(self) -> Vec<(K, V)> {
+        self.entries
+    }
+}
+
+impl<K: PartialEq, V> ListMap<K, V> {
+    /// Returns the position of the first entry with the given key.
+    pub fn position(&self, key: &K) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+
+    /// Returns the value of the first entry with the given key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries
+            .iter()
+            .find_map(|(k, value)| (k == key).then_some(value))
+    }
+
+    /// Returns the value of the first entry with the given key, mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find_map(|(k, value)| (k == key).then_some(value))
+    }
+
+    /// Inserts an entry, following the [`DuplicateKeys`] policy of the list.
+    ///
+    /// Returns the replaced value with [`DuplicateKeys::Replace`],
+    /// or the discarded value with [`DuplicateKeys::KeepFirst`],
+    /// if the key was already present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.duplicates == DuplicateKeys::Append {
+            self.entries.push((key, value));
+            return None;
+        }
+        match self.position(&key) {
+            Some(index) if self.duplicates == DuplicateKeys::Replace => {
+                Some(std::mem::replace(&mut self.entries[index].1, value))
+            }
+            Some(_) => Some(value),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Removes all entries with the given key, returning the value of the first one.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.position(key)?;
+        let (_, value) = self.entries.remove(index);
+        self.entries.retain(|(k, _)| k != key);
+        Some(value)
+    }
+}
+
+impl<K, V> Deref for ListMap<K, V> {
+    type Target = Vec<(K, V)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl<K, V> DerefMut for ListMap<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entries
+    }
+}
+
+impl<K, V> From<Vec<(K, V)>> for ListMap<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        Self {
+            entries,
+            duplicates: DuplicateKeys::default(),
+        }
+    }
+}
+
+impl<K, V> From<ListMap<K, V>> for Vec<(K, V)> {
+    fn from(list: ListMap<K, V>) -> Self {
+        list.entries
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for ListMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Vec::from_iter(iter).into()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for ListMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for ListMap<K, V> {}
+
+impl<K, V> Map for ListMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(|key| Self::get(self, key))
+            .map(|value| value as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(move |key| Self::get_mut(self, key))
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.entries
+            .get(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+        self.entries
+            .get_mut(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn iter(&self) -> MapIter {
+        MapIter::new(self)
+    }
+
+    fn keys(&self) -> MapKeys {
+        MapKeys::new(self.entries.iter().map(|(key, _)| key as &dyn Reflect))
+    }
+
+    fn values(&self) -> MapValues {
+        MapValues::new(self.entries.iter().map(|(_, value)| value as &dyn Reflect))
+    }
+
+    fn values_mut(&mut self) -> MapValuesMut {
+        MapValuesMut::new(
+            self.entries
+                .iter_mut()
+                .map(|(_, value)| value as &mut dyn Reflect),
+        )
+    }
+
+    fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        self.entries
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value) as Box<dyn Reflect>,
+                )
+            })
+            .collect()
+    }
+
+    fn clone_dynamic(&self) -> DynamicMap {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
+        let mut dynamic_map = DynamicMap::default();
+        dynamic_map.set_represented_type(self.get_represented_type_info());
+        for (k, v) in &self.entries {
+            let key = K::from_reflect(k).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to clone invalid key of type {}.",
+                    k.reflect_type_path()
+                )
+            });
+            dynamic_map.insert_boxed(Box::new(key), v.clone_value());
+        }
+        dynamic_map
+    }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+            panic!(
+                "Attempted to insert invalid value of type {}.",
+                value.reflect_type_path()
+            )
+        });
+        self.insert(key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        let index = self.position(&key).unwrap_or_else(|| {
+            let value = V::take_from_reflect(default()).unwrap_or_else(|value| {
+                panic!(
+                    "Attempted to insert invalid value of type {}.",
+                    value.reflect_type_path()
+                )
+            });
+            self.entries.push((key, value));
+            self.entries.len() - 1
+        });
+        &mut self.entries[index].1
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let mut from_reflect = None;
+        key.downcast_ref::<K>()
+            .or_else(|| {
+                from_reflect = K::from_reflect(key);
+                from_reflect.as_ref()
+            })
+            .and_then(|key| Self::remove(self, key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        self.entries.retain_mut(|(key, value)| f(&*key, value));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K, V> Reflect for ListMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        map_apply(self, value);
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        map_try_apply(self, value)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Map
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Map(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        self.iter()
+            .map(|(key, value)| -> Result<_, ReflectCloneError> {
+                Ok((
+                    <dyn Reflect>::reflect_clone_and_take::<K>(key)?,
+                    <dyn Reflect>::reflect_clone_and_take::<V>(value)?,
+                ))
+            })
+            .collect::<Result<Self, _>>()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        map_partial_eq(self, value)
+    }
+
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        map_cmp(self, value)
+    }
+}
+
+impl<K, V> Typed for ListMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+    }
+}
+
+impl_type_path!((in bevy_reflect) ListMap<K, V>);
+
+impl<K, V> GetTypeRegistration for ListMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
+}
+
+impl<K, V> FromReflect for ListMap<K, V>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+    V: FromReflect + TypePath + GetTypeRegistration,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Map(ref_map) = reflect.reflect_ref() else {
+            return None;
+        };
+        let duplicates = reflect
+            .downcast_ref::<Self>()
+            .map(Self::duplicates)
+            .unwrap_or_default();
+
+        // Keep every entry of the source map, in its order
+        let entries = ref_map
+            .iter()
+            .map(|(key, value)| Some((K::from_reflect(key)?, V::from_reflect(value)?)))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            entries,
+            duplicates,
+        })
+    }
+}
+
+#[cfg(feature = "functions")]
+crate::func::macros::impl_function_traits!(ListMap<K, V>;
+    <
+        K: FromReflect + TypePath + GetTypeRegistration + PartialEq,
+        V: FromReflect + TypePath + GetTypeRegistration
+    >
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
+    use crate::{DuplicateKeys, FromReflect, ListMap, Map, TypeRegistry};
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn should_follow_duplicate_key_policy() {
+        let mut replace = ListMap::new();
+        assert_eq!(None, replace.insert("a", 1));
+        assert_eq!(None, replace.insert("b", 2));
+        assert_eq!(Some(1), replace.insert("a", 3));
+        assert_eq!(vec![("a", 3), ("b", 2)], *replace);
+
+        let mut keep_first = ListMap::with_duplicates(DuplicateKeys::KeepFirst);
+        keep_first.insert("a", 1);
+        assert_eq!(Some(3), keep_first.insert("a", 3));
+        assert_eq!(vec![("a", 1)], *keep_first);
+
+        let mut append = ListMap::with_duplicates(DuplicateKeys::Append);
+        append.insert("a", 1);
+        append.insert("b", 2);
+        assert_eq!(None, append.insert("a", 3));
+        assert_eq!(vec![("a", 1), ("b", 2), ("a", 3)], *append);
+        assert_eq!(Some(&1), append.get(&"a"));
+
+        assert_eq!(Some(1), append.remove(&"a"));
+        assert_eq!(vec![("b", 2)], *append);
+    }
+
+    #[test]
+    fn should_reflect_list_map_as_map() {
+        let mut list = ListMap::with_duplicates(DuplicateKeys::Append);
+        list.push((3_u32, "three".to_string()));
+        list.push((1_u32, "one".to_string()));
+        list.push((3_u32, "again".to_string()));
+
+        let map: &mut dyn Map = &mut list;
+        assert_eq!(3, map.len());
+        assert_eq!(
+            Some(&"three".to_string()),
+            map.get(&3_u32).unwrap().downcast_ref()
+        );
+        let keys = map
+            .keys()
+            .map(|key| *key.downcast_ref::<u32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![3, 1, 3], keys);
+
+        map.insert_boxed(Box::new(2_u32), Box::new("two".to_string()));
+        assert_eq!(Some((2, "two".to_string())), list.last().cloned());
+
+        let cloned = ListMap::<u32, String>::from_reflect(&list).unwrap();
+        assert_eq!(list, cloned);
+        assert_eq!(DuplicateKeys::Append, cloned.duplicates());
+    }
+
+    #[test]
+    fn should_serialize_list_map_in_order() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<ListMap<String, i32>>();
+
+        let list: ListMap<String, i32> = vec![
+            ("z".to_string(), 1),
+            ("a".to_string(), 2),
+            ("m".to_string(), 3),
+        ]
+        .into();
+
+        let serializer = ReflectSerializer::new(&list, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::ListMap<alloc::string::String, i32>":{"z":1,"a":2,"m":3}}"#,
+            output
+        );
+
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        let deserialized = ListMap::<String, i32>::from_reflect(&*value).unwrap();
+        assert_eq!(list, deserialized);
+    }
+}
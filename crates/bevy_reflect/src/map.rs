@@ -1,12 +1,15 @@
 use std::any::{Any, TypeId};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
+use std::ops::Bound;
 
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::{Entry, HashMap};
 
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
-    self as bevy_reflect, ApplyError, Reflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, reflect_trait, ApplyError, Reflect, ReflectCloneError, ReflectKind,
+    ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
 
 /// A trait used to power [map-like] operations via [reflection].
@@ -68,6 +71,19 @@ pub trait Map: Reflect {
     /// Returns an iterator over the key-value pairs of the map.
     fn iter(&self) -> MapIter;
 
+    /// Returns an iterator over the keys of the map.
+    fn keys(&self) -> MapKeys {
+        MapKeys::new(self.iter().map(|(key, _)| key))
+    }
+
+    /// Returns an iterator over the values of the map.
+    fn values(&self) -> MapValues {
+        MapValues::new(self.iter().map(|(_, value)| value))
+    }
+
+    /// Returns an iterator over mutable references to the values of the map.
+    fn values_mut(&mut self) -> MapValuesMut;
+
     /// Drain the key-value pairs of this map to get a vector of owned values.
     fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)>;
 
@@ -84,11 +100,176 @@ pub trait Map: Reflect {
         value: Box<dyn Reflect>,
     ) -> Option<Box<dyn Reflect>>;
 
+    /// Returns a mutable reference to the value associated with the given key,
+    /// inserting the value returned by `default` if the map did not have this key present.
+    ///
+    /// `default` is only called if the key is missing.
+    ///
+    /// The default implementation looks the key up again after inserting it,
+    /// so implementors should override it when they can do both in a single lookup.
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        if self.get(&*key).is_none() {
+            self.insert_boxed(key.clone_value(), default());
+        }
+        self.get_mut(&*key)
+            .expect("the key should be present after inserting it")
+    }
+
     /// Removes an entry from the map.
     ///
     /// If the map did not have this key present, `None` is returned.
     /// If the map did have this key present, the removed value is returned.
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+    /// Retains only the entries specified by the predicate.
+    ///
+    /// In other words, removes all entries for which `f(&key, &mut value)` returns `false`.
+    ///
+    /// The default implementation clones every key to look its entry up again,
+    /// so implementors should override it when they can visit their entries directly.
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        let keys = self
+            .iter()
+            .map(|(key, _)| key.clone_value())
+            .collect::<Vec<_>>();
+        for key in keys {
+            let Some(value) = self.get_mut(&*key) else {
+                continue;
+            };
+            if !f(&*key, value) {
+                self.remove(&*key);
+            }
+        }
+    }
+
+    /// Removes all entries from the map.
+    fn clear(&mut self) {
+        self.retain(&mut |_, _| false);
+    }
+}
+
+/// A [`Map`] whose entries are sorted by their keys, such as a [`BTreeMap`].
+///
+/// Unlike [`Map::get_at`], which only follows the iteration order of the map,
+/// the methods of this trait make use of the ordering of the keys.
+///
+/// Since this can't be detected from a [`Map`] alone,
+/// it is provided as [type data] in the form of [`ReflectOrderedMap`]:
+///
+/// ```
+/// # use std::{collections::BTreeMap, ops::Bound};
+/// # use bevy_reflect::{GetTypeRegistration, Reflect, ReflectOrderedMap};
+/// let map = BTreeMap::from([(1_u32, "a"), (2, "b"), (3, "c")]);
+///
+/// let registration = BTreeMap::<u32, &str>::get_type_registration();
+/// let reflect_ordered_map = registration.data::<ReflectOrderedMap>().unwrap();
+/// let ordered_map = reflect_ordered_map.get(&map).unwrap();
+///
+/// let (last_key, _) = ordered_map.last().unwrap();
+/// assert_eq!(Some(&3), last_key.downcast_ref::<u32>());
+///
+/// let range = ordered_map.range(Bound::Excluded(&1_u32), Bound::Unbounded);
+/// assert_eq!(2, range.count());
+/// ```
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+/// [type data]: crate::TypeData
+#[reflect_trait]
+pub trait OrderedMap: Map {
+    /// Returns the entry with the smallest key, or `None` if the map is empty.
+    fn first(&self) -> Option<(&dyn Reflect, &dyn Reflect)>;
+
+    /// Returns the entry with the largest key, or `None` if the map is empty.
+    fn last(&self) -> Option<(&dyn Reflect, &dyn Reflect)>;
+
+    /// Returns an iterator over the entries whose keys are within the given bounds, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bound is not a valid key for the map,
+    /// if the start of the range is greater than its end,
+    /// or if both bounds are excluded and equal.
+    fn range(&self, start: Bound<&dyn Reflect>, end: Bound<&dyn Reflect>) -> OrderedMapRange;
+}
+
+/// A [`Map`] whose entries keep their insertion order and can be addressed by index,
+/// such as an [`IndexMap`].
+///
+/// For these maps, [`Map::get_at`] and [`Map::get_at_mut`] return the entry at the given
+/// position in insertion order, and [`Map::iter`] visits entries in that order.
+/// This trait adds the operations needed to look up and change those positions.
+///
+/// Since this can't be detected from a [`Map`] alone,
+/// it is provided as [type data] in the form of [`ReflectIndexedMap`].
+///
+/// [`IndexMap`]: https://docs.rs/indexmap/latest/indexmap/map/struct.IndexMap.html
+/// [type data]: crate::TypeData
+#[reflect_trait]
+pub trait IndexedMap: Map {
+    /// Returns the index of the entry with the given key, or `None` if the key is not present.
+    fn get_index_of(&self, key: &dyn Reflect) -> Option<usize>;
+
+    /// Removes the entry with the given key, shifting all following entries down by one.
+    ///
+    /// This preserves the order of the remaining entries.
+    /// If the map did not have this key present, `None` is returned.
+    fn shift_remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>>;
+
+    /// Removes the entry at `index`, shifting all following entries down by one.
+    ///
+    /// Returns the removed key-value pair, or `None` if `index` is out of bounds.
+    fn shift_remove_index(&mut self, index: usize) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)>;
+
+    /// Moves the entry at index `from` to index `to`, shifting the entries in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_index(&mut self, from: usize, to: usize);
+}
+
+/// An iterator over a range of entries of an [`OrderedMap`].
+///
+/// This is created by calling [`OrderedMap::range`].
+pub struct OrderedMapRange<'a> {
+    iter: Box<dyn DoubleEndedIterator<Item = (&'a dyn Reflect, &'a dyn Reflect)> + 'a>,
+}
+
+impl<'a> OrderedMapRange<'a> {
+    /// Creates a new [`OrderedMapRange`] from an iterator.
+    #[inline]
+    pub fn new(
+        iter: impl DoubleEndedIterator<Item = (&'a dyn Reflect, &'a dyn Reflect)> + 'a,
+    ) -> Self {
+        Self {
+            iter: Box::new(iter),
+        }
+    }
+}
+
+impl<'a> Iterator for OrderedMapRange<'a> {
+    type Item = (&'a dyn Reflect, &'a dyn Reflect);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for OrderedMapRange<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
 }
 
 /// A container for compile-time map info.
@@ -100,11 +281,21 @@ pub struct MapInfo {
     key_type_id: TypeId,
     value_type_path: TypePathTable,
     value_type_id: TypeId,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl MapInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`MapInfo`].
     pub fn new<TMap: Map + TypePath, TKey: Reflect + TypePath, TValue: Reflect + TypePath>() -> Self
     {
@@ -115,6 +306,7 @@ impl MapInfo {
             key_type_id: TypeId::of::<TKey>(),
             value_type_path: TypePathTable::of::<TValue>(),
             value_type_id: TypeId::of::<TValue>(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -192,6 +384,8 @@ impl MapInfo {
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
+
+    impl_generic_info_methods!(self.generics);
 }
 
 #[macro_export]
@@ -251,6 +445,56 @@ impl DynamicMap {
     pub fn insert<K: Reflect, V: Reflect>(&mut self, key: K, value: V) {
         self.insert_boxed(Box::new(key), Box::new(value));
     }
+
+    /// Sorts the entries of the map by key.
+    ///
+    /// Entries are otherwise kept in insertion order, so this gives maps built from
+    /// unordered sources, like a `HashMap`, a deterministic order.
+    /// Keys are ordered with [`Reflect::reflect_cmp`] if possible,
+    /// and by their debug representation otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{DynamicMap, Map};
+    /// let mut map = DynamicMap::default();
+    /// map.insert(3_u32, "c");
+    /// map.insert(1_u32, "a");
+    /// map.insert(2_u32, "b");
+    ///
+    /// map.sort_keys();
+    /// let keys = map.iter().map(|(key, _)| *key.downcast_ref::<u32>().unwrap());
+    /// assert_eq!(vec![1, 2, 3], keys.collect::<Vec<_>>());
+    /// ```
+    pub fn sort_keys(&mut self) {
+        sort_entries_by_key(&mut self.values, |(key, _)| &**key);
+        self.rebuild_indices();
+    }
+
+    fn rebuild_indices(&mut self) {
+        self.indices.clear();
+        for (index, (key, _)) in self.values.iter().enumerate() {
+            self.indices
+                .insert(key.reflect_hash().expect(hash_error!(key)), index);
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl DynamicMap {
+    /// Returns a [parallel iterator] over the key-value pairs of the map,
+    /// with mutable references to the values.
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_iter_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (&dyn Reflect, &mut dyn Reflect)> {
+        use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+        self.values
+            .par_iter_mut()
+            .map(|(key, value)| (&**key, &mut **value))
+    }
 }
 
 impl Map for DynamicMap {
@@ -287,11 +531,25 @@ impl Map for DynamicMap {
         MapIter::new(self)
     }
 
+    fn keys(&self) -> MapKeys {
+        MapKeys::new(self.values.iter().map(|(key, _)| &**key))
+    }
+
+    fn values(&self) -> MapValues {
+        MapValues::new(self.values.iter().map(|(_, value)| &**value))
+    }
+
+    fn values_mut(&mut self) -> MapValuesMut {
+        MapValuesMut::new(self.values.iter_mut().map(|(_, value)| &mut **value))
+    }
+
     fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
         self.values
     }
 
     fn clone_dynamic(&self) -> DynamicMap {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicMap {
             represented_type: self.represented_type,
             values: self
@@ -325,13 +583,45 @@ impl Map for DynamicMap {
         }
     }
 
+    fn get_or_insert_with(
+        &mut self,
+        key: Box<dyn Reflect>,
+        default: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let index = match self
+            .indices
+            .entry(key.reflect_hash().expect(hash_error!(key)))
+        {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                entry.insert(self.values.len());
+                self.values.push((key, default()));
+                self.values.len() - 1
+            }
+        };
+        &mut *self.values[index].1
+    }
+
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
         let index = self
             .indices
             .remove(&key.reflect_hash().expect(hash_error!(key)))?;
         let (_key, value) = self.values.remove(index);
+        // Later entries have shifted down by one
+        self.rebuild_indices();
         Some(value)
     }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        self.values
+            .retain_mut(|(key, value)| f(&**key, &mut **value));
+        self.rebuild_indices();
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.indices.clear();
+    }
 }
 
 impl Reflect for DynamicMap {
@@ -397,13 +687,23 @@ impl Reflect for DynamicMap {
     }
 
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         map_partial_eq(self, value)
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        map_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicMap(")?;
         map_debug(self, f)?;
@@ -455,6 +755,36 @@ impl<'a> Iterator for MapIter<'a> {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl dyn Map {
+    /// Returns a [parallel iterator] over the key-value pairs of the map.
+    ///
+    /// The entries are first collected with [`Map::iter`],
+    /// since [`Map::get_at`] may need to walk the map to reach a given index.
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_iter(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (&dyn Reflect, &dyn Reflect)> {
+        use rayon::iter::IntoParallelIterator;
+
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+
+    /// Returns a [parallel iterator] over mutable references to the values of the map.
+    ///
+    /// The values are first collected with [`Map::values_mut`].
+    ///
+    /// [parallel iterator]: rayon::iter::IndexedParallelIterator
+    pub fn par_values_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut dyn Reflect> {
+        use rayon::iter::IntoParallelIterator;
+
+        self.values_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
 impl IntoIterator for DynamicMap {
     type Item = (Box<dyn Reflect>, Box<dyn Reflect>);
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -466,6 +796,65 @@ impl IntoIterator for DynamicMap {
 
 impl<'a> ExactSizeIterator for MapIter<'a> {}
 
+macro_rules! map_side_iter {
+    ($(#[$meta:meta])* $name:ident, $item:ty) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            iter: Box<dyn ExactSizeIterator<Item = $item> + 'a>,
+        }
+
+        impl<'a> $name<'a> {
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] from an iterator.")]
+            #[inline]
+            pub fn new(iter: impl ExactSizeIterator<Item = $item> + 'a) -> Self {
+                Self {
+                    iter: Box::new(iter),
+                }
+            }
+        }
+
+        impl<'a> Iterator for $name<'a> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<'a> ExactSizeIterator for $name<'a> {}
+    };
+}
+
+map_side_iter!(
+    /// An iterator over the keys of a [`Map`].
+    ///
+    /// This is created by calling [`Map::keys`].
+    MapKeys,
+    &'a dyn Reflect
+);
+
+map_side_iter!(
+    /// An iterator over the values of a [`Map`].
+    ///
+    /// This is created by calling [`Map::values`].
+    MapValues,
+    &'a dyn Reflect
+);
+
+map_side_iter!(
+    /// An iterator over mutable references to the values of a [`Map`].
+    ///
+    /// This is created by calling [`Map::values_mut`].
+    MapValuesMut,
+    &'a mut dyn Reflect
+);
+
 /// Compares a [`Map`] with a [`Reflect`] value.
 ///
 /// Returns true if and only if all of the following are true:
@@ -499,6 +888,68 @@ pub fn map_partial_eq<M: Map>(a: &M, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`Map`] with a [`Reflect`] value.
+///
+/// Since maps are not required to be ordered, the entries of both maps are first sorted
+/// by key using [`Reflect::reflect_cmp`].
+/// The sorted entries are then compared lexicographically, first by key and then by value.
+/// If the entries of one map are a prefix of the other's, the smaller map is ordered first.
+///
+/// Returns [`None`] if `b` is not a map or if any keys or values couldn't be compared.
+pub fn map_cmp<M: Map>(a: &M, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Map(map) = b.reflect_ref() else {
+        return None;
+    };
+
+    let a_entries = sorted_map_entries(a)?;
+    let b_entries = sorted_map_entries(map)?;
+
+    for ((a_key, a_value), (b_key, b_value)) in a_entries.iter().zip(b_entries.iter()) {
+        match a_key.reflect_cmp(*b_key)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+        match a_value.reflect_cmp(*b_value)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+
+    Some(a_entries.len().cmp(&b_entries.len()))
+}
+
+/// Collects the entries of a map sorted by key, or `None` if the keys can't be ordered.
+fn sorted_map_entries(map: &dyn Map) -> Option<Vec<(&dyn Reflect, &dyn Reflect)>> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    let mut comparable = true;
+    entries.sort_by(|(a, _), (b, _)| {
+        a.reflect_cmp(*b).unwrap_or_else(|| {
+            comparable = false;
+            Ordering::Equal
+        })
+    });
+    comparable.then_some(entries)
+}
+
+/// Sorts map entries by the key returned by `key`, giving them a deterministic order.
+///
+/// Keys are ordered with [`Reflect::reflect_cmp`] if they can be compared with each other,
+/// and by their debug representation otherwise.
+pub(crate) fn sort_entries_by_key<T>(entries: &mut [T], key: impl Fn(&T) -> &dyn Reflect) {
+    let Some(first) = entries.first() else {
+        return;
+    };
+    let comparable = entries
+        .iter()
+        .all(|entry| key(entry).reflect_cmp(key(first)).is_some());
+
+    if comparable {
+        entries.sort_by(|a, b| key(a).reflect_cmp(key(b)).unwrap_or(Ordering::Equal));
+    } else {
+        entries.sort_by_cached_key(|entry| format!("{:?}", key(entry)));
+    }
+}
+
 /// The default debug formatter for [`Map`] types.
 ///
 /// # Example
@@ -550,10 +1001,14 @@ pub fn map_apply<M: Map>(a: &mut M, b: &dyn Reflect) {
 /// applying elements to each other fails.
 #[inline]
 pub fn map_try_apply<M: Map>(a: &mut M, b: &dyn Reflect) -> Result<(), ApplyError> {
+    #[cfg(feature = "stats")]
+    crate::stats::record_apply();
     if let ReflectRef::Map(map_value) = b.reflect_ref() {
         for (key, b_value) in map_value.iter() {
             if let Some(a_value) = a.get_mut(key) {
-                a_value.try_apply(b_value)?;
+                a_value
+                    .try_apply(b_value)
+                    .map_err(|err| err.with_key(key))?;
             } else {
                 a.insert_boxed(key.clone_value(), b_value.clone_value());
             }
@@ -571,7 +1026,65 @@ pub fn map_try_apply<M: Map>(a: &mut M, b: &dyn Reflect) -> Result<(), ApplyErro
 mod tests {
     use super::DynamicMap;
     use super::Map;
-    use crate::reflect::Reflect;
+    use super::{MapIter, MapValuesMut};
+    use crate as bevy_reflect;
+    use crate::Reflect;
+    use std::collections::BTreeMap;
+
+    /// A map only implementing the required methods of [`Map`], to test the provided ones.
+    #[derive(Reflect, Clone, Default)]
+    #[reflect_value]
+    struct MinimalMap(BTreeMap<u32, u32>);
+
+    impl Map for MinimalMap {
+        fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+            Map::get(&self.0, key)
+        }
+
+        fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+            Map::get_mut(&mut self.0, key)
+        }
+
+        fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+            Map::get_at(&self.0, index)
+        }
+
+        fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+            Map::get_at_mut(&mut self.0, index)
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn iter(&self) -> MapIter {
+            MapIter::new(self)
+        }
+
+        fn values_mut(&mut self) -> MapValuesMut {
+            Map::values_mut(&mut self.0)
+        }
+
+        fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+            Box::new(self.0).drain()
+        }
+
+        fn clone_dynamic(&self) -> DynamicMap {
+            Map::clone_dynamic(&self.0)
+        }
+
+        fn insert_boxed(
+            &mut self,
+            key: Box<dyn Reflect>,
+            value: Box<dyn Reflect>,
+        ) -> Option<Box<dyn Reflect>> {
+            Map::insert_boxed(&mut self.0, key, value)
+        }
+
+        fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+            Map::remove(&mut self.0, key)
+        }
+    }
 
     #[test]
     fn test_into_iter() {
@@ -669,4 +1182,203 @@ mod tests {
             assert_eq!(size, iter.index);
         }
     }
+
+    #[test]
+    fn should_get_or_insert_with() {
+        let mut dynamic_map = DynamicMap::default();
+        let mut hash_map = bevy_utils::HashMap::<u32, String>::new();
+        let mut btree_map = std::collections::BTreeMap::<u32, String>::new();
+
+        for map in [
+            &mut dynamic_map as &mut dyn Map,
+            &mut hash_map,
+            &mut btree_map,
+        ] {
+            let mut calls = 0;
+            for _ in 0..2 {
+                let value = map.get_or_insert_with(Box::new(1_u32), &mut || {
+                    calls += 1;
+                    Box::new(String::from("a"))
+                });
+                value.downcast_mut::<String>().unwrap().push('b');
+            }
+
+            assert_eq!(1, calls);
+            assert_eq!(1, map.len());
+            assert_eq!(
+                Some(&String::from("abb")),
+                map.get(&1_u32).unwrap().downcast_ref::<String>()
+            );
+        }
+
+        let mut minimal_map = MinimalMap::default();
+        let mut calls = 0;
+        for _ in 0..2 {
+            let value = minimal_map.get_or_insert_with(Box::new(1_u32), &mut || {
+                calls += 1;
+                Box::new(10_u32)
+            });
+            *value.downcast_mut::<u32>().unwrap() += 1;
+        }
+        assert_eq!(1, calls);
+        assert_eq!(BTreeMap::from([(1, 12)]), minimal_map.0);
+    }
+
+    #[test]
+    fn should_iterate_keys_and_values() {
+        let mut dynamic_map = DynamicMap::default();
+        let mut hash_map = bevy_utils::HashMap::new();
+        let mut btree_map = std::collections::BTreeMap::new();
+        for key in 0_u32..3 {
+            dynamic_map.insert(key, key * 10);
+            hash_map.insert(key, key * 10);
+            btree_map.insert(key, key * 10);
+        }
+
+        for map in [
+            &mut dynamic_map as &mut dyn Map,
+            &mut hash_map,
+            &mut btree_map,
+        ] {
+            assert_eq!(3, map.values_mut().len());
+            for value in map.values_mut() {
+                *value.downcast_mut::<u32>().unwrap() += 1;
+            }
+
+            let mut keys = map
+                .keys()
+                .map(|key| *key.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            keys.sort_unstable();
+            assert_eq!(vec![0, 1, 2], keys);
+
+            let mut values = map
+                .values()
+                .map(|value| *value.downcast_ref::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            values.sort_unstable();
+            assert_eq!(vec![1, 11, 21], values);
+        }
+    }
+
+    #[test]
+    fn should_query_ordered_map() {
+        use crate::{GetTypeRegistration, OrderedMap, ReflectOrderedMap};
+        use std::{collections::BTreeMap, ops::Bound};
+
+        let map = BTreeMap::from([(1_u32, 10_u32), (3, 30), (5, 50), (7, 70)]);
+        let registration = BTreeMap::<u32, u32>::get_type_registration();
+        let ordered_map = registration
+            .data::<ReflectOrderedMap>()
+            .unwrap()
+            .get(&map)
+            .unwrap();
+
+        let key = |(key, _): (&dyn Reflect, &dyn Reflect)| *key.downcast_ref::<u32>().unwrap();
+        assert_eq!(Some(1), ordered_map.first().map(key));
+        assert_eq!(Some(7), ordered_map.last().map(key));
+
+        let range = ordered_map.range(Bound::Included(&3_u32), Bound::Excluded(&7_u32));
+        assert_eq!(vec![3, 5], range.map(key).collect::<Vec<_>>());
+        let range = ordered_map.range(Bound::Unbounded, Bound::Included(&5_u32));
+        assert_eq!(vec![5, 3, 1], range.rev().map(key).collect::<Vec<_>>());
+
+        assert!(OrderedMap::first(&BTreeMap::<u32, u32>::new()).is_none());
+        assert!(registration_is_unordered::<bevy_utils::HashMap<u32, u32>>());
+
+        fn registration_is_unordered<T: GetTypeRegistration>() -> bool {
+            T::get_type_registration()
+                .data::<ReflectOrderedMap>()
+                .is_none()
+        }
+    }
+
+    #[test]
+    fn should_retain_and_clear() {
+        fn retain_even(map: &mut dyn Map) {
+            map.retain(&mut |key, value| {
+                *value.downcast_mut::<u32>().unwrap() += 1;
+                key.downcast_ref::<u32>().unwrap() % 2 == 0
+            });
+        }
+
+        let mut dynamic_map = DynamicMap::default();
+        let mut hash_map = bevy_utils::HashMap::new();
+        let mut btree_map = std::collections::BTreeMap::new();
+        let mut minimal_map = MinimalMap::default();
+        for key in 0_u32..4 {
+            dynamic_map.insert(key, key * 10);
+            hash_map.insert(key, key * 10);
+            btree_map.insert(key, key * 10);
+            minimal_map.0.insert(key, key * 10);
+        }
+
+        retain_even(&mut dynamic_map);
+        retain_even(&mut hash_map);
+        retain_even(&mut btree_map);
+        retain_even(&mut minimal_map);
+
+        for map in [
+            &mut dynamic_map as &mut dyn Map,
+            &mut hash_map,
+            &mut btree_map,
+            &mut minimal_map,
+        ] {
+            assert_eq!(2, map.len());
+            assert_eq!(Some(&1), map.get(&0_u32).unwrap().downcast_ref::<u32>());
+            assert_eq!(Some(&21), map.get(&2_u32).unwrap().downcast_ref::<u32>());
+            assert!(map.get(&1_u32).is_none());
+
+            map.clear();
+            assert!(map.is_empty());
+            assert!(map.get(&0_u32).is_none());
+        }
+    }
+
+    #[test]
+    fn should_sort_keys() {
+        let mut map = DynamicMap::default();
+        for key in [5_u32, 1, 4, 2, 3] {
+            map.insert(key, key * 10);
+        }
+
+        map.sort_keys();
+        let keys = map
+            .iter()
+            .map(|(key, _)| *key.downcast_ref::<u32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3, 4, 5], keys);
+
+        // Lookups still work after reordering and removing entries
+        assert_eq!(Some(&20), map.get(&2_u32).unwrap().downcast_ref::<u32>());
+        map.remove(&1_u32);
+        assert_eq!(Some(&50), map.get(&5_u32).unwrap().downcast_ref::<u32>());
+        assert_eq!(Some(&30), map.get(&3_u32).unwrap().downcast_ref::<u32>());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn should_iterate_in_parallel() {
+        use rayon::iter::ParallelIterator;
+
+        let mut map = DynamicMap::default();
+        map.insert(1_u32, 10_u32);
+        map.insert(2_u32, 20_u32);
+
+        map.par_iter_mut().for_each(|(_, value)| {
+            *value.downcast_mut::<u32>().unwrap() += 1;
+        });
+
+        let sum: u32 = (&map as &dyn Map)
+            .par_iter()
+            .map(|(_, value)| *value.downcast_ref::<u32>().unwrap())
+            .sum();
+        assert_eq!(32, sum);
+
+        let mut map = std::collections::BTreeMap::from([(1_u32, 10_u32), (2, 20)]);
+        (&mut map as &mut dyn Map)
+            .par_values_mut()
+            .for_each(|value| *value.downcast_mut::<u32>().unwrap() *= 2);
+        assert_eq!(std::collections::BTreeMap::from([(1, 20), (2, 40)]), map);
+    }
 }
@@ -0,0 +1,429 @@
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::{
+    access::Access, attributes::CustomAttributes, FromReflect, FromType, GetPath,
+    GetTypeRegistration, ParsedPath, Reflect, ReflectPath, ReflectRef, TypeInfo, TypePath,
+    TypeRegistry, VariantInfo,
+};
+
+/// A numeric type that supports checked arithmetic through reflection.
+///
+/// This trait is implemented for all primitive integer and floating point types,
+/// which register [`ReflectNumeric`] as type data.
+pub trait Numeric: FromReflect + TypePath + GetTypeRegistration + Copy + PartialOrd {
+    /// Adds `rhs` to `self`, returning `None` if the result is out of range.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result is out of range.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_numeric_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Numeric for $ty {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_numeric_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Numeric for $ty {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs).filter(|value| value.is_finite())
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    Some(self * rhs).filter(|value| value.is_finite())
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_numeric_float!(f32, f64);
+
+/// Type data for performing checked arithmetic on reflected [`Numeric`] values.
+///
+/// This allows tools, such as consoles or inspectors, to increment or scale
+/// a value without knowing its concrete type.
+/// See [`add_assign_reflect`] and [`mul_assign_reflect`] to do so by path.
+#[derive(Clone)]
+pub struct ReflectNumeric {
+    add_assign: fn(&mut dyn Reflect, &dyn Reflect) -> Result<(), ReflectArithmeticError>,
+    mul_assign: fn(&mut dyn Reflect, &dyn Reflect) -> Result<(), ReflectArithmeticError>,
+    clamp: fn(&mut dyn Reflect, &CustomAttributes) -> bool,
+    range: fn(&CustomAttributes) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)>,
+    contains: fn(&dyn Reflect, &CustomAttributes) -> Option<bool>,
+}
+
+impl ReflectNumeric {
+    /// Adds `delta` to `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either value is not of the type this data was registered for,
+    /// or if the result is out of range, in which case `target` is left unchanged.
+    pub fn add_assign(
+        &self,
+        target: &mut dyn Reflect,
+        delta: &dyn Reflect,
+    ) -> Result<(), ReflectArithmeticError> {
+        (self.add_assign)(target, delta)
+    }
+
+    /// Multiplies `target` by `factor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either value is not of the type this data was registered for,
+    /// or if the result is out of range, in which case `target` is left unchanged.
+    pub fn mul_assign(
+        &self,
+        target: &mut dyn Reflect,
+        factor: &dyn Reflect,
+    ) -> Result<(), ReflectArithmeticError> {
+        (self.mul_assign)(target, factor)
+    }
+
+    /// Clamps `target` to the [`RangeInclusive`] found in `attributes`, if any.
+    ///
+    /// Returns `true` if `target` was changed.
+    pub fn clamp(&self, target: &mut dyn Reflect, attributes: &CustomAttributes) -> bool {
+        (self.clamp)(target, attributes)
+    }
+
+    /// Returns the start and end of the [`RangeInclusive`] found in `attributes`, if any.
+    pub fn range(
+        &self,
+        attributes: &CustomAttributes,
+    ) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        (self.range)(attributes)
+    }
+
+    /// Returns whether `value` lies within the [`RangeInclusive`] found in `attributes`.
+    ///
+    /// Returns `None` if there is no such range, or if `value` is not of the type this data was registered for.
+    pub fn contains(&self, value: &dyn Reflect, attributes: &CustomAttributes) -> Option<bool> {
+        (self.contains)(value, attributes)
+    }
+}
+
+impl<T: Numeric> FromType<T> for ReflectNumeric {
+    fn from_type() -> Self {
+        ReflectNumeric {
+            add_assign: |target, delta| apply_checked::<T>(target, delta, T::checked_add),
+            mul_assign: |target, factor| apply_checked::<T>(target, factor, T::checked_mul),
+            clamp: |target, attributes| {
+                let (Some(target), Some(range)) = (
+                    target.downcast_mut::<T>(),
+                    attributes.get::<RangeInclusive<T>>(),
+                ) else {
+                    return false;
+                };
+                let clamped = if *target < *range.start() {
+                    *range.start()
+                } else if *target > *range.end() {
+                    *range.end()
+                } else {
+                    return false;
+                };
+                *target = clamped;
+                true
+            },
+            range: |attributes| {
+                let range = attributes.get::<RangeInclusive<T>>()?;
+                Some((Box::new(*range.start()), Box::new(*range.end())))
+            },
+            contains: |value, attributes| {
+                let range = attributes.get::<RangeInclusive<T>>()?;
+                Some(range.contains(value.downcast_ref::<T>()?))
+            },
+        }
+    }
+}
+
+fn apply_checked<T: Numeric>(
+    target: &mut dyn Reflect,
+    operand: &dyn Reflect,
+    op: fn(T, T) -> Option<T>,
+) -> Result<(), ReflectArithmeticError> {
+    let operand =
+        T::from_reflect(operand).ok_or_else(|| ReflectArithmeticError::MismatchedTypes {
+            expected: T::type_path(),
+            received: operand.reflect_type_path().to_string(),
+        })?;
+    let target_type_path = target.reflect_type_path().to_string();
+    let target = target
+        .downcast_mut::<T>()
+        .ok_or(ReflectArithmeticError::MismatchedTypes {
+            expected: T::type_path(),
+            received: target_type_path,
+        })?;
+    *target = op(*target, operand).ok_or(ReflectArithmeticError::Overflow {
+        type_path: T::type_path(),
+    })?;
+    Ok(())
+}
+
+/// An error returned by reflected arithmetic operations.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ReflectArithmeticError {
+    /// The path does not point to a value.
+    #[error("invalid path `{path}`: {message}")]
+    InvalidPath {
+        /// The path that was accessed.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The value at the path has no [`ReflectNumeric`] registered.
+    #[error("`{type_path}` does not have `ReflectNumeric` registered")]
+    NotNumeric {
+        /// The type path of the value.
+        type_path: String,
+    },
+    /// The operand is not of the same type as the value.
+    #[error("expected a value of type `{expected}` but received `{received}`")]
+    MismatchedTypes {
+        /// The type path of the value.
+        expected: &'static str,
+        /// The type path of the operand.
+        received: String,
+    },
+    /// The result of the operation is out of range for the type of the value.
+    #[error("the result does not fit in a `{type_path}`")]
+    Overflow {
+        /// The type path of the value.
+        type_path: &'static str,
+    },
+}
+
+/// Adds `delta` to the numeric value at `path` in `root`.
+///
+/// If the field at `path` has a [`RangeInclusive`] [custom attribute] of its own type,
+/// the result is clamped to that range.
+///
+/// ```
+/// # use bevy_reflect::{add_assign_reflect, prelude::*, TypeRegistry};
+/// # use std::ops::RangeInclusive;
+/// #[derive(Reflect)]
+/// struct Light {
+///     #[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]
+///     intensity: f32,
+/// }
+///
+/// let registry = TypeRegistry::default();
+/// let mut light = Light { intensity: 0.8 };
+///
+/// add_assign_reflect(&mut light, "intensity", &0.5_f32, &registry).unwrap();
+/// assert_eq!(1.0, light.intensity);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the path is invalid, if the value has no [`ReflectNumeric`] registered,
+/// if `delta` is of another type, or if the result overflows.
+/// The value is left unchanged in that case.
+///
+/// [custom attribute]: crate::attributes::CustomAttributes
+pub fn add_assign_reflect(
+    root: &mut dyn Reflect,
+    path: &str,
+    delta: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), ReflectArithmeticError> {
+    apply_at_path(root, path, delta, registry, ReflectNumeric::add_assign)
+}
+
+/// Multiplies the numeric value at `path` in `root` by `factor`.
+///
+/// If the field at `path` has a [`RangeInclusive`] [custom attribute] of its own type,
+/// the result is clamped to that range.
+///
+/// # Errors
+///
+/// Returns an error if the path is invalid, if the value has no [`ReflectNumeric`] registered,
+/// if `factor` is of another type, or if the result overflows.
+/// The value is left unchanged in that case.
+///
+/// [custom attribute]: crate::attributes::CustomAttributes
+pub fn mul_assign_reflect(
+    root: &mut dyn Reflect,
+    path: &str,
+    factor: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), ReflectArithmeticError> {
+    apply_at_path(root, path, factor, registry, ReflectNumeric::mul_assign)
+}
+
+fn apply_at_path(
+    root: &mut dyn Reflect,
+    path: &str,
+    operand: &dyn Reflect,
+    registry: &TypeRegistry,
+    op: fn(&ReflectNumeric, &mut dyn Reflect, &dyn Reflect) -> Result<(), ReflectArithmeticError>,
+) -> Result<(), ReflectArithmeticError> {
+    let attributes = ParsedPath::parse(path).ok().and_then(|mut parent_path| {
+        let last = parent_path.0.pop()?;
+        let parent = (&parent_path).reflect_element(&*root).ok()?;
+        field_attributes(parent, &last.access)
+    });
+    let target =
+        root.reflect_path_mut(path)
+            .map_err(|error| ReflectArithmeticError::InvalidPath {
+                path: path.to_string(),
+                message: error.to_string(),
+            })?;
+
+    let type_path = target.reflect_type_path();
+    let numeric = target
+        .get_represented_type_info()
+        .and_then(|info| registry.get_type_data::<ReflectNumeric>(info.type_id()))
+        .ok_or_else(|| ReflectArithmeticError::NotNumeric {
+            type_path: type_path.to_string(),
+        })?;
+
+    op(numeric, target, operand)?;
+    if let Some(attributes) = attributes {
+        numeric.clamp(target, attributes);
+    }
+    Ok(())
+}
+
+/// Returns the custom attributes of the field of `parent` accessed by `access`, if any.
+fn field_attributes(parent: &dyn Reflect, access: &Access) -> Option<&'static CustomAttributes> {
+    let info = parent.get_represented_type_info()?;
+    let field_attributes = match (info, access) {
+        (TypeInfo::Struct(info), Access::Field(name)) => info.field(name)?.custom_attributes(),
+        (TypeInfo::Struct(info), Access::FieldIndex(index)) => {
+            info.field_at(*index)?.custom_attributes()
+        }
+        (TypeInfo::TupleStruct(info), Access::TupleIndex(index)) => {
+            info.field_at(*index)?.custom_attributes()
+        }
+        (TypeInfo::Enum(info), access) => {
+            let ReflectRef::Enum(value) = parent.reflect_ref() else {
+                return None;
+            };
+            match (info.variant(value.variant_name())?, access) {
+                (VariantInfo::Struct(info), Access::Field(name)) => {
+                    info.field(name)?.custom_attributes()
+                }
+                (VariantInfo::Struct(info), Access::FieldIndex(index)) => {
+                    info.field_at(*index)?.custom_attributes()
+                }
+                (VariantInfo::Tuple(info), Access::TupleIndex(index)) => {
+                    info.field_at(*index)?.custom_attributes()
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(field_attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+
+    #[derive(Reflect)]
+    struct Settings {
+        #[reflect(@RangeInclusive::<i32>::new(0, 10))]
+        volume: i32,
+        count: u8,
+        scale: f32,
+        modes: Vec<Mode>,
+    }
+
+    #[derive(Reflect)]
+    enum Mode {
+        Fixed(#[reflect(@RangeInclusive::<f64>::new(-1.0, 1.0))] f64),
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            volume: 5,
+            count: 250,
+            scale: 2.0,
+            modes: vec![Mode::Fixed(0.5)],
+        }
+    }
+
+    #[test]
+    fn should_add_and_multiply_by_path() {
+        let registry = TypeRegistry::default();
+        let mut settings = settings();
+
+        add_assign_reflect(&mut settings, "count", &5_u8, &registry).unwrap();
+        assert_eq!(255, settings.count);
+
+        mul_assign_reflect(&mut settings, "scale", &1.5_f32, &registry).unwrap();
+        assert_eq!(3.0, settings.scale);
+
+        add_assign_reflect(&mut settings, "volume", &-2, &registry).unwrap();
+        assert_eq!(3, settings.volume);
+    }
+
+    #[test]
+    fn should_clamp_to_range_attribute() {
+        let registry = TypeRegistry::default();
+        let mut settings = settings();
+
+        add_assign_reflect(&mut settings, "volume", &100, &registry).unwrap();
+        assert_eq!(10, settings.volume);
+
+        mul_assign_reflect(&mut settings, "volume", &-1, &registry).unwrap();
+        assert_eq!(0, settings.volume);
+
+        add_assign_reflect(&mut settings, "modes[0].0", &2.0_f64, &registry).unwrap();
+        let Mode::Fixed(value) = settings.modes[0];
+        assert_eq!(1.0, value);
+    }
+
+    #[test]
+    fn should_report_errors() {
+        let registry = TypeRegistry::default();
+        let mut settings = settings();
+
+        assert_eq!(
+            Err(ReflectArithmeticError::Overflow { type_path: "u8" }),
+            add_assign_reflect(&mut settings, "count", &6_u8, &registry)
+        );
+        assert_eq!(250, settings.count);
+
+        assert_eq!(
+            Err(ReflectArithmeticError::MismatchedTypes {
+                expected: "u8",
+                received: "f32".to_string(),
+            }),
+            add_assign_reflect(&mut settings, "count", &1.0_f32, &registry)
+        );
+
+        assert!(matches!(
+            add_assign_reflect(&mut settings, "missing", &1_u8, &registry),
+            Err(ReflectArithmeticError::InvalidPath { .. })
+        ));
+
+        assert_eq!(
+            Err(ReflectArithmeticError::NotNumeric {
+                type_path: "alloc::vec::Vec<bevy_reflect::numeric::tests::Mode>".to_string(),
+            }),
+            add_assign_reflect(&mut settings, "modes", &1_u8, &registry)
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use std::fmt::{self, Formatter};
+
+use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
+use serde::Deserializer;
+use thiserror::Error;
+
+use crate::serde::TypedReflectDeserializer;
+use crate::{ApplyError, GetPath, Reflect, TypeInfo, TypeRegistration, TypeRegistry};
+
+/// Returns the name of the platform the application is running on.
+///
+/// This is the value of [`std::env::consts::OS`], such as `"linux"`, `"windows"`, or `"android"`,
+/// and is used by [`PlatformOverlays::apply`] to select the patches to apply.
+pub fn current_platform() -> &'static str {
+    std::env::consts::OS
+}
+
+/// A patch to a single field of a value, only applied on a specific platform.
+#[derive(Debug)]
+pub struct PlatformPatch {
+    /// The [platform](current_platform) this patch applies to.
+    pub platform: String,
+    /// The [path](GetPath) of the patched field, relative to the patched value.
+    pub path: String,
+    /// The value that is [applied](Reflect::try_apply) to the field.
+    pub value: Box<dyn Reflect>,
+}
+
+/// A set of platform-specific patches for a reflected value, such as a settings struct.
+///
+/// This allows a single config asset to serve every target:
+/// the shared value is loaded as usual, and the patches for the running platform
+/// are applied on top of it.
+///
+/// Overlays are usually loaded with a [`PlatformOverlaysDeserializer`],
+/// which reads a map from platform names to a map of field paths and their values:
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, PlatformOverlaysDeserializer, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect)]
+/// struct Settings {
+///     msaa: u32,
+///     vsync: bool,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Settings>();
+/// let registration = registry.get(std::any::TypeId::of::<Settings>()).unwrap();
+///
+/// let input = r#"{
+///     "android": { "msaa": 0 },
+///     "windows": { "msaa": 8, "vsync": false },
+/// }"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let overlays = PlatformOverlaysDeserializer::new(registration, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let mut settings = Settings { msaa: 4, vsync: true };
+/// overlays.apply_for(&mut settings, "android").unwrap();
+/// assert_eq!(0, settings.msaa);
+/// assert!(settings.vsync);
+/// ```
+#[derive(Debug, Default)]
+pub struct PlatformOverlays {
+    patches: Vec<PlatformPatch>,
+}
+
+impl PlatformOverlays {
+    /// Creates an empty set of overlays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a patch setting the field at `path` to `value` on the given platform.
+    pub fn with_patch(
+        mut self,
+        platform: impl Into<String>,
+        path: impl Into<String>,
+        value: impl Reflect,
+    ) -> Self {
+        self.insert(platform, path, Box::new(value));
+        self
+    }
+
+    /// Adds a patch setting the field at `path` to `value` on the given platform.
+    ///
+    /// Patches are applied in the order they were added,
+    /// so a later patch to the same field takes precedence.
+    pub fn insert(
+        &mut self,
+        platform: impl Into<String>,
+        path: impl Into<String>,
+        value: Box<dyn Reflect>,
+    ) {
+        self.patches.push(PlatformPatch {
+            platform: platform.into(),
+            path: path.into(),
+            value,
+        });
+    }
+
+    /// Returns an iterator over all patches, in the order they are applied.
+    pub fn iter(&self) -> impl Iterator<Item = &PlatformPatch> {
+        self.patches.iter()
+    }
+
+    /// Returns the number of patches across all platforms.
+    pub fn len(&self) -> usize {
+        self.patches.len()
+    }
+
+    /// Returns `true` if there are no patches.
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Applies the patches for the [current platform](current_platform) to `target`.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), OverlayError> {
+        self.apply_for(target, current_platform())
+    }
+
+    /// Applies the patches for the given platform to `target`.
+    ///
+    /// If a patch fails to apply, the patches before it will already have been applied.
+    pub fn apply_for(&self, target: &mut dyn Reflect, platform: &str) -> Result<(), OverlayError> {
+        for patch in self
+            .patches
+            .iter()
+            .filter(|patch| patch.platform == platform)
+        {
+            let field = target
+                .reflect_path_mut(patch.path.as_str())
+                .map_err(|err| OverlayError::InvalidPath {
+                    platform: patch.platform.clone(),
+                    path: patch.path.clone(),
+                    message: err.to_string(),
+                })?;
+            field
+                .try_apply(&*patch.value)
+                .map_err(|error| OverlayError::Apply {
+                    platform: patch.platform.clone(),
+                    path: patch.path.clone(),
+                    error,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when applying [`PlatformOverlays`].
+#[derive(Error, Debug)]
+pub enum OverlayError {
+    /// The path of a patch does not point to a field of the target.
+    #[error("invalid path `{path}` in overlay for `{platform}`: {message}")]
+    InvalidPath {
+        /// The platform of the patch.
+        platform: String,
+        /// The path of the patch.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The value of a patch could not be applied to its field.
+    #[error("failed to apply `{path}` in overlay for `{platform}`: {error}")]
+    Apply {
+        /// The platform of the patch.
+        platform: String,
+        /// The path of the patch.
+        path: String,
+        /// The error returned when applying the value.
+        error: ApplyError,
+    },
+}
+
+/// A deserializer for the [`PlatformOverlays`] of a given type.
+///
+/// The input is a map from platform names to a map of field paths and their values.
+/// Paths are made of struct field names separated by `.`, such as `graphics.msaa`,
+/// and each value is deserialized as the type of the field it patches.
+pub struct PlatformOverlaysDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PlatformOverlaysDeserializer<'a> {
+    /// Creates a deserializer for overlays of the type of `registration`.
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        Self {
+            registration,
+            registry,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PlatformOverlaysDeserializer<'a> {
+    type Value = PlatformOverlays;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(PlatformOverlaysVisitor {
+            registration: self.registration,
+            registry: self.registry,
+        })
+    }
+}
+
+struct PlatformOverlaysVisitor<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for PlatformOverlaysVisitor<'a> {
+    type Value = PlatformOverlays;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of platforms to overlay patches")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut overlays = PlatformOverlays::new();
+        while let Some(platform) = map.next_key::<String>()? {
+            map.next_value_seed(PlatformPatchesVisitor {
+                platform,
+                registration: self.registration,
+                registry: self.registry,
+                overlays: &mut overlays,
+            })?;
+        }
+        Ok(overlays)
+    }
+}
+
+struct PlatformPatchesVisitor<'a, 'b> {
+    platform: String,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    overlays: &'b mut PlatformOverlays,
+}
+
+impl<'a, 'b, 'de> DeserializeSeed<'de> for PlatformPatchesVisitor<'a, 'b> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'b, 'de> Visitor<'de> for PlatformPatchesVisitor<'a, 'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of field paths to values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(path) = map.next_key::<String>()? {
+            let registration = field_registration(self.registration, &path, self.registry)
+                .map_err(Error::custom)?;
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            self.overlays.insert(self.platform.clone(), path, value);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the registration of the type of the struct field at `path`.
+fn field_registration<'a>(
+    registration: &'a TypeRegistration,
+    path: &str,
+    registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, String> {
+    let mut registration = registration;
+    for name in path.split('.') {
+        let TypeInfo::Struct(info) = registration.type_info() else {
+            return Err(format!(
+                "cannot access field `{name}` of `{}` in overlay path `{path}`: overlays only support struct fields",
+                registration.type_info().type_path()
+            ));
+        };
+        let field = info.field(name).ok_or_else(|| {
+            format!(
+                "`{}` has no field `{name}` in overlay path `{path}`",
+                info.type_path()
+            )
+        })?;
+        registration = registry.get(field.type_id()).ok_or_else(|| {
+            format!(
+                "no registration found for `{}` in overlay path `{path}`",
+                field.type_path()
+            )
+        })?;
+    }
+    Ok(registration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Graphics {
+        msaa: u32,
+        vsync: bool,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Settings {
+        graphics: Graphics,
+        volume: f32,
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            graphics: Graphics {
+                msaa: 4,
+                vsync: true,
+            },
+            volume: 1.0,
+        }
+    }
+
+    #[test]
+    fn should_apply_overlays_for_platform() {
+        let overlays = PlatformOverlays::new()
+            .with_patch("android", "graphics.msaa", 0_u32)
+            .with_patch("windows", "graphics.vsync", false)
+            .with_patch("android", "volume", 0.5_f32);
+
+        let mut value = settings();
+        overlays.apply_for(&mut value, "android").unwrap();
+        assert_eq!(
+            Settings {
+                graphics: Graphics {
+                    msaa: 0,
+                    vsync: true
+                },
+                volume: 0.5,
+            },
+            value
+        );
+
+        let mut value = settings();
+        overlays.apply_for(&mut value, "ios").unwrap();
+        assert_eq!(settings(), value);
+
+        let overlays = PlatformOverlays::new().with_patch("ios", "graphics.fps", 60_u32);
+        assert!(matches!(
+            overlays.apply_for(&mut value, "ios"),
+            Err(OverlayError::InvalidPath { .. })
+        ));
+    }
+
+    #[test]
+    fn should_deserialize_overlays() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+
+        let input = r#"{
+            "android": { "graphics.msaa": 0, "volume": 0.5 },
+            "windows": { "graphics": (msaa: 8, vsync: false) },
+        }"#;
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let overlays = PlatformOverlaysDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(3, overlays.len());
+
+        let mut value = settings();
+        overlays.apply_for(&mut value, "windows").unwrap();
+        assert_eq!(
+            Graphics {
+                msaa: 8,
+                vsync: false
+            },
+            value.graphics
+        );
+
+        let input = r#"{ "android": { "graphics.fps": 60 } }"#;
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let error = PlatformOverlaysDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("has no field `fps`"));
+    }
+}
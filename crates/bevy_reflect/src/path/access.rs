@@ -1,9 +1,15 @@
 //! Representation for individual element accesses within a path.
 
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 use super::error::AccessErrorKind;
-use crate::{AccessError, Reflect, ReflectKind, ReflectMut, ReflectRef, VariantType};
+use crate::{
+    AccessError, NamedField, Reflect, ReflectKind, ReflectMut, ReflectRef, TypeInfo, VariantInfo,
+    VariantType,
+};
 
 type InnerResult<T> = Result<T, AccessErrorKind>;
 
@@ -21,6 +27,18 @@ pub enum Access<'a> {
     TupleIndex(usize),
     /// An index-based access on a list.
     ListIndex(usize),
+    /// An access on every element of a list or array, or every value of a map.
+    ///
+    /// Since it matches any number of elements,
+    /// paths containing it must be resolved with [`GetPath::reflect_path_iter`].
+    ///
+    /// [`GetPath::reflect_path_iter`]: super::GetPath::reflect_path_iter
+    Wildcard,
+    /// An access on the elements of a list or array, or the values of a map,
+    /// which match a [`Filter`].
+    ///
+    /// Like a [`Wildcard`](Access::Wildcard), this can match any number of elements.
+    Filter(Box<Filter<'a>>),
 }
 
 impl fmt::Display for Access<'_> {
@@ -30,6 +48,8 @@ impl fmt::Display for Access<'_> {
             Access::FieldIndex(index) => write!(f, "#{index}"),
             Access::TupleIndex(index) => write!(f, ".{index}"),
             Access::ListIndex(index) => write!(f, "[{index}]"),
+            Access::Wildcard => f.write_str("[*]"),
+            Access::Filter(filter) => write!(f, "[{filter}]"),
         }
     }
 }
@@ -46,6 +66,8 @@ impl<'a> Access<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(value),
             Self::TupleIndex(value) => Access::TupleIndex(value),
             Self::ListIndex(value) => Access::ListIndex(value),
+            Self::Wildcard => Access::Wildcard,
+            Self::Filter(filter) => Access::Filter(Box::new(filter.into_owned())),
         }
     }
 
@@ -66,9 +88,18 @@ impl<'a> Access<'a> {
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
         match (self, base.reflect_ref()) {
-            (Self::Field(field), Struct(struct_ref)) => Ok(struct_ref.field(field.as_ref())),
+            (Self::Field(field), Struct(struct_ref)) => {
+                Ok(struct_ref.field(field.as_ref()).or_else(|| {
+                    let name = aliased_field(struct_ref.get_represented_type_info(), None, field)?;
+                    struct_ref.field(name)
+                }))
+            }
             (Self::Field(field), Enum(enum_ref)) => match enum_ref.variant_type() {
-                VariantType::Struct => Ok(enum_ref.field(field.as_ref())),
+                VariantType::Struct => Ok(enum_ref.field(field.as_ref()).or_else(|| {
+                    let info = enum_ref.get_represented_type_info();
+                    let name = aliased_field(info, Some(enum_ref.variant_name()), field)?;
+                    enum_ref.field(name)
+                })),
                 actual => Err(invalid_variant(VariantType::Struct, actual)),
             },
             (&Self::FieldIndex(index), Struct(struct_ref)) => Ok(struct_ref.field_at(index)),
@@ -96,10 +127,15 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get(index)),
-            (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
-                expected: ReflectKind::List,
-                actual: actual.into(),
-            }),
+            (Self::Wildcard | Self::Filter(_), List(_) | Array(_) | Map(_)) => {
+                Err(AccessErrorKind::MultipleElements)
+            }
+            (Self::ListIndex(_) | Self::Wildcard | Self::Filter(_), actual) => {
+                Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                })
+            }
         }
     }
 
@@ -125,9 +161,26 @@ impl<'a> Access<'a> {
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
         match (self, base.reflect_mut()) {
-            (Self::Field(field), Struct(struct_mut)) => Ok(struct_mut.field_mut(field.as_ref())),
+            (Self::Field(field), Struct(struct_mut)) => {
+                let name = match struct_mut.field(field.as_ref()) {
+                    Some(_) => field.as_ref(),
+                    None => aliased_field(struct_mut.get_represented_type_info(), None, field)
+                        .unwrap_or(field.as_ref()),
+                };
+                Ok(struct_mut.field_mut(name))
+            }
             (Self::Field(field), Enum(enum_mut)) => match enum_mut.variant_type() {
-                VariantType::Struct => Ok(enum_mut.field_mut(field.as_ref())),
+                VariantType::Struct => {
+                    let name = match enum_mut.field(field.as_ref()) {
+                        Some(_) => field.as_ref(),
+                        None => {
+                            let info = enum_mut.get_represented_type_info();
+                            aliased_field(info, Some(enum_mut.variant_name()), field)
+                                .unwrap_or(field.as_ref())
+                        }
+                    };
+                    Ok(enum_mut.field_mut(name))
+                }
                 actual => Err(invalid_variant(VariantType::Struct, actual)),
             },
             (&Self::FieldIndex(index), Struct(struct_mut)) => Ok(struct_mut.field_at_mut(index)),
@@ -155,10 +208,70 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get_mut(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get_mut(index)),
-            (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
-                expected: ReflectKind::List,
-                actual: actual.into(),
-            }),
+            (Self::Wildcard | Self::Filter(_), List(_) | Array(_) | Map(_)) => {
+                Err(AccessErrorKind::MultipleElements)
+            }
+            (Self::ListIndex(_) | Self::Wildcard | Self::Filter(_), actual) => {
+                Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                })
+            }
+        }
+    }
+
+    /// Returns every element matched by a [`Wildcard`](Self::Wildcard)
+    /// or [`Filter`](Self::Filter) access on `base`.
+    pub(super) fn matched_elements<'s, 'r: 's>(
+        &'s self,
+        base: &'r dyn Reflect,
+        offset: Option<usize>,
+    ) -> Result<Box<dyn Iterator<Item = &'r dyn Reflect> + 's>, AccessError<'a>> {
+        let elements: Box<dyn Iterator<Item = &'r dyn Reflect> + 's> = match base.reflect_ref() {
+            ReflectRef::List(list) => Box::new(list.iter()),
+            ReflectRef::Array(array) => Box::new(array.iter()),
+            ReflectRef::Map(map) => Box::new(map.values()),
+            actual => {
+                return Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                }
+                .with_access(self.clone(), offset))
+            }
+        };
+        match self {
+            Self::Filter(filter) => Ok(Box::new(
+                elements.filter(move |element| filter.matches(*element)),
+            )),
+            _ => Ok(elements),
+        }
+    }
+
+    /// Returns mutable references to every element matched by a [`Wildcard`](Self::Wildcard)
+    /// or [`Filter`](Self::Filter) access on `base`.
+    pub(super) fn matched_elements_mut<'s, 'r: 's>(
+        &'s self,
+        base: &'r mut dyn Reflect,
+        offset: Option<usize>,
+    ) -> Result<Box<dyn Iterator<Item = &'r mut dyn Reflect> + 's>, AccessError<'a>> {
+        let elements: Box<dyn Iterator<Item = &'r mut dyn Reflect> + 's> = match base.reflect_mut()
+        {
+            ReflectMut::List(list) => Box::new(list.iter_mut()),
+            ReflectMut::Array(array) => Box::new(array.iter_mut()),
+            ReflectMut::Map(map) => Box::new(map.values_mut()),
+            actual => {
+                return Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                }
+                .with_access(self.clone(), offset))
+            }
+        };
+        match self {
+            Self::Filter(filter) => Ok(Box::new(
+                elements.filter(move |element| filter.matches(&**element)),
+            )),
+            _ => Ok(elements),
         }
     }
 
@@ -167,6 +280,8 @@ impl<'a> Access<'a> {
         match self {
             Self::Field(value) => value,
             Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::Wildcard => &"*",
+            Self::Filter(filter) => filter,
         }
     }
 
@@ -175,6 +290,189 @@ impl<'a> Access<'a> {
             Self::Field(_) => "field",
             Self::FieldIndex(_) => "field index",
             Self::TupleIndex(_) | Self::ListIndex(_) => "index",
+            Self::Wildcard => "wildcard",
+            Self::Filter(_) => "filter",
+        }
+    }
+}
+
+/// A filter on the elements of a collection, written as `[?path == value]` in a path string.
+///
+/// An element matches if the value at [`path`](Filter::path) within it
+/// compares to [`value`](Filter::value) as described by [`operator`](Filter::operator).
+/// Elements without a value at the path, or whose value can't be compared to the literal,
+/// never match.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Filter<'a> {
+    /// The path to the compared value, relative to each element.
+    ///
+    /// An empty path compares the element itself.
+    pub path: Vec<Access<'a>>,
+    /// How the value at `path` is compared to `value`.
+    pub operator: FilterOperator,
+    /// The literal that the value at `path` is compared to.
+    pub value: FilterValue<'a>,
+}
+
+impl<'a> Filter<'a> {
+    /// Converts this into an "owned" value.
+    ///
+    /// See [`Access::into_owned`] for details.
+    pub fn into_owned(self) -> Filter<'static> {
+        Filter {
+            path: self.path.into_iter().map(Access::into_owned).collect(),
+            operator: self.operator,
+            value: self.value.into_owned(),
+        }
+    }
+
+    /// Returns true if `element` is matched by this filter.
+    pub fn matches(&self, element: &dyn Reflect) -> bool {
+        self.path
+            .iter()
+            .try_fold(element, |value, access| {
+                access.element_inner(value).ok().flatten()
+            })
+            .and_then(|value| self.value.compare(value))
+            .is_some_and(|equal| equal == (self.operator == FilterOperator::Equal))
+    }
+}
+
+impl fmt::Display for Filter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("?")?;
+        for access in &self.path {
+            write!(f, "{access}")?;
         }
+        write!(f, " {} {}", self.operator, self.value)
     }
 }
+
+/// The comparison performed by a [`Filter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FilterOperator {
+    /// Matches values equal to the literal, written as `==`.
+    Equal,
+    /// Matches values not equal to the literal, written as `!=`.
+    NotEqual,
+}
+
+impl fmt::Display for FilterOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Equal => f.write_str("=="),
+            Self::NotEqual => f.write_str("!="),
+        }
+    }
+}
+
+/// A literal which values are compared to in a [`Filter`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FilterValue<'a> {
+    /// A `true` or `false` literal, compared to [`bool`] values.
+    Bool(bool),
+    /// A numeric literal, compared to primitive numbers by parsing it as the number's type.
+    ///
+    /// The literal is kept as written, so that it can be parsed as any numeric type.
+    Number(Cow<'a, str>),
+    /// A quoted string literal, compared to strings and to the names of unit enum variants.
+    String(Cow<'a, str>),
+}
+
+impl<'a> FilterValue<'a> {
+    /// Converts this into an "owned" value.
+    ///
+    /// See [`Access::into_owned`] for details.
+    pub fn into_owned(self) -> FilterValue<'static> {
+        match self {
+            Self::Bool(value) => FilterValue::Bool(value),
+            Self::Number(value) => FilterValue::Number(Cow::Owned(value.into_owned())),
+            Self::String(value) => FilterValue::String(Cow::Owned(value.into_owned())),
+        }
+    }
+
+    /// Returns whether `value` is equal to this literal,
+    /// or `None` if the two can't be compared.
+    pub fn compare(&self, value: &dyn Reflect) -> Option<bool> {
+        match self {
+            Self::Bool(literal) => value.downcast_ref::<bool>().map(|value| value == literal),
+            Self::Number(literal) => compare_number(value, literal),
+            Self::String(literal) => string_value(value).map(|value| value == literal),
+        }
+    }
+}
+
+impl fmt::Display for FilterValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Number(value) => f.write_str(value),
+            Self::String(value) => {
+                f.write_char('"')?;
+                for c in value.chars() {
+                    if matches!(c, '"' | '\\') {
+                        f.write_char('\\')?;
+                    }
+                    f.write_char(c)?;
+                }
+                f.write_char('"')
+            }
+        }
+    }
+}
+
+/// Compares a primitive number to a numeric literal parsed as the same type.
+fn compare_number(value: &dyn Reflect, literal: &str) -> Option<bool> {
+    macro_rules! compare {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return literal.parse::<$ty>().ok().map(|literal| literal == *value);
+                }
+            )*
+        };
+    }
+
+    compare!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    None
+}
+
+/// Returns the string held by `value`, or the variant name if it is a unit enum variant.
+fn string_value(value: &dyn Reflect) -> Option<&str> {
+    if let Some(value) = value.downcast_ref::<String>() {
+        return Some(value);
+    }
+    if let Some(value) = value.downcast_ref::<&'static str>() {
+        return Some(value);
+    }
+    if let Some(value) = value.downcast_ref::<Cow<'static, str>>() {
+        return Some(value);
+    }
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) if value.variant_type() == VariantType::Unit => {
+            Some(value.variant_name())
+        }
+        _ => None,
+    }
+}
+
+/// Resolves a field [alias] to the field's actual name using the given type info.
+///
+/// For enums, `variant` is the name of the current variant.
+///
+/// [alias]: NamedField::aliases
+fn aliased_field(
+    info: Option<&'static TypeInfo>,
+    variant: Option<&str>,
+    alias: &str,
+) -> Option<&'static str> {
+    let field = match (info?, variant) {
+        (TypeInfo::Struct(info), None) => info.field_or_alias(alias),
+        (TypeInfo::Enum(info), Some(variant)) => match info.variant(variant)? {
+            VariantInfo::Struct(info) => info.field_or_alias(alias),
+            _ => None,
+        },
+        _ => None,
+    };
+    field.map(NamedField::name)
+}
@@ -27,6 +27,10 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when a [`Wildcard`](Access::Wildcard) or [`Filter`](Access::Filter)
+    /// access, which matches any number of elements, is used where a single element is expected.
+    MultipleElements,
 }
 
 impl AccessErrorKind {
@@ -106,7 +110,10 @@ impl std::fmt::Display for AccessError<'_> {
                         "The {type_accessed} accessed doesn't have field index `{}`",
                         access.display_value(),
                     ),
-                    Access::TupleIndex(_) | Access::ListIndex(_) => write!(
+                    Access::TupleIndex(_)
+                    | Access::ListIndex(_)
+                    | Access::Wildcard
+                    | Access::Filter(_) => write!(
                         f,
                         "The {type_accessed} accessed doesn't have index `{}`",
                         access.display_value()
@@ -123,6 +130,10 @@ impl std::fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::MultipleElements => write!(
+                f,
+                "The access matches multiple elements, use `reflect_path_iter` to access them."
+            ),
         }
     }
 }
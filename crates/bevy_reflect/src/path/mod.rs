@@ -75,6 +75,50 @@ pub trait ReflectPath<'a>: Sized {
                 .ok_or(ReflectPathError::InvalidDowncast)
         })
     }
+
+    /// Gets references to all elements matched by the path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_iter`] for more details.
+    fn reflect_elements(self, root: &dyn Reflect) -> PathResult<'a, PathIter<'_, dyn Reflect>> {
+        self.reflect_element(root)
+            .map(|element| PathIter::new(vec![element]))
+    }
+
+    /// Gets mutable references to all elements matched by the path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_iter_mut`] for more details.
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn Reflect,
+    ) -> PathResult<'a, PathIterMut<'_, dyn Reflect>> {
+        self.reflect_element_mut(root)
+            .map(|element| PathIterMut::new(vec![element]))
+    }
+
+    /// Gets a `&T` to each element matched by the path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::path_iter`] for more details.
+    fn elements<T: Reflect>(self, root: &dyn Reflect) -> PathResult<'a, PathIter<'_, T>> {
+        self.reflect_elements(root)?
+            .map(|element| element.downcast_ref::<T>())
+            .collect::<Option<Vec<_>>>()
+            .map(PathIter::new)
+            .ok_or(ReflectPathError::InvalidDowncast)
+    }
+
+    /// Gets a `&mut T` to each element matched by the path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::path_iter_mut`] for more details.
+    fn elements_mut<T: Reflect>(
+        self,
+        root: &mut dyn Reflect,
+    ) -> PathResult<'a, PathIterMut<'_, T>> {
+        self.reflect_elements_mut(root)?
+            .map(|element| element.downcast_mut::<T>())
+            .collect::<Option<Vec<_>>>()
+            .map(PathIterMut::new)
+            .ok_or(ReflectPathError::InvalidDowncast)
+    }
 }
 impl<'a> ReflectPath<'a> for &'a str {
     fn reflect_element(self, mut root: &dyn Reflect) -> PathResult<'a, &dyn Reflect> {
@@ -90,7 +134,157 @@ impl<'a> ReflectPath<'a> for &'a str {
         }
         Ok(root)
     }
+    fn reflect_elements(self, root: &dyn Reflect) -> PathResult<'a, PathIter<'_, dyn Reflect>> {
+        let accesses = parse_accesses(self)?;
+        let mut elements = Vec::new();
+        collect_elements(
+            accesses
+                .iter()
+                .map(|(access, offset)| (access, Some(*offset))),
+            root,
+            &mut elements,
+        )?;
+        Ok(PathIter::new(elements))
+    }
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn Reflect,
+    ) -> PathResult<'a, PathIterMut<'_, dyn Reflect>> {
+        let accesses = parse_accesses(self)?;
+        let mut elements = Vec::new();
+        collect_elements_mut(
+            accesses
+                .iter()
+                .map(|(access, offset)| (access, Some(*offset))),
+            root,
+            &mut elements,
+        )?;
+        Ok(PathIterMut::new(elements))
+    }
+}
+
+fn parse_accesses(path: &str) -> PathResult<Vec<(Access, usize)>> {
+    PathParser::new(path)
+        .map(|(access, offset)| access.map(|access| (access, offset)))
+        .collect()
+}
+
+/// Resolves `accesses` on `root`, following every element matched by a wildcard or filter,
+/// and pushes the resolved elements to `elements`.
+fn collect_elements<'a: 'p, 'p, 'r>(
+    mut accesses: impl Iterator<Item = (&'p Access<'a>, Option<usize>)> + Clone,
+    mut root: &'r dyn Reflect,
+    elements: &mut Vec<&'r dyn Reflect>,
+) -> Result<(), AccessError<'a>> {
+    while let Some((access, offset)) = accesses.next() {
+        if let Access::Wildcard | Access::Filter(_) = access {
+            for element in access.matched_elements(root, offset)? {
+                collect_elements(accesses.clone(), element, elements)?;
+            }
+            return Ok(());
+        }
+        root = access.element(root, offset)?;
+    }
+    elements.push(root);
+    Ok(())
+}
+
+/// Mutable counterpart of [`collect_elements`].
+fn collect_elements_mut<'a: 'p, 'p, 'r>(
+    mut accesses: impl Iterator<Item = (&'p Access<'a>, Option<usize>)> + Clone,
+    mut root: &'r mut dyn Reflect,
+    elements: &mut Vec<&'r mut dyn Reflect>,
+) -> Result<(), AccessError<'a>> {
+    while let Some((access, offset)) = accesses.next() {
+        if let Access::Wildcard | Access::Filter(_) = access {
+            for element in access.matched_elements_mut(root, offset)? {
+                collect_elements_mut(accesses.clone(), element, elements)?;
+            }
+            return Ok(());
+        }
+        root = access.element_mut(root, offset)?;
+    }
+    elements.push(root);
+    Ok(())
+}
+
+/// An iterator over the elements matched by a path containing wildcards or filters.
+///
+/// This is created by calling [`GetPath::reflect_path_iter`] or [`GetPath::path_iter`].
+pub struct PathIter<'r, T: ?Sized + 'r> {
+    elements: std::vec::IntoIter<&'r T>,
+}
+
+impl<'r, T: ?Sized + 'r> PathIter<'r, T> {
+    fn new(elements: Vec<&'r T>) -> Self {
+        Self {
+            elements: elements.into_iter(),
+        }
+    }
 }
+
+impl<'r, T: ?Sized + 'r> Iterator for PathIter<'r, T> {
+    type Item = &'r T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<'r, T: ?Sized + 'r> DoubleEndedIterator for PathIter<'r, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.elements.next_back()
+    }
+}
+
+impl<'r, T: ?Sized + 'r> ExactSizeIterator for PathIter<'r, T> {}
+
+/// An iterator over mutable references to the elements matched by a path
+/// containing wildcards or filters.
+///
+/// This is created by calling [`GetPath::reflect_path_iter_mut`] or [`GetPath::path_iter_mut`].
+pub struct PathIterMut<'r, T: ?Sized + 'r> {
+    elements: std::vec::IntoIter<&'r mut T>,
+}
+
+impl<'r, T: ?Sized + 'r> PathIterMut<'r, T> {
+    fn new(elements: Vec<&'r mut T>) -> Self {
+        Self {
+            elements: elements.into_iter(),
+        }
+    }
+}
+
+impl<'r, T: ?Sized + 'r> Iterator for PathIterMut<'r, T> {
+    type Item = &'r mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<'r, T: ?Sized + 'r> DoubleEndedIterator for PathIterMut<'r, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.elements.next_back()
+    }
+}
+
+impl<'r, T: ?Sized + 'r> ExactSizeIterator for PathIterMut<'r, T> {}
+
 /// A trait which allows nested [`Reflect`] values to be retrieved with path strings.
 ///
 /// Using these functions repeatedly with the same string requires parsing the string every time.
@@ -202,6 +396,79 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// assert!(matches!(tuple_variant.path::<u32>(".value"), Err(_)));
 /// ```
 ///
+/// # Wildcards
+///
+/// A wildcard, written as `[*]`, matches every element of a [`List`] or [`Array`],
+/// or every value of a [`Map`].
+/// Paths containing wildcards can match any number of values,
+/// so they must be resolved with [`path_iter`] or [`path_iter_mut`],
+/// and the other methods of this trait will return an error for them.
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Wave {
+///   enemies: Vec<Enemy>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Enemy {
+///   health: f32,
+/// }
+///
+/// let mut wave = Wave {
+///   enemies: vec![Enemy { health: 10.0 }, Enemy { health: 20.0 }],
+/// };
+/// for health in wave.path_iter_mut::<f32>("enemies[*].health").unwrap() {
+///   *health *= 1.5;
+/// }
+///
+/// let health = wave.path_iter::<f32>("enemies[*].health").unwrap();
+/// assert_eq!(health.copied().collect::<Vec<_>>(), vec![15.0, 30.0]);
+/// ```
+///
+/// # Filters
+///
+/// A filter, written as `[?path == value]` or `[?path != value]`, works like a wildcard,
+/// but only matches the elements whose value at `path` compares to the literal `value`.
+/// The literal may be a number, a quoted string, or `true` or `false`.
+/// Strings are compared to [`String`]s, as well as to the names of unit enum variants.
+///
+/// The `path` is relative to each element, and may be empty to compare the element itself.
+/// Elements without a value at `path`, or whose value can't be compared to the literal,
+/// are not matched.
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Inventory {
+///   items: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Item {
+///   id: u32,
+///   name: String,
+///   count: u32,
+/// }
+///
+/// let mut inventory = Inventory {
+///   items: vec![
+///     Item { id: 1, name: String::from("shield"), count: 1 },
+///     Item { id: 3, name: String::from("sword"), count: 2 },
+///   ],
+/// };
+/// for count in inventory.path_iter_mut::<u32>("items[?id == 3].count").unwrap() {
+///   *count += 1;
+/// }
+///
+/// let mut counts = inventory.path_iter::<u32>(r#"items[?name == "sword"].count"#).unwrap();
+/// assert_eq!(counts.next(), Some(&3));
+/// assert_eq!(counts.next(), None);
+/// ```
+///
 /// # Chaining
 ///
 /// Using the aforementioned syntax, path items may be chained one after another
@@ -229,7 +496,10 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
+/// [`Map`]: crate::Map
 /// [`Enum`]: crate::Enum
+/// [`path_iter`]: GetPath::path_iter
+/// [`path_iter_mut`]: GetPath::path_iter_mut
 #[diagnostic::on_unimplemented(
     message = "`{Self}` does not provide a reflection path",
     note = "consider annotating `{Self}` with `#[derive(Reflect)]`"
@@ -275,6 +545,59 @@ pub trait GetPath: Reflect {
     fn path_mut<'p, T: Reflect>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, &mut T> {
         path.element_mut(self.as_reflect_mut())
     }
+
+    /// Returns an iterator over references to every value matched by `path`.
+    ///
+    /// Unlike [`reflect_path`], the path may contain [wildcards] and [filters],
+    /// which match many elements of a list or array, or many values of a map.
+    /// Elements are visited in the order of their containers' iterators.
+    ///
+    /// Every match must resolve the remainder of the path,
+    /// so this returns an error if any of them is missing a field or has the wrong kind.
+    ///
+    /// To retrieve statically typed references, use [`path_iter`][GetPath::path_iter].
+    ///
+    /// [`reflect_path`]: GetPath::reflect_path
+    /// [wildcards]: GetPath#wildcards
+    /// [filters]: GetPath#filters
+    fn reflect_path_iter<'p>(
+        &self,
+        path: impl ReflectPath<'p>,
+    ) -> PathResult<'p, PathIter<dyn Reflect>> {
+        path.reflect_elements(self.as_reflect())
+    }
+
+    /// Returns an iterator over mutable references to every value matched by `path`.
+    ///
+    /// See [`reflect_path_iter`][GetPath::reflect_path_iter] for more details.
+    ///
+    /// To retrieve statically typed mutable references, use
+    /// [`path_iter_mut`][GetPath::path_iter_mut].
+    fn reflect_path_iter_mut<'p>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+    ) -> PathResult<'p, PathIterMut<dyn Reflect>> {
+        path.reflect_elements_mut(self.as_reflect_mut())
+    }
+
+    /// Returns an iterator over statically typed references to every value matched by `path`.
+    ///
+    /// See [`reflect_path_iter`][GetPath::reflect_path_iter] for more details.
+    /// This returns an error if any of the matched values is not of type `T`.
+    fn path_iter<'p, T: Reflect>(&self, path: impl ReflectPath<'p>) -> PathResult<'p, PathIter<T>> {
+        path.elements(self.as_reflect())
+    }
+
+    /// Returns an iterator over statically typed mutable references to every value matched by `path`.
+    ///
+    /// See [`reflect_path_iter`][GetPath::reflect_path_iter] for more details.
+    /// This returns an error if any of the matched values is not of type `T`.
+    fn path_iter_mut<'p, T: Reflect>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+    ) -> PathResult<'p, PathIterMut<T>> {
+        path.elements_mut(self.as_reflect_mut())
+    }
 }
 
 // Implement `GetPath` for `dyn Reflect`
@@ -368,6 +691,8 @@ impl ParsedPath {
     /// - Unnamed field access (`.1`)
     /// - Field index access (`#0`)
     /// - Sequence access (`[2]`)
+    /// - Wildcard access (`[*]`)
+    /// - Filter access (`[?id == 3]`)
     ///
     /// # Example
     /// ```
@@ -439,6 +764,27 @@ impl<'a> ReflectPath<'a> for &'a ParsedPath {
         }
         Ok(root)
     }
+    fn reflect_elements(self, root: &dyn Reflect) -> PathResult<'a, PathIter<'_, dyn Reflect>> {
+        let mut elements = Vec::new();
+        collect_elements(
+            self.0.iter().map(|access| (&access.access, access.offset)),
+            root,
+            &mut elements,
+        )?;
+        Ok(PathIter::new(elements))
+    }
+    fn reflect_elements_mut(
+        self,
+        root: &mut dyn Reflect,
+    ) -> PathResult<'a, PathIterMut<'_, dyn Reflect>> {
+        let mut elements = Vec::new();
+        collect_elements_mut(
+            self.0.iter().map(|access| (&access.access, access.offset)),
+            root,
+            &mut elements,
+        )?;
+        Ok(PathIterMut::new(elements))
+    }
 }
 impl From<Vec<OffsetAccess>> for ParsedPath {
     fn from(value: Vec<OffsetAccess>) -> Self {
@@ -765,6 +1111,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reflect_path_with_aliases() {
+        #[derive(Reflect)]
+        struct Renamed {
+            #[reflect(rename = "health", alias = "hp", alias = "hit_points")]
+            value: u32,
+            state: State,
+        }
+
+        #[derive(Reflect)]
+        enum State {
+            Moving {
+                #[reflect(alias = "velocity")]
+                speed: f32,
+            },
+        }
+
+        let mut renamed = Renamed {
+            value: 10,
+            state: State::Moving { speed: 1.5 },
+        };
+
+        assert_eq!(*renamed.path::<u32>("health").unwrap(), 10);
+        assert_eq!(*renamed.path::<u32>("hp").unwrap(), 10);
+        assert!(renamed.reflect_path("value").is_err());
+
+        *renamed.path_mut::<u32>("hit_points").unwrap() = 20;
+        assert_eq!(renamed.value, 20);
+
+        assert_eq!(*renamed.path::<f32>("state.velocity").unwrap(), 1.5);
+        *renamed.path_mut::<f32>("state.velocity").unwrap() = 2.5;
+        assert_eq!(*renamed.path::<f32>("state.speed").unwrap(), 2.5);
+    }
+
     #[test]
     fn accept_leading_tokens() {
         assert_eq!(
@@ -790,4 +1170,155 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn wildcard_paths() {
+        let mut a = a_sample();
+
+        let parsed = ParsedPath::parse("y[*].mосква").unwrap();
+        assert_eq!(
+            parsed.0,
+            &[
+                offset(access_field("y"), 1),
+                offset(Access::Wildcard, 2),
+                offset(access_field("mосква"), 5),
+            ]
+        );
+        assert_eq!(parsed.to_string(), ".y[*].mосква");
+
+        let values = a.path_iter::<f32>(&parsed).unwrap();
+        assert_eq!(values.copied().collect::<Vec<_>>(), vec![1.0, 2.0]);
+
+        for value in a.path_iter_mut::<i32>("array[*]").unwrap() {
+            *value += 1;
+        }
+        assert_eq!(a.array, [87, 76, 310]);
+
+        // Paths without wildcards match a single element
+        assert_eq!(a.reflect_path_iter("x.foo").unwrap().len(), 1);
+
+        // Single-element accessors reject wildcards
+        let err = a.reflect_path("y[*]").unwrap_err();
+        let ReflectPathError::InvalidAccess(err) = err else {
+            panic!("expected an access error, got {err:?}");
+        };
+        assert_eq!(err.kind(), &AccessErrorKind::MultipleElements);
+
+        assert!(a.reflect_path_iter("x[*]").is_err());
+        assert!(a.reflect_path_iter("y[*].foo").is_err());
+        assert!(matches!(
+            a.path_iter::<u32>("y[*].mосква"),
+            Err(ReflectPathError::InvalidDowncast)
+        ));
+    }
+
+    #[test]
+    fn nested_wildcard_paths() {
+        #[derive(Reflect)]
+        struct Waves {
+            waves: Vec<Vec<C>>,
+            bosses: bevy_utils::HashMap<u32, C>,
+        }
+
+        let mut waves = Waves {
+            waves: vec![
+                vec![C { mосква: 1.0 }, C { mосква: 2.0 }],
+                vec![],
+                vec![C { mосква: 3.0 }],
+            ],
+            bosses: bevy_utils::HashMap::from([(0, C { mосква: 10.0 })]),
+        };
+
+        for value in waves.path_iter_mut::<f32>("waves[*][*].mосква").unwrap() {
+            *value *= 2.0;
+        }
+        let values = waves.path_iter::<f32>("waves[*][*].mосква").unwrap();
+        assert_eq!(values.copied().collect::<Vec<_>>(), vec![2.0, 4.0, 6.0]);
+
+        *waves
+            .path_iter_mut::<f32>("bosses[*].mосква")
+            .unwrap()
+            .next()
+            .unwrap() = 5.0;
+        assert_eq!(waves.bosses[&0].mосква, 5.0);
+    }
+
+    #[test]
+    fn filter_paths() {
+        #[derive(Reflect)]
+        struct Quest {
+            items: Vec<Item>,
+            rewards: bevy_utils::HashMap<u32, Item>,
+        }
+
+        #[derive(Reflect)]
+        struct Item {
+            id: u32,
+            name: String,
+            rarity: Rarity,
+            weight: f32,
+            count: u32,
+        }
+
+        #[derive(Reflect)]
+        enum Rarity {
+            Common,
+            Rare,
+        }
+
+        let item = |id, name: &str, rarity, count| Item {
+            id,
+            name: String::from(name),
+            rarity,
+            weight: 1.5,
+            count,
+        };
+        let mut quest = Quest {
+            items: vec![
+                item(1, "shield", Rarity::Common, 1),
+                item(3, "sword", Rarity::Rare, 2),
+                item(4, "sword", Rarity::Common, 5),
+            ],
+            rewards: bevy_utils::HashMap::from([(0, item(7, "gem", Rarity::Rare, 1))]),
+        };
+
+        let counts = |quest: &Quest, path: &str| {
+            quest
+                .path_iter::<u32>(path)
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(counts(&quest, "items[?id == 3].count"), vec![2]);
+        assert_eq!(
+            counts(&quest, r#"items[?name == "sword"].count"#),
+            vec![2, 5]
+        );
+        assert_eq!(counts(&quest, r#"items[?name != "sword"].count"#), vec![1]);
+        assert_eq!(counts(&quest, r#"items[?rarity == "Rare"].count"#), vec![2]);
+        assert_eq!(counts(&quest, "items[?weight == 1.5].count"), vec![1, 2, 5]);
+        assert_eq!(counts(&quest, "items[?id == 9].count"), Vec::<u32>::new());
+        assert_eq!(counts(&quest, "items[?count == 5].id"), vec![4]);
+
+        // Elements which can't be compared never match, even when filtering for inequality
+        assert_eq!(
+            counts(&quest, r#"items[?id != "3"].count"#),
+            Vec::<u32>::new()
+        );
+        assert_eq!(
+            counts(&quest, "items[?missing != 3].count"),
+            Vec::<u32>::new()
+        );
+
+        for count in quest
+            .path_iter_mut::<u32>(r#"rewards[?name == "gem"].count"#)
+            .unwrap()
+        {
+            *count = 10;
+        }
+        assert_eq!(quest.rewards[&0].count, 10);
+
+        assert!(quest.reflect_path("items[?id == 3].count").is_err());
+        assert!(quest.reflect_path_iter("items[0][?id == 3]").is_err());
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     fmt::{self, Write},
     num::ParseIntError,
     str::from_utf8_unchecked,
@@ -6,7 +7,7 @@ use std::{
 
 use thiserror::Error;
 
-use super::{Access, ReflectPathError};
+use super::{Access, Filter, FilterOperator, FilterValue, ReflectPathError};
 
 /// An error that occurs when parsing reflect path strings.
 #[derive(Debug, PartialEq, Eq, Error)]
@@ -33,6 +34,21 @@ enum Error<'a> {
 
     #[error("a ']' was found before an opening '['")]
     CloseBeforeOpen,
+
+    #[error("a '\"' wasn't closed, reached end of path string before finding a '\"'")]
+    UnclosedString,
+
+    #[error("expected a filter comparing a path with '==' or '!=', got '{0}' instead")]
+    MissingFilterOperator(&'a str),
+
+    #[error("expected a number, string or boolean to filter by, got '{0}' instead")]
+    InvalidFilterValue(&'a str),
+
+    #[error("invalid path in filter: {0}")]
+    InvalidFilterPath(Box<Error<'a>>),
+
+    #[error("the path of a filter can't contain wildcards or filters")]
+    NestedFilter,
 }
 
 pub(super) struct PathParser<'a> {
@@ -86,6 +102,7 @@ impl<'a> PathParser<'a> {
             Token::Pound => self.next_ident()?.field_index(),
             Token::Ident(ident) => Ok(ident.field()),
             Token::CloseBracket => Err(Error::CloseBeforeOpen),
+            Token::OpenBracket if self.remaining.first() == Some(&b'?') => self.filter(),
             Token::OpenBracket => {
                 let index_ident = self.next_ident()?.list_index()?;
                 match self.next_token() {
@@ -97,10 +114,116 @@ impl<'a> PathParser<'a> {
         }
     }
 
+    /// Parses a filter, with `remaining` starting at the `?` following its opening `[`.
+    fn filter(&mut self) -> Result<Access<'a>, Error<'a>> {
+        let start = self.offset() + 1;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut depth = 0_usize;
+        let mut len = None;
+        for (index, byte) in self.remaining[1..].iter().enumerate() {
+            match (in_string, byte) {
+                (true, _) if escaped => escaped = false,
+                (true, b'\\') => escaped = true,
+                (_, b'"') => in_string = !in_string,
+                (false, b'[') => depth += 1,
+                (false, b']') if depth == 0 => {
+                    len = Some(index);
+                    break;
+                }
+                (false, b']') => depth -= 1,
+                _ => {}
+            }
+        }
+        let Some(len) = len else {
+            return Err(if in_string {
+                Error::UnclosedString
+            } else {
+                Error::Unclosed
+            });
+        };
+
+        // NOTE: `?` and `]` are ASCII, so both ends of the filter are char boundaries.
+        let filter = &self.path[start..start + len];
+        self.remaining = &self.remaining[len + 2..];
+        parse_filter(filter)
+    }
+
     fn offset(&self) -> usize {
         self.path.len() - self.remaining.len()
     }
 }
+
+/// Parses the contents of a filter, such as `id == 3`, excluding the surrounding `[?` and `]`.
+fn parse_filter(filter: &str) -> Result<Access, Error> {
+    // The path comes first and can't contain a `=`, so the first operator is the right one,
+    // even if the value is a string containing another operator.
+    let Some(index) = [filter.find("=="), filter.find("!=")]
+        .into_iter()
+        .flatten()
+        .min()
+    else {
+        return Err(Error::MissingFilterOperator(filter));
+    };
+    let operator = match &filter[index..index + 2] {
+        "==" => FilterOperator::Equal,
+        _ => FilterOperator::NotEqual,
+    };
+
+    let mut parser = PathParser::new(filter[..index].trim());
+    let mut path = Vec::new();
+    while let Some(token) = parser.next_token() {
+        let access = parser
+            .access_following(token)
+            .map_err(|error| Error::InvalidFilterPath(Box::new(error)))?;
+        if let Access::Wildcard | Access::Filter(_) = access {
+            return Err(Error::NestedFilter);
+        }
+        path.push(access);
+    }
+
+    let value = filter[index + 2..].trim();
+    let value = match value {
+        "true" => FilterValue::Bool(true),
+        "false" => FilterValue::Bool(false),
+        _ if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') => {
+            FilterValue::String(
+                unescape(&value[1..value.len() - 1]).ok_or(Error::InvalidFilterValue(value))?,
+            )
+        }
+        _ if value.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+            && value.parse::<f64>().is_ok() =>
+        {
+            FilterValue::Number(value.into())
+        }
+        _ => return Err(Error::InvalidFilterValue(value)),
+    };
+
+    Ok(Access::Filter(Box::new(Filter {
+        path,
+        operator,
+        value,
+    })))
+}
+
+/// Removes the escaping backslashes from the contents of a string literal,
+/// returning `None` if it contains an unescaped `"`.
+fn unescape(string: &str) -> Option<Cow<str>> {
+    if !string.contains(['\\', '"']) {
+        return Some(Cow::Borrowed(string));
+    }
+
+    let mut unescaped = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.push(chars.next()?),
+            '"' => return None,
+            c => unescaped.push(c),
+        }
+    }
+    Some(Cow::Owned(unescaped))
+}
 impl<'a> Iterator for PathParser<'a> {
     type Item = (Result<Access<'a>, ReflectPathError<'a>>, usize);
 
@@ -131,6 +254,9 @@ impl<'a> Ident<'a> {
         Ok(Access::FieldIndex(self.0.parse()?))
     }
     fn list_index(self) -> Result<Access<'a>, Error<'a>> {
+        if self.0 == "*" {
+            return Ok(Access::Wildcard);
+        }
         Ok(Access::ListIndex(self.0.parse()?))
     }
 }
@@ -195,4 +321,76 @@ mod test {
             }),
         ));
     }
+
+    #[test]
+    fn parse_filters() {
+        let filter = |path: Vec<Access<'static>>, operator, value| {
+            Access::Filter(Box::new(Filter {
+                path,
+                operator,
+                value,
+            }))
+        };
+
+        let parsed = ParsedPath::parse_static("items[?id == 3].count").unwrap();
+        assert_eq!(
+            parsed.0[1].access,
+            filter(
+                vec![Access::Field("id".into())],
+                FilterOperator::Equal,
+                FilterValue::Number("3".into())
+            )
+        );
+        assert_eq!(parsed.0[2].access, Access::Field("count".into()));
+        assert_eq!(parsed.to_string(), ".items[?.id == 3].count");
+
+        let parsed = ParsedPath::parse_static(r#"[?stats[0].name!="a \"]\" != b"]"#).unwrap();
+        assert_eq!(
+            parsed.0[0].access,
+            filter(
+                vec![
+                    Access::Field("stats".into()),
+                    Access::ListIndex(0),
+                    Access::Field("name".into())
+                ],
+                FilterOperator::NotEqual,
+                FilterValue::String(r#"a "]" != b"#.into())
+            )
+        );
+        assert_eq!(
+            ParsedPath::parse(&parsed.to_string()).unwrap().0[0].access,
+            parsed.0[0].access
+        );
+
+        let parsed = ParsedPath::parse_static("[? == true]").unwrap();
+        assert_eq!(
+            parsed.0[0].access,
+            filter(Vec::new(), FilterOperator::Equal, FilterValue::Bool(true))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_filters() {
+        let error = |path| match ParsedPath::parse_static(path) {
+            Err(ReflectPathError::ParseError {
+                error: ParseError(error),
+                ..
+            }) => error,
+            other => panic!("expected a parse error for `{path}`, got {other:?}"),
+        };
+
+        assert_eq!(error("[?id == 3"), Error::Unclosed);
+        assert_eq!(error(r#"[?name == "sword]"#), Error::UnclosedString);
+        assert_eq!(error("[?id = 3]"), Error::MissingFilterOperator("id = 3"));
+        assert_eq!(error("[?id == three]"), Error::InvalidFilterValue("three"));
+        assert_eq!(
+            error(r#"[?id == "a" "b"]"#),
+            Error::InvalidFilterValue(r#""a" "b""#)
+        );
+        assert_eq!(error("[?items[*] == 3]"), Error::NestedFilter);
+        assert_eq!(
+            error("[?id.. == 3]"),
+            Error::InvalidFilterPath(Box::new(Error::ExpectedIdent(Token::Dot)))
+        );
+    }
 }
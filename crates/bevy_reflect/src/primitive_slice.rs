@@ -0,0 +1,215 @@
+use std::any::{Any, TypeId};
+use std::hash::{Hash, Hasher};
+
+use serde::{Serialize, Serializer};
+
+use crate::utility::reflect_hasher;
+use crate::Reflect;
+
+/// A contiguous slice of primitive values, borrowed from a [`List`].
+///
+/// Lists which store their elements contiguously, like `Vec<f32>` or `Vec<u32>`,
+/// expose them through [`List::as_primitive_slice`].
+/// This allows them to be serialized, cloned, hashed and compared as a whole,
+/// rather than element by element through `dyn Reflect`,
+/// which matters for large lists like mesh attributes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{List, PrimitiveSlice};
+/// let positions: &dyn List = &vec![0.0_f32, 1.0, 2.0];
+/// assert_eq!(
+///     Some(PrimitiveSlice::F32(&[0.0, 1.0, 2.0])),
+///     positions.as_primitive_slice()
+/// );
+///
+/// let names: &dyn List = &vec![String::from("a")];
+/// assert_eq!(None, names.as_primitive_slice());
+/// ```
+///
+/// [`List`]: crate::List
+/// [`List::as_primitive_slice`]: crate::List::as_primitive_slice
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrimitiveSlice<'a> {
+    Bool(&'a [bool]),
+    U8(&'a [u8]),
+    U16(&'a [u16]),
+    U32(&'a [u32]),
+    U64(&'a [u64]),
+    Usize(&'a [usize]),
+    I8(&'a [i8]),
+    I16(&'a [i16]),
+    I32(&'a [i32]),
+    I64(&'a [i64]),
+    Isize(&'a [isize]),
+    F32(&'a [f32]),
+    F64(&'a [f64]),
+}
+
+macro_rules! impl_primitive_slice {
+    (hashable: [$($hashable:ident($hashable_ty:ty)),*], unhashable: [$($unhashable:ident($unhashable_ty:ty)),*]) => {
+        impl<'a> PrimitiveSlice<'a> {
+            /// Returns the elements of `value` as a primitive slice,
+            /// or `None` if it is not a `Vec` of one of the supported primitive types.
+            pub fn from_vec(value: &'a dyn Any) -> Option<Self> {
+                $(
+                    if let Some(vec) = value.downcast_ref::<Vec<$hashable_ty>>() {
+                        return Some(Self::$hashable(vec));
+                    }
+                )*
+                $(
+                    if let Some(vec) = value.downcast_ref::<Vec<$unhashable_ty>>() {
+                        return Some(Self::$unhashable(vec));
+                    }
+                )*
+                None
+            }
+
+            /// Returns true if `T` is a primitive type which can be stored in a `PrimitiveSlice`.
+            pub fn supports<T: Any>() -> bool {
+                let type_id = TypeId::of::<T>();
+                $(type_id == TypeId::of::<$hashable_ty>() ||)*
+                $(type_id == TypeId::of::<$unhashable_ty>() ||)*
+                false
+            }
+
+            /// Returns the number of elements in the slice.
+            pub fn len(&self) -> usize {
+                match self {
+                    $(Self::$hashable(slice) => slice.len(),)*
+                    $(Self::$unhashable(slice) => slice.len(),)*
+                }
+            }
+
+            /// Returns true if the slice contains no elements.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Copies the slice into a new `Vec` of the same primitive type.
+            pub fn to_vec(&self) -> Box<dyn Reflect> {
+                match self {
+                    $(Self::$hashable(slice) => Box::new(slice.to_vec()),)*
+                    $(Self::$unhashable(slice) => Box::new(slice.to_vec()),)*
+                }
+            }
+
+            /// Feeds the [`Reflect::reflect_hash`] of each element into `hasher`,
+            /// or returns `None` if the elements can't be hashed.
+            pub(crate) fn hash_elements(&self, hasher: &mut impl Hasher) -> Option<()> {
+                match self {
+                    $(Self::$hashable(slice) => {
+                        for value in slice.iter() {
+                            hasher.write_u64(hash_element(value));
+                        }
+                        Some(())
+                    })*
+                    $(Self::$unhashable(_) => None,)*
+                }
+            }
+        }
+
+        impl Serialize for PrimitiveSlice<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $(Self::$hashable(slice) => slice.serialize(serializer),)*
+                    $(Self::$unhashable(slice) => slice.serialize(serializer),)*
+                }
+            }
+        }
+    };
+}
+
+impl_primitive_slice!(
+    hashable: [
+        Bool(bool),
+        U8(u8),
+        U16(u16),
+        U32(u32),
+        U64(u64),
+        Usize(usize),
+        I8(i8),
+        I16(i16),
+        I32(i32),
+        I64(i64),
+        Isize(isize)
+    ],
+    unhashable: [F32(f32), F64(f64)]
+);
+
+/// Hashes a primitive value the same way as its [`Reflect::reflect_hash`].
+fn hash_element<T: Hash + Any>(value: &T) -> u64 {
+    let mut hasher = reflect_hasher();
+    Hash::hash(&TypeId::of::<T>(), &mut hasher);
+    Hash::hash(value, &mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{List, ListInfo, TypeInfo, Typed};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn should_expose_primitive_vecs() {
+        let list: &dyn List = &vec![1_u16, 2, 3];
+        assert_eq!(
+            Some(PrimitiveSlice::U16(&[1, 2, 3])),
+            list.as_primitive_slice()
+        );
+        assert_eq!(Some(&[1_u16, 2, 3][..]), list.as_slice_of::<u16>());
+        assert_eq!(None, list.as_slice_of::<u32>());
+
+        let list: &dyn List = &VecDeque::from([1_u16, 2, 3]);
+        assert_eq!(None, list.as_primitive_slice());
+
+        let TypeInfo::List(info) = <Vec<f64> as Typed>::type_info() else {
+            panic!("expected list info");
+        };
+        assert!(info.has_primitive_slice());
+        assert!(!ListInfo::new::<VecDeque<f64>, f64>().has_primitive_slice());
+        assert!(!ListInfo::new::<Vec<String>, String>().has_primitive_slice());
+    }
+
+    #[test]
+    fn should_match_element_wise_operations() {
+        let values = vec![3_i32, -1, 4, 1, -5];
+        let mut dynamic = crate::DynamicList::default();
+        for value in &values {
+            dynamic.push(*value);
+        }
+
+        // Hashes only depend on the elements, not on how they are stored
+        assert_eq!(crate::list_hash(&values), crate::list_hash(&values.clone()));
+        let mut hasher = reflect_hasher();
+        PrimitiveSlice::I32(&values)
+            .hash_elements(&mut hasher)
+            .unwrap();
+        let mut expected = reflect_hasher();
+        for value in &values {
+            expected.write_u64(value.reflect_hash().unwrap());
+        }
+        assert_eq!(expected.finish(), hasher.finish());
+        assert_eq!(None, vec![1.0_f32].reflect_hash());
+
+        assert_eq!(Some(true), values.reflect_partial_eq(&dynamic));
+        assert_eq!(Some(true), values.reflect_partial_eq(&values.clone()));
+        assert_eq!(Some(false), values.reflect_partial_eq(&vec![3_i32, -1]));
+        assert_eq!(
+            Some(false),
+            values.reflect_partial_eq(&vec![3_i64, -1, 4, 1, -5])
+        );
+        assert_eq!(
+            Some(true),
+            Vec::<u8>::new().reflect_partial_eq(&Vec::<f32>::new())
+        );
+
+        let clone = values.reflect_clone().unwrap();
+        assert_eq!(Some(&values), clone.downcast_ref::<Vec<i32>>());
+    }
+}
@@ -0,0 +1,427 @@
+use std::any::TypeId;
+use std::fmt::{self, Formatter};
+use std::sync::Arc;
+
+use serde::de::{DeserializeSeed, Error as _, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeTuple};
+use serde::{Deserializer, Serialize, Serializer};
+
+use crate::serde::Serializable;
+use crate::std_traits::ReflectDefault;
+use crate::{
+    DynamicArray, DynamicEnum, DynamicTuple, DynamicVariant, Reflect, ReflectDeserialize,
+    ReflectFromReflect, ReflectRef, ReflectSerialize, TypeData, TypeInfo, TypeRegistry,
+    VariantInfo,
+};
+
+/// The shape of a container whose type data can be propagated from its element types.
+#[derive(Clone, Copy)]
+enum Container {
+    Option,
+    Array(usize),
+    Tuple(usize),
+}
+
+impl Container {
+    /// Returns the container shape described by `info`, along with the type ids of its elements.
+    fn of(info: &TypeInfo) -> Option<(Self, Vec<TypeId>)> {
+        match info {
+            TypeInfo::Array(info) => {
+                Some((Container::Array(info.capacity()), vec![info.item_type_id()]))
+            }
+            TypeInfo::Tuple(info) => Some((
+                Container::Tuple(info.field_len()),
+                info.iter().map(crate::UnnamedField::type_id).collect(),
+            )),
+            TypeInfo::Enum(info)
+                if info.type_path_table().module_path() == Some("core::option")
+                    && info.type_path_table().ident() == Some("Option") =>
+            {
+                let VariantInfo::Tuple(some) = info.variant("Some")? else {
+                    return None;
+                };
+                Some((Container::Option, vec![some.field_at(0)?.type_id()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the element type data used for the element at `index`.
+    fn element_index(self, index: usize) -> usize {
+        match self {
+            Container::Array(_) => 0,
+            Container::Option | Container::Tuple(_) => index,
+        }
+    }
+}
+
+impl TypeRegistry {
+    /// Propagates the serialization and default type data of the element types
+    /// of an [`Option`], array, or tuple to the registration of the container itself.
+    ///
+    /// The [`ReflectSerialize`], [`ReflectDeserialize`], and [`ReflectDefault`] type data
+    /// are each added to the container if every one of its element types has them,
+    /// unless the container already has them.
+    /// Other types, as well as the serialization of byte arrays, are left untouched.
+    ///
+    /// This is done automatically when registering a type with [`register`](Self::register),
+    /// so it only needs to be called again if type data is added to the element types afterwards,
+    /// such as with [`register_type_data`](Self::register_type_data).
+    ///
+    /// The propagated type data serializes containers the same way as the reflection serializers.
+    /// Deserialized and default values are converted to the container type
+    /// if it has [`ReflectFromReflect`], and are otherwise returned as dynamic values.
+    pub fn propagate_type_data(&mut self, type_id: TypeId) {
+        let Some(registration) = self.get(type_id) else {
+            return;
+        };
+        let info = registration.type_info();
+        let Some((container, elements)) = Container::of(info) else {
+            return;
+        };
+        let from_reflect = registration.data::<ReflectFromReflect>().cloned();
+        // Byte arrays and lists have a compact format of their own in the reflection serializers
+        let propagate_serde = match info {
+            TypeInfo::Array(info) => !info.item_is::<u8>(),
+            TypeInfo::List(info) => !info.item_is::<u8>(),
+            _ => true,
+        };
+
+        let serialize = (propagate_serde && registration.data::<ReflectSerialize>().is_none())
+            .then(|| self.element_data::<ReflectSerialize>(&elements))
+            .flatten()
+            .map(|elements| propagate_serialize(container, elements));
+        let deserialize = (propagate_serde && registration.data::<ReflectDeserialize>().is_none())
+            .then(|| self.element_data::<ReflectDeserialize>(&elements))
+            .flatten()
+            .map(|elements| propagate_deserialize(container, info, elements, from_reflect.clone()));
+        let default = registration
+            .data::<ReflectDefault>()
+            .is_none()
+            .then(|| self.element_data::<ReflectDefault>(&elements))
+            .flatten()
+            .map(|elements| propagate_default(container, info, elements, from_reflect));
+
+        let registration = self.get_mut(type_id).unwrap();
+        if let Some(serialize) = serialize {
+            registration.insert(serialize);
+        }
+        if let Some(deserialize) = deserialize {
+            registration.insert(deserialize);
+        }
+        if let Some(default) = default {
+            registration.insert(default);
+        }
+    }
+
+    /// Returns the type data `T` of every element type, if they all have it.
+    fn element_data<T: TypeData + Clone>(&self, elements: &[TypeId]) -> Option<Arc<[T]>> {
+        elements
+            .iter()
+            .map(|&type_id| self.get_type_data::<T>(type_id).cloned())
+            .collect()
+    }
+}
+
+fn propagate_serialize(
+    container: Container,
+    elements: Arc<[ReflectSerialize]>,
+) -> ReflectSerialize {
+    ReflectSerialize::new(move |value| {
+        Serializable::Owned(Box::new(ContainerSerializer {
+            container,
+            value,
+            elements: elements.clone(),
+        }))
+    })
+}
+
+fn propagate_deserialize(
+    container: Container,
+    info: &'static TypeInfo,
+    elements: Arc<[ReflectDeserialize]>,
+    from_reflect: Option<ReflectFromReflect>,
+) -> ReflectDeserialize {
+    ReflectDeserialize::new(move |deserializer| {
+        let value: Box<dyn Reflect> = match container {
+            Container::Option => {
+                let mut value = deserializer.deserialize_option(OptionVisitor {
+                    element: &elements[0],
+                })?;
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+            Container::Array(len) => {
+                let values = deserializer.deserialize_tuple(
+                    len,
+                    ElementsVisitor {
+                        container,
+                        len,
+                        elements: &elements,
+                    },
+                )?;
+                let mut value = DynamicArray::new(values.into_boxed_slice());
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+            Container::Tuple(len) => {
+                let values = deserializer.deserialize_tuple(
+                    len,
+                    ElementsVisitor {
+                        container,
+                        len,
+                        elements: &elements,
+                    },
+                )?;
+                let mut value = DynamicTuple::default();
+                for field in values {
+                    value.insert_boxed(field);
+                }
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+        };
+        Ok(convert(value, from_reflect.as_ref()))
+    })
+}
+
+fn propagate_default(
+    container: Container,
+    info: &'static TypeInfo,
+    elements: Arc<[ReflectDefault]>,
+    from_reflect: Option<ReflectFromReflect>,
+) -> ReflectDefault {
+    ReflectDefault::new(move || {
+        let value: Box<dyn Reflect> = match container {
+            Container::Option => {
+                let mut value = DynamicEnum::new("None", DynamicVariant::Unit);
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+            Container::Array(len) => {
+                let mut value =
+                    DynamicArray::new((0..len).map(|_| elements[0].default()).collect());
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+            Container::Tuple(_) => {
+                let mut value = DynamicTuple::default();
+                for element in elements.iter() {
+                    value.insert_boxed(element.default());
+                }
+                value.set_represented_type(Some(info));
+                Box::new(value)
+            }
+        };
+        convert(value, from_reflect.as_ref())
+    })
+}
+
+/// Converts a dynamic `value` to its concrete type, if possible.
+fn convert(value: Box<dyn Reflect>, from_reflect: Option<&ReflectFromReflect>) -> Box<dyn Reflect> {
+    from_reflect
+        .and_then(|from_reflect| from_reflect.from_reflect(&*value))
+        .unwrap_or(value)
+}
+
+struct ContainerSerializer<'a> {
+    container: Container,
+    value: &'a dyn Reflect,
+    elements: Arc<[ReflectSerialize]>,
+}
+
+impl<'a> Serialize for ContainerSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let fields: Vec<&dyn Reflect> = match (self.container, self.value.reflect_ref()) {
+            (Container::Option, ReflectRef::Enum(value)) => {
+                return match value.field_at(0) {
+                    Some(field) => {
+                        serializer.serialize_some(self.elements[0].get_serializable(field).borrow())
+                    }
+                    None => serializer.serialize_none(),
+                };
+            }
+            (Container::Array(_), ReflectRef::Array(value)) => value.iter().collect(),
+            (Container::Tuple(_), ReflectRef::Tuple(value)) => value.iter_fields().collect(),
+            _ => {
+                return Err(S::Error::custom(format_args!(
+                    "cannot serialize `{}` with the type data propagated to its container type",
+                    self.value.reflect_type_path()
+                )))
+            }
+        };
+
+        let mut state = serializer.serialize_tuple(fields.len())?;
+        for (index, field) in fields.into_iter().enumerate() {
+            let element = &self.elements[self.container.element_index(index)];
+            state.serialize_element(element.get_serializable(field).borrow())?;
+        }
+        state.end()
+    }
+}
+
+struct ElementSeed<'a>(&'a ReflectDeserialize);
+
+impl<'a, 'de> DeserializeSeed<'de> for ElementSeed<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize(deserializer)
+    }
+}
+
+struct OptionVisitor<'a> {
+    element: &'a ReflectDeserialize,
+}
+
+impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(DynamicEnum::new("None", DynamicVariant::Unit))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = DynamicTuple::default();
+        value.insert_boxed(self.element.deserialize(deserializer)?);
+        Ok(DynamicEnum::new("Some", DynamicVariant::Tuple(value)))
+    }
+}
+
+struct ElementsVisitor<'a> {
+    container: Container,
+    len: usize,
+    elements: &'a [ReflectDeserialize],
+}
+
+impl<'a, 'de> Visitor<'de> for ElementsVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of {} elements", self.len)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.len);
+        for index in 0..self.len {
+            let element = &self.elements[self.container.element_index(index)];
+            let value = seq
+                .next_element_seed(ElementSeed(element))?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{self as bevy_reflect, FromReflect};
+    use serde::de::DeserializeSeed;
+
+    #[derive(
+        Reflect, Debug, Default, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize,
+    )]
+    #[reflect(Serialize, Deserialize, Default)]
+    struct Meters(f32);
+
+    #[derive(Reflect, Debug, Default, PartialEq)]
+    struct Unserializable(f32);
+
+    #[test]
+    fn should_propagate_type_data_to_containers() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Option<Meters>>();
+        registry.register::<[Meters; 2]>();
+        registry.register::<(Meters, u32)>();
+        registry.register::<Option<Unserializable>>();
+
+        for type_id in [
+            TypeId::of::<Option<Meters>>(),
+            TypeId::of::<[Meters; 2]>(),
+            TypeId::of::<(Meters, u32)>(),
+        ] {
+            let registration = registry.get(type_id).unwrap();
+            assert!(registration.data::<ReflectSerialize>().is_some());
+            assert!(registration.data::<ReflectDeserialize>().is_some());
+            assert!(registration.data::<ReflectDefault>().is_some());
+        }
+
+        let registration = registry
+            .get(TypeId::of::<Option<Unserializable>>())
+            .unwrap();
+        assert!(registration.data::<ReflectSerialize>().is_none());
+        assert!(registration.data::<ReflectDeserialize>().is_none());
+
+        // Type data added afterwards is picked up by propagating again
+        registry.register_type_data::<Unserializable, ReflectDefault>();
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Option<Unserializable>>())
+            .is_none());
+        registry.propagate_type_data(TypeId::of::<Option<Unserializable>>());
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Option<Unserializable>>())
+            .is_some());
+    }
+
+    #[test]
+    fn should_use_propagated_type_data() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Option<Meters>>();
+        registry.register::<[Meters; 2]>();
+
+        let value = Some(Meters(1.5));
+        let serializable = registry
+            .get_type_data::<ReflectSerialize>(TypeId::of::<Option<Meters>>())
+            .unwrap()
+            .get_serializable(&value);
+        let output = ron::to_string(serializable.borrow()).unwrap();
+        assert_eq!("Some((1.5))", output);
+        assert_eq!(
+            output,
+            ron::to_string(&TypedReflectSerializer::new(&value, &registry)).unwrap()
+        );
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let deserialized = registry
+            .get_type_data::<ReflectDeserialize>(TypeId::of::<Option<Meters>>())
+            .unwrap()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(&value), deserialized.downcast_ref::<Option<Meters>>());
+
+        let registration = registry.get(TypeId::of::<[Meters; 2]>()).unwrap();
+        let mut deserializer = ron::Deserializer::from_str("((1.0), (2.0))").unwrap();
+        let deserialized = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some([Meters(1.0), Meters(2.0)]),
+            <[Meters; 2]>::from_reflect(&*deserialized)
+        );
+
+        let default = registration.data::<ReflectDefault>().unwrap().default();
+        assert_eq!(
+            Some([Meters(0.0); 2]),
+            <[Meters; 2]>::from_reflect(&*default)
+        );
+    }
+}
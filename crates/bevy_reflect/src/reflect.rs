@@ -5,6 +5,7 @@ use crate::{
 };
 use std::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt::Debug,
 };
 
@@ -102,6 +103,26 @@ pub enum ReflectOwned {
 impl_reflect_enum!(ReflectOwned);
 
 /// A enumeration of all error outcomes that might happen when running [`try_apply`](Reflect::try_apply).
+///
+/// Errors raised while applying a field, element or entry of the value are wrapped in
+/// [`ApplyError::AtPath`], so matching on another variant only catches errors about the value itself.
+/// Use [`ApplyError::root_cause`] to match on the underlying error regardless of where it occurred:
+///
+/// ```
+/// # use bevy_reflect::{ApplyError, Reflect};
+/// #[derive(Reflect)]
+/// struct Health {
+///     current: u32,
+/// }
+///
+/// let mut health = Health { current: 10 };
+/// let mut patch = bevy_reflect::DynamicStruct::default();
+/// patch.insert("current", 5.0_f32);
+///
+/// let error = health.try_apply(&patch).unwrap_err();
+/// assert_eq!(Some(".current"), error.path());
+/// assert!(matches!(error.root_cause(), ApplyError::MismatchedTypes { .. }));
+/// ```
 #[derive(Error, Debug)]
 pub enum ApplyError {
     #[error("attempted to apply `{from_kind}` to `{to_kind}`")]
@@ -135,6 +156,107 @@ pub enum ApplyError {
         enum_name: Box<str>,
         variant_name: Box<str>,
     },
+
+    #[error("no field named `{field_name}` exists")]
+    /// The struct we tried to apply to was missing a field.
+    MissingField { field_name: Box<str> },
+
+    #[error("index {index} is out of bounds for a value of length {len}")]
+    /// Attempted to access an element or field past the end of a value.
+    IndexOutOfBounds { index: usize, len: usize },
+
+    #[error("map does not contain the key `{key}`")]
+    /// The map we tried to apply to was missing an entry.
+    ///
+    /// The `key` is the [debug](Reflect::debug) representation of the missing key.
+    MissingKey { key: Box<str> },
+
+    #[error("field `{field_name}` is read-only")]
+    /// Attempted to change the value of a [read-only] field.
+    ///
+    /// [read-only]: crate::NamedField::is_read_only
+    ReadOnlyField { field_name: Box<str> },
+
+    #[error("failed at `{path}`: {error}")]
+    /// Applying a nested value failed.
+    ///
+    /// The `path` is relative to the value being applied to, such as `.items[37].stats.hp`.
+    AtPath {
+        path: Box<str>,
+        error: Box<ApplyError>,
+    },
+}
+
+impl ApplyError {
+    /// Prepends a field access (e.g. `.name` or `.0`) to the path of this error.
+    pub fn with_field(self, name: impl std::fmt::Display) -> Self {
+        self.with_path_segment(format!(".{name}"))
+    }
+
+    /// Prepends an index access (e.g. `[37]`) to the path of this error.
+    pub fn with_index(self, index: usize) -> Self {
+        self.with_path_segment(format!("[{index}]"))
+    }
+
+    /// Prepends a map key access (e.g. `[\"key\"]`) to the path of this error,
+    /// using the key's [debug](Reflect::debug) representation.
+    pub fn with_key(self, key: &dyn Reflect) -> Self {
+        self.with_path_segment(format!("[{key:?}]"))
+    }
+
+    fn with_path_segment(self, segment: String) -> Self {
+        match self {
+            ApplyError::AtPath { path, error } => ApplyError::AtPath {
+                path: (segment + &path).into(),
+                error,
+            },
+            error => ApplyError::AtPath {
+                path: segment.into(),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// The path at which this error occurred, if it occurred in a nested value.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            ApplyError::AtPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without any path information.
+    pub fn root_cause(&self) -> &ApplyError {
+        match self {
+            ApplyError::AtPath { error, .. } => error.root_cause(),
+            error => error,
+        }
+    }
+}
+
+/// A enumeration of all error outcomes that might happen when running [`reflect_clone`](Reflect::reflect_clone).
+#[derive(Error, Debug)]
+pub enum ReflectCloneError {
+    #[error("`reflect_clone` is not implemented for `{type_path}`")]
+    /// The type does not support [`Reflect::reflect_clone`].
+    NotImplemented { type_path: Box<str> },
+
+    #[error("field `{field_name}` of `{container_type_path}` cannot be cloned")]
+    /// An ignored field could not be cloned.
+    ///
+    /// Ignored fields need either a `#[reflect(clone_with = "...")]`
+    /// or a `#[reflect(default)]` attribute in order to be cloned.
+    FieldNotCloneable {
+        container_type_path: Box<str>,
+        field_name: Box<str>,
+    },
+
+    #[error("expected a clone of type `{expected}` but received `{received}`")]
+    /// A clone was not of the expected type.
+    FailedDowncast {
+        expected: Box<str>,
+        received: Box<str>,
+    },
 }
 
 /// A zero-sized enumuration of the "kinds" of a reflected type.
@@ -311,6 +433,27 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
     /// use those subtraits' respective `clone_dynamic` methods.
     fn clone_value(&self) -> Box<dyn Reflect>;
 
+    /// Clones the value into a new instance of its concrete type.
+    ///
+    /// Unlike [`clone_value`], which generally returns a dynamic representation
+    /// of the value (such as a [`DynamicStruct`]), this returns an actual clone of
+    /// the underlying type, including any fields that are ignored by reflection.
+    ///
+    /// When deriving `Reflect`, the type's [`Clone`] implementation is used if it is
+    /// registered with `#[reflect(Clone)]`.
+    /// Otherwise, each field is cloned with `reflect_clone`, and ignored fields use their
+    /// `#[reflect(clone_with = "...")]` or `#[reflect(default)]` attributes.
+    ///
+    /// Dynamic types return a clone of themselves, just like [`clone_value`].
+    ///
+    /// [`clone_value`]: Reflect::clone_value
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Err(ReflectCloneError::NotImplemented {
+            type_path: self.reflect_type_path().into(),
+        })
+    }
+
     /// Returns a hash of the value (which includes the type).
     ///
     /// If the underlying type does not support hashing, returns `None`.
@@ -325,6 +468,14 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
         None
     }
 
+    /// Returns a "partial ordering" comparison result.
+    ///
+    /// If the underlying type does not support ordering,
+    /// or the two values can't be compared with each other, returns `None`.
+    fn reflect_cmp(&self, _value: &dyn Reflect) -> Option<Ordering> {
+        None
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
@@ -415,6 +566,16 @@ impl dyn Reflect {
         self.downcast::<T>().map(|value| *value)
     }
 
+    /// Clones the value with [`Reflect::reflect_clone`] and downcasts the clone to type `T`.
+    pub fn reflect_clone_and_take<T: Reflect + TypePath>(&self) -> Result<T, ReflectCloneError> {
+        self.reflect_clone()?
+            .take::<T>()
+            .map_err(|value| ReflectCloneError::FailedDowncast {
+                expected: T::type_path().into(),
+                received: (*value).reflect_type_path().into(),
+            })
+    }
+
     /// Returns `true` if the underlying value represents a value of type `T`, or `false`
     /// otherwise.
     ///
@@ -0,0 +1,233 @@
+use std::fmt::{Debug, Formatter};
+
+use bevy_ptr::{Ptr, PtrMut};
+
+use crate::{FromType, Reflect, ReflectFromPtr, TypeInfo, TypeRegistration, Typed};
+
+/// A type-erased, borrowed pointer to a [`Reflect`] value.
+///
+/// This pairs a [`Ptr`] with the [`TypeInfo`] of the value it points to,
+/// allowing it to be passed through layers that only deal in erased pointers
+/// (such as ECS storage or FFI boundaries) and turned back into a `&dyn Reflect` on demand.
+///
+/// The pointer is only checked once, when the `ReflectPtr` is created.
+/// After that, [`as_reflect`](Self::as_reflect) can be called safely any number of times.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectPtr};
+/// #[derive(Reflect)]
+/// struct Health(u32);
+///
+/// let health = Health(100);
+/// let ptr = ReflectPtr::new(&health);
+///
+/// assert!(ptr.type_info().is::<Health>());
+/// assert_eq!(100, ptr.as_reflect().downcast_ref::<Health>().unwrap().0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ReflectPtr<'a> {
+    ptr: Ptr<'a>,
+    type_info: &'static TypeInfo,
+    from_ptr: unsafe fn(Ptr) -> &dyn Reflect,
+}
+
+impl<'a> ReflectPtr<'a> {
+    /// Creates a new [`ReflectPtr`] from a reference to a typed value.
+    pub fn new<T: Reflect + Typed>(value: &'a T) -> Self {
+        Self {
+            ptr: Ptr::from(value),
+            type_info: T::type_info(),
+            from_ptr: <ReflectFromPtr as FromType<T>>::from_type().from_ptr(),
+        }
+    }
+
+    /// Creates a new [`ReflectPtr`] from an erased pointer and the registration of its type.
+    ///
+    /// Returns `None` if the registration does not contain [`ReflectFromPtr`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the type described by `registration`.
+    #[allow(unsafe_code)]
+    pub unsafe fn from_ptr(ptr: Ptr<'a>, registration: &TypeRegistration) -> Option<Self> {
+        let reflect_from_ptr = registration.data::<ReflectFromPtr>()?;
+        Some(Self {
+            ptr,
+            type_info: registration.type_info(),
+            from_ptr: reflect_from_ptr.from_ptr(),
+        })
+    }
+
+    /// Returns the [`TypeInfo`] of the pointed-to value.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+
+    /// Returns the underlying erased pointer.
+    pub fn as_ptr(&self) -> Ptr<'a> {
+        self.ptr
+    }
+
+    /// Returns the pointed-to value as a `&dyn Reflect`.
+    #[allow(unsafe_code)]
+    pub fn as_reflect(&self) -> &'a dyn Reflect {
+        // SAFETY: `from_ptr` was created for the type `ptr` points to,
+        // which is upheld by the constructors.
+        unsafe { (self.from_ptr)(self.ptr) }
+    }
+}
+
+impl<'a> Debug for ReflectPtr<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_reflect().debug(f)
+    }
+}
+
+/// A type-erased, mutably borrowed pointer to a [`Reflect`] value.
+///
+/// This is the mutable counterpart to [`ReflectPtr`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectPtrMut};
+/// #[derive(Reflect)]
+/// struct Health(u32);
+///
+/// let mut health = Health(100);
+/// let mut ptr = ReflectPtrMut::new(&mut health);
+/// ptr.as_reflect_mut().apply(&Health(50));
+///
+/// assert_eq!(50, health.0);
+/// ```
+pub struct ReflectPtrMut<'a> {
+    ptr: PtrMut<'a>,
+    type_info: &'static TypeInfo,
+    from_ptr: unsafe fn(Ptr) -> &dyn Reflect,
+    from_ptr_mut: unsafe fn(PtrMut) -> &mut dyn Reflect,
+}
+
+impl<'a> ReflectPtrMut<'a> {
+    /// Creates a new [`ReflectPtrMut`] from a mutable reference to a typed value.
+    pub fn new<T: Reflect + Typed>(value: &'a mut T) -> Self {
+        let reflect_from_ptr = <ReflectFromPtr as FromType<T>>::from_type();
+        Self {
+            ptr: PtrMut::from(value),
+            type_info: T::type_info(),
+            from_ptr: reflect_from_ptr.from_ptr(),
+            from_ptr_mut: reflect_from_ptr.from_ptr_mut(),
+        }
+    }
+
+    /// Creates a new [`ReflectPtrMut`] from an erased pointer and the registration of its type.
+    ///
+    /// Returns `None` if the registration does not contain [`ReflectFromPtr`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the type described by `registration`.
+    #[allow(unsafe_code)]
+    pub unsafe fn from_ptr(ptr: PtrMut<'a>, registration: &TypeRegistration) -> Option<Self> {
+        let reflect_from_ptr = registration.data::<ReflectFromPtr>()?;
+        Some(Self {
+            ptr,
+            type_info: registration.type_info(),
+            from_ptr: reflect_from_ptr.from_ptr(),
+            from_ptr_mut: reflect_from_ptr.from_ptr_mut(),
+        })
+    }
+
+    /// Returns the [`TypeInfo`] of the pointed-to value.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+
+    /// Returns the underlying erased pointer.
+    pub fn into_inner(self) -> PtrMut<'a> {
+        self.ptr
+    }
+
+    /// Returns a [`ReflectPtr`] to the same value, borrowing from this pointer.
+    pub fn as_ref(&self) -> ReflectPtr<'_> {
+        ReflectPtr {
+            ptr: self.ptr.as_ref(),
+            type_info: self.type_info,
+            from_ptr: self.from_ptr,
+        }
+    }
+
+    /// Returns a shorter-lived [`ReflectPtrMut`] to the same value.
+    pub fn reborrow(&mut self) -> ReflectPtrMut<'_> {
+        ReflectPtrMut {
+            ptr: self.ptr.reborrow(),
+            type_info: self.type_info,
+            from_ptr: self.from_ptr,
+            from_ptr_mut: self.from_ptr_mut,
+        }
+    }
+
+    /// Returns the pointed-to value as a `&dyn Reflect`.
+    pub fn as_reflect(&self) -> &dyn Reflect {
+        self.as_ref().as_reflect()
+    }
+
+    /// Returns the pointed-to value as a `&mut dyn Reflect`.
+    pub fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self.reborrow().into_reflect_mut()
+    }
+
+    /// Consumes the pointer, returning the pointed-to value as a `&mut dyn Reflect`.
+    #[allow(unsafe_code)]
+    pub fn into_reflect_mut(self) -> &'a mut dyn Reflect {
+        // SAFETY: `from_ptr_mut` was created for the type `ptr` points to,
+        // which is upheld by the constructors.
+        unsafe { (self.from_ptr_mut)(self.ptr) }
+    }
+}
+
+impl<'a> Debug for ReflectPtrMut<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_reflect().debug(f)
+    }
+}
+
+#[cfg(test)]
+#[allow(unsafe_code)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, GetTypeRegistration};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Foo {
+        a: f32,
+    }
+
+    #[test]
+    fn should_roundtrip_through_erased_pointer() {
+        let registration = Foo::get_type_registration();
+        let mut value = Foo { a: 1.0 };
+
+        {
+            // SAFETY: the pointer points to a `Foo`, which matches the registration
+            let mut ptr =
+                unsafe { ReflectPtrMut::from_ptr(PtrMut::from(&mut value), &registration) }
+                    .unwrap();
+            assert!(ptr.type_info().is::<Foo>());
+            ptr.as_reflect_mut().apply(&Foo { a: 2.0 });
+            assert_eq!(Some(&Foo { a: 2.0 }), ptr.as_reflect().downcast_ref());
+        }
+
+        let ptr = ReflectPtrMut::new(&mut value);
+        assert_eq!(
+            Some(&Foo { a: 2.0 }),
+            ptr.as_ref().as_reflect().downcast_ref()
+        );
+
+        let registration = crate::TypeRegistration::of::<Foo>();
+        // SAFETY: the pointer points to a `Foo`, which matches the registration
+        let ptr = unsafe { ReflectPtr::from_ptr(Ptr::from(&value), &registration) };
+        assert!(ptr.is_none());
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{TypeRegistration, TypeRegistry};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// How [`TypeRegistry::merge`] handles types that are registered in both registries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Keep the existing registration and discard the one from the other registry.
+    #[default]
+    Skip,
+    /// Replace the existing registration with the one from the other registry.
+    Overwrite,
+    /// Fail with a [`TypeRegistryMergeError`] without merging anything.
+    Error,
+}
+
+/// The error returned by [`TypeRegistry::merge`] with [`MergePolicy::Error`]
+/// when types are registered in both registries.
+#[derive(Error, Debug)]
+#[error("types are registered in both registries: {}", .type_paths.join(", "))]
+pub struct TypeRegistryMergeError {
+    /// The type paths of the conflicting types, sorted.
+    pub type_paths: Vec<&'static str>,
+}
+
+/// The differences between two [`TypeRegistry`] instances, as returned by [`TypeRegistry::diff`].
+///
+/// Types are matched by their [type path], and all lists are sorted.
+///
+/// [type path]: crate::TypePath::type_path
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeRegistryDiff {
+    /// The types registered only in the registry `diff` was called on.
+    pub only_in_self: Vec<&'static str>,
+    /// The types registered only in the other registry.
+    pub only_in_other: Vec<&'static str>,
+    /// The types registered in both registries, but whose [structure differs].
+    ///
+    /// [structure differs]: crate::TypeRegistration::type_hash
+    pub changed: Vec<&'static str>,
+}
+
+impl TypeRegistryDiff {
+    /// Returns `true` if both registries contain the same types, with the same structure.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_self.is_empty() && self.only_in_other.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl TypeRegistry {
+    /// Moves the registrations of `other` into this registry.
+    ///
+    /// Types registered in both registries are handled according to the given [`MergePolicy`].
+    /// With [`MergePolicy::Error`], nothing is merged if any type is registered in both.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{MergePolicy, TypeRegistry};
+    /// let mut registry = TypeRegistry::empty();
+    /// registry.register::<u32>();
+    ///
+    /// let mut plugin_registry = TypeRegistry::empty();
+    /// plugin_registry.register::<u32>();
+    /// plugin_registry.register::<String>();
+    ///
+    /// registry.merge(plugin_registry, MergePolicy::Skip).unwrap();
+    /// assert!(registry.get_with_type_path("alloc::string::String").is_some());
+    /// ```
+    pub fn merge(
+        &mut self,
+        other: TypeRegistry,
+        policy: MergePolicy,
+    ) -> Result<(), TypeRegistryMergeError> {
+        if policy == MergePolicy::Error {
+            let mut type_paths = other
+                .iter()
+                .filter(|registration| self.contains(registration.type_id()))
+                .map(|registration| registration.type_info().type_path())
+                .collect::<Vec<_>>();
+            if !type_paths.is_empty() {
+                type_paths.sort_unstable();
+                return Err(TypeRegistryMergeError { type_paths });
+            }
+        }
+
+        for registration in other.into_registrations() {
+            match policy {
+                MergePolicy::Skip | MergePolicy::Error => {
+                    self.add_registration(registration);
+                }
+                MergePolicy::Overwrite => self.overwrite_registration(registration),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares the types registered in this registry with those registered in `other`.
+    ///
+    /// Types are matched by their [type path], so that registries built by different binaries,
+    /// or with different versions of a type, can be compared.
+    /// Types with the same path are reported as changed if their [`type_hash`] differs.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    /// [`type_hash`]: crate::TypeRegistration::type_hash
+    pub fn diff(&self, other: &TypeRegistry) -> TypeRegistryDiff {
+        fn by_path(registry: &TypeRegistry) -> HashMap<&'static str, &TypeRegistration> {
+            registry
+                .iter()
+                .map(|registration| (registration.type_info().type_path(), registration))
+                .collect()
+        }
+
+        let ours = by_path(self);
+        let theirs = by_path(other);
+
+        let mut diff = TypeRegistryDiff::default();
+        for (type_path, registration) in &ours {
+            match theirs.get(type_path) {
+                None => diff.only_in_self.push(*type_path),
+                Some(other) if registration.type_hash() != other.type_hash() => {
+                    diff.changed.push(*type_path);
+                }
+                Some(_) => {}
+            }
+        }
+        diff.only_in_other.extend(
+            theirs
+                .keys()
+                .copied()
+                .filter(|type_path| !ours.contains_key(type_path)),
+        );
+
+        diff.only_in_self.sort_unstable();
+        diff.only_in_other.sort_unstable();
+        diff.changed.sort_unstable();
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{std_traits::ReflectDefault, Reflect, TypeRegistration};
+    use std::any::TypeId;
+
+    mod v1 {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Score(pub u32);
+    }
+
+    mod v2 {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Score(pub u64);
+    }
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Health(f32);
+
+    #[test]
+    fn should_merge_registries() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<u32>();
+
+        let mut other = TypeRegistry::empty();
+        other.register::<Health>();
+
+        registry.merge(other, MergePolicy::Error).unwrap();
+        assert!(registry.contains(TypeId::of::<Health>()));
+        assert!(registry.contains(TypeId::of::<f32>()));
+        assert!(registry.get_with_short_type_path("Health").is_some());
+    }
+
+    #[test]
+    fn should_apply_merge_policy() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Health>();
+
+        let new_other = || {
+            let mut other = TypeRegistry::empty();
+            other.add_registration(TypeRegistration::of::<Health>());
+            other.register::<bool>();
+            other
+        };
+
+        let error = registry.merge(new_other(), MergePolicy::Error).unwrap_err();
+        assert_eq!(
+            vec!["bevy_reflect::registry_merge::tests::Health"],
+            error.type_paths
+        );
+        assert!(!registry.contains(TypeId::of::<bool>()));
+
+        registry.merge(new_other(), MergePolicy::Skip).unwrap();
+        assert!(registry.contains(TypeId::of::<bool>()));
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Health>())
+            .is_some());
+
+        registry.merge(new_other(), MergePolicy::Overwrite).unwrap();
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Health>())
+            .is_none());
+        assert!(registry.get_with_short_type_path("Health").is_some());
+    }
+
+    #[test]
+    fn should_diff_registries() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<v1::Score>();
+        registry.register::<String>();
+
+        let mut other = TypeRegistry::empty();
+        other.register::<v2::Score>();
+        other.register::<String>();
+
+        let diff = registry.diff(&other);
+        assert_eq!(vec!["u32"], diff.only_in_self);
+        assert_eq!(vec!["u64"], diff.only_in_other);
+        assert_eq!(vec!["game::Score"], diff.changed);
+        assert!(!diff.is_empty());
+
+        assert!(registry.diff(&registry).is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{FromReflect, FromType, Reflect, TypeInfo, Typed};
+use std::any::{Any, TypeId};
+
+/// A trait for local "mirror" types that stand in for a type from another crate.
+///
+/// The orphan rule prevents implementing [`Reflect`] directly on a type defined in a crate
+/// we don't control.
+/// Instead, a local type with the same shape can be reflected in its place and converted
+/// to and from the foreign type whenever a concrete value is needed.
+///
+/// It's recommended to use the [`reflect_remote`] attribute macro rather than implementing
+/// this trait manually.
+/// The macro derives [`Reflect`] (and therefore [`FromReflect`], [`Typed`] and
+/// [`GetTypeRegistration`]) for the mirror type and generates the conversions between the
+/// mirror and the foreign type field by field.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{reflect_remote, DynamicStruct, ReflectRemote};
+/// mod external {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Rect {
+///         pub width: f32,
+///         pub height: f32,
+///     }
+/// }
+///
+/// #[reflect_remote(external::Rect)]
+/// struct RectDef {
+///     width: f32,
+///     height: f32,
+/// }
+///
+/// let mut patch = DynamicStruct::default();
+/// patch.insert("width", 3.0_f32);
+/// patch.insert("height", 4.0_f32);
+///
+/// let rect = RectDef::remote_from_reflect(&patch).unwrap();
+/// assert_eq!(external::Rect { width: 3.0, height: 4.0 }, rect);
+/// ```
+///
+/// [`reflect_remote`]: bevy_reflect_derive::reflect_remote
+/// [`Typed`]: crate::Typed
+/// [`GetTypeRegistration`]: crate::GetTypeRegistration
+pub trait ReflectRemote: Reflect + From<Self::Remote> + Into<Self::Remote> {
+    /// The foreign type this type mirrors.
+    type Remote: 'static;
+
+    /// Converts a value of the foreign type into its reflectable mirror.
+    fn from_remote(remote: Self::Remote) -> Self {
+        Self::from(remote)
+    }
+
+    /// Converts this mirror back into a value of the foreign type.
+    fn into_remote(self) -> Self::Remote {
+        self.into()
+    }
+
+    /// Constructs a value of the foreign type from reflected data shaped like this mirror.
+    fn remote_from_reflect(reflect: &dyn Reflect) -> Option<Self::Remote>
+    where
+        Self: FromReflect,
+    {
+        Self::from_reflect(reflect).map(Self::into_remote)
+    }
+
+    /// Returns the [`TypeInfo`] describing the reflected data of the foreign type,
+    /// which is the type info of this mirror.
+    fn remote_type_info() -> &'static TypeInfo
+    where
+        Self: Typed,
+    {
+        Self::type_info()
+    }
+}
+
+/// Type data that allows values of a remote type to be handled through its registered mirror.
+///
+/// This can be registered on a mirror type with `#[reflect(RemoteType)]`, letting code that only
+/// has a [`TypeRegistration`] for the mirror convert between reflected data and values of the
+/// foreign type.
+///
+/// [`TypeRegistration`]: crate::TypeRegistration
+#[derive(Clone)]
+pub struct ReflectRemoteType {
+    remote_type_id: TypeId,
+    remote_type_name: &'static str,
+    remote_type_info: fn() -> &'static TypeInfo,
+    from_reflect: fn(&dyn Reflect) -> Option<Box<dyn Any>>,
+    to_reflect: fn(Box<dyn Any>) -> Result<Box<dyn Reflect>, Box<dyn Any>>,
+}
+
+impl ReflectRemoteType {
+    /// The [`TypeId`] of the foreign type.
+    pub fn remote_type_id(&self) -> TypeId {
+        self.remote_type_id
+    }
+
+    /// The [type name] of the foreign type.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn remote_type_name(&self) -> &'static str {
+        self.remote_type_name
+    }
+
+    /// The [`TypeInfo`] describing the reflected data of the foreign type.
+    ///
+    /// See [`ReflectRemote::remote_type_info`].
+    pub fn remote_type_info(&self) -> &'static TypeInfo {
+        (self.remote_type_info)()
+    }
+
+    /// Constructs a boxed value of the foreign type from reflected data shaped like the mirror.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_reflect(&self, reflect: &dyn Reflect) -> Option<Box<dyn Any>> {
+        (self.from_reflect)(reflect)
+    }
+
+    /// Converts a boxed value of the foreign type into its mirror, returned as `Box<dyn Reflect>`.
+    ///
+    /// Returns the original box if it doesn't contain a value of the foreign type.
+    pub fn to_reflect(&self, remote: Box<dyn Any>) -> Result<Box<dyn Reflect>, Box<dyn Any>> {
+        (self.to_reflect)(remote)
+    }
+}
+
+impl<T: ReflectRemote + FromReflect + Typed> FromType<T> for ReflectRemoteType {
+    fn from_type() -> Self {
+        Self {
+            remote_type_id: TypeId::of::<T::Remote>(),
+            remote_type_name: std::any::type_name::<T::Remote>(),
+            remote_type_info: T::remote_type_info,
+            from_reflect: |reflect| {
+                T::remote_from_reflect(reflect).map(|remote| Box::new(remote) as Box<dyn Any>)
+            },
+            to_reflect: |remote| {
+                remote
+                    .downcast::<T::Remote>()
+                    .map(|remote| Box::new(T::from_remote(*remote)) as Box<dyn Reflect>)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{
+        reflect_remote, DynamicEnum, DynamicStruct, GetField, GetTypeRegistration, TypePath,
+        TypeRegistry,
+    };
+
+    mod external {
+        #[derive(Debug, PartialEq)]
+        pub struct Size {
+            pub width: u32,
+            pub height: u32,
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct Wrapper<T>(pub T, pub bool);
+
+        #[derive(Debug, PartialEq)]
+        pub enum Shape {
+            Empty,
+            Square(u32),
+            Rect { width: u32, height: u32 },
+        }
+    }
+
+    #[reflect_remote(external::Size)]
+    #[derive(Debug, PartialEq)]
+    #[reflect(RemoteType)]
+    struct SizeDef {
+        width: u32,
+        height: u32,
+    }
+
+    #[reflect_remote(external::Wrapper<T>)]
+    struct WrapperDef<T: FromReflect + TypePath + GetTypeRegistration>(T, bool);
+
+    #[reflect_remote(external::Shape)]
+    enum ShapeDef {
+        Empty,
+        Square(u32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[test]
+    fn should_convert_between_mirror_and_remote() {
+        let size = SizeDef::from_remote(external::Size {
+            width: 1,
+            height: 2,
+        });
+        assert_eq!(Some(&2), size.get_field::<u32>("height"));
+        assert_eq!(
+            external::Size {
+                width: 1,
+                height: 2
+            },
+            size.into_remote()
+        );
+
+        let wrapper = WrapperDef::from_remote(external::Wrapper(5_u8, true));
+        assert_eq!(external::Wrapper(5_u8, true), wrapper.into_remote());
+
+        let shape = ShapeDef::from_remote(external::Shape::Rect {
+            width: 2,
+            height: 3,
+        });
+        assert_eq!(
+            external::Shape::Rect {
+                width: 2,
+                height: 3
+            },
+            shape.into_remote()
+        );
+        assert_eq!(
+            "ShapeDef",
+            ShapeDef::remote_type_info().type_path_table().short_path()
+        );
+    }
+
+    #[test]
+    fn should_construct_remote_from_reflect() {
+        let mut patch = DynamicStruct::default();
+        patch.insert("width", 3_u32);
+        patch.insert("height", 4_u32);
+        assert_eq!(
+            Some(external::Size {
+                width: 3,
+                height: 4
+            }),
+            SizeDef::remote_from_reflect(&patch)
+        );
+
+        let shape = DynamicEnum::from_ref::<ShapeDef>(&ShapeDef::Square(7));
+        assert_eq!(
+            Some(external::Shape::Square(7)),
+            ShapeDef::remote_from_reflect(&shape)
+        );
+        assert_eq!(
+            external::Shape::Empty,
+            ShapeDef::from_remote(external::Shape::Empty).into_remote()
+        );
+    }
+
+    #[test]
+    fn should_register_remote_type_data() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<SizeDef>();
+
+        let remote = registry
+            .get_type_data::<ReflectRemoteType>(TypeId::of::<SizeDef>())
+            .unwrap();
+        assert_eq!(TypeId::of::<external::Size>(), remote.remote_type_id());
+        assert!(remote.remote_type_info().is::<SizeDef>());
+
+        let reflected = remote
+            .to_reflect(Box::new(external::Size {
+                width: 8,
+                height: 9,
+            }))
+            .unwrap();
+        let value = remote.from_reflect(reflected.as_ref()).unwrap();
+        assert_eq!(
+            external::Size {
+                width: 8,
+                height: 9
+            },
+            *value.downcast::<external::Size>().unwrap()
+        );
+        assert!(remote.to_reflect(Box::new(1_u32)).is_err());
+    }
+}
@@ -0,0 +1,434 @@
+use super::option_type_id;
+use crate::serde::SerializationData;
+use crate::{NamedField, TypeInfo, TypeRegistry, UnnamedField, VariantInfo};
+use serde_json::{json, Map, Value};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+/// The URI of the JSON Schema dialect generated by [`to_json_schema`].
+pub const JSON_SCHEMA_DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a [JSON Schema] (draft 2020-12) describing the given type
+/// as it is serialized to JSON by the [`ReflectSerializer`] and [`TypedReflectSerializer`].
+///
+/// The schema describes:
+/// * structs as objects, where fields without a `#[reflect(default)]` are required
+/// * tuples and tuple structs as fixed-length arrays
+/// * lists and arrays as arrays of their items
+/// * maps as objects whose values follow the value type
+/// * enums as externally tagged unions, with unit variants as plain strings
+/// * `Option<T>` as either `null` or the schema of `T`
+/// * primitives, strings, and paths as the matching JSON types, with bounds for integers
+///
+/// Fields marked with `#[reflect(skip_serializing)]` are left out.
+/// Every other type is described in the `$defs` of the schema, keyed by its type path, and referenced with `$ref`,
+/// which allows recursive types to be described.
+/// Types that are not registered in the registry, and value types that aren't known primitives,
+/// accept any value.
+///
+/// Types with a custom [`ReflectSerialize`] are described by their reflected structure,
+/// which matches their output as long as their `Serialize` implementation is derived.
+/// Field aliases are not accepted by the schema.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, Typed};
+/// # use bevy_reflect::schema::to_json_schema;
+/// # use serde_json::json;
+/// #[derive(Reflect)]
+/// #[type_path = "game"]
+/// struct Settings {
+///     volume: f32,
+///     #[reflect(default)]
+///     name: Option<String>,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Settings>();
+///
+/// let schema = to_json_schema(Settings::type_info(), &registry);
+/// assert_eq!(json!("object"), schema["type"]);
+/// assert_eq!(json!(["volume"]), schema["required"]);
+/// assert_eq!(json!("number"), schema["properties"]["volume"]["type"]);
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+pub fn to_json_schema(info: &TypeInfo, registry: &TypeRegistry) -> Value {
+    let mut builder = JsonSchemaBuilder {
+        registry,
+        defs: Map::new(),
+    };
+
+    let mut schema = Map::new();
+    schema.insert("$schema".into(), JSON_SCHEMA_DRAFT_2020_12.into());
+    if let Value::Object(definition) = builder.definition(info) {
+        schema.extend(definition);
+    }
+    if !builder.defs.is_empty() {
+        schema.insert("$defs".into(), Value::Object(builder.defs));
+    }
+    Value::Object(schema)
+}
+
+struct JsonSchemaBuilder<'a> {
+    registry: &'a TypeRegistry,
+    defs: Map<String, Value>,
+}
+
+impl JsonSchemaBuilder<'_> {
+    /// Returns the schema of a field, item, key, or value of the given type.
+    ///
+    /// Primitives and options are inlined, while other types are referenced
+    /// from the `$defs` of the schema, adding them first if needed.
+    fn reference(&mut self, type_id: TypeId) -> Value {
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+
+        let Some(info) = self.registry.get_type_info(type_id) else {
+            return json!({});
+        };
+
+        if let Some(some_type_id) = option_type_id(info) {
+            return self.option_schema(some_type_id);
+        }
+
+        let type_path = info.type_path();
+        if !self.defs.contains_key(type_path) {
+            // Insert a placeholder first so that recursive types terminate
+            self.defs.insert(type_path.into(), Value::Null);
+            let definition = self.definition(info);
+            self.defs.insert(type_path.into(), definition);
+        }
+
+        json!({ "$ref": definition_ref(type_path) })
+    }
+
+    /// Returns the full schema of the given type.
+    fn definition(&mut self, info: &TypeInfo) -> Value {
+        let registry = self.registry;
+        let serialization_data = registry.get_type_data::<SerializationData>(info.type_id());
+
+        let mut schema = match info {
+            TypeInfo::Struct(info) => self.struct_schema(info.iter(), serialization_data),
+            TypeInfo::TupleStruct(info) => self.tuple_schema(info.iter(), serialization_data),
+            TypeInfo::Tuple(info) => self.tuple_schema(info.iter(), None),
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.reference(info.item_type_id()),
+            }),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.reference(info.item_type_id()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity(),
+            }),
+            TypeInfo::Map(info) => json!({
+                "type": "object",
+                "additionalProperties": self.reference(info.value_type_id()),
+            }),
+            TypeInfo::Enum(enum_info) => match option_type_id(info) {
+                Some(some_type_id) => self.option_schema(some_type_id),
+                None => {
+                    let variants = enum_info
+                        .iter()
+                        .enumerate()
+                        .map(|(index, variant)| {
+                            let serialization_data =
+                                serialization_data.and_then(|data| data.variant(index));
+                            self.variant_schema(variant, serialization_data)
+                        })
+                        .collect::<Vec<_>>();
+                    json!({ "oneOf": variants })
+                }
+            },
+            TypeInfo::Value(info) => primitive_schema(info.type_id()).unwrap_or_else(|| json!({})),
+        };
+
+        if let Value::Object(schema) = &mut schema {
+            schema.insert("title".into(), info.type_path_table().short_path().into());
+
+            #[cfg(feature = "documentation")]
+            if let Some(docs) = info.docs() {
+                schema.insert("description".into(), docs.trim().into());
+            }
+        }
+
+        schema
+    }
+
+    fn option_schema(&mut self, some_type_id: TypeId) -> Value {
+        json!({
+            "anyOf": [
+                { "type": "null" },
+                self.reference(some_type_id),
+            ],
+        })
+    }
+
+    fn struct_schema<'a>(
+        &mut self,
+        fields: impl Iterator<Item = &'a NamedField>,
+        serialization_data: Option<&SerializationData>,
+    ) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for (index, field) in fields.enumerate() {
+            if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                continue;
+            }
+            if !serialization_data.is_some_and(|data| data.has_default(index)) {
+                required.push(Value::from(field.name()));
+            }
+            properties.insert(field.name().into(), self.reference(field.type_id()));
+        }
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    fn tuple_schema<'a>(
+        &mut self,
+        fields: impl Iterator<Item = &'a UnnamedField>,
+        serialization_data: Option<&SerializationData>,
+    ) -> Value {
+        let items = fields
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .map(|(_, field)| self.reference(field.type_id()))
+            .collect::<Vec<_>>();
+
+        json!({
+            "type": "array",
+            "minItems": items.len(),
+            "maxItems": items.len(),
+            "prefixItems": items,
+            "items": false,
+        })
+    }
+
+    fn variant_schema(
+        &mut self,
+        variant: &VariantInfo,
+        serialization_data: Option<&SerializationData>,
+    ) -> Value {
+        let name = variant.name();
+        let payload = match variant {
+            VariantInfo::Unit(_) => return json!({ "const": name }),
+            VariantInfo::Struct(info) => self.struct_schema(info.iter(), serialization_data),
+            // Tuple variants with a single field are serialized as newtype variants
+            VariantInfo::Tuple(info)
+                if info.field_len() == 1
+                    && !serialization_data.is_some_and(|data| data.is_field_skipped(0)) =>
+            {
+                self.reference(info.field_at(0).unwrap().type_id())
+            }
+            VariantInfo::Tuple(info) => self.tuple_schema(info.iter(), serialization_data),
+        };
+
+        json!({
+            "type": "object",
+            "properties": { name: payload },
+            "required": [name],
+            "additionalProperties": false,
+        })
+    }
+}
+
+/// Returns the schema of a primitive or string-like value type, if it is one.
+fn primitive_schema(type_id: TypeId) -> Option<Value> {
+    macro_rules! bounded_integers {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Some(json!({
+                        "type": "integer",
+                        "minimum": <$ty>::MIN,
+                        "maximum": <$ty>::MAX,
+                    }));
+                }
+            )*
+        };
+    }
+
+    bounded_integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    if type_id == TypeId::of::<u128>() {
+        Some(json!({ "type": "integer", "minimum": 0 }))
+    } else if type_id == TypeId::of::<i128>() {
+        Some(json!({ "type": "integer" }))
+    } else if type_id == TypeId::of::<f32>() || type_id == TypeId::of::<f64>() {
+        Some(json!({ "type": "number" }))
+    } else if type_id == TypeId::of::<bool>() {
+        Some(json!({ "type": "boolean" }))
+    } else if type_id == TypeId::of::<char>() {
+        Some(json!({ "type": "string", "minLength": 1, "maxLength": 1 }))
+    } else if type_id == TypeId::of::<String>()
+        || type_id == TypeId::of::<&'static str>()
+        || type_id == TypeId::of::<Cow<'static, str>>()
+        || type_id == TypeId::of::<PathBuf>()
+    {
+        Some(json!({ "type": "string" }))
+    } else {
+        None
+    }
+}
+
+/// Returns the `$ref` pointing to the definition of the type with the given path.
+///
+/// The path is escaped as a JSON Pointer token, then percent-encoded to form a valid URI fragment.
+fn definition_ref(type_path: &str) -> String {
+    let token = type_path.replace('~', "~0").replace('/', "~1");
+
+    let mut reference = String::from("#/$defs/");
+    for byte in token.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+            reference.push(char::from(byte));
+        } else {
+            reference.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypePath, Typed};
+    use std::collections::HashMap;
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    struct Settings {
+        volume: f32,
+        #[reflect(default)]
+        nickname: Option<String>,
+        #[reflect(skip_serializing)]
+        cache: u32,
+        difficulty: Difficulty,
+        bindings: HashMap<String, Vec<u8>>,
+        position: Position,
+    }
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    enum Difficulty {
+        Easy,
+        Custom(u8),
+        Ranged(u8, u8),
+        Scaled { factor: f32 },
+    }
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    struct Position(i32, i32);
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    #[reflect(no_field_bounds)]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    #[test]
+    fn should_generate_struct_schema() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Settings>();
+
+        let schema = to_json_schema(Settings::type_info(), &registry);
+        assert_eq!(json!(JSON_SCHEMA_DRAFT_2020_12), schema["$schema"]);
+        assert_eq!(json!("object"), schema["type"]);
+        assert_eq!(json!("Settings"), schema["title"]);
+        assert_eq!(
+            json!(["volume", "difficulty", "bindings", "position"]),
+            schema["required"]
+        );
+        assert_eq!(json!(false), schema["additionalProperties"]);
+
+        let properties = &schema["properties"];
+        assert_eq!(json!({ "type": "number" }), properties["volume"]);
+        assert_eq!(
+            json!({ "anyOf": [{ "type": "null" }, { "type": "string" }] }),
+            properties["nickname"]
+        );
+        assert!(properties.get("cache").is_none());
+        assert_eq!(
+            json!({ "$ref": "#/$defs/game::Difficulty" }),
+            properties["difficulty"]
+        );
+
+        let bindings = &schema["$defs"][<HashMap<String, Vec<u8>> as TypePath>::type_path()];
+        assert_eq!(json!("object"), bindings["type"]);
+        assert_eq!(
+            json!({ "$ref": "#/$defs/alloc::vec::Vec%3Cu8%3E" }),
+            bindings["additionalProperties"]
+        );
+        assert_eq!(
+            json!({ "type": "integer", "minimum": 0, "maximum": 255 }),
+            schema["$defs"]["alloc::vec::Vec<u8>"]["items"]
+        );
+
+        let position = &schema["$defs"]["game::Position"];
+        assert_eq!(json!("array"), position["type"]);
+        assert_eq!(json!(2), position["minItems"]);
+        assert_eq!(json!(false), position["items"]);
+        assert_eq!(json!("integer"), position["prefixItems"][1]["type"]);
+    }
+
+    #[test]
+    fn should_generate_tagged_enum_schema() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Difficulty>();
+
+        let schema = to_json_schema(Difficulty::type_info(), &registry);
+        let variants = &schema["oneOf"];
+        assert_eq!(json!({ "const": "Easy" }), variants[0]);
+        assert_eq!(
+            json!({ "type": "integer", "minimum": 0, "maximum": 255 }),
+            variants[1]["properties"]["Custom"]
+        );
+        assert_eq!(json!(["Custom"]), variants[1]["required"]);
+        assert_eq!(json!("array"), variants[2]["properties"]["Ranged"]["type"]);
+        assert_eq!(
+            json!(["factor"]),
+            variants[3]["properties"]["Scaled"]["required"]
+        );
+    }
+
+    #[test]
+    fn should_generate_recursive_schema() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Node>();
+
+        let schema = to_json_schema(Node::type_info(), &registry);
+        let children_ref = &schema["properties"]["children"]["$ref"];
+        let list = &schema["$defs"]["alloc::vec::Vec<game::Node>"];
+        assert_eq!(
+            json!("#/$defs/alloc::vec::Vec%3Cgame::Node%3E"),
+            *children_ref
+        );
+        assert_eq!(json!({ "$ref": "#/$defs/game::Node" }), list["items"]);
+        assert_eq!(json!("object"), schema["$defs"]["game::Node"]["type"]);
+    }
+
+    #[test]
+    fn unregistered_types_should_accept_anything() {
+        let registry = TypeRegistry::empty();
+
+        let schema = to_json_schema(Settings::type_info(), &registry);
+        assert_eq!(json!({}), schema["properties"]["difficulty"]);
+        assert_eq!(json!({ "type": "number" }), schema["properties"]["volume"]);
+    }
+}
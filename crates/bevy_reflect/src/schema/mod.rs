@@ -0,0 +1,525 @@
+//! Machine-readable schemas describing reflected types.
+//!
+//! A [`RegistrySchema`] describes every type in a [`TypeRegistry`] using its [`TypeInfo`]:
+//! the kind of each type, its fields and variants, its item, key, and value types,
+//! its generic arguments, its custom attributes, and (with the `documentation` feature) its docs.
+//!
+//! Both [`RegistrySchema`] and [`TypeSchema`] implement [`Serialize`],
+//! so they can be written out with any serde format.
+//! This allows external editors and non-Rust tools to generate UI for reflected types
+//! and validate data files against them.
+//!
+//! With the `json_schema` feature, `to_json_schema` can also generate a standard JSON Schema
+//! for validating the JSON produced by the reflection serializers.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, TypeRegistry};
+//! # use bevy_reflect::schema::RegistrySchema;
+//! #[derive(Reflect)]
+//! #[type_path = "game"]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//! }
+//!
+//! let mut registry = TypeRegistry::empty();
+//! registry.register::<Player>();
+//!
+//! let schema = serde_json::to_value(RegistrySchema::new(&registry)).unwrap();
+//! let player = &schema["game::Player"];
+//! assert_eq!("struct", player["kind"]);
+//! assert_eq!("health", player["fields"][1]["name"]);
+//! assert_eq!("u32", player["fields"][1]["type"]);
+//! ```
+
+#[cfg(feature = "json_schema")]
+mod json_schema;
+
+#[cfg(feature = "typescript")]
+mod typescript;
+
+#[cfg(feature = "json_schema")]
+pub use json_schema::*;
+#[cfg(feature = "typescript")]
+pub use typescript::*;
+
+use crate::attributes::CustomAttributes;
+use crate::generics::{GenericInfo, Generics};
+use crate::serde::TypedReflectSerializer;
+use crate::{NamedField, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+/// A serializable schema of every type in a [`TypeRegistry`].
+///
+/// This is serialized as a map from the [type path] of each registered type
+/// to its [`TypeSchema`], sorted by type path.
+///
+/// See the [module-level documentation] for more information.
+///
+/// [type path]: crate::TypePath::type_path
+/// [module-level documentation]: crate::schema
+pub struct RegistrySchema<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> RegistrySchema<'a> {
+    /// Creates a schema of the types in the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl Serialize for RegistrySchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut infos = self
+            .registry
+            .iter()
+            .map(TypeRegistration::type_info)
+            .collect::<Vec<_>>();
+        infos.sort_by_key(|info| info.type_path());
+
+        let mut map = serializer.serialize_map(Some(infos.len()))?;
+        for info in infos {
+            map.serialize_entry(info.type_path(), &TypeSchema::new(info, self.registry))?;
+        }
+        map.end()
+    }
+}
+
+/// A serializable schema of a single reflected type.
+///
+/// This is serialized as a map containing:
+/// * `kind`: one of `struct`, `tuple_struct`, `tuple`, `list`, `array`, `map`, `enum`, or `value`
+/// * `type_path` and `short_path`
+/// * `generics`: the name and type of each generic argument, along with the value of const arguments
+/// * `fields`: for structs, tuple structs, and tuples
+/// * `variants`: for enums, each with a `name`, a `kind` (`struct`, `tuple`, or `unit`), and `fields`
+/// * `item`, `key`, `value`, and `capacity`: for lists, arrays, and maps
+/// * `attributes`: the custom attributes of the type, keyed by their type path
+/// * `docs`: the docstring of the type, when the `documentation` feature is enabled
+///
+/// Custom attributes and const generic values are serialized with a [`TypedReflectSerializer`],
+/// so their types need to be registered if they rely on [`ReflectSerialize`].
+///
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+pub struct TypeSchema<'a> {
+    info: &'static TypeInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypeSchema<'a> {
+    /// Creates a schema of the type described by `info`.
+    ///
+    /// The registry is used to serialize custom attributes and const generic values.
+    pub fn new(info: &'static TypeInfo, registry: &'a TypeRegistry) -> Self {
+        Self { info, registry }
+    }
+}
+
+impl Serialize for TypeSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        let mut map = serializer.serialize_map(None)?;
+
+        let kind = match self.info {
+            TypeInfo::Struct(_) => "struct",
+            TypeInfo::TupleStruct(_) => "tuple_struct",
+            TypeInfo::Tuple(_) => "tuple",
+            TypeInfo::List(_) => "list",
+            TypeInfo::Array(_) => "array",
+            TypeInfo::Map(_) => "map",
+            TypeInfo::Enum(_) => "enum",
+            TypeInfo::Value(_) => "value",
+        };
+        map.serialize_entry("kind", kind)?;
+        map.serialize_entry("type_path", self.info.type_path())?;
+        map.serialize_entry("short_path", self.info.type_path_table().short_path())?;
+        map.serialize_entry(
+            "generics",
+            &GenericsSchema {
+                generics: self.info.generics(),
+                registry,
+            },
+        )?;
+
+        match self.info {
+            TypeInfo::Struct(info) => {
+                map.serialize_entry(
+                    "fields",
+                    &NamedFieldsSchema {
+                        fields: info.iter().collect(),
+                        registry,
+                    },
+                )?;
+            }
+            TypeInfo::TupleStruct(info) => {
+                map.serialize_entry(
+                    "fields",
+                    &UnnamedFieldsSchema {
+                        fields: info.iter().collect(),
+                        registry,
+                    },
+                )?;
+            }
+            TypeInfo::Tuple(info) => {
+                map.serialize_entry(
+                    "fields",
+                    &UnnamedFieldsSchema {
+                        fields: info.iter().collect(),
+                        registry,
+                    },
+                )?;
+            }
+            TypeInfo::List(info) => {
+                map.serialize_entry("item", info.item_type_path_table().path())?;
+            }
+            TypeInfo::Array(info) => {
+                map.serialize_entry("item", info.item_type_path_table().path())?;
+                map.serialize_entry("capacity", &info.capacity())?;
+            }
+            TypeInfo::Map(info) => {
+                map.serialize_entry("key", info.key_type_path_table().path())?;
+                map.serialize_entry("value", info.value_type_path_table().path())?;
+            }
+            TypeInfo::Enum(info) => {
+                let variants = info
+                    .iter()
+                    .map(|variant| VariantSchema { variant, registry })
+                    .collect::<Vec<_>>();
+                map.serialize_entry("variants", &variants)?;
+            }
+            TypeInfo::Value(_) => {}
+        }
+
+        if let Some(attributes) = self.info.custom_attributes() {
+            map.serialize_entry(
+                "attributes",
+                &AttributesSchema {
+                    attributes,
+                    registry,
+                },
+            )?;
+        }
+
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = self.info.docs() {
+            map.serialize_entry("docs", docs)?;
+        }
+
+        map.end()
+    }
+}
+
+struct VariantSchema<'a> {
+    variant: &'a VariantInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for VariantSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("name", self.variant.name())?;
+
+        match self.variant {
+            VariantInfo::Struct(info) => {
+                map.serialize_entry("kind", "struct")?;
+                map.serialize_entry(
+                    "fields",
+                    &NamedFieldsSchema {
+                        fields: info.iter().collect(),
+                        registry,
+                    },
+                )?;
+            }
+            VariantInfo::Tuple(info) => {
+                map.serialize_entry("kind", "tuple")?;
+                map.serialize_entry(
+                    "fields",
+                    &UnnamedFieldsSchema {
+                        fields: info.iter().collect(),
+                        registry,
+                    },
+                )?;
+            }
+            VariantInfo::Unit(_) => {
+                map.serialize_entry("kind", "unit")?;
+            }
+        }
+
+        map.serialize_entry("aliases", self.variant.aliases())?;
+        map.serialize_entry(
+            "attributes",
+            &AttributesSchema {
+                attributes: self.variant.custom_attributes(),
+                registry,
+            },
+        )?;
+
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = self.variant.docs() {
+            map.serialize_entry("docs", docs)?;
+        }
+
+        map.end()
+    }
+}
+
+struct NamedFieldsSchema<'a> {
+    fields: Vec<&'a NamedField>,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for NamedFieldsSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.fields.len()))?;
+        for field in &self.fields {
+            seq.serialize_element(&NamedFieldSchema {
+                field,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct NamedFieldSchema<'a> {
+    field: &'a NamedField,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for NamedFieldSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("name", self.field.name())?;
+        map.serialize_entry("type", self.field.type_path())?;
+        map.serialize_entry("read_only", &self.field.is_read_only())?;
+        map.serialize_entry("aliases", self.field.aliases())?;
+        map.serialize_entry(
+            "attributes",
+            &AttributesSchema {
+                attributes: self.field.custom_attributes(),
+                registry: self.registry,
+            },
+        )?;
+
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = self.field.docs() {
+            map.serialize_entry("docs", docs)?;
+        }
+
+        map.end()
+    }
+}
+
+struct UnnamedFieldsSchema<'a> {
+    fields: Vec<&'a UnnamedField>,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for UnnamedFieldsSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.fields.len()))?;
+        for field in &self.fields {
+            seq.serialize_element(&UnnamedFieldSchema {
+                field,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct UnnamedFieldSchema<'a> {
+    field: &'a UnnamedField,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for UnnamedFieldSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("index", &self.field.index())?;
+        map.serialize_entry("type", self.field.type_path())?;
+        map.serialize_entry("read_only", &self.field.is_read_only())?;
+        map.serialize_entry(
+            "attributes",
+            &AttributesSchema {
+                attributes: self.field.custom_attributes(),
+                registry: self.registry,
+            },
+        )?;
+
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = self.field.docs() {
+            map.serialize_entry("docs", docs)?;
+        }
+
+        map.end()
+    }
+}
+
+struct GenericsSchema<'a> {
+    generics: &'a Generics,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for GenericsSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.generics.len()))?;
+        for generic in self.generics.iter() {
+            seq.serialize_element(&GenericSchema {
+                generic,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct GenericSchema<'a> {
+    generic: &'a GenericInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for GenericSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("name", self.generic.name())?;
+        map.serialize_entry("type", self.generic.type_path_table().path())?;
+        if let GenericInfo::Const(info) = self.generic {
+            map.serialize_entry(
+                "value",
+                &TypedReflectSerializer::new(info.reflect_value(), self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+struct AttributesSchema<'a> {
+    attributes: &'a CustomAttributes,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for AttributesSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut attributes = self
+            .attributes
+            .iter()
+            .map(|(_, value)| (value.reflect_type_path(), value))
+            .collect::<Vec<_>>();
+        attributes.sort_by_key(|(type_path, _)| *type_path);
+
+        let mut map = serializer.serialize_map(Some(attributes.len()))?;
+        for (type_path, value) in attributes {
+            map.serialize_entry(
+                type_path,
+                &TypedReflectSerializer::new(value, self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Returns the [`TypeId`] of `T` if the given type is an `Option<T>`.
+///
+/// Options are serialized as either nothing or their inner value,
+/// so generated schemas describe them as nullable rather than as enums.
+#[cfg(any(feature = "json_schema", feature = "typescript"))]
+fn option_type_id(info: &TypeInfo) -> Option<std::any::TypeId> {
+    let TypeInfo::Enum(info) = info else {
+        return None;
+    };
+
+    let type_path = info.type_path_table();
+    if type_path.module_path() != Some("core::option") || type_path.ident() != Some("Option") {
+        return None;
+    }
+
+    match info.variant("Some")? {
+        VariantInfo::Tuple(variant) => variant.field_at(0).map(UnnamedField::type_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::Reflect;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    struct Player {
+        #[reflect(@0..=100_u32)]
+        health: u32,
+        #[reflect(read_only)]
+        id: u64,
+    }
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    enum Action {
+        Idle,
+        Move(f32, f32),
+        Attack { target: u32 },
+    }
+
+    #[test]
+    fn should_serialize_registry_schema() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Player>();
+        registry.register::<Action>();
+
+        let schema = serde_json::to_value(RegistrySchema::new(&registry)).unwrap();
+
+        let player = &schema["game::Player"];
+        assert_eq!(json!("struct"), player["kind"]);
+        assert_eq!(json!("Player"), player["short_path"]);
+        assert_eq!(json!("health"), player["fields"][0]["name"]);
+        assert_eq!(json!("u32"), player["fields"][0]["type"]);
+        assert_eq!(json!(false), player["fields"][0]["read_only"]);
+        assert_eq!(
+            json!({ "start": 0, "end": 100 }),
+            player["fields"][0]["attributes"]["core::ops::RangeInclusive<u32>"]
+        );
+        assert_eq!(json!(true), player["fields"][1]["read_only"]);
+
+        let action = &schema["game::Action"];
+        assert_eq!(json!("enum"), action["kind"]);
+        assert_eq!(json!("unit"), action["variants"][0]["kind"]);
+        assert_eq!(json!("Move"), action["variants"][1]["name"]);
+        assert_eq!(json!("f32"), action["variants"][1]["fields"][1]["type"]);
+        assert_eq!(json!("target"), action["variants"][2]["fields"][0]["name"]);
+
+        // Registering `Player` and `Action` also registers their field types
+        assert_eq!(json!("value"), schema["u32"]["kind"]);
+    }
+
+    #[test]
+    fn should_serialize_container_schemas() {
+        // Const generic values are serialized using the registered primitive types
+        let registry = TypeRegistry::new();
+
+        let schema = |info| serde_json::to_value(TypeSchema::new(info, &registry)).unwrap();
+
+        let list = schema(<Vec<u8> as crate::Typed>::type_info());
+        assert_eq!(json!("list"), list["kind"]);
+        assert_eq!(json!("u8"), list["item"]);
+
+        let array = schema(<[u16; 3] as crate::Typed>::type_info());
+        assert_eq!(json!("array"), array["kind"]);
+        assert_eq!(json!(3), array["capacity"]);
+        assert_eq!(json!(3), array["generics"][1]["value"]);
+
+        let map = schema(<HashMap<String, f32> as crate::Typed>::type_info());
+        assert_eq!(json!("map"), map["kind"]);
+        assert_eq!(json!("alloc::string::String"), map["key"]);
+        assert_eq!(json!("f32"), map["value"]);
+    }
+}
@@ -0,0 +1,481 @@
+use super::option_type_id;
+use crate::serde::SerializationData;
+use crate::{NamedField, TypeInfo, TypeRegistry, Typed, UnnamedField, VariantInfo};
+use bevy_utils::{HashMap, HashSet};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Generates TypeScript declarations (such as for a `.d.ts` file) for reflected types.
+///
+/// The declarations describe the types as they are serialized to JSON by the
+/// [`ReflectSerializer`] and [`TypedReflectSerializer`]:
+/// * structs become interfaces, where fields with a `#[reflect(default)]` are optional
+/// * tuple structs become tuple types
+/// * enums become unions, with unit variants as string literals
+///   and other variants as objects keyed by the variant name
+///
+/// Other types are written inline: lists and arrays as `Array<T>`, maps as `Record<string, V>`,
+/// tuples as tuple types, `Option<T>` as `T | null`, and primitives as `number`, `string`, or `boolean`.
+///
+/// Structs, tuple structs, and enums used by the selected types are declared as well,
+/// as long as they are registered in the registry.
+/// Types that are unregistered, or are values other than primitives and strings, become `unknown`.
+/// Declarations are named after the [short type path] of their type,
+/// or after its full type path if several declared types share the same short type path.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::schema::TypeScriptGenerator;
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     team: Team,
+/// }
+///
+/// #[derive(Reflect)]
+/// enum Team {
+///     Red,
+///     Blue,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Player>();
+///
+/// let declarations = TypeScriptGenerator::new(&registry)
+///     .with_type::<Player>()
+///     .generate();
+///
+/// assert_eq!(
+///     declarations,
+///     r#"export interface Player {
+///     name: string;
+///     team: Team;
+/// }
+///
+/// export type Team =
+///     | "Red"
+///     | "Blue";
+/// "#
+/// );
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [short type path]: crate::TypePath::short_type_path
+pub struct TypeScriptGenerator<'a> {
+    registry: &'a TypeRegistry,
+    types: Vec<&'static TypeInfo>,
+}
+
+impl<'a> TypeScriptGenerator<'a> {
+    /// Creates a generator for types registered in the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            types: Vec::new(),
+        }
+    }
+
+    /// Selects the type `T` to be declared.
+    pub fn with_type<T: Typed>(self) -> Self {
+        self.with_type_info(T::type_info())
+    }
+
+    /// Selects the type described by the given [`TypeInfo`] to be declared.
+    pub fn with_type_info(mut self, info: &'static TypeInfo) -> Self {
+        self.types.push(info);
+        self
+    }
+
+    /// Generates the declarations of the selected types, and of the types they depend on.
+    pub fn generate(&self) -> String {
+        let declarations = self.collect_declarations();
+        let names = declaration_names(&declarations);
+
+        let mut output = String::new();
+        for (index, info) in declarations.iter().enumerate() {
+            if index > 0 {
+                output.push('\n');
+            }
+            self.write_declaration(&mut output, info, &names);
+        }
+        output
+    }
+
+    /// Finds the structs, tuple structs, and enums to declare, in the order they are first encountered.
+    fn collect_declarations(&self) -> Vec<&'static TypeInfo> {
+        let mut declarations = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = self.types.iter().copied().collect::<VecDeque<_>>();
+
+        while let Some(info) = queue.pop_front() {
+            if !visited.insert(info.type_id()) {
+                continue;
+            }
+
+            if is_declared(info) {
+                declarations.push(info);
+            }
+
+            queue.extend(
+                referenced_type_ids(info)
+                    .into_iter()
+                    .filter_map(|type_id| self.registry.get_type_info(type_id)),
+            );
+        }
+
+        declarations
+    }
+
+    fn write_declaration(
+        &self,
+        output: &mut String,
+        info: &TypeInfo,
+        names: &HashMap<TypeId, String>,
+    ) {
+        let name = &names[&info.type_id()];
+        let serialization_data = self
+            .registry
+            .get_type_data::<SerializationData>(info.type_id());
+
+        #[cfg(feature = "documentation")]
+        write_docs(output, info.docs(), "");
+
+        match info {
+            TypeInfo::Struct(info) => {
+                let _ = writeln!(output, "export interface {name} {{");
+                self.write_fields(output, info.iter(), serialization_data, names, "    ");
+                output.push_str("}\n");
+            }
+            TypeInfo::TupleStruct(info) => {
+                let fields = self.tuple_type(info.iter(), serialization_data, names);
+                let _ = writeln!(output, "export type {name} = {fields};");
+            }
+            TypeInfo::Enum(info) => {
+                let _ = write!(output, "export type {name} =");
+                if info.variant_len() == 0 {
+                    output.push_str(" never");
+                }
+                for (index, variant) in info.iter().enumerate() {
+                    let serialization_data =
+                        serialization_data.and_then(|data| data.variant(index));
+                    let variant = self.variant_type(variant, serialization_data, names);
+                    let _ = write!(output, "\n    | {variant}");
+                }
+                output.push_str(";\n");
+            }
+            _ => {}
+        }
+    }
+
+    fn write_fields<'f>(
+        &self,
+        output: &mut String,
+        fields: impl Iterator<Item = &'f NamedField>,
+        serialization_data: Option<&SerializationData>,
+        names: &HashMap<TypeId, String>,
+        indent: &str,
+    ) {
+        for (index, field) in fields.enumerate() {
+            if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                continue;
+            }
+
+            #[cfg(feature = "documentation")]
+            write_docs(output, field.docs(), indent);
+
+            let optional = if serialization_data.is_some_and(|data| data.has_default(index)) {
+                "?"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                output,
+                "{indent}{}{optional}: {};",
+                field.name(),
+                self.type_expr(field.type_id(), names)
+            );
+        }
+    }
+
+    fn tuple_type<'f>(
+        &self,
+        fields: impl Iterator<Item = &'f UnnamedField>,
+        serialization_data: Option<&SerializationData>,
+        names: &HashMap<TypeId, String>,
+    ) -> String {
+        let fields = fields
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .map(|(_, field)| self.type_expr(field.type_id(), names))
+            .collect::<Vec<_>>();
+        format!("[{}]", fields.join(", "))
+    }
+
+    fn variant_type(
+        &self,
+        variant: &VariantInfo,
+        serialization_data: Option<&SerializationData>,
+        names: &HashMap<TypeId, String>,
+    ) -> String {
+        let name = variant.name();
+        let payload = match variant {
+            VariantInfo::Unit(_) => return format!("\"{name}\""),
+            VariantInfo::Struct(info) => {
+                let mut fields = String::new();
+                self.write_fields(&mut fields, info.iter(), serialization_data, names, "");
+                format!("{{ {} }}", fields.trim_end().replace('\n', " "))
+            }
+            // Tuple variants with a single field are serialized as newtype variants
+            VariantInfo::Tuple(info)
+                if info.field_len() == 1
+                    && !serialization_data.is_some_and(|data| data.is_field_skipped(0)) =>
+            {
+                self.type_expr(info.field_at(0).unwrap().type_id(), names)
+            }
+            VariantInfo::Tuple(info) => self.tuple_type(info.iter(), serialization_data, names),
+        };
+        format!("{{ {name}: {payload} }}")
+    }
+
+    /// Returns the TypeScript type used to refer to the type with the given [`TypeId`].
+    fn type_expr(&self, type_id: TypeId, names: &HashMap<TypeId, String>) -> String {
+        if let Some(primitive) = primitive_type(type_id) {
+            return primitive.to_string();
+        }
+
+        if let Some(name) = names.get(&type_id) {
+            return name.clone();
+        }
+
+        let Some(info) = self.registry.get_type_info(type_id) else {
+            return "unknown".to_string();
+        };
+
+        if let Some(some_type_id) = option_type_id(info) {
+            return format!("{} | null", self.type_expr(some_type_id, names));
+        }
+
+        match info {
+            TypeInfo::List(info) => {
+                format!("Array<{}>", self.type_expr(info.item_type_id(), names))
+            }
+            TypeInfo::Array(info) => {
+                format!("Array<{}>", self.type_expr(info.item_type_id(), names))
+            }
+            TypeInfo::Map(info) => format!(
+                "Record<string, {}>",
+                self.type_expr(info.value_type_id(), names)
+            ),
+            TypeInfo::Tuple(info) => self.tuple_type(info.iter(), None, names),
+            _ => "unknown".to_string(),
+        }
+    }
+}
+
+/// Returns `true` if the type gets its own declaration, rather than being written inline.
+fn is_declared(info: &TypeInfo) -> bool {
+    match info {
+        TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) => true,
+        TypeInfo::Enum(_) => option_type_id(info).is_none(),
+        _ => false,
+    }
+}
+
+/// Returns the types of the fields, items, keys, and values of the given type.
+fn referenced_type_ids(info: &TypeInfo) -> Vec<TypeId> {
+    match info {
+        TypeInfo::Struct(info) => info.iter().map(NamedField::type_id).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::List(info) => vec![info.item_type_id()],
+        TypeInfo::Array(info) => vec![info.item_type_id()],
+        TypeInfo::Map(info) => vec![info.key_type_id(), info.value_type_id()],
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(variant) => variant.iter().map(NamedField::type_id).collect(),
+                VariantInfo::Tuple(variant) => variant.iter().map(UnnamedField::type_id).collect(),
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Value(_) => Vec::new(),
+    }
+}
+
+/// Assigns a TypeScript identifier to each declared type.
+fn declaration_names(declarations: &[&'static TypeInfo]) -> HashMap<TypeId, String> {
+    let mut short_path_counts = HashMap::<&str, usize>::new();
+    for info in declarations {
+        *short_path_counts
+            .entry(info.type_path_table().short_path())
+            .or_default() += 1;
+    }
+
+    declarations
+        .iter()
+        .map(|info| {
+            let short_path = info.type_path_table().short_path();
+            let path = if short_path_counts[short_path] > 1 {
+                info.type_path()
+            } else {
+                short_path
+            };
+            (info.type_id(), to_identifier(path))
+        })
+        .collect()
+}
+
+/// Converts a type path into a valid TypeScript identifier,
+/// replacing every sequence of other characters with an underscore.
+fn to_identifier(path: &str) -> String {
+    let mut identifier = String::with_capacity(path.len());
+    for c in path.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            identifier.push(c);
+        } else if !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+    identifier.trim_end_matches('_').to_string()
+}
+
+/// Returns the TypeScript type of a primitive or string-like value type, if it is one.
+fn primitive_type(type_id: TypeId) -> Option<&'static str> {
+    macro_rules! is_any {
+        ($($ty:ty),*) => {
+            [$(TypeId::of::<$ty>()),*].contains(&type_id)
+        };
+    }
+
+    if is_any!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64) {
+        Some("number")
+    } else if is_any!(bool) {
+        Some("boolean")
+    } else if is_any!(char, String, &'static str, Cow<'static, str>, PathBuf) {
+        Some("string")
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "documentation")]
+fn write_docs(output: &mut String, docs: Option<&str>, indent: &str) {
+    let Some(docs) = docs else {
+        return;
+    };
+
+    let _ = writeln!(output, "{indent}/**");
+    for line in docs.lines() {
+        let _ = writeln!(output, "{indent} *{line}");
+    }
+    let _ = writeln!(output, "{indent} */");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::Reflect;
+
+    #[derive(Reflect)]
+    struct Level {
+        name: String,
+        #[reflect(default)]
+        music: Option<String>,
+        #[reflect(skip_serializing)]
+        loaded: bool,
+        spawns: Vec<(f32, f32)>,
+        layers: HashMap<String, Layer>,
+        boss: Option<Enemy>,
+    }
+
+    #[derive(Reflect)]
+    struct Layer(u32, bool);
+
+    #[derive(Reflect)]
+    enum Enemy {
+        Slime,
+        Goblin(u8),
+        Dragon { wings: u8, fire: bool },
+        Swarm(u8, u8),
+    }
+
+    mod other {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        pub struct Layer;
+    }
+
+    #[derive(Reflect)]
+    struct Layers {
+        first: Layer,
+        second: other::Layer,
+    }
+
+    #[test]
+    fn should_generate_declarations() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Level>();
+
+        let output = TypeScriptGenerator::new(&registry)
+            .with_type::<Level>()
+            .generate();
+
+        let expected = r#"export interface Level {
+    name: string;
+    music?: string | null;
+    spawns: Array<[number, number]>;
+    layers: Record<string, Layer>;
+    boss: Enemy | null;
+}
+
+export type Layer = [number, boolean];
+
+export type Enemy =
+    | "Slime"
+    | { Goblin: number }
+    | { Dragon: { wings: number; fire: boolean; } }
+    | { Swarm: [number, number] };
+"#;
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_disambiguate_names() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Layers>();
+
+        let output = TypeScriptGenerator::new(&registry)
+            .with_type::<Layers>()
+            .generate();
+
+        assert!(output.contains("first: bevy_reflect_schema_typescript_tests_Layer;"));
+        assert!(output.contains("second: bevy_reflect_schema_typescript_tests_other_Layer;"));
+        assert!(output
+            .contains("export interface bevy_reflect_schema_typescript_tests_other_Layer {\n}\n"));
+    }
+
+    #[test]
+    fn unregistered_types_should_be_unknown() {
+        let registry = TypeRegistry::empty();
+
+        let output = TypeScriptGenerator::new(&registry)
+            .with_type::<Layers>()
+            .generate();
+
+        assert_eq!(
+            "export interface Layers {\n    first: unknown;\n    second: unknown;\n}\n",
+            output
+        );
+    }
+}
@@ -0,0 +1,73 @@
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes the given bytes as a padded base64 string, using the standard alphabet.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    let mut base64 = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0_u32, |group, (i, &byte)| {
+            group | (u32::from(byte) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                base64.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                base64.push('=');
+            }
+        }
+    }
+    base64
+}
+
+/// Decodes a padded base64 string using the standard alphabet,
+/// or returns `None` if the string is not valid base64.
+pub(crate) fn decode_base64(base64: &str) -> Option<Vec<u8>> {
+    if base64.len() % 4 != 0 {
+        return None;
+    }
+
+    let chunks = base64.as_bytes().chunks(4);
+    let last = chunks.len().saturating_sub(1);
+    let mut bytes = Vec::with_capacity(base64.len() / 4 * 3);
+    for (index, chunk) in chunks.enumerate() {
+        // Padding is only allowed at the end of the string
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && index != last) {
+            return None;
+        }
+
+        let mut group = 0_u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let value = BASE64_ALPHABET.as_slice().iter().position(|&a| a == c)?;
+            group |= (value as u32) << (18 - 6 * i);
+        }
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_base64, encode_base64};
+
+    #[test]
+    fn should_roundtrip_base64() {
+        let cases: [(&[u8], &str); 6] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foobar", "Zm9vYmFy"),
+            (&[0xff, 0xfe, 0x00], "//4A"),
+        ];
+        for (bytes, base64) in cases {
+            assert_eq!(base64, encode_base64(bytes));
+            assert_eq!(Some(bytes.to_vec()), decode_base64(base64));
+        }
+        assert_eq!(None, decode_base64("Zg="));
+        assert_eq!(None, decode_base64("Z==="));
+        assert_eq!(None, decode_base64("Zg==Zg=="));
+        assert_eq!(None, decode_base64("Zm9*"));
+    }
+}
@@ -0,0 +1,975 @@
+use crate::serde::de::get_registration;
+use crate::serde::ser::get_serializable;
+use crate::serde::SerializationData;
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, EnumInfo, Map, Reflect, ReflectDeserialize, ReflectRef, TypeInfo,
+    TypeRegistration, TypeRegistry, VariantInfo,
+};
+use serde::de::{
+    DeserializeSeed, EnumAccess, Error as _, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq, SerializeTuple};
+use serde::{Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::{self, Formatter};
+
+/// A serializer for reflected values, designed for compact, non-self-describing formats
+/// such as [bincode] or [postcard].
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a tuple of two elements:
+/// the [type hash] of the reflected type, as a `u64`, and the value,
+/// serialized with [`CompactTypedReflectSerializer`].
+///
+/// Compared to [`ReflectSerializer`], the type is identified by its hash instead of its type path,
+/// and field names are never written: fields are written in the order given by the [`TypeInfo`]
+/// of their type, even for dynamic values that list them in a different order.
+/// Both ends must agree on the layout of the serialized types,
+/// which the type hash checks for the root value.
+///
+/// Self-describing formats can deserialize this output as well,
+/// but should generally prefer [`ReflectSerializer`], which is readable and more forgiving.
+///
+/// # Example
+///
+/// ```
+/// # use bincode::Options;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectDeserializer, CompactReflectSerializer}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Transform {
+///   translation: (f32, f32),
+///   layer: u8,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Transform>();
+///
+/// let input = Transform { translation: (1.0, 2.0), layer: 3 };
+///
+/// let bytes = bincode::serialize(&CompactReflectSerializer::new(&input, &registry)).unwrap();
+/// // The type hash, followed by two `f32` and a `u8`
+/// assert_eq!(bytes.len(), 8 + 4 + 4 + 1);
+///
+/// let output = bincode::DefaultOptions::new()
+///   .with_fixint_encoding()
+///   .deserialize_seed(CompactReflectDeserializer::new(&registry), &bytes)
+///   .unwrap();
+/// assert_eq!(input, Transform::from_reflect(&*output).unwrap());
+/// ```
+///
+/// [bincode]: https://docs.rs/bincode
+/// [postcard]: https://docs.rs/postcard
+/// [type hash]: crate::TypeRegistration::type_hash
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct CompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let info = represented_type_info::<S::Error>(self.value)?;
+        let registration = self.registry.get(info.type_id()).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "type `{}` is not registered in the type registry",
+                info.type_path()
+            ))
+        })?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&registration.type_hash())?;
+        state.serialize_element(&CompactTypedReflectSerializer::new(
+            self.value,
+            self.registry,
+        ))?;
+        state.end()
+    }
+}
+
+/// A serializer for reflected values whose type will be known during deserialization,
+/// designed for compact, non-self-describing formats.
+///
+/// This is the serializer counterpart to [`CompactTypedReflectDeserializer`].
+///
+/// # Output
+///
+/// Types with [`ReflectSerialize`] type data are serialized with their own implementation.
+/// Otherwise, structs, tuple structs, tuples, and arrays are written as tuples
+/// of their non-skipped fields, in the order given by their [`TypeInfo`], without field names.
+/// Enum variants are written as their index followed by a tuple of their fields,
+/// `Option`s as options, lists as sequences, and maps as maps.
+///
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+pub struct CompactTypedReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactTypedReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+}
+
+impl<'a> Serialize for CompactTypedReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Handle both Value case and types that have a custom `Serialize`
+        let serializable = get_serializable::<S::Error>(self.value, self.registry);
+        if let Ok(serializable) = serializable {
+            return serializable.borrow().serialize(serializer);
+        }
+
+        let info = represented_type_info::<S::Error>(self.value)?;
+        let serialization_data = self
+            .registry
+            .get_type_data::<SerializationData>(info.type_id());
+
+        match (info, self.value.reflect_ref()) {
+            (TypeInfo::Struct(info), ReflectRef::Struct(value)) => FieldsSerializer::new(
+                info.type_path(),
+                info.iter().map(|field| value.field(field.name())),
+                serialization_data,
+                self.registry,
+            )
+            .serialize(serializer),
+            (TypeInfo::TupleStruct(info), ReflectRef::TupleStruct(value)) => FieldsSerializer::new(
+                info.type_path(),
+                (0..info.field_len()).map(|index| value.field(index)),
+                serialization_data,
+                self.registry,
+            )
+            .serialize(serializer),
+            (TypeInfo::Tuple(info), ReflectRef::Tuple(value)) => FieldsSerializer::new(
+                info.type_path(),
+                (0..info.field_len()).map(|index| value.field(index)),
+                None,
+                self.registry,
+            )
+            .serialize(serializer),
+            (TypeInfo::Array(info), ReflectRef::Array(value)) => {
+                if value.len() != info.capacity() {
+                    return Err(S::Error::custom(format_args!(
+                        "expected {} elements for `{}` but found {}",
+                        info.capacity(),
+                        info.type_path(),
+                        value.len()
+                    )));
+                }
+                FieldsSerializer::new(
+                    info.type_path(),
+                    value.iter().map(Some),
+                    None,
+                    self.registry,
+                )
+                .serialize(serializer)
+            }
+            (TypeInfo::List(_), ReflectRef::List(value)) => {
+                let mut state = serializer.serialize_seq(Some(value.len()))?;
+                for item in value.iter() {
+                    state.serialize_element(&CompactTypedReflectSerializer::new(
+                        item,
+                        self.registry,
+                    ))?;
+                }
+                state.end()
+            }
+            (TypeInfo::Map(_), ReflectRef::Map(value)) => {
+                let mut state = serializer.serialize_map(Some(value.len()))?;
+                for (key, value) in value.iter() {
+                    state.serialize_entry(
+                        &CompactTypedReflectSerializer::new(key, self.registry),
+                        &CompactTypedReflectSerializer::new(value, self.registry),
+                    )?;
+                }
+                state.end()
+            }
+            (TypeInfo::Enum(info), ReflectRef::Enum(value)) => {
+                let variant_index = info.index_of(value.variant_name()).ok_or_else(|| {
+                    S::Error::custom(format_args!(
+                        "unknown variant `{}` for enum `{}`",
+                        value.variant_name(),
+                        info.type_path()
+                    ))
+                })?;
+                let variant_info = info.variant_at(variant_index).unwrap();
+                let serialization_data =
+                    serialization_data.and_then(|data| data.variant(variant_index));
+
+                if is_option(info) {
+                    return match value.field_at(0) {
+                        Some(field) => serializer.serialize_some(
+                            &CompactTypedReflectSerializer::new(field, self.registry),
+                        ),
+                        None => serializer.serialize_none(),
+                    };
+                }
+
+                let enum_name = info.type_path_table().ident().unwrap();
+                let variant_index_u32 = variant_index as u32;
+                let fields = match variant_info {
+                    VariantInfo::Unit(variant) => {
+                        return serializer.serialize_unit_variant(
+                            enum_name,
+                            variant_index_u32,
+                            variant.name(),
+                        );
+                    }
+                    VariantInfo::Struct(variant) => FieldsSerializer::new(
+                        info.type_path(),
+                        variant.iter().map(|field| value.field(field.name())),
+                        serialization_data,
+                        self.registry,
+                    ),
+                    VariantInfo::Tuple(variant) => FieldsSerializer::new(
+                        info.type_path(),
+                        (0..variant.field_len()).map(|index| value.field_at(index)),
+                        serialization_data,
+                        self.registry,
+                    ),
+                };
+                serializer.serialize_newtype_variant(
+                    enum_name,
+                    variant_index_u32,
+                    variant_info.name(),
+                    &fields,
+                )
+            }
+            (TypeInfo::Value(_), _) => Err(serializable.err().unwrap()),
+            (info, _) => Err(S::Error::custom(format_args!(
+                "the value of type `{}` does not match the kind of its represented type `{}`",
+                self.value.reflect_type_path(),
+                info.type_path()
+            ))),
+        }
+    }
+}
+
+/// Serializes the non-skipped fields of a value as a tuple, in order.
+struct FieldsSerializer<'a> {
+    type_path: &'static str,
+    fields: Vec<(usize, Option<&'a dyn Reflect>)>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> FieldsSerializer<'a> {
+    fn new(
+        type_path: &'static str,
+        fields: impl Iterator<Item = Option<&'a dyn Reflect>>,
+        serialization_data: Option<&SerializationData>,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        let fields = fields
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .collect();
+        Self {
+            type_path,
+            fields,
+            registry,
+        }
+    }
+}
+
+impl<'a> Serialize for FieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(self.fields.len())?;
+        for &(index, field) in &self.fields {
+            let field = field.ok_or_else(|| {
+                S::Error::custom(format_args!(
+                    "missing field at index {index} of `{}`",
+                    self.type_path
+                ))
+            })?;
+            state.serialize_element(&CompactTypedReflectSerializer::new(field, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for reflected values written by the [`CompactReflectSerializer`].
+///
+/// The type of the value is looked up by its [type hash],
+/// so it must be registered in the registry with the same layout as when it was serialized.
+///
+/// Like the [`ReflectDeserializer`], this returns a [`Box<dyn Reflect>`],
+/// containing the expected type for types with [`ReflectDeserialize`] type data,
+/// and its dynamic equivalent otherwise.
+///
+/// [type hash]: crate::TypeRegistration::type_hash
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`Box<dyn Reflect>`]: crate::Reflect
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a type hash followed by a compact reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_hash: u64 = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let registration = self
+            .registry
+            .iter()
+            .find(|registration| registration.type_hash() == type_hash)
+            .ok_or_else(|| {
+                A::Error::custom(format_args!(
+                    "no registered type has the type hash `{type_hash:#018x}`"
+                ))
+            })?;
+        seq.next_element_seed(CompactTypedReflectDeserializer::new(
+            registration,
+            self.registry,
+        ))?
+        .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+/// A deserializer for reflected values of a known type written by the [`CompactTypedReflectSerializer`].
+///
+/// Like the [`TypedReflectDeserializer`], this returns a [`Box<dyn Reflect>`],
+/// containing the expected type for types with [`ReflectDeserialize`] type data,
+/// and its dynamic equivalent otherwise.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [`Box<dyn Reflect>`]: crate::Reflect
+pub struct CompactTypedReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactTypedReflectDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        Self {
+            registration,
+            registry,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactTypedReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Handle both Value case and types that have a custom `ReflectDeserialize`
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            return deserialize_reflect.deserialize(deserializer);
+        }
+
+        let type_info = self.registration.type_info();
+        let serialization_data = self.registration.data::<SerializationData>();
+
+        match type_info {
+            TypeInfo::Struct(info) => {
+                let fields = FieldsDeserializer {
+                    type_path: info.type_path(),
+                    field_types: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data,
+                    registry: self.registry,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (field, value) in info.iter().zip(fields) {
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                dynamic_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(info) => {
+                let fields = FieldsDeserializer {
+                    type_path: info.type_path(),
+                    field_types: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data,
+                    registry: self.registry,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                for value in fields {
+                    dynamic_tuple_struct.insert_boxed(value);
+                }
+                dynamic_tuple_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Tuple(info) => {
+                let fields = FieldsDeserializer {
+                    type_path: info.type_path(),
+                    field_types: info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_path()))
+                        .collect(),
+                    serialization_data: None,
+                    registry: self.registry,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_tuple = DynamicTuple::default();
+                for value in fields {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_tuple.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::Array(info) => {
+                let item = (info.item_type_id(), info.item_type_path_table().path());
+                let items = FieldsDeserializer {
+                    type_path: info.type_path(),
+                    field_types: vec![item; info.capacity()],
+                    serialization_data: None,
+                    registry: self.registry,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_array = DynamicArray::new(items.into_boxed_slice());
+                dynamic_array.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::List(info) => {
+                let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
+                    item_registration: get_registration(
+                        info.item_type_id(),
+                        info.item_type_path_table().path(),
+                        self.registry,
+                    )?,
+                    registry: self.registry,
+                })?;
+                dynamic_list.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Map(info) => {
+                let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
+                    key_registration: get_registration(
+                        info.key_type_id(),
+                        info.key_type_path_table().path(),
+                        self.registry,
+                    )?,
+                    value_registration: get_registration(
+                        info.value_type_id(),
+                        info.value_type_path_table().path(),
+                        self.registry,
+                    )?,
+                    registry: self.registry,
+                })?;
+                dynamic_map.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Enum(info) => {
+                let visitor = EnumVisitor {
+                    enum_info: info,
+                    serialization_data,
+                    registry: self.registry,
+                };
+                let mut dynamic_enum = if is_option(info) {
+                    deserializer.deserialize_option(visitor)?
+                } else {
+                    deserializer.deserialize_enum(
+                        info.type_path_table().ident().unwrap(),
+                        info.variant_names(),
+                        visitor,
+                    )?
+                };
+                dynamic_enum.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Value(info) => Err(D::Error::custom(format_args!(
+                "type `{}` did not register the `ReflectDeserialize` type data",
+                info.type_path()
+            ))),
+        }
+    }
+}
+
+/// Deserializes the fields written by a [`FieldsSerializer`],
+/// generating the default value of skipped fields.
+struct FieldsDeserializer<'a> {
+    type_path: &'static str,
+    field_types: Vec<(TypeId, &'static str)>,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> FieldsDeserializer<'a> {
+    fn is_skipped(&self, index: usize) -> bool {
+        self.serialization_data
+            .is_some_and(|data| data.is_field_skipped(index))
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let len = (0..self.field_types.len())
+            .filter(|index| !self.is_skipped(*index))
+            .count();
+        deserializer.deserialize_tuple(len, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for FieldsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "the fields of `{}`", self.type_path)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.field_types.len());
+        let mut read = 0;
+        for (index, (type_id, type_path)) in self.field_types.iter().enumerate() {
+            if self.is_skipped(index) {
+                if let Some(value) = self
+                    .serialization_data
+                    .and_then(|data| data.generate_default(index))
+                {
+                    values.push(value);
+                }
+                continue;
+            }
+
+            let registration = get_registration(*type_id, type_path, self.registry)?;
+            let value = seq
+                .next_element_seed(CompactTypedReflectDeserializer::new(
+                    registration,
+                    self.registry,
+                ))?
+                .ok_or_else(|| A::Error::invalid_length(read, &self))?;
+            values.push(value);
+            read += 1;
+        }
+        Ok(values)
+    }
+}
+
+struct ListVisitor<'a> {
+    item_registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
+    type Value = DynamicList;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a compact reflected list")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut dynamic_list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(CompactTypedReflectDeserializer::new(
+            self.item_registration,
+            self.registry,
+        ))? {
+            dynamic_list.push_box(value);
+        }
+        Ok(dynamic_list)
+    }
+}
+
+struct MapVisitor<'a> {
+    key_registration: &'a TypeRegistration,
+    value_registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a compact reflected map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(CompactTypedReflectDeserializer::new(
+            self.key_registration,
+            self.registry,
+        ))? {
+            let value = map.next_value_seed(CompactTypedReflectDeserializer::new(
+                self.value_registration,
+                self.registry,
+            ))?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
+
+struct EnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> EnumVisitor<'a> {
+    fn fields_deserializer(
+        &self,
+        variant_index: usize,
+        field_types: Vec<(TypeId, &'static str)>,
+    ) -> FieldsDeserializer<'a> {
+        FieldsDeserializer {
+            type_path: self.enum_info.type_path(),
+            field_types,
+            serialization_data: self
+                .serialization_data
+                .and_then(|data| data.variant(variant_index)),
+            registry: self.registry,
+        }
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a compact variant of `{}`",
+            self.enum_info.type_path()
+        )
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant_index, variant) = data.variant_seed(VariantIndexDeserializer {
+            enum_info: self.enum_info,
+        })?;
+        let variant_info = self.enum_info.variant_at(variant_index).unwrap();
+
+        let mut dynamic_enum = DynamicEnum::default();
+        match variant_info {
+            VariantInfo::Unit(info) => {
+                variant.unit_variant()?;
+                dynamic_enum.set_variant_with_index(variant_index, info.name(), ());
+            }
+            VariantInfo::Struct(info) => {
+                let fields = variant.newtype_variant_seed(
+                    self.fields_deserializer(
+                        variant_index,
+                        info.iter()
+                            .map(|field| (field.type_id(), field.type_path()))
+                            .collect(),
+                    ),
+                )?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (field, value) in info.iter().zip(fields) {
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                dynamic_enum.set_variant_with_index(variant_index, info.name(), dynamic_struct);
+            }
+            VariantInfo::Tuple(info) => {
+                let fields = variant.newtype_variant_seed(
+                    self.fields_deserializer(
+                        variant_index,
+                        info.iter()
+                            .map(|field| (field.type_id(), field.type_path()))
+                            .collect(),
+                    ),
+                )?;
+                let mut dynamic_tuple = DynamicTuple::default();
+                for value in fields {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_enum.set_variant_with_index(variant_index, info.name(), dynamic_tuple);
+            }
+        }
+        Ok(dynamic_enum)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut option = DynamicEnum::default();
+        option.set_variant("None", ());
+        Ok(option)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let Some(VariantInfo::Tuple(info)) = self.enum_info.variant("Some") else {
+            return Err(D::Error::custom(format_args!(
+                "expected a `Some` tuple variant on `{}`",
+                self.enum_info.type_path()
+            )));
+        };
+        let field = info.field_at(0).unwrap();
+        let registration = get_registration(field.type_id(), field.type_path(), self.registry)?;
+        let value = CompactTypedReflectDeserializer::new(registration, self.registry)
+            .deserialize(deserializer)?;
+
+        let mut some = DynamicTuple::default();
+        some.insert_boxed(value);
+        let mut option = DynamicEnum::default();
+        option.set_variant("Some", some);
+        Ok(option)
+    }
+}
+
+/// Deserializes the index of an enum variant.
+struct VariantIndexDeserializer {
+    enum_info: &'static EnumInfo,
+}
+
+impl<'de> DeserializeSeed<'de> for VariantIndexDeserializer {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndexDeserializer {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a variant index of `{}`",
+            self.enum_info.type_path()
+        )
+    }
+
+    fn visit_u32<E>(self, variant_index: u32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(variant_index as u64)
+    }
+
+    fn visit_u64<E>(self, variant_index: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        usize::try_from(variant_index)
+            .ok()
+            .filter(|index| *index < self.enum_info.variant_len())
+            .ok_or_else(|| {
+                E::custom(format_args!(
+                    "no variant found at index `{variant_index}` on enum `{}`",
+                    self.enum_info.type_path()
+                ))
+            })
+    }
+}
+
+fn represented_type_info<E: serde::ser::Error>(
+    value: &dyn Reflect,
+) -> Result<&'static TypeInfo, E> {
+    value.get_represented_type_info().ok_or_else(|| {
+        E::custom(format_args!(
+            "cannot get type info for {}",
+            value.reflect_type_path()
+        ))
+    })
+}
+
+fn is_option(info: &EnumInfo) -> bool {
+    info.type_path_table().module_path() == Some("core::option")
+        && info.type_path_table().ident() == Some("Option")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{from_bincode, ReflectSerializer};
+    use crate::{FromReflect, Struct};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Snapshot {
+        id: u32,
+        name: String,
+        #[reflect(skip_serializing)]
+        cached: u8,
+        position: Position,
+        velocity: Option<(f32, f32)>,
+        target: Option<Position>,
+        state: State,
+        history: Vec<State>,
+        slots: [u16; 3],
+        tags: HashMap<u8, String>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Moving(f32),
+        Attacking { target: u32, damage: u16 },
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            id: 7,
+            name: String::from("goblin"),
+            cached: 3,
+            position: Position(1.0, 2.0),
+            velocity: Some((0.5, -0.5)),
+            target: None,
+            state: State::Attacking {
+                target: 12,
+                damage: 40,
+            },
+            history: vec![State::Idle, State::Moving(2.5)],
+            slots: [1, 2, 3],
+            tags: HashMap::from([(1, String::from("hostile"))]),
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Snapshot>();
+        registry
+    }
+
+    fn roundtrip(bytes: &[u8], registry: &TypeRegistry) -> Box<dyn Reflect> {
+        from_bincode(CompactReflectDeserializer::new(registry), bytes).unwrap()
+    }
+
+    #[test]
+    fn should_roundtrip_with_bincode() {
+        let registry = get_registry();
+        let input = snapshot();
+
+        let bytes = bincode::serialize(&CompactReflectSerializer::new(&input, &registry)).unwrap();
+        let output = Snapshot::from_reflect(&*roundtrip(&bytes, &registry)).unwrap();
+
+        assert_eq!(Snapshot { cached: 0, ..input }, output);
+    }
+
+    #[test]
+    fn should_be_smaller_than_reflect_serializer() {
+        let registry = get_registry();
+        let input = snapshot();
+
+        let compact =
+            bincode::serialize(&CompactReflectSerializer::new(&input, &registry)).unwrap();
+        let plain = bincode::serialize(&ReflectSerializer::new(&input, &registry)).unwrap();
+
+        assert!(compact.len() < plain.len());
+    }
+
+    #[test]
+    fn should_write_fields_in_type_info_order() {
+        let registry = get_registry();
+        let input = snapshot();
+
+        // A dynamic struct listing the fields in reverse order
+        let mut dynamic = DynamicStruct::default();
+        let input_struct = input.clone_dynamic();
+        for index in (0..input_struct.field_len()).rev() {
+            let name = input_struct.name_at(index).unwrap();
+            dynamic.insert_boxed(name, input_struct.field_at(index).unwrap().clone_value());
+        }
+        dynamic.set_represented_type(Some(<Snapshot as crate::Typed>::type_info()));
+
+        let bytes =
+            bincode::serialize(&CompactReflectSerializer::new(&dynamic, &registry)).unwrap();
+        let output = Snapshot::from_reflect(&*roundtrip(&bytes, &registry)).unwrap();
+
+        assert_eq!(Snapshot { cached: 0, ..input }, output);
+    }
+
+    #[test]
+    fn should_fail_on_missing_field() {
+        let registry = get_registry();
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("id", 7_u32);
+        dynamic.set_represented_type(Some(<Snapshot as crate::Typed>::type_info()));
+
+        let error =
+            bincode::serialize(&CompactReflectSerializer::new(&dynamic, &registry)).unwrap_err();
+        assert_eq!(
+            "missing field at index 1 of `bevy_reflect::serde::compact::tests::Snapshot`",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unknown_type_hash() {
+        let registry = get_registry();
+        let bytes = bincode::serialize(&(0_u64, 0_u32)).unwrap();
+
+        let error = from_bincode(CompactReflectDeserializer::new(&registry), &bytes).unwrap_err();
+        assert_eq!(
+            "no registered type has the type hash `0x0000000000000000`",
+            error.to_string()
+        );
+    }
+}
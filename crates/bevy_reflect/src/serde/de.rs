@@ -1,13 +1,19 @@
-use crate::serde::SerializationData;
+use crate::serde::base64::decode_base64;
+use crate::serde::migrate::{versioned_field_names, visit_version_seq, visit_versioned_struct};
+use crate::serde::{
+    ReflectDenyUnknownFields, ReflectDeserializeWithRegistry, ReflectMigrate, SerializationData,
+};
+use crate::std_traits::ReflectDefault;
 use crate::{
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
     DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
-    TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+    ReflectDeserialize, ReflectRef, Struct, StructInfo, StructVariantInfo, TupleInfo,
+    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
-    DeserializeSeed, EnumAccess, Error, IgnoredAny, MapAccess, SeqAccess, VariantAccess, Visitor,
+    DeserializeSeed, EnumAccess, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected,
+    VariantAccess, Visitor,
 };
 use serde::Deserialize;
 use std::any::TypeId;
@@ -43,7 +49,7 @@ trait Container {
 
 impl StructLikeInfo for StructInfo {
     fn get_field(&self, name: &str) -> Option<&NamedField> {
-        self.field(name)
+        self.field_or_alias(name)
     }
 
     fn field_at(&self, index: usize) -> Option<&NamedField> {
@@ -78,7 +84,7 @@ impl Container for StructInfo {
 
 impl StructLikeInfo for StructVariantInfo {
     fn get_field(&self, name: &str) -> Option<&NamedField> {
-        self.field(name)
+        self.field_or_alias(name)
     }
 
     fn field_at(&self, index: usize) -> Option<&NamedField> {
@@ -205,7 +211,7 @@ impl<T: Display> Debug for ExpectedValues<T> {
 
 /// Represents a simple reflected identifier.
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Ident(String);
+pub(super) struct Ident(pub(super) String);
 
 impl<'de> Deserialize<'de> for Ident {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -369,11 +375,33 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
 /// [`ReflectFromReflect`]: crate::ReflectFromReflect
 pub struct ReflectDeserializer<'a> {
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a> ReflectDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            deny_unknown_fields: true,
+            compact_bytes: false,
+        }
+    }
+
+    /// Sets whether unknown struct fields are rejected, which is the default.
+    ///
+    /// See [`TypedReflectDeserializer::with_deny_unknown_fields`] for details.
+    pub fn with_deny_unknown_fields(mut self, deny_unknown_fields: bool) -> Self {
+        self.deny_unknown_fields = deny_unknown_fields;
+        self
+    }
+
+    /// Sets whether lists and arrays of bytes are read as a single value.
+    ///
+    /// See [`TypedReflectDeserializer::with_compact_bytes`] for details.
+    pub fn with_compact_bytes(mut self, compact_bytes: bool) -> Self {
+        self.compact_bytes = compact_bytes;
+        self
     }
 }
 
@@ -386,6 +414,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
     {
         struct UntypedReflectDeserializerVisitor<'a> {
             registry: &'a TypeRegistry,
+            deny_unknown_fields: bool,
+            compact_bytes: bool,
         }
 
         impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
@@ -407,6 +437,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
                 let value = map.next_value_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    deny_unknown_fields: self.deny_unknown_fields,
+                    compact_bytes: self.compact_bytes,
                 })?;
 
                 if map.next_key::<IgnoredAny>()?.is_some() {
@@ -419,6 +451,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 
         deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
             registry: self.registry,
+            deny_unknown_fields: self.deny_unknown_fields,
+            compact_bytes: self.compact_bytes,
         })
     }
 }
@@ -502,6 +536,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 pub struct TypedReflectDeserializer<'a> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -509,18 +545,57 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            deny_unknown_fields: true,
+            compact_bytes: false,
         }
     }
+
+    /// Sets whether unknown struct fields are rejected, which is the default.
+    ///
+    /// When set to `false`, fields of structs and struct variants which the type doesn't have
+    /// are skipped instead, so that data written by other versions of a type can still be loaded.
+    /// Types which register [`ReflectDenyUnknownFields`] always reject unknown fields,
+    /// in themselves and in any value they contain.
+    ///
+    /// Unknown enum variants are always rejected, since there is no value to use in their place.
+    pub fn with_deny_unknown_fields(mut self, deny_unknown_fields: bool) -> Self {
+        self.deny_unknown_fields = deny_unknown_fields;
+        self
+    }
+
+    /// Sets whether lists and arrays of bytes, such as `Vec<u8>` and `[u8; N]`,
+    /// are read as a single value, as written by a serializer with [compact bytes] enabled.
+    ///
+    /// Human-readable formats accept both base64 strings and bytes written element by element,
+    /// whether this is enabled or not.
+    /// Other formats can't tell the two apart, so this must match how the data was written.
+    ///
+    /// [compact bytes]: crate::serde::TypedReflectSerializer::with_compact_bytes
+    pub fn with_compact_bytes(mut self, compact_bytes: bool) -> Self {
+        self.compact_bytes = compact_bytes;
+        self
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     type Value = Box<dyn Reflect>;
 
-    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    fn deserialize<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let type_path = self.registration.type_info().type_path();
+        self.deny_unknown_fields |= self
+            .registration
+            .data::<ReflectDenyUnknownFields>()
+            .is_some();
+
+        // Types with a custom deserializer that needs the registry take precedence
+        if let Some(deserialize_with_registry) =
+            self.registration.data::<ReflectDeserializeWithRegistry>()
+        {
+            return deserialize_with_registry.deserialize(deserializer, self.registry);
+        }
 
         // Handle both Value case and types that have a custom `ReflectDeserialize`
         if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
@@ -530,13 +605,19 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
 
         match self.registration.type_info() {
             TypeInfo::Struct(struct_info) => {
+                let field_names = match self.registration.data::<ReflectMigrate>() {
+                    Some(_) => versioned_field_names(struct_info),
+                    None => struct_info.field_names(),
+                };
                 let mut dynamic_struct = deserializer.deserialize_struct(
                     struct_info.type_path_table().ident().unwrap(),
-                    struct_info.field_names(),
+                    field_names,
                     StructVisitor {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     },
                 )?;
                 dynamic_struct.set_represented_type(Some(self.registration.type_info()));
@@ -549,26 +630,61 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     TupleStructVisitor {
                         tuple_struct_info,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                         registration: self.registration,
                     },
                 )?;
                 dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_tuple_struct))
             }
+            TypeInfo::List(list_info) if list_info.item_is::<u8>() => {
+                let bytes = BytesVisitor {
+                    array_len: None,
+                    compact_bytes: self.compact_bytes,
+                }
+                .deserialize(deserializer)?;
+                let mut dynamic_list = DynamicList::default();
+                for byte in bytes {
+                    dynamic_list.push(byte);
+                }
+                dynamic_list.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_list))
+            }
             TypeInfo::List(list_info) => {
                 let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
                     list_info,
                     registry: self.registry,
+                    deny_unknown_fields: self.deny_unknown_fields,
+                    compact_bytes: self.compact_bytes,
                 })?;
                 dynamic_list.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_list))
             }
+            TypeInfo::Array(array_info) if array_info.item_is::<u8>() => {
+                let bytes = BytesVisitor {
+                    array_len: Some(array_info.capacity()),
+                    compact_bytes: self.compact_bytes,
+                }
+                .deserialize(deserializer)?;
+                if bytes.len() != array_info.capacity() {
+                    return Err(Error::invalid_length(
+                        bytes.len(),
+                        &array_info.capacity().to_string().as_str(),
+                    ));
+                }
+                let mut dynamic_array = DynamicArray::from_bytes(&bytes);
+                dynamic_array.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_array))
+            }
             TypeInfo::Array(array_info) => {
                 let mut dynamic_array = deserializer.deserialize_tuple(
                     array_info.capacity(),
                     ArrayVisitor {
                         array_info,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     },
                 )?;
                 dynamic_array.set_represented_type(Some(self.registration.type_info()));
@@ -578,6 +694,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
                     map_info,
                     registry: self.registry,
+                    deny_unknown_fields: self.deny_unknown_fields,
+                    compact_bytes: self.compact_bytes,
                 })?;
                 dynamic_map.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_map))
@@ -589,6 +707,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         tuple_info,
                         registration: self.registration,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     },
                 )?;
                 dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
@@ -602,6 +722,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     deserializer.deserialize_option(OptionVisitor {
                         enum_info,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     })?
                 } else {
                     deserializer.deserialize_enum(
@@ -611,6 +733,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                             enum_info,
                             registration: self.registration,
                             registry: self.registry,
+                            deny_unknown_fields: self.deny_unknown_fields,
+                            compact_bytes: self.compact_bytes,
                         },
                     )?
                 };
@@ -631,6 +755,8 @@ struct StructVisitor<'a> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
@@ -644,20 +770,58 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        if let Some(migrate) = self.registration.data::<ReflectMigrate>() {
+            visit_version_seq(&mut seq, self.struct_info, migrate)?;
+        }
+
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration.data::<SerializationData>(),
+            self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        let mut dynamic_struct = if let Some(migrate) = self.registration.data::<ReflectMigrate>() {
+            visit_versioned_struct(
+                &mut map,
+                self.struct_info,
+                self.registration.data::<SerializationData>(),
+                migrate,
+                self.registry,
+                self.deny_unknown_fields,
+                self.compact_bytes,
+            )?
+        } else {
+            visit_struct(
+                &mut map,
+                self.struct_info,
+                self.registration.data::<SerializationData>(),
+                self.registry,
+                self.deny_unknown_fields,
+                self.compact_bytes,
+            )?
+        };
+
+        if let Some(reflect_default) = self.registration.data::<ReflectDefault>() {
+            insert_default_fields(&mut dynamic_struct, self.struct_info, reflect_default);
+        }
+
+        Ok(dynamic_struct)
     }
 }
 
 struct TupleStructVisitor<'a> {
     tuple_struct_info: &'static TupleStructInfo,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
     registration: &'a TypeRegistration,
 }
 
@@ -675,8 +839,10 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
         visit_tuple(
             &mut seq,
             self.tuple_struct_info,
-            self.registration,
+            self.registration.data::<SerializationData>(),
             self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
         )
         .map(DynamicTupleStruct::from)
     }
@@ -686,6 +852,8 @@ struct TupleVisitor<'a> {
     tuple_info: &'static TupleInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
@@ -699,13 +867,91 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration.data::<SerializationData>(),
+            self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
+        )
+    }
+}
+
+/// Visitor for byte arrays and lists (`[u8; N]` and `Vec<u8>`).
+///
+/// With [compact bytes], these are serialized as a base64 string in human-readable formats
+/// and as a byte buffer otherwise. Without it, they are serialized element by element.
+/// Human-readable formats accept both.
+///
+/// [compact bytes]: TypedReflectDeserializer::with_compact_bytes
+struct BytesVisitor {
+    array_len: Option<usize>,
+    compact_bytes: bool,
+}
+
+impl BytesVisitor {
+    fn deserialize<'de, D>(self, deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else if self.compact_bytes {
+            deserializer.deserialize_bytes(self)
+        } else if let Some(len) = self.array_len {
+            deserializer.deserialize_tuple(len, self)
+        } else {
+            deserializer.deserialize_seq(self)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected bytes value")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        decode_base64(v).ok_or_else(|| Error::invalid_value(Unexpected::Str(v), &"a base64 string"))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
     }
 }
 
 struct ArrayVisitor<'a> {
     array_info: &'static ArrayInfo,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
@@ -728,6 +974,8 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            deny_unknown_fields: self.deny_unknown_fields,
+            compact_bytes: self.compact_bytes,
         })? {
             vec.push(value);
         }
@@ -746,6 +994,8 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
 struct ListVisitor<'a> {
     list_info: &'static ListInfo,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
@@ -768,6 +1018,8 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            deny_unknown_fields: self.deny_unknown_fields,
+            compact_bytes: self.compact_bytes,
         })? {
             list.push_box(value);
         }
@@ -778,6 +1030,8 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
 struct MapVisitor<'a> {
     map_info: &'static MapInfo,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
@@ -805,10 +1059,14 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         while let Some(key) = map.next_key_seed(TypedReflectDeserializer {
             registration: key_registration,
             registry: self.registry,
+            deny_unknown_fields: self.deny_unknown_fields,
+            compact_bytes: self.compact_bytes,
         })? {
             let value = map.next_value_seed(TypedReflectDeserializer {
                 registration: value_registration,
                 registry: self.registry,
+                deny_unknown_fields: self.deny_unknown_fields,
+                compact_bytes: self.compact_bytes,
             })?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -821,6 +1079,8 @@ struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
@@ -838,6 +1098,15 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
         let (variant_info, variant) = data.variant_seed(VariantDeserializer {
             enum_info: self.enum_info,
         })?;
+        let variant_name = variant_info.name();
+        let variant_index = self
+            .enum_info
+            .index_of(variant_name)
+            .expect("variant should exist");
+        let serialization_data = self
+            .registration
+            .data::<SerializationData>()
+            .and_then(|data| data.variant(variant_index));
 
         let value: DynamicVariant = match variant_info {
             VariantInfo::Unit(..) => variant.unit_variant()?.into(),
@@ -846,16 +1115,23 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                     struct_info.field_names(),
                     StructVariantVisitor {
                         struct_info,
-                        registration: self.registration,
+                        serialization_data,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     },
                 )?
                 .into(),
-            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+            VariantInfo::Tuple(tuple_info)
+                if tuple_info.field_len() == 1
+                    && !serialization_data.is_some_and(|data| data.is_field_skipped(0)) =>
+            {
                 let registration = tuple_info.get_field_registration(0, self.registry)?;
                 let value = variant.newtype_variant_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    deny_unknown_fields: self.deny_unknown_fields,
+                    compact_bytes: self.compact_bytes,
                 })?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -866,17 +1142,14 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                     tuple_info.field_len(),
                     TupleVariantVisitor {
                         tuple_info,
-                        registration: self.registration,
+                        serialization_data,
                         registry: self.registry,
+                        deny_unknown_fields: self.deny_unknown_fields,
+                        compact_bytes: self.compact_bytes,
                     },
                 )?
                 .into(),
         };
-        let variant_name = variant_info.name();
-        let variant_index = self
-            .enum_info
-            .index_of(variant_name)
-            .expect("variant should exist");
         dynamic_enum.set_variant_with_index(variant_index, variant_name, value);
         Ok(dynamic_enum)
     }
@@ -919,7 +1192,7 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             where
                 E: Error,
             {
-                self.0.variant(variant_name).ok_or_else(|| {
+                self.0.variant_or_alias(variant_name).ok_or_else(|| {
                     let names = self.0.iter().map(VariantInfo::name);
                     Error::custom(format_args!(
                         "unknown variant `{}`, expected one of {:?}",
@@ -936,8 +1209,10 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
 
 struct StructVariantVisitor<'a> {
     struct_info: &'static StructVariantInfo,
-    registration: &'a TypeRegistration,
+    serialization_data: Option<&'a SerializationData>,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
@@ -951,21 +1226,37 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.serialization_data,
+            self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.serialization_data,
+            self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
+        )
     }
 }
 
 struct TupleVariantVisitor<'a> {
     tuple_info: &'static TupleVariantInfo,
-    registration: &'a TypeRegistration,
+    serialization_data: Option<&'a SerializationData>,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
@@ -979,13 +1270,22 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.serialization_data,
+            self.registry,
+            self.deny_unknown_fields,
+            self.compact_bytes,
+        )
     }
 }
 
 struct OptionVisitor<'a> {
     enum_info: &'static EnumInfo,
     registry: &'a TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
@@ -1018,6 +1318,8 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
                 let de = TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    deny_unknown_fields: self.deny_unknown_fields,
+                    compact_bytes: self.compact_bytes,
                 };
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -1036,8 +1338,10 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
 fn visit_struct<'de, T, V>(
     map: &mut V,
     info: &'static T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
@@ -1045,28 +1349,36 @@ where
 {
     let mut dynamic_struct = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.get_field(&key).ok_or_else(|| {
-            let fields = info.iter_fields().map(NamedField::name);
-            Error::custom(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues(fields.collect())
-            ))
-        })?;
+        let Some(field) = info.get_field(&key) else {
+            if deny_unknown_fields {
+                let fields = info.iter_fields().map(NamedField::name);
+                return Err(Error::custom(format_args!(
+                    "unknown field `{}`, expected one of {:?}",
+                    key,
+                    ExpectedValues(fields.collect())
+                )));
+            }
+            map.next_value::<IgnoredAny>()?;
+            continue;
+        };
         let registration = get_registration(field.type_id(), field.type_path(), registry)?;
         let value = map.next_value_seed(TypedReflectDeserializer {
             registration,
             registry,
+            deny_unknown_fields,
+            compact_bytes,
         })?;
-        dynamic_struct.insert_boxed(&key, value);
+        dynamic_struct.insert_boxed(field.name(), value);
     }
 
-    if let Some(serialization_data) = registration.data::<SerializationData>() {
-        for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
-            let Some(field) = info.field_at(*skipped_index) else {
+    if let Some(serialization_data) = serialization_data {
+        for (index, field) in info.iter_fields().enumerate() {
+            if dynamic_struct.field(field.name()).is_some() {
                 continue;
-            };
-            dynamic_struct.insert_boxed(field.name(), skipped_field.generate_default());
+            }
+            if let Some(value) = serialization_data.generate_missing(index) {
+                dynamic_struct.insert_boxed(field.name(), value);
+            }
         }
     }
 
@@ -1076,8 +1388,10 @@ where
 fn visit_tuple<'de, T, V>(
     seq: &mut V,
     info: &T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo + Container,
@@ -1092,8 +1406,6 @@ where
         return Ok(tuple);
     }
 
-    let serialization_data = registration.data::<SerializationData>();
-
     for index in 0..len {
         if let Some(value) = serialization_data.and_then(|data| data.generate_default(index)) {
             tuple.insert_boxed(value);
@@ -1104,7 +1416,10 @@ where
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                deny_unknown_fields,
+                compact_bytes,
             })?
+            .or_else(|| serialization_data.and_then(|data| data.generate_missing(index)))
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
     }
@@ -1115,8 +1430,10 @@ where
 fn visit_struct_seq<'de, T, V>(
     seq: &mut V,
     info: &T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo + Container,
@@ -1131,8 +1448,6 @@ where
         return Ok(dynamic_struct);
     }
 
-    let serialization_data = registration.data::<SerializationData>();
-
     for index in 0..len {
         let name = info.field_at(index).unwrap().name();
 
@@ -1150,7 +1465,10 @@ where
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                deny_unknown_fields,
+                compact_bytes,
             })?
+            .or_else(|| serialization_data.and_then(|data| data.generate_missing(index)))
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         dynamic_struct.insert_boxed(name, value);
     }
@@ -1158,7 +1476,38 @@ where
     Ok(dynamic_struct)
 }
 
-fn get_registration<'a, E: Error>(
+/// Fills in the fields missing from `dynamic_struct` with those of the struct's default value.
+///
+/// This allows reading back structs serialized with [`TypedReflectSerializer::with_skip_defaults`].
+///
+/// [`TypedReflectSerializer::with_skip_defaults`]: crate::serde::TypedReflectSerializer::with_skip_defaults
+fn insert_default_fields(
+    dynamic_struct: &mut DynamicStruct,
+    info: &StructInfo,
+    reflect_default: &ReflectDefault,
+) {
+    if info
+        .iter()
+        .all(|field| dynamic_struct.field(field.name()).is_some())
+    {
+        return;
+    }
+
+    let default_value = reflect_default.default();
+    let ReflectRef::Struct(default_struct) = default_value.reflect_ref() else {
+        return;
+    };
+    for field in info.iter() {
+        if dynamic_struct.field(field.name()).is_some() {
+            continue;
+        }
+        if let Some(value) = default_struct.field(field.name()) {
+            dynamic_struct.insert_boxed(field.name(), value.clone_value());
+        }
+    }
+}
+
+pub(super) fn get_registration<'a, E: Error>(
     type_id: TypeId,
     type_path: &str,
     registry: &'a TypeRegistry,
@@ -1174,7 +1523,6 @@ mod tests {
     use bincode::Options;
     use std::any::TypeId;
     use std::f32::consts::PI;
-    use std::ops::RangeInclusive;
 
     use serde::de::DeserializeSeed;
     use serde::Deserialize;
@@ -1182,7 +1530,9 @@ mod tests {
     use bevy_utils::HashMap;
 
     use crate as bevy_reflect;
-    use crate::serde::{ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer};
+    use crate::serde::{
+        ReflectDenyUnknownFields, ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer,
+    };
     use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -1637,14 +1987,71 @@ mod tests {
     #[test]
     fn should_return_error_if_missing_type_data() {
         let mut registry = TypeRegistry::new();
-        registry.register::<RangeInclusive<f32>>();
+        registry.register::<TypeId>();
 
-        let input = r#"{"core::ops::RangeInclusive<f32>":(start:0.0,end:1.0)}"#;
+        let input = r#"{"std::any::TypeId":0}"#;
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
         let reflect_deserializer = ReflectDeserializer::new(&registry);
         let error = reflect_deserializer
             .deserialize(&mut deserializer)
             .unwrap_err();
-        assert_eq!(error, ron::Error::Message("Type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()));
+        assert_eq!(error, ron::Error::Message("Type `std::any::TypeId` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()));
+    }
+
+    #[test]
+    fn should_skip_unknown_fields_when_allowed() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Settings {
+            volume: f32,
+            mode: Mode,
+            audio: Audio,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Mode {
+            Windowed { width: u32 },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(DenyUnknownFields)]
+        struct Audio {
+            muted: bool,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Settings>();
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+
+        let deserialize = |input: &str, deny_unknown_fields: bool| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::new(registration, &registry)
+                .with_deny_unknown_fields(deny_unknown_fields)
+                .deserialize(&mut deserializer)
+        };
+
+        let input = r#"(
+            volume: 0.5,
+            brightness: [1, 2, 3],
+            mode: Windowed(width: 800, height: 600),
+            audio: (muted: true),
+        )"#;
+        assert!(deserialize(input, true).is_err());
+        let output = deserialize(input, false).unwrap();
+        assert_eq!(
+            Settings {
+                volume: 0.5,
+                mode: Mode::Windowed { width: 800 },
+                audio: Audio { muted: true },
+            },
+            Settings::from_reflect(output.as_ref()).unwrap()
+        );
+
+        // Types registering `ReflectDenyUnknownFields` are always strict
+        let input = r#"(
+            volume: 0.5,
+            mode: Windowed(width: 800),
+            audio: (muted: true, device: "default"),
+        )"#;
+        assert!(deserialize(input, false).is_err());
     }
 }
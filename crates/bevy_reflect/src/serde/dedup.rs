@@ -0,0 +1,515 @@
+use crate::serde::de::Ident;
+use crate::serde::{
+    DictionaryReflectDeserializer, DictionaryReflectSerializer, ReflectDeserializer,
+    ReflectSerializer, SchemaDictionary,
+};
+use crate::utility::reflect_hasher;
+use crate::{Reflect, ReflectRef, TypeInfo, TypeRegistry};
+use bevy_utils::HashMap;
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::Serialize;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+
+const DEDUP_STRUCT: &str = "DedupValues";
+const DEDUP_FIELDS: &[&str] = &["shared", "refs"];
+
+/// A serializer for a list of reflected values that writes repeated values only once.
+///
+/// Values are considered identical when they represent the same type and compare equal
+/// using [`Reflect::reflect_partial_eq`].
+/// Candidates are found through a hash of the whole structure of each value,
+/// so values don't need to support [`Reflect::reflect_hash`] themselves.
+///
+/// Only the values of the list itself are deduplicated, each compared as a whole:
+/// identical fields or elements nested inside of different values are written out in full.
+/// To share such sub-values, they should be listed as values of their own.
+///
+/// This is the serializer counterpart to [`DedupReflectDeserializer`].
+///
+/// With a [`SchemaDictionary`], given by [`with_dictionary`](Self::with_dictionary),
+/// the shared values are written with [`DictionaryReflectSerializer`] instead,
+/// replacing their type paths by small ids.
+///
+/// # Output
+///
+/// This serializer will output a struct with two fields:
+/// * `shared`: the list of unique values, each serialized with [`ReflectSerializer`]
+/// * `refs`: for each input value, the index of its entry in `shared`
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{DedupReflectSerializer, DedupReflectDeserializer}};
+/// #[derive(Reflect)]
+/// #[type_path = "my_crate"]
+/// struct Material {
+///   roughness: f32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Material>();
+///
+/// let values: Vec<Box<dyn Reflect>> = vec![
+///   Box::new(Material { roughness: 0.5 }),
+///   Box::new(Material { roughness: 0.5 }),
+///   Box::new(Material { roughness: 1.0 }),
+/// ];
+///
+/// let serializer = DedupReflectSerializer::new(&values, &registry);
+/// let output = ron::to_string(&serializer).unwrap();
+/// assert_eq!(
+///   output,
+///   r#"(shared:[{"my_crate::Material":(roughness:0.5)},{"my_crate::Material":(roughness:1.0)}],refs:[0,0,1])"#
+/// );
+///
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let values = DedupReflectDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+/// assert_eq!(3, values.len());
+/// ```
+pub struct DedupReflectSerializer<'a> {
+    values: &'a [Box<dyn Reflect>],
+    registry: &'a TypeRegistry,
+    dictionary: Option<&'a SchemaDictionary>,
+}
+
+impl<'a> DedupReflectSerializer<'a> {
+    /// Creates a serializer for the given values, which must have their types registered.
+    pub fn new(values: &'a [Box<dyn Reflect>], registry: &'a TypeRegistry) -> Self {
+        Self {
+            values,
+            registry,
+            dictionary: None,
+        }
+    }
+
+    /// Writes the type paths of the shared values as their ids in the given dictionary.
+    ///
+    /// The output must be read by a [`DedupReflectDeserializer`] using the same dictionary.
+    pub fn with_dictionary(mut self, dictionary: &'a SchemaDictionary) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    /// Splits the values into a list of unique values and, for each value,
+    /// the index of its unique counterpart.
+    fn dedup(&self) -> (Vec<&'a dyn Reflect>, Vec<usize>) {
+        let mut shared: Vec<&'a dyn Reflect> = Vec::new();
+        let mut candidates: HashMap<u64, Vec<usize>> = HashMap::default();
+        let mut refs = Vec::with_capacity(self.values.len());
+
+        for value in self.values {
+            let value = &**value;
+            let type_id = value
+                .get_represented_type_info()
+                .map(TypeInfo::type_id)
+                .unwrap_or_else(|| value.as_any().type_id());
+            let mut hasher = reflect_hasher();
+            type_id.hash(&mut hasher);
+            hasher.write_u64(structural_hash(value));
+
+            let candidates = candidates.entry(hasher.finish()).or_default();
+            let existing = candidates
+                .iter()
+                .copied()
+                .find(|&index| value.reflect_partial_eq(shared[index]) == Some(true));
+
+            let index = existing.unwrap_or_else(|| {
+                shared.push(value);
+                candidates.push(shared.len() - 1);
+                shared.len() - 1
+            });
+            refs.push(index);
+        }
+
+        (shared, refs)
+    }
+}
+
+/// Hashes a value through its structure, such that values comparing equal with
+/// [`Reflect::reflect_partial_eq`] have the same hash.
+///
+/// Fields and entries looked up by name or key are combined regardless of their order.
+/// Opaque values which don't support [`Reflect::reflect_hash`] only contribute their kind,
+/// except for floats, which are hashed by value.
+fn structural_hash(value: &dyn Reflect) -> u64 {
+    let mut hasher = reflect_hasher();
+    std::mem::discriminant(&value.reflect_kind()).hash(&mut hasher);
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let mut fields = 0u64;
+            for (index, field) in value.iter_fields().enumerate() {
+                let mut hasher = reflect_hasher();
+                value.name_at(index).hash(&mut hasher);
+                hasher.write_u64(structural_hash(field));
+                fields = fields.wrapping_add(hasher.finish());
+            }
+            hasher.write_u64(fields);
+        }
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .for_each(|field| hasher.write_u64(structural_hash(field))),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .for_each(|field| hasher.write_u64(structural_hash(field))),
+        ReflectRef::List(value) => value
+            .iter()
+            .for_each(|item| hasher.write_u64(structural_hash(item))),
+        ReflectRef::Array(value) => value
+            .iter()
+            .for_each(|item| hasher.write_u64(structural_hash(item))),
+        ReflectRef::Map(value) => {
+            let mut entries = 0u64;
+            for (key, value) in value.iter() {
+                let mut hasher = reflect_hasher();
+                hasher.write_u64(structural_hash(key));
+                hasher.write_u64(structural_hash(value));
+                entries = entries.wrapping_add(hasher.finish());
+            }
+            hasher.write_u64(entries);
+        }
+        ReflectRef::Enum(value) => {
+            value.variant_name().hash(&mut hasher);
+            value
+                .iter_fields()
+                .for_each(|field| hasher.write_u64(structural_hash(field.value())));
+        }
+        ReflectRef::Value(value) => {
+            if let Some(hash) = value.reflect_hash() {
+                hasher.write_u64(hash);
+            } else if let Some(value) = value.downcast_ref::<f32>() {
+                // `0.0` and `-0.0` compare equal
+                hasher.write_u32(if *value == 0.0 { 0 } else { value.to_bits() });
+            } else if let Some(value) = value.downcast_ref::<f64>() {
+                hasher.write_u64(if *value == 0.0 { 0 } else { value.to_bits() });
+            }
+        }
+    }
+    hasher.finish()
+}
+
+impl<'a> Serialize for DedupReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let (shared, refs) = self.dedup();
+
+        let mut state = serializer.serialize_struct(DEDUP_STRUCT, DEDUP_FIELDS.len())?;
+        state.serialize_field(
+            DEDUP_FIELDS[0],
+            &SharedValuesSerializer {
+                values: &shared,
+                registry: self.registry,
+                dictionary: self.dictionary,
+            },
+        )?;
+        state.serialize_field(DEDUP_FIELDS[1], &refs)?;
+        state.end()
+    }
+}
+
+struct SharedValuesSerializer<'a> {
+    values: &'a [&'a dyn Reflect],
+    registry: &'a TypeRegistry,
+    dictionary: Option<&'a SchemaDictionary>,
+}
+
+impl<'a> Serialize for SharedValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in self.values {
+            match self.dictionary {
+                Some(dictionary) => state.serialize_element(&DictionaryReflectSerializer::new(
+                    *value,
+                    self.registry,
+                    dictionary,
+                ))?,
+                None => state.serialize_element(&ReflectSerializer::new(*value, self.registry))?,
+            }
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for lists of reflected values written by [`DedupReflectSerializer`].
+///
+/// Each reference is resolved to a [clone](Reflect::clone_value) of its shared value,
+/// so the output contains one value per input value, in the original order.
+pub struct DedupReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    dictionary: Option<&'a SchemaDictionary>,
+}
+
+impl<'a> DedupReflectDeserializer<'a> {
+    /// Creates a deserializer reading values whose types are registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            dictionary: None,
+        }
+    }
+
+    /// Reads values written by a [`DedupReflectSerializer`] using the given dictionary.
+    pub fn with_dictionary(mut self, dictionary: &'a SchemaDictionary) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DedupReflectDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            DEDUP_STRUCT,
+            DEDUP_FIELDS,
+            DedupVisitor {
+                registry: self.registry,
+                dictionary: self.dictionary,
+            },
+        )
+    }
+}
+
+struct DedupVisitor<'a> {
+    registry: &'a TypeRegistry,
+    dictionary: Option<&'a SchemaDictionary>,
+}
+
+impl<'a> DedupVisitor<'a> {
+    fn resolve<E: Error>(
+        shared: Vec<Box<dyn Reflect>>,
+        refs: Vec<usize>,
+    ) -> Result<Vec<Box<dyn Reflect>>, E> {
+        refs.into_iter()
+            .map(|index| {
+                shared
+                    .get(index)
+                    .map(|value| value.clone_value())
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "reference to shared value {index} is out of bounds (found {} shared values)",
+                            shared.len()
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DedupVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("deduplicated reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let shared = seq
+            .next_element_seed(SharedValuesDeserializer {
+                registry: self.registry,
+                dictionary: self.dictionary,
+            })?
+            .ok_or_else(|| Error::invalid_length(0, &"2 fields"))?;
+        let refs = seq
+            .next_element::<Vec<usize>>()?
+            .ok_or_else(|| Error::invalid_length(1, &"2 fields"))?;
+
+        Self::resolve(shared, refs)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut shared = None;
+        let mut refs = None;
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            match key.as_str() {
+                "shared" => {
+                    if shared.is_some() {
+                        return Err(Error::duplicate_field(DEDUP_FIELDS[0]));
+                    }
+                    shared = Some(map.next_value_seed(SharedValuesDeserializer {
+                        registry: self.registry,
+                        dictionary: self.dictionary,
+                    })?);
+                }
+                "refs" => {
+                    if refs.is_some() {
+                        return Err(Error::duplicate_field(DEDUP_FIELDS[1]));
+                    }
+                    refs = Some(map.next_value::<Vec<usize>>()?);
+                }
+                _ => return Err(Error::unknown_field(&key, DEDUP_FIELDS)),
+            }
+        }
+
+        let shared = shared.ok_or_else(|| Error::missing_field(DEDUP_FIELDS[0]))?;
+        let refs = refs.ok_or_else(|| Error::missing_field(DEDUP_FIELDS[1]))?;
+        Self::resolve(shared, refs)
+    }
+}
+
+struct SharedValuesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    dictionary: Option<&'a SchemaDictionary>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SharedValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SharedValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        loop {
+            let value = match self.dictionary {
+                Some(dictionary) => seq.next_element_seed(DictionaryReflectDeserializer::new(
+                    self.registry,
+                    dictionary,
+                ))?,
+                None => seq.next_element_seed(ReflectDeserializer::new(self.registry))?,
+            };
+            let Some(value) = value else {
+                break;
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DedupReflectDeserializer, DedupReflectSerializer};
+    use crate::serde::{from_bincode, SchemaDictionary};
+    use crate::{self as bevy_reflect, DynamicStruct, FromReflect, Reflect, TypeRegistry, Typed};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Settings {
+        color: [f32; 3],
+        name: String,
+    }
+
+    fn settings(name: &str) -> Box<dyn Reflect> {
+        Box::new(Settings {
+            color: [1.0, 0.5, 0.25],
+            name: name.to_string(),
+        })
+    }
+
+    #[test]
+    fn should_roundtrip_with_bincode() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+
+        let values = vec![settings("a"), settings("b"), settings("a"), Box::new(5_u32)];
+        let serializer = DedupReflectSerializer::new(&values, &registry);
+        let (shared, refs) = serializer.dedup();
+        assert_eq!(3, shared.len());
+        assert_eq!(vec![0, 1, 0, 2], refs);
+
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let output = from_bincode(DedupReflectDeserializer::new(&registry), &bytes).unwrap();
+
+        assert_eq!(values.len(), output.len());
+        for (expected, actual) in values.iter().zip(&output) {
+            assert!(expected.reflect_partial_eq(&**actual).unwrap());
+        }
+        assert_eq!(
+            Settings {
+                color: [1.0, 0.5, 0.25],
+                name: "a".to_string(),
+            },
+            Settings::from_reflect(&*output[2]).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_dedup_values_by_structure() {
+        let registry = TypeRegistry::default();
+
+        // Dynamic values share their concrete counterpart, even with fields in another order
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_represented_type(Some(Settings::type_info()));
+        dynamic.insert("name", "a".to_string());
+        dynamic.insert("color", [1.0_f32, 0.5, 0.25]);
+
+        let values = vec![
+            settings("a"),
+            Box::new(dynamic),
+            settings("b"),
+            settings("a").clone_value(),
+            Box::new(-0.0_f32),
+            Box::new(0.0_f32),
+        ];
+        let (shared, refs) = DedupReflectSerializer::new(&values, &registry).dedup();
+        assert_eq!(3, shared.len());
+        assert_eq!(vec![0, 0, 1, 0, 2, 2], refs);
+    }
+
+    #[test]
+    fn should_roundtrip_with_dictionary() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        let dictionary = SchemaDictionary::from_registry(&registry);
+
+        let values = vec![settings("a"), settings("b"), settings("a")];
+        let plain = bincode::serialize(&DedupReflectSerializer::new(&values, &registry)).unwrap();
+        let bytes = bincode::serialize(
+            &DedupReflectSerializer::new(&values, &registry).with_dictionary(&dictionary),
+        )
+        .unwrap();
+        assert!(bytes.len() < plain.len());
+
+        let output = from_bincode(
+            DedupReflectDeserializer::new(&registry).with_dictionary(&dictionary),
+            &bytes,
+        )
+        .unwrap();
+        assert_eq!(values.len(), output.len());
+        for (expected, actual) in values.iter().zip(&output) {
+            assert!(expected.reflect_partial_eq(&**actual).unwrap());
+        }
+    }
+
+    #[test]
+    fn should_error_on_invalid_reference() {
+        let registry = TypeRegistry::default();
+        let input = r#"(shared: [{"u32": 1}], refs: [0, 1])"#;
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let result = DedupReflectDeserializer::new(&registry).deserialize(&mut deserializer);
+        assert!(result.is_err());
+    }
+}
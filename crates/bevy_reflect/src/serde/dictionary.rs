@@ -0,0 +1,381 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeInfo, TypeRegistry, VariantInfo};
+use bevy_utils::HashMap;
+use serde::de::{DeserializeSeed, Error as _, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeSeq, SerializeTuple};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt::{self, Formatter};
+
+/// A shared table of the identifiers found in the reflected schema of a [`TypeRegistry`]:
+/// type paths, field names and enum variant names.
+///
+/// Each identifier is given a small id, so that encoders can write the id
+/// instead of repeating the identifier, such as the [`DictionaryReflectSerializer`]
+/// which writes the type path of a value as an id.
+///
+/// Both ends must use the same dictionary.
+/// It can either be built from the same registry on both ends,
+/// or built once and shipped alongside the data, since it is itself serializable.
+/// Comparing [fingerprints](Self::fingerprint) is a cheap way to check that two dictionaries match,
+/// for instance when a client connects to a server.
+///
+/// Identifiers are sorted, so building a dictionary from two registries
+/// with the same registered types always gives the same ids.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaDictionary {
+    identifiers: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl SchemaDictionary {
+    /// Builds a dictionary from the types of the given registry.
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut identifiers = Vec::new();
+        for registration in registry.iter() {
+            let info = registration.type_info();
+            identifiers.push(info.type_path().to_string());
+            match info {
+                TypeInfo::Struct(info) => {
+                    identifiers.extend(info.iter().map(|field| field.name().to_string()));
+                }
+                TypeInfo::Enum(info) => {
+                    for variant in info.iter() {
+                        identifiers.push(variant.name().to_string());
+                        if let VariantInfo::Struct(variant) = variant {
+                            identifiers
+                                .extend(variant.iter().map(|field| field.name().to_string()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self::from_identifiers(identifiers)
+    }
+
+    /// Builds a dictionary from a list of identifiers.
+    ///
+    /// The identifiers are sorted and duplicates are removed.
+    pub fn from_identifiers(identifiers: impl IntoIterator<Item = String>) -> Self {
+        let mut identifiers: Vec<String> = identifiers.into_iter().collect();
+        identifiers.sort_unstable();
+        identifiers.dedup();
+        let ids = identifiers
+            .iter()
+            .enumerate()
+            .map(|(id, identifier)| (identifier.clone(), id as u32))
+            .collect();
+        Self { identifiers, ids }
+    }
+
+    /// Returns the id of the given identifier, if it is part of the dictionary.
+    pub fn id(&self, identifier: &str) -> Option<u32> {
+        self.ids.get(identifier).copied()
+    }
+
+    /// Returns the identifier with the given id, if any.
+    pub fn identifier(&self, id: u32) -> Option<&str> {
+        self.identifiers.get(id as usize).map(String::as_str)
+    }
+
+    /// Returns an iterator over the identifiers of the dictionary, in the order of their ids.
+    pub fn identifiers(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.identifiers.iter().map(String::as_str)
+    }
+
+    /// The number of identifiers of the dictionary.
+    pub fn len(&self) -> usize {
+        self.identifiers.len()
+    }
+
+    /// Returns `true` if the dictionary has no identifiers.
+    pub fn is_empty(&self) -> bool {
+        self.identifiers.is_empty()
+    }
+
+    /// Returns a hash of the identifiers of the dictionary.
+    ///
+    /// The hash is stable across builds and platforms,
+    /// so it can be persisted or sent over the network.
+    pub fn fingerprint(&self) -> u64 {
+        // 64-bit FNV-1a, which doesn't depend on the version of any hashing crate
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        for identifier in &self.identifiers {
+            // The terminator keeps `["ab", "c"]` and `["a", "bc"]` apart
+            for byte in identifier.bytes().chain([0]) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(PRIME);
+            }
+        }
+        hash
+    }
+}
+
+impl Serialize for SchemaDictionary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.identifiers.len()))?;
+        for identifier in &self.identifiers {
+            state.serialize_element(identifier)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SchemaDictionary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer).map(Self::from_identifiers)
+    }
+}
+
+/// A serializer for reflected values that writes the type path of the value
+/// as its id in a [`SchemaDictionary`].
+///
+/// This is a more compact alternative to [`ReflectSerializer`](crate::serde::ReflectSerializer),
+/// whose output starts with the full type path of the value.
+/// Within the value, field and variant names are written by the [`TypedReflectSerializer`],
+/// which binary formats already encode by index.
+///
+/// This is the serializer counterpart to [`DictionaryReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a tuple of the id of the type path,
+/// and the value serialized with [`TypedReflectSerializer`].
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{DictionaryReflectDeserializer, DictionaryReflectSerializer, SchemaDictionary}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::empty();
+/// registry.register::<Health>();
+/// let dictionary = SchemaDictionary::from_registry(&registry);
+///
+/// let serializer = DictionaryReflectSerializer::new(&Health(100), &registry, &dictionary);
+/// let output = ron::to_string(&serializer).unwrap();
+/// assert_eq!(output, "(0,(100))");
+///
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let value = DictionaryReflectDeserializer::new(&registry, &dictionary)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Health(100), Health::from_reflect(&*value).unwrap());
+/// ```
+pub struct DictionaryReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+    pub dictionary: &'a SchemaDictionary,
+}
+
+impl<'a> DictionaryReflectSerializer<'a> {
+    pub fn new(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        dictionary: &'a SchemaDictionary,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            dictionary,
+        }
+    }
+}
+
+impl<'a> Serialize for DictionaryReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_path = self
+            .value
+            .get_represented_type_info()
+            .ok_or_else(|| {
+                S::Error::custom(format_args!(
+                    "cannot get type info for {}",
+                    self.value.reflect_type_path()
+                ))
+            })?
+            .type_path();
+        let id = self.dictionary.id(type_path).ok_or_else(|| {
+            S::Error::custom(format_args!(
+                "type `{type_path}` is not part of the schema dictionary"
+            ))
+        })?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&id)?;
+        state.serialize_element(&TypedReflectSerializer::new(self.value, self.registry))?;
+        state.end()
+    }
+}
+
+/// A deserializer for reflected values written by [`DictionaryReflectSerializer`].
+///
+/// Like the [`ReflectDeserializer`](crate::serde::ReflectDeserializer),
+/// this returns a [`Box<dyn Reflect>`] which may contain a dynamic representation of the value.
+pub struct DictionaryReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    dictionary: &'a SchemaDictionary,
+}
+
+impl<'a> DictionaryReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry, dictionary: &'a SchemaDictionary) -> Self {
+        Self {
+            registry,
+            dictionary,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DictionaryReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DictionaryReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a type id and a reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let id = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let type_path = self.dictionary.identifier(id).ok_or_else(|| {
+            A::Error::custom(format_args!(
+                "no identifier with id {id} in the schema dictionary (found {} identifiers)",
+                self.dictionary.len()
+            ))
+        })?;
+        let registration = self.registry.get_with_type_path(type_path).ok_or_else(|| {
+            A::Error::custom(format_args!("no registration found for `{type_path}`"))
+        })?;
+        seq.next_element_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DictionaryReflectDeserializer, DictionaryReflectSerializer, SchemaDictionary};
+    use crate::serde::{from_bincode, ReflectSerializer};
+    use crate::{self as bevy_reflect, FromReflect, Reflect, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Unit {
+        name: String,
+        stance: Stance,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Stance {
+        Idle,
+        Moving { speed: f32 },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Unit>();
+        registry
+    }
+
+    #[test]
+    fn should_collect_schema_identifiers() {
+        let dictionary = SchemaDictionary::from_registry(&get_registry());
+        for identifier in [
+            std::any::type_name::<Unit>(),
+            std::any::type_name::<Stance>(),
+            "name",
+            "stance",
+            "Idle",
+            "Moving",
+            "speed",
+        ] {
+            assert!(dictionary.id(identifier).is_some(), "{identifier}");
+        }
+
+        let id = dictionary.id("speed").unwrap();
+        assert_eq!(Some("speed"), dictionary.identifier(id));
+        assert_eq!(
+            dictionary.fingerprint(),
+            SchemaDictionary::from_registry(&get_registry()).fingerprint()
+        );
+        assert_ne!(
+            dictionary.fingerprint(),
+            SchemaDictionary::from_registry(&TypeRegistry::default()).fingerprint()
+        );
+
+        let output = ron::to_string(&dictionary).unwrap();
+        assert_eq!(dictionary, ron::from_str(&output).unwrap());
+    }
+
+    #[test]
+    fn should_roundtrip_with_bincode() {
+        let registry = get_registry();
+        let dictionary = SchemaDictionary::from_registry(&registry);
+        let unit = Unit {
+            name: String::from("scout"),
+            stance: Stance::Moving { speed: 2.5 },
+        };
+
+        let bytes = bincode::serialize(&DictionaryReflectSerializer::new(
+            &unit,
+            &registry,
+            &dictionary,
+        ))
+        .unwrap();
+        let plain = bincode::serialize(&ReflectSerializer::new(&unit, &registry)).unwrap();
+        assert!(bytes.len() < plain.len());
+
+        let value = from_bincode(
+            DictionaryReflectDeserializer::new(&registry, &dictionary),
+            &bytes,
+        )
+        .unwrap();
+        assert_eq!(unit, Unit::from_reflect(&*value).unwrap());
+    }
+
+    #[test]
+    fn should_error_on_unknown_types() {
+        let registry = get_registry();
+        let dictionary = SchemaDictionary::default();
+        let result = bincode::serialize(&DictionaryReflectSerializer::new(
+            &Stance::Idle,
+            &registry,
+            &dictionary,
+        ));
+        assert!(result.is_err());
+
+        let bytes = bincode::serialize(&(7_u32, ())).unwrap();
+        let result = from_bincode(
+            DictionaryReflectDeserializer::new(&registry, &dictionary),
+            &bytes,
+        );
+        assert!(result.is_err());
+    }
+}
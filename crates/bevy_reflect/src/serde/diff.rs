@@ -0,0 +1,622 @@
+use crate::serde::{ReflectDeserializer, ReflectSerializer};
+use crate::{Diff, ListChange, MapChange, TypeRegistry};
+use serde::de::{
+    DeserializeSeed, EnumAccess, Error as _, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::ser::{SerializeMap, SerializeSeq, SerializeTupleVariant};
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+const DIFF: &str = "Diff";
+const DIFF_VARIANTS: &[&str] = &["NoChange", "Replace", "Struct", "Indexed", "List", "Map"];
+const LIST_CHANGE: &str = "ListChange";
+const MAP_CHANGE: &str = "MapChange";
+const CHANGE_VARIANTS: &[&str] = &["Modify", "Insert", "Remove"];
+
+/// A serializer for a [`Diff`], allowing it to be stored or sent elsewhere as a patch.
+///
+/// Values stored in the diff, such as replaced values, inserted elements and map keys,
+/// are serialized with a [`ReflectSerializer`], so each records its own type path.
+/// The resulting patch can be read back with [`DiffDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{apply_patch, diff, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{DiffDeserializer, DiffSerializer};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let old = Health { current: 10, max: 10 };
+/// let new = Health { current: 7, max: 10 };
+/// let patch = diff(&old, &new);
+///
+/// let output = ron::to_string(&DiffSerializer::new(&patch, &registry)).unwrap();
+/// assert_eq!(r#"Struct({"current":Replace({"u32":7})})"#, output);
+///
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let patch = DiffDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let mut value = old.clone();
+/// apply_patch(&mut value, &patch).unwrap();
+/// assert_eq!(new, value);
+/// ```
+pub struct DiffSerializer<'a> {
+    pub diff: &'a Diff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffSerializer<'a> {
+    pub fn new(diff: &'a Diff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl<'a> Serialize for DiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        match self.diff {
+            Diff::NoChange => serializer.serialize_unit_variant(DIFF, 0, DIFF_VARIANTS[0]),
+            Diff::Replace(value) => serializer.serialize_newtype_variant(
+                DIFF,
+                1,
+                DIFF_VARIANTS[1],
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            Diff::Struct(fields) => serializer.serialize_newtype_variant(
+                DIFF,
+                2,
+                DIFF_VARIANTS[2],
+                &FieldDiffsSerializer { fields, registry },
+            ),
+            Diff::Indexed(fields) => serializer.serialize_newtype_variant(
+                DIFF,
+                3,
+                DIFF_VARIANTS[3],
+                &seq_of(fields, |(index, diff)| {
+                    (index, DiffSerializer::new(diff, registry))
+                }),
+            ),
+            Diff::List(changes) => serializer.serialize_newtype_variant(
+                DIFF,
+                4,
+                DIFF_VARIANTS[4],
+                &seq_of(changes, |change| ListChangeSerializer { change, registry }),
+            ),
+            Diff::Map(changes) => serializer.serialize_newtype_variant(
+                DIFF,
+                5,
+                DIFF_VARIANTS[5],
+                &seq_of(changes, |change| MapChangeSerializer { change, registry }),
+            ),
+        }
+    }
+}
+
+/// Serializes each item of a slice as a sequence, after mapping it to a serializable value.
+struct SeqSerializer<'a, T, F>(&'a [T], F);
+
+fn seq_of<'a, T, F, U>(items: &'a [T], map: F) -> SeqSerializer<'a, T, F>
+where
+    F: Fn(&'a T) -> U,
+    U: Serialize,
+{
+    SeqSerializer(items, map)
+}
+
+impl<'a, T, F, U> Serialize for SeqSerializer<'a, T, F>
+where
+    F: Fn(&'a T) -> U,
+    U: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0 {
+            seq.serialize_element(&(self.1)(item))?;
+        }
+        seq.end()
+    }
+}
+
+/// Serializes the changed fields of a struct as a map of field names to diffs.
+struct FieldDiffsSerializer<'a> {
+    fields: &'a [(String, Diff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for FieldDiffsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (name, diff) in self.fields {
+            map.serialize_entry(name, &DiffSerializer::new(diff, self.registry))?;
+        }
+        map.end()
+    }
+}
+
+struct ListChangeSerializer<'a> {
+    change: &'a ListChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            ListChange::Modify(index, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(LIST_CHANGE, 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+            ListChange::Insert(index, value) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(LIST_CHANGE, 1, CHANGE_VARIANTS[1], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            ListChange::Remove(index) => {
+                serializer.serialize_newtype_variant(LIST_CHANGE, 2, CHANGE_VARIANTS[2], index)
+            }
+        }
+    }
+}
+
+struct MapChangeSerializer<'a> {
+    change: &'a MapChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            MapChange::Modify(key, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(MAP_CHANGE, 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+            MapChange::Insert(key, value) => {
+                let mut state =
+                    serializer.serialize_tuple_variant(MAP_CHANGE, 1, CHANGE_VARIANTS[1], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            MapChange::Remove(key) => serializer.serialize_newtype_variant(
+                MAP_CHANGE,
+                2,
+                CHANGE_VARIANTS[2],
+                &ReflectSerializer::new(key.as_ref(), self.registry),
+            ),
+        }
+    }
+}
+
+/// A deserializer for a [`Diff`] written by a [`DiffSerializer`].
+///
+/// Values stored in the diff are read with a [`ReflectDeserializer`],
+/// so their types must be registered in the given [`TypeRegistry`].
+/// Like with [`ReflectDeserializer`], these values may be dynamic types,
+/// which [`Diff::apply`] handles.
+#[derive(Clone, Copy)]
+pub struct DiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(DIFF, DIFF_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a reflection diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let (index, variant) = data.variant_seed(VariantIdentifier(DIFF_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                variant.unit_variant()?;
+                Diff::NoChange
+            }
+            1 => Diff::Replace(variant.newtype_variant_seed(ReflectDeserializer::new(registry))?),
+            2 => Diff::Struct(variant.newtype_variant_seed(FieldDiffsDeserializer(self))?),
+            3 => Diff::Indexed(
+                variant
+                    .newtype_variant_seed(SeqDeserializer(PairDeserializer(PhantomData, self)))?,
+            ),
+            4 => Diff::List(
+                variant.newtype_variant_seed(SeqDeserializer(ListChangeDeserializer(self)))?,
+            ),
+            _ => Diff::Map(
+                variant.newtype_variant_seed(SeqDeserializer(MapChangeDeserializer(self)))?,
+            ),
+        })
+    }
+}
+
+/// Deserializes the name or index of an enum variant into its index.
+#[derive(Clone, Copy)]
+struct VariantIdentifier(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}", self.0)
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        usize::try_from(index)
+            .ok()
+            .filter(|&index| index < self.0.len())
+            .ok_or_else(|| E::custom(format_args!("no variant found at index `{index}`")))
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.0
+            .iter()
+            .position(|&variant| variant == name)
+            .ok_or_else(|| E::unknown_variant(name, self.0))
+    }
+}
+
+/// Deserializes a sequence, using a copy of the same seed for each element.
+struct SeqDeserializer<T>(T);
+
+impl<'de, T: DeserializeSeed<'de> + Copy> DeserializeSeed<'de> for SeqDeserializer<T> {
+    type Value = Vec<T::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: DeserializeSeed<'de> + Copy> Visitor<'de> for SeqDeserializer<T> {
+    type Value = Vec<T::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of changes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a pair of values, either as a tuple or as the fields of a tuple variant.
+#[derive(Clone, Copy)]
+struct PairDeserializer<A, B>(A, B);
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> DeserializeSeed<'de>
+    for PairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de>
+    for PairDeserializer<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a pair of values")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| S::Error::invalid_length(0, &"a pair of values"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| S::Error::invalid_length(1, &"a pair of values"))?;
+        Ok((first, second))
+    }
+}
+
+/// Deserializes the changed fields of a struct from a map of field names to diffs.
+struct FieldDiffsDeserializer<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldDiffsDeserializer<'a> {
+    type Value = Vec<(String, Diff)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for FieldDiffsDeserializer<'a> {
+    type Value = Vec<(String, Diff)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of field names to diffs")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(name) = map.next_key::<String>()? {
+            fields.push((name, map.next_value_seed(self.0)?));
+        }
+        Ok(fields)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListChangeDeserializer<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(LIST_CHANGE, CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a change to a list element")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant_seed(VariantIdentifier(CHANGE_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (index, diff) =
+                    variant.tuple_variant(2, PairDeserializer(PhantomData, self.0))?;
+                ListChange::Modify(index, diff)
+            }
+            1 => {
+                let values =
+                    PairDeserializer(PhantomData, ReflectDeserializer::new(self.0.registry));
+                let (index, value) = variant.tuple_variant(2, values)?;
+                ListChange::Insert(index, value)
+            }
+            _ => ListChange::Remove(variant.newtype_variant()?),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapChangeDeserializer<'a>(DiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(MAP_CHANGE, CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a change to a map entry")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.0.registry;
+        let (index, variant) = data.variant_seed(VariantIdentifier(CHANGE_VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (key, diff) = variant.tuple_variant(
+                    2,
+                    PairDeserializer(ReflectDeserializer::new(registry), self.0),
+                )?;
+                MapChange::Modify(key, diff)
+            }
+            1 => {
+                let entry = PairDeserializer(
+                    ReflectDeserializer::new(registry),
+                    ReflectDeserializer::new(registry),
+                );
+                let (key, value) = variant.tuple_variant(2, entry)?;
+                MapChange::Insert(key, value)
+            }
+            _ => {
+                MapChange::Remove(variant.newtype_variant_seed(ReflectDeserializer::new(registry))?)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffDeserializer, DiffSerializer};
+    use crate::serde::from_bincode;
+    use crate::{self as bevy_reflect, apply_patch, diff, Diff, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Stance {
+        Idle,
+        Guarding(f32),
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Unit {
+        position: (f32, f32),
+        inventory: Vec<String>,
+        stats: HashMap<String, i32>,
+        stance: Stance,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Unit>();
+        registry
+    }
+
+    fn values() -> (Unit, Unit) {
+        let old = Unit {
+            position: (0.0, 0.0),
+            inventory: vec!["sword".to_string(), "shield".to_string()],
+            stats: [("hp".to_string(), 10), ("mp".to_string(), 3)]
+                .into_iter()
+                .collect(),
+            stance: Stance::Idle,
+        };
+        let new = Unit {
+            position: (0.0, 2.5),
+            inventory: vec!["bow".to_string(), "shield".to_string(), "arrow".to_string()],
+            stats: [("hp".to_string(), 8), ("xp".to_string(), 1)]
+                .into_iter()
+                .collect(),
+            stance: Stance::Guarding(0.5),
+        };
+        (old, new)
+    }
+
+    #[test]
+    fn should_roundtrip_patch_with_ron() {
+        let registry = get_registry();
+        let (old, new) = values();
+
+        let patch = diff(&old, &new);
+        let output = ron::to_string(&DiffSerializer::new(&patch, &registry)).unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let patch = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = old.clone();
+        apply_patch(&mut value, &patch).unwrap();
+        assert_eq!(new, value);
+    }
+
+    #[test]
+    fn should_roundtrip_patch_with_bincode() {
+        let registry = get_registry();
+        let (old, new) = values();
+
+        let patch = diff(&old, &new);
+        let bytes = bincode::serialize(&DiffSerializer::new(&patch, &registry)).unwrap();
+        let patch = from_bincode(DiffDeserializer::new(&registry), &bytes).unwrap();
+
+        let mut value = old.clone();
+        apply_patch(&mut value, &patch).unwrap();
+        assert_eq!(new, value);
+    }
+
+    #[test]
+    fn should_roundtrip_empty_patch() {
+        let registry = get_registry();
+        let (old, _) = values();
+
+        let patch = diff(&old, &old);
+        let output = ron::to_string(&DiffSerializer::new(&patch, &registry)).unwrap();
+        assert_eq!("NoChange", output);
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let patch = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert!(matches!(patch, Diff::NoChange));
+    }
+}
@@ -0,0 +1,535 @@
+use crate::serde::de::{get_registration, Ident};
+use crate::serde::{
+    ReflectDeserializeWithRegistry, ReflectMigrate, SerializationData, TypedReflectDeserializer,
+};
+use crate::{
+    try_apply_with, ApplyStrategy, Array, List, ListInfo, Map, MapInfo, Reflect,
+    ReflectApplyStrategy, ReflectDeserialize, ReflectMut, Struct, StructInfo, Tuple, TupleStruct,
+    TypeInfo, TypeRegistration, TypeRegistry,
+};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use std::fmt::{self, Formatter};
+
+/// A deserializer which writes its data directly into an existing value.
+///
+/// Unlike the [`TypedReflectDeserializer`], which builds a new dynamic value
+/// that then has to be applied or converted, this walks the existing value and deserializes
+/// each field into it, so large values such as scenes don't need to be allocated twice.
+///
+/// The data is applied with the same semantics as [`try_apply_with`]:
+/// * fields of structs which are missing from the input keep their current value
+/// * elements of lists are applied according to the list's registered [`ApplyStrategy`]
+/// * entries of maps are updated or inserted, and entries missing from the input are kept
+///
+/// Enums, values and types with custom deserialization, such as [`ReflectDeserialize`],
+/// are deserialized as a whole and then applied.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::InPlaceDeserializer;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Light {
+///     intensity: f32,
+///     range: f32,
+///     shadows: bool,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Light>();
+///
+/// let mut light = Light {
+///     intensity: 800.0,
+///     range: 20.0,
+///     shadows: false,
+/// };
+///
+/// let mut deserializer = ron::Deserializer::from_str("(intensity: 1000.0, shadows: true)").unwrap();
+/// InPlaceDeserializer::new(&mut light, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// assert_eq!(
+///     Light {
+///         intensity: 1000.0,
+///         range: 20.0,
+///         shadows: true,
+///     },
+///     light
+/// );
+/// ```
+///
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+pub struct InPlaceDeserializer<'a> {
+    target: &'a mut dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> InPlaceDeserializer<'a> {
+    pub fn new(target: &'a mut dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { target, registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for InPlaceDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let registry = self.registry;
+        let info = self.target.get_represented_type_info().ok_or_else(|| {
+            D::Error::custom(format_args!(
+                "cannot get type info for `{}`",
+                self.target.reflect_type_path()
+            ))
+        })?;
+        let registration = get_registration(info.type_id(), info.type_path(), registry)?;
+
+        // Types which control their own deserialization can only be deserialized as a whole,
+        // as can enums, whose variant isn't known until it has been read,
+        // and byte arrays and lists, which have their own representation
+        if registration
+            .data::<ReflectDeserializeWithRegistry>()
+            .is_some()
+            || registration.data::<ReflectDeserialize>().is_some()
+            || registration.data::<ReflectMigrate>().is_some()
+            || matches!(info, TypeInfo::Enum(_) | TypeInfo::Value(_))
+            || matches!(info, TypeInfo::Array(info) if info.item_is::<u8>())
+            || matches!(info, TypeInfo::List(info) if info.item_is::<u8>())
+        {
+            return deserialize_and_apply(self.target, registration, registry, deserializer);
+        }
+
+        let serialization_data = registration.data::<SerializationData>();
+        match (self.target.reflect_mut(), info) {
+            (ReflectMut::Struct(target), TypeInfo::Struct(info)) => deserializer
+                .deserialize_struct(
+                    info.type_path_table().ident().unwrap(),
+                    info.field_names(),
+                    StructVisitor {
+                        target,
+                        info,
+                        serialization_data,
+                        registry,
+                    },
+                ),
+            (ReflectMut::TupleStruct(target), TypeInfo::TupleStruct(info)) => deserializer
+                .deserialize_tuple_struct(
+                    info.type_path_table().ident().unwrap(),
+                    info.field_len(),
+                    TupleVisitor {
+                        target: TupleTarget::TupleStruct(target),
+                        len: info.field_len(),
+                        serialization_data,
+                        registry,
+                    },
+                ),
+            (ReflectMut::Tuple(target), TypeInfo::Tuple(info)) => deserializer.deserialize_tuple(
+                info.field_len(),
+                TupleVisitor {
+                    target: TupleTarget::Tuple(target),
+                    len: info.field_len(),
+                    serialization_data,
+                    registry,
+                },
+            ),
+            (ReflectMut::Array(target), TypeInfo::Array(info)) => deserializer.deserialize_tuple(
+                info.capacity(),
+                TupleVisitor {
+                    target: TupleTarget::Array(target),
+                    len: info.capacity(),
+                    serialization_data: None,
+                    registry,
+                },
+            ),
+            (ReflectMut::List(target), TypeInfo::List(info)) => {
+                let strategy = registration
+                    .data::<ReflectApplyStrategy>()
+                    .map(|data| data.0)
+                    .unwrap_or_default();
+                deserializer.deserialize_seq(ListVisitor {
+                    target,
+                    info,
+                    strategy,
+                    registry,
+                })
+            }
+            (ReflectMut::Map(target), TypeInfo::Map(info)) => {
+                deserializer.deserialize_map(MapVisitor {
+                    target,
+                    info,
+                    registry,
+                })
+            }
+            _ => Err(D::Error::custom(format_args!(
+                "the kind of `{}` does not match its type info",
+                info.type_path()
+            ))),
+        }
+    }
+}
+
+/// Deserializes a new value of the type described by `registration` and applies it to `target`.
+fn deserialize_and_apply<'de, D>(
+    target: &mut dyn Reflect,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = TypedReflectDeserializer::new(registration, registry).deserialize(deserializer)?;
+    try_apply_with(target, value.as_ref(), registry).map_err(Error::custom)
+}
+
+struct StructVisitor<'a> {
+    target: &'a mut dyn Struct,
+    info: &'static StructInfo,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected struct value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        for (index, field) in self.info.iter().enumerate() {
+            if self
+                .serialization_data
+                .is_some_and(|data| data.is_field_skipped(index))
+            {
+                continue;
+            }
+            let target = self.target.field_mut(field.name()).ok_or_else(|| {
+                A::Error::custom(format_args!("missing field `{}` in target", field.name()))
+            })?;
+            seq.next_element_seed(InPlaceDeserializer::new(target, self.registry))?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+        Ok(())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            let target = self.target.field_mut(&key).ok_or_else(|| {
+                A::Error::custom(format_args!(
+                    "unknown field `{key}` for struct `{}`",
+                    self.info.type_path()
+                ))
+            })?;
+            map.next_value_seed(InPlaceDeserializer::new(target, self.registry))?;
+        }
+        Ok(())
+    }
+}
+
+/// A value whose fields are accessed by index.
+enum TupleTarget<'a> {
+    TupleStruct(&'a mut dyn TupleStruct),
+    Tuple(&'a mut dyn Tuple),
+    Array(&'a mut dyn Array),
+}
+
+impl<'a> TupleTarget<'a> {
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match self {
+            TupleTarget::TupleStruct(target) => target.field_mut(index),
+            TupleTarget::Tuple(target) => target.field_mut(index),
+            TupleTarget::Array(target) => target.get_mut(index),
+        }
+    }
+}
+
+struct TupleVisitor<'a> {
+    target: TupleTarget<'a>,
+    len: usize,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected tuple value")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        for index in 0..self.len {
+            if self
+                .serialization_data
+                .is_some_and(|data| data.is_field_skipped(index))
+            {
+                continue;
+            }
+            let target = self
+                .target
+                .field_mut(index)
+                .ok_or_else(|| A::Error::custom(format_args!("missing field {index} in target")))?;
+            seq.next_element_seed(InPlaceDeserializer::new(target, self.registry))?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+        Ok(())
+    }
+}
+
+struct ListVisitor<'a> {
+    target: &'a mut dyn List,
+    info: &'static ListInfo,
+    strategy: ApplyStrategy,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected list value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        if self.strategy == ApplyStrategy::Replace {
+            while self.target.pop().is_some() {}
+        }
+
+        let mut len = 0;
+        loop {
+            let done = match self.target.get_mut(len) {
+                Some(target) => seq
+                    .next_element_seed(InPlaceDeserializer::new(target, self.registry))?
+                    .is_none(),
+                None => {
+                    let registration = get_registration(
+                        self.info.item_type_id(),
+                        self.info.item_type_path_table().path(),
+                        self.registry,
+                    )?;
+                    match seq.next_element_seed(TypedReflectDeserializer::new(
+                        registration,
+                        self.registry,
+                    ))? {
+                        Some(value) => {
+                            self.target.push(value);
+                            false
+                        }
+                        None => true,
+                    }
+                }
+            };
+            if done {
+                break;
+            }
+            len += 1;
+        }
+
+        if self.strategy == ApplyStrategy::Truncate {
+            while self.target.len() > len {
+                self.target.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+struct MapVisitor<'a> {
+    target: &'a mut dyn Map,
+    info: &'static MapInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected map value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let key_registration = get_registration(
+            self.info.key_type_id(),
+            self.info.key_type_path_table().path(),
+            self.registry,
+        )?;
+        let value_registration = get_registration(
+            self.info.value_type_id(),
+            self.info.value_type_path_table().path(),
+            self.registry,
+        )?;
+
+        while let Some(key) = map.next_key_seed(TypedReflectDeserializer::new(
+            key_registration,
+            self.registry,
+        ))? {
+            match self.target.get_mut(key.as_ref()) {
+                Some(target) => {
+                    map.next_value_seed(InPlaceDeserializer::new(target, self.registry))?;
+                }
+                None => {
+                    let value = map.next_value_seed(TypedReflectDeserializer::new(
+                        value_registration,
+                        self.registry,
+                    ))?;
+                    self.target.insert_boxed(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Scene {
+        name: String,
+        entities: Vec<Entity>,
+        lookup: HashMap<String, u32>,
+        bounds: (f32, f32),
+        ids: [u16; 3],
+        #[reflect(skip_serializing)]
+        loaded: bool,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Entity {
+        position: Position,
+        kind: Kind,
+        parent: Option<u32>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Kind {
+        Light,
+        Mesh { path: String },
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            name: String::from("level"),
+            entities: vec![
+                Entity {
+                    position: Position(0.0, 0.0),
+                    kind: Kind::Light,
+                    parent: None,
+                },
+                Entity {
+                    position: Position(1.0, 2.0),
+                    kind: Kind::Light,
+                    parent: Some(0),
+                },
+            ],
+            lookup: HashMap::from([(String::from("sun"), 0)]),
+            bounds: (10.0, 10.0),
+            ids: [1, 2, 3],
+            loaded: true,
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Scene>();
+        registry
+    }
+
+    fn deserialize_into(target: &mut dyn Reflect, input: &str, registry: &TypeRegistry) {
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        InPlaceDeserializer::new(target, registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+    }
+
+    #[test]
+    fn should_deserialize_in_place() {
+        let registry = get_registry();
+        let mut value = scene();
+
+        deserialize_into(
+            &mut value,
+            r#"(
+                entities: [
+                    (position: (5.0, 6.0)),
+                    (kind: Mesh(path: "tree.glb")),
+                    (position: (7.0, 8.0), kind: Light, parent: Some(1)),
+                ],
+                lookup: {"sun": 4, "tree": 1},
+                bounds: (20.0, 5.0),
+                ids: (4, 5, 6),
+            )"#,
+            &registry,
+        );
+
+        let mut expected = scene();
+        expected.entities[0].position = Position(5.0, 6.0);
+        expected.entities[1].kind = Kind::Mesh {
+            path: String::from("tree.glb"),
+        };
+        expected.entities.push(Entity {
+            position: Position(7.0, 8.0),
+            kind: Kind::Light,
+            parent: Some(1),
+        });
+        expected.lookup = HashMap::from([(String::from("sun"), 4), (String::from("tree"), 1)]);
+        expected.bounds = (20.0, 5.0);
+        expected.ids = [4, 5, 6];
+        assert_eq!(expected, value);
+    }
+
+    #[test]
+    fn should_apply_list_strategy_in_place() {
+        let mut registry = get_registry();
+        registry
+            .get_mut(std::any::TypeId::of::<Vec<Entity>>())
+            .unwrap()
+            .insert(ReflectApplyStrategy(ApplyStrategy::Truncate));
+        let mut value = scene();
+
+        deserialize_into(&mut value, "(entities: [(parent: Some(3))])", &registry);
+
+        let mut expected = scene();
+        expected.entities.truncate(1);
+        expected.entities[0].parent = Some(3);
+        assert_eq!(expected, value);
+    }
+
+    #[test]
+    fn should_reject_unknown_fields_in_place() {
+        let registry = get_registry();
+        let mut value = scene();
+
+        let mut deserializer = ron::Deserializer::from_str("(name: \"x\", size: 3)").unwrap();
+        assert!(InPlaceDeserializer::new(&mut value, &registry)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+
+/// Converts a reflected value to a [`serde_json::Value`].
+///
+/// Only the data of the value is converted, as with the [`TypedReflectSerializer`],
+/// so this avoids the round-trip through a JSON string when working with JSON in memory.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use serde_json::json;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_json_value, to_json_value};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Camera {
+///     fov: f32,
+///     target: Option<u32>,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Camera>();
+///
+/// let camera = Camera { fov: 90.0, target: None };
+/// let value = to_json_value(&camera, &registry).unwrap();
+/// assert_eq!(json!({ "fov": 90.0, "target": null }), value);
+///
+/// let registration = registry.get(TypeId::of::<Camera>()).unwrap();
+/// let output = from_json_value(value, registration, &registry).unwrap();
+/// assert_eq!(camera, Camera::from_reflect(&*output).unwrap());
+/// ```
+pub fn to_json_value(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(TypedReflectSerializer::new(value, registry))
+}
+
+/// Converts a [`serde_json::Value`] to a reflected value of the type described by `registration`.
+///
+/// Like the [`TypedReflectDeserializer`], this returns the dynamic equivalent of the value,
+/// unless its type registers [`ReflectDeserialize`].
+///
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+pub fn from_json_value(
+    value: Value,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, serde_json::Error> {
+    TypedReflectDeserializer::new(registration, registry).deserialize(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use bevy_utils::HashMap;
+    use serde_json::json;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Level {
+        name: String,
+        spawns: HashMap<u32, Spawn>,
+        checksum: [u8; 2],
+        music: Option<String>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Spawn {
+        Player,
+        Enemy { count: u8 },
+        Pickup(String),
+    }
+
+    fn level() -> Level {
+        Level {
+            name: String::from("intro"),
+            spawns: HashMap::from([
+                (0, Spawn::Player),
+                (1, Spawn::Enemy { count: 3 }),
+                (2, Spawn::Pickup(String::from("key"))),
+            ]),
+            checksum: [0xab, 0x01],
+            music: Some(String::from("calm.ogg")),
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Level>();
+        registry
+    }
+
+    #[test]
+    fn should_convert_to_json_value() {
+        let registry = get_registry();
+
+        let value = to_json_value(&level(), &registry).unwrap();
+        assert_eq!(
+            json!({
+                "name": "intro",
+                "spawns": {
+                    "0": "Player",
+                    "1": { "Enemy": { "count": 3 } },
+                    "2": { "Pickup": "key" },
+                },
+                "checksum": [171, 1],
+                "music": "calm.ogg",
+            }),
+            value
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_json_value() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Level>()).unwrap();
+
+        let value = to_json_value(&level(), &registry).unwrap();
+        let output = from_json_value(value, registration, &registry).unwrap();
+        assert_eq!(level(), Level::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_fail_on_mismatched_json_value() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Level>()).unwrap();
+
+        let value = json!({ "name": 5 });
+        assert!(from_json_value(value, registration, &registry).is_err());
+    }
+}
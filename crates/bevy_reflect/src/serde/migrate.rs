@@ -0,0 +1,513 @@
+use crate::serde::de::{get_registration, Ident};
+use crate::serde::{SerializationData, TypedReflectDeserializer};
+use crate::{
+    DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple, FromType, Map, Reflect,
+    Struct, StructInfo, TypeRegistry,
+};
+use bevy_utils::{NoOpHash, TypeIdMap};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use std::fmt::{self, Formatter};
+use std::sync::{PoisonError, RwLock};
+
+/// The name of the field holding the schema version of structs with [`ReflectMigrate`] type data.
+pub const VERSION_FIELD: &str = "__version";
+
+/// A type whose serialized data can be migrated from older versions of the type.
+///
+/// See [`ReflectMigrate`] for how versions are written and migrations are run.
+pub trait Migrate {
+    /// The current schema version of the type.
+    ///
+    /// This should be incremented whenever the serialized form of the type changes,
+    /// and should start at 1, since data written without a version is read as version 0.
+    const VERSION: u32;
+
+    /// Converts the fields of a struct written with `old_version` to the fields of the current version.
+    fn migrate(old_version: u32, value: DynamicStruct) -> DynamicStruct;
+}
+
+/// Type data for structs whose serialized data is versioned.
+///
+/// The [`TypedReflectSerializer`] writes the current version of such structs in an extra
+/// [`VERSION_FIELD`], before their fields.
+/// When the [`TypedReflectDeserializer`] reads data with an older version,
+/// it passes the deserialized fields to the migration function before building the struct,
+/// so that data written before a refactor can still be loaded.
+///
+/// Fields which the struct still has are deserialized with their current type,
+/// so a field whose type changes should also be renamed.
+/// Fields of older versions that the struct no longer has are deserialized without type information,
+/// as [`bool`], [`i64`], [`u64`], [`f64`], [`String`], [`DynamicList`], [`DynamicStruct`] or [`DynamicMap`] values,
+/// so they can only be read from self-describing formats.
+/// Formats which write structs as sequences, such as `bincode`, can only read the current version.
+///
+/// A [`ReflectMigrate`] for type `T` can be obtained via [`FromType::from_type`],
+/// by implementing [`Migrate`] and using `#[reflect(Migrate)]`.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{DynamicStruct, FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{Migrate, ReflectMigrate, TypedReflectDeserializer};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[reflect(Migrate)]
+/// struct Player {
+///     health: f32,
+/// }
+///
+/// impl Migrate for Player {
+///     const VERSION: u32 = 2;
+///
+///     fn migrate(old_version: u32, mut value: DynamicStruct) -> DynamicStruct {
+///         if old_version < 2 {
+///             // Version 1 stored the health as an integer named `hp`
+///             let hp = value.remove("hp").unwrap();
+///             value.insert("health", *hp.downcast_ref::<u64>().unwrap() as f32);
+///         }
+///         value
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// let registration = registry.get(TypeId::of::<Player>()).unwrap();
+///
+/// let mut deserializer = ron::Deserializer::from_str("(__version: 1, hp: 80)").unwrap();
+/// let output = TypedReflectDeserializer::new(registration, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Player { health: 80.0 }, Player::from_reflect(&*output).unwrap());
+/// ```
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+#[derive(Clone)]
+pub struct ReflectMigrate {
+    version: u32,
+    migrate: fn(u32, DynamicStruct) -> DynamicStruct,
+}
+
+impl ReflectMigrate {
+    /// Creates a [`ReflectMigrate`] from the current version of a type and its migration function.
+    ///
+    /// Usually, a [`ReflectMigrate`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(version: u32, migrate: fn(u32, DynamicStruct) -> DynamicStruct) -> Self {
+        Self { version, migrate }
+    }
+
+    /// Returns the current schema version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Converts the fields of a struct written with `old_version` to the fields of the current version.
+    pub fn migrate(&self, old_version: u32, value: DynamicStruct) -> DynamicStruct {
+        (self.migrate)(old_version, value)
+    }
+}
+
+impl<T: Migrate> FromType<T> for ReflectMigrate {
+    fn from_type() -> Self {
+        ReflectMigrate::new(T::VERSION, T::migrate)
+    }
+}
+
+/// Returns the field names of a struct with [`ReflectMigrate`] type data, as written by the serializer:
+/// the [`VERSION_FIELD`] followed by the fields of the struct.
+///
+/// Formats which write structs as sequences rely on this list to know how many elements to read.
+pub(super) fn versioned_field_names(info: &'static StructInfo) -> &'static [&'static str] {
+    static FIELD_NAMES: RwLock<TypeIdMap<&'static [&'static str]>> =
+        RwLock::new(TypeIdMap::with_hasher(NoOpHash));
+
+    if let Some(names) = FIELD_NAMES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&info.type_id())
+    {
+        return names;
+    }
+
+    let mut field_names = FIELD_NAMES.write().unwrap_or_else(PoisonError::into_inner);
+    field_names.entry(info.type_id()).or_insert_with(|| {
+        let names = std::iter::once(VERSION_FIELD)
+            .chain(info.field_names().iter().copied())
+            .collect::<Vec<_>>();
+        // Leaked once per type, like the type info it is built from
+        Box::leak(names.into_boxed_slice())
+    })
+}
+
+/// Deserializes the fields of a struct with [`ReflectMigrate`] type data,
+/// migrating them if they were written by an older version.
+pub(super) fn visit_versioned_struct<'de, V>(
+    map: &mut V,
+    info: &'static StructInfo,
+    serialization_data: Option<&SerializationData>,
+    migrate: &ReflectMigrate,
+    registry: &TypeRegistry,
+    deny_unknown_fields: bool,
+    compact_bytes: bool,
+) -> Result<DynamicStruct, V::Error>
+where
+    V: MapAccess<'de>,
+{
+    let mut version = None;
+    let mut unknown_fields = Vec::new();
+    let mut dynamic_struct = DynamicStruct::default();
+    while let Some(Ident(key)) = map.next_key::<Ident>()? {
+        if key == VERSION_FIELD {
+            version = Some(map.next_value::<u32>()?);
+            continue;
+        }
+
+        let value = match info.field(&key) {
+            Some(field) => {
+                let registration = get_registration(field.type_id(), field.type_path(), registry)?;
+                map.next_value_seed(
+                    TypedReflectDeserializer::new(registration, registry)
+                        .with_deny_unknown_fields(deny_unknown_fields)
+                        .with_compact_bytes(compact_bytes),
+                )?
+            }
+            None => {
+                let value = map.next_value_seed(UntypedValueDeserializer)?;
+                unknown_fields.push(key.clone());
+                value
+            }
+        };
+        dynamic_struct.insert_boxed(key, value);
+    }
+
+    let version = version.unwrap_or(0);
+    if version > migrate.version() {
+        return Err(Error::custom(format_args!(
+            "`{}` was written with version {version}, which is newer than the current version {}",
+            info.type_path(),
+            migrate.version()
+        )));
+    }
+    if version < migrate.version() {
+        dynamic_struct = migrate.migrate(version, dynamic_struct);
+    } else if let Some(field) = unknown_fields.first() {
+        if deny_unknown_fields {
+            return Err(Error::custom(format_args!(
+                "unknown field `{field}` for version {version} of `{}`",
+                info.type_path()
+            )));
+        }
+        for field in &unknown_fields {
+            dynamic_struct.remove(field);
+        }
+    }
+
+    if let Some(serialization_data) = serialization_data {
+        for (index, field) in info.iter().enumerate() {
+            if dynamic_struct.field(field.name()).is_some() {
+                continue;
+            }
+            if let Some(value) = serialization_data.generate_missing(index) {
+                dynamic_struct.insert_boxed(field.name(), value);
+            }
+        }
+    }
+
+    Ok(dynamic_struct)
+}
+
+/// Reads the version written before the fields of a struct with [`ReflectMigrate`] type data
+/// in formats which write structs as sequences, which can't be migrated.
+pub(super) fn visit_version_seq<'de, V>(
+    seq: &mut V,
+    info: &'static StructInfo,
+    migrate: &ReflectMigrate,
+) -> Result<(), V::Error>
+where
+    V: SeqAccess<'de>,
+{
+    let version = seq
+        .next_element::<u32>()?
+        .ok_or_else(|| Error::custom(format_args!("missing version of `{}`", info.type_path())))?;
+    if version != migrate.version() {
+        return Err(Error::custom(format_args!(
+            "cannot migrate `{}` from version {version} to version {} without field names",
+            info.type_path(),
+            migrate.version()
+        )));
+    }
+    Ok(())
+}
+
+/// Deserializes a value of an unknown type, using the types given by a self-describing format.
+struct UntypedValueDeserializer;
+
+impl<'de> DeserializeSeed<'de> for UntypedValueDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for UntypedValueDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any self-describing value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_string()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_vec()))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(DynamicEnum::new("None", ())))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(self.deserialize(deserializer)?);
+        Ok(Box::new(DynamicEnum::new("Some", tuple)))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(UntypedValueDeserializer)? {
+            list.push_box(value);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some(key) = map.next_key_seed(UntypedValueDeserializer)? {
+            entries.push((key, map.next_value_seed(UntypedValueDeserializer)?));
+        }
+
+        // Maps keyed by strings are most likely nested structs
+        if entries.iter().all(|(key, _)| key.is::<String>()) {
+            let mut dynamic_struct = DynamicStruct::default();
+            for (key, value) in entries {
+                dynamic_struct.insert_boxed(*key.downcast::<String>().unwrap(), value);
+            }
+            return Ok(Box::new(dynamic_struct));
+        }
+
+        let mut dynamic_map = DynamicMap::default();
+        for (key, value) in entries {
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(Box::new(dynamic_map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::TypedReflectSerializer;
+    use crate::FromReflect;
+    use bincode::Options;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Migrate)]
+    struct Settings {
+        volume: f32,
+        muted: bool,
+        #[reflect(default)]
+        language: String,
+    }
+
+    impl Migrate for Settings {
+        const VERSION: u32 = 3;
+
+        fn migrate(old_version: u32, mut value: DynamicStruct) -> DynamicStruct {
+            if old_version < 2 {
+                // Version 1 stored the volume as a percentage
+                let percent = value.remove("volume_percent").unwrap();
+                let percent = *percent.downcast_ref::<u64>().unwrap() as f32;
+                value.insert("volume", percent / 100.0);
+            }
+            if old_version < 3 {
+                // Version 2 stored the muted state as a string named `mute`
+                let muted = value.remove("mute").unwrap();
+                value.insert("muted", muted.downcast_ref::<String>().unwrap() == "yes");
+            }
+            value
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Settings>();
+        registry
+    }
+
+    fn from_ron(input: &str, registry: &TypeRegistry) -> Result<Settings, ron::Error> {
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let output =
+            TypedReflectDeserializer::new(registration, registry).deserialize(&mut deserializer)?;
+        Ok(Settings::from_reflect(&*output).unwrap())
+    }
+
+    #[test]
+    fn should_write_version() {
+        let registry = get_registry();
+        let settings = Settings {
+            volume: 0.5,
+            muted: true,
+            language: String::from("en"),
+        };
+
+        let output = ron::to_string(&TypedReflectSerializer::new(&settings, &registry)).unwrap();
+        assert_eq!(
+            r#"(__version:3,volume:0.5,muted:true,language:"en")"#,
+            output
+        );
+        assert_eq!(settings, from_ron(&output, &registry).unwrap());
+    }
+
+    #[test]
+    fn should_migrate_old_versions() {
+        let registry = get_registry();
+
+        let expected = Settings {
+            volume: 0.25,
+            muted: true,
+            language: String::new(),
+        };
+        assert_eq!(
+            expected,
+            from_ron(
+                "(__version: 1, volume_percent: 25, mute: \"yes\")",
+                &registry
+            )
+            .unwrap()
+        );
+        // Data written before the type was versioned is read as version 0
+        assert_eq!(
+            expected,
+            from_ron("(volume_percent: 25, mute: \"yes\")", &registry).unwrap()
+        );
+        assert_eq!(
+            Settings {
+                volume: 0.25,
+                muted: false,
+                language: String::from("fr"),
+            },
+            from_ron(
+                "(__version: 2, volume: 0.25, mute: \"no\", language: \"fr\")",
+                &registry
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_versions_and_fields() {
+        let registry = get_registry();
+
+        assert!(from_ron("(__version: 4, volume: 1.0, muted: false)", &registry).is_err());
+        assert!(from_ron(
+            "(__version: 3, volume: 1.0, muted: false, volume_percent: 100)",
+            &registry
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_write_version_in_non_self_describing_formats() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+        let settings = Settings {
+            volume: 1.0,
+            muted: false,
+            language: String::from("de"),
+        };
+
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+        let bytes = options
+            .serialize(&TypedReflectSerializer::new(&settings, &registry))
+            .unwrap();
+        assert_eq!(3_u32.to_le_bytes(), bytes[..4]);
+
+        let output = options
+            .deserialize_seed(
+                TypedReflectDeserializer::new(registration, &registry),
+                &bytes,
+            )
+            .unwrap();
+        assert_eq!(settings, Settings::from_reflect(&*output).unwrap());
+
+        let mut old_bytes = bytes.clone();
+        old_bytes[..4].copy_from_slice(&2_u32.to_le_bytes());
+        assert!(options
+            .deserialize_seed(
+                TypedReflectDeserializer::new(registration, &registry),
+                &old_bytes
+            )
+            .is_err());
+    }
+}
@@ -1,18 +1,67 @@
+mod base64;
+mod compact;
 mod de;
+mod dedup;
+mod dictionary;
+mod diff;
+mod in_place;
+#[cfg(feature = "json")]
+mod json;
+mod migrate;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod os_string;
+mod projection;
+mod self_describing;
 mod ser;
+#[cfg(feature = "toml")]
+mod toml_config;
 mod type_data;
+mod weak_schema;
+mod with_registry;
 
+pub use compact::*;
 pub use de::*;
+pub use dedup::*;
+pub use dictionary::*;
+pub use diff::*;
+pub use in_place::*;
+#[cfg(feature = "json")]
+pub use json::*;
+pub use migrate::*;
+#[cfg(feature = "msgpack")]
+pub use msgpack::*;
+pub use os_string::*;
+pub use projection::*;
+pub use self_describing::*;
 pub use ser::*;
+#[cfg(feature = "toml")]
+pub use toml_config::*;
 pub use type_data::*;
+pub use weak_schema::*;
+pub use with_registry::*;
+
+/// Deserializes `bytes` written by `bincode::serialize` using the given seed.
+#[cfg(test)]
+pub(crate) fn from_bincode<'de, T: ::serde::de::DeserializeSeed<'de>>(
+    seed: T,
+    bytes: &'de [u8],
+) -> bincode::Result<T::Value> {
+    use bincode::Options;
+
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .deserialize_seed(seed, bytes)
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::{self as bevy_reflect, DynamicTupleStruct, Struct};
+    use super::from_bincode;
+    use crate::{self as bevy_reflect, DynamicTupleStruct, GetField, Struct};
     use crate::{
         serde::{ReflectDeserializer, ReflectSerializer},
         type_registry::TypeRegistry,
-        DynamicStruct, FromReflect, Reflect,
+        DynamicList, DynamicStruct, FromReflect, Reflect,
     };
     use serde::de::DeserializeSeed;
 
@@ -136,6 +185,157 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialization_enum_variant_fields() {
+        #[derive(Debug, Reflect, PartialEq)]
+        #[reflect(PartialEq)]
+        enum TestEnum {
+            Struct {
+                a: i32,
+                #[reflect(skip_serializing)]
+                #[reflect(default = "custom_default")]
+                b: i32,
+            },
+            Tuple(#[reflect(skip_serializing)] i32, i32),
+            NewType(#[reflect(skip_serializing)] i32),
+        }
+
+        fn custom_default() -> i32 {
+            -1
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestEnum>();
+
+        for (value, expected, expected_ron) in [
+            (
+                TestEnum::Struct { a: 3, b: 4 },
+                TestEnum::Struct { a: 3, b: -1 },
+                "Struct(a:3)",
+            ),
+            (TestEnum::Tuple(5, 6), TestEnum::Tuple(0, 6), "Tuple(6)"),
+            (TestEnum::NewType(7), TestEnum::NewType(0), "NewType()"),
+        ] {
+            let serializer = ReflectSerializer::new(&value, &registry);
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            assert!(
+                serialized.contains(expected_ron),
+                "expected `{expected_ron}` in {serialized}"
+            );
+
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            let reflect_deserializer = ReflectDeserializer::new(&registry);
+            let deserialized = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+            let received = <TestEnum as FromReflect>::from_reflect(&*deserialized).unwrap();
+
+            assert_eq!(
+                expected, received,
+                "FromReflect failed: expected {expected:?} found {received:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_default_fields_missing_from_input() {
+        #[derive(Debug, Reflect, PartialEq)]
+        struct TestStruct {
+            a: i32,
+            #[reflect(default)]
+            b: String,
+            #[reflect(default = "custom_default")]
+            c: i32,
+        }
+
+        #[derive(Debug, Reflect, PartialEq)]
+        struct TestTupleStruct(i32, #[reflect(default = "custom_default")] i32);
+
+        fn custom_default() -> i32 {
+            -1
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+        registry.register::<TestTupleStruct>();
+
+        let input = r#"{
+            "bevy_reflect::serde::tests::TestStruct": (a: 3),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = value.take::<DynamicStruct>().unwrap();
+        assert_eq!(Some(&-1), deserialized.get_field::<i32>("c"));
+        assert_eq!(
+            TestStruct {
+                a: 3,
+                b: String::new(),
+                c: -1,
+            },
+            <TestStruct as FromReflect>::from_reflect(&deserialized).unwrap()
+        );
+
+        let input = r#"{
+            "bevy_reflect::serde::tests::TestTupleStruct": (3),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            TestTupleStruct(3, -1),
+            <TestTupleStruct as FromReflect>::from_reflect(value.as_ref()).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_renamed_fields_and_variants_by_alias() {
+        #[derive(Debug, Reflect, PartialEq)]
+        struct Player {
+            #[reflect(alias = "hp")]
+            health: u32,
+            #[reflect(rename = "class")]
+            kind: Class,
+        }
+
+        #[derive(Debug, Reflect, PartialEq)]
+        enum Class {
+            #[reflect(rename = "Mage", alias = "Wizard")]
+            Caster,
+            Fighter,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Class>();
+
+        let player = Player {
+            health: 5,
+            kind: Class::Caster,
+        };
+        let serializer = ReflectSerializer::new(&player, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Player":(health:5,class:Mage)}"#,
+            serialized
+        );
+
+        let input = r#"{
+            "bevy_reflect::serde::tests::Player": (hp: 7, class: Wizard),
+        }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Player {
+                health: 7,
+                kind: Class::Caster,
+            },
+            <Player as FromReflect>::from_reflect(value.as_ref()).unwrap()
+        );
+    }
+
     #[test]
     #[should_panic(
         expected = "cannot serialize dynamic value without represented type: bevy_reflect::DynamicStruct"
@@ -181,4 +381,134 @@ mod tests {
 
         assert!(expected.reflect_partial_eq(&result).unwrap());
     }
+
+    #[test]
+    fn should_serialize_byte_arrays_compactly() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Asset {
+            hash: [u8; 4],
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Asset>();
+
+        let asset = Asset {
+            hash: [0xde, 0xad, 0xbe, 0xef],
+        };
+
+        // Byte arrays are written element by element unless compact bytes are requested
+        let ron = ron::to_string(&ReflectSerializer::new(&asset, &registry)).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Asset":(hash:(222,173,190,239))}"#,
+            ron
+        );
+
+        let serializer = ReflectSerializer::new(&asset, &registry).with_compact_bytes(true);
+        let ron = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Asset":(hash:"3q2+7w==")}"#,
+            ron
+        );
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .with_compact_bytes(true)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(asset), Asset::from_reflect(output.as_ref()));
+
+        let asset = Asset { hash: [1, 2, 3, 4] };
+        for compact_bytes in [false, true] {
+            let serializer =
+                ReflectSerializer::new(&asset, &registry).with_compact_bytes(compact_bytes);
+            let bytes = bincode::serialize(&serializer).unwrap();
+            let output = from_bincode(
+                ReflectDeserializer::new(&registry).with_compact_bytes(compact_bytes),
+                &bytes,
+            )
+            .unwrap();
+            assert_eq!(Some(&asset), Asset::from_reflect(output.as_ref()).as_ref());
+        }
+
+        // Arrays written element by element are still accepted
+        let input = r#"{"bevy_reflect::serde::tests::Asset":(hash:(1, 2, 3, 4))}"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .with_compact_bytes(true)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(Asset { hash: [1, 2, 3, 4] }),
+            Asset::from_reflect(output.as_ref())
+        );
+
+        let input = r#"{"bevy_reflect::serde::tests::Asset":(hash:"AQI=")}"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        assert!(ReflectDeserializer::new(&registry)
+            .with_compact_bytes(true)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+
+    #[test]
+    fn should_serialize_byte_lists_compactly() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Texture {
+            data: Vec<u8>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Texture>();
+
+        let texture = Texture {
+            data: vec![0xff, 0x00, 0x7f, 0x80, 0x01],
+        };
+
+        let ron = ron::to_string(&ReflectSerializer::new(&texture, &registry)).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Texture":(data:[255,0,127,128,1])}"#,
+            ron
+        );
+
+        let serializer = ReflectSerializer::new(&texture, &registry).with_compact_bytes(true);
+        let ron = ron::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Texture":(data:"/wB/gAE=")}"#,
+            ron
+        );
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .with_compact_bytes(true)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&texture),
+            Texture::from_reflect(output.as_ref()).as_ref()
+        );
+
+        // Like any other list, the bytes are deserialized into a `DynamicList`
+        let output = output.downcast_ref::<DynamicStruct>().unwrap();
+        assert!(output.field("data").unwrap().is::<DynamicList>());
+
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let output = from_bincode(
+            ReflectDeserializer::new(&registry).with_compact_bytes(true),
+            &bytes,
+        )
+        .unwrap();
+        assert_eq!(Some(texture), Texture::from_reflect(output.as_ref()));
+
+        // Lists written element by element are still accepted
+        let input = r#"{"bevy_reflect::serde::tests::Texture":(data:[1, 2, 3])}"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .with_compact_bytes(true)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(Texture {
+                data: vec![1, 2, 3]
+            }),
+            Texture::from_reflect(output.as_ref())
+        );
+    }
 }
@@ -0,0 +1,194 @@
+use crate::serde::{
+    ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+/// An error returned when converting reflected values to or from MessagePack.
+#[derive(Error, Debug)]
+pub enum MsgPackError {
+    /// The value could not be serialized.
+    #[error("failed to encode MessagePack: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    /// The data could not be deserialized into the expected type.
+    #[error("failed to decode MessagePack: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+    /// The encoded data could not be read as an [`rmpv::Value`].
+    #[error("failed to read MessagePack value: {0}")]
+    Value(#[from] rmpv::decode::Error),
+}
+
+/// Serializes a reflected value to MessagePack, along with its type path.
+///
+/// The output is the same as serializing a [`ReflectSerializer`] with [`rmp_serde`],
+/// with structs written as maps keyed by field name, so it can be read back with [`from_msgpack`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_msgpack, to_msgpack};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct FrameTime {
+///     millis: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<FrameTime>();
+///
+/// let bytes = to_msgpack(&FrameTime { millis: 16.6 }, &registry).unwrap();
+/// let output = from_msgpack(&bytes, &registry).unwrap();
+/// assert_eq!(FrameTime { millis: 16.6 }, FrameTime::from_reflect(&*output).unwrap());
+/// ```
+pub fn to_msgpack(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Vec<u8>, MsgPackError> {
+    Ok(rmp_serde::to_vec_named(&ReflectSerializer::new(
+        value, registry,
+    ))?)
+}
+
+/// Deserializes a reflected value written by [`to_msgpack`].
+///
+/// Like the [`ReflectDeserializer`], this returns the dynamic equivalent of the value,
+/// unless its type registers [`ReflectDeserialize`].
+///
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+pub fn from_msgpack(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, MsgPackError> {
+    let mut deserializer = rmp_serde::Deserializer::new(bytes);
+    Ok(ReflectDeserializer::new(registry).deserialize(&mut deserializer)?)
+}
+
+/// Converts a reflected value to an [`rmpv::Value`].
+///
+/// Only the data of the value is converted, as with the [`TypedReflectSerializer`],
+/// with structs converted to maps keyed by field name.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{from_msgpack_value, to_msgpack_value};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Sample {
+///     fps: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Sample>();
+///
+/// let value = to_msgpack_value(&Sample { fps: 60 }, &registry).unwrap();
+/// assert_eq!(
+///     rmpv::Value::Map(vec![(rmpv::Value::from("fps"), rmpv::Value::from(60))]),
+///     value
+/// );
+///
+/// let registration = registry.get(TypeId::of::<Sample>()).unwrap();
+/// let output = from_msgpack_value(&value, registration, &registry).unwrap();
+/// assert_eq!(Sample { fps: 60 }, Sample::from_reflect(&*output).unwrap());
+/// ```
+pub fn to_msgpack_value(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<rmpv::Value, MsgPackError> {
+    let bytes = rmp_serde::to_vec_named(&TypedReflectSerializer::new(value, registry))?;
+    Ok(rmpv::decode::read_value(&mut bytes.as_slice())?)
+}
+
+/// Converts an [`rmpv::Value`] to a reflected value of the type described by `registration`.
+///
+/// Like the [`TypedReflectDeserializer`], this returns the dynamic equivalent of the value,
+/// unless its type registers [`ReflectDeserialize`].
+///
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+pub fn from_msgpack_value(
+    value: &rmpv::Value,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, MsgPackError> {
+    let mut bytes = Vec::new();
+    rmpv::encode::write_value(&mut bytes, value).expect("writing to a `Vec` cannot fail");
+    let mut deserializer = rmp_serde::Deserializer::new(bytes.as_slice());
+    Ok(TypedReflectDeserializer::new(registration, registry).deserialize(&mut deserializer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use rmpv::Value;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Telemetry {
+        frame: u64,
+        label: Option<String>,
+        phase: Phase,
+        samples: Vec<f32>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Phase {
+        Update,
+        Render { draw_calls: u32 },
+    }
+
+    fn telemetry() -> Telemetry {
+        Telemetry {
+            frame: 42,
+            label: None,
+            phase: Phase::Render { draw_calls: 128 },
+            samples: vec![0.5, 1.5],
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Telemetry>();
+        registry
+    }
+
+    #[test]
+    fn should_roundtrip_msgpack_bytes() {
+        let registry = get_registry();
+
+        let bytes = to_msgpack(&telemetry(), &registry).unwrap();
+        let output = from_msgpack(&bytes, &registry).unwrap();
+
+        assert_eq!(telemetry(), Telemetry::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_roundtrip_msgpack_value() {
+        let registry = get_registry();
+
+        let value = to_msgpack_value(&telemetry(), &registry).unwrap();
+        let fields = value.as_map().unwrap();
+        assert_eq!(
+            vec!["frame", "label", "phase", "samples"],
+            fields
+                .iter()
+                .map(|(key, _)| key.as_str().unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Value::from(42), fields[0].1);
+        assert_eq!(Value::Nil, fields[1].1);
+
+        let registration = registry.get(TypeId::of::<Telemetry>()).unwrap();
+        let output = from_msgpack_value(&value, registration, &registry).unwrap();
+        assert_eq!(telemetry(), Telemetry::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_fail_on_mismatched_value() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Telemetry>()).unwrap();
+
+        let result = from_msgpack_value(&Value::from("not a struct"), registration, &registry);
+        assert!(matches!(result, Err(MsgPackError::Decode(_))));
+    }
+}
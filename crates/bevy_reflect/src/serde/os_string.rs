@@ -0,0 +1,138 @@
+use crate::serde::Serializable;
+use crate::{Reflect, ReflectDeserialize, ReflectSerialize, TypeRegistry};
+use serde::ser::Error as _;
+use serde::{Deserialize, Serialize, Serializer};
+use std::any::TypeId;
+use std::ffi::{OsStr, OsString};
+
+/// How [`OsString`]s that are not valid UTF-8 are serialized
+/// once registered with [`TypeRegistry::register_os_string_as_utf8`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Utf8Mode {
+    /// Replace invalid sequences with [`U+FFFD REPLACEMENT CHARACTER`],
+    /// as done by [`OsStr::to_string_lossy`].
+    ///
+    /// Such strings won't deserialize to the original value.
+    ///
+    /// [`U+FFFD REPLACEMENT CHARACTER`]: std::char::REPLACEMENT_CHARACTER
+    #[default]
+    Lossy,
+    /// Fail to serialize strings that are not valid UTF-8.
+    Strict,
+}
+
+impl TypeRegistry {
+    /// Registers [`OsString`], serialized as a UTF-8 string.
+    ///
+    /// By default, [`OsString`] is serialized by serde in a platform-specific format,
+    /// only on Unix and Windows.
+    /// Once this is called, it is serialized as a plain string on all platforms,
+    /// following the given [`Utf8Mode`] for strings that are not valid UTF-8.
+    /// This is useful for human-readable formats and for sharing data between platforms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// # use bevy_reflect::serde::{ReflectSerializer, Utf8Mode};
+    /// # use std::ffi::OsString;
+    /// #[derive(Reflect)]
+    /// struct FileWatcher {
+    ///     watched: Vec<OsString>,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<FileWatcher>();
+    /// registry.register_os_string_as_utf8(Utf8Mode::Strict);
+    ///
+    /// let watcher = FileWatcher {
+    ///     watched: vec![OsString::from("assets")],
+    /// };
+    /// let serializer = ReflectSerializer::new(&watcher, &registry);
+    /// let output = ron::to_string(&serializer).unwrap();
+    /// assert!(output.ends_with(r#":(watched:["assets"])}"#));
+    /// ```
+    pub fn register_os_string_as_utf8(&mut self, mode: Utf8Mode) {
+        self.register::<OsString>();
+        let registration = self.get_mut(TypeId::of::<OsString>()).unwrap();
+        registration.insert(ReflectSerialize::new(move |value| {
+            let value = value
+                .downcast_ref::<OsString>()
+                .expect("value should be an `OsString`");
+            Serializable::Owned(Box::new(Utf8OsStr { value, mode }))
+        }));
+        registration.insert(ReflectDeserialize::new(|deserializer| {
+            let value = String::deserialize(deserializer)?;
+            Ok(Box::new(OsString::from(value)) as Box<dyn Reflect>)
+        }));
+    }
+}
+
+struct Utf8OsStr<'a> {
+    value: &'a OsStr,
+    mode: Utf8Mode,
+}
+
+impl<'a> Serialize for Utf8OsStr<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match (self.value.to_str(), self.mode) {
+            (Some(value), _) => serializer.serialize_str(value),
+            (None, Utf8Mode::Lossy) => serializer.serialize_str(&self.value.to_string_lossy()),
+            (None, Utf8Mode::Strict) => Err(S::Error::custom(format_args!(
+                "OS string {:?} is not valid UTF-8",
+                self.value
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer, Utf8Mode};
+    use crate::TypeRegistry;
+    use serde::de::DeserializeSeed;
+    use std::any::TypeId;
+    use std::ffi::OsString;
+
+    fn to_ron(value: &OsString, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        ron::to_string(&TypedReflectSerializer::new(value, registry))
+    }
+
+    fn from_ron(input: &str, registry: &TypeRegistry) -> OsString {
+        let registration = registry.get(TypeId::of::<OsString>()).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+        let value = TypedReflectDeserializer::new(registration, registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        value.take().unwrap()
+    }
+
+    #[test]
+    fn should_serialize_os_string_as_utf8() {
+        let mut registry = TypeRegistry::empty();
+        registry.register_os_string_as_utf8(Utf8Mode::Strict);
+
+        let output = to_ron(&OsString::from("hello"), &registry).unwrap();
+        assert_eq!(r#""hello""#, output);
+        assert_eq!(OsString::from("hello"), from_ron(&output, &registry));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_follow_utf8_mode() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = OsString::from_vec(vec![b'a', 0xff, b'b']);
+
+        let mut registry = TypeRegistry::empty();
+        registry.register_os_string_as_utf8(Utf8Mode::Strict);
+        assert!(to_ron(&invalid, &registry).is_err());
+
+        registry.register_os_string_as_utf8(Utf8Mode::Lossy);
+        let output = to_ron(&invalid, &registry).unwrap();
+        assert_eq!(OsString::from("a\u{FFFD}b"), from_ron(&output, &registry));
+    }
+}
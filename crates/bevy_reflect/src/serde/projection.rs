@@ -0,0 +1,276 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{GetPath, ParsedPath, Reflect, TypeRegistry};
+use serde::de::{DeserializeSeed, Error as _, MapAccess, Visitor};
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Formatter};
+
+/// A serializer for the parts of a value selected by [`ParsedPath`]s.
+///
+/// The output is a map from each path to the value found at that path,
+/// serialized with a [`TypedReflectSerializer`].
+/// Since it only contains the selected parts, it is a sparse patch which can be applied
+/// to an existing value with a [`ProjectionDeserializer`],
+/// such as for sending partial updates of a value over the network.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{ParsedPath, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{ProjectionDeserializer, ProjectionSerializer};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+///     score: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let player = Player {
+///     name: String::from("Ferris"),
+///     position: (1.0, 2.0),
+///     score: 5,
+/// };
+/// let paths = [
+///     ParsedPath::parse(".position.0").unwrap(),
+///     ParsedPath::parse(".score").unwrap(),
+/// ];
+///
+/// let output = ron::to_string(&ProjectionSerializer::new(&player, &paths, &registry)).unwrap();
+/// assert_eq!(r#"{".position.0":1.0,".score":5}"#, output);
+///
+/// let mut copy = Player {
+///     name: String::from("Ferris"),
+///     position: (0.0, 2.0),
+///     score: 0,
+/// };
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// ProjectionDeserializer::new(&mut copy, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(player, copy);
+/// ```
+pub struct ProjectionSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub paths: &'a [ParsedPath],
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ProjectionSerializer<'a> {
+    pub fn new(
+        value: &'a dyn Reflect,
+        paths: &'a [ParsedPath],
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        Self {
+            value,
+            paths,
+            registry,
+        }
+    }
+}
+
+impl<'a> Serialize for ProjectionSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.paths.len()))?;
+        for path in self.paths {
+            let value = self.value.reflect_path(path).map_err(|err| {
+                S::Error::custom(format_args!(
+                    "cannot project `{path}` of `{}`: {err}",
+                    self.value.reflect_type_path()
+                ))
+            })?;
+            state.serialize_entry(
+                &path.to_string(),
+                &TypedReflectSerializer::new(value, self.registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// A deserializer which applies the output of a [`ProjectionSerializer`] to an existing value.
+///
+/// Each path is resolved in `target`, and the value found there determines the type
+/// which is deserialized and applied at that path.
+/// Parts of `target` which aren't selected by any path are left unchanged.
+pub struct ProjectionDeserializer<'a> {
+    target: &'a mut dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ProjectionDeserializer<'a> {
+    pub fn new(target: &'a mut dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { target, registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ProjectionDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ProjectionDeserializer<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of reflection paths to values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let type_path = self.target.reflect_type_path().to_string();
+        while let Some(key) = map.next_key::<String>()? {
+            let path = ParsedPath::parse(&key).map_err(|err| {
+                A::Error::custom(format_args!("invalid reflection path `{key}`: {err}"))
+            })?;
+            let field = self.target.reflect_path_mut(&path).map_err(|err| {
+                A::Error::custom(format_args!(
+                    "cannot project `{key}` of `{type_path}`: {err}"
+                ))
+            })?;
+
+            let registration = field
+                .get_represented_type_info()
+                .and_then(|info| self.registry.get(info.type_id()))
+                .ok_or_else(|| {
+                    A::Error::custom(format_args!(
+                        "no registration found for type `{}` at `{key}`",
+                        field.reflect_type_path()
+                    ))
+                })?;
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            field.try_apply(value.as_ref()).map_err(|err| {
+                A::Error::custom(format_args!("cannot apply value at `{key}`: {err}"))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Unit {
+        name: String,
+        transform: Transform,
+        tags: Vec<String>,
+        stats: HashMap<String, u32>,
+        target: Option<u32>,
+        state: State,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Transform {
+        translation: (f32, f32, f32),
+        scale: f32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Moving { speed: f32 },
+    }
+
+    fn unit() -> Unit {
+        Unit {
+            name: String::from("scout"),
+            transform: Transform {
+                translation: (1.0, 2.0, 3.0),
+                scale: 1.0,
+            },
+            tags: vec![String::from("fast"), String::from("small")],
+            stats: HashMap::from([(String::from("hp"), 10)]),
+            target: None,
+            state: State::Idle,
+        }
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Unit>();
+        registry
+    }
+
+    fn paths(paths: &[&str]) -> Vec<ParsedPath> {
+        paths
+            .iter()
+            .map(|path| ParsedPath::parse(path).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_serialize_selected_paths() {
+        let registry = get_registry();
+        let paths = paths(&[".transform.translation.1", ".tags[1]", ".target"]);
+
+        let output =
+            ron::to_string(&ProjectionSerializer::new(&unit(), &paths, &registry)).unwrap();
+        assert_eq!(
+            r#"{".transform.translation.1":2.0,".tags[1]":"small",".target":None}"#,
+            output
+        );
+    }
+
+    #[test]
+    fn should_apply_projection() {
+        let registry = get_registry();
+        let mut updated = unit();
+        updated.transform.translation.0 = 5.0;
+        updated.tags[0] = String::from("slow");
+        updated.target = Some(3);
+        updated.state = State::Moving { speed: 2.5 };
+        updated.name = String::from("ignored");
+
+        let paths = paths(&[".transform.translation.0", ".tags[0]", ".target", ".state"]);
+        let output =
+            ron::to_string(&ProjectionSerializer::new(&updated, &paths, &registry)).unwrap();
+
+        let mut value = unit();
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        ProjectionDeserializer::new(&mut value, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(
+            Unit {
+                name: String::from("scout"),
+                ..updated
+            },
+            value
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_paths() {
+        let registry = get_registry();
+
+        let paths = paths(&[".transform.rotation"]);
+        assert!(ron::to_string(&ProjectionSerializer::new(&unit(), &paths, &registry)).is_err());
+
+        let mut value = unit();
+        let mut deserializer = ron::Deserializer::from_str(r#"{".tags[5]":"x"}"#).unwrap();
+        assert!(ProjectionDeserializer::new(&mut value, &registry)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+}
@@ -0,0 +1,798 @@
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, Reflect, ReflectRef, VariantType,
+};
+use serde::de::{
+    DeserializeSeed, EnumAccess, Error as _, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt::{self, Formatter};
+
+const DYNAMIC_VALUE: &str = "DynamicValue";
+const VARIANT_FIELDS: &str = "VariantFields";
+
+/// The kinds of values supported by the self-describing format.
+///
+/// Each kind is serialized as a variant of the same name.
+#[derive(Clone, Copy)]
+enum Kind {
+    Struct,
+    TupleStruct,
+    Tuple,
+    List,
+    Array,
+    Map,
+    Enum,
+    Bool,
+    Char,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    F32,
+    F64,
+    String,
+}
+
+impl Kind {
+    const ALL: [Kind; 22] = [
+        Kind::Struct,
+        Kind::TupleStruct,
+        Kind::Tuple,
+        Kind::List,
+        Kind::Array,
+        Kind::Map,
+        Kind::Enum,
+        Kind::Bool,
+        Kind::Char,
+        Kind::U8,
+        Kind::U16,
+        Kind::U32,
+        Kind::U64,
+        Kind::U128,
+        Kind::I8,
+        Kind::I16,
+        Kind::I32,
+        Kind::I64,
+        Kind::I128,
+        Kind::F32,
+        Kind::F64,
+        Kind::String,
+    ];
+
+    const NAMES: &'static [&'static str] = &[
+        "Struct",
+        "TupleStruct",
+        "Tuple",
+        "List",
+        "Array",
+        "Map",
+        "Enum",
+        "Bool",
+        "Char",
+        "U8",
+        "U16",
+        "U32",
+        "U64",
+        "U128",
+        "I8",
+        "I16",
+        "I32",
+        "I64",
+        "I128",
+        "F32",
+        "F64",
+        "String",
+    ];
+
+    fn serialize_newtype<S: Serializer, T: Serialize + ?Sized>(
+        self,
+        serializer: S,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_variant(
+            DYNAMIC_VALUE,
+            self as u32,
+            Self::NAMES[self as usize],
+            value,
+        )
+    }
+}
+
+/// A serializer for reflected values that doesn't require a [`TypeRegistry`]
+/// or type information.
+///
+/// Values are written in a self-describing format that records the kind of each value,
+/// such as struct or list, along with its fields or elements.
+/// Leaf values are limited to primitives: `bool`, `char`, integers, floats and strings.
+/// Any other opaque value results in an error.
+///
+/// This is mostly useful for dynamic values that don't represent any concrete type,
+/// which [`ReflectSerializer`] can't serialize.
+/// Type paths are not recorded, so the values read back by
+/// [`SelfDescribingReflectDeserializer`] are always dynamic types,
+/// except for leaf values.
+///
+/// `usize` and `isize` are written as `u64` and `i64` respectively.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::{DynamicStruct, Reflect};
+/// # use bevy_reflect::serde::{SelfDescribingReflectDeserializer, SelfDescribingReflectSerializer};
+/// let mut value = DynamicStruct::default();
+/// value.insert("health", 100_u32);
+/// value.insert("name", String::from("Ferris"));
+///
+/// let serializer = SelfDescribingReflectSerializer::new(&value);
+/// let output = ron::to_string(&serializer).unwrap();
+/// assert_eq!(r#"Struct({"health":U32(100),"name":String("Ferris")})"#, output);
+///
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let output = SelfDescribingReflectDeserializer.deserialize(&mut deserializer).unwrap();
+/// assert!(output.reflect_partial_eq(&value).unwrap());
+/// ```
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct SelfDescribingReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+}
+
+impl<'a> SelfDescribingReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect) -> Self {
+        Self { value }
+    }
+}
+
+impl<'a> Serialize for SelfDescribingReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let fields = (0..value.field_len())
+                    .map(|index| {
+                        (
+                            value.name_at(index).unwrap(),
+                            value.field_at(index).unwrap(),
+                        )
+                    })
+                    .collect();
+                Kind::Struct.serialize_newtype(serializer, &FieldsSerializer(fields))
+            }
+            ReflectRef::TupleStruct(value) => Kind::TupleStruct.serialize_newtype(
+                serializer,
+                &ElementsSerializer(value.iter_fields().collect()),
+            ),
+            ReflectRef::Tuple(value) => Kind::Tuple.serialize_newtype(
+                serializer,
+                &ElementsSerializer(value.iter_fields().collect()),
+            ),
+            ReflectRef::List(value) => Kind::List
+                .serialize_newtype(serializer, &ElementsSerializer(value.iter().collect())),
+            ReflectRef::Array(value) => Kind::Array
+                .serialize_newtype(serializer, &ElementsSerializer(value.iter().collect())),
+            ReflectRef::Map(value) => {
+                Kind::Map.serialize_newtype(serializer, &EntriesSerializer(value))
+            }
+            ReflectRef::Enum(value) => {
+                let fields = match value.variant_type() {
+                    VariantType::Unit => VariantFieldsSerializer::Unit,
+                    VariantType::Struct => VariantFieldsSerializer::Struct(FieldsSerializer(
+                        value
+                            .iter_fields()
+                            .map(|field| (field.name().unwrap(), field.value()))
+                            .collect(),
+                    )),
+                    VariantType::Tuple => VariantFieldsSerializer::Tuple(ElementsSerializer(
+                        value.iter_fields().map(|field| field.value()).collect(),
+                    )),
+                };
+                Kind::Enum.serialize_newtype(serializer, &(value.variant_name(), fields))
+            }
+            ReflectRef::Value(value) => serialize_leaf(value, serializer),
+        }
+    }
+}
+
+fn serialize_leaf<S: Serializer>(value: &dyn Reflect, serializer: S) -> Result<S::Ok, S::Error> {
+    let any = value.as_any();
+
+    macro_rules! serialize_as {
+        ($($ty:ty => $kind:ident),* $(,)?) => {
+            $(
+                if let Some(value) = any.downcast_ref::<$ty>() {
+                    return Kind::$kind.serialize_newtype(serializer, value);
+                }
+            )*
+        };
+    }
+
+    serialize_as!(
+        bool => Bool,
+        char => Char,
+        u8 => U8,
+        u16 => U16,
+        u32 => U32,
+        u64 => U64,
+        u128 => U128,
+        i8 => I8,
+        i16 => I16,
+        i32 => I32,
+        i64 => I64,
+        i128 => I128,
+        f32 => F32,
+        f64 => F64,
+        String => String,
+        &'static str => String,
+    );
+
+    if let Some(&value) = any.downcast_ref::<usize>() {
+        return Kind::U64.serialize_newtype(serializer, &(value as u64));
+    }
+    if let Some(&value) = any.downcast_ref::<isize>() {
+        return Kind::I64.serialize_newtype(serializer, &(value as i64));
+    }
+
+    Err(S::Error::custom(format_args!(
+        "cannot serialize opaque value of type `{}` without type information",
+        value.reflect_type_path()
+    )))
+}
+
+struct FieldsSerializer<'a>(Vec<(&'a str, &'a dyn Reflect)>);
+
+impl<'a> Serialize for FieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.0.len()))?;
+        for &(name, value) in &self.0 {
+            state.serialize_entry(name, &SelfDescribingReflectSerializer::new(value))?;
+        }
+        state.end()
+    }
+}
+
+struct ElementsSerializer<'a>(Vec<&'a dyn Reflect>);
+
+impl<'a> Serialize for ElementsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for &value in &self.0 {
+            state.serialize_element(&SelfDescribingReflectSerializer::new(value))?;
+        }
+        state.end()
+    }
+}
+
+struct EntriesSerializer<'a>(&'a dyn Map);
+
+impl<'a> Serialize for EntriesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for (key, value) in self.0.iter() {
+            state.serialize_element(&(
+                SelfDescribingReflectSerializer::new(key),
+                SelfDescribingReflectSerializer::new(value),
+            ))?;
+        }
+        state.end()
+    }
+}
+
+enum VariantFieldsSerializer<'a> {
+    Unit,
+    Struct(FieldsSerializer<'a>),
+    Tuple(ElementsSerializer<'a>),
+}
+
+impl<'a> Serialize for VariantFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Unit => serializer.serialize_unit_variant(VARIANT_FIELDS, 0, "Unit"),
+            Self::Struct(fields) => {
+                serializer.serialize_newtype_variant(VARIANT_FIELDS, 1, "Struct", fields)
+            }
+            Self::Tuple(fields) => {
+                serializer.serialize_newtype_variant(VARIANT_FIELDS, 2, "Tuple", fields)
+            }
+        }
+    }
+}
+
+/// A deserializer for reflected values written by [`SelfDescribingReflectSerializer`].
+///
+/// This doesn't require a [`TypeRegistry`]:
+/// the structure of each value is read from the data itself.
+/// Structs, tuples, lists, arrays, maps and enums are returned as their dynamic types,
+/// such as [`DynamicStruct`], which don't represent any concrete type.
+/// Leaf values are returned as the primitive type they were written as.
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+#[derive(Clone, Copy, Default)]
+pub struct SelfDescribingReflectDeserializer;
+
+impl<'de> DeserializeSeed<'de> for SelfDescribingReflectDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(DYNAMIC_VALUE, Kind::NAMES, DynamicValueVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct KindVisitor;
+
+        impl<'de> Visitor<'de> for KindVisitor {
+            type Value = Kind;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("the kind of a self-describing value")
+            }
+
+            fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Kind::ALL.get(index as usize).copied().ok_or_else(|| {
+                    E::custom(format_args!("no value kind found at index `{index}`"))
+                })
+            }
+
+            fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Kind::NAMES
+                    .iter()
+                    .position(|&kind| kind == name)
+                    .map(|index| Kind::ALL[index])
+                    .ok_or_else(|| E::unknown_variant(name, Kind::NAMES))
+            }
+        }
+
+        deserializer.deserialize_identifier(KindVisitor)
+    }
+}
+
+struct DynamicValueVisitor;
+
+impl<'de> Visitor<'de> for DynamicValueVisitor {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a self-describing reflected value")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (kind, variant) = data.variant::<Kind>()?;
+        Ok(match kind {
+            Kind::Struct => Box::new(variant.newtype_variant_seed(FieldsDeserializer)?),
+            Kind::TupleStruct => {
+                let mut value = DynamicTupleStruct::default();
+                for field in variant.newtype_variant_seed(ElementsDeserializer)? {
+                    value.insert_boxed(field);
+                }
+                Box::new(value)
+            }
+            Kind::Tuple => {
+                let mut value = DynamicTuple::default();
+                for field in variant.newtype_variant_seed(ElementsDeserializer)? {
+                    value.insert_boxed(field);
+                }
+                Box::new(value)
+            }
+            Kind::List => {
+                let mut value = DynamicList::default();
+                for element in variant.newtype_variant_seed(ElementsDeserializer)? {
+                    value.push_box(element);
+                }
+                Box::new(value)
+            }
+            Kind::Array => {
+                let elements = variant.newtype_variant_seed(ElementsDeserializer)?;
+                Box::new(DynamicArray::new(elements.into_boxed_slice()))
+            }
+            Kind::Map => Box::new(variant.newtype_variant_seed(EntriesDeserializer)?),
+            Kind::Enum => Box::new(variant.newtype_variant_seed(EnumDeserializer)?),
+            Kind::Bool => Box::new(variant.newtype_variant::<bool>()?),
+            Kind::Char => Box::new(variant.newtype_variant::<char>()?),
+            Kind::U8 => Box::new(variant.newtype_variant::<u8>()?),
+            Kind::U16 => Box::new(variant.newtype_variant::<u16>()?),
+            Kind::U32 => Box::new(variant.newtype_variant::<u32>()?),
+            Kind::U64 => Box::new(variant.newtype_variant::<u64>()?),
+            Kind::U128 => Box::new(variant.newtype_variant::<u128>()?),
+            Kind::I8 => Box::new(variant.newtype_variant::<i8>()?),
+            Kind::I16 => Box::new(variant.newtype_variant::<i16>()?),
+            Kind::I32 => Box::new(variant.newtype_variant::<i32>()?),
+            Kind::I64 => Box::new(variant.newtype_variant::<i64>()?),
+            Kind::I128 => Box::new(variant.newtype_variant::<i128>()?),
+            Kind::F32 => Box::new(variant.newtype_variant::<f32>()?),
+            Kind::F64 => Box::new(variant.newtype_variant::<f64>()?),
+            Kind::String => Box::new(variant.newtype_variant::<String>()?),
+        })
+    }
+}
+
+struct FieldsDeserializer;
+
+impl<'de> DeserializeSeed<'de> for FieldsDeserializer {
+    type Value = DynamicStruct;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsDeserializer {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of field names to self-describing values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = DynamicStruct::default();
+        while let Some(name) = map.next_key::<String>()? {
+            let field = map.next_value_seed(SelfDescribingReflectDeserializer)?;
+            value.insert_boxed(name, field);
+        }
+        Ok(value)
+    }
+}
+
+struct ElementsDeserializer;
+
+impl<'de> DeserializeSeed<'de> for ElementsDeserializer {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ElementsDeserializer {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of self-describing values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(element) = seq.next_element_seed(SelfDescribingReflectDeserializer)? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+struct EntriesDeserializer;
+
+impl<'de> DeserializeSeed<'de> for EntriesDeserializer {
+    type Value = DynamicMap;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntriesDeserializer {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of self-describing key-value pairs")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut value = DynamicMap::default();
+        while let Some((key, entry)) = seq.next_element_seed(PairDeserializer)? {
+            value.insert_boxed(key, entry);
+        }
+        Ok(value)
+    }
+}
+
+/// Deserializes a key-value pair of self-describing values.
+struct PairDeserializer;
+
+impl<'de> DeserializeSeed<'de> for PairDeserializer {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for PairDeserializer {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a self-describing key-value pair")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let key = seq
+            .next_element_seed(SelfDescribingReflectDeserializer)?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element_seed(SelfDescribingReflectDeserializer)?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok((key, value))
+    }
+}
+
+struct EnumDeserializer;
+
+impl<'de> DeserializeSeed<'de> for EnumDeserializer {
+    type Value = DynamicEnum;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for EnumDeserializer {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a variant name followed by its self-describing fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let name = seq
+            .next_element::<String>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let variant = seq
+            .next_element_seed(VariantFieldsDeserializer)?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(DynamicEnum::new(name, variant))
+    }
+}
+
+/// The kinds of enum variants, serialized as variants of the same name.
+#[derive(Clone, Copy)]
+enum VariantKind {
+    Unit,
+    Struct,
+    Tuple,
+}
+
+impl VariantKind {
+    const ALL: [VariantKind; 3] = [VariantKind::Unit, VariantKind::Struct, VariantKind::Tuple];
+    const NAMES: &'static [&'static str] = &["Unit", "Struct", "Tuple"];
+}
+
+impl<'de> Deserialize<'de> for VariantKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct VariantKindVisitor;
+
+        impl<'de> Visitor<'de> for VariantKindVisitor {
+            type Value = VariantKind;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("the kind of an enum variant")
+            }
+
+            fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                VariantKind::ALL
+                    .get(index as usize)
+                    .copied()
+                    .ok_or_else(|| {
+                        E::custom(format_args!("no variant kind found at index `{index}`"))
+                    })
+            }
+
+            fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                VariantKind::NAMES
+                    .iter()
+                    .position(|&kind| kind == name)
+                    .map(|index| VariantKind::ALL[index])
+                    .ok_or_else(|| E::unknown_variant(name, VariantKind::NAMES))
+            }
+        }
+
+        deserializer.deserialize_identifier(VariantKindVisitor)
+    }
+}
+
+struct VariantFieldsDeserializer;
+
+impl<'de> DeserializeSeed<'de> for VariantFieldsDeserializer {
+    type Value = DynamicVariant;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(VARIANT_FIELDS, VariantKind::NAMES, self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantFieldsDeserializer {
+    type Value = DynamicVariant;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("the self-describing fields of an enum variant")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (kind, variant) = data.variant::<VariantKind>()?;
+        match kind {
+            VariantKind::Unit => {
+                variant.unit_variant()?;
+                Ok(DynamicVariant::Unit)
+            }
+            VariantKind::Struct => Ok(variant.newtype_variant_seed(FieldsDeserializer)?.into()),
+            VariantKind::Tuple => {
+                let mut fields = DynamicTuple::default();
+                for field in variant.newtype_variant_seed(ElementsDeserializer)? {
+                    fields.insert_boxed(field);
+                }
+                Ok(fields.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::from_bincode;
+    use crate::{FromReflect, GetField, Struct};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        position: (f32, f32),
+        inventory: Vec<u8>,
+        scores: HashMap<String, i64>,
+        state: State,
+        slots: [Option<u16>; 2],
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Moving { speed: f32 },
+        Attacking(usize),
+    }
+
+    fn player() -> Player {
+        Player {
+            name: String::from("Ferris"),
+            position: (1.0, -2.5),
+            inventory: vec![1, 2, 3],
+            scores: [(String::from("level_1"), 1200)].into_iter().collect(),
+            state: State::Moving { speed: 4.0 },
+            slots: [Some(3), None],
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_dynamic_value_without_registry() {
+        let value = player().clone_value();
+        assert!(value.is_dynamic());
+
+        let serializer = SelfDescribingReflectSerializer::new(&*value);
+        let output = ron::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let output = SelfDescribingReflectDeserializer
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert!(output.get_represented_type_info().is_none());
+        assert_eq!(player(), Player::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_roundtrip_in_non_self_describing_format() {
+        let mut value = DynamicStruct::default();
+        value.insert("state", State::Idle);
+        value.insert("moving", State::Moving { speed: 2.0 });
+        value.insert("count", 3_usize);
+
+        let serializer = SelfDescribingReflectSerializer::new(&value);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let output = from_bincode(SelfDescribingReflectDeserializer, &bytes).unwrap();
+        let output = output.downcast_ref::<DynamicStruct>().unwrap();
+
+        assert!(output
+            .field("state")
+            .unwrap()
+            .reflect_partial_eq(&State::Idle)
+            .unwrap());
+        assert_eq!(
+            State::Moving { speed: 2.0 },
+            State::from_reflect(output.field("moving").unwrap()).unwrap()
+        );
+        assert_eq!(Some(&3_u64), output.get_field::<u64>("count"));
+    }
+
+    #[test]
+    fn should_error_on_opaque_values() {
+        let mut value = DynamicStruct::default();
+        value.insert("id", std::any::TypeId::of::<u32>());
+
+        let serializer = SelfDescribingReflectSerializer::new(&value);
+        let error = ron::to_string(&serializer).unwrap_err();
+        assert!(error.to_string().contains("TypeId"));
+    }
+}
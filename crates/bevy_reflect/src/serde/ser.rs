@@ -1,3 +1,6 @@
+use crate::map::sort_entries_by_key;
+use crate::serde::base64::encode_base64;
+use crate::std_traits::ReflectDefault;
 use crate::{
     Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
     TypeInfo, TypeRegistry, VariantInfo, VariantType,
@@ -11,7 +14,7 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{ReflectMigrate, ReflectSerializeWithRegistry, SerializationData, VERSION_FIELD};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -28,7 +31,7 @@ impl<'a> Serializable<'a> {
     }
 }
 
-fn get_serializable<'a, E: Error>(
+pub(super) fn get_serializable<'a, E: Error>(
     reflect_value: &'a dyn Reflect,
     type_registry: &TypeRegistry,
 ) -> Result<Serializable<'a>, E> {
@@ -95,11 +98,44 @@ fn get_serializable<'a, E: Error>(
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> ReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        ReflectSerializer { value, registry }
+        ReflectSerializer {
+            value,
+            registry,
+            skip_defaults: false,
+            sort_maps: false,
+            compact_bytes: false,
+        }
+    }
+
+    /// Sets whether struct fields equal to their default value are left out.
+    ///
+    /// See [`TypedReflectSerializer::with_skip_defaults`] for details.
+    pub fn with_skip_defaults(mut self, skip_defaults: bool) -> Self {
+        self.skip_defaults = skip_defaults;
+        self
+    }
+
+    /// Sets whether the entries of maps are sorted by key.
+    ///
+    /// See [`TypedReflectSerializer::with_sort_maps`] for details.
+    pub fn with_sort_maps(mut self, sort_maps: bool) -> Self {
+        self.sort_maps = sort_maps;
+        self
+    }
+
+    /// Sets whether lists and arrays of bytes are written as a single value.
+    ///
+    /// See [`TypedReflectSerializer::with_compact_bytes`] for details.
+    pub fn with_compact_bytes(mut self, compact_bytes: bool) -> Self {
+        self.compact_bytes = compact_bytes;
+        self
     }
 }
 
@@ -126,7 +162,10 @@ impl<'a> Serialize for ReflectSerializer<'a> {
                     }
                 })?
                 .type_path(),
-            &TypedReflectSerializer::new(self.value, self.registry),
+            &TypedReflectSerializer::new(self.value, self.registry)
+                .with_skip_defaults(self.skip_defaults)
+                .with_sort_maps(self.sort_maps)
+                .with_compact_bytes(self.compact_bytes),
         )?;
         state.end()
     }
@@ -173,11 +212,123 @@ impl<'a> Serialize for ReflectSerializer<'a> {
 pub struct TypedReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        TypedReflectSerializer { value, registry }
+        TypedReflectSerializer {
+            value,
+            registry,
+            skip_defaults: false,
+            sort_maps: false,
+            compact_bytes: false,
+        }
+    }
+
+    /// Sets whether struct fields equal to their default value are left out.
+    ///
+    /// When enabled, each field of a struct registering [`ReflectDefault`] is compared
+    /// against the same field of the struct's default value, and only the fields which differ
+    /// are written. This applies at every level, so a nested struct which differs from the
+    /// default only writes its own overridden fields.
+    ///
+    /// The [`TypedReflectDeserializer`] fills in missing fields of such structs from their
+    /// default value, so the output reads back to the original value.
+    /// Since fields are omitted by name, this should only be used with self-describing formats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::prelude::*;
+    /// # use bevy_reflect::{TypeRegistry, serde::TypedReflectSerializer};
+    /// #[derive(Reflect, Default)]
+    /// #[reflect(Default)]
+    /// struct Enemy {
+    ///   health: u32,
+    ///   speed: f32,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Enemy>();
+    ///
+    /// let input = Enemy { health: 50, ..Default::default() };
+    ///
+    /// let reflect_serializer = TypedReflectSerializer::new(&input, &registry).with_skip_defaults(true);
+    /// let output = ron::to_string(&reflect_serializer).unwrap();
+    ///
+    /// assert_eq!(output, r#"(health:50)"#);
+    /// ```
+    ///
+    /// [`ReflectDefault`]: crate::std_traits::ReflectDefault
+    /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+    pub fn with_skip_defaults(mut self, skip_defaults: bool) -> Self {
+        self.skip_defaults = skip_defaults;
+        self
+    }
+
+    /// Sets whether the entries of maps are sorted by key.
+    ///
+    /// Maps like `HashMap` iterate in an arbitrary order which changes between runs,
+    /// so their output changes even when their contents don't.
+    /// Sorting the entries gives a stable output, such as for scene files kept in version control.
+    ///
+    /// Keys are ordered with [`Reflect::reflect_cmp`] if they can be compared with each other,
+    /// and by their debug representation otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_utils::HashMap;
+    /// # use bevy_reflect::{TypeRegistry, serde::TypedReflectSerializer};
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<HashMap<u32, bool>>();
+    ///
+    /// let input = HashMap::from([(3_u32, true), (1, false), (2, true)]);
+    ///
+    /// let reflect_serializer = TypedReflectSerializer::new(&input, &registry).with_sort_maps(true);
+    /// let output = ron::to_string(&reflect_serializer).unwrap();
+    ///
+    /// assert_eq!(output, r#"{1:false,2:true,3:true}"#);
+    /// ```
+    pub fn with_sort_maps(mut self, sort_maps: bool) -> Self {
+        self.sort_maps = sort_maps;
+        self
+    }
+
+    /// Sets whether lists and arrays of bytes, such as `Vec<u8>` and `[u8; N]`,
+    /// are written as a single value rather than element by element.
+    ///
+    /// When enabled, the bytes are written as a base64 string in human-readable formats,
+    /// and as a byte buffer otherwise, which considerably shrinks blobs such as texture data.
+    ///
+    /// This changes the format of these values, so the output must be read back by a
+    /// [`TypedReflectDeserializer`] with [`with_compact_bytes`] enabled too.
+    /// Data written without this option can be migrated by deserializing it as before
+    /// and serializing it again with the option enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{TypeRegistry, serde::TypedReflectSerializer};
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Vec<u8>>();
+    ///
+    /// let input: Vec<u8> = vec![0xde, 0xad, 0xbe, 0xef];
+    ///
+    /// let reflect_serializer = TypedReflectSerializer::new(&input, &registry).with_compact_bytes(true);
+    /// let output = ron::to_string(&reflect_serializer).unwrap();
+    ///
+    /// assert_eq!(output, r#""3q2+7w==""#);
+    /// ```
+    ///
+    /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+    /// [`with_compact_bytes`]: crate::serde::TypedReflectDeserializer::with_compact_bytes
+    pub fn with_compact_bytes(mut self, compact_bytes: bool) -> Self {
+        self.compact_bytes = compact_bytes;
+        self
     }
 }
 
@@ -186,6 +337,16 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        // Types with a custom serializer that needs the registry take precedence
+        if let Some(serialize_with_registry) =
+            self.value.get_represented_type_info().and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectSerializeWithRegistry>(info.type_id())
+            })
+        {
+            return serialize_with_registry.serialize(self.value, serializer, self.registry);
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
         let serializable = get_serializable::<S::Error>(self.value, self.registry);
         if let Ok(serializable) = serializable {
@@ -196,36 +357,57 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
             ReflectRef::Struct(value) => StructSerializer {
                 struct_value: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(value) => TupleStructSerializer {
                 tuple_struct: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::Tuple(value) => TupleSerializer {
                 tuple: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::List(value) => ListSerializer {
                 list: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::Array(value) => ArraySerializer {
                 array: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::Map(value) => MapSerializer {
                 map: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
+                skip_defaults: self.skip_defaults,
+                sort_maps: self.sort_maps,
+                compact_bytes: self.compact_bytes,
             }
             .serialize(serializer),
             ReflectRef::Value(_) => Err(serializable.err().unwrap()),
@@ -252,6 +434,9 @@ impl<'a> Serialize for ReflectValueSerializer<'a> {
 pub struct StructSerializer<'a> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for StructSerializer<'a> {
@@ -278,25 +463,65 @@ impl<'a> Serialize for StructSerializer<'a> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
-        let mut state = serializer.serialize_struct(
-            struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
-        )?;
+        let registration = self.registry.get(type_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let migrate = registration.and_then(|registration| registration.data::<ReflectMigrate>());
+        // When skipping defaults, fields equal to those of the default value are left out
+        let default_value = registration
+            .and_then(|registration| registration.data::<ReflectDefault>())
+            .filter(|_| self.skip_defaults)
+            .map(ReflectDefault::default);
+        let default_struct = match default_value.as_deref().map(Reflect::reflect_ref) {
+            Some(ReflectRef::Struct(default_struct)) => Some(default_struct),
+            _ => None,
+        };
 
+        let mut fields = Vec::with_capacity(self.struct_value.field_len());
         for (index, value) in self.struct_value.iter_fields().enumerate() {
+            // Dynamic structs may only contain a subset of the fields (or list them in a different order),
+            // so the field needs to be resolved by name rather than by position.
+            let name = self.struct_value.name_at(index).unwrap();
+            let field_index = struct_info.index_of(name).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "unknown field `{name}` for struct `{}`",
+                    struct_info.type_path()
+                ))
+            })?;
             if serialization_data
-                .map(|data| data.is_field_skipped(index))
+                .map(|data| data.is_field_skipped(field_index))
+                .unwrap_or(false)
+            {
+                continue;
+            }
+            let key = struct_info.field_at(field_index).unwrap().name();
+            if default_struct
+                .and_then(|default_struct| default_struct.field(key))
+                .and_then(|default| default.reflect_partial_eq(value))
                 .unwrap_or(false)
             {
                 continue;
             }
-            let key = struct_info.field_at(index).unwrap().name();
-            state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
+            fields.push((key, value));
+        }
+
+        let mut state = serializer.serialize_struct(
+            struct_info.type_path_table().ident().unwrap(),
+            fields.len() + usize::from(migrate.is_some()),
+        )?;
+
+        if let Some(migrate) = migrate {
+            state.serialize_field(VERSION_FIELD, &migrate.version())?;
+        }
+
+        for (key, value) in fields {
+            state.serialize_field(
+                key,
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+            )?;
         }
         state.end()
     }
@@ -305,6 +530,9 @@ impl<'a> Serialize for StructSerializer<'a> {
 pub struct TupleStructSerializer<'a> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for TupleStructSerializer<'a> {
@@ -348,7 +576,12 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             {
                 continue;
             }
-            state.serialize_field(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+            )?;
         }
         state.end()
     }
@@ -357,6 +590,9 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
 pub struct EnumSerializer<'a> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for EnumSerializer<'a> {
@@ -391,7 +627,19 @@ impl<'a> Serialize for EnumSerializer<'a> {
             })?;
         let variant_name = variant_info.name();
         let variant_type = self.enum_value.variant_type();
+        let serialization_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<SerializationData>())
+            .and_then(|data| data.variant(variant_index as usize));
+        let is_skipped = |index: usize| {
+            serialization_data
+                .map(|data| data.is_field_skipped(index))
+                .unwrap_or(false)
+        };
         let field_len = self.enum_value.field_len();
+        let serialized_len =
+            field_len.saturating_sub(serialization_data.map(SerializationData::len).unwrap_or(0));
 
         match variant_type {
             VariantType::Unit => {
@@ -417,30 +665,44 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    serialized_len,
                 )?;
                 for (index, field) in self.enum_value.iter_fields().enumerate() {
+                    if is_skipped(index) {
+                        continue;
+                    }
                     let field_info = struct_info.field_at(index).unwrap();
                     state.serialize_field(
                         field_info.name(),
-                        &TypedReflectSerializer::new(field.value(), self.registry),
+                        &TypedReflectSerializer::new(field.value(), self.registry)
+                            .with_skip_defaults(self.skip_defaults)
+                            .with_sort_maps(self.sort_maps)
+                            .with_compact_bytes(self.compact_bytes),
                     )?;
                 }
                 state.end()
             }
-            VariantType::Tuple if field_len == 1 => {
+            VariantType::Tuple if field_len == 1 && !is_skipped(0) => {
                 let field = self.enum_value.field_at(0).unwrap();
 
                 if type_info.type_path_table().module_path() == Some("core::option")
                     && type_info.type_path_table().ident() == Some("Option")
                 {
-                    serializer.serialize_some(&TypedReflectSerializer::new(field, self.registry))
+                    serializer.serialize_some(
+                        &TypedReflectSerializer::new(field, self.registry)
+                            .with_skip_defaults(self.skip_defaults)
+                            .with_sort_maps(self.sort_maps)
+                            .with_compact_bytes(self.compact_bytes),
+                    )
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new(field, self.registry),
+                        &TypedReflectSerializer::new(field, self.registry)
+                            .with_skip_defaults(self.skip_defaults)
+                            .with_sort_maps(self.sort_maps)
+                            .with_compact_bytes(self.compact_bytes),
                     )
                 }
             }
@@ -449,13 +711,18 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    serialized_len,
                 )?;
-                for field in self.enum_value.iter_fields() {
-                    state.serialize_field(&TypedReflectSerializer::new(
-                        field.value(),
-                        self.registry,
-                    ))?;
+                for (index, field) in self.enum_value.iter_fields().enumerate() {
+                    if is_skipped(index) {
+                        continue;
+                    }
+                    state.serialize_field(
+                        &TypedReflectSerializer::new(field.value(), self.registry)
+                            .with_skip_defaults(self.skip_defaults)
+                            .with_sort_maps(self.sort_maps)
+                            .with_compact_bytes(self.compact_bytes),
+                    )?;
                 }
                 state.end()
             }
@@ -466,6 +733,9 @@ impl<'a> Serialize for EnumSerializer<'a> {
 pub struct TupleSerializer<'a> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for TupleSerializer<'a> {
@@ -476,7 +746,12 @@ impl<'a> Serialize for TupleSerializer<'a> {
         let mut state = serializer.serialize_tuple(self.tuple.field_len())?;
 
         for value in self.tuple.iter_fields() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+            )?;
         }
         state.end()
     }
@@ -485,6 +760,9 @@ impl<'a> Serialize for TupleSerializer<'a> {
 pub struct MapSerializer<'a> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for MapSerializer<'a> {
@@ -492,11 +770,22 @@ impl<'a> Serialize for MapSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.map.len()))?;
-        for (key, value) in self.map.iter() {
+        let mut entries = self.map.iter().collect::<Vec<_>>();
+        if self.sort_maps {
+            sort_entries_by_key(&mut entries, |(key, _)| *key);
+        }
+
+        let mut state = serializer.serialize_map(Some(entries.len()))?;
+        for (key, value) in entries {
             state.serialize_entry(
-                &TypedReflectSerializer::new(key, self.registry),
-                &TypedReflectSerializer::new(value, self.registry),
+                &TypedReflectSerializer::new(key, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
             )?;
         }
         state.end()
@@ -506,6 +795,9 @@ impl<'a> Serialize for MapSerializer<'a> {
 pub struct ListSerializer<'a> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for ListSerializer<'a> {
@@ -513,9 +805,41 @@ impl<'a> Serialize for ListSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        // Byte lists are written like byte arrays.
+        // `Vec<u8>` is borrowed directly, while other lists need their bytes collected.
+        let is_byte_list = self.compact_bytes
+            && matches!(
+                self.list.get_represented_type_info(),
+                Some(TypeInfo::List(info)) if info.item_is::<u8>()
+            );
+        if is_byte_list {
+            if let Some(bytes) = self.list.as_slice_of::<u8>() {
+                return serialize_bytes(bytes, serializer);
+            }
+            let bytes = self
+                .list
+                .iter()
+                .map(|item| item.downcast_ref::<u8>().copied())
+                .collect::<Option<Vec<_>>>();
+            if let Some(bytes) = bytes {
+                return serialize_bytes(&bytes, serializer);
+            }
+        }
+
+        // Primitive elements serialize the same through reflection as through serde,
+        // so contiguous lists can skip the per-element `TypedReflectSerializer`.
+        if let Some(slice) = self.list.as_primitive_slice() {
+            return slice.serialize(serializer);
+        }
+
         let mut state = serializer.serialize_seq(Some(self.list.len()))?;
         for value in self.list.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+            )?;
         }
         state.end()
     }
@@ -524,6 +848,9 @@ impl<'a> Serialize for ListSerializer<'a> {
 pub struct ArraySerializer<'a> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
+    pub skip_defaults: bool,
+    pub sort_maps: bool,
+    pub compact_bytes: bool,
 }
 
 impl<'a> Serialize for ArraySerializer<'a> {
@@ -531,23 +858,51 @@ impl<'a> Serialize for ArraySerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let is_byte_array = self.compact_bytes
+            && matches!(
+                self.array.get_represented_type_info(),
+                Some(TypeInfo::Array(info)) if info.item_is::<u8>()
+            );
+        if let Some(bytes) = is_byte_array.then(|| self.array.as_bytes()).flatten() {
+            return serialize_bytes(&bytes, serializer);
+        }
+
         let mut state = serializer.serialize_tuple(self.array.len())?;
         for value in self.array.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_skip_defaults(self.skip_defaults)
+                    .with_sort_maps(self.sort_maps)
+                    .with_compact_bytes(self.compact_bytes),
+            )?;
         }
         state.end()
     }
 }
 
+/// Writes bytes as a single base64 string in human-readable formats,
+/// or as a byte buffer otherwise, rather than element by element.
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode_base64(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::serde::ReflectSerializer;
     use crate::{self as bevy_reflect, Struct};
     use crate::{Reflect, ReflectSerialize, TypeRegistry};
-    use bevy_utils::HashMap;
+    use bevy_utils::{Duration, HashMap};
     use ron::extensions::Extensions;
     use ron::ser::PrettyConfig;
     use serde::Serialize;
+    use std::any::TypeId;
     use std::f32::consts::PI;
     use std::ops::RangeInclusive;
 
@@ -940,9 +1295,171 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_skip_default_fields() {
+        use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+        use crate::std_traits::ReflectDefault;
+        use crate::FromReflect;
+        use serde::de::DeserializeSeed;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Default)]
+        struct Prefab {
+            name: String,
+            scale: f32,
+            light: Light,
+            tags: Vec<String>,
+        }
+
+        impl Default for Prefab {
+            fn default() -> Self {
+                Self {
+                    name: String::from("prefab"),
+                    scale: 1.0,
+                    light: Light::default(),
+                    tags: vec![String::from("static")],
+                }
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Default)]
+        struct Light {
+            intensity: f32,
+            shadows: bool,
+        }
+
+        impl Default for Light {
+            fn default() -> Self {
+                Self {
+                    intensity: 800.0,
+                    shadows: true,
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Prefab>();
+
+        let input = Prefab {
+            scale: 2.0,
+            light: Light {
+                shadows: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let serializer = TypedReflectSerializer::new(&input, &registry).with_skip_defaults(true);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("(scale:2.0,light:(shadows:false))", output);
+
+        let registration = registry.get(TypeId::of::<Prefab>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(input, Prefab::from_reflect(value.as_ref()).unwrap());
+
+        let output = ron::ser::to_string(&TypedReflectSerializer::new(&input, &registry)).unwrap();
+        assert_eq!(
+            r#"(name:"prefab",scale:2.0,light:(intensity:800.0,shadows:false),tags:["static"])"#,
+            output
+        );
+    }
+
+    #[test]
+    fn should_report_emitted_field_count_of_partial_structs() {
+        use crate::serde::TypedReflectSerializer;
+        use crate::{DynamicStruct, Typed};
+
+        #[derive(Reflect)]
+        struct Foo {
+            #[reflect(skip_serializing)]
+            a: u32,
+            b: u32,
+            c: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+
+        // A patch-like dynamic struct, only containing some of the fields
+        let mut partial = DynamicStruct::default();
+        partial.set_represented_type(Some(Foo::type_info()));
+        partial.insert("c", 3_u32);
+
+        // Formats like MessagePack write the field count ahead of the fields
+        let serializer = TypedReflectSerializer::new(&partial, &registry);
+        assert_eq!(vec![0x91, 3], rmp_serde::to_vec(&serializer).unwrap());
+
+        let input = Foo { a: 1, b: 2, c: 3 };
+        let serializer = TypedReflectSerializer::new(&input, &registry);
+        assert_eq!(vec![0x92, 2, 3], rmp_serde::to_vec(&serializer).unwrap());
+    }
+
+    #[test]
+    fn should_sort_map_entries() {
+        use crate::serde::TypedReflectSerializer;
+
+        #[derive(Reflect)]
+        struct Level {
+            spawns: HashMap<String, (i32, i32)>,
+            layers: HashMap<u8, HashMap<String, bool>>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+
+        let input = Level {
+            spawns: HashMap::from([
+                (String::from("player"), (0, 0)),
+                (String::from("boss"), (10, 4)),
+                (String::from("door"), (5, 1)),
+            ]),
+            layers: HashMap::from([
+                (2, HashMap::from([(String::from("fog"), true)])),
+                (
+                    0,
+                    HashMap::from([(String::from("sky"), true), (String::from("clouds"), false)]),
+                ),
+            ]),
+        };
+
+        let serializer = TypedReflectSerializer::new(&input, &registry).with_sort_maps(true);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"(spawns:{"boss":(10,4),"door":(5,1),"player":(0,0)},layers:{0:{"clouds":false,"sky":true},2:{"fog":true}})"#,
+            output
+        );
+    }
+
+    #[test]
+    fn should_serialize_primitive_lists_as_slices() {
+        use crate::serde::TypedReflectSerializer;
+        use crate::List;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec<f32>>();
+        registry.register::<Vec<i64>>();
+
+        let positions = vec![0.5_f32, -1.0, 2.25];
+        let output = ron::ser::to_string(&TypedReflectSerializer::new(&positions, &registry));
+        assert_eq!("[0.5,-1.0,2.25]", output.unwrap());
+
+        // The output matches that of serializing each element through reflection
+        let indices = vec![3_i64, -7, 11];
+        let dynamic = indices.clone_dynamic();
+        assert_eq!(
+            ron::ser::to_string(&TypedReflectSerializer::new(&indices, &registry)).unwrap(),
+            ron::ser::to_string(&TypedReflectSerializer::new(&dynamic, &registry)).unwrap()
+        );
+    }
+
     #[test]
     fn should_return_error_if_missing_registration() {
-        let value = RangeInclusive::<f32>::new(0.0, 1.0);
+        // Ranges are reflected as structs, so only their fields need to be registered
+        let value = RangeInclusive::<Duration>::new(Duration::ZERO, Duration::from_secs(1));
         let registry = TypeRegistry::new();
 
         let serializer = ReflectSerializer::new(&value, &registry);
@@ -950,24 +1467,23 @@ mod tests {
         assert_eq!(
             error,
             ron::Error::Message(
-                "Type `core::ops::RangeInclusive<f32>` is not registered in the type registry"
-                    .to_string()
+                "Type `bevy_utils::Duration` is not registered in the type registry".to_string()
             )
         );
     }
 
     #[test]
     fn should_return_error_if_missing_type_data() {
-        let value = RangeInclusive::<f32>::new(0.0, 1.0);
+        let value = TypeId::of::<f32>();
         let mut registry = TypeRegistry::new();
-        registry.register::<RangeInclusive<f32>>();
+        registry.register::<TypeId>();
 
         let serializer = ReflectSerializer::new(&value, &registry);
         let error = ron::ser::to_string(&serializer).unwrap_err();
         assert_eq!(
             error,
             ron::Error::Message(
-                "Type `core::ops::RangeInclusive<f32>` did not register the `ReflectSerialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()
+                "Type `std::any::TypeId` did not register the `ReflectSerialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()
             )
         );
     }
@@ -0,0 +1,789 @@
+use crate::serde::{
+    ReflectMigrate, SerializationData, TypedReflectDeserializer, TypedReflectSerializer,
+    VERSION_FIELD,
+};
+use crate::{
+    DynamicEnum, DynamicStruct, Reflect, ReflectMut, ReflectRef, ReflectSerialize, Struct,
+    TypeInfo, TypePathTable, TypeRegistration, TypeRegistry, VariantType,
+};
+use serde::de::value::{MapDeserializer, SeqDeserializer, StringDeserializer};
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error as _, IntoDeserializer, Unexpected,
+    VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use thiserror::Error;
+use toml::{Table, Value};
+
+/// An error returned when converting reflected values to or from TOML.
+#[derive(Error, Debug)]
+pub enum TomlError {
+    /// The input is not valid TOML, or does not match the expected type.
+    #[error(transparent)]
+    Deserialize(#[from] toml::de::Error),
+    /// A value could not be serialized to TOML.
+    #[error(transparent)]
+    Serialize(#[from] toml::ser::Error),
+    /// A map key could not be converted to a TOML key.
+    #[error("map keys of type `{0}` cannot be written as TOML keys")]
+    UnsupportedKey(String),
+    /// A `None` value was found where TOML can't omit it, such as in an array.
+    #[error("`None` cannot be written in `{0}`, since TOML has no null value")]
+    UnsupportedNone(String),
+    /// The top-level value is not written as a table.
+    #[error("`{0}` cannot be written as a TOML document, which must be a table")]
+    NotATable(String),
+}
+
+/// Deserializes a TOML document into a reflected value of the type described by `registration`.
+///
+/// This goes through the [`TypedReflectDeserializer`], so it returns the dynamic equivalent
+/// of the value, unless its type registers [`ReflectDeserialize`],
+/// and can be converted to the concrete type using [`FromReflect`] or [`ReflectFromReflect`].
+///
+/// TOML differs from Rust's data model in a few ways, which are bridged as follows:
+/// * TOML keys are always strings, so map keys are parsed into the key type of the map,
+///   which allows maps with integer, float, boolean, or `char` keys
+/// * TOML datetimes are read as strings in RFC 3339 format, so they can be loaded into
+///   `String` fields or into any type that deserializes from such a string
+/// * TOML has no null value, so `Option` fields of structs which are missing are set to `None`,
+///   and present ones to `Some`
+/// * unit enum variants are written as strings, and other variants as tables with a single key,
+///   the name of the variant
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::from_toml_str;
+/// # use bevy_utils::HashMap;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Settings {
+///     window: Window,
+///     fullscreen: Option<bool>,
+///     bindings: HashMap<u8, String>,
+/// }
+///
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Window {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Settings>();
+///
+/// let input = r#"
+/// [window]
+/// width = 1280
+/// height = 720
+///
+/// [bindings]
+/// 1 = "jump"
+/// "#;
+///
+/// let registration = registry.get(TypeId::of::<Settings>()).unwrap();
+/// let output = from_toml_str(input, registration, &registry).unwrap();
+///
+/// let settings = Settings::from_reflect(&*output).unwrap();
+/// assert_eq!(settings.window, Window { width: 1280, height: 720 });
+/// assert_eq!(settings.fullscreen, None);
+/// assert_eq!(settings.bindings[&1], "jump");
+/// ```
+///
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+/// [`FromReflect`]: crate::FromReflect
+/// [`ReflectFromReflect`]: crate::ReflectFromReflect
+pub fn from_toml_str(
+    input: &str,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, TomlError> {
+    let table: Table = input.parse()?;
+    from_toml_value(Value::Table(table), registration, registry)
+}
+
+/// Deserializes a [`toml::Value`] into a reflected value of the type described by `registration`.
+///
+/// See [`from_toml_str`] for how TOML values are mapped to reflected types.
+pub fn from_toml_value(
+    value: Value,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, TomlError> {
+    let mut output = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(TomlDeserializer(value))?;
+    insert_missing_options(output.as_mut(), registry);
+    Ok(output)
+}
+
+/// Serializes a reflected value to a TOML document.
+///
+/// The value must be written as a table, such as a struct or a map.
+/// See [`to_toml_value`] for how the value is converted.
+pub fn to_toml_string(value: &dyn Reflect, registry: &TypeRegistry) -> Result<String, TomlError> {
+    match to_toml_value(value, registry)? {
+        Value::Table(table) => Ok(toml::to_string(&table)?),
+        _ => Err(TomlError::NotATable(value.reflect_type_path().to_string())),
+    }
+}
+
+/// Converts a reflected value to a [`toml::Value`], so that it can be read back with [`from_toml_value`].
+///
+/// Fields set to `None` are left out of their table, and map keys are converted to strings.
+/// Values with [`ReflectSerialize`] type data are converted using their own `Serialize` implementation.
+pub fn to_toml_value(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Value, TomlError> {
+    to_toml(value, registry)?
+        .ok_or_else(|| TomlError::UnsupportedNone(value.reflect_type_path().to_string()))
+}
+
+/// Converts a value to TOML, returning `None` for values that are left out, which are `None`s.
+fn to_toml(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Option<Value>, TomlError> {
+    let info = value.get_represented_type_info();
+
+    if let ReflectRef::Enum(value) = value.reflect_ref() {
+        if info.is_some_and(|info| is_option(info.type_path_table())) {
+            return match value.field_at(0) {
+                Some(some) => to_toml(some, registry),
+                None => Ok(None),
+            };
+        }
+    }
+
+    if info.is_some_and(|info| {
+        registry
+            .get_type_data::<ReflectSerialize>(info.type_id())
+            .is_some()
+    }) {
+        return Ok(Some(Value::try_from(TypedReflectSerializer::new(
+            value, registry,
+        ))?));
+    }
+
+    let serialization_data =
+        info.and_then(|info| registry.get_type_data::<SerializationData>(info.type_id()));
+    let is_skipped =
+        |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+    let type_path = value.reflect_type_path();
+
+    let value = match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let mut table = Table::new();
+            if let Some(migrate) =
+                info.and_then(|info| registry.get_type_data::<ReflectMigrate>(info.type_id()))
+            {
+                table.insert(
+                    VERSION_FIELD.to_string(),
+                    Value::Integer(migrate.version().into()),
+                );
+            }
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap();
+                // Dynamic structs may list their fields in a different order
+                let field_index = match info {
+                    Some(TypeInfo::Struct(info)) => info.index_of(name).unwrap_or(index),
+                    _ => index,
+                };
+                if is_skipped(field_index) {
+                    continue;
+                }
+                if let Some(field) = to_toml(field, registry)? {
+                    table.insert(name.to_string(), field);
+                }
+            }
+            Value::Table(table)
+        }
+        ReflectRef::TupleStruct(value) => to_toml_array(
+            value
+                .iter_fields()
+                .enumerate()
+                .filter(|(index, _)| !is_skipped(*index))
+                .map(|(_, field)| field),
+            type_path,
+            registry,
+        )?,
+        ReflectRef::Tuple(value) => to_toml_array(value.iter_fields(), type_path, registry)?,
+        ReflectRef::List(value) => to_toml_array(value.iter(), type_path, registry)?,
+        ReflectRef::Array(value) => to_toml_array(value.iter(), type_path, registry)?,
+        ReflectRef::Map(value) => {
+            let mut table = Table::new();
+            for (key, value) in value.iter() {
+                if let Some(value) = to_toml(value, registry)? {
+                    table.insert(to_toml_key(key, registry)?, value);
+                }
+            }
+            Value::Table(table)
+        }
+        ReflectRef::Enum(value) => {
+            let variant_name = value.variant_name().to_string();
+            let variant_index = match info {
+                Some(TypeInfo::Enum(info)) => info
+                    .index_of(&variant_name)
+                    .unwrap_or(value.variant_index()),
+                _ => value.variant_index(),
+            };
+            let serialization_data =
+                serialization_data.and_then(|data| data.variant(variant_index));
+            let is_skipped =
+                |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+            let fields = value
+                .iter_fields()
+                .enumerate()
+                .filter(|(index, _)| !is_skipped(*index))
+                .map(|(_, field)| field);
+
+            let payload = match value.variant_type() {
+                VariantType::Unit => return Ok(Some(Value::String(variant_name))),
+                VariantType::Struct => {
+                    let mut table = Table::new();
+                    for field in fields {
+                        if let Some(value) = to_toml(field.value(), registry)? {
+                            table.insert(field.name().unwrap().to_string(), value);
+                        }
+                    }
+                    Value::Table(table)
+                }
+                VariantType::Tuple if value.field_len() == 1 && !is_skipped(0) => {
+                    to_toml(value.field_at(0).unwrap(), registry)?
+                        .ok_or_else(|| TomlError::UnsupportedNone(type_path.to_string()))?
+                }
+                VariantType::Tuple => {
+                    to_toml_array(fields.map(|field| field.value()), type_path, registry)?
+                }
+            };
+            Value::Table(Table::from_iter([(variant_name, payload)]))
+        }
+        // Values without `ReflectSerialize` can't be serialized,
+        // so let the serializer report the error
+        ReflectRef::Value(value) => Value::try_from(TypedReflectSerializer::new(value, registry))?,
+    };
+    Ok(Some(value))
+}
+
+fn to_toml_array<'a>(
+    values: impl Iterator<Item = &'a dyn Reflect>,
+    type_path: &str,
+    registry: &TypeRegistry,
+) -> Result<Value, TomlError> {
+    values
+        .map(|value| {
+            to_toml(value, registry)?
+                .ok_or_else(|| TomlError::UnsupportedNone(type_path.to_string()))
+        })
+        .collect::<Result<_, _>>()
+        .map(Value::Array)
+}
+
+fn to_toml_key(key: &dyn Reflect, registry: &TypeRegistry) -> Result<String, TomlError> {
+    match to_toml(key, registry)? {
+        Some(Value::String(key)) => Ok(key),
+        Some(Value::Integer(key)) => Ok(key.to_string()),
+        Some(Value::Float(key)) => Ok(key.to_string()),
+        Some(Value::Boolean(key)) => Ok(key.to_string()),
+        _ => Err(TomlError::UnsupportedKey(
+            key.reflect_type_path().to_string(),
+        )),
+    }
+}
+
+/// Sets the missing `Option` fields of deserialized structs to `None`, since TOML has no null value.
+fn insert_missing_options(value: &mut dyn Reflect, registry: &TypeRegistry) {
+    if let Some(dynamic_struct) = value.downcast_mut::<DynamicStruct>() {
+        if let Some(TypeInfo::Struct(info)) = dynamic_struct.get_represented_type_info() {
+            for field in info.iter() {
+                if dynamic_struct.field(field.name()).is_none()
+                    && is_option(field.type_path_table())
+                {
+                    let mut none = DynamicEnum::new("None", ());
+                    none.set_represented_type(registry.get_type_info(field.type_id()));
+                    dynamic_struct.insert_boxed(field.name(), Box::new(none));
+                }
+            }
+        }
+    }
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                insert_missing_options(value.field_at_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                insert_missing_options(value.field_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                insert_missing_options(value.field_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                insert_missing_options(value.get_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                insert_missing_options(value.get_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::Map(value) => {
+            for index in 0..value.len() {
+                insert_missing_options(value.get_at_mut(index).unwrap().1, registry);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                insert_missing_options(value.field_at_mut(index).unwrap(), registry);
+            }
+        }
+        ReflectMut::Value(_) => {}
+    }
+}
+
+fn is_option(type_path: &TypePathTable) -> bool {
+    type_path.module_path() == Some("core::option") && type_path.ident() == Some("Option")
+}
+
+fn unexpected(value: &Value) -> Unexpected {
+    match value {
+        Value::String(value) => Unexpected::Str(value),
+        Value::Integer(value) => Unexpected::Signed(*value),
+        Value::Float(value) => Unexpected::Float(*value),
+        Value::Boolean(value) => Unexpected::Bool(*value),
+        Value::Datetime(_) => Unexpected::Other("datetime"),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Table(_) => Unexpected::Map,
+    }
+}
+
+/// A [`Deserializer`] over a [`toml::Value`], bridging the differences between TOML
+/// and the data model expected by the reflection deserializers.
+struct TomlDeserializer(Value);
+
+impl<'de> Deserializer<'de> for TomlDeserializer {
+    type Error = toml::de::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::String(value) => visitor.visit_string(value),
+            Value::Integer(value) => visitor.visit_i64(value),
+            Value::Float(value) => visitor.visit_f64(value),
+            Value::Boolean(value) => visitor.visit_bool(value),
+            Value::Datetime(value) => visitor.visit_string(value.to_string()),
+            Value::Array(values) => visitor.visit_seq(SeqDeserializer::new(
+                values.into_iter().map(TomlDeserializer),
+            )),
+            Value::Table(table) => visitor.visit_map(MapDeserializer::new(
+                table
+                    .into_iter()
+                    .map(|(key, value)| (TomlKeyDeserializer(key), TomlDeserializer(value))),
+            )),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Absent values are handled by the containing struct
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.0 {
+            Value::Array(values) if values.is_empty() => visitor.visit_unit(),
+            Value::Table(table) if table.is_empty() => visitor.visit_unit(),
+            value => Err(Self::Error::invalid_type(unexpected(value), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::String(variant) => {
+                let variant: StringDeserializer<Self::Error> = variant.into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            Value::Table(table) if table.len() == 1 => {
+                let (variant, value) = table.into_iter().next().unwrap();
+                visitor.visit_enum(TomlEnumAccess { variant, value })
+            }
+            value => Err(Self::Error::invalid_type(
+                unexpected(&value),
+                &"a variant name or a table with a single key",
+            )),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, toml::de::Error> for TomlDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct TomlEnumAccess {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> EnumAccess<'de> for TomlEnumAccess {
+    type Error = toml::de::Error;
+    type Variant = TomlDeserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant: StringDeserializer<Self::Error> = self.variant.into_deserializer();
+        Ok((seed.deserialize(variant)?, TomlDeserializer(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for TomlDeserializer {
+    type Error = toml::de::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match &self.0 {
+            Value::Array(values) if values.is_empty() => Ok(()),
+            Value::Table(table) if table.is_empty() => Ok(()),
+            value => Err(Self::Error::invalid_type(
+                unexpected(value),
+                &"unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+}
+
+/// A [`Deserializer`] for TOML keys, which parses them into the requested type when possible.
+struct TomlKeyDeserializer(String);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0.parse() {
+                    Ok(key) => visitor.$visit(key),
+                    // Let the visitor report the mismatch
+                    Err(_) => visitor.visit_string(self.0),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for TomlKeyDeserializer {
+    type Error = toml::de::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let variant: StringDeserializer<Self::Error> = self.0.into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, toml::de::Error> for TomlKeyDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use bevy_utils::HashMap;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        created: String,
+        volume: f32,
+        seed: Option<u64>,
+        title: Option<String>,
+        #[reflect(skip_serializing)]
+        loaded: bool,
+        mode: Mode,
+        fallback: Mode,
+        levels: HashMap<u32, Level>,
+        flags: HashMap<bool, char>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Level {
+        music: Option<String>,
+        spawns: Vec<(i32, i32)>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Mode {
+        Windowed,
+        Fullscreen { monitor: u8 },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Config>();
+        registry
+    }
+
+    fn from_toml(input: &str, registry: &TypeRegistry) -> Config {
+        let registration = registry.get(TypeId::of::<Config>()).unwrap();
+        let output = from_toml_str(input, registration, registry).unwrap();
+        Config::from_reflect(&*output).unwrap()
+    }
+
+    #[test]
+    fn should_deserialize_toml() {
+        let registry = get_registry();
+        let input = r#"
+name = "demo"
+created = 1979-05-27T07:32:00Z
+volume = 1
+title = "Demo"
+fallback = "Windowed"
+
+[mode.Fullscreen]
+monitor = 2
+
+[levels.1]
+spawns = [[0, 0], [4, -2]]
+
+[levels.12]
+music = "boss.ogg"
+spawns = []
+
+[flags]
+true = "y"
+"#;
+
+        let config = from_toml(input, &registry);
+        assert_eq!(
+            Config {
+                name: String::from("demo"),
+                created: String::from("1979-05-27T07:32:00Z"),
+                volume: 1.0,
+                seed: None,
+                title: Some(String::from("Demo")),
+                loaded: false,
+                mode: Mode::Fullscreen { monitor: 2 },
+                fallback: Mode::Windowed,
+                levels: HashMap::from([
+                    (
+                        1,
+                        Level {
+                            music: None,
+                            spawns: vec![(0, 0), (4, -2)],
+                        },
+                    ),
+                    (
+                        12,
+                        Level {
+                            music: Some(String::from("boss.ogg")),
+                            spawns: Vec::new(),
+                        },
+                    ),
+                ]),
+                flags: HashMap::from([(true, 'y')]),
+            },
+            config
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_toml() {
+        let registry = get_registry();
+        let input = Config {
+            name: String::from("roundtrip"),
+            created: String::from("2024-01-01"),
+            volume: 0.5,
+            seed: Some(7),
+            title: None,
+            loaded: true,
+            mode: Mode::Windowed,
+            fallback: Mode::Fullscreen { monitor: 1 },
+            levels: HashMap::from([(
+                3,
+                Level {
+                    music: None,
+                    spawns: vec![(1, 2)],
+                },
+            )]),
+            flags: HashMap::from([(false, 'n')]),
+        };
+
+        let output = to_toml_string(&input, &registry).unwrap();
+        assert!(!output.contains("title"));
+        assert!(!output.contains("loaded"));
+
+        assert_eq!(
+            Config {
+                loaded: false,
+                ..input
+            },
+            from_toml(&output, &registry)
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_keys() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Config>()).unwrap();
+        let input = r#"
+name = "demo"
+created = "today"
+volume = 1.0
+mode = "Windowed"
+fallback = "Windowed"
+flags = {}
+
+[levels.first]
+spawns = []
+"#;
+
+        assert!(matches!(
+            from_toml_str(input, registration, &registry),
+            Err(TomlError::Deserialize(_))
+        ));
+    }
+
+    #[test]
+    fn should_require_a_table() {
+        let registry = get_registry();
+        assert!(matches!(
+            to_toml_string(&5_u32, &registry),
+            Err(TomlError::NotATable(_))
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{FromType, Reflect};
 use bevy_utils::hashbrown::hash_map::Iter;
 use bevy_utils::HashMap;
 
@@ -6,6 +6,8 @@ use bevy_utils::HashMap;
 #[derive(Debug, Clone)]
 pub struct SerializationData {
     skipped_fields: HashMap<usize, SkippedField>,
+    defaulted_fields: HashMap<usize, SkippedField>,
+    variants: HashMap<usize, SerializationData>,
 }
 
 impl SerializationData {
@@ -20,8 +22,98 @@ impl SerializationData {
     pub fn new<I: Iterator<Item = (usize, SkippedField)>>(skipped_iter: I) -> Self {
         Self {
             skipped_fields: skipped_iter.collect(),
+            defaulted_fields: HashMap::default(),
+            variants: HashMap::default(),
         }
     }
+
+    /// Registers a default for a serialized field, used when that field is missing from the input.
+    ///
+    /// This is what `#[reflect(default)]` and `#[reflect(default = "path")]` generate for
+    /// fields that are not skipped, allowing data serialized before the field existed to still
+    /// be deserialized.
+    pub fn with_default(mut self, index: usize, field: SkippedField) -> Self {
+        self.defaulted_fields.insert(index, field);
+        self
+    }
+
+    /// Adds the serialization data for the fields of an enum variant.
+    ///
+    /// For enums, the skipped fields of each variant are stored separately,
+    /// keyed by the variant's index, since field indices are only unique within a variant.
+    pub fn with_variant(mut self, variant_index: usize, data: SerializationData) -> Self {
+        self.variants.insert(variant_index, data);
+        self
+    }
+
+    /// Expands the field at `index` into the `field_len` fields of a struct flattened into it
+    /// with `#[reflect(flatten)]`, merging in that struct's own serialization data.
+    ///
+    /// The indices of all fields after `index` are shifted to account for the flattened fields.
+    pub fn with_flattened(
+        mut self,
+        index: usize,
+        field_len: usize,
+        inner: Option<&SerializationData>,
+    ) -> Self {
+        let shift = |fields: HashMap<usize, SkippedField>| {
+            fields
+                .into_iter()
+                .filter(|(field_index, _)| *field_index != index)
+                .map(|(field_index, field)| {
+                    if field_index > index {
+                        (field_index - 1 + field_len, field)
+                    } else {
+                        (field_index, field)
+                    }
+                })
+                .collect::<HashMap<_, _>>()
+        };
+        self.skipped_fields = shift(self.skipped_fields);
+        self.defaulted_fields = shift(self.defaulted_fields);
+
+        if let Some(inner) = inner {
+            for (field_index, field) in &inner.skipped_fields {
+                self.skipped_fields
+                    .insert(index + field_index, field.clone());
+            }
+            for (field_index, field) in &inner.defaulted_fields {
+                self.defaulted_fields
+                    .insert(index + field_index, field.clone());
+            }
+        }
+        self
+    }
+
+    /// Returns the serialization data for the fields of the enum variant at the given index.
+    ///
+    /// Returns `None` if none of that variant's fields are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry, serde::SerializationData};
+    /// #[derive(Reflect)]
+    /// enum MyEnum {
+    ///   Unit,
+    ///   Struct {
+    ///     serialize_me: i32,
+    ///     #[reflect(skip_serializing)]
+    ///     skip_me: i32,
+    ///   },
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<MyEnum>();
+    ///
+    /// let serialization_data = registry.get_type_data::<SerializationData>(TypeId::of::<MyEnum>()).unwrap();
+    /// assert!(serialization_data.variant(0).is_none());
+    /// assert!(serialization_data.variant(1).unwrap().is_field_skipped(1));
+    /// ```
+    pub fn variant(&self, variant_index: usize) -> Option<&SerializationData> {
+        self.variants.get(&variant_index)
+    }
     /// Returns true if the given index corresponds to a field meant to be skipped during (de)serialization.
     ///
     /// # Example
@@ -93,6 +185,48 @@ impl SerializationData {
             .map(SkippedField::generate_default)
     }
 
+    /// Generates a value for the field at the given index when it is missing from the serialized data.
+    ///
+    /// This covers both skipped fields and fields marked with `#[reflect(default)]`.
+    /// Returns `None` if the field is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry, serde::SerializationData};
+    /// #[derive(Reflect)]
+    /// struct MyStruct {
+    ///   required: i32,
+    ///   #[reflect(default = "added_later_default")]
+    ///   added_later: i32
+    /// }
+    ///
+    /// fn added_later_default() -> i32 {
+    ///   5
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<MyStruct>();
+    ///
+    /// let serialization_data = registry.get_type_data::<SerializationData>(TypeId::of::<MyStruct>()).unwrap();
+    /// assert!(!serialization_data.is_field_skipped(1));
+    /// assert!(serialization_data.generate_missing(0).is_none());
+    /// assert_eq!(5, serialization_data.generate_missing(1).unwrap().take::<i32>().unwrap());
+    /// ```
+    pub fn generate_missing(&self, index: usize) -> Option<Box<dyn Reflect>> {
+        self.skipped_fields
+            .get(&index)
+            .or_else(|| self.defaulted_fields.get(&index))
+            .map(SkippedField::generate_default)
+    }
+
+    /// Returns true if the serialized field at the given index has a default,
+    /// meaning it may be missing from the input.
+    pub fn has_default(&self, index: usize) -> bool {
+        self.defaulted_fields.contains_key(&index)
+    }
+
     /// Returns the number of skipped fields.
     pub fn len(&self) -> usize {
         self.skipped_fields.len()
@@ -113,7 +247,8 @@ impl SerializationData {
     }
 }
 
-/// Data needed for (de)serialization of a skipped field.
+/// Data needed to fill in a field that is absent from serialized data,
+/// either because it is skipped or because it is marked with `#[reflect(default)]`.
 #[derive(Debug, Clone)]
 pub struct SkippedField {
     default_fn: fn() -> Box<dyn Reflect>,
@@ -134,3 +269,22 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+/// Type data marking a type whose serialized data must not contain unknown fields.
+///
+/// The [`TypedReflectDeserializer`] rejects unknown fields of such types, and of any value they contain,
+/// even when it is [configured] to skip them.
+/// This allows shipped data to be validated strictly, while development builds stay lenient.
+///
+/// It can be registered using `#[reflect(DenyUnknownFields)]`.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [configured]: crate::serde::TypedReflectDeserializer::with_deny_unknown_fields
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReflectDenyUnknownFields;
+
+impl<T> FromType<T> for ReflectDenyUnknownFields {
+    fn from_type() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,768 @@
+use crate::serde::{SelfDescribingReflectDeserializer, SelfDescribingReflectSerializer};
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Enum, Map, Reflect, ReflectFromReflect, ReflectKind,
+    ReflectMut, ReflectRef, TypeInfo, TypeRegistry, VariantField, VariantInfo, VariantType,
+};
+use serde::de::{Error as _, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::{self, Display, Formatter};
+use thiserror::Error;
+
+/// A reflected message that preserves the parts unknown to the local schema.
+///
+/// Peers running different versions of an application may disagree on the fields
+/// of a struct or the variants of an enum.
+/// A `WeakMessage` is serialized as its type path and its value,
+/// written with [`SelfDescribingReflectSerializer`],
+/// so that it can always be deserialized, whatever the local version of its type.
+///
+/// The received value is kept as is, as a dynamic payload:
+/// serializing the message again, such as when forwarding it to another peer,
+/// re-emits everything it contained,
+/// including fields and variants that the local schema doesn't know about.
+///
+/// [`WeakMessage::read`] converts the payload to the local schema,
+/// skipping unknown fields, and [`WeakMessage::update`] replaces the known parts
+/// of the payload with a local value while keeping the unknown fields.
+/// Unknown enum variants can't be represented by the local type,
+/// so reading them fails, but they are still forwarded.
+///
+/// Like with [`SelfDescribingReflectSerializer`],
+/// leaf values are limited to primitives and strings.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, TypeRegistry};
+/// # use bevy_reflect::serde::WeakMessage;
+/// mod v1 {
+///     # use bevy_reflect::Reflect;
+///     #[derive(Reflect, Debug, PartialEq)]
+///     #[type_path = "game"]
+///     pub struct Hit {
+///         pub damage: u32,
+///     }
+/// }
+///
+/// mod v2 {
+///     # use bevy_reflect::Reflect;
+///     #[derive(Reflect)]
+///     #[type_path = "game"]
+///     pub struct Hit {
+///         pub damage: u32,
+///         pub critical: bool,
+///     }
+/// }
+///
+/// // A newer peer sends a message with a field we don't know about
+/// let sent = WeakMessage::new(&v2::Hit { damage: 10, critical: true });
+/// let output = ron::to_string(&sent).unwrap();
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<v1::Hit>();
+///
+/// let received: WeakMessage = ron::from_str(&output).unwrap();
+/// let read = received.read(&registry).unwrap();
+/// assert_eq!(&[".critical".to_string()], read.unknown_fields());
+/// assert_eq!(
+///     Some(v1::Hit { damage: 10 }),
+///     v1::Hit::from_reflect(read.value())
+/// );
+///
+/// // The unknown field is forwarded as is
+/// assert_eq!(output, ron::to_string(&received).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct WeakMessage {
+    type_path: String,
+    payload: Box<dyn Reflect>,
+}
+
+impl WeakMessage {
+    /// Creates a message containing a copy of `value`.
+    pub fn new(value: &dyn Reflect) -> Self {
+        Self {
+            type_path: value.reflect_type_path().to_string(),
+            payload: value.clone_value(),
+        }
+    }
+
+    /// The type path of the message's value.
+    pub fn type_path(&self) -> &str {
+        &self.type_path
+    }
+
+    /// The value of the message, including the parts unknown to the local schema.
+    pub fn payload(&self) -> &dyn Reflect {
+        &*self.payload
+    }
+
+    /// Converts the payload to the local version of its type.
+    ///
+    /// Fields unknown to the local schema are skipped,
+    /// and reported by [`WeakValue::unknown_fields`].
+    /// Fields missing from the payload are left missing,
+    /// so that [`FromReflect`] can use their default value, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload contains an enum variant unknown to the local schema,
+    /// or if its structure doesn't match the local schema.
+    ///
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn read(&self, registry: &TypeRegistry) -> Result<WeakValue, WeakSchemaError> {
+        let info = registry
+            .get_with_type_path(&self.type_path)
+            .ok_or_else(|| WeakSchemaError::NotRegistered(self.type_path.clone()))?
+            .type_info();
+        let mut conformer = Conformer {
+            registry,
+            path: String::new(),
+            unknown_fields: Vec::new(),
+        };
+        let value = conformer.conform(&*self.payload, info)?;
+        Ok(WeakValue {
+            value,
+            unknown_fields: conformer.unknown_fields,
+        })
+    }
+
+    /// Replaces the payload with `value`, keeping the fields of the previous payload
+    /// that `value` doesn't have.
+    ///
+    /// This is used to forward a message after modifying it locally,
+    /// without dropping the fields that only newer peers know about.
+    pub fn update(&mut self, value: &dyn Reflect) {
+        let mut payload = value.clone_value();
+        restore_unknown_fields(&mut *payload, &*self.payload);
+        self.type_path = value.reflect_type_path().to_string();
+        self.payload = payload;
+    }
+}
+
+impl Serialize for WeakMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(
+            &self.type_path,
+            &SelfDescribingReflectSerializer::new(&*self.payload),
+        )?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for WeakMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct WeakMessageVisitor;
+
+        impl<'de> Visitor<'de> for WeakMessageVisitor {
+            type Value = WeakMessage;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("map containing a type path and a self-describing value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let type_path = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &"a single entry"))?;
+                let payload = map.next_value_seed(SelfDescribingReflectDeserializer)?;
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(2, &"a single entry"));
+                }
+                Ok(WeakMessage { type_path, payload })
+            }
+        }
+
+        deserializer.deserialize_map(WeakMessageVisitor)
+    }
+}
+
+/// The value of a [`WeakMessage`], converted to the local schema by [`WeakMessage::read`].
+#[derive(Debug)]
+pub struct WeakValue {
+    value: Box<dyn Reflect>,
+    unknown_fields: Vec<String>,
+}
+
+impl WeakValue {
+    /// The converted value, as a dynamic type representing the local type.
+    ///
+    /// Use [`FromReflect`](crate::FromReflect) to get the concrete type.
+    pub fn value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// Consumes the read value, returning the converted value.
+    pub fn into_value(self) -> Box<dyn Reflect> {
+        self.value
+    }
+
+    /// The [paths] of the fields of the payload that are unknown to the local schema.
+    ///
+    /// Map entries are denoted by their key between square brackets,
+    /// which is not a valid path segment.
+    ///
+    /// [paths]: crate::ReflectPath
+    pub fn unknown_fields(&self) -> &[String] {
+        &self.unknown_fields
+    }
+}
+
+/// An error returned when reading a [`WeakMessage`].
+#[derive(Debug, Error, PartialEq)]
+pub enum WeakSchemaError {
+    /// The type of the message, or of one of its fields, is not registered.
+    #[error("no registration found for type `{0}`")]
+    NotRegistered(String),
+    /// The payload contains an enum variant unknown to the local schema.
+    #[error("unknown variant `{variant}` of enum `{enum_path}` at `{path}`")]
+    UnknownVariant {
+        path: String,
+        enum_path: &'static str,
+        variant: String,
+    },
+    /// The payload has a different [kind](ReflectKind) than the local type.
+    #[error("expected {expected} at `{path}` but received {received}")]
+    MismatchedKinds {
+        path: String,
+        expected: ReflectKind,
+        received: ReflectKind,
+    },
+    /// A leaf value of the payload can't be converted to the local type.
+    #[error("expected value of type `{expected}` at `{path}` but received `{received}`")]
+    MismatchedTypes {
+        path: String,
+        expected: &'static str,
+        received: String,
+    },
+}
+
+struct Conformer<'a> {
+    registry: &'a TypeRegistry,
+    path: String,
+    unknown_fields: Vec<String>,
+}
+
+impl<'a> Conformer<'a> {
+    fn conform(
+        &mut self,
+        value: &dyn Reflect,
+        info: &'static TypeInfo,
+    ) -> Result<Box<dyn Reflect>, WeakSchemaError> {
+        match (info, value.reflect_ref()) {
+            (TypeInfo::Struct(struct_info), ReflectRef::Struct(value)) => {
+                let mut dynamic = DynamicStruct::default();
+                dynamic.set_represented_type(Some(info));
+                for (index, field) in value.iter_fields().enumerate() {
+                    let name = value.name_at(index).unwrap();
+                    let Some(field_info) = struct_info.field(name) else {
+                        self.unknown(format_args!(".{name}"));
+                        continue;
+                    };
+                    let field_info =
+                        self.type_info(field_info.type_id(), field_info.type_path())?;
+                    dynamic.insert_boxed(
+                        name,
+                        self.nested(format_args!(".{name}"), field, field_info)?,
+                    );
+                }
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::TupleStruct(tuple_struct_info), ReflectRef::TupleStruct(value)) => {
+                let mut dynamic = DynamicTupleStruct::default();
+                dynamic.set_represented_type(Some(info));
+                for (index, field) in value.iter_fields().enumerate() {
+                    let Some(field_info) = tuple_struct_info.field_at(index) else {
+                        self.unknown(format_args!(".{index}"));
+                        continue;
+                    };
+                    let field_info =
+                        self.type_info(field_info.type_id(), field_info.type_path())?;
+                    dynamic.insert_boxed(self.nested(
+                        format_args!(".{index}"),
+                        field,
+                        field_info,
+                    )?);
+                }
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::Tuple(tuple_info), ReflectRef::Tuple(value)) => {
+                let mut dynamic = DynamicTuple::default();
+                dynamic.set_represented_type(Some(info));
+                for (index, field) in value.iter_fields().enumerate() {
+                    let Some(field_info) = tuple_info.field_at(index) else {
+                        self.unknown(format_args!(".{index}"));
+                        continue;
+                    };
+                    let field_info =
+                        self.type_info(field_info.type_id(), field_info.type_path())?;
+                    dynamic.insert_boxed(self.nested(
+                        format_args!(".{index}"),
+                        field,
+                        field_info,
+                    )?);
+                }
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::List(list_info), ReflectRef::List(value)) => {
+                let item_info = self.type_info(
+                    list_info.item_type_id(),
+                    list_info.item_type_path_table().path(),
+                )?;
+                let mut dynamic = DynamicList::default();
+                dynamic.set_represented_type(Some(info));
+                for (index, item) in value.iter().enumerate() {
+                    dynamic.push_box(self.nested(format_args!("[{index}]"), item, item_info)?);
+                }
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::Array(array_info), ReflectRef::Array(value)) => {
+                let item_info = self.type_info(
+                    array_info.item_type_id(),
+                    array_info.item_type_path_table().path(),
+                )?;
+                let items = value
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| self.nested(format_args!("[{index}]"), item, item_info))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut dynamic = DynamicArray::new(items.into_boxed_slice());
+                dynamic.set_represented_type(Some(info));
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::Map(map_info), ReflectRef::Map(value)) => {
+                let key_info = self.type_info(
+                    map_info.key_type_id(),
+                    map_info.key_type_path_table().path(),
+                )?;
+                let value_info = self.type_info(
+                    map_info.value_type_id(),
+                    map_info.value_type_path_table().path(),
+                )?;
+                let mut dynamic = DynamicMap::default();
+                dynamic.set_represented_type(Some(info));
+                for (key, value) in value.iter() {
+                    let segment = format!("[{key:?}]");
+                    let key = self.nested(&segment, key, key_info)?;
+                    let value = self.nested(&segment, value, value_info)?;
+                    dynamic.insert_boxed(key, value);
+                }
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::Enum(enum_info), ReflectRef::Enum(value)) => {
+                let variant_name = value.variant_name();
+                let Some(variant_index) = enum_info.index_of(variant_name) else {
+                    return Err(WeakSchemaError::UnknownVariant {
+                        path: self.path.clone(),
+                        enum_path: enum_info.type_path(),
+                        variant: variant_name.to_string(),
+                    });
+                };
+                let variant = match enum_info.variant_at(variant_index).unwrap() {
+                    VariantInfo::Unit(_) => {
+                        for (index, field) in value.iter_fields().enumerate() {
+                            self.unknown(variant_field_segment(&field, index));
+                        }
+                        DynamicVariant::Unit
+                    }
+                    VariantInfo::Struct(variant_info) => {
+                        let mut dynamic = DynamicStruct::default();
+                        for (index, field) in value.iter_fields().enumerate() {
+                            let segment = variant_field_segment(&field, index);
+                            let Some((name, field_info)) = field
+                                .name()
+                                .and_then(|name| Some((name, variant_info.field(name)?)))
+                            else {
+                                self.unknown(segment);
+                                continue;
+                            };
+                            let field_info =
+                                self.type_info(field_info.type_id(), field_info.type_path())?;
+                            dynamic.insert_boxed(
+                                name,
+                                self.nested(segment, field.value(), field_info)?,
+                            );
+                        }
+                        DynamicVariant::Struct(dynamic)
+                    }
+                    VariantInfo::Tuple(variant_info) => {
+                        let mut dynamic = DynamicTuple::default();
+                        for (index, field) in value.iter_fields().enumerate() {
+                            let segment = variant_field_segment(&field, index);
+                            let Some(field_info) = variant_info
+                                .field_at(index)
+                                .filter(|_| field.name().is_none())
+                            else {
+                                self.unknown(segment);
+                                continue;
+                            };
+                            let field_info =
+                                self.type_info(field_info.type_id(), field_info.type_path())?;
+                            dynamic.insert_boxed(self.nested(
+                                segment,
+                                field.value(),
+                                field_info,
+                            )?);
+                        }
+                        DynamicVariant::Tuple(dynamic)
+                    }
+                };
+                let mut dynamic = DynamicEnum::new_with_index(variant_index, variant_name, variant);
+                dynamic.set_represented_type(Some(info));
+                Ok(Box::new(dynamic))
+            }
+            (TypeInfo::Value(_), ReflectRef::Value(value)) => self.convert_value(value, info),
+            (info, value) => Err(WeakSchemaError::MismatchedKinds {
+                path: self.path.clone(),
+                expected: kind_of(info),
+                received: value.kind(),
+            }),
+        }
+    }
+
+    /// Converts a leaf value, which is written as a primitive by the self-describing format.
+    fn convert_value(
+        &self,
+        value: &dyn Reflect,
+        info: &'static TypeInfo,
+    ) -> Result<Box<dyn Reflect>, WeakSchemaError> {
+        if value.as_any().type_id() == info.type_id() {
+            return Ok(value.clone_value());
+        }
+
+        let from_reflect = self
+            .registry
+            .get_type_data::<ReflectFromReflect>(info.type_id())
+            .and_then(|from_reflect| from_reflect.from_reflect(value));
+        if let Some(converted) = from_reflect {
+            return Ok(converted);
+        }
+
+        // `usize` and `isize` are written as `u64` and `i64`
+        let any = value.as_any();
+        if info.is::<usize>() {
+            if let Some(converted) = any
+                .downcast_ref::<u64>()
+                .and_then(|&v| usize::try_from(v).ok())
+            {
+                return Ok(Box::new(converted));
+            }
+        } else if info.is::<isize>() {
+            if let Some(converted) = any
+                .downcast_ref::<i64>()
+                .and_then(|&v| isize::try_from(v).ok())
+            {
+                return Ok(Box::new(converted));
+            }
+        }
+
+        Err(WeakSchemaError::MismatchedTypes {
+            path: self.path.clone(),
+            expected: info.type_path(),
+            received: value.reflect_type_path().to_string(),
+        })
+    }
+
+    fn nested(
+        &mut self,
+        segment: impl Display,
+        value: &dyn Reflect,
+        info: &'static TypeInfo,
+    ) -> Result<Box<dyn Reflect>, WeakSchemaError> {
+        let len = self.path.len();
+        self.path.push_str(&segment.to_string());
+        let result = self.conform(value, info);
+        self.path.truncate(len);
+        result
+    }
+
+    fn unknown(&mut self, segment: impl Display) {
+        self.unknown_fields.push(format!("{}{segment}", self.path));
+    }
+
+    fn type_info(
+        &self,
+        type_id: TypeId,
+        type_path: &str,
+    ) -> Result<&'static TypeInfo, WeakSchemaError> {
+        self.registry
+            .get_type_info(type_id)
+            .ok_or_else(|| WeakSchemaError::NotRegistered(type_path.to_string()))
+    }
+}
+
+fn variant_field_segment(field: &VariantField, index: usize) -> String {
+    match field.name() {
+        Some(name) => format!(".{name}"),
+        None => format!(".{index}"),
+    }
+}
+
+fn kind_of(info: &TypeInfo) -> ReflectKind {
+    match info {
+        TypeInfo::Struct(_) => ReflectKind::Struct,
+        TypeInfo::TupleStruct(_) => ReflectKind::TupleStruct,
+        TypeInfo::Tuple(_) => ReflectKind::Tuple,
+        TypeInfo::List(_) => ReflectKind::List,
+        TypeInfo::Array(_) => ReflectKind::Array,
+        TypeInfo::Map(_) => ReflectKind::Map,
+        TypeInfo::Enum(_) => ReflectKind::Enum,
+        TypeInfo::Value(_) => ReflectKind::Value,
+    }
+}
+
+/// Copies the fields of `old` that `new` doesn't have into `new`, recursively.
+///
+/// Fields can only be added to dynamic types, which is what [`Reflect::clone_value`] returns.
+fn restore_unknown_fields(new: &mut dyn Reflect, old: &dyn Reflect) {
+    match (new.reflect_mut(), old.reflect_ref()) {
+        (ReflectMut::Struct(new), ReflectRef::Struct(old)) => {
+            for (index, old_field) in old.iter_fields().enumerate() {
+                let name = old.name_at(index).unwrap();
+                if let Some(new_field) = new.field_mut(name) {
+                    restore_unknown_fields(new_field, old_field);
+                } else if let Some(new) = new.as_any_mut().downcast_mut::<DynamicStruct>() {
+                    new.insert_boxed(name, old_field.clone_value());
+                }
+            }
+        }
+        (ReflectMut::TupleStruct(new), ReflectRef::TupleStruct(old)) => {
+            for (index, old_field) in old.iter_fields().enumerate() {
+                if let Some(new_field) = new.field_mut(index) {
+                    restore_unknown_fields(new_field, old_field);
+                } else if let Some(new) = new.as_any_mut().downcast_mut::<DynamicTupleStruct>() {
+                    new.insert_boxed(old_field.clone_value());
+                }
+            }
+        }
+        (ReflectMut::Tuple(new), ReflectRef::Tuple(old)) => {
+            for (index, old_field) in old.iter_fields().enumerate() {
+                if let Some(new_field) = new.field_mut(index) {
+                    restore_unknown_fields(new_field, old_field);
+                } else if let Some(new) = new.as_any_mut().downcast_mut::<DynamicTuple>() {
+                    new.insert_boxed(old_field.clone_value());
+                }
+            }
+        }
+        (ReflectMut::List(new), ReflectRef::List(old)) => {
+            for (index, old_item) in old.iter().enumerate() {
+                if let Some(new_item) = new.get_mut(index) {
+                    restore_unknown_fields(new_item, old_item);
+                }
+            }
+        }
+        (ReflectMut::Array(new), ReflectRef::Array(old)) => {
+            for (index, old_item) in old.iter().enumerate() {
+                if let Some(new_item) = new.get_mut(index) {
+                    restore_unknown_fields(new_item, old_item);
+                }
+            }
+        }
+        (ReflectMut::Map(new), ReflectRef::Map(old)) => {
+            for (key, old_value) in old.iter() {
+                if let Some(new_value) = new.get_mut(key) {
+                    restore_unknown_fields(new_value, old_value);
+                }
+            }
+        }
+        (ReflectMut::Enum(new), ReflectRef::Enum(old))
+            if new.variant_name() == old.variant_name() =>
+        {
+            let mut missing = Vec::new();
+            for (index, old_field) in old.iter_fields().enumerate() {
+                let new_field = match old_field.name() {
+                    Some(name) => new.field_mut(name),
+                    None => new.field_at_mut(index),
+                };
+                match new_field {
+                    Some(new_field) => restore_unknown_fields(new_field, old_field.value()),
+                    None => missing.push((old_field.name(), old_field.value())),
+                }
+            }
+            if let Some(new) = new.as_any_mut().downcast_mut::<DynamicEnum>() {
+                restore_variant_fields(new, missing);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn restore_variant_fields(new: &mut DynamicEnum, missing: Vec<(Option<&str>, &dyn Reflect)>) {
+    if missing.is_empty() {
+        return;
+    }
+
+    if new.variant_type() == VariantType::Struct
+        || (new.variant_type() == VariantType::Unit && missing[0].0.is_some())
+    {
+        for (name, value) in missing {
+            if let Some(name) = name {
+                new.insert_field_boxed(name, value.clone_value());
+            }
+        }
+    } else {
+        let mut fields = DynamicTuple::default();
+        for field in new.iter_fields() {
+            fields.insert_boxed(field.value().clone_value());
+        }
+        for (name, value) in missing {
+            if name.is_none() {
+                fields.insert_boxed(value.clone_value());
+            }
+        }
+        let variant_name = new.variant_name().to_string();
+        new.set_variant_with_index(new.variant_index(), variant_name, fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WeakMessage, WeakSchemaError};
+    use crate::{FromReflect, ReflectKind, TypeRegistry};
+
+    mod v1 {
+        use crate::{self as bevy_reflect, Reflect};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        pub struct Attack {
+            pub damage: u32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        pub enum Action {
+            Idle,
+            Attack(Attack),
+        }
+    }
+
+    mod v2 {
+        use crate::{self as bevy_reflect, Reflect};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        pub struct Attack {
+            pub damage: u32,
+            pub critical: bool,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        pub enum Action {
+            Idle,
+            Attack(Attack),
+            Dodge { distance: f32 },
+        }
+    }
+
+    fn forward(message: &WeakMessage) -> WeakMessage {
+        let output = ron::to_string(message).unwrap();
+        ron::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn should_forward_unknown_fields() {
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<v1::Action>();
+        let mut new_registry = TypeRegistry::default();
+        new_registry.register::<v2::Action>();
+
+        let sent = WeakMessage::new(&v2::Action::Attack(v2::Attack {
+            damage: 5,
+            critical: true,
+        }));
+
+        let mut received = forward(&sent);
+        let read = received.read(&old_registry).unwrap();
+        assert_eq!(&[".0.critical".to_string()], read.unknown_fields());
+        let mut action = v1::Action::from_reflect(read.value()).unwrap();
+        assert_eq!(v1::Action::Attack(v1::Attack { damage: 5 }), action);
+
+        // Modify the known part of the message before forwarding it
+        let v1::Action::Attack(attack) = &mut action else {
+            unreachable!()
+        };
+        attack.damage = 6;
+        received.update(&action);
+
+        let forwarded = forward(&received);
+        let read = forwarded.read(&new_registry).unwrap();
+        assert!(read.unknown_fields().is_empty());
+        assert_eq!(
+            Some(v2::Action::Attack(v2::Attack {
+                damage: 6,
+                critical: true,
+            })),
+            v2::Action::from_reflect(read.value())
+        );
+    }
+
+    #[test]
+    fn should_forward_unknown_variants() {
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<v1::Action>();
+        let mut new_registry = TypeRegistry::default();
+        new_registry.register::<v2::Action>();
+
+        let sent = WeakMessage::new(&v2::Action::Dodge { distance: 2.0 });
+
+        let received = forward(&sent);
+        assert_eq!(
+            Err(WeakSchemaError::UnknownVariant {
+                path: String::new(),
+                enum_path: "game::Action",
+                variant: "Dodge".to_string(),
+            }),
+            received.read(&old_registry).map(|_| ())
+        );
+
+        let forwarded = forward(&received);
+        let read = forwarded.read(&new_registry).unwrap();
+        assert_eq!(
+            Some(v2::Action::Dodge { distance: 2.0 }),
+            v2::Action::from_reflect(read.value())
+        );
+    }
+
+    #[test]
+    fn should_not_read_mismatched_kinds() {
+        mod v3 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Attack(pub u32);
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<v3::Attack>();
+
+        let received = forward(&WeakMessage::new(&v1::Attack { damage: 1 }));
+        assert_eq!(
+            Err(WeakSchemaError::NotRegistered("game::Attack".to_string())),
+            received.read(&TypeRegistry::empty()).map(|_| ())
+        );
+        assert_eq!(
+            Err(WeakSchemaError::MismatchedKinds {
+                path: String::new(),
+                expected: ReflectKind::TupleStruct,
+                received: ReflectKind::Struct,
+            }),
+            received.read(&registry).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{FromReflect, FromType, Reflect, TypePath, TypeRegistry};
+use serde::{Deserializer, Serialize, Serializer};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A type which can be serialized with access to the [`TypeRegistry`].
+///
+/// This is useful for types which refer to other types or values through the registry,
+/// such as handles, and can't implement [`Serialize`] on their own.
+/// Register [`ReflectSerializeWithRegistry`] for such types to have the
+/// [`TypedReflectSerializer`] use this implementation.
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+pub trait SerializeWithRegistry {
+    /// Serializes this value, using `registry` to resolve any types it refers to.
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// A type which can be deserialized with access to the [`TypeRegistry`].
+///
+/// This is the counterpart of [`SerializeWithRegistry`].
+/// Register [`ReflectDeserializeWithRegistry`] for such types to have the
+/// [`TypedReflectDeserializer`] use this implementation.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+pub trait DeserializeWithRegistry: Sized {
+    /// Deserializes a value, using `registry` to resolve any types it refers to.
+    fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// A struct used to serialize reflected instances of a type with access to the [`TypeRegistry`].
+///
+/// When registered for a type, the [`TypedReflectSerializer`] uses it instead of
+/// [`ReflectSerialize`] or the structure of the type.
+///
+/// A `ReflectSerializeWithRegistry` for type `T` can be obtained via
+/// [`FromType::from_type`].
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use serde::{Deserialize, Deserializer, Serialize, Serializer};
+/// # use serde::de::{DeserializeSeed, Error as _};
+/// # use serde::ser::Error as _;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{
+/// #     DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+/// #     SerializeWithRegistry, TypedReflectDeserializer, TypedReflectSerializer,
+/// # };
+/// /// Refers to a registered type, and is serialized as its type path.
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[reflect(SerializeWithRegistry, DeserializeWithRegistry)]
+/// struct TypeRef(TypeId);
+///
+/// impl SerializeWithRegistry for TypeRef {
+///     fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+///     where
+///         S: Serializer,
+///     {
+///         let registration = registry
+///             .get(self.0)
+///             .ok_or_else(|| S::Error::custom("unregistered type"))?;
+///         registration.type_info().type_path().serialize(serializer)
+///     }
+/// }
+///
+/// impl DeserializeWithRegistry for TypeRef {
+///     fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+///     where
+///         D: Deserializer<'de>,
+///     {
+///         let type_path = String::deserialize(deserializer)?;
+///         let registration = registry
+///             .get_with_type_path(&type_path)
+///             .ok_or_else(|| D::Error::custom(format_args!("unregistered type `{type_path}`")))?;
+///         Ok(TypeRef(registration.type_id()))
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<TypeRef>();
+///
+/// let value = TypeRef(TypeId::of::<f32>());
+/// let output = ron::to_string(&TypedReflectSerializer::new(&value, &registry)).unwrap();
+/// assert_eq!(r#""f32""#, output);
+///
+/// let registration = registry.get(TypeId::of::<TypeRef>()).unwrap();
+/// let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+/// let output = TypedReflectDeserializer::new(registration, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(value, TypeRef::from_reflect(&*output).unwrap());
+/// ```
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+#[derive(Clone)]
+pub struct ReflectSerializeWithRegistry {
+    get_serializable: Arc<
+        dyn for<'a> Fn(&'a dyn Reflect, &'a TypeRegistry) -> Box<dyn erased_serde::Serialize + 'a>
+            + Send
+            + Sync,
+    >,
+}
+
+impl ReflectSerializeWithRegistry {
+    /// Creates a [`ReflectSerializeWithRegistry`] from a function turning a value into a serializable representation.
+    ///
+    /// Usually, a [`ReflectSerializeWithRegistry`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(
+        get_serializable: impl for<'a> Fn(&'a dyn Reflect, &'a TypeRegistry) -> Box<dyn erased_serde::Serialize + 'a>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self {
+            get_serializable: Arc::new(get_serializable),
+        }
+    }
+
+    /// Serializes a reflected value, using `registry` to resolve any types it refers to.
+    pub fn serialize<S>(
+        &self,
+        value: &dyn Reflect,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.get_serializable)(value, registry).serialize(serializer)
+    }
+}
+
+impl<T: TypePath + FromReflect + SerializeWithRegistry> FromType<T>
+    for ReflectSerializeWithRegistry
+{
+    fn from_type() -> Self {
+        ReflectSerializeWithRegistry::new(|value, registry| {
+            if let Some(value) = value.downcast_ref::<T>() {
+                return Box::new(WithRegistry { value, registry });
+            }
+            let value = T::from_reflect(value).unwrap_or_else(|| {
+                panic!(
+                    "FromReflect::from_reflect failed when called on type `{}` with this value: {value:?}",
+                    T::type_path(),
+                );
+            });
+            Box::new(WithRegistry {
+                value: Box::new(value),
+                registry,
+            })
+        })
+    }
+}
+
+/// A value paired with the registry it is serialized with.
+struct WithRegistry<'a, V> {
+    value: V,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, V> Serialize for WithRegistry<'a, V>
+where
+    V: Deref,
+    V::Target: SerializeWithRegistry,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializeWithRegistry::serialize(&*self.value, serializer, self.registry)
+    }
+}
+
+/// A struct used to deserialize reflected instances of a type with access to the [`TypeRegistry`].
+///
+/// When registered for a type, the [`TypedReflectDeserializer`] uses it instead of
+/// [`ReflectDeserialize`] or the structure of the type.
+///
+/// A `ReflectDeserializeWithRegistry` for type `T` can be obtained via
+/// [`FromType::from_type`].
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+#[derive(Clone)]
+pub struct ReflectDeserializeWithRegistry {
+    func: Arc<
+        dyn Fn(
+                &mut dyn erased_serde::Deserializer,
+                &TypeRegistry,
+            ) -> Result<Box<dyn Reflect>, erased_serde::Error>
+            + Send
+            + Sync,
+    >,
+}
+
+impl ReflectDeserializeWithRegistry {
+    /// Creates a [`ReflectDeserializeWithRegistry`] from a function deserializing a reflected value.
+    ///
+    /// Usually, a [`ReflectDeserializeWithRegistry`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(
+        func: impl Fn(
+                &mut dyn erased_serde::Deserializer,
+                &TypeRegistry,
+            ) -> Result<Box<dyn Reflect>, erased_serde::Error>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self {
+            func: Arc::new(func),
+        }
+    }
+
+    /// Deserializes a reflected value, using `registry` to resolve any types it refers to.
+    pub fn deserialize<'de, D>(
+        &self,
+        deserializer: D,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.func)(&mut erased, registry)
+            .map_err(<<D as Deserializer<'de>>::Error as serde::de::Error>::custom)
+    }
+}
+
+impl<T: Reflect + DeserializeWithRegistry> FromType<T> for ReflectDeserializeWithRegistry {
+    fn from_type() -> Self {
+        ReflectDeserializeWithRegistry::new(|deserializer, registry| {
+            Ok(Box::new(T::deserialize(deserializer, registry)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{
+        ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+    };
+    use crate::DynamicTupleStruct;
+    use serde::de::{DeserializeSeed, Error as _};
+    use serde::ser::Error as _;
+    use serde::Deserialize;
+    use std::any::TypeId;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(SerializeWithRegistry, DeserializeWithRegistry)]
+    struct TypeRef(TypeId);
+
+    impl SerializeWithRegistry for TypeRef {
+        fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let registration = registry
+                .get(self.0)
+                .ok_or_else(|| S::Error::custom("unregistered type"))?;
+            registration
+                .type_info()
+                .type_path_table()
+                .short_path()
+                .serialize(serializer)
+        }
+    }
+
+    impl DeserializeWithRegistry for TypeRef {
+        fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let short_path = String::deserialize(deserializer)?;
+            let registration = registry
+                .try_get_with_short_type_path(&short_path)
+                .map_err(D::Error::custom)?
+                .ok_or_else(|| {
+                    D::Error::custom(format_args!("unregistered type `{short_path}`"))
+                })?;
+            Ok(TypeRef(registration.type_id()))
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Spawner {
+        kind: TypeRef,
+        count: u32,
+    }
+
+    #[derive(Reflect)]
+    struct Enemy;
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Spawner>();
+        registry.register::<Enemy>();
+        registry
+    }
+
+    #[test]
+    fn should_serialize_with_registry() {
+        let registry = get_registry();
+        let spawner = Spawner {
+            kind: TypeRef(TypeId::of::<Enemy>()),
+            count: 3,
+        };
+
+        let output = ron::to_string(&ReflectSerializer::new(&spawner, &registry)).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::with_registry::tests::Spawner":(kind:"Enemy",count:3)}"#,
+            output
+        );
+
+        let mut deserializer = ron::Deserializer::from_str(&output).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(spawner, Spawner::from_reflect(&*value).unwrap());
+    }
+
+    #[test]
+    fn should_serialize_dynamic_values_with_registry() {
+        let registry = get_registry();
+        let mut value = DynamicTupleStruct::default();
+        value.insert(TypeId::of::<Enemy>());
+        value.set_represented_type(registry.get_type_info(TypeId::of::<TypeRef>()));
+
+        let output = ron::to_string(&TypedReflectSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(r#""Enemy""#, output);
+    }
+
+    #[test]
+    fn should_report_deserialization_errors() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<TypeRef>()).unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(r#""Missing""#).unwrap();
+        let error = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("unregistered type `Missing`"));
+    }
+}
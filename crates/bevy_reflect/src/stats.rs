@@ -0,0 +1,236 @@
+//! Counters for how often reflection hot paths are hit.
+//!
+//! Reflection is convenient, but some of its operations are far more expensive
+//! than their static counterparts: [`Reflect::clone_value`] allocates a dynamic
+//! copy of the entire value, [`Reflect::apply`] walks it field by field,
+//! and every [`TypeRegistry`] lookup hashes a [`TypeId`] or type path.
+//! This module counts these operations so that code which leans on them too
+//! heavily can be found.
+//!
+//! The counters are process-wide and shared by all threads.
+//! They are only updated when the `stats` feature is enabled,
+//! so builds without it pay nothing for them.
+//!
+//! Most users will want to read these counters once per frame using
+//! [`take`], which returns the counts since the last call and resets them.
+//! To attribute operations to a specific piece of code instead,
+//! wrap it in [`measure`], which only counts the operations made on the current thread.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{prelude::*, stats};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     health: u32,
+//! }
+//!
+//! stats::take();
+//!
+//! let mut player = Player { health: 100 };
+//! let clone = player.clone_value();
+//! player.apply(&*clone);
+//!
+//! let counts = stats::snapshot();
+//! assert!(counts.clones >= 1);
+//! assert!(counts.applies >= 1);
+//! ```
+//!
+//! [`Reflect::clone_value`]: crate::Reflect::clone_value
+//! [`Reflect::apply`]: crate::Reflect::apply
+//! [`TypeRegistry`]: crate::TypeRegistry
+//! [`TypeId`]: std::any::TypeId
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static DYNAMIC_ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static REGISTRY_LOOKUPS: AtomicU64 = AtomicU64::new(0);
+static APPLIES: AtomicU64 = AtomicU64::new(0);
+static CLONES: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The operations made on the current thread, used by [`measure`].
+    /// These are never reset, so measurements are taken as differences.
+    static THREAD_STATS: Cell<ReflectStats> = const { Cell::new(ReflectStats::ZERO) };
+}
+
+/// A snapshot of the reflection counters.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReflectStats {
+    /// The number of dynamic values (such as [`DynamicStruct`]) created
+    /// by `clone_dynamic` and [`DynamicEnum::from_ref`].
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    /// [`DynamicEnum::from_ref`]: crate::DynamicEnum::from_ref
+    pub dynamic_allocations: u64,
+    /// The number of [`TypeRegistration`] lookups made on a [`TypeRegistry`],
+    /// whether by [`TypeId`] or by type path.
+    ///
+    /// [`TypeRegistration`]: crate::TypeRegistration
+    /// [`TypeRegistry`]: crate::TypeRegistry
+    /// [`TypeId`]: std::any::TypeId
+    pub registry_lookups: u64,
+    /// The number of calls to [`Reflect::try_apply`], including nested calls
+    /// made for each field or element of the value being applied.
+    ///
+    /// [`Reflect::try_apply`]: crate::Reflect::try_apply
+    pub applies: u64,
+    /// The number of calls to [`Reflect::clone_value`], including nested calls
+    /// made for each field or element of the value being cloned.
+    ///
+    /// [`Reflect::clone_value`]: crate::Reflect::clone_value
+    pub clones: u64,
+}
+
+impl ReflectStats {
+    const ZERO: Self = Self {
+        dynamic_allocations: 0,
+        registry_lookups: 0,
+        applies: 0,
+        clones: 0,
+    };
+
+    /// Returns the total number of operations counted.
+    pub fn total(&self) -> u64 {
+        self.dynamic_allocations + self.registry_lookups + self.applies + self.clones
+    }
+}
+
+/// Returns the current value of the counters without resetting them.
+pub fn snapshot() -> ReflectStats {
+    ReflectStats {
+        dynamic_allocations: DYNAMIC_ALLOCATIONS.load(Ordering::Relaxed),
+        registry_lookups: REGISTRY_LOOKUPS.load(Ordering::Relaxed),
+        applies: APPLIES.load(Ordering::Relaxed),
+        clones: CLONES.load(Ordering::Relaxed),
+    }
+}
+
+/// Returns the current value of the counters and resets them to zero.
+///
+/// Each counter is reset atomically, so no operation is lost,
+/// but operations that happen on other threads during this call
+/// may be attributed to either this snapshot or the next one.
+pub fn take() -> ReflectStats {
+    ReflectStats {
+        dynamic_allocations: DYNAMIC_ALLOCATIONS.swap(0, Ordering::Relaxed),
+        registry_lookups: REGISTRY_LOOKUPS.swap(0, Ordering::Relaxed),
+        applies: APPLIES.swap(0, Ordering::Relaxed),
+        clones: CLONES.swap(0, Ordering::Relaxed),
+    }
+}
+
+/// Runs `f` and returns the reflection operations it performed, along with its result.
+///
+/// Unlike [`snapshot`] and [`take`], only the operations made on the current thread are counted,
+/// so work done concurrently by other threads isn't attributed to `f`.
+/// Likewise, work that `f` hands off to other threads isn't counted.
+///
+/// Calls to `measure` can be nested, in which case the outer call also counts
+/// the operations of the inner one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, stats};
+/// let value = vec![1_u32, 2, 3];
+/// let (_, counts) = stats::measure(|| value.clone_value());
+/// assert_eq!(4, counts.clones);
+/// ```
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, ReflectStats) {
+    let before = THREAD_STATS.with(Cell::get);
+    let result = f();
+    let after = THREAD_STATS.with(Cell::get);
+    let counts = ReflectStats {
+        dynamic_allocations: after.dynamic_allocations - before.dynamic_allocations,
+        registry_lookups: after.registry_lookups - before.registry_lookups,
+        applies: after.applies - before.applies,
+        clones: after.clones - before.clones,
+    };
+    (result, counts)
+}
+
+#[inline]
+fn record_thread(record: impl FnOnce(&mut ReflectStats)) {
+    THREAD_STATS.with(|stats| {
+        let mut counts = stats.get();
+        record(&mut counts);
+        stats.set(counts);
+    });
+}
+
+#[inline]
+pub(crate) fn record_dynamic_allocation() {
+    DYNAMIC_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    record_thread(|counts| counts.dynamic_allocations += 1);
+}
+
+#[inline]
+pub(crate) fn record_registry_lookup() {
+    REGISTRY_LOOKUPS.fetch_add(1, Ordering::Relaxed);
+    record_thread(|counts| counts.registry_lookups += 1);
+}
+
+#[inline]
+pub(crate) fn record_apply() {
+    APPLIES.fetch_add(1, Ordering::Relaxed);
+    record_thread(|counts| counts.applies += 1);
+}
+
+#[inline]
+pub(crate) fn record_clone() {
+    CLONES.fetch_add(1, Ordering::Relaxed);
+    record_thread(|counts| counts.clones += 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect, TypeRegistry};
+
+    #[test]
+    fn should_count_reflection_operations() {
+        #[derive(Reflect)]
+        struct Foo {
+            a: u32,
+            b: Vec<u32>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+
+        let before = snapshot();
+
+        let mut foo = Foo { a: 1, b: vec![2] };
+        let clone = foo.clone_value();
+        foo.apply(&*clone);
+        registry.get(std::any::TypeId::of::<Foo>());
+
+        // Other tests may run concurrently, so only lower bounds can be checked
+        let after = snapshot();
+        assert!(after.clones - before.clones >= 3);
+        assert!(after.dynamic_allocations - before.dynamic_allocations >= 2);
+        assert!(after.applies - before.applies >= 3);
+        assert!(after.registry_lookups - before.registry_lookups >= 1);
+    }
+
+    #[test]
+    fn should_measure_operations_on_current_thread() {
+        let value = vec![1_u32, 2];
+
+        let ((), outer) = measure(|| {
+            let (_, inner) = measure(|| value.clone_value());
+            assert_eq!(3, inner.clones);
+            assert_eq!(0, inner.applies);
+
+            // Operations on other threads aren't counted
+            std::thread::scope(|scope| {
+                scope.spawn(|| value.clone_value());
+            });
+        });
+        assert_eq!(3, outer.clones);
+    }
+}
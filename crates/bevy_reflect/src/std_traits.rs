@@ -1,14 +1,25 @@
 use crate::{FromType, Reflect};
+use std::sync::Arc;
 
 /// A struct used to provide the default value of a type.
 ///
 /// A [`ReflectDefault`] for type `T` can be obtained via [`FromType::from_type`].
 #[derive(Clone)]
 pub struct ReflectDefault {
-    default: fn() -> Box<dyn Reflect>,
+    default: Arc<dyn Fn() -> Box<dyn Reflect> + Send + Sync>,
 }
 
 impl ReflectDefault {
+    /// Creates a [`ReflectDefault`] from a function returning the default value.
+    ///
+    /// This can be used for types whose default value is only known at runtime.
+    /// Usually, a [`ReflectDefault`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(default: impl Fn() -> Box<dyn Reflect> + Send + Sync + 'static) -> Self {
+        Self {
+            default: Arc::new(default),
+        }
+    }
+
     pub fn default(&self) -> Box<dyn Reflect> {
         (self.default)()
     }
@@ -16,8 +27,6 @@ impl ReflectDefault {
 
 impl<T: Reflect + Default> FromType<T> for ReflectDefault {
     fn from_type() -> Self {
-        ReflectDefault {
-            default: || Box::<T>::default(),
-        }
+        ReflectDefault::new(|| Box::<T>::default())
     }
 }
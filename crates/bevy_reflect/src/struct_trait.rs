@@ -1,7 +1,8 @@
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
-    self as bevy_reflect, ApplyError, NamedField, Reflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, ApplyError, NamedField, Reflect, ReflectCloneError, ReflectKind,
+    ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::HashMap;
@@ -84,11 +85,21 @@ pub struct StructInfo {
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
     custom_attributes: Arc<CustomAttributes>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl StructInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`StructInfo`].
     ///
     /// # Arguments
@@ -111,6 +122,7 @@ impl StructInfo {
             field_names,
             field_indices,
             custom_attributes: Arc::new(CustomAttributes::default()),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -142,6 +154,14 @@ impl StructInfo {
             .map(|index| &self.fields[*index])
     }
 
+    /// Get the field with the given name, falling back to a field with the given [alias].
+    ///
+    /// [alias]: NamedField::aliases
+    pub fn field_or_alias(&self, name: &str) -> Option<&NamedField> {
+        self.field(name)
+            .or_else(|| self.fields.iter().find(|field| field.is_named(name)))
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&NamedField> {
         self.fields.get(index)
@@ -195,6 +215,8 @@ impl StructInfo {
         self.docs
     }
 
+    impl_generic_info_methods!(self.generics);
+
     impl_custom_attribute_methods!(self.custom_attributes, "struct");
 }
 
@@ -333,6 +355,20 @@ impl DynamicStruct {
         self.insert_boxed(name, Box::new(value));
     }
 
+    /// Removes the field named `name` from the struct, returning its value.
+    ///
+    /// The fields after it are shifted down by one index.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for field_index in self.field_indices.values_mut() {
+            if *field_index > index {
+                *field_index -= 1;
+            }
+        }
+        Some(self.fields.remove(index))
+    }
+
     /// Gets the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
@@ -385,6 +421,8 @@ impl Struct for DynamicStruct {
     }
 
     fn clone_dynamic(&self) -> DynamicStruct {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicStruct {
             represented_type: self.get_represented_type_info(),
             field_names: self.field_names.clone(),
@@ -435,11 +473,13 @@ impl Reflect for DynamicStruct {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         if let ReflectRef::Struct(struct_value) = value.reflect_ref() {
             for (i, value) in struct_value.iter_fields().enumerate() {
                 let name = struct_value.name_at(i).unwrap();
                 if let Some(v) = self.field_mut(name) {
-                    v.try_apply(value)?;
+                    v.try_apply(value).map_err(|err| err.with_field(name))?;
                 }
             }
         } else {
@@ -479,9 +519,15 @@ impl Reflect for DynamicStruct {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         struct_partial_eq(self, value)
     }
@@ -605,4 +651,21 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(prev_index, iter.index);
     }
+
+    #[test]
+    fn should_remove_dynamic_struct_fields() {
+        let mut dynamic_struct = DynamicStruct::default();
+        dynamic_struct.insert("a", 1_u8);
+        dynamic_struct.insert("b", 2_u8);
+        dynamic_struct.insert("c", 3_u8);
+
+        let removed = dynamic_struct.remove("a").unwrap();
+        assert_eq!(Some(&1), removed.downcast_ref::<u8>());
+        assert!(dynamic_struct.remove("a").is_none());
+
+        assert_eq!(2, dynamic_struct.field_len());
+        assert_eq!(Some(0), dynamic_struct.index_of("b"));
+        assert_eq!(Some("c"), dynamic_struct.name_at(1));
+        assert_eq!(Some(&3), dynamic_struct.get_field::<u8>("c"));
+    }
 }
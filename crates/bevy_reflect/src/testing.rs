@@ -0,0 +1,308 @@
+//! Conformance checks for [`Reflect`] implementations.
+//!
+//! Most types get their reflection implementation from `#[derive(Reflect)]`,
+//! which upholds the contracts of the reflection traits by construction.
+//! Types with manual implementations do not have that guarantee,
+//! so this module provides assertions that can be used in their tests
+//! to catch common mistakes, such as a [`clone_value`] that loses data,
+//! or a [`reflect_hash`] that depends on more than the reflected data.
+//!
+//! These assertions panic with a description of the first violated contract.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{prelude::*, testing::assert_full_reflection_contract};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     name: String,
+//!     scores: Vec<u32>,
+//! }
+//!
+//! assert_full_reflection_contract(&Player {
+//!     name: String::from("Ferris"),
+//!     scores: vec![10, 20],
+//! });
+//! ```
+//!
+//! [`clone_value`]: Reflect::clone_value
+//! [`reflect_hash`]: Reflect::reflect_hash
+
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{
+    FromReflect, GetPath, GetTypeRegistration, Reflect, ReflectKind, ReflectRef, TypeInfo,
+    TypeRegistry, Typed, VariantType,
+};
+use bincode::Options;
+use serde::de::DeserializeSeed;
+
+/// Asserts that `value` survives being cloned, converted, and serialized through reflection.
+///
+/// This checks that:
+/// - [`Reflect::clone_value`] produces an equal value
+/// - [`FromReflect::from_reflect`] can recreate the value from its clone
+/// - the value round-trips through the reflection serializers, using both RON and bincode
+///
+/// Values are compared using [`Reflect::reflect_partial_eq`],
+/// so the type must support it.
+#[track_caller]
+pub fn assert_reflect_roundtrip<T: FromReflect + Typed + GetTypeRegistration>(value: &T) {
+    let type_path = T::type_path();
+
+    let clone = value.clone_value();
+    assert_reflect_eq(
+        value,
+        &*clone,
+        "`clone_value` did not produce an equal value",
+    );
+
+    let from_clone = T::from_reflect(&*clone).unwrap_or_else(|| {
+        panic!("`{type_path}` could not be created with `from_reflect` from its `clone_value`")
+    });
+    assert_reflect_eq(
+        value,
+        &from_clone,
+        "`from_reflect` of the `clone_value` did not produce an equal value",
+    );
+
+    let mut registry = TypeRegistry::default();
+    registry.register::<T>();
+    let registration = registry.get(std::any::TypeId::of::<T>()).unwrap();
+
+    let serializer = TypedReflectSerializer::new(value, &registry);
+
+    let ron = ron::to_string(&serializer)
+        .unwrap_or_else(|err| panic!("`{type_path}` could not be serialized to RON: {err}"));
+    let mut deserializer = ron::Deserializer::from_str(&ron).unwrap();
+    let output = TypedReflectDeserializer::new(registration, &registry)
+        .deserialize(&mut deserializer)
+        .unwrap_or_else(|err| {
+            panic!("`{type_path}` could not be deserialized from RON `{ron}`: {err}")
+        });
+    assert_from_output::<T>(value, &*output, "RON");
+
+    let bytes = bincode::serialize(&serializer)
+        .unwrap_or_else(|err| panic!("`{type_path}` could not be serialized to bincode: {err}"));
+    let output = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .deserialize_seed(
+            TypedReflectDeserializer::new(registration, &registry),
+            &bytes,
+        )
+        .unwrap_or_else(|err| {
+            panic!("`{type_path}` could not be deserialized from bincode: {err}")
+        });
+    assert_from_output::<T>(value, &*output, "bincode");
+}
+
+/// Asserts that `value` upholds the contracts of [`Reflect`] and its [kind] subtraits.
+///
+/// In addition to the checks performed by [`assert_reflect_roundtrip`], this checks that:
+/// - the [represented type] and [type path] of the value match `T`
+/// - [`reflect_kind`], [`reflect_ref`], [`reflect_mut`], and the type's [`TypeInfo`] agree on its kind
+/// - applying the value to a copy of itself succeeds and leaves it unchanged
+/// - equal values produce the same [`reflect_hash`]
+/// - every field or element can be accessed by [path], pointing to the same value as the kind's accessors
+///
+/// [kind]: ReflectKind
+/// [represented type]: Reflect::get_represented_type_info
+/// [type path]: Reflect::reflect_type_path
+/// [`reflect_kind`]: Reflect::reflect_kind
+/// [`reflect_ref`]: Reflect::reflect_ref
+/// [`reflect_mut`]: Reflect::reflect_mut
+/// [`reflect_hash`]: Reflect::reflect_hash
+/// [path]: GetPath
+#[track_caller]
+pub fn assert_full_reflection_contract<T: FromReflect + Typed + GetTypeRegistration>(value: &T) {
+    let type_path = T::type_path();
+
+    assert_eq!(
+        type_path,
+        value.reflect_type_path(),
+        "`reflect_type_path` does not match `TypePath::type_path`"
+    );
+    let info = value
+        .get_represented_type_info()
+        .unwrap_or_else(|| panic!("`{type_path}` does not return a represented type"));
+    assert!(
+        info.is::<T>(),
+        "`{type_path}` returned the represented type `{}`",
+        info.type_path()
+    );
+    assert_eq!(
+        T::type_info().type_id(),
+        info.type_id(),
+        "`{type_path}` returned a represented type that is not `Typed::type_info`"
+    );
+
+    let kind = value.reflect_kind();
+    assert_eq!(
+        kind,
+        value.reflect_ref().kind(),
+        "`{type_path}` returned a different kind from `reflect_ref` than `reflect_kind`"
+    );
+    assert_eq!(
+        kind,
+        info_kind(info),
+        "`{type_path}` returned a different kind from `reflect_kind` than its `TypeInfo`"
+    );
+
+    assert_reflect_roundtrip(value);
+
+    let mut copy = T::from_reflect(value).unwrap();
+    assert_eq!(
+        kind,
+        copy.reflect_mut().kind(),
+        "`{type_path}` returned a different kind from `reflect_mut` than `reflect_kind`"
+    );
+    copy.try_apply(value)
+        .unwrap_or_else(|err| panic!("`{type_path}` could not be applied to itself: {err}"));
+    assert_reflect_eq(value, &copy, "applying the value to a copy changed it");
+
+    assert_eq!(
+        value.reflect_hash(),
+        copy.reflect_hash(),
+        "`{type_path}` has a different `reflect_hash` than an equal copy"
+    );
+
+    for (path, element) in element_paths(value) {
+        let accessed = value
+            .reflect_path(path.as_str())
+            .unwrap_or_else(|err| panic!("`{type_path}` could not access path `{path}`: {err}"));
+        assert!(
+            std::ptr::addr_eq(accessed, element),
+            "`{type_path}` returned a different value for path `{path}` than its kind's accessors"
+        );
+    }
+}
+
+#[track_caller]
+fn assert_reflect_eq(expected: &dyn Reflect, actual: &dyn Reflect, message: &str) {
+    let type_path = expected.reflect_type_path();
+    match expected.reflect_partial_eq(actual) {
+        Some(true) => {}
+        Some(false) => panic!("{message} for `{type_path}`: expected {expected:?}, got {actual:?}"),
+        None => panic!("`{type_path}` does not support `reflect_partial_eq`"),
+    }
+}
+
+#[track_caller]
+fn assert_from_output<T: FromReflect + Typed>(expected: &T, output: &dyn Reflect, format: &str) {
+    let output = T::from_reflect(output).unwrap_or_else(|| {
+        panic!(
+            "`{}` could not be created with `from_reflect` after deserializing from {format}",
+            T::type_path()
+        )
+    });
+    assert_reflect_eq(
+        expected,
+        &output,
+        &format!("round-tripping through {format} did not produce an equal value"),
+    );
+}
+
+fn info_kind(info: &TypeInfo) -> ReflectKind {
+    match info {
+        TypeInfo::Struct(_) => ReflectKind::Struct,
+        TypeInfo::TupleStruct(_) => ReflectKind::TupleStruct,
+        TypeInfo::Tuple(_) => ReflectKind::Tuple,
+        TypeInfo::List(_) => ReflectKind::List,
+        TypeInfo::Array(_) => ReflectKind::Array,
+        TypeInfo::Map(_) => ReflectKind::Map,
+        TypeInfo::Enum(_) => ReflectKind::Enum,
+        TypeInfo::Value(_) => ReflectKind::Value,
+    }
+}
+
+/// Returns the path to every direct field or element of `value`,
+/// along with the value returned for it by the kind's accessors.
+fn element_paths(value: &dyn Reflect) -> Vec<(String, &dyn Reflect)> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .map(|i| {
+                (
+                    format!(".{}", value.name_at(i).unwrap()),
+                    value.field_at(i).unwrap(),
+                )
+            })
+            .collect(),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(i, field)| (format!(".{i}"), field))
+            .collect(),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(i, field)| (format!(".{i}"), field))
+            .collect(),
+        ReflectRef::List(value) => value
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (format!("[{i}]"), item))
+            .collect(),
+        ReflectRef::Array(value) => value
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (format!("[{i}]"), item))
+            .collect(),
+        ReflectRef::Enum(value) => (0..value.field_len())
+            .map(|i| {
+                let path = match value.variant_type() {
+                    VariantType::Struct => format!(".{}", value.name_at(i).unwrap()),
+                    _ => format!(".{i}"),
+                };
+                (path, value.field_at(i).unwrap())
+            })
+            .collect(),
+        ReflectRef::Map(_) | ReflectRef::Value(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Empty,
+    }
+
+    #[derive(Reflect, Debug)]
+    struct Scene {
+        shapes: Vec<Shape>,
+        names: HashMap<u32, String>,
+        origin: (f32, f32),
+        tint: [u8; 4],
+    }
+
+    #[test]
+    fn should_uphold_contract_for_derived_types() {
+        assert_full_reflection_contract(&Scene {
+            shapes: vec![
+                Shape::Circle { radius: 1.0 },
+                Shape::Rect(2.0, 3.0),
+                Shape::Empty,
+            ],
+            names: HashMap::from([(1, String::from("circle"))]),
+            origin: (0.5, -0.5),
+            tint: [255, 128, 0, 255],
+        });
+        assert_full_reflection_contract(&Shape::Rect(1.0, 2.0));
+        assert_full_reflection_contract(&Some(5_u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not support `reflect_partial_eq`")]
+    fn should_reject_values_without_partial_eq() {
+        #[derive(Reflect, Clone, Debug)]
+        #[reflect_value]
+        struct Opaque;
+
+        assert_reflect_roundtrip(&Opaque);
+    }
+}
@@ -1,13 +1,15 @@
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::all_tuples;
 
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
     self as bevy_reflect, utility::GenericTypePathCell, ApplyError, FromReflect,
-    GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath,
-    TypeRegistration, TypeRegistry, Typed, UnnamedField,
+    GetTypeRegistration, Reflect, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+    TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed, UnnamedField,
 };
 use crate::{ReflectKind, TypePathTable};
 use std::any::{Any, TypeId};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
 
@@ -16,7 +18,7 @@ use std::slice::Iter;
 /// This trait uses the [`Reflect`] trait to allow implementors to have their fields
 /// be dynamically addressed by index.
 ///
-/// This trait is automatically implemented for arbitrary tuples of up to 12
+/// This trait is automatically implemented for arbitrary tuples of up to 16
 /// elements, provided that each element implements [`Reflect`].
 ///
 /// # Example
@@ -142,11 +144,21 @@ pub struct TupleInfo {
     type_path: TypePathTable,
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl TupleInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`TupleInfo`].
     ///
     /// # Arguments
@@ -158,6 +170,7 @@ impl TupleInfo {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -216,6 +229,8 @@ impl TupleInfo {
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
+
+    impl_generic_info_methods!(self.generics);
 }
 
 /// A tuple which allows fields to be added at runtime.
@@ -288,6 +303,8 @@ impl Tuple for DynamicTuple {
 
     #[inline]
     fn clone_dynamic(&self) -> DynamicTuple {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicTuple {
             represented_type: self.represented_type,
             fields: self
@@ -366,9 +383,15 @@ impl Reflect for DynamicTuple {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
         tuple_try_apply(self, value)
     }
@@ -377,6 +400,10 @@ impl Reflect for DynamicTuple {
         tuple_partial_eq(self, value)
     }
 
+    fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        tuple_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicTuple(")?;
         tuple_debug(self, f)?;
@@ -412,10 +439,12 @@ pub fn tuple_apply<T: Tuple>(a: &mut T, b: &dyn Reflect) {
 /// applying elements to each other fails.
 #[inline]
 pub fn tuple_try_apply<T: Tuple>(a: &mut T, b: &dyn Reflect) -> Result<(), ApplyError> {
+    #[cfg(feature = "stats")]
+    crate::stats::record_apply();
     if let ReflectRef::Tuple(tuple) = b.reflect_ref() {
         for (i, value) in tuple.iter_fields().enumerate() {
             if let Some(v) = a.field_mut(i) {
-                v.try_apply(value)?;
+                v.try_apply(value).map_err(|err| err.with_field(i))?;
             }
         }
     } else {
@@ -455,6 +484,33 @@ pub fn tuple_partial_eq<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Compares a [`Tuple`] with a [`Reflect`] value lexicographically.
+///
+/// Elements are compared pairwise using [`Reflect::reflect_cmp`],
+/// stopping at the first pair that isn't equal.
+///
+/// Returns [`None`] if `b` is not a tuple, has a different number of elements than `a`,
+/// or if any pair of elements couldn't be compared.
+#[inline]
+pub fn tuple_cmp<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Tuple(b) = b.reflect_ref() else {
+        return None;
+    };
+
+    if a.field_len() != b.field_len() {
+        return None;
+    }
+
+    for (a_field, b_field) in a.iter_fields().zip(b.iter_fields()) {
+        match a_field.reflect_cmp(b_field)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// # Example
@@ -523,6 +579,8 @@ macro_rules! impl_reflect_tuple {
 
             #[inline]
             fn clone_dynamic(&self) -> DynamicTuple {
+                #[cfg(feature = "stats")]
+                crate::stats::record_dynamic_allocation();
                 let info = self.get_represented_type_info();
                 DynamicTuple {
                     represented_type: info,
@@ -593,12 +651,22 @@ macro_rules! impl_reflect_tuple {
             }
 
             fn clone_value(&self) -> Box<dyn Reflect> {
+                #[cfg(feature = "stats")]
+                crate::stats::record_clone();
                 Box::new(self.clone_dynamic())
             }
 
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                Ok(Box::new(($(<dyn Reflect>::reflect_clone_and_take::<$name>(&self.$index)?,)*)))
+            }
+
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 crate::tuple_partial_eq(self, value)
             }
+
+            fn reflect_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+                crate::tuple_cmp(self, value)
+            }
         }
 
         impl <$($name: Reflect + TypePath + GetTypeRegistration),*> Typed for ($($name,)*) {
@@ -656,6 +724,10 @@ impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I}
 impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J}
 impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K}
 impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L}
+impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L, 12: M}
+impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L, 12: M, 13: N}
+impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L, 12: M, 13: N, 14: O}
+impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L, 12: M, 13: N, 14: O, 15: P}
 
 macro_rules! impl_type_path_tuple {
     () => {
@@ -708,7 +780,7 @@ macro_rules! impl_type_path_tuple {
     };
 }
 
-all_tuples!(impl_type_path_tuple, 0, 12, P);
+all_tuples!(impl_type_path_tuple, 0, 16, P);
 
 #[cfg(feature = "functions")]
 const _: () = {
@@ -718,7 +790,7 @@ const _: () = {
     };
 }
 
-    all_tuples!(impl_get_ownership_tuple, 0, 12, P);
+    all_tuples!(impl_get_ownership_tuple, 0, 16, P);
 
     macro_rules! impl_from_arg_tuple {
     ($($name: ident),*) => {
@@ -726,7 +798,7 @@ const _: () = {
     };
 }
 
-    all_tuples!(impl_from_arg_tuple, 0, 12, P);
+    all_tuples!(impl_from_arg_tuple, 0, 16, P);
 
     macro_rules! impl_into_return_tuple {
     ($($name: ident),+) => {
@@ -735,12 +807,65 @@ const _: () = {
 }
 
     // The unit type (i.e. `()`) is special-cased, so we skip implementing it here.
-    all_tuples!(impl_into_return_tuple, 1, 12, P);
+    all_tuples!(impl_into_return_tuple, 1, 16, P);
 };
 
 #[cfg(test)]
 mod tests {
     use super::Tuple;
+    use crate::{FromReflect, Reflect, TypeInfo, Typed};
+
+    #[test]
+    fn should_reflect_long_tuples() {
+        type Long = (
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            String,
+        );
+
+        let TypeInfo::Tuple(info) = Long::type_info() else {
+            panic!("expected tuple info");
+        };
+        assert_eq!(16, info.field_len());
+
+        let value: Long = (
+            0,
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            9,
+            10,
+            11,
+            12,
+            13,
+            14,
+            String::from("fifteen"),
+        );
+        let clone = value.clone_value();
+        assert_eq!(Some(true), value.reflect_partial_eq(&*clone));
+        // `PartialEq` and `Debug` are only implemented by tuples of up to 12 elements
+        let from_reflect = Long::from_reflect(&*clone).unwrap();
+        assert_eq!("fifteen", from_reflect.15);
+        assert_eq!(Some(true), from_reflect.reflect_partial_eq(&value));
+    }
 
     #[test]
     fn next_index_increment() {
@@ -1,9 +1,10 @@
 use bevy_reflect_derive::impl_type_path;
 
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
-    self as bevy_reflect, ApplyError, DynamicTuple, Reflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, Tuple, TypeInfo, TypePath, TypePathTable, UnnamedField,
+    self as bevy_reflect, ApplyError, DynamicTuple, Reflect, ReflectCloneError, ReflectKind,
+    ReflectMut, ReflectOwned, ReflectRef, Tuple, TypeInfo, TypePath, TypePathTable, UnnamedField,
 };
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
@@ -62,11 +63,21 @@ pub struct TupleStructInfo {
     type_id: TypeId,
     fields: Box<[UnnamedField]>,
     custom_attributes: Arc<CustomAttributes>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl TupleStructInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     /// Create a new [`TupleStructInfo`].
     ///
     /// # Arguments
@@ -79,6 +90,7 @@ impl TupleStructInfo {
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -146,6 +158,8 @@ impl TupleStructInfo {
         self.docs
     }
 
+    impl_generic_info_methods!(self.generics);
+
     impl_custom_attribute_methods!(self.custom_attributes, "struct");
 }
 
@@ -296,6 +310,8 @@ impl TupleStruct for DynamicTupleStruct {
     }
 
     fn clone_dynamic(&self) -> DynamicTupleStruct {
+        #[cfg(feature = "stats")]
+        crate::stats::record_dynamic_allocation();
         DynamicTupleStruct {
             represented_type: self.represented_type,
             fields: self
@@ -344,10 +360,12 @@ impl Reflect for DynamicTupleStruct {
     }
 
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_apply();
         if let ReflectRef::TupleStruct(tuple_struct) = value.reflect_ref() {
             for (i, value) in tuple_struct.iter_fields().enumerate() {
                 if let Some(v) = self.field_mut(i) {
-                    v.try_apply(value)?;
+                    v.try_apply(value).map_err(|err| err.with_field(i))?;
                 }
             }
         } else {
@@ -387,9 +405,15 @@ impl Reflect for DynamicTupleStruct {
 
     #[inline]
     fn clone_value(&self) -> Box<dyn Reflect> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_clone();
         Box::new(self.clone_dynamic())
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone_dynamic()))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         tuple_struct_partial_eq(self, value)
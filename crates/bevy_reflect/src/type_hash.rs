@@ -0,0 +1,277 @@
+use crate::{NamedField, TypeInfo, UnnamedField, VariantInfo};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher whose output only depends on the bytes written to it.
+///
+/// Unlike [`DefaultHasher`], this produces the same result on every platform,
+/// for every build, and for every version of Rust.
+///
+/// [`DefaultHasher`]: std::collections::hash_map::DefaultHasher
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    /// Writes a length-prefixed string so that adjacent strings can't run into each other.
+    fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+    }
+
+    fn write_named_fields<'a>(&mut self, fields: impl ExactSizeIterator<Item = &'a NamedField>) {
+        self.write_u64(fields.len() as u64);
+        for field in fields {
+            self.write_str(field.name());
+            self.write_str(field.type_path());
+        }
+    }
+
+    fn write_unnamed_fields<'a>(
+        &mut self,
+        fields: impl ExactSizeIterator<Item = &'a UnnamedField>,
+    ) {
+        self.write_u64(fields.len() as u64);
+        for field in fields {
+            self.write_str(field.type_path());
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Computes the structural hash of the given [`TypeInfo`].
+///
+/// See [`TypeInfo::stable_hash`] for details.
+pub(crate) fn stable_type_hash(info: &TypeInfo) -> u64 {
+    let mut hasher = StableHasher::new();
+
+    match info {
+        TypeInfo::Struct(info) => {
+            hasher.write_str("struct");
+            hasher.write_str(info.type_path());
+            hasher.write_named_fields(info.iter());
+        }
+        TypeInfo::TupleStruct(info) => {
+            hasher.write_str("tuple_struct");
+            hasher.write_str(info.type_path());
+            hasher.write_unnamed_fields(info.iter());
+        }
+        TypeInfo::Tuple(info) => {
+            hasher.write_str("tuple");
+            hasher.write_str(info.type_path());
+            hasher.write_unnamed_fields(info.iter());
+        }
+        TypeInfo::List(info) => {
+            hasher.write_str("list");
+            hasher.write_str(info.type_path());
+            hasher.write_str(info.item_type_path_table().path());
+        }
+        TypeInfo::Array(info) => {
+            hasher.write_str("array");
+            hasher.write_str(info.type_path());
+            hasher.write_str(info.item_type_path_table().path());
+            hasher.write_u64(info.capacity() as u64);
+        }
+        TypeInfo::Map(info) => {
+            hasher.write_str("map");
+            hasher.write_str(info.type_path());
+            hasher.write_str(info.key_type_path_table().path());
+            hasher.write_str(info.value_type_path_table().path());
+        }
+        TypeInfo::Enum(info) => {
+            hasher.write_str("enum");
+            hasher.write_str(info.type_path());
+            hasher.write_u64(info.variant_len() as u64);
+            for variant in info.iter() {
+                match variant {
+                    VariantInfo::Struct(variant) => {
+                        hasher.write_str("struct");
+                        hasher.write_str(variant.name());
+                        hasher.write_named_fields(variant.iter());
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        hasher.write_str("tuple");
+                        hasher.write_str(variant.name());
+                        hasher.write_unnamed_fields(variant.iter());
+                    }
+                    VariantInfo::Unit(variant) => {
+                        hasher.write_str("unit");
+                        hasher.write_str(variant.name());
+                    }
+                }
+            }
+        }
+        TypeInfo::Value(info) => {
+            hasher.write_str("value");
+            hasher.write_str(info.type_path());
+        }
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeRegistration, Typed};
+
+    mod before {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub health: u32,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub enum Action {
+            Idle,
+            Move(f32, f32),
+            Attack { target: u32 },
+        }
+    }
+
+    mod renamed_field {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub hp: u32,
+        }
+    }
+
+    mod changed_type {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub health: u64,
+        }
+    }
+
+    mod reordered_fields {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub health: u32,
+            pub name: String,
+        }
+    }
+
+    mod same_layout {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub health: u32,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub enum Action {
+            Idle,
+            Move(f32, f32),
+            Attack { target: u32 },
+        }
+    }
+
+    mod changed_variant {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub enum Action {
+            Idle,
+            Move(f32, f32),
+            Attack(u32),
+        }
+    }
+
+    fn hash_of<T: Typed>() -> u64 {
+        T::type_info().stable_hash()
+    }
+
+    #[test]
+    fn same_layout_should_have_same_hash() {
+        assert_eq!(
+            hash_of::<before::Player>(),
+            hash_of::<same_layout::Player>()
+        );
+        assert_eq!(
+            hash_of::<before::Action>(),
+            hash_of::<same_layout::Action>()
+        );
+        assert_eq!(
+            hash_of::<before::Player>(),
+            TypeRegistration::of::<before::Player>().type_hash()
+        );
+    }
+
+    #[test]
+    fn changed_layout_should_change_hash() {
+        let original = hash_of::<before::Player>();
+        assert_ne!(original, hash_of::<renamed_field::Player>());
+        assert_ne!(original, hash_of::<changed_type::Player>());
+        assert_ne!(original, hash_of::<reordered_fields::Player>());
+
+        assert_ne!(
+            hash_of::<before::Action>(),
+            hash_of::<changed_variant::Action>()
+        );
+    }
+
+    #[test]
+    fn std_types_should_hash_structurally() {
+        assert_ne!(hash_of::<Vec<u32>>(), hash_of::<Vec<u64>>());
+        assert_ne!(hash_of::<[u8; 3]>(), hash_of::<[u8; 4]>());
+        assert_ne!(hash_of::<(u8, u16)>(), hash_of::<(u16, u8)>());
+        assert_eq!(hash_of::<u32>(), hash_of::<u32>());
+    }
+
+    #[test]
+    fn hash_should_be_stable() {
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        struct Position {
+            x: f32,
+            y: f32,
+        }
+
+        // This value must never change, as it is compared across builds.
+        assert_eq!(0xb30e_d1b5_48cc_b10a, hash_of::<Position>());
+    }
+}
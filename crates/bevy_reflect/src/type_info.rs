@@ -1,6 +1,7 @@
+use crate::generics::{impl_generic_info_methods, Generics};
 use crate::{
-    ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
-    TypePath, TypePathTable,
+    attributes::CustomAttributes, ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo,
+    TupleInfo, TupleStructInfo, TypePath, TypePathTable,
 };
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
@@ -115,6 +116,23 @@ pub enum TypeInfo {
 }
 
 impl TypeInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    ///
+    /// This is used by transparent wrappers, such as [`Box<T>`],
+    /// which are reflected with the same kind and fields as their contents.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        match self {
+            Self::Struct(info) => Self::Struct(info.with_type::<T>()),
+            Self::TupleStruct(info) => Self::TupleStruct(info.with_type::<T>()),
+            Self::Tuple(info) => Self::Tuple(info.with_type::<T>()),
+            Self::List(info) => Self::List(info.with_type::<T>()),
+            Self::Array(info) => Self::Array(info.with_type::<T>()),
+            Self::Map(info) => Self::Map(info.with_type::<T>()),
+            Self::Enum(info) => Self::Enum(info.with_type::<T>()),
+            Self::Value(info) => Self::Value(info.with_type::<T>()),
+        }
+    }
+
     /// The [`TypeId`] of the underlying type.
     pub fn type_id(&self) -> TypeId {
         match self {
@@ -160,6 +178,49 @@ impl TypeInfo {
         TypeId::of::<T>() == self.type_id()
     }
 
+    /// The generic parameters of the underlying type, along with the arguments they were instantiated with.
+    pub fn generics(&self) -> &Generics {
+        match self {
+            Self::Struct(info) => info.generics(),
+            Self::TupleStruct(info) => info.generics(),
+            Self::Tuple(info) => info.generics(),
+            Self::List(info) => info.generics(),
+            Self::Array(info) => info.generics(),
+            Self::Map(info) => info.generics(),
+            Self::Enum(info) => info.generics(),
+            Self::Value(info) => info.generics(),
+        }
+    }
+
+    /// A stable hash of the structure of the underlying type.
+    ///
+    /// The hash covers the type path, the kind of the type, and its layout:
+    /// field names and field type paths (in order), variant names and kinds,
+    /// item, key, and value type paths, and array capacities.
+    /// Field types are identified by their type path only; their own structure is not included.
+    ///
+    /// Unlike [`TypeId`], this hash is deterministic across platforms, compilers, and builds,
+    /// making it suitable for checking that two binaries agree on the layout of a type
+    /// before exchanging reflection-serialized data.
+    pub fn stable_hash(&self) -> u64 {
+        crate::type_hash::stable_type_hash(self)
+    }
+
+    /// The [custom attributes] of the underlying type, if it supports them.
+    ///
+    /// Only structs, tuple structs, and enums can have custom attributes.
+    /// Use the info for a specific field or variant to access its own attributes.
+    ///
+    /// [custom attributes]: CustomAttributes
+    pub fn custom_attributes(&self) -> Option<&CustomAttributes> {
+        match self {
+            Self::Struct(info) => Some(info.custom_attributes()),
+            Self::TupleStruct(info) => Some(info.custom_attributes()),
+            Self::Enum(info) => Some(info.custom_attributes()),
+            Self::Tuple(_) | Self::List(_) | Self::Array(_) | Self::Map(_) | Self::Value(_) => None,
+        }
+    }
+
     /// The docstring of the underlying type, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -188,15 +249,26 @@ impl TypeInfo {
 pub struct ValueInfo {
     type_path: TypePathTable,
     type_id: TypeId,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
 
 impl ValueInfo {
+    /// Returns a copy of this info describing the type `T` instead.
+    pub(crate) fn with_type<T: Any + TypePath>(&self) -> Self {
+        Self {
+            type_path: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            ..self.clone()
+        }
+    }
+
     pub fn new<T: Reflect + TypePath + ?Sized>() -> Self {
         Self {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -240,4 +312,6 @@ impl ValueInfo {
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
+
+    impl_generic_info_methods!(self.generics);
 }
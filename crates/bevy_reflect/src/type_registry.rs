@@ -1,4 +1,8 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::Serializable, std_traits::ReflectDefault, DynamicEnum, DynamicList, DynamicMap,
+    DynamicStruct, DynamicTuple, DynamicTupleStruct, FromReflect, Reflect, ReflectFromReflect,
+    TypeInfo, TypePath, Typed, VariantInfo,
+};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
 use downcast_rs::{impl_downcast, Downcast};
@@ -8,6 +12,7 @@ use std::{
     fmt::Debug,
     sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
+use thiserror::Error;
 
 /// A registry of [reflected] types.
 ///
@@ -26,6 +31,7 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    short_path_aliases: HashMap<String, TypeId>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -47,6 +53,17 @@ impl Debug for TypeRegistryArc {
     }
 }
 
+/// The error returned by [`TypeRegistry::try_get_with_short_type_path`]
+/// when a short type path matches multiple registered types.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("the short type path `{short_type_path}` is ambiguous between: {}", .candidates.join(", "))]
+pub struct AmbiguousTypeName {
+    /// The ambiguous short type path.
+    pub short_type_path: String,
+    /// The full type paths of the types sharing the short type path, sorted.
+    pub candidates: Vec<&'static str>,
+}
+
 /// A trait which allows a type to generate its [`TypeRegistration`]
 /// for registration into the [`TypeRegistry`].
 ///
@@ -85,6 +102,7 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            short_path_aliases: Default::default(),
         }
     }
 
@@ -122,6 +140,11 @@ impl TypeRegistry {
     ///
     /// Additionally, this will add any reflect [type data](TypeData) as specified in the [`Reflect`] derive.
     ///
+    /// Registering an [`Option`], array, or tuple will also [propagate] the serialization and default
+    /// type data of its element types to it, if it does not already have any.
+    ///
+    /// [propagate]: Self::propagate_type_data
+    ///
     /// # Example
     ///
     /// ```
@@ -154,6 +177,7 @@ impl TypeRegistry {
     {
         if self.register_internal(TypeId::of::<T>(), T::get_type_registration) {
             T::register_type_dependencies(self);
+            self.propagate_type_data(TypeId::of::<T>());
         }
     }
 
@@ -183,14 +207,90 @@ impl TypeRegistry {
     /// This method will _not_ register type dependencies.
     /// Use [`register`](Self::register) to register a type with its dependencies.
     pub fn overwrite_registration(&mut self, registration: TypeRegistration) {
-        Self::update_registration_indices(
-            &registration,
-            &mut self.short_path_to_id,
-            &mut self.type_path_to_id,
-            &mut self.ambiguous_names,
-        );
-        self.registrations
-            .insert(registration.type_id(), registration);
+        self.replace_registration(registration);
+    }
+
+    /// Registers the type described by `registration`, returning its previous registration, if any.
+    ///
+    /// Unlike removing the type with [`deregister`](Self::deregister) and registering it again,
+    /// the type stays registered throughout, and its path lookups are left untouched.
+    ///
+    /// This method will _not_ register type dependencies.
+    /// Use [`register`](Self::register) to register a type with its dependencies.
+    pub fn replace_registration(
+        &mut self,
+        registration: TypeRegistration,
+    ) -> Option<TypeRegistration> {
+        let type_id = registration.type_id();
+        // The indices of an already registered type don't change,
+        // and updating them again would make its short type path look ambiguous
+        if !self.registrations.contains_key(&type_id) {
+            Self::update_registration_indices(
+                &registration,
+                &mut self.short_path_to_id,
+                &mut self.type_path_to_id,
+                &mut self.ambiguous_names,
+            );
+        }
+        self.registrations.insert(type_id, registration)
+    }
+
+    /// Removes the registration of the type with the given [`TypeId`], returning it if it was registered.
+    ///
+    /// The type can no longer be looked up by its type path, short type path, or [aliases].
+    /// If its short type path was ambiguous and is now used by a single registered type,
+    /// that type can be looked up by its short type path again.
+    ///
+    /// The type dependencies of the removed type are left registered,
+    /// as other types may depend on them too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// #[derive(Reflect)]
+    /// struct ScriptComponent(u32);
+    ///
+    /// let mut registry = TypeRegistry::empty();
+    /// registry.register::<ScriptComponent>();
+    ///
+    /// let registration = registry.deregister(TypeId::of::<ScriptComponent>());
+    /// assert!(registration.is_some());
+    /// assert!(registry
+    ///     .get_with_short_type_path("ScriptComponent")
+    ///     .is_none());
+    /// assert!(registry.contains(TypeId::of::<u32>()));
+    /// ```
+    ///
+    /// [aliases]: Self::register_short_type_path_alias
+    pub fn deregister(&mut self, type_id: TypeId) -> Option<TypeRegistration> {
+        let registration = self.registrations.remove(&type_id)?;
+        let type_path_table = registration.type_info().type_path_table();
+
+        self.type_path_to_id.remove(type_path_table.path());
+        self.short_path_aliases.retain(|_, id| *id != type_id);
+
+        let short_path = type_path_table.short_path();
+        if self.ambiguous_names.contains(short_path) {
+            let mut remaining = self.registrations.values().filter(|registration| {
+                registration.type_info().type_path_table().short_path() == short_path
+            });
+            if let (Some(remaining), None) = (remaining.next(), remaining.next()) {
+                self.ambiguous_names.remove(short_path);
+                self.short_path_to_id
+                    .insert(short_path, remaining.type_id());
+            }
+        } else {
+            self.short_path_to_id.remove(short_path);
+        }
+
+        Some(registration)
+    }
+
+    /// Consumes the registry, returning its registrations.
+    pub(crate) fn into_registrations(self) -> impl Iterator<Item = TypeRegistration> {
+        self.registrations.into_values()
     }
 
     /// Internal method to register a type with a given [`TypeId`] and [`TypeRegistration`].
@@ -276,6 +376,8 @@ impl TypeRegistry {
     /// If the specified type has not been registered, returns `None`.
     ///
     pub fn get(&self, type_id: TypeId) -> Option<&TypeRegistration> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
         self.registrations.get(&type_id)
     }
 
@@ -285,6 +387,8 @@ impl TypeRegistry {
     /// If the specified type has not been registered, returns `None`.
     ///
     pub fn get_mut(&mut self, type_id: TypeId) -> Option<&mut TypeRegistration> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
         self.registrations.get_mut(&type_id)
     }
 
@@ -295,9 +399,11 @@ impl TypeRegistry {
     ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
         self.type_path_to_id
             .get(type_path)
-            .and_then(|id| self.get(*id))
+            .and_then(|id| self.registrations.get(id))
     }
 
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
@@ -307,39 +413,184 @@ impl TypeRegistry {
     ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
         self.type_path_to_id
             .get(type_path)
-            .cloned()
-            .and_then(move |id| self.get_mut(id))
+            .and_then(|id| self.registrations.get_mut(id))
     }
 
     /// Returns a reference to the [`TypeRegistration`] of the type with
-    /// the given [short type path].
+    /// the given [short type path] or [alias].
     ///
+    /// Aliases take precedence over short type paths.
     /// If the short type path is ambiguous, or if no type with the given path
     /// has been registered, returns `None`.
+    /// Use [`try_get_with_short_type_path`](Self::try_get_with_short_type_path)
+    /// to find out which types an ambiguous short type path matches.
     ///
     /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_short_type_path_alias
     pub fn get_with_short_type_path(&self, short_type_path: &str) -> Option<&TypeRegistration> {
-        self.short_path_to_id
-            .get(short_type_path)
-            .and_then(|id| self.registrations.get(id))
+        self.try_get_with_short_type_path(short_type_path)
+            .ok()
+            .flatten()
     }
 
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
-    /// the given [short type path].
+    /// the given [short type path] or [alias].
     ///
+    /// Aliases take precedence over short type paths.
     /// If the short type path is ambiguous, or if no type with the given path
     /// has been registered, returns `None`.
     ///
     /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_short_type_path_alias
     pub fn get_with_short_type_path_mut(
         &mut self,
         short_type_path: &str,
     ) -> Option<&mut TypeRegistration> {
-        self.short_path_to_id
-            .get(short_type_path)
-            .and_then(|id| self.registrations.get_mut(id))
+        self.try_get_with_short_type_path_mut(short_type_path)
+            .ok()
+            .flatten()
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with
+    /// the given [short type path] or [alias], reporting ambiguous short type paths.
+    ///
+    /// Aliases take precedence over short type paths.
+    /// If no type with the given path has been registered, returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AmbiguousTypeName`] error listing the candidates if the short type path
+    /// matches multiple registered types and is not an alias.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::TypeRegistry;
+    /// # mod physics {
+    /// #     use bevy_reflect::Reflect;
+    /// #     #[derive(Reflect)]
+    /// #     pub struct Velocity;
+    /// # }
+    /// # mod ui {
+    /// #     use bevy_reflect::Reflect;
+    /// #     #[derive(Reflect)]
+    /// #     pub struct Velocity;
+    /// # }
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<physics::Velocity>();
+    /// type_registry.register::<ui::Velocity>();
+    ///
+    /// let error = type_registry.try_get_with_short_type_path("Velocity").unwrap_err();
+    /// assert_eq!(error.candidates.len(), 2);
+    ///
+    /// let physics_velocity = TypeId::of::<physics::Velocity>();
+    /// type_registry.register_short_type_path_alias("Velocity", physics_velocity);
+    ///
+    /// let registration = type_registry.try_get_with_short_type_path("Velocity").unwrap();
+    /// assert!(registration.unwrap().type_info().is::<physics::Velocity>());
+    /// ```
+    ///
+    /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_short_type_path_alias
+    pub fn try_get_with_short_type_path(
+        &self,
+        short_type_path: &str,
+    ) -> Result<Option<&TypeRegistration>, AmbiguousTypeName> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
+        let type_id = self.resolve_short_type_path(short_type_path)?;
+        Ok(type_id.and_then(|id| self.registrations.get(&id)))
+    }
+
+    /// Returns a mutable reference to the [`TypeRegistration`] of the type with
+    /// the given [short type path] or [alias], reporting ambiguous short type paths.
+    ///
+    /// Aliases take precedence over short type paths.
+    /// If no type with the given path has been registered, returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AmbiguousTypeName`] error listing the candidates if the short type path
+    /// matches multiple registered types and is not an alias.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_short_type_path_alias
+    pub fn try_get_with_short_type_path_mut(
+        &mut self,
+        short_type_path: &str,
+    ) -> Result<Option<&mut TypeRegistration>, AmbiguousTypeName> {
+        #[cfg(feature = "stats")]
+        crate::stats::record_registry_lookup();
+        let type_id = self.resolve_short_type_path(short_type_path)?;
+        Ok(type_id.and_then(|id| self.registrations.get_mut(&id)))
+    }
+
+    fn resolve_short_type_path(
+        &self,
+        short_type_path: &str,
+    ) -> Result<Option<TypeId>, AmbiguousTypeName> {
+        if let Some(type_id) = self.short_path_aliases.get(short_type_path) {
+            return Ok(Some(*type_id));
+        }
+
+        if self.ambiguous_names.contains(short_type_path) {
+            let mut candidates = self
+                .get_all_with_short_type_path(short_type_path)
+                .map(|registration| registration.type_info().type_path())
+                .collect::<Vec<_>>();
+            candidates.sort_unstable();
+            return Err(AmbiguousTypeName {
+                short_type_path: short_type_path.to_string(),
+                candidates,
+            });
+        }
+
+        Ok(self.short_path_to_id.get(short_type_path).copied())
+    }
+
+    /// Returns an iterator over the [`TypeRegistration`]s of all registered types
+    /// with the given [short type path], in no particular order.
+    ///
+    /// Unlike [`try_get_with_short_type_path`](Self::try_get_with_short_type_path),
+    /// this returns every candidate when the short type path is ambiguous, and ignores aliases.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn get_all_with_short_type_path<'a>(
+        &'a self,
+        short_type_path: &'a str,
+    ) -> impl Iterator<Item = &'a TypeRegistration> + 'a {
+        self.registrations.values().filter(move |registration| {
+            registration.type_info().type_path_table().short_path() == short_type_path
+        })
+    }
+
+    /// Makes `alias` resolve to the type with the given [`TypeId`] in
+    /// [`get_with_short_type_path`](Self::get_with_short_type_path).
+    ///
+    /// This can be used to pick one of the types sharing an [ambiguous] short type path,
+    /// or to give a type an additional short name.
+    /// An alias is removed when the type it refers to is [deregistered].
+    ///
+    /// Returns the [`TypeId`] the alias previously referred to, if any.
+    ///
+    /// [ambiguous]: Self::is_ambiguous
+    /// [deregistered]: Self::deregister
+    pub fn register_short_type_path_alias(
+        &mut self,
+        alias: impl Into<String>,
+        type_id: TypeId,
+    ) -> Option<TypeId> {
+        self.short_path_aliases.insert(alias.into(), type_id)
+    }
+
+    /// Removes an alias added with [`register_short_type_path_alias`](Self::register_short_type_path_alias),
+    /// returning the [`TypeId`] it referred to.
+    pub fn remove_short_type_path_alias(&mut self, alias: &str) -> Option<TypeId> {
+        self.short_path_aliases.remove(alias)
     }
 
     /// Returns `true` if the given [short type path] is ambiguous, that is, it matches multiple registered types.
@@ -398,6 +649,109 @@ impl TypeRegistry {
         self.get(type_id).map(TypeRegistration::type_info)
     }
 
+    /// Returns the [type paths] of the field, item, key, and value types of the registered types
+    /// that are not registered themselves, sorted and without duplicates.
+    ///
+    /// [`register`](Self::register) already registers these types recursively,
+    /// as long as each type lists them in [`GetTypeRegistration::register_type_dependencies`].
+    /// This can be used to find the types it missed, such as those of manual registrations
+    /// or of registrations added with [`add_registration`](Self::add_registration),
+    /// which would otherwise fail to deserialize at runtime.
+    ///
+    /// [type paths]: TypePath::type_path
+    pub fn unregistered_dependencies(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        let mut check = |type_id: TypeId, type_path: &'static str| {
+            if !self.contains(type_id) {
+                missing.push(type_path);
+            }
+        };
+
+        for registration in self.iter() {
+            match registration.type_info() {
+                TypeInfo::Struct(info) => info
+                    .iter()
+                    .for_each(|field| check(field.type_id(), field.type_path())),
+                TypeInfo::TupleStruct(info) => info
+                    .iter()
+                    .for_each(|field| check(field.type_id(), field.type_path())),
+                TypeInfo::Tuple(info) => info
+                    .iter()
+                    .for_each(|field| check(field.type_id(), field.type_path())),
+                TypeInfo::List(info) => {
+                    check(info.item_type_id(), info.item_type_path_table().path());
+                }
+                TypeInfo::Array(info) => {
+                    check(info.item_type_id(), info.item_type_path_table().path());
+                }
+                TypeInfo::Map(info) => {
+                    check(info.key_type_id(), info.key_type_path_table().path());
+                    check(info.value_type_id(), info.value_type_path_table().path());
+                }
+                TypeInfo::Enum(info) => {
+                    for variant in info.iter() {
+                        match variant {
+                            VariantInfo::Struct(variant) => variant
+                                .iter()
+                                .for_each(|field| check(field.type_id(), field.type_path())),
+                            VariantInfo::Tuple(variant) => variant
+                                .iter()
+                                .for_each(|field| check(field.type_id(), field.type_path())),
+                            VariantInfo::Unit(_) => {}
+                        }
+                    }
+                }
+                TypeInfo::Value(_) => {}
+            }
+        }
+
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Constructs a new instance of the type with the given [type path] or [short type path].
+    ///
+    /// The full type path is tried first, followed by the short type path.
+    /// The instance is created using the type's [`ReflectDefault`] if it has one.
+    /// Otherwise, its [`ReflectFromReflect`] is used to build it from an empty dynamic value,
+    /// which works for lists, maps, enums whose first variant is a unit variant,
+    /// and structs whose fields all have a `#[reflect(default)]`.
+    ///
+    /// Returns `None` if the type isn't registered, if its short type path is ambiguous,
+    /// or if it can't be constructed this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{prelude::ReflectDefault, Reflect, TypeRegistry};
+    /// #[derive(Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Default)]
+    /// struct Health(u32);
+    ///
+    /// let mut registry = TypeRegistry::empty();
+    /// registry.register::<Health>();
+    ///
+    /// let health = registry.construct("Health").unwrap();
+    /// assert_eq!(Some(&Health(0)), health.downcast_ref::<Health>());
+    /// ```
+    ///
+    /// [type path]: TypePath::type_path
+    /// [short type path]: TypePath::short_type_path
+    pub fn construct(&self, path: &str) -> Option<Box<dyn Reflect>> {
+        let registration = self
+            .get_with_type_path(path)
+            .or_else(|| self.get_with_short_type_path(path))?;
+
+        if let Some(default) = registration.data::<ReflectDefault>() {
+            return Some(default.default());
+        }
+
+        let from_reflect = registration.data::<ReflectFromReflect>()?;
+        let empty = empty_dynamic(registration.type_info())?;
+        from_reflect.from_reflect(empty.as_ref())
+    }
+
     /// Returns an iterator over the [`TypeRegistration`]s of the registered
     /// types.
     pub fn iter(&self) -> impl Iterator<Item = &TypeRegistration> {
@@ -418,6 +772,68 @@ impl TypeRegistry {
             type_data.map(|data| (item, data))
         })
     }
+
+    /// Returns the number of registered types that have [`TypeData`] of type `T`.
+    ///
+    /// This is cheaper than counting the results of [`iter_with_data`](Self::iter_with_data),
+    /// as the type data doesn't need to be downcast.
+    pub fn count_with_data<T: TypeData>(&self) -> usize {
+        self.registrations
+            .values()
+            .filter(|registration| registration.contains::<T>())
+            .count()
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] has been registered
+    /// and has [`TypeData`] of type `T`.
+    pub fn contains_type_data<T: TypeData>(&self, type_id: TypeId) -> bool {
+        self.get(type_id)
+            .is_some_and(TypeRegistration::contains::<T>)
+    }
+}
+
+/// Creates an empty dynamic value representing the given type.
+///
+/// Returns `None` for kinds that can't be represented without any data.
+fn empty_dynamic(info: &'static TypeInfo) -> Option<Box<dyn Reflect>> {
+    let represented_type = Some(info);
+    let value: Box<dyn Reflect> = match info {
+        TypeInfo::Struct(_) => {
+            let mut value = DynamicStruct::default();
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::TupleStruct(_) => {
+            let mut value = DynamicTupleStruct::default();
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::Tuple(_) => {
+            let mut value = DynamicTuple::default();
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::List(_) => {
+            let mut value = DynamicList::default();
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::Map(_) => {
+            let mut value = DynamicMap::default();
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::Enum(enum_info) => {
+            let VariantInfo::Unit(variant) = enum_info.variant_at(0)? else {
+                return None;
+            };
+            let mut value = DynamicEnum::new(variant.name(), ());
+            value.set_represented_type(represented_type);
+            Box::new(value)
+        }
+        TypeInfo::Array(_) | TypeInfo::Value(_) => return None,
+    };
+    Some(value)
 }
 
 impl TypeRegistryArc {
@@ -462,12 +878,14 @@ impl TypeRegistryArc {
 pub struct TypeRegistration {
     data: TypeIdMap<Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    type_hash: u64,
 }
 
 impl Debug for TypeRegistration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TypeRegistration")
             .field("type_info", &self.type_info)
+            .field("type_hash", &self.type_hash)
             .finish()
     }
 }
@@ -500,11 +918,25 @@ impl TypeRegistration {
             .and_then(|value| value.downcast_mut())
     }
 
+    /// Returns `true` if this registration has type data of type `T`.
+    pub fn contains<T: TypeData>(&self) -> bool {
+        self.data.contains_key(&TypeId::of::<T>())
+    }
+
     /// Returns a reference to the registration's [`TypeInfo`]
     pub fn type_info(&self) -> &'static TypeInfo {
         self.type_info
     }
 
+    /// Returns the stable hash of this type's structure.
+    ///
+    /// This is computed once from the registration's [`TypeInfo`] using [`TypeInfo::stable_hash`].
+    /// Comparing these hashes lets two binaries (such as a client and a server) verify that they agree
+    /// on the layout of a type before exchanging reflection-serialized values of it.
+    pub fn type_hash(&self) -> u64 {
+        self.type_hash
+    }
+
     /// Inserts an instance of `T` into this registration's type data.
     ///
     /// If another instance of `T` was previously inserted, it is replaced.
@@ -514,9 +946,11 @@ impl TypeRegistration {
 
     /// Creates type registration information for `T`.
     pub fn of<T: Reflect + Typed + TypePath>() -> Self {
+        let type_info = T::type_info();
         Self {
             data: Default::default(),
-            type_info: T::type_info(),
+            type_info,
+            type_hash: type_info.stable_hash(),
         }
     }
 }
@@ -531,6 +965,7 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             type_info: self.type_info,
+            type_hash: self.type_hash,
         }
     }
 }
@@ -574,14 +1009,13 @@ pub trait FromType<T> {
 /// [`FromType::from_type`].
 #[derive(Clone)]
 pub struct ReflectSerialize {
-    get_serializable: for<'a> fn(value: &'a dyn Reflect) -> Serializable,
+    get_serializable: Arc<dyn for<'a> Fn(&'a dyn Reflect) -> Serializable<'a> + Send + Sync>,
 }
 
 impl<T: TypePath + FromReflect + erased_serde::Serialize> FromType<T> for ReflectSerialize {
     fn from_type() -> Self {
-        ReflectSerialize {
-            get_serializable: |value| {
-                value
+        ReflectSerialize::new(|value| {
+            value
                     .downcast_ref::<T>()
                     .map(|value| Serializable::Borrowed(value))
                     .or_else(|| T::from_reflect(value).map(|value| Serializable::Owned(Box::new(value))))
@@ -591,12 +1025,23 @@ impl<T: TypePath + FromReflect + erased_serde::Serialize> FromType<T> for Reflec
                             T::type_path(),
                         );
                     })
-            },
-        }
+        })
     }
 }
 
 impl ReflectSerialize {
+    /// Creates a [`ReflectSerialize`] from a function turning a value into a serializable representation.
+    ///
+    /// This can be used to serialize types whose representation is only known at runtime.
+    /// Usually, a [`ReflectSerialize`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(
+        get_serializable: impl for<'a> Fn(&'a dyn Reflect) -> Serializable<'a> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            get_serializable: Arc::new(get_serializable),
+        }
+    }
+
     /// Turn the value into a serializable representation
     pub fn get_serializable<'a>(&self, value: &'a dyn Reflect) -> Serializable<'a> {
         (self.get_serializable)(value)
@@ -606,15 +1051,40 @@ impl ReflectSerialize {
 /// A struct used to deserialize reflected instances of a type.
 ///
 /// A `ReflectDeserialize` for type `T` can be obtained via
-/// [`FromType::from_type`].
+/// [`FromType::from_type`], or created from a function with [`ReflectDeserialize::new`].
+///
+/// # Migration
+///
+/// [`func`](Self::func) used to be a plain `fn` pointer. It is now a shared closure,
+/// so that deserializers built at runtime can capture state.
+/// Calling it with `(data.func)(deserializer)` works as before,
+/// but a `ReflectDeserialize` built with a struct literal should be created with
+/// [`ReflectDeserialize::new`] instead, which accepts the same functions.
 #[derive(Clone)]
 pub struct ReflectDeserialize {
-    pub func: fn(
-        deserializer: &mut dyn erased_serde::Deserializer,
-    ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
+    pub func: Arc<
+        dyn Fn(&mut dyn erased_serde::Deserializer) -> Result<Box<dyn Reflect>, erased_serde::Error>
+            + Send
+            + Sync,
+    >,
 }
 
 impl ReflectDeserialize {
+    /// Creates a [`ReflectDeserialize`] from a function deserializing a reflected value.
+    ///
+    /// This can be used to deserialize types whose representation is only known at runtime.
+    /// Usually, a [`ReflectDeserialize`] should be obtained using [`FromType::from_type`] instead.
+    pub fn new(
+        func: impl Fn(&mut dyn erased_serde::Deserializer) -> Result<Box<dyn Reflect>, erased_serde::Error>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self {
+            func: Arc::new(func),
+        }
+    }
+
     /// Deserializes a reflected value.
     ///
     /// The underlying type of the reflected value, and thus the expected
@@ -632,9 +1102,7 @@ impl ReflectDeserialize {
 
 impl<T: for<'a> Deserialize<'a> + Reflect> FromType<T> for ReflectDeserialize {
     fn from_type() -> Self {
-        ReflectDeserialize {
-            func: |deserializer| Ok(Box::new(T::deserialize(deserializer)?)),
-        }
+        ReflectDeserialize::new(|deserializer| Ok(Box::new(T::deserialize(deserializer)?)))
     }
 }
 
@@ -749,7 +1217,8 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 #[cfg(test)]
 #[allow(unsafe_code)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr};
+    use crate::std_traits::ReflectDefault;
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
 
     use crate as bevy_reflect;
@@ -795,4 +1264,228 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn should_query_types_with_data() {
+        #[derive(Reflect, Default)]
+        #[reflect(Default)]
+        struct Foo;
+
+        #[derive(Reflect, Default)]
+        #[reflect(Default)]
+        struct Bar;
+
+        #[derive(Reflect)]
+        struct Baz;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register::<Baz>();
+
+        let mut with_default = registry
+            .iter_with_data::<ReflectDefault>()
+            .map(|(registration, _)| registration.type_info().type_path_table().short_path())
+            .collect::<Vec<_>>();
+        with_default.sort();
+        assert_eq!(vec!["Bar", "Foo"], with_default);
+
+        assert_eq!(2, registry.count_with_data::<ReflectDefault>());
+        assert_eq!(3, registry.count_with_data::<ReflectFromPtr>());
+
+        assert!(registry.contains_type_data::<ReflectDefault>(std::any::TypeId::of::<Foo>()));
+        assert!(!registry.contains_type_data::<ReflectDefault>(std::any::TypeId::of::<Baz>()));
+        assert!(!registry.contains_type_data::<ReflectDefault>(std::any::TypeId::of::<u8>()));
+
+        let registration = registry.get(std::any::TypeId::of::<Baz>()).unwrap();
+        assert!(registration.contains::<ReflectFromPtr>());
+        assert!(!registration.contains::<ReflectDefault>());
+    }
+
+    #[test]
+    fn should_construct_by_type_path() {
+        #[derive(Reflect, Default, PartialEq, Debug)]
+        #[reflect(Default)]
+        struct Health(u32);
+
+        #[derive(Reflect, PartialEq, Debug)]
+        struct Speed {
+            #[reflect(default)]
+            value: f32,
+        }
+
+        #[derive(Reflect, PartialEq, Debug)]
+        enum State {
+            Idle,
+            Running(f32),
+        }
+
+        #[derive(Reflect)]
+        struct Target {
+            entity: u32,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Health>();
+        registry.register::<Speed>();
+        registry.register::<State>();
+        registry.register::<Target>();
+        registry.register::<Vec<u32>>();
+
+        let health = registry.construct("Health").unwrap();
+        assert_eq!(Some(&Health(0)), health.downcast_ref::<Health>());
+
+        let speed = registry
+            .construct("bevy_reflect::type_registry::test::Speed")
+            .unwrap();
+        assert_eq!(Some(&Speed { value: 0.0 }), speed.downcast_ref::<Speed>());
+
+        let state = registry.construct("State").unwrap();
+        assert_eq!(Some(&State::Idle), state.downcast_ref::<State>());
+
+        let list = registry.construct("Vec<u32>").unwrap();
+        assert_eq!(Some(&Vec::<u32>::new()), list.downcast_ref::<Vec<u32>>());
+
+        assert!(registry.construct("Target").is_none());
+        assert!(registry.construct("Missing").is_none());
+    }
+
+    #[test]
+    fn should_register_nested_types() {
+        #[derive(Reflect)]
+        struct Inventory {
+            slots: std::collections::BTreeMap<u8, Item>,
+        }
+
+        #[derive(Reflect)]
+        enum Item {
+            Empty,
+            Stack { count: u16 },
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Inventory>();
+
+        assert!(registry.contains(std::any::TypeId::of::<Item>()));
+        assert!(registry.contains(std::any::TypeId::of::<u8>()));
+        assert!(registry.contains(std::any::TypeId::of::<u16>()));
+        assert!(registry.unregistered_dependencies().is_empty());
+
+        let mut registry = TypeRegistry::empty();
+        registry.add_registration(Inventory::get_type_registration());
+        assert_eq!(
+            vec!["std::collections::BTreeMap<u8, bevy_reflect::type_registry::test::Item>"],
+            registry.unregistered_dependencies()
+        );
+    }
+
+    #[test]
+    fn should_deregister_types() {
+        mod a {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Shared;
+        }
+
+        mod b {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Shared;
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<a::Shared>();
+        registry.register::<b::Shared>();
+        assert!(registry.is_ambiguous("Shared"));
+
+        let removed = registry.deregister(std::any::TypeId::of::<a::Shared>());
+        assert!(removed.unwrap().type_info().is::<a::Shared>());
+        assert!(registry
+            .deregister(std::any::TypeId::of::<a::Shared>())
+            .is_none());
+        assert!(registry
+            .get_with_type_path(<a::Shared as crate::TypePath>::type_path())
+            .is_none());
+
+        // The short path now only refers to `b::Shared`
+        assert!(!registry.is_ambiguous("Shared"));
+        let registration = registry.get_with_short_type_path("Shared").unwrap();
+        assert!(registration.type_info().is::<b::Shared>());
+
+        registry.deregister(std::any::TypeId::of::<b::Shared>());
+        assert!(registry.get_with_short_type_path("Shared").is_none());
+        assert_eq!(0, registry.iter().count());
+    }
+
+    #[test]
+    fn should_replace_registration() {
+        #[derive(Reflect, Default)]
+        #[reflect(Default)]
+        struct Foo;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+
+        let previous = registry.replace_registration(TypeRegistration::of::<Foo>());
+        assert!(previous.unwrap().contains::<ReflectDefault>());
+        assert!(!registry.contains_type_data::<ReflectDefault>(std::any::TypeId::of::<Foo>()));
+        assert!(!registry.is_ambiguous("Foo"));
+        assert!(registry.get_with_short_type_path("Foo").is_some());
+    }
+
+    #[test]
+    fn should_resolve_ambiguous_short_type_paths() {
+        mod a {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Shared;
+        }
+
+        mod b {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Shared;
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<a::Shared>();
+        registry.register::<b::Shared>();
+
+        assert!(registry.get_with_short_type_path("Shared").is_none());
+        let error = registry.try_get_with_short_type_path("Shared").unwrap_err();
+        assert_eq!("Shared", error.short_type_path);
+        assert_eq!(
+            vec![
+                "bevy_reflect::type_registry::test::a::Shared",
+                "bevy_reflect::type_registry::test::b::Shared",
+            ],
+            error.candidates
+        );
+        assert_eq!(2, registry.get_all_with_short_type_path("Shared").count());
+        assert!(registry
+            .try_get_with_short_type_path("Missing")
+            .unwrap()
+            .is_none());
+
+        let b = std::any::TypeId::of::<b::Shared>();
+        assert_eq!(None, registry.register_short_type_path_alias("Shared", b));
+        registry.register_short_type_path_alias("OtherShared", b);
+        let registration = registry.get_with_short_type_path("Shared").unwrap();
+        assert!(registration.type_info().is::<b::Shared>());
+        assert!(registry.is_ambiguous("Shared"));
+
+        assert_eq!(Some(b), registry.remove_short_type_path_alias("Shared"));
+        assert!(registry.try_get_with_short_type_path("Shared").is_err());
+
+        registry.deregister(b);
+        assert!(registry.get_with_short_type_path("OtherShared").is_none());
+    }
 }
@@ -0,0 +1,377 @@
+use crate::SchemaSnapshot;
+use bevy_ecs::{
+    archetype::Archetype,
+    component::ComponentId,
+    entity::{Entity, EntityHashMap},
+    reflect::ReflectComponent,
+    world::World,
+};
+use bevy_reflect::TypeRegistry;
+use std::any::TypeId;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The assignment of a bit to each component type of a [`SchemaSnapshot`],
+/// used to build [`ComponentMask`]s.
+///
+/// Bit `i` stands for the `i`-th type of the snapshot.
+/// Since the snapshot is persisted, or shared between the peers of a replicated session,
+/// the bits are the same across builds,
+/// as long as new component types are appended with [`SchemaSnapshot::extend_from_registry`].
+///
+/// Types of the snapshot that are not registered as components in the current build
+/// keep their bit, which is never set.
+pub struct ComponentBitLayout {
+    bits: Vec<ComponentBit>,
+}
+
+struct ComponentBit {
+    type_path: String,
+    registration: Option<(TypeId, ReflectComponent)>,
+}
+
+impl ComponentBitLayout {
+    /// Assign a bit to each component type of the given snapshot.
+    pub fn new(snapshot: &SchemaSnapshot, registry: &TypeRegistry) -> Self {
+        let bits = snapshot
+            .types
+            .iter()
+            .map(|ty| ComponentBit {
+                type_path: ty.type_path.clone(),
+                registration: registry
+                    .get_with_type_path(&ty.type_path)
+                    .and_then(|registration| {
+                        let reflect_component = registration.data::<ReflectComponent>()?;
+                        Some((registration.type_id(), reflect_component.clone()))
+                    }),
+            })
+            .collect();
+        Self { bits }
+    }
+
+    /// The number of bits of the layout.
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Returns `true` if the layout has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Returns the bit of the component type with the given [`TypeId`], if any.
+    pub fn bit(&self, type_id: TypeId) -> Option<usize> {
+        self.bits.iter().position(|bit| {
+            bit.registration
+                .as_ref()
+                .is_some_and(|(id, _)| *id == type_id)
+        })
+    }
+
+    /// Returns the type path of the component type of the given bit.
+    pub fn type_path(&self, bit: usize) -> Option<&str> {
+        self.bits.get(bit).map(|bit| bit.type_path.as_str())
+    }
+
+    /// Returns the [`TypeId`] of the component type of the given bit,
+    /// if it is registered in the current build.
+    pub fn type_id(&self, bit: usize) -> Option<TypeId> {
+        self.registration(bit).map(|(type_id, _)| *type_id)
+    }
+
+    /// Returns the [`ReflectComponent`] of the component type of the given bit,
+    /// if it is registered in the current build.
+    ///
+    /// This is used to access the values of the components of a [`ComponentMask`],
+    /// such as to diff them.
+    pub fn reflect_component(&self, bit: usize) -> Option<&ReflectComponent> {
+        self.registration(bit)
+            .map(|(_, reflect_component)| reflect_component)
+    }
+
+    fn registration(&self, bit: usize) -> Option<&(TypeId, ReflectComponent)> {
+        self.bits.get(bit)?.registration.as_ref()
+    }
+
+    /// Returns the [`ComponentMask`] of the given entity,
+    /// or `None` if the entity doesn't exist.
+    pub fn mask(&self, world: &World, entity: Entity) -> Option<ComponentMask> {
+        let ids = self.component_ids(world);
+        let entity = world.get_entity(entity)?;
+        Some(Self::archetype_mask(entity.archetype(), &ids))
+    }
+
+    /// Returns the [`ComponentMask`] of every entity of the world.
+    ///
+    /// Masks are computed once per archetype.
+    pub fn masks(&self, world: &World) -> EntityHashMap<ComponentMask> {
+        let ids = self.component_ids(world);
+        let mut masks = EntityHashMap::default();
+        for archetype in world.archetypes().iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            let mask = Self::archetype_mask(archetype, &ids);
+            masks.extend(
+                archetype
+                    .entities()
+                    .iter()
+                    .map(|entity| (entity.id(), mask.clone())),
+            );
+        }
+        masks
+    }
+
+    fn component_ids(&self, world: &World) -> Vec<Option<ComponentId>> {
+        self.bits
+            .iter()
+            .map(|bit| {
+                let (type_id, _) = bit.registration.as_ref()?;
+                world.components().get_id(*type_id)
+            })
+            .collect()
+    }
+
+    fn archetype_mask(archetype: &Archetype, ids: &[Option<ComponentId>]) -> ComponentMask {
+        let mut mask = ComponentMask::default();
+        for (bit, id) in ids.iter().enumerate() {
+            if id.is_some_and(|id| archetype.contains(id)) {
+                mask.insert(bit);
+            }
+        }
+        mask
+    }
+}
+
+/// A compact set of component types, given by their bit in a [`ComponentBitLayout`].
+///
+/// Comparing the masks of an entity at two points in time, with [`ComponentMask::diff`],
+/// tells which components were added and removed,
+/// and which ones were kept and may need their values compared.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ComponentMask {
+    // Trailing zero words are trimmed, so that equal sets are equal masks
+    words: Vec<u64>,
+}
+
+impl ComponentMask {
+    /// Returns `true` if the given bit is set.
+    pub fn contains(&self, bit: usize) -> bool {
+        self.words
+            .get(bit / 64)
+            .is_some_and(|word| word & (1 << (bit % 64)) != 0)
+    }
+
+    /// Sets the given bit.
+    pub fn insert(&mut self, bit: usize) {
+        let index = bit / 64;
+        if index >= self.words.len() {
+            self.words.resize(index + 1, 0);
+        }
+        self.words[index] |= 1 << (bit % 64);
+    }
+
+    /// Clears the given bit.
+    pub fn remove(&mut self, bit: usize) {
+        if let Some(word) = self.words.get_mut(bit / 64) {
+            *word &= !(1 << (bit % 64));
+            self.trim();
+        }
+    }
+
+    /// Returns `true` if no bit is set.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the number of set bits.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over the set bits, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| index * 64 + bit)
+        })
+    }
+
+    /// Returns the bits set in either mask.
+    pub fn union(&self, other: &Self) -> Self {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (word, other) in words.iter_mut().zip(&shorter.words) {
+            *word |= other;
+        }
+        Self { words }
+    }
+
+    /// Returns the bits set in both masks.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(word, other)| word & other)
+            .collect();
+        let mut mask = Self { words };
+        mask.trim();
+        mask
+    }
+
+    /// Returns the bits set in this mask but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| word & !other.words.get(index).copied().unwrap_or_default())
+            .collect();
+        let mut mask = Self { words };
+        mask.trim();
+        mask
+    }
+
+    /// Compares this mask to a newer mask of the same entity.
+    pub fn diff(&self, new: &Self) -> ComponentMaskDiff {
+        ComponentMaskDiff {
+            added: new.difference(self),
+            removed: self.difference(new),
+            retained: self.intersection(new),
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl FromIterator<usize> for ComponentMask {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut mask = Self::default();
+        for bit in iter {
+            mask.insert(bit);
+        }
+        mask
+    }
+}
+
+/// The difference between two [`ComponentMask`]s of an entity, returned by [`ComponentMask::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ComponentMaskDiff {
+    /// The components present in the new mask only.
+    pub added: ComponentMask,
+    /// The components present in the old mask only.
+    pub removed: ComponentMask,
+    /// The components present in both masks.
+    ///
+    /// Only these components need their values compared,
+    /// using the [`ReflectComponent`] given by [`ComponentBitLayout::reflect_component`].
+    pub retained: ComponentMask,
+}
+
+impl ComponentMaskDiff {
+    /// Returns `true` if no component was added or removed.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentBitLayout, ComponentMask};
+    use crate::SchemaSnapshot;
+    use bevy_ecs::prelude::{Component, ReflectComponent, World};
+    use bevy_reflect::{Reflect, TypeRegistry};
+    use std::any::TypeId;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct A;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct B;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct C(u32);
+
+    #[test]
+    fn should_keep_bits_stable() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<B>();
+        registry.register::<C>();
+        let mut snapshot = SchemaSnapshot::from_registry(&registry);
+
+        registry.register::<A>();
+        snapshot.extend_from_registry(&registry);
+
+        let layout = ComponentBitLayout::new(&snapshot, &registry);
+        assert_eq!(Some(0), layout.bit(TypeId::of::<B>()));
+        assert_eq!(Some(1), layout.bit(TypeId::of::<C>()));
+        assert_eq!(Some(2), layout.bit(TypeId::of::<A>()));
+        assert!(layout.reflect_component(1).is_some());
+        assert!(layout.type_id(3).is_none());
+    }
+
+    #[test]
+    fn should_diff_entity_masks() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<A>();
+        registry.register::<B>();
+        registry.register::<C>();
+        let layout = ComponentBitLayout::new(&SchemaSnapshot::from_registry(&registry), &registry);
+        let (a, b, c) = (0, 1, 2);
+
+        let mut world = World::new();
+        let first = world.spawn((A, C(1))).id();
+        let second = world.spawn((A, C(2))).id();
+        let third = world.spawn(B).id();
+
+        let old = layout.masks(&world);
+        assert_eq!(3, old.len());
+        assert_eq!([a, c].into_iter().collect::<ComponentMask>(), old[&first]);
+        assert_eq!(old[&first], old[&second]);
+        assert_eq!(Some(&old[&third]), layout.mask(&world, third).as_ref());
+
+        world.entity_mut(first).remove::<A>().insert(B);
+        let new = layout.masks(&world);
+        let diff = old[&first].diff(&new[&first]);
+        assert_eq!(vec![b], diff.added.iter().collect::<Vec<_>>());
+        assert_eq!(vec![a], diff.removed.iter().collect::<Vec<_>>());
+        assert_eq!(vec![c], diff.retained.iter().collect::<Vec<_>>());
+        assert!(old[&second].diff(&new[&second]).is_unchanged());
+    }
+
+    #[test]
+    fn should_trim_masks() {
+        let mut mask = ComponentMask::default();
+        mask.insert(3);
+        mask.insert(130);
+        assert_eq!(2, mask.len());
+        assert_eq!(vec![3, 130], mask.iter().collect::<Vec<_>>());
+
+        mask.remove(130);
+        assert_eq!(std::iter::once(3).collect::<ComponentMask>(), mask);
+        assert!(mask.difference(&mask).is_empty());
+        assert_eq!(
+            [3, 70].into_iter().collect::<ComponentMask>(),
+            mask.union(&std::iter::once(70).collect())
+        );
+    }
+}
@@ -0,0 +1,535 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use bevy_ecs::{
+    component::ComponentInfo,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    schedule::ScheduleLabel,
+    world::World,
+};
+use bevy_reflect::{Reflect, ReflectRef};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ron, SceneFilter};
+
+/// Runs a world for a number of fixed steps, recording the reflected components
+/// of its entities after every step.
+///
+/// This is meant for determinism tests: two runs of the same simulation,
+/// or a run and a golden file written by an earlier run, should produce equal [`Recording`]s.
+/// When they don't, [`Recording::diff`] points at the exact step, entity, component and field
+/// where they started to diverge.
+///
+/// Only the component types allowed by the recorder's [`SceneFilter`] are recorded,
+/// and they must be registered with [`ReflectComponent`] type data in the world's [`AppTypeRegistry`].
+///
+/// Steps are run back to back, without involving `Time` at all,
+/// so the recording doesn't depend on how fast the test machine is.
+/// Systems that rely on the fixed timestep should read it from `Time<Fixed>`,
+/// which is left untouched.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// # use bevy_scene::DeterminismRecorder;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Position(f32);
+///
+/// fn setup() -> World {
+///     let mut world = World::new();
+///     world.init_resource::<AppTypeRegistry>();
+///     world.resource::<AppTypeRegistry>().write().register::<Position>();
+///     world.spawn(Position(0.0));
+///     world
+/// }
+///
+/// fn step(world: &mut World) {
+///     for mut position in world.query::<&mut Position>().iter_mut(world) {
+///         position.0 += 0.5;
+///     }
+/// }
+///
+/// let recorder = DeterminismRecorder::new().record::<Position>();
+/// let first = recorder.run(&mut setup(), 10, step);
+/// let second = recorder.run(&mut setup(), 10, step);
+/// first.assert_matches(&second);
+/// ```
+pub struct DeterminismRecorder {
+    filter: SceneFilter,
+}
+
+impl Default for DeterminismRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeterminismRecorder {
+    /// Creates a recorder that doesn't record any component type yet.
+    pub fn new() -> Self {
+        Self {
+            filter: SceneFilter::deny_all(),
+        }
+    }
+
+    /// Creates a recorder that records the component types allowed by the given filter.
+    pub fn with_filter(filter: SceneFilter) -> Self {
+        Self { filter }
+    }
+
+    /// Records the component type `T`.
+    #[must_use]
+    pub fn record<T: bevy_ecs::component::Component>(mut self) -> Self {
+        self.filter = self.filter.allow::<T>();
+        self
+    }
+
+    /// Runs `step` on the world `steps` times, capturing the world after each run.
+    pub fn run(
+        &self,
+        world: &mut World,
+        steps: usize,
+        mut step: impl FnMut(&mut World),
+    ) -> Recording {
+        let steps = (0..steps)
+            .map(|_| {
+                step(world);
+                self.capture(world)
+            })
+            .collect();
+        Recording { steps }
+    }
+
+    /// Runs the schedule with the given label `steps` times, capturing the world after each run.
+    ///
+    /// This is usually `FixedUpdate` or `FixedMain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule doesn't exist.
+    pub fn run_schedule(
+        &self,
+        world: &mut World,
+        steps: usize,
+        label: impl ScheduleLabel,
+    ) -> Recording {
+        let label = label.intern();
+        self.run(world, steps, |world| world.run_schedule(label))
+    }
+
+    /// Captures the recorded components of every entity of the world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world doesn't have an [`AppTypeRegistry`].
+    pub fn capture(&self, world: &World) -> StepSnapshot {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut snapshot = StepSnapshot::default();
+        for entity in world.iter_entities() {
+            let mut components = BTreeMap::new();
+            for component_id in entity.archetype().components() {
+                let Some(type_id) = world
+                    .components()
+                    .get_info(component_id)
+                    .and_then(ComponentInfo::type_id)
+                else {
+                    continue;
+                };
+                if !self.filter.is_allowed_by_id(type_id) {
+                    continue;
+                }
+                let Some(registration) = registry.get(type_id) else {
+                    continue;
+                };
+                let Some(value) = registration
+                    .data::<ReflectComponent>()
+                    .and_then(|reflect_component| reflect_component.reflect(entity))
+                else {
+                    continue;
+                };
+                let mut fields = BTreeMap::new();
+                record_fields(value, &mut String::new(), &mut fields);
+                components.insert(registration.type_info().type_path().to_string(), fields);
+            }
+            if !components.is_empty() {
+                snapshot.entities.insert(entity.id(), components);
+            }
+        }
+        snapshot
+    }
+}
+
+/// Records the leaf fields of `value`, keyed by their path relative to the component.
+fn record_fields(value: &dyn Reflect, path: &mut String, fields: &mut BTreeMap<String, String>) {
+    let len = path.len();
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{}", value.name_at(index).unwrap()).unwrap();
+                record_fields(field, path, fields);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{index}").unwrap();
+                record_fields(field, path, fields);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{index}").unwrap();
+                record_fields(field, path, fields);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::List(value) => {
+            for (index, item) in value.iter().enumerate() {
+                write!(path, "[{index}]").unwrap();
+                record_fields(item, path, fields);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (index, item) in value.iter().enumerate() {
+                write!(path, "[{index}]").unwrap();
+                record_fields(item, path, fields);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            // The variant is recorded at the path of the enum itself,
+            // so that switching variants shows up even if the fields look alike.
+            fields.insert(path.clone(), value.variant_name().to_string());
+            for (index, field) in value.iter_fields().enumerate() {
+                match field.name() {
+                    Some(name) => write!(path, ".{name}").unwrap(),
+                    None => write!(path, ".{index}").unwrap(),
+                }
+                record_fields(field.value(), path, fields);
+                path.truncate(len);
+            }
+        }
+        _ => {
+            fields.insert(path.clone(), format!("{value:?}"));
+        }
+    }
+}
+
+/// The components of a world captured by a [`DeterminismRecorder`] after each step.
+///
+/// Recordings can be saved as golden files with [`to_ron`](Self::to_ron),
+/// and loaded again with [`from_ron`](Self::from_ron).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct Recording {
+    /// The snapshot taken after each step.
+    pub steps: Vec<StepSnapshot>,
+}
+
+/// The recorded components of a world at a single step of a [`Recording`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct StepSnapshot {
+    /// The leaf fields of each recorded component, by entity and component type path.
+    ///
+    /// Fields are keyed by their path within the component (e.g. `.velocity.x`),
+    /// and hold the [`Debug`] representation of their value.
+    /// Variants of enums are recorded at the path of the enum.
+    pub entities: BTreeMap<Entity, BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl StepSnapshot {
+    fn fields(&self) -> BTreeMap<(Entity, &str, &str), &str> {
+        self.entities
+            .iter()
+            .flat_map(|(entity, components)| {
+                components.iter().flat_map(move |(component, fields)| {
+                    fields.iter().map(move |(field, value)| {
+                        (
+                            (*entity, component.as_str(), field.as_str()),
+                            value.as_str(),
+                        )
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+impl Recording {
+    /// Compares this recording, the expected one, to the `actual` recording.
+    pub fn diff(&self, actual: &Recording) -> RecordingDiff {
+        let mut divergences = Vec::new();
+        for (step, (expected, actual)) in self.steps.iter().zip(&actual.steps).enumerate() {
+            let expected = expected.fields();
+            let actual = actual.fields();
+            let mut keys: Vec<_> = expected.keys().chain(actual.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let (entity, component, field) = key;
+                let expected = expected.get(key).copied();
+                let actual = actual.get(key).copied();
+                if expected != actual {
+                    divergences.push(Divergence {
+                        step,
+                        entity: *entity,
+                        component: component.to_string(),
+                        field: field.to_string(),
+                        expected: expected.map(str::to_string),
+                        actual: actual.map(str::to_string),
+                    });
+                }
+            }
+        }
+        RecordingDiff {
+            divergences,
+            expected_steps: self.steps.len(),
+            actual_steps: actual.steps.len(),
+        }
+    }
+
+    /// Asserts that the `actual` recording matches this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the rendered [`RecordingDiff`] if the recordings differ.
+    #[track_caller]
+    pub fn assert_matches(&self, actual: &Recording) {
+        let diff = self.diff(actual);
+        if !diff.is_empty() {
+            panic!("{diff}");
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Recording {
+    /// Serializes this recording as RON, such as to write a golden file.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        let pretty_config = ron::ser::PrettyConfig::default()
+            .indentor("  ".to_string())
+            .new_line("\n".to_string());
+        ron::ser::to_string_pretty(self, pretty_config)
+    }
+
+    /// Deserializes a recording written with [`to_ron`](Self::to_ron).
+    pub fn from_ron(input: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::de::from_str(input)
+    }
+}
+
+/// A field that differs between two [`Recording`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// The index of the step, starting at zero for the snapshot taken after the first step.
+    pub step: usize,
+    /// The entity of the component.
+    pub entity: Entity,
+    /// The type path of the component.
+    pub component: String,
+    /// The path of the field within the component.
+    pub field: String,
+    /// The value in the expected recording, or `None` if the field wasn't recorded.
+    pub expected: Option<String>,
+    /// The value in the actual recording, or `None` if the field wasn't recorded.
+    pub actual: Option<String>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "step {}, entity {}, `{}{}`: expected {}, found {}",
+            self.step,
+            self.entity,
+            self.component,
+            self.field,
+            self.expected.as_deref().unwrap_or("nothing"),
+            self.actual.as_deref().unwrap_or("nothing"),
+        )
+    }
+}
+
+/// The differences between two [`Recording`]s, returned by [`Recording::diff`].
+///
+/// Its [`Display`](fmt::Display) implementation renders the diverging fields grouped by step,
+/// with `-` lines for the expected values and `+` lines for the actual ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordingDiff {
+    /// The fields that differ, ordered by step, entity, component and field.
+    ///
+    /// Only the steps present in both recordings are compared.
+    pub divergences: Vec<Divergence>,
+    /// The number of steps of the expected recording.
+    pub expected_steps: usize,
+    /// The number of steps of the actual recording.
+    pub actual_steps: usize,
+}
+
+impl RecordingDiff {
+    /// Returns `true` if the recordings match.
+    pub fn is_empty(&self) -> bool {
+        self.divergences.is_empty() && self.expected_steps == self.actual_steps
+    }
+
+    /// Returns the first diverging field, which usually points at the cause of the divergence.
+    pub fn first(&self) -> Option<&Divergence> {
+        self.divergences.first()
+    }
+}
+
+impl fmt::Display for RecordingDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(first) = self.first() {
+            writeln!(f, "recordings diverge at {first}")?;
+        }
+        let mut last_step = None;
+        let mut last_component = None;
+        for divergence in &self.divergences {
+            if last_step != Some(divergence.step) {
+                writeln!(f, "step {}:", divergence.step)?;
+                last_step = Some(divergence.step);
+                last_component = None;
+            }
+            let component = (divergence.entity, divergence.component.as_str());
+            if last_component != Some(component) {
+                writeln!(f, "  entity {} `{}`", component.0, component.1)?;
+                last_component = Some(component);
+            }
+            if let Some(expected) = &divergence.expected {
+                writeln!(f, "  - {}: {expected}", divergence.field)?;
+            }
+            if let Some(actual) = &divergence.actual {
+                writeln!(f, "  + {}: {actual}", divergence.field)?;
+            }
+        }
+        if self.expected_steps != self.actual_steps {
+            writeln!(
+                f,
+                "expected {} steps, found {}",
+                self.expected_steps, self.actual_steps
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::schedule::ScheduleLabel;
+    use bevy_reflect::Reflect;
+
+    use super::{DeterminismRecorder, Recording};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Velocity(f32, f32);
+
+    #[derive(Component)]
+    struct Ignored;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Step;
+
+    fn integrate(mut query: Query<(&mut Position, &Velocity)>) {
+        for (mut position, velocity) in &mut query {
+            position.x += velocity.0;
+            position.y += velocity.1;
+        }
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Velocity>();
+        }
+        world.spawn((Position::default(), Velocity(1.0, 0.5)));
+        world.spawn((Position::default(), Velocity(-1.0, 0.0), Ignored));
+        world.spawn(Ignored);
+        let mut schedule = Schedule::new(Step);
+        schedule.add_systems(integrate);
+        world.add_schedule(schedule);
+        world
+    }
+
+    fn recorder() -> DeterminismRecorder {
+        DeterminismRecorder::new()
+            .record::<Position>()
+            .record::<Velocity>()
+    }
+
+    #[test]
+    fn should_record_every_step() {
+        let recording = recorder().run_schedule(&mut world(), 3, Step);
+        assert_eq!(3, recording.steps.len());
+
+        let last = &recording.steps[2];
+        assert_eq!(2, last.entities.len());
+        let (_, components) = last.entities.first_key_value().unwrap();
+        let position = &components[std::any::type_name::<Position>()];
+        assert_eq!("3.0", position[".x"]);
+        assert_eq!("1.5", position[".y"]);
+
+        recording.assert_matches(&recorder().run_schedule(&mut world(), 3, Step));
+    }
+
+    #[test]
+    fn should_find_first_divergence() {
+        let expected = recorder().run_schedule(&mut world(), 5, Step);
+
+        let mut world = world();
+        let mut step = 0;
+        let actual = recorder().run(&mut world, 4, |world| {
+            world.run_schedule(Step);
+            step += 1;
+            if step == 3 {
+                for mut velocity in world.query::<&mut Velocity>().iter_mut(world) {
+                    velocity.1 += 0.25;
+                }
+            }
+        });
+
+        let diff = expected.diff(&actual);
+        assert!(!diff.is_empty());
+        let first = diff.first().unwrap();
+        assert_eq!(2, first.step);
+        assert_eq!(std::any::type_name::<Velocity>(), first.component);
+        assert_eq!(".1", first.field);
+        assert_eq!(Some("0.5"), first.expected.as_deref());
+        assert_eq!(Some("0.75"), first.actual.as_deref());
+
+        let rendered = diff.to_string();
+        assert!(rendered.contains("  - .1: 0.5\n  + .1: 0.75\n"));
+        assert!(rendered.ends_with("expected 5 steps, found 4\n"));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn should_compare_against_golden() {
+        let recording = recorder().run_schedule(&mut world(), 2, Step);
+        let golden = recording.to_ron().unwrap();
+        Recording::from_ron(&golden)
+            .unwrap()
+            .assert_matches(&recording);
+    }
+}
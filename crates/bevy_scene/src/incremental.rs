@@ -0,0 +1,524 @@
+use std::fmt::Write;
+
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    reflect::{
+        AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectMapEntitiesResource,
+        ReflectResource,
+    },
+    world::World,
+};
+use bevy_reflect::{
+    ApplyError, GetPath, Reflect, ReflectRef, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use bevy_utils::TypeIdMap;
+use thiserror::Error;
+
+use crate::{DynamicScene, SceneSpawnError};
+
+/// Applies a [`DynamicScene`] to a world over multiple calls,
+/// doing a bounded amount of work each time.
+///
+/// Writing a large scene with [`DynamicScene::write_to_world_with`] happens all at once,
+/// which can cause a noticeable hitch when streaming in a level or rolling back a lot of state.
+/// Instead, an `IncrementalPatch` can be advanced a little every frame using [`apply`](Self::apply).
+///
+/// The scene is treated as a patch: every top-level value (a component or a resource)
+/// is split into its leaf fields, and each applied leaf counts as one unit of work
+/// towards the budget passed to [`apply`](Self::apply).
+/// A value that does not exist in the world yet is inserted as a whole, counting as a single unit.
+///
+/// Leaves are applied to a staged copy of the value, which is only written back
+/// to the world once all of its leaves have been applied.
+/// This means that other systems never observe a partially patched component or resource.
+/// Changes made to a value in the world while it is staged are overwritten when it is committed.
+///
+/// Entity references within components are mapped once all entities have been committed,
+/// before any resources are applied, matching [`DynamicScene::write_to_world_with`].
+pub struct IncrementalPatch {
+    scene: DynamicScene,
+    values: Vec<PatchValue>,
+    component_count: usize,
+    next_value: usize,
+    next_leaf: usize,
+    staged: Option<Box<dyn Reflect>>,
+    scene_mappings: TypeIdMap<Vec<Entity>>,
+    applied: usize,
+    total: usize,
+}
+
+struct PatchValue {
+    target: PatchTarget,
+    /// The paths of the leaf fields of the value.
+    leaves: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+enum PatchTarget {
+    Component { entity: usize, component: usize },
+    Resource(usize),
+}
+
+impl IncrementalPatch {
+    /// Creates an incremental patch that will write `scene` to a world.
+    pub fn new(scene: DynamicScene) -> Self {
+        let mut values = Vec::new();
+        for (entity, scene_entity) in scene.entities.iter().enumerate() {
+            for (component, value) in scene_entity.components.iter().enumerate() {
+                values.push(PatchValue {
+                    target: PatchTarget::Component { entity, component },
+                    leaves: leaf_paths(&**value),
+                });
+            }
+        }
+        let component_count = values.len();
+        for (index, value) in scene.resources.iter().enumerate() {
+            values.push(PatchValue {
+                target: PatchTarget::Resource(index),
+                leaves: leaf_paths(&**value),
+            });
+        }
+        let total = values.iter().map(|value| value.leaves.len()).sum();
+
+        Self {
+            scene,
+            values,
+            component_count,
+            next_value: 0,
+            next_leaf: 0,
+            staged: None,
+            scene_mappings: Default::default(),
+            applied: 0,
+            total,
+        }
+    }
+
+    /// Returns the scene being applied.
+    pub fn scene(&self) -> &DynamicScene {
+        &self.scene
+    }
+
+    /// Returns `true` once the whole scene has been written to the world.
+    pub fn is_finished(&self) -> bool {
+        self.next_value == self.values.len()
+    }
+
+    /// Returns the fraction of leaves applied so far, between `0.0` and `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.is_finished() || self.total == 0 {
+            1.0
+        } else {
+            self.applied as f32 / self.total as f32
+        }
+    }
+
+    /// Applies at most `budget` units of work, returning `true` once the whole scene has been written.
+    ///
+    /// At least one unit of work is done per call, so a budget of `0` still makes progress.
+    /// Scene entities are mapped to world entities using `entity_map`, in the same way as
+    /// [`DynamicScene::write_to_world_with`], so the same map must be passed to every call.
+    ///
+    /// If an error is returned, the value being staged is left uncommitted
+    /// and the values committed by earlier calls remain in the world.
+    pub fn apply(
+        &mut self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+        budget: usize,
+    ) -> Result<bool, IncrementalPatchError> {
+        let type_registry = type_registry.read();
+        let budget = budget.max(1);
+        let mut work = 0;
+
+        while work < budget && !self.is_finished() {
+            let PatchValue { target, leaves } = &self.values[self.next_value];
+            let patch = patch_value(&self.scene, *target);
+            let registration = registration(&type_registry, patch)?;
+
+            let staged = match &mut self.staged {
+                Some(staged) => staged,
+                staged @ None => {
+                    let current = match *target {
+                        PatchTarget::Component { entity, .. } => {
+                            let entity = world_entity(&self.scene, world, entity_map, entity);
+                            component_data(registration)?.reflect(world.entity(entity))
+                        }
+                        PatchTarget::Resource(_) => resource_data(registration)?.reflect(world),
+                    };
+                    match current {
+                        Some(current) => staged.insert(current.clone_value()),
+                        None => {
+                            // There is nothing to patch, so the value is inserted as a whole.
+                            self.next_leaf = leaves.len();
+                            self.applied += leaves.len();
+                            work += 1;
+                            staged.insert(patch.clone_value())
+                        }
+                    }
+                }
+            };
+
+            while work < budget && self.next_leaf < leaves.len() {
+                let path = leaves[self.next_leaf].as_str();
+                apply_leaf(&mut **staged, patch, path).map_err(|error| {
+                    IncrementalPatchError::Apply {
+                        type_path: registration.type_info().type_path().to_string(),
+                        path: path.to_string(),
+                        error,
+                    }
+                })?;
+                self.next_leaf += 1;
+                self.applied += 1;
+                work += 1;
+            }
+
+            if self.next_leaf == leaves.len() {
+                let target = *target;
+                self.commit(world, entity_map, &type_registry, target)?;
+            }
+        }
+
+        Ok(self.is_finished())
+    }
+
+    /// Writes the staged value to the world and moves on to the next value.
+    fn commit(
+        &mut self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        target: PatchTarget,
+    ) -> Result<(), SceneSpawnError> {
+        let staged = self.staged.take().unwrap();
+        let registration = registration(type_registry, patch_value(&self.scene, target))?;
+
+        match target {
+            PatchTarget::Component { entity, .. } => {
+                let entity = world_entity(&self.scene, world, entity_map, entity);
+                component_data(registration)?.apply_or_insert(
+                    &mut world.entity_mut(entity),
+                    &*staged,
+                    type_registry,
+                );
+                if registration.data::<ReflectMapEntities>().is_some() {
+                    self.scene_mappings
+                        .entry(registration.type_id())
+                        .or_default()
+                        .push(entity);
+                }
+            }
+            PatchTarget::Resource(_) => {
+                resource_data(registration)?.apply_or_insert(world, &*staged, type_registry);
+                if let Some(map_entities) = registration.data::<ReflectMapEntitiesResource>() {
+                    map_entities.map_entities(world, entity_map);
+                }
+            }
+        }
+
+        self.next_value += 1;
+        self.next_leaf = 0;
+
+        // Map entity references once every component has been committed,
+        // so that references to entities spawned by later calls are valid.
+        if self.next_value == self.component_count {
+            for (type_id, entities) in self.scene_mappings.drain() {
+                let registration = type_registry.get(type_id).expect(
+                    "we should be getting TypeId from this TypeRegistration in the first place",
+                );
+                if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                    map_entities.map_entities(world, entity_map, &entities);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn patch_value(scene: &DynamicScene, target: PatchTarget) -> &dyn Reflect {
+    match target {
+        PatchTarget::Component { entity, component } => {
+            &*scene.entities[entity].components[component]
+        }
+        PatchTarget::Resource(index) => &*scene.resources[index],
+    }
+}
+
+/// Fetches the world entity for the scene entity at `index`, spawning it if needed.
+fn world_entity(
+    scene: &DynamicScene,
+    world: &mut World,
+    entity_map: &mut EntityHashMap<Entity>,
+    index: usize,
+) -> Entity {
+    *entity_map
+        .entry(scene.entities[index].entity)
+        .or_insert_with(|| world.spawn_empty().id())
+}
+
+/// An error that occurs when applying an [`IncrementalPatch`].
+#[derive(Error, Debug)]
+pub enum IncrementalPatchError {
+    /// A value in the scene could not be written to the world.
+    #[error(transparent)]
+    Spawn(#[from] SceneSpawnError),
+    /// A leaf of the patch could not be applied to the staged value.
+    #[error("failed to apply `{path}` of `{type_path}`: {error}")]
+    Apply {
+        /// The type path of the patched component or resource.
+        type_path: String,
+        /// The path of the leaf within the patched value.
+        path: String,
+        /// The underlying error.
+        error: ApplyError,
+    },
+}
+
+/// Returns the paths to the leaves of `value`.
+///
+/// Struct, tuple struct, and tuple fields are split further,
+/// while all other kinds (such as lists, maps, and enums) are applied as a whole.
+/// The empty path refers to the value itself.
+fn leaf_paths(value: &dyn Reflect) -> Vec<String> {
+    fn visit(value: &dyn Reflect, path: &mut String, leaves: &mut Vec<String>) {
+        let len = path.len();
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    write!(path, ".{}", value.name_at(index).unwrap()).unwrap();
+                    visit(field, path, leaves);
+                    path.truncate(len);
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    write!(path, ".{index}").unwrap();
+                    visit(field, path, leaves);
+                    path.truncate(len);
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    write!(path, ".{index}").unwrap();
+                    visit(field, path, leaves);
+                    path.truncate(len);
+                }
+            }
+            _ => leaves.push(path.clone()),
+        }
+    }
+
+    let mut leaves = Vec::new();
+    visit(value, &mut String::new(), &mut leaves);
+    if leaves.is_empty() {
+        // Values without any fields are applied as a whole.
+        leaves.push(String::new());
+    }
+    leaves
+}
+
+fn apply_leaf(staged: &mut dyn Reflect, patch: &dyn Reflect, path: &str) -> Result<(), ApplyError> {
+    if path.is_empty() {
+        return staged.try_apply(patch);
+    }
+    // Leaf paths are taken from the patch, and the staged value has the same type,
+    // so both paths are expected to be valid.
+    let value = patch
+        .reflect_path(path)
+        .expect("leaf paths should be valid for the patch");
+    let field = staged
+        .reflect_path_mut(path)
+        .expect("leaf paths should be valid for a value of the patched type");
+    field.try_apply(value)
+}
+
+fn registration<'a>(
+    type_registry: &'a TypeRegistry,
+    value: &dyn Reflect,
+) -> Result<&'a TypeRegistration, SceneSpawnError> {
+    let type_info: &TypeInfo =
+        value
+            .get_represented_type_info()
+            .ok_or_else(|| SceneSpawnError::NoRepresentedType {
+                type_path: value.reflect_type_path().to_string(),
+            })?;
+    type_registry.get(type_info.type_id()).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_info.type_path().to_string(),
+        }
+    })
+}
+
+fn component_data(registration: &TypeRegistration) -> Result<&ReflectComponent, SceneSpawnError> {
+    registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+            type_path: registration.type_info().type_path().to_string(),
+        })
+}
+
+fn resource_data(registration: &TypeRegistration) -> Result<&ReflectResource, SceneSpawnError> {
+    registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| SceneSpawnError::UnregisteredResource {
+            type_path: registration.type_info().type_path().to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicEntity;
+    use bevy_ecs::{component::Component, reflect::ReflectResource, system::Resource};
+    use bevy_reflect::DynamicStruct;
+
+    #[derive(Component, Reflect, Debug, Default, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+        speed: (f32, f32),
+    }
+
+    #[derive(Resource, Reflect, Debug, Default, PartialEq)]
+    #[reflect(Resource)]
+    struct Level(u32);
+
+    fn registry() -> AppTypeRegistry {
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<Stats>();
+            registry.register::<Level>();
+        }
+        type_registry
+    }
+
+    #[test]
+    fn applies_patch_within_budget() {
+        let type_registry = registry();
+        let mut world = World::new();
+        let existing = world
+            .spawn(Stats {
+                health: 10,
+                speed: (1.0, 1.0),
+            })
+            .id();
+        world.insert_resource(Level(1));
+
+        let scene = DynamicScene {
+            resources: vec![Box::new(Level(2))],
+            entities: vec![
+                DynamicEntity {
+                    entity: Entity::from_raw(0),
+                    components: vec![Box::new(Stats {
+                        health: 20,
+                        speed: (2.0, 3.0),
+                    })],
+                },
+                DynamicEntity {
+                    entity: Entity::from_raw(1),
+                    components: vec![Box::new(Stats::default())],
+                },
+            ],
+        };
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(Entity::from_raw(0), existing);
+
+        let mut patch = IncrementalPatch::new(scene);
+        assert!(!patch
+            .apply(&mut world, &mut entity_map, &type_registry, 2)
+            .unwrap());
+        // The component is staged, so the world does not see the partial patch
+        assert_eq!(10, world.get::<Stats>(existing).unwrap().health);
+        assert_eq!(2.0 / 7.0, patch.progress());
+
+        assert!(!patch
+            .apply(&mut world, &mut entity_map, &type_registry, 1)
+            .unwrap());
+        assert_eq!(
+            &Stats {
+                health: 20,
+                speed: (2.0, 3.0)
+            },
+            world.get::<Stats>(existing).unwrap()
+        );
+        assert_eq!(1, world.resource::<Level>().0);
+
+        // The second entity is spawned and its component inserted as a single unit of work
+        assert!(!patch
+            .apply(&mut world, &mut entity_map, &type_registry, 1)
+            .unwrap());
+        let spawned = entity_map[&Entity::from_raw(1)];
+        assert_eq!(&Stats::default(), world.get::<Stats>(spawned).unwrap());
+
+        assert!(patch
+            .apply(&mut world, &mut entity_map, &type_registry, 1)
+            .unwrap());
+        assert_eq!(2, world.resource::<Level>().0);
+        assert!(patch.is_finished());
+        assert_eq!(1.0, patch.progress());
+    }
+
+    #[test]
+    fn applies_partial_patch() {
+        let type_registry = registry();
+        let mut world = World::new();
+        let existing = world
+            .spawn(Stats {
+                health: 10,
+                speed: (1.0, 1.0),
+            })
+            .id();
+
+        let mut stats = DynamicStruct::default();
+        stats.set_represented_type(Some(<Stats as bevy_reflect::Typed>::type_info()));
+        stats.insert("health", 5_u32);
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(stats)],
+            }],
+        };
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(Entity::from_raw(0), existing);
+
+        let mut patch = IncrementalPatch::new(scene);
+        assert!(patch
+            .apply(&mut world, &mut entity_map, &type_registry, 0)
+            .unwrap());
+        assert_eq!(
+            &Stats {
+                health: 5,
+                speed: (1.0, 1.0)
+            },
+            world.get::<Stats>(existing).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_unregistered_components() {
+        let mut world = World::new();
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(Stats::default())],
+            }],
+        };
+
+        let mut patch = IncrementalPatch::new(scene);
+        let result = patch.apply(
+            &mut world,
+            &mut EntityHashMap::default(),
+            &AppTypeRegistry::default(),
+            10,
+        );
+        assert!(matches!(
+            result,
+            Err(IncrementalPatchError::Spawn(
+                SceneSpawnError::UnregisteredButReflectedType { .. }
+            ))
+        ));
+    }
+}
@@ -12,12 +12,17 @@
 //! for example to save part of the world state to a file.
 
 mod bundle;
+mod component_mask;
+mod determinism;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod incremental;
+mod prefab;
 mod scene;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
+mod schema_drift;
 
 #[cfg(feature = "serialize")]
 pub mod serde;
@@ -27,12 +32,17 @@ pub use bevy_asset::ron;
 
 use bevy_ecs::schedule::IntoSystemConfigs;
 pub use bundle::*;
+pub use component_mask::*;
+pub use determinism::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use incremental::*;
+pub use prefab::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
+pub use schema_drift::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
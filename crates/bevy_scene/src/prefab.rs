@@ -0,0 +1,448 @@
+use bevy_ecs::{
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::World,
+};
+use bevy_reflect::{ApplyError, Reflect, ReflectRef, TypeInfo, TypeRegistry};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use crate::{
+    ron,
+    serde::{SceneMapDeserializer, SceneMapSerializer},
+    serialize_ron,
+};
+#[cfg(feature = "serialize")]
+use serde::de::DeserializeSeed;
+
+/// A reflected entity template made up of a base set of components
+/// and any number of named variants that patch it.
+///
+/// A variant (such as `"Elite"` or `"Frozen"`) is a list of component patches.
+/// A patch is a reflected value representing one of the component types:
+/// if the base contains that component, the patch is [applied](Reflect::apply) to it,
+/// so a [`DynamicStruct`](bevy_reflect::DynamicStruct) containing only some of the fields
+/// will leave the remaining fields untouched.
+/// Otherwise, the patch is added as a new component.
+///
+/// Variants are composed in the order they are requested.
+/// Two variants patching the same field of the same component is considered a conflict
+/// and will result in a [`PrefabError::Conflict`].
+///
+/// The base and every variant can be serialized as separate documents using
+/// [`Prefab::serialize_base`] and [`PrefabVariant::serialize`].
+#[derive(Default)]
+pub struct Prefab {
+    /// The components every instance of this prefab starts with.
+    pub base: Vec<Box<dyn Reflect>>,
+    /// The named variants that may be applied on top of the base.
+    pub variants: Vec<PrefabVariant>,
+}
+
+/// A named set of component patches for a [`Prefab`].
+pub struct PrefabVariant {
+    /// The name of this variant.
+    pub name: String,
+    /// The component patches making up this variant.
+    pub patches: Vec<Box<dyn Reflect>>,
+}
+
+/// Errors that can occur when composing, validating, or spawning a [`Prefab`].
+#[derive(Error, Debug)]
+pub enum PrefabError {
+    /// A requested variant does not exist on the prefab.
+    #[error("prefab has no variant named `{name}`")]
+    UnknownVariant {
+        /// The name of the requested variant.
+        name: String,
+    },
+    /// Two variants patch the same part of the same component.
+    #[error("variants `{first}` and `{second}` both patch `{member}` of `{type_path}`")]
+    Conflict {
+        /// The type path of the patched component.
+        type_path: String,
+        /// The patched field, or `*` if the whole value is replaced.
+        member: String,
+        /// The variant that patched the member first.
+        first: String,
+        /// The variant that attempted to patch the member again.
+        second: String,
+    },
+    /// A patch could not be applied to the base component.
+    #[error("failed to apply patch for `{type_path}` from variant `{variant}`: {error}")]
+    Apply {
+        /// The type path of the patched component.
+        type_path: String,
+        /// The variant containing the patch.
+        variant: String,
+        /// The underlying error.
+        error: ApplyError,
+    },
+    /// A value does not have a represented type.
+    #[error("prefab contains dynamic type `{type_path}` without a represented type")]
+    NoRepresentedType {
+        /// The dynamic instance type.
+        type_path: String,
+    },
+    /// A value's type is not registered.
+    #[error("prefab contains the reflected type `{type_path}` but it was not found in the type registry")]
+    UnregisteredType {
+        /// The unregistered type.
+        type_path: String,
+    },
+    /// A value's type does not reflect `Component`.
+    #[error("prefab contains `{type_path}` which does not reflect `Component`")]
+    UnregisteredComponent {
+        /// The type which is missing `ReflectComponent`.
+        type_path: String,
+    },
+}
+
+impl Prefab {
+    /// Create a new prefab from the given base components.
+    pub fn new(base: Vec<Box<dyn Reflect>>) -> Self {
+        Self {
+            base,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Add a variant to this prefab, replacing any existing variant with the same name.
+    pub fn with_variant(mut self, variant: PrefabVariant) -> Self {
+        self.add_variant(variant);
+        self
+    }
+
+    /// Add a variant to this prefab, replacing any existing variant with the same name.
+    pub fn add_variant(&mut self, variant: PrefabVariant) {
+        match self.variants.iter_mut().find(|v| v.name == variant.name) {
+            Some(existing) => *existing = variant,
+            None => self.variants.push(variant),
+        }
+    }
+
+    /// Returns the variant with the given name, if any.
+    pub fn variant(&self, name: &str) -> Option<&PrefabVariant> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// Compose the base components with the given variants, applied in order.
+    ///
+    /// A variant that is selected more than once is only applied once.
+    ///
+    /// Returns the resulting list of components, or an error if a variant does not exist,
+    /// two variants conflict, or a patch fails to apply.
+    pub fn compose(&self, variants: &[&str]) -> Result<Vec<Box<dyn Reflect>>, PrefabError> {
+        let mut components: Vec<Box<dyn Reflect>> =
+            self.base.iter().map(|value| value.clone_value()).collect();
+        // Maps (component type path, member) to the variant that patched it.
+        let mut patched: HashMap<(&str, String), &str> = HashMap::default();
+
+        for (index, &name) in variants.iter().enumerate() {
+            if variants[..index].contains(&name) {
+                continue;
+            }
+
+            let variant = self
+                .variant(name)
+                .ok_or_else(|| PrefabError::UnknownVariant {
+                    name: name.to_string(),
+                })?;
+
+            for patch in &variant.patches {
+                let type_info = represented_type_info(&**patch)?;
+                let type_path = type_info.type_path();
+
+                for member in patched_members(&**patch) {
+                    if let Some(first) = patched.insert((type_path, member.clone()), name) {
+                        return Err(PrefabError::Conflict {
+                            type_path: type_path.to_string(),
+                            member,
+                            first: first.to_string(),
+                            second: name.to_string(),
+                        });
+                    }
+                }
+
+                let existing = components.iter_mut().find(|component| {
+                    component
+                        .get_represented_type_info()
+                        .is_some_and(|info| info.type_id() == type_info.type_id())
+                });
+
+                match existing {
+                    Some(component) => {
+                        component
+                            .try_apply(&**patch)
+                            .map_err(|error| PrefabError::Apply {
+                                type_path: type_path.to_string(),
+                                variant: name.to_string(),
+                                error,
+                            })?;
+                    }
+                    None => components.push(patch.clone_value()),
+                }
+            }
+        }
+
+        Ok(components)
+    }
+
+    /// Check that every base component and variant patch is a registered component type.
+    pub fn validate(&self, registry: &TypeRegistry) -> Result<(), PrefabError> {
+        self.base
+            .iter()
+            .chain(self.variants.iter().flat_map(|variant| &variant.patches))
+            .try_for_each(|value| {
+                component_registration(&**value, registry)?;
+                Ok(())
+            })
+    }
+
+    /// Spawn a new entity in the world made up of the base components and the given variants.
+    ///
+    /// The prefab's types are resolved using the world's [`AppTypeRegistry`].
+    pub fn spawn(&self, world: &mut World, variants: &[&str]) -> Result<Entity, PrefabError> {
+        let components = self.compose(variants)?;
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let reflect_components = components
+            .iter()
+            .map(|component| component_registration(&**component, &registry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entity = world.spawn_empty();
+        for (component, reflect_component) in components.iter().zip(reflect_components) {
+            reflect_component.apply_or_insert(&mut entity, &**component, &registry);
+        }
+
+        Ok(entity.id())
+    }
+
+    /// Serialize the base components of this prefab into a RON document.
+    #[cfg(feature = "serialize")]
+    pub fn serialize_base(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(SceneMapSerializer {
+            entries: &self.base,
+            registry,
+        })
+    }
+
+    /// Deserialize the base components of a prefab from a RON document.
+    #[cfg(feature = "serialize")]
+    pub fn deserialize_base(input: &str, registry: &TypeRegistry) -> Result<Self, ron::Error> {
+        let mut deserializer = ron::de::Deserializer::from_str(input)?;
+        let base = SceneMapDeserializer { registry }.deserialize(&mut deserializer)?;
+        Ok(Self::new(base))
+    }
+}
+
+impl PrefabVariant {
+    /// Create a new variant with the given name and patches.
+    pub fn new(name: impl Into<String>, patches: Vec<Box<dyn Reflect>>) -> Self {
+        Self {
+            name: name.into(),
+            patches,
+        }
+    }
+
+    /// Serialize the patches of this variant into a RON document.
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(SceneMapSerializer {
+            entries: &self.patches,
+            registry,
+        })
+    }
+
+    /// Deserialize a variant with the given name from a RON document.
+    ///
+    /// Struct patches may omit fields, which will be left untouched when the variant is applied.
+    #[cfg(feature = "serialize")]
+    pub fn deserialize(
+        name: impl Into<String>,
+        input: &str,
+        registry: &TypeRegistry,
+    ) -> Result<Self, ron::Error> {
+        let mut deserializer = ron::de::Deserializer::from_str(input)?;
+        let patches = SceneMapDeserializer { registry }.deserialize(&mut deserializer)?;
+        Ok(Self::new(name, patches))
+    }
+}
+
+fn represented_type_info(value: &dyn Reflect) -> Result<&'static TypeInfo, PrefabError> {
+    value
+        .get_represented_type_info()
+        .ok_or_else(|| PrefabError::NoRepresentedType {
+            type_path: value.reflect_type_path().to_string(),
+        })
+}
+
+fn component_registration<'a>(
+    value: &dyn Reflect,
+    registry: &'a TypeRegistry,
+) -> Result<&'a ReflectComponent, PrefabError> {
+    let type_info = represented_type_info(value)?;
+    let registration =
+        registry
+            .get(type_info.type_id())
+            .ok_or_else(|| PrefabError::UnregisteredType {
+                type_path: type_info.type_path().to_string(),
+            })?;
+    registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| PrefabError::UnregisteredComponent {
+            type_path: type_info.type_path().to_string(),
+        })
+}
+
+/// Returns the members of a component that the given patch will modify.
+///
+/// Structs patch their individual fields, while any other kind replaces the whole value.
+fn patched_members(patch: &dyn Reflect) -> Vec<String> {
+    match patch.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .filter_map(|index| value.name_at(index))
+            .map(ToString::to_string)
+            .collect(),
+        _ => vec![String::from("*")],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prefab, PrefabError, PrefabVariant};
+    use bevy_ecs::prelude::{Component, ReflectComponent, World};
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::{DynamicStruct, Reflect, TypeRegistry};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Frozen;
+
+    fn health_patch(field: &str, value: f32) -> Box<dyn Reflect> {
+        let mut patch = DynamicStruct::default();
+        patch.set_represented_type(Some(<Health as bevy_reflect::Typed>::type_info()));
+        patch.insert(field, value);
+        Box::new(patch)
+    }
+
+    fn prefab() -> Prefab {
+        Prefab::new(vec![Box::new(Health {
+            current: 10.0,
+            max: 10.0,
+        })])
+        .with_variant(PrefabVariant::new("Elite", vec![health_patch("max", 50.0)]))
+        .with_variant(PrefabVariant::new("Frozen", vec![Box::new(Frozen)]))
+        .with_variant(PrefabVariant::new(
+            "Giant",
+            vec![health_patch("max", 100.0)],
+        ))
+    }
+
+    fn world() -> World {
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Frozen>();
+        }
+        let mut world = World::new();
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn should_spawn_composed_variants() {
+        let mut world = world();
+        let entity = prefab().spawn(&mut world, &["Elite", "Frozen"]).unwrap();
+
+        assert_eq!(
+            Some(&Health {
+                current: 10.0,
+                max: 50.0
+            }),
+            world.get::<Health>(entity)
+        );
+        assert!(world.get::<Frozen>(entity).is_some());
+    }
+
+    #[test]
+    fn should_detect_conflicting_variants() {
+        let result = prefab().compose(&["Elite", "Giant"]);
+        assert!(matches!(
+            result,
+            Err(PrefabError::Conflict { first, second, member, .. })
+                if first == "Elite" && second == "Giant" && member == "max"
+        ));
+    }
+
+    #[test]
+    fn should_apply_repeated_variants_once() {
+        let components = prefab().compose(&["Elite", "Frozen", "Elite"]).unwrap();
+        assert_eq!(2, components.len());
+        assert!(components[0]
+            .reflect_partial_eq(&Health {
+                current: 10.0,
+                max: 50.0,
+            })
+            .unwrap());
+    }
+
+    #[test]
+    fn should_error_on_unknown_variant() {
+        let result = prefab().compose(&["Boss"]);
+        assert!(matches!(result, Err(PrefabError::UnknownVariant { name }) if name == "Boss"));
+    }
+
+    #[test]
+    fn should_validate_against_registry() {
+        let world = world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        assert!(prefab().validate(&registry).is_ok());
+
+        let mut partial = TypeRegistry::empty();
+        partial.register::<Health>();
+        assert!(matches!(
+            prefab().validate(&partial),
+            Err(PrefabError::UnregisteredType { type_path }) if type_path.ends_with("Frozen")
+        ));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn should_roundtrip_variant_documents() {
+        let world = world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let prefab = prefab();
+
+        let base = prefab.serialize_base(&registry).unwrap();
+        let elite = prefab
+            .variant("Elite")
+            .unwrap()
+            .serialize(&registry)
+            .unwrap();
+
+        let prefab = Prefab::deserialize_base(&base, &registry)
+            .unwrap()
+            .with_variant(PrefabVariant::deserialize("Elite", &elite, &registry).unwrap());
+
+        let components = prefab.compose(&["Elite"]).unwrap();
+        assert_eq!(1, components.len());
+        assert!(components[0]
+            .reflect_partial_eq(&Health {
+                current: 10.0,
+                max: 50.0
+            })
+            .unwrap());
+    }
+}
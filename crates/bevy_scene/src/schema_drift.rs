@@ -0,0 +1,515 @@
+use bevy_app::{App, Plugin};
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_reflect::{TypeInfo, TypeRegistry, VariantInfo};
+use bevy_utils::tracing::{info, warn};
+use std::fmt;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A snapshot of the reflected layout of every registered component type.
+///
+/// Snapshots are meant to be exported alongside saved content (such as scenes)
+/// so that later builds can detect when the component types have changed since the
+/// content was written. See [`SchemaDriftPlugin`] for reporting these changes at startup.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SchemaSnapshot {
+    /// The captured component types.
+    pub types: Vec<TypeSnapshot>,
+}
+
+/// The reflected layout of a single type within a [`SchemaSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct TypeSnapshot {
+    /// The [type path](bevy_reflect::TypePath::type_path) of the type.
+    pub type_path: String,
+    /// The fields of the type, in declaration order.
+    ///
+    /// Tuple struct fields are named by their index,
+    /// and enum variant fields are prefixed by their variant name (e.g. `Variant::field`).
+    pub fields: Vec<FieldSnapshot>,
+    /// The variants of the type, in declaration order, if it is an enum.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub variants: Vec<VariantSnapshot>,
+}
+
+/// A single field within a [`TypeSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct FieldSnapshot {
+    /// The name of the field.
+    pub name: String,
+    /// The type path of the field's type.
+    pub type_path: String,
+}
+
+/// A single enum variant within a [`TypeSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct VariantSnapshot {
+    /// The name of the variant.
+    pub name: String,
+    /// The kind of the variant.
+    pub kind: VariantKind,
+}
+
+/// The kind of a [`VariantSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum VariantKind {
+    /// A variant with named fields.
+    Struct,
+    /// A variant with unnamed fields.
+    Tuple,
+    /// A variant without fields.
+    Unit,
+}
+
+impl fmt::Display for VariantKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariantKind::Struct => f.write_str("struct"),
+            VariantKind::Tuple => f.write_str("tuple"),
+            VariantKind::Unit => f.write_str("unit"),
+        }
+    }
+}
+
+/// A difference between a [`SchemaSnapshot`] and the types currently registered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaDrift {
+    /// A type in the snapshot is no longer registered as a component.
+    TypeRemoved {
+        /// The type path of the missing type.
+        type_path: String,
+    },
+    /// A field in the snapshot no longer exists on the type.
+    FieldRemoved {
+        /// The type path of the containing type.
+        type_path: String,
+        /// The name of the removed field.
+        field: String,
+    },
+    /// A field exists on the type but not in the snapshot.
+    FieldAdded {
+        /// The type path of the containing type.
+        type_path: String,
+        /// The name of the new field.
+        field: String,
+    },
+    /// The type of a field changed since the snapshot was taken.
+    FieldTypeChanged {
+        /// The type path of the containing type.
+        type_path: String,
+        /// The name of the changed field.
+        field: String,
+        /// The type path of the field in the snapshot.
+        from: String,
+        /// The type path of the field in the current build.
+        to: String,
+    },
+    /// A variant in the snapshot no longer exists on the enum.
+    VariantRemoved {
+        /// The type path of the enum.
+        type_path: String,
+        /// The name of the removed variant.
+        variant: String,
+    },
+    /// A variant exists on the enum but not in the snapshot.
+    VariantAdded {
+        /// The type path of the enum.
+        type_path: String,
+        /// The name of the new variant.
+        variant: String,
+    },
+    /// The kind of a variant changed since the snapshot was taken.
+    VariantKindChanged {
+        /// The type path of the enum.
+        type_path: String,
+        /// The name of the changed variant.
+        variant: String,
+        /// The kind of the variant in the snapshot.
+        from: VariantKind,
+        /// The kind of the variant in the current build.
+        to: VariantKind,
+    },
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaDrift::TypeRemoved { type_path } => write!(
+                f,
+                "component `{type_path}` is no longer registered; saved values will be dropped"
+            ),
+            SchemaDrift::FieldRemoved { type_path, field } => write!(
+                f,
+                "field `{field}` was removed from `{type_path}`; saved values will be dropped"
+            ),
+            SchemaDrift::FieldAdded { type_path, field } => write!(
+                f,
+                "field `{field}` was added to `{type_path}`; saves will need a default"
+            ),
+            SchemaDrift::FieldTypeChanged {
+                type_path,
+                field,
+                from,
+                to,
+            } => write!(
+                f,
+                "field `{field}` of `{type_path}` changed {from} -> {to}; saves will be migrated"
+            ),
+            SchemaDrift::VariantRemoved { type_path, variant } => write!(
+                f,
+                "variant `{variant}` was removed from `{type_path}`; saved values using it will fail to load"
+            ),
+            SchemaDrift::VariantAdded { type_path, variant } => {
+                write!(f, "variant `{variant}` was added to `{type_path}`")
+            }
+            SchemaDrift::VariantKindChanged {
+                type_path,
+                variant,
+                from,
+                to,
+            } => write!(
+                f,
+                "variant `{variant}` of `{type_path}` changed {from} -> {to}; saved values using it will fail to load"
+            ),
+        }
+    }
+}
+
+impl SchemaSnapshot {
+    /// Capture the layout of every component type in the given registry.
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut types = registry
+            .iter_with_data::<ReflectComponent>()
+            .map(|(registration, _)| TypeSnapshot::new(registration.type_info()))
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.type_path.cmp(&b.type_path));
+        Self { types }
+    }
+
+    /// Append the component types of the given registry that are not in this snapshot yet.
+    ///
+    /// The types already in the snapshot keep their position,
+    /// so that the bits of a [`ComponentBitLayout`](crate::ComponentBitLayout)
+    /// built from the snapshot remain stable.
+    pub fn extend_from_registry(&mut self, registry: &TypeRegistry) {
+        let mut new_types = registry
+            .iter_with_data::<ReflectComponent>()
+            .filter(|(registration, _)| {
+                let type_path = registration.type_info().type_path();
+                !self.types.iter().any(|ty| ty.type_path == type_path)
+            })
+            .map(|(registration, _)| TypeSnapshot::new(registration.type_info()))
+            .collect::<Vec<_>>();
+        new_types.sort_by(|a, b| a.type_path.cmp(&b.type_path));
+        self.types.extend(new_types);
+    }
+
+    /// Compare this snapshot against the types currently registered in the given registry.
+    pub fn drift(&self, registry: &TypeRegistry) -> Vec<SchemaDrift> {
+        let mut drift = Vec::new();
+
+        for snapshot in &self.types {
+            let Some(registration) = registry
+                .get_with_type_path(&snapshot.type_path)
+                .filter(|registration| registration.data::<ReflectComponent>().is_some())
+            else {
+                drift.push(SchemaDrift::TypeRemoved {
+                    type_path: snapshot.type_path.clone(),
+                });
+                continue;
+            };
+
+            let current = TypeSnapshot::new(registration.type_info());
+
+            for variant in &snapshot.variants {
+                match current.variant(&variant.name) {
+                    None => drift.push(SchemaDrift::VariantRemoved {
+                        type_path: snapshot.type_path.clone(),
+                        variant: variant.name.clone(),
+                    }),
+                    Some(current) if current.kind != variant.kind => {
+                        drift.push(SchemaDrift::VariantKindChanged {
+                            type_path: snapshot.type_path.clone(),
+                            variant: variant.name.clone(),
+                            from: variant.kind,
+                            to: current.kind,
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            for variant in &current.variants {
+                if snapshot.variant(&variant.name).is_none() {
+                    drift.push(SchemaDrift::VariantAdded {
+                        type_path: snapshot.type_path.clone(),
+                        variant: variant.name.clone(),
+                    });
+                }
+            }
+
+            for field in &snapshot.fields {
+                match current.field(&field.name) {
+                    None => drift.push(SchemaDrift::FieldRemoved {
+                        type_path: snapshot.type_path.clone(),
+                        field: field.name.clone(),
+                    }),
+                    Some(current) if current.type_path != field.type_path => {
+                        drift.push(SchemaDrift::FieldTypeChanged {
+                            type_path: snapshot.type_path.clone(),
+                            field: field.name.clone(),
+                            from: field.type_path.clone(),
+                            to: current.type_path.clone(),
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            for field in &current.fields {
+                if snapshot.field(&field.name).is_none() {
+                    drift.push(SchemaDrift::FieldAdded {
+                        type_path: snapshot.type_path.clone(),
+                        field: field.name.clone(),
+                    });
+                }
+            }
+        }
+
+        drift
+    }
+}
+
+impl TypeSnapshot {
+    /// Capture the layout of the given type.
+    pub fn new(type_info: &TypeInfo) -> Self {
+        let field = |name: String, type_path: &str| FieldSnapshot {
+            name,
+            type_path: type_path.to_string(),
+        };
+
+        let fields = match type_info {
+            TypeInfo::Struct(info) => info
+                .iter()
+                .map(|f| field(f.name().to_string(), f.type_path()))
+                .collect(),
+            TypeInfo::TupleStruct(info) => info
+                .iter()
+                .map(|f| field(f.index().to_string(), f.type_path()))
+                .collect(),
+            TypeInfo::Enum(info) => info
+                .iter()
+                .flat_map(|variant| match variant {
+                    VariantInfo::Struct(v) => v
+                        .iter()
+                        .map(|f| field(format!("{}::{}", v.name(), f.name()), f.type_path()))
+                        .collect::<Vec<_>>(),
+                    VariantInfo::Tuple(v) => v
+                        .iter()
+                        .map(|f| field(format!("{}::{}", v.name(), f.index()), f.type_path()))
+                        .collect(),
+                    VariantInfo::Unit(_) => Vec::new(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let variants = match type_info {
+            TypeInfo::Enum(info) => info
+                .iter()
+                .map(|variant| VariantSnapshot {
+                    name: variant.name().to_string(),
+                    kind: match variant {
+                        VariantInfo::Struct(_) => VariantKind::Struct,
+                        VariantInfo::Tuple(_) => VariantKind::Tuple,
+                        VariantInfo::Unit(_) => VariantKind::Unit,
+                    },
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Self {
+            type_path: type_info.type_path().to_string(),
+            fields,
+            variants,
+        }
+    }
+
+    /// Returns the field with the given name, if any.
+    pub fn field(&self, name: &str) -> Option<&FieldSnapshot> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the variant with the given name, if any.
+    pub fn variant(&self, name: &str) -> Option<&VariantSnapshot> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+}
+
+/// Logs a warning at startup for every difference between the registered component types
+/// and the given [`SchemaSnapshot`]s.
+///
+/// The check runs once all plugins have been built, so it happens before any content is loaded.
+#[derive(Default)]
+pub struct SchemaDriftPlugin {
+    /// The snapshots to compare against, typically deserialized from saved content.
+    pub snapshots: Vec<SchemaSnapshot>,
+}
+
+impl Plugin for SchemaDriftPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    fn finish(&self, app: &mut App) {
+        let Some(registry) = app.world().get_resource::<AppTypeRegistry>() else {
+            return;
+        };
+        let registry = registry.read();
+
+        info!(
+            components = registry.iter_with_data::<ReflectComponent>().count(),
+            snapshots = self.snapshots.len(),
+            "checking reflected component schemas for drift"
+        );
+
+        for drift in self.snapshots.iter().flat_map(|s| s.drift(&registry)) {
+            warn!(?drift, "{drift}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SchemaDrift, SchemaSnapshot, VariantKind};
+    use bevy_ecs::prelude::{Component, ReflectComponent};
+    use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+
+    mod v1 {
+        use super::*;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub speed: f32,
+            pub name: String,
+        }
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        #[type_path = "game"]
+        pub enum State {
+            #[default]
+            Idle,
+            Walking(f32),
+            Dead,
+        }
+    }
+
+    mod v2 {
+        use super::*;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub speed: f64,
+            pub level: u32,
+        }
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        #[type_path = "game"]
+        pub enum State {
+            #[default]
+            Idle,
+            Walking {
+                speed: f32,
+            },
+            Jumping,
+        }
+    }
+
+    #[test]
+    fn should_report_drift() {
+        let mut old = TypeRegistry::empty();
+        old.register::<v1::Player>();
+        let snapshot = SchemaSnapshot::from_registry(&old);
+
+        let mut new = TypeRegistry::empty();
+        new.register::<v2::Player>();
+
+        let type_path = v2::Player::type_path().to_string();
+        assert_eq!(
+            vec![
+                SchemaDrift::FieldTypeChanged {
+                    type_path: type_path.clone(),
+                    field: "speed".to_string(),
+                    from: "f32".to_string(),
+                    to: "f64".to_string(),
+                },
+                SchemaDrift::FieldRemoved {
+                    type_path: type_path.clone(),
+                    field: "name".to_string(),
+                },
+                SchemaDrift::FieldAdded {
+                    type_path: type_path.clone(),
+                    field: "level".to_string(),
+                },
+            ],
+            snapshot.drift(&new)
+        );
+
+        assert_eq!(
+            vec![SchemaDrift::TypeRemoved { type_path }],
+            snapshot.drift(&TypeRegistry::empty())
+        );
+        assert!(snapshot.drift(&old).is_empty());
+    }
+
+    #[test]
+    fn should_report_variant_drift() {
+        let mut old = TypeRegistry::empty();
+        old.register::<v1::State>();
+        let snapshot = SchemaSnapshot::from_registry(&old);
+
+        let mut new = TypeRegistry::empty();
+        new.register::<v2::State>();
+
+        let type_path = v2::State::type_path().to_string();
+        assert_eq!(
+            vec![
+                SchemaDrift::VariantKindChanged {
+                    type_path: type_path.clone(),
+                    variant: "Walking".to_string(),
+                    from: VariantKind::Tuple,
+                    to: VariantKind::Struct,
+                },
+                SchemaDrift::VariantRemoved {
+                    type_path: type_path.clone(),
+                    variant: "Dead".to_string(),
+                },
+                SchemaDrift::VariantAdded {
+                    type_path: type_path.clone(),
+                    variant: "Jumping".to_string(),
+                },
+                SchemaDrift::FieldRemoved {
+                    type_path: type_path.clone(),
+                    field: "Walking::0".to_string(),
+                },
+                SchemaDrift::FieldAdded {
+                    type_path,
+                    field: "Walking::speed".to_string(),
+                },
+            ],
+            snapshot.drift(&new)
+        );
+        assert!(snapshot.drift(&old).is_empty());
+    }
+}
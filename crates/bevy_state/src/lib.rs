@@ -32,6 +32,9 @@
 pub mod app;
 /// Provides definitions for the runtime conditions that interact with the state system
 pub mod condition;
+/// Provides type data for reading and changing states through reflection
+#[cfg(feature = "bevy_reflect")]
+pub mod reflect;
 /// Provides definitions for the basic traits required by the state system
 pub mod state;
 
@@ -46,6 +49,9 @@ pub mod prelude {
     pub use crate::app::AppExtStates;
     #[doc(hidden)]
     pub use crate::condition::*;
+    #[cfg(feature = "bevy_reflect")]
+    #[doc(hidden)]
+    pub use crate::reflect::{ReflectFreelyMutableState, ReflectState};
     #[doc(hidden)]
     pub use crate::state::{
         last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState, OnEnter, OnExit,
@@ -0,0 +1,315 @@
+use crate::state::{FreelyMutableState, NextState, State, States};
+
+use bevy_ecs::world::World;
+use bevy_reflect::{
+    DynamicEnum, DynamicVariant, EnumInfo, FromReflect, FromType, Reflect, TypeInfo, Typed,
+    VariantInfo,
+};
+use thiserror::Error;
+
+/// A struct used to operate on the reflected [`States`] trait of a type.
+///
+/// A [`ReflectState`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectState(ReflectStateFns);
+
+/// The raw function pointers needed to make up a [`ReflectState`].
+#[derive(Clone)]
+pub struct ReflectStateFns {
+    /// Function pointer implementing [`ReflectState::reflect()`].
+    pub reflect: fn(&World) -> Option<&dyn Reflect>,
+    /// Function pointer implementing [`ReflectState::type_info()`].
+    pub type_info: fn() -> &'static TypeInfo,
+}
+
+impl ReflectStateFns {
+    /// Get the default set of [`ReflectStateFns`] for a specific state type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: States + Reflect + Typed>() -> Self {
+        <ReflectState as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectState {
+    /// Gets the value of the current state of this type, if its [`State`] resource exists.
+    pub fn reflect<'a>(&self, world: &'a World) -> Option<&'a dyn Reflect> {
+        (self.0.reflect)(world)
+    }
+
+    /// Returns the [`TypeInfo`] of the state type.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        (self.0.type_info)()
+    }
+
+    /// Returns the [`EnumInfo`] of the state type, if it is an enum.
+    ///
+    /// This can be used to enumerate the possible values of the state.
+    pub fn enum_info(&self) -> Option<&'static EnumInfo> {
+        match self.type_info() {
+            TypeInfo::Enum(info) => Some(info),
+            _ => None,
+        }
+    }
+
+    /// Create a custom implementation of [`ReflectState`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(State)]` attribute
+    /// to generate a [`ReflectState`] implementation automatically.
+    pub fn new(fns: ReflectStateFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectState`.
+    pub fn fn_pointers(&self) -> &ReflectStateFns {
+        &self.0
+    }
+}
+
+impl<S: States + Reflect + Typed> FromType<S> for ReflectState {
+    fn from_type() -> Self {
+        ReflectState(ReflectStateFns {
+            reflect: |world| {
+                world
+                    .get_resource::<State<S>>()
+                    .map(|state| state.get() as &dyn Reflect)
+            },
+            type_info: S::type_info,
+        })
+    }
+}
+
+/// A struct used to request transitions of a reflected [`FreelyMutableState`] type.
+///
+/// A [`ReflectFreelyMutableState`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectFreelyMutableState(ReflectFreelyMutableStateFns);
+
+/// The raw function pointers needed to make up a [`ReflectFreelyMutableState`].
+#[derive(Clone)]
+pub struct ReflectFreelyMutableStateFns {
+    /// Function pointer implementing [`ReflectFreelyMutableState::set_next_state()`].
+    pub set_next_state: fn(&mut World, &dyn Reflect) -> Result<(), ReflectStateError>,
+}
+
+impl ReflectFreelyMutableStateFns {
+    /// Get the default set of [`ReflectFreelyMutableStateFns`] for a specific state type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: FreelyMutableState + FromReflect + Typed>() -> Self {
+        <ReflectFreelyMutableState as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectFreelyMutableState {
+    /// Queues a transition to the given state in the [`NextState`] resource of this type.
+    ///
+    /// The transition is applied the next time the
+    /// [`StateTransition`](crate::state::StateTransition) schedule runs.
+    pub fn set_next_state(
+        &self,
+        world: &mut World,
+        state: &dyn Reflect,
+    ) -> Result<(), ReflectStateError> {
+        (self.0.set_next_state)(world, state)
+    }
+
+    /// Queues a transition to the unit variant with the given name.
+    ///
+    /// This is a convenience method for enum states that allows
+    /// requesting transitions without constructing a reflected value.
+    pub fn set_next_state_by_name(
+        &self,
+        world: &mut World,
+        state_info: &ReflectState,
+        variant_name: &str,
+    ) -> Result<(), ReflectStateError> {
+        let type_path = state_info.type_info().type_path();
+        let enum_info = state_info
+            .enum_info()
+            .ok_or(ReflectStateError::NotAnEnum { type_path })?;
+        match enum_info.variant(variant_name) {
+            Some(VariantInfo::Unit(_)) => {}
+            Some(_) => {
+                return Err(ReflectStateError::NonUnitVariant {
+                    type_path,
+                    variant_name: variant_name.into(),
+                })
+            }
+            None => {
+                return Err(ReflectStateError::MissingVariant {
+                    type_path,
+                    variant_name: variant_name.into(),
+                })
+            }
+        }
+
+        let mut state = DynamicEnum::new(variant_name, DynamicVariant::Unit);
+        state.set_represented_type(Some(state_info.type_info()));
+        self.set_next_state(world, &state)
+    }
+
+    /// Create a custom implementation of [`ReflectFreelyMutableState`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(FreelyMutableState)]` attribute
+    /// to generate a [`ReflectFreelyMutableState`] implementation automatically.
+    pub fn new(fns: ReflectFreelyMutableStateFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectFreelyMutableState`.
+    pub fn fn_pointers(&self) -> &ReflectFreelyMutableStateFns {
+        &self.0
+    }
+}
+
+impl<S: FreelyMutableState + FromReflect + Typed> FromType<S> for ReflectFreelyMutableState {
+    fn from_type() -> Self {
+        ReflectFreelyMutableState(ReflectFreelyMutableStateFns {
+            set_next_state: |world, state| {
+                let type_path = S::type_info().type_path();
+                let state =
+                    S::from_reflect(state).ok_or_else(|| ReflectStateError::InvalidState {
+                        type_path,
+                        value_type_path: state.reflect_type_path().into(),
+                    })?;
+                world
+                    .get_resource_mut::<NextState<S>>()
+                    .ok_or(ReflectStateError::MissingNextState { type_path })?
+                    .set(state);
+                Ok(())
+            },
+        })
+    }
+}
+
+/// An error that occurs when requesting a state transition through reflection.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ReflectStateError {
+    /// The state type is not an enum, so it has no named variants.
+    #[error("the state `{type_path}` is not an enum")]
+    NotAnEnum {
+        /// The type path of the state.
+        type_path: &'static str,
+    },
+    /// The state type has no variant with the given name.
+    #[error("the state `{type_path}` has no variant named `{variant_name}`")]
+    MissingVariant {
+        /// The type path of the state.
+        type_path: &'static str,
+        /// The name of the requested variant.
+        variant_name: Box<str>,
+    },
+    /// The requested variant has fields, so it cannot be created from its name alone.
+    #[error("the variant `{variant_name}` of the state `{type_path}` is not a unit variant")]
+    NonUnitVariant {
+        /// The type path of the state.
+        type_path: &'static str,
+        /// The name of the requested variant.
+        variant_name: Box<str>,
+    },
+    /// The given value could not be converted into the state type.
+    #[error("a value of type `{value_type_path}` is not a valid `{type_path}`")]
+    InvalidState {
+        /// The type path of the state.
+        type_path: &'static str,
+        /// The type path of the given value.
+        value_type_path: Box<str>,
+    },
+    /// The [`NextState`] resource of the state type does not exist in the world.
+    #[error("the state `{type_path}` has not been initialized")]
+    MissingNextState {
+        /// The type path of the state.
+        type_path: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_state;
+    use crate::state::States;
+    use bevy_reflect::TypeRegistry;
+
+    #[derive(States, Reflect, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    #[reflect(State, FreelyMutableState)]
+    enum GameState {
+        #[default]
+        Menu,
+        InGame,
+        Paused(bool),
+    }
+
+    #[test]
+    fn reflect_state() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<GameState>();
+        let registration = registry.get(std::any::TypeId::of::<GameState>()).unwrap();
+        let reflect_state = registration.data::<ReflectState>().unwrap();
+        let reflect_mutable_state = registration.data::<ReflectFreelyMutableState>().unwrap();
+
+        let mut world = World::new();
+        assert!(reflect_state.reflect(&world).is_none());
+        assert_eq!(
+            Err(ReflectStateError::MissingNextState {
+                type_path: GameState::type_info().type_path()
+            }),
+            reflect_mutable_state.set_next_state_by_name(&mut world, reflect_state, "InGame")
+        );
+
+        world.insert_resource(State::new(GameState::Menu));
+        world.init_resource::<NextState<GameState>>();
+        assert_eq!(
+            Some(&GameState::Menu),
+            reflect_state.reflect(&world).unwrap().downcast_ref()
+        );
+
+        let variants = reflect_state.enum_info().unwrap().variant_names().to_vec();
+        assert_eq!(vec!["Menu", "InGame", "Paused"], variants);
+
+        reflect_mutable_state
+            .set_next_state_by_name(&mut world, reflect_state, "InGame")
+            .unwrap();
+        assert!(matches!(
+            world.resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::InGame)
+        ));
+
+        reflect_mutable_state
+            .set_next_state(&mut world, &GameState::Paused(true))
+            .unwrap();
+        assert!(matches!(
+            world.resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Paused(true))
+        ));
+
+        assert!(matches!(
+            reflect_mutable_state.set_next_state_by_name(&mut world, reflect_state, "Paused"),
+            Err(ReflectStateError::NonUnitVariant { .. })
+        ));
+        assert!(matches!(
+            reflect_mutable_state.set_next_state_by_name(&mut world, reflect_state, "Credits"),
+            Err(ReflectStateError::MissingVariant { .. })
+        ));
+        assert!(matches!(
+            reflect_mutable_state.set_next_state(&mut world, &1_u32),
+            Err(ReflectStateError::InvalidState { .. })
+        ));
+    }
+}